@@ -3,6 +3,7 @@
 //! This module contains all the argument structures used by the FerrumC CLI,
 //! powered by the `clap` crate.
 
+use crate::pregen::PregenShape;
 use clap::{ArgGroup, Parser, Subcommand, ValueEnum};
 use tracing::Level;
 
@@ -32,6 +33,8 @@ pub enum Command {
     Run,
     /// Clear server data (configs, whitelist, logs, world)
     Clear(ClearArgs),
+    /// Generate the world around a centre ahead of time
+    Pregen(PregenArgs),
 }
 
 /// Arguments for the clear command.
@@ -89,6 +92,36 @@ pub struct ImportArgs {
     pub max_concurrent_tasks: usize,
 }
 
+/// Arguments for the pregen command.
+///
+/// Pressing Ctrl+C pauses the pregeneration, which can then be continued with `--resume`.
+#[derive(Debug, Clone, Parser)]
+pub struct PregenArgs {
+    /// Radius to generate around the centre, in chunks
+    #[clap(long, required_unless_present = "resume")]
+    pub radius: Option<u32>,
+
+    /// X coordinate of the centre chunk
+    #[clap(long, default_value_t = 0, allow_hyphen_values = true)]
+    pub center_x: i32,
+
+    /// Z coordinate of the centre chunk
+    #[clap(long, default_value_t = 0, allow_hyphen_values = true)]
+    pub center_z: i32,
+
+    /// Shape of the area to generate
+    #[clap(long, value_enum, default_value_t = PregenShape::Square)]
+    pub shape: PregenShape,
+
+    /// Continue the last paused pregeneration instead of starting a new one
+    #[clap(long, conflicts_with = "radius")]
+    pub resume: bool,
+
+    /// Number of chunks to generate at a time
+    #[clap(long, default_value_t = 256)]
+    pub batch_size: usize,
+}
+
 /// Wrapper struct for the tracing `Level` enum to implement `ValueEnum`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct LogLevel(pub Level);
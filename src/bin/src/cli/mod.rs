@@ -15,10 +15,14 @@
 //!
 //! # Clear all server data
 //! ferrumc clear --all
+//!
+//! # Pregenerate 100 chunks around the origin, then continue after pausing with Ctrl+C
+//! ferrumc pregen --radius 100
+//! ferrumc pregen --resume
 //! ```
 
 mod args;
 mod clear;
 
-pub use args::{CLIArgs, ClearArgs, Command, ImportArgs};
+pub use args::{CLIArgs, ClearArgs, Command, ImportArgs, PregenArgs};
 pub use clear::handle_clear;
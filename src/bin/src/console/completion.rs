@@ -0,0 +1,79 @@
+use crate::packet_handlers::play_packets::command_suggestions::suggest;
+use ferrumc_commands::{infrastructure, Sender};
+use ferrumc_state::GlobalState;
+
+/// Finds every way to complete `input`, each returned as the full completed line.
+///
+/// Candidates are command names (including subcommands like `time set`) that start with the
+/// input, and the suggestions for the argument being typed.
+pub(super) fn complete(input: &str, state: GlobalState) -> Vec<String> {
+    // Arguments only start after the command name
+    let arguments = if input.contains(' ') {
+        let (start, suggestions) = suggest(input, Sender::Server, state);
+        suggestions
+            .into_iter()
+            .map(|suggestion| format!("{}{}", &input[..start], suggestion.content))
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    candidates(input, infrastructure::get_command_names(), arguments)
+}
+
+/// The command names longer than `input` that start with it, along with the completed
+/// arguments, sorted and without duplicates.
+fn candidates(input: &str, names: Vec<&str>, arguments: Vec<String>) -> Vec<String> {
+    let mut candidates: Vec<String> = names
+        .into_iter()
+        .filter(|name| name.len() > input.len() && name.starts_with(input))
+        .map(str::to_string)
+        .chain(arguments)
+        .collect();
+
+    candidates.sort();
+    candidates.dedup();
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NAMES: [&str; 5] = ["teleport", "tellraw", "time", "time set", "tp"];
+
+    #[test]
+    fn test_command_name_prefixes() {
+        assert_eq!(
+            candidates("t", NAMES.to_vec(), Vec::new()),
+            ["teleport", "tellraw", "time", "time set", "tp"]
+        );
+        assert_eq!(
+            candidates("tel", NAMES.to_vec(), Vec::new()),
+            ["teleport", "tellraw"]
+        );
+        // A name that's already typed in full only completes to its subcommands
+        assert_eq!(candidates("time", NAMES.to_vec(), Vec::new()), ["time set"]);
+        assert!(candidates("x", NAMES.to_vec(), Vec::new()).is_empty());
+    }
+
+    #[test]
+    fn test_arguments_are_sorted_and_deduplicated() {
+        let arguments = vec![
+            "time set night".to_string(),
+            "time set day".to_string(),
+            "time set night".to_string(),
+        ];
+        assert_eq!(
+            candidates("time set ", NAMES.to_vec(), arguments),
+            ["time set day", "time set night"]
+        );
+
+        // A subcommand suggested as an argument too only shows up once
+        let arguments = vec!["time add".to_string(), "time set".to_string()];
+        assert_eq!(
+            candidates("time ", NAMES.to_vec(), arguments),
+            ["time add", "time set"]
+        );
+    }
+}
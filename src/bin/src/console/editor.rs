@@ -0,0 +1,257 @@
+use super::completion;
+use console::Key;
+use ferrumc_logging::console::{clear_input_line, print_line, set_input_line};
+use ferrumc_state::GlobalState;
+
+const PROMPT: &str = "> ";
+const MAX_HISTORY: usize = 100;
+
+/// The console's input line, with history and tab completion.
+pub(super) struct LineEditor {
+    buffer: Vec<char>,
+    cursor: usize,
+    history: History,
+    /// Whether the previous key was a Tab that couldn't complete anything further.
+    listed_completions: bool,
+    state: GlobalState,
+}
+
+impl LineEditor {
+    pub(super) fn new(state: GlobalState) -> Self {
+        Self {
+            buffer: Vec::new(),
+            cursor: 0,
+            history: History::default(),
+            listed_completions: false,
+            state,
+        }
+    }
+
+    pub(super) fn redraw(&self) {
+        let text: String = self.buffer.iter().collect();
+        set_input_line(format!("{PROMPT}{text}"), self.buffer.len() - self.cursor);
+    }
+
+    /// Handles a key press. Returns the entered line once Enter is pressed on a non-empty line.
+    pub(super) fn handle_key(&mut self, key: Key) -> Option<String> {
+        if key != Key::Tab {
+            self.listed_completions = false;
+        }
+
+        match key {
+            Key::Enter => return self.submit(),
+            Key::Char(c) if !c.is_control() => {
+                self.buffer.insert(self.cursor, c);
+                self.cursor += 1;
+            }
+            Key::Backspace if self.cursor > 0 => {
+                self.cursor -= 1;
+                self.buffer.remove(self.cursor);
+            }
+            Key::Del if self.cursor < self.buffer.len() => {
+                self.buffer.remove(self.cursor);
+            }
+            Key::ArrowLeft => self.cursor = self.cursor.saturating_sub(1),
+            Key::ArrowRight => self.cursor = (self.cursor + 1).min(self.buffer.len()),
+            Key::Home => self.cursor = 0,
+            Key::End => self.cursor = self.buffer.len(),
+            Key::ArrowUp => self.browse_history(true),
+            Key::ArrowDown => self.browse_history(false),
+            Key::Escape => {
+                self.buffer.clear();
+                self.cursor = 0;
+                self.history.stop_browsing();
+            }
+            Key::Tab => self.complete(),
+            _ => return None,
+        }
+
+        self.redraw();
+        None
+    }
+
+    fn submit(&mut self) -> Option<String> {
+        let line: String = self.buffer.iter().collect();
+        let line = line.trim().to_string();
+
+        self.buffer.clear();
+        self.cursor = 0;
+        self.history.stop_browsing();
+        self.redraw();
+
+        if line.is_empty() {
+            return None;
+        }
+        self.history.push(line.clone());
+        Some(line)
+    }
+
+    fn browse_history(&mut self, older: bool) {
+        if self.history.browse(older, &mut self.buffer) {
+            self.cursor = self.buffer.len();
+        }
+    }
+
+    /// Completes the text before the cursor.
+    ///
+    /// A single candidate is inserted in full. With several, their common prefix is inserted,
+    /// and pressing Tab again lists them.
+    fn complete(&mut self) {
+        let input: String = self.buffer[..self.cursor].iter().collect();
+        let candidates = completion::complete(&input, self.state.clone());
+
+        let completed = match candidates.as_slice() {
+            [] => return,
+            [candidate] => format!("{candidate} "),
+            _ => common_prefix(&candidates),
+        };
+
+        if completed.len() > input.len() {
+            let completed: Vec<char> = completed.chars().collect();
+            let inserted = completed.len() - self.cursor;
+            self.buffer.splice(..self.cursor, completed);
+            self.cursor += inserted;
+        } else if self.listed_completions {
+            // Only show the word being completed, not the whole line
+            let word_start = input.rfind(' ').map_or(0, |i| i + 1);
+            let words: Vec<_> = candidates
+                .iter()
+                .map(|candidate| &candidate[word_start..])
+                .collect();
+
+            clear_input_line();
+            print_line(&words.join("  "));
+        } else {
+            self.listed_completions = true;
+        }
+    }
+}
+
+/// The lines entered before, which can be brought back with the arrow keys.
+#[derive(Default)]
+struct History {
+    entries: Vec<String>,
+    /// The entry being shown, if the operator is browsing history.
+    index: Option<usize>,
+    /// What was typed before browsing history, restored when moving past the newest entry.
+    draft: Vec<char>,
+}
+
+impl History {
+    /// Adds an entered line, unless it's the same as the one before it.
+    fn push(&mut self, line: String) {
+        if self.entries.last() == Some(&line) {
+            return;
+        }
+        if self.entries.len() == MAX_HISTORY {
+            self.entries.remove(0);
+        }
+        self.entries.push(line);
+    }
+
+    fn stop_browsing(&mut self) {
+        self.index = None;
+    }
+
+    /// Replaces the input line with the next older or newer entry. Returns false if there's
+    /// nothing to move to.
+    fn browse(&mut self, older: bool, buffer: &mut Vec<char>) -> bool {
+        let index = match (self.index, older) {
+            (None, true) if !self.entries.is_empty() => {
+                self.draft = std::mem::take(buffer);
+                Some(self.entries.len() - 1)
+            }
+            (Some(index), true) => Some(index.saturating_sub(1)),
+            (Some(index), false) if index + 1 < self.entries.len() => Some(index + 1),
+            (Some(_), false) => None,
+            (None, _) => return false,
+        };
+
+        *buffer = match index {
+            Some(index) => self.entries[index].chars().collect(),
+            None => std::mem::take(&mut self.draft),
+        };
+        self.index = index;
+        true
+    }
+}
+
+fn common_prefix(candidates: &[String]) -> String {
+    let mut prefix = candidates[0].as_str();
+    for candidate in &candidates[1..] {
+        let length = prefix
+            .char_indices()
+            .zip(candidate.chars())
+            .find(|((_, a), b)| a != b)
+            .map_or(prefix.len().min(candidate.len()), |((i, _), _)| i);
+        prefix = &prefix[..length];
+    }
+    prefix.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn history(entries: &[&str]) -> History {
+        let mut history = History::default();
+        for entry in entries {
+            history.push(entry.to_string());
+        }
+        history
+    }
+
+    fn browse(history: &mut History, older: bool, buffer: &mut Vec<char>) -> String {
+        assert!(history.browse(older, buffer));
+        buffer.iter().collect()
+    }
+
+    #[test]
+    fn test_history_walk() {
+        let mut history = history(&["first", "second", "third"]);
+        let mut buffer: Vec<char> = "draft".chars().collect();
+
+        assert_eq!(browse(&mut history, true, &mut buffer), "third");
+        assert_eq!(browse(&mut history, true, &mut buffer), "second");
+        assert_eq!(browse(&mut history, true, &mut buffer), "first");
+        // Stays on the oldest entry
+        assert_eq!(browse(&mut history, true, &mut buffer), "first");
+
+        assert_eq!(browse(&mut history, false, &mut buffer), "second");
+        assert_eq!(browse(&mut history, false, &mut buffer), "third");
+        // Moving past the newest entry brings back what was being typed
+        assert_eq!(browse(&mut history, false, &mut buffer), "draft");
+        assert!(!history.browse(false, &mut buffer));
+    }
+
+    #[test]
+    fn test_history_without_entries() {
+        let mut history = History::default();
+        let mut buffer: Vec<char> = "draft".chars().collect();
+        assert!(!history.browse(true, &mut buffer));
+        assert!(!history.browse(false, &mut buffer));
+        assert_eq!(buffer.iter().collect::<String>(), "draft");
+    }
+
+    #[test]
+    fn test_history_push() {
+        // Repeating the last line doesn't add it again
+        let history = history(&["a", "b", "b", "a"]);
+        assert_eq!(history.entries, ["a", "b", "a"]);
+
+        let mut history = History::default();
+        for i in 0..MAX_HISTORY + 5 {
+            history.push(i.to_string());
+        }
+        assert_eq!(history.entries.len(), MAX_HISTORY);
+        assert_eq!(history.entries[0], "5");
+    }
+
+    #[test]
+    fn test_common_prefix() {
+        let candidates = |list: &[&str]| list.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        assert_eq!(common_prefix(&candidates(&["teleport", "tellraw"])), "tel");
+        assert_eq!(common_prefix(&candidates(&["time", "time set"])), "time");
+        assert_eq!(common_prefix(&candidates(&["tp", "weather"])), "");
+    }
+}
@@ -0,0 +1,122 @@
+//! Interactive server console.
+//!
+//! Lines typed into the terminal running `ferrumc run` are executed as commands sent by
+//! [`Sender::Server`]. When attached to a terminal the console supports line editing, history
+//! and tab completion from the command graph. Otherwise (for example when stdin is piped),
+//! lines are read as-is.
+
+mod completion;
+mod editor;
+
+use crate::errors::BinaryError;
+use crate::packet_handlers::play_packets::command::resolve;
+use bevy_ecs::prelude::*;
+use crossbeam_channel::{Receiver, Sender as ChannelSender};
+use editor::LineEditor;
+use ferrumc_commands::messages::{CommandDispatched, ResolvedCommandDispatched};
+use ferrumc_commands::Sender;
+use ferrumc_state::{GlobalState, GlobalStateResource};
+use std::io::{self, BufRead, IsTerminal};
+use std::sync::atomic::Ordering;
+use tracing::{debug, error, info};
+
+/// Command lines entered into the console, waiting to be dispatched on the next tick.
+#[derive(Resource)]
+pub struct ConsoleCommandRecv(pub Receiver<String>);
+
+/// Starts reading console input on a dedicated thread.
+pub fn start_console(state: GlobalState) -> Result<ConsoleCommandRecv, BinaryError> {
+    let (send, recv) = crossbeam_channel::unbounded();
+
+    std::thread::Builder::new()
+        .name("ConsoleThread".to_string())
+        .spawn(move || {
+            if io::stdin().is_terminal() && io::stdout().is_terminal() {
+                read_interactive(state, send);
+            } else {
+                read_lines(state, send);
+            }
+        })
+        .map_err(BinaryError::Io)?;
+
+    Ok(ConsoleCommandRecv(recv))
+}
+
+/// Reads keys from the terminal, editing the input line until a command is entered.
+fn read_interactive(state: GlobalState, send: ChannelSender<String>) {
+    let term = console::Term::stdout();
+    let mut editor = LineEditor::new(state.clone());
+    editor.redraw();
+
+    while !state.shut_down.load(Ordering::Relaxed) {
+        let key = match term.read_key() {
+            Ok(key) => key,
+            // Ctrl+C, which raises SIGINT and shuts the server down
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => break,
+            Err(e) => {
+                error!("Failed to read console input: {e}");
+                break;
+            }
+        };
+
+        if let Some(line) = editor.handle_key(key) {
+            if send.send(line).is_err() {
+                break;
+            }
+        }
+    }
+
+    ferrumc_logging::console::clear_input_line();
+}
+
+/// Reads whole lines from a non-interactive stdin.
+fn read_lines(state: GlobalState, send: ChannelSender<String>) {
+    for line in io::stdin().lock().lines() {
+        if state.shut_down.load(Ordering::Relaxed) {
+            break;
+        }
+        match line {
+            Ok(line) if line.trim().is_empty() => {}
+            Ok(line) => {
+                if send.send(line.trim().to_string()).is_err() {
+                    break;
+                }
+            }
+            Err(e) => {
+                error!("Failed to read console input: {e}");
+                break;
+            }
+        }
+    }
+    debug!("Console input closed");
+}
+
+/// Dispatches the commands entered into the console since the last tick.
+pub fn dispatch_console_commands(
+    receiver: Res<ConsoleCommandRecv>,
+    mut dispatch_msgs: MessageWriter<CommandDispatched>,
+    mut resolved_dispatch_msgs: MessageWriter<ResolvedCommandDispatched>,
+    state: Res<GlobalStateResource>,
+) {
+    for line in receiver.0.try_iter() {
+        let command = line.strip_prefix('/').unwrap_or(&line).to_string();
+        info!("Console issued server command: /{command}");
+
+        let sender = Sender::Server;
+        dispatch_msgs.write(CommandDispatched {
+            command: command.clone(),
+            sender,
+        });
+
+        match resolve(command, sender, state.0.clone()) {
+            Err(err) => sender.send_message(*err, false),
+            Ok((command, ctx)) => {
+                resolved_dispatch_msgs.write(ResolvedCommandDispatched {
+                    command,
+                    ctx,
+                    sender,
+                });
+            }
+        }
+    }
+}
@@ -6,6 +6,7 @@
 //! - Runs timed schedules (tick, world sync, keepalive, etc.)
 //! - Handles graceful shutdown
 
+use crate::console::{dispatch_console_commands, start_console};
 use crate::errors::BinaryError;
 use crate::packet_handlers::{play_packets, register_player_systems};
 use crate::register_messages::register_messages;
@@ -16,14 +17,16 @@ use crate::systems::listeners::register_gameplay_listeners;
 use crate::systems::mobs::register_mob_systems;
 use crate::systems::new_connections::accept_new_connections;
 use crate::systems::physics::register_physics;
+use crate::systems::query::QueryServer;
 use crate::systems::register_game_systems;
 use crate::systems::shutdown_systems::register_shutdown_systems;
 use bevy_ecs::prelude::World;
 use bevy_ecs::schedule::{ApplyDeferred, ExecutorKind, IntoScheduleConfigs, Schedule};
 use crossbeam_channel::Sender;
-use ferrumc_commands::infrastructure::register_command_systems;
+use ferrumc_commands::infrastructure::{register_command_systems, CommandSystems};
 use ferrumc_config::server_config::get_global_config;
 use ferrumc_net::connection::{handle_connection, NewConnection};
+use ferrumc_net::rcon::{start_rcon_server, RconCommand};
 use ferrumc_net::server::create_server_listener;
 use ferrumc_net::PacketSender;
 use ferrumc_performance::tick::TickData;
@@ -66,6 +69,9 @@ pub fn start_game_loop(global_state: GlobalState) -> Result<(), BinaryError> {
     // Channel for new player connections (TCP acceptor -> main loop)
     let (new_conn_send, new_conn_recv) = crossbeam_channel::unbounded();
 
+    // Channel for commands received over RCON (RCON server -> main loop)
+    let (rcon_send, rcon_recv) = crossbeam_channel::unbounded();
+
     // Shutdown coordination channels:
     // - shutdown_send/recv: Main loop tells TCP acceptor to stop
     // - shutdown_response: TCP acceptor confirms it has stopped
@@ -85,8 +91,17 @@ pub fn start_game_loop(global_state: GlobalState) -> Result<(), BinaryError> {
     // Register event messages the ECS will handle
     register_messages(&mut ecs_world);
 
+    // Start reading commands typed into the server console
+    let console_recv = start_console(global_state.clone())?;
+
     // Register shared resources (connection receiver, global state, etc.)
-    register_resources(&mut ecs_world, new_conn_recv, global_state_res);
+    register_resources(
+        &mut ecs_world,
+        new_conn_recv,
+        console_recv,
+        rcon_recv,
+        global_state_res,
+    )?;
 
     // Build the timed scheduler with all periodic schedules (tick, sync, keepalive)
     let mut timed = build_timed_scheduler();
@@ -103,6 +118,7 @@ pub fn start_game_loop(global_state: GlobalState) -> Result<(), BinaryError> {
         global_state.clone(),
         sender_struct,
         Arc::new(new_conn_send),
+        rcon_send,
         shutdown_recv,
         shutdown_response_send,
     )?;
@@ -236,6 +252,7 @@ pub fn start_game_loop(global_state: GlobalState) -> Result<(), BinaryError> {
 ///
 /// Each schedule runs at a specific interval and handles different aspects of the game:
 /// - **tick**: Main game tick (player updates, packets, commands) - runs at configured TPS
+/// - **redstone**: Redstone power and components - every 2 game ticks
 /// - **world_sync**: Persists world data to disk - every 15 seconds
 /// - **keepalive**: Sends keepalive packets to prevent timeouts - every 1 second
 fn build_timed_scheduler() -> Scheduler {
@@ -251,6 +268,7 @@ fn build_timed_scheduler() -> Scheduler {
         s.set_executor_kind(ExecutorKind::SingleThreaded);
         register_packet_handlers(s); // Handle incoming packets from players
         register_player_systems(s); // Update player state (position, inventory, etc.)
+        s.add_systems(dispatch_console_commands.before(CommandSystems)); // Dispatch commands typed into the console
         register_command_systems(s); // Process queued commands
 
         // Player connection handling - chained to ensure proper event timing:
@@ -271,6 +289,28 @@ fn build_timed_scheduler() -> Scheduler {
             .with_max_catch_up(5), // But only catch up 5 ticks max at once
     );
 
+    // -------------------------------------------------------------------------
+    // REDSTONE SCHEDULE - One redstone tick (2 game ticks)
+    // -------------------------------------------------------------------------
+    // Propagates redstone power and runs delayed components (repeaters, torches, etc).
+    // Uses Burst behavior so circuit timings stay consistent after a lag spike.
+    let build_redstone = |s: &mut Schedule| {
+        s.set_executor_kind(ExecutorKind::SingleThreaded);
+        s.add_systems(
+            (
+                crate::systems::redstone::queue_block_changes,
+                crate::systems::redstone::detect_pressure_plates,
+                crate::systems::redstone::tick_redstone,
+            )
+                .chain(),
+        );
+    };
+    timed.register(
+        TimedSchedule::new("redstone", tick_period * 2, build_redstone)
+            .with_behavior(MissedTickBehavior::Burst)
+            .with_max_catch_up(5),
+    );
+
     // -------------------------------------------------------------------------
     // WORLD SYNC SCHEDULE - Periodic world persistence
     // -------------------------------------------------------------------------
@@ -346,6 +386,19 @@ async fn spawn_lan_pinger() {
     }
 }
 
+async fn spawn_query_server(state: GlobalState) {
+    if !get_global_config().query.enabled {
+        return;
+    }
+
+    let Ok(mut server) = QueryServer::new(state).await else {
+        error!("Failed creating query server");
+        return;
+    };
+
+    server.run().await;
+}
+
 /// Spawns a dedicated thread for accepting TCP connections.
 ///
 /// This function creates a new OS thread with its own Tokio async runtime that:
@@ -369,6 +422,7 @@ fn tcp_conn_acceptor(
     state: GlobalState,
     packet_sender: Arc<PacketSender>,
     sender: Arc<Sender<NewConnection>>,
+    rcon_sender: Sender<RconCommand>,
     mut shutdown_notify: tokio::sync::oneshot::Receiver<()>,
     shutdown_response: Sender<()>,
 ) -> Result<(), BinaryError> {
@@ -386,6 +440,16 @@ fn tcp_conn_acceptor(
             // Spawn LAN broadcast pinger (for local network server discovery)
             async_runtime.spawn(spawn_lan_pinger());
 
+            // Spawn the UDP query server (for server list sites and monitoring tools)
+            async_runtime.spawn(spawn_query_server(state.clone()));
+
+            // Spawn the RCON server, if enabled, on its own port next to the game listener
+            async_runtime.spawn(async move {
+                if let Err(e) = start_rcon_server(rcon_sender).await {
+                    error!("RCON server stopped: {}", e);
+                }
+            });
+
             // Main connection accept loop
             async_runtime.block_on({
                 let state = Arc::clone(&state);
@@ -1,32 +1,72 @@
-//! Launch utilities for server initialization, chunk generation, and world import.
+//! Launch utilities for server initialization, chunk generation, world import and pregeneration.
 
-use crate::cli::ImportArgs;
+use crate::cli::{ImportArgs, PregenArgs};
 use crate::errors::BinaryError;
+use crate::pregen::PregenTask;
 use ferrumc_config::server_config::get_global_config;
+use ferrumc_core::game_rules::{GameRule, GameRuleValue};
+use ferrumc_core::scoreboard::ScoreboardData;
+use ferrumc_core::world_metadata::{WorldInfo, WorldMetadata};
 use ferrumc_general_purpose::paths::get_root_path;
+use ferrumc_net::packets::outgoing::set_default_spawn_position::DEFAULT_SPAWN_POSITION;
 use ferrumc_state::player_list::PlayerList;
 use ferrumc_state::{GlobalState, ServerState};
 use ferrumc_threadpool::ThreadPool;
 use ferrumc_world::pos::ChunkPos;
 use ferrumc_world::World;
 use ferrumc_world_gen::WorldGenerator;
+use indicatif::{ProgressBar, ProgressStyle};
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
 use std::time::Instant;
 use tracing::{error, info};
 
 /// Creates the initial server state with all required components.
 pub fn create_state(start_time: Instant) -> Result<ServerState, BinaryError> {
-    // Fixed seed for world generation. This seed ensures you spawn above land at the default spawn point.
-    const SEED: u64 = 380;
+    let world = World::new(&get_global_config().database.db_path);
+    let metadata = load_world_metadata(&world)?;
     Ok(ServerState {
-        world: World::new(&get_global_config().database.db_path),
-        terrain_generator: WorldGenerator::new(SEED),
+        world,
+        terrain_generator: WorldGenerator::new(metadata.seed),
         shut_down: false.into(),
         players: PlayerList::default(),
         thread_pool: ThreadPool::new(),
         start_time,
+        info: WorldInfo {
+            seed: metadata.seed,
+            spawn: metadata.spawn,
+        },
     })
 }
 
+/// Loads the world's metadata, or creates it for a new world.
+///
+/// Metadata that can't be read is an error rather than a fresh start, which would overwrite it
+/// (and the seed) the next time the world is saved.
+pub fn load_world_metadata(world: &World) -> Result<WorldMetadata, BinaryError> {
+    // Fixed seed for world generation. This seed ensures you spawn above land at the default spawn point.
+    const SEED: u64 = 380;
+
+    if let Some(metadata) = world.load_world_data(WorldMetadata::SAVE_NAME)? {
+        return Ok(metadata);
+    }
+
+    let spawn = DEFAULT_SPAWN_POSITION;
+    let mut metadata = WorldMetadata::new(SEED, (spawn.x, spawn.y as i32, spawn.z));
+    metadata.game_rules.set(
+        GameRule::RandomTickSpeed,
+        GameRuleValue::Int(get_global_config().random_tick_speed as i32),
+    );
+    Ok(metadata)
+}
+
+/// Loads the world's scoreboard, which is empty for a new world.
+pub fn load_scoreboard(world: &World) -> Result<ScoreboardData, BinaryError> {
+    Ok(world
+        .load_world_data(ScoreboardData::SAVE_NAME)?
+        .unwrap_or_default())
+}
+
 /// Generates spawn chunks around the origin if they don't exist.
 pub fn generate_spawn_chunks(state: GlobalState) -> Result<(), BinaryError> {
     info!("No overworld spawn chunk found, generating spawn chunks...");
@@ -83,3 +123,58 @@ pub fn handle_import(import_args: ImportArgs) -> Result<(), BinaryError> {
 
     Ok(())
 }
+
+/// Handles pregenerating the world from the CLI, pausing on Ctrl+C.
+pub fn handle_pregen(pregen_args: PregenArgs) -> Result<(), BinaryError> {
+    let mut task = if pregen_args.resume {
+        PregenTask::load()?
+            .ok_or_else(|| BinaryError::Custom("There is no pregeneration to resume.".into()))?
+    } else {
+        let radius = pregen_args
+            .radius
+            .expect("clap requires a radius when not resuming");
+        PregenTask::new(
+            (pregen_args.center_x, pregen_args.center_z),
+            radius,
+            pregen_args.shape,
+        )
+    };
+
+    let state: GlobalState = Arc::new(create_state(Instant::now())?);
+
+    let stop = Arc::new(AtomicBool::new(false));
+    ctrlc::set_handler({
+        let stop = stop.clone();
+        move || {
+            info!("Pausing pregeneration...");
+            stop.store(true, std::sync::atomic::Ordering::Relaxed);
+        }
+    })
+    .expect("Error setting Ctrl-C handler");
+
+    let progress_style = ProgressStyle::default_bar()
+        .template("[{elapsed_precise}/{eta_precise} eta] {bar:40.cyan/blue} {percent}%, {pos:>7}/{len:7}, {msg}")
+        .unwrap();
+    let progress = ProgressBar::new(0);
+    progress.set_style(progress_style);
+    progress.set_message("Generating chunks...");
+
+    let start = Instant::now();
+    let finished = crate::pregen::run(
+        &state,
+        &mut task,
+        || pregen_args.batch_size,
+        &stop,
+        &progress,
+    )?;
+
+    if finished {
+        progress.finish_with_message("Pregeneration complete");
+        info!("Pregenerated {} chunks in {:?}", task.done, start.elapsed());
+    } else {
+        progress.abandon_with_message("Pregeneration paused");
+        info!("Pregeneration paused, continue it with `pregen --resume`");
+    }
+
+    Ok(())
+}
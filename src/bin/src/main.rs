@@ -10,10 +10,12 @@ use std::time::Instant;
 use tracing::{error, info};
 
 mod cli;
+mod console;
 pub(crate) mod errors;
 mod game_loop;
 mod launch;
 mod packet_handlers;
+mod pregen;
 mod register_messages;
 mod register_resources;
 mod systems;
@@ -57,6 +59,16 @@ fn main() {
             }
         }
 
+        Some(Command::Pregen(pregen_args)) => {
+            info!("Starting pregeneration...");
+            if let Err(e) = launch::handle_pregen(pregen_args) {
+                error!(
+                    "Pregeneration failed with the following error: {}",
+                    e.to_string()
+                );
+            }
+        }
+
         Some(Command::Clear(clear_args)) => {
             if let Err(e) = cli::handle_clear(clear_args) {
                 error!("Clear failed: {}", e);
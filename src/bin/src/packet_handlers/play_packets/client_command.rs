@@ -0,0 +1,304 @@
+//! Respawning players after they die.
+//!
+//! Players respawn on the bed they last used if it's still there, or otherwise on the surface
+//! somewhere within the `spawnRadius` game rule of the world spawn.
+//! They come back with full health and hunger and without any effects. Whether they kept their
+//! items and XP was already decided by the `keepInventory` game rule when they died.
+
+use crate::systems::effects::send_removed;
+use crate::systems::listeners::player_spawn::PLAYER_TYPE_ID;
+use bevy_ecs::prelude::{DetectChangesMut, Entity, MessageWriter, Query, Res, With};
+use ferrumc_components::active_effects::ActiveEffects;
+use ferrumc_components::attributes::Attributes;
+use ferrumc_components::health::{Absorption, Health, HurtCooldown};
+use ferrumc_components::player::abilities::PlayerAbilities;
+use ferrumc_components::player::experience::Experience;
+use ferrumc_components::player::gamemode::GameModeComponent;
+use ferrumc_components::player::hunger::Hunger;
+use ferrumc_components::player::respawn_point::RespawnPoint;
+use ferrumc_core::game_rules::{GameRule, GameRules};
+use ferrumc_core::identity::player_identity::PlayerIdentity;
+use ferrumc_core::transform::position::Position;
+use ferrumc_core::transform::rotation::Rotation;
+use ferrumc_core::world_metadata::WorldInfo;
+use ferrumc_inventories::inventory::Inventory;
+use ferrumc_messages::teleport_player::TeleportPlayer;
+use ferrumc_net::broadcast::broadcast_packet_all;
+use ferrumc_net::connection::StreamWriter;
+use ferrumc_net::packets::outgoing::game_event::GameEventPacket;
+use ferrumc_net::packets::outgoing::player_abilities::PlayerAbilities as OutgoingAbilities;
+use ferrumc_net::packets::outgoing::remove_entities::RemoveEntitiesPacket;
+use ferrumc_net::packets::outgoing::respawn::RespawnPacket;
+use ferrumc_net::packets::outgoing::set_container_content::SetContainerContent;
+use ferrumc_net::packets::outgoing::set_health::SetHealth;
+use ferrumc_net::packets::outgoing::spawn_entity::SpawnEntityPacket;
+use ferrumc_net::ClientCommandReceiver;
+use ferrumc_net_codec::encode::NetEncode;
+use ferrumc_net_codec::net_types::length_prefixed_vec::LengthPrefixedVec;
+use ferrumc_net_codec::net_types::var_int::VarInt;
+use ferrumc_state::GlobalStateResource;
+use ferrumc_world::pos::BlockPos;
+use ferrumc_world::{World, DEFAULT_DIMENSION};
+use rand::Rng;
+use tracing::error;
+
+/// How far above the bottom of a bed players stand when they respawn on it.
+const BED_HEIGHT: f64 = 0.5625;
+
+/// How many random spots around the world spawn are tried before using the spawn itself.
+const SPAWN_ATTEMPTS: usize = 10;
+
+/// The overworld's build limits.
+const MIN_Y: i32 = -64;
+const MAX_Y: i32 = 319;
+
+type RespawnQuery<'a> = (
+    &'a PlayerIdentity,
+    &'a StreamWriter,
+    &'a GameModeComponent,
+    &'a PlayerAbilities,
+    &'a Inventory,
+    &'a mut RespawnPoint,
+    &'a mut Health,
+    &'a mut Hunger,
+    &'a mut Absorption,
+    &'a mut HurtCooldown,
+    &'a mut ActiveEffects,
+    &'a mut Attributes,
+    &'a mut Experience,
+);
+
+pub fn handle(
+    receiver: Res<ClientCommandReceiver>,
+    state: Res<GlobalStateResource>,
+    info: Res<WorldInfo>,
+    game_rules: Res<GameRules>,
+    mut players: Query<RespawnQuery>,
+    others: Query<(Entity, &StreamWriter), With<PlayerIdentity>>,
+    mut teleports: MessageWriter<TeleportPlayer>,
+) {
+    for (packet, entity) in receiver.0.try_iter() {
+        if !packet.is_respawn_request() {
+            continue;
+        }
+        let Ok((
+            identity,
+            writer,
+            gamemode,
+            abilities,
+            inventory,
+            mut respawn_point,
+            mut health,
+            mut hunger,
+            mut absorption,
+            mut cooldown,
+            mut effects,
+            mut attributes,
+            mut experience,
+        )) = players.get_mut(entity)
+        else {
+            continue;
+        };
+        // The client also asks to respawn when it joins while dead, but never while alive
+        if health.current > 0.0 {
+            continue;
+        }
+
+        let (position, yaw) = match bed_spawn(&state.0.world, &respawn_point) {
+            Some(position) => (position, respawn_point.angle),
+            None => {
+                if respawn_point.position.is_some() {
+                    *respawn_point = RespawnPoint::default();
+                    send(
+                        writer,
+                        &GameEventPacket::new(GameEventPacket::NO_RESPAWN_BLOCK_AVAILABLE, 0.0),
+                    );
+                }
+                let radius = game_rules.get_int(GameRule::SpawnRadius);
+                (world_spawn(&state.0.world, &info, radius), 0.0)
+            }
+        };
+
+        health.current = health.max;
+        *hunger = Hunger::default();
+        absorption.amount = 0.0;
+        *cooldown = HurtCooldown::default();
+        let removed = effects.clear();
+        send_removed(identity.short_uuid, &mut attributes, &removed, &others);
+
+        // The client starts over with a new player, so everything it knows about them is sent again
+        send(writer, &RespawnPacket::same_dimension(gamemode.0));
+        send(writer, &GameEventPacket::start_waiting_for_level_chunks());
+        send(writer, &OutgoingAbilities::from_abilities(abilities));
+        send(
+            writer,
+            &SetHealth::new(health.current, hunger.level as i32, hunger.saturation),
+        );
+        send(writer, &inventory_contents(inventory));
+        attributes.set_changed();
+        experience.set_changed();
+
+        // Other players still see the body, so it's replaced with the respawned player
+        let rotation = Rotation::new(yaw, 0.0);
+        broadcast_packet_all(
+            &RemoveEntitiesPacket::from_ids([identity.short_uuid]),
+            others.iter().filter(|(other, _)| *other != entity),
+        );
+        broadcast_packet_all(
+            &SpawnEntityPacket::new(
+                identity.short_uuid,
+                identity.uuid.as_u128(),
+                PLAYER_TYPE_ID,
+                &position,
+                &rotation,
+            ),
+            others.iter().filter(|(other, _)| *other != entity),
+        );
+
+        teleports.write(TeleportPlayer {
+            entity,
+            x: position.x,
+            y: position.y,
+            z: position.z,
+            vel_x: 0.0,
+            vel_y: 0.0,
+            vel_z: 0.0,
+            yaw,
+            pitch: 0.0,
+        });
+    }
+}
+
+/// Where a player respawns on the bed they last used, if they used one and it's still there.
+fn bed_spawn(world: &World, respawn_point: &RespawnPoint) -> Option<Position> {
+    let (x, y, z) = respawn_point.position?;
+    let block = world
+        .get_block_and_fetch(BlockPos::of(x, y, z), DEFAULT_DIMENSION)
+        .ok()?;
+    if !block.name()?.ends_with("_bed") {
+        return None;
+    }
+    Some(Position::new(
+        x as f64 + 0.5,
+        y as f64 + BED_HEIGHT,
+        z as f64 + 0.5,
+    ))
+}
+
+/// A random spot on the surface within `radius` blocks of the world spawn, or the spawn itself
+/// if there's nowhere safe to stand.
+fn world_spawn(world: &World, info: &WorldInfo, radius: i32) -> Position {
+    if radius <= 0 {
+        return info.spawn_position();
+    }
+    let mut rng = rand::thread_rng();
+    let (spawn_x, _, spawn_z) = info.spawn;
+    for _ in 0..SPAWN_ATTEMPTS {
+        let x = spawn_x + rng.gen_range(-radius..=radius);
+        let z = spawn_z + rng.gen_range(-radius..=radius);
+        if let Some(y) = surface(world, x, z) {
+            return Position::new(x as f64 + 0.5, y as f64, z as f64 + 0.5);
+        }
+    }
+    info.spawn_position()
+}
+
+/// The height players stand at on top of a column, unless it's topped with a liquid.
+fn surface(world: &World, x: i32, z: i32) -> Option<i32> {
+    for y in (MIN_Y..=MAX_Y).rev() {
+        let block = world
+            .get_block_and_fetch(BlockPos::of(x, y, z), DEFAULT_DIMENSION)
+            .ok()?;
+        let name = block.name()?.strip_prefix("minecraft:")?;
+        if name.ends_with("air") {
+            continue;
+        }
+        if matches!(name, "water" | "lava") {
+            return None;
+        }
+        return Some(y + 1);
+    }
+    None
+}
+
+/// Every slot of the player's inventory, for the window it's always open in.
+fn inventory_contents(inventory: &Inventory) -> SetContainerContent {
+    SetContainerContent {
+        window_id: VarInt::new(0),
+        state_id: VarInt::new(0),
+        slots: LengthPrefixedVec::new(
+            inventory
+                .slots
+                .iter()
+                .map(|slot| slot.clone().unwrap_or_default())
+                .collect(),
+        ),
+        carried_item: Default::default(),
+    }
+}
+
+fn send(writer: &StreamWriter, packet: &(impl NetEncode + Send)) {
+    if let Err(err) = writer.send_packet_ref(packet) {
+        error!("Failed to send respawn packet: {:?}", err);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ferrumc_macros::block;
+    use ferrumc_world::pos::ChunkPos;
+
+    #[test]
+    fn test_bed_spawn() {
+        let (state, _temp_dir) = ferrumc_state::create_test_state();
+        ferrumc_utils::world::load_or_generate_mut(
+            &state.0,
+            ChunkPos::new(0, 0),
+            DEFAULT_DIMENSION,
+        )
+        .expect("Failed to load chunk")
+        .fill(block!("air"));
+        let world = &state.0.world;
+        let head = BlockPos::of(3, 64, 5);
+        let respawn_point = RespawnPoint {
+            position: Some((3, 64, 5)),
+            angle: 90.0,
+        };
+        assert!(bed_spawn(world, &RespawnPoint::default()).is_none());
+        assert!(bed_spawn(world, &respawn_point).is_none());
+
+        let bed = block!("red_bed", { facing: "north", occupied: false, part: "head" });
+        world.set_loaded_block(head, DEFAULT_DIMENSION, bed);
+        let position = bed_spawn(world, &respawn_point).unwrap();
+        assert_eq!(
+            (position.x, position.y, position.z),
+            (3.5, 64.0 + BED_HEIGHT, 5.5)
+        );
+    }
+
+    #[test]
+    fn test_surface() {
+        let (state, _temp_dir) = ferrumc_state::create_test_state();
+        ferrumc_utils::world::load_or_generate_mut(
+            &state.0,
+            ChunkPos::new(0, 0),
+            DEFAULT_DIMENSION,
+        )
+        .expect("Failed to load chunk")
+        .fill(block!("air"));
+        let world = &state.0.world;
+        assert_eq!(surface(world, 2, 2), None);
+
+        world.set_loaded_block(BlockPos::of(2, 70, 2), DEFAULT_DIMENSION, block!("stone"));
+        world.set_loaded_block(BlockPos::of(2, 40, 2), DEFAULT_DIMENSION, block!("stone"));
+        assert_eq!(surface(world, 2, 2), Some(71));
+
+        world.set_loaded_block(
+            BlockPos::of(2, 71, 2),
+            DEFAULT_DIMENSION,
+            block!("water", { level: 0 }),
+        );
+        assert_eq!(surface(world, 2, 2), None);
+    }
+}
@@ -1,4 +1,7 @@
-use bevy_ecs::system::{Query, Res};
+use crate::systems::containers::{give_back, leftover_inputs};
+use bevy_ecs::prelude::{Commands, Query, Res};
+use ferrumc_core::transform::position::Position;
+use ferrumc_inventories::container::{CarriedItem, OpenContainer};
 use ferrumc_inventories::defined_slots;
 use ferrumc_inventories::inventory::Inventory;
 use ferrumc_net::CloseContainerReceiver;
@@ -6,24 +9,50 @@ use tracing::{debug, error};
 
 /// Called when a player closes a container.
 ///
-/// For now, all it does is check if that container is the player inventory,
-/// and then clears the player's crafting grid if so.
-pub fn handle(receiver: Res<CloseContainerReceiver>, mut inventories: Query<&mut Inventory>) {
+/// Items left in an enchanting table, anvil or grindstone, and the one on the cursor, go back to
+/// the player. Closing the player inventory also clears their crafting grid.
+pub fn handle(
+    receiver: Res<CloseContainerReceiver>,
+    mut players: Query<(
+        &mut Inventory,
+        &mut CarriedItem,
+        Option<&OpenContainer>,
+        &Position,
+    )>,
+    mut commands: Commands,
+) {
     for (event, eid) in receiver.0.try_iter() {
+        let Ok((mut inventory, mut carried, container, position)) = players.get_mut(eid) else {
+            error!("Failed to get inventory for entity {eid}");
+            continue;
+        };
+
         // 0 is the player's inventory
         if event.window_id.0 == 0 {
             debug!("Clearing crafting grid");
 
-            if let Ok(mut inventory) = inventories.get_mut(eid) {
-                // CRAFT_SLOT_OUTPUT is slot 0 and CRAFT_SLOT 1-4 are slots 1-4, this clears all the survival inventory crafting grid slots
-                for slot_id in
-                    defined_slots::player::CRAFT_SLOT_OUTPUT..=defined_slots::player::CRAFT_SLOT_4
-                {
-                    inventory
-                        .clear_slot_with_update(slot_id as _, eid)
-                        .unwrap_or_else(|err| error!("Failed to clear player inventory: {}", err))
-                }
+            // CRAFT_SLOT_OUTPUT is slot 0 and CRAFT_SLOT 1-4 are slots 1-4, this clears all the survival inventory crafting grid slots
+            for slot_id in
+                defined_slots::player::CRAFT_SLOT_OUTPUT..=defined_slots::player::CRAFT_SLOT_4
+            {
+                inventory
+                    .clear_slot_with_update(slot_id as _, eid)
+                    .unwrap_or_else(|err| error!("Failed to clear player inventory: {}", err))
             }
+            let cursor = std::mem::take(&mut carried.0);
+            give_back(&mut commands, eid, [cursor], &mut inventory, position);
+            continue;
+        }
+
+        let Some(container) = container else {
+            continue;
+        };
+        if container.window_id as i32 != event.window_id.0 {
+            continue;
         }
+        let cursor = std::mem::take(&mut carried.0);
+        let stacks = leftover_inputs(container).chain([cursor]);
+        give_back(&mut commands, eid, stacks, &mut inventory, position);
+        commands.entity(eid).remove::<OpenContainer>();
     }
 }
@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use bevy_ecs::prelude::*;
-use ferrumc_commands::{Command, CommandContext, CommandInput, Sender, ROOT_COMMAND};
+use ferrumc_commands::{Command, CommandContext, CommandInput, Sender, Suggestion, ROOT_COMMAND};
 use ferrumc_net::{
     connection::StreamWriter,
     packets::outgoing::command_suggestions::{CommandSuggestionsPacket, Match},
@@ -67,6 +67,41 @@ fn create_ctx(
     }
 }
 
+/// Suggests completions for the last word of a command `input`, with or without the leading slash.
+///
+/// Returns the byte offset that word starts at, along with the suggestions matching it.
+pub(crate) fn suggest(input: &str, sender: Sender, state: GlobalState) -> (usize, Vec<Suggestion>) {
+    let command = find_command(input.to_string());
+    let command_arg = input
+        .strip_prefix('/')
+        .unwrap_or(input)
+        .strip_prefix(&format!(
+            "{} ",
+            command.clone().map(|c| c.name).unwrap_or_default()
+        ))
+        .unwrap_or(input)
+        .to_string();
+    let mut ctx = create_ctx(command_arg.clone(), command.clone(), sender, state);
+    let current_token = command_arg.split(" ").last().unwrap_or_default();
+
+    let mut suggestions = Vec::new();
+
+    if let Some(command) = command {
+        for arg in command.args.clone() {
+            let arg_suggestions = (arg.suggester)(&mut ctx);
+            ctx.input.skip_whitespace(u32::MAX, true);
+            if !ctx.input.has_remaining_input() {
+                suggestions = arg_suggestions;
+                break;
+            }
+        }
+    }
+
+    let start = input.len() - current_token.len();
+    suggestions.retain(|sug| sug.content.starts_with(current_token));
+    (start, suggestions)
+}
+
 pub fn handle(
     receiver: Res<CommandSuggestionRequestReceiver>,
     query: Query<&StreamWriter>,
@@ -78,43 +113,8 @@ pub fn handle(
         }
 
         let input = request.input;
-
-        let command = find_command(input.clone());
-        let command_arg = input
-            .clone()
-            .strip_prefix(&format!(
-                "/{} ",
-                command.clone().map(|c| c.name).unwrap_or_default()
-            ))
-            .unwrap_or(&input)
-            .to_string();
-        let mut ctx = create_ctx(
-            command_arg.clone(),
-            command.clone(),
-            Sender::Player(entity),
-            state.0.clone(),
-        );
-        let command_arg = command_arg.clone(); // ok borrow checker
-        let tokens = command_arg.split(" ").collect::<Vec<&str>>();
-        let Some(current_token) = tokens.last() else {
-            return; // whitespace
-        };
-
-        let mut suggestions = Vec::new();
-
-        if let Some(command) = command {
-            for arg in command.args.clone() {
-                let arg_suggestions = (arg.suggester)(&mut ctx);
-                ctx.input.skip_whitespace(u32::MAX, true);
-                if !ctx.input.has_remaining_input() {
-                    suggestions = arg_suggestions;
-                    break;
-                }
-            }
-        }
-
+        let (start, suggestions) = suggest(&input, Sender::Player(entity), state.0.clone());
         let length = input.len();
-        let start = length - current_token.len();
 
         if let Err(e) = query
             .get(entity)
@@ -124,7 +124,6 @@ pub fn handle(
                 matches: LengthPrefixedVec::new(
                     suggestions
                         .into_iter()
-                        .filter(|sug| sug.content.starts_with(current_token))
                         .map(|sug| Match {
                             content: sug.content,
                             tooltip: PrefixedOptional::new(sug.tooltip),
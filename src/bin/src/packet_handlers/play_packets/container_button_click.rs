@@ -0,0 +1,80 @@
+use crate::systems::containers::{refresh, send_properties, ContainerUserQuery};
+use bevy_ecs::prelude::{Query, Res};
+use ferrumc_components::player::gamemode::GameMode;
+use ferrumc_data::items::Item;
+use ferrumc_inventories::container::Menu;
+use ferrumc_inventories::enchanting::{
+    apply_table_enchantments, can_enchant_at_table, table_enchantments,
+};
+use ferrumc_inventories::slot::InventorySlot;
+use ferrumc_net::packets::outgoing::set_container_slot::SetContainerSlot;
+use ferrumc_net::ContainerButtonClickReceiver;
+use ferrumc_net_codec::net_types::var_int::VarInt;
+use tracing::error;
+
+/// Enchants the item in an enchanting table with the offer the player clicked.
+pub fn handle(receiver: Res<ContainerButtonClickReceiver>, mut players: Query<ContainerUserQuery>) {
+    for (event, eid) in receiver.0.try_iter() {
+        let Ok((writer, _, _, Some(mut container), mut experience, mut seed, gamemode, _)) =
+            players.get_mut(eid)
+        else {
+            continue;
+        };
+        if container.window_id as i32 != event.window_id.0 {
+            continue;
+        }
+        let Menu::EnchantingTable { offers, .. } = &container.menu else {
+            continue;
+        };
+        let Some((button, cost)) = usize::try_from(event.button_id.0)
+            .ok()
+            .and_then(|button| Some((button, offers.get(button)?.cost)))
+        else {
+            continue;
+        };
+        let creative = gamemode.0 == GameMode::Creative;
+
+        // The first offer takes one lapis and level, the second two and the third three
+        let price = button as u32 + 1;
+        let lapis = &container.slots[1];
+        let has_lapis = lapis
+            .item_id
+            .is_some_and(|id| id.0 .0 == Item::LAPIS_LAZULI.id as i32)
+            && lapis.count.0 >= price as i32;
+        let can_afford =
+            creative || (has_lapis && experience.level >= cost && experience.level >= price);
+        if cost == 0 || !can_afford || !can_enchant_at_table(&container.slots[0]) {
+            continue;
+        }
+
+        let enchantments = table_enchantments(&container.slots[0], seed.0, button, cost);
+        if enchantments.is_empty() {
+            continue;
+        }
+        container.slots[0] = apply_table_enchantments(&container.slots[0], &enchantments);
+        if !creative {
+            let lapis = &mut container.slots[1];
+            lapis.count.0 -= price as i32;
+            if lapis.count.0 <= 0 {
+                *lapis = InventorySlot::empty();
+            }
+            experience.add_levels(-(price as i32));
+        }
+
+        // New offers for the next item
+        seed.0 = rand::random();
+        refresh(&mut container, seed.0, creative);
+        for slot in 0..container.slots.len() {
+            let packet = SetContainerSlot {
+                window_id: VarInt::new(container.window_id as i32),
+                state_id: VarInt::new(0),
+                slot_index: slot as i16,
+                slot: container.slots[slot].clone(),
+            };
+            if let Err(err) = writer.send_packet_ref(&packet) {
+                error!("Failed to send enchanted item: {:?}", err);
+            }
+        }
+        send_properties(writer, &container, seed.0);
+    }
+}
@@ -0,0 +1,147 @@
+//! Players attacking each other and mobs.
+//!
+//! The damage comes from the attacker's attack damage attribute, which includes their weapon,
+//! plus enchantments like sharpness. Attacking again before the weapon has recharged, which takes
+//! longer the lower the attack speed attribute is, does less damage.
+
+use bevy_ecs::prelude::{Commands, Component, Entity, MessageWriter, Query, Res, With};
+use ferrumc_components::attributes::Attributes;
+use ferrumc_components::player::gamemode::{GameMode, GameModeComponent};
+use ferrumc_core::identity::entity_identity::EntityIdentity;
+use ferrumc_core::identity::player_identity::PlayerIdentity;
+use ferrumc_core::transform::position::Position;
+use ferrumc_data::generated::attributes::Attribute;
+use ferrumc_data::generated::damage_types::DamageType;
+use ferrumc_entities::components::CombatProperties;
+use ferrumc_inventories::enchanting::{attack_damage_bonus, hurt_item};
+use ferrumc_inventories::hotbar::Hotbar;
+use ferrumc_inventories::inventory::Inventory;
+use ferrumc_messages::{EntityDamaged, PlayerDamaged};
+use ferrumc_net::broadcast::broadcast_packet_all;
+use ferrumc_net::connection::StreamWriter;
+use ferrumc_net::packets::outgoing::entity_event::EntityStatus;
+use ferrumc_net::InteractEntityReceiver;
+use std::time::Instant;
+use tracing::error;
+
+/// How much further than their interaction range a player can be from who they hit, since
+/// positions are measured at the feet rather than the closest point of the hitbox.
+const REACH_MARGIN: f64 = 1.5;
+
+/// The entity status that plays the break animation of the item in the main hand.
+const MAINHAND_BREAK_STATUS: u8 = 47;
+
+/// When a player last attacked, to work out how far their weapon has recharged.
+#[derive(Component)]
+pub struct LastAttack(pub Instant);
+
+type AttackerQuery<'a> = (
+    &'a PlayerIdentity,
+    &'a Position,
+    &'a Attributes,
+    &'a GameModeComponent,
+    &'a mut Inventory,
+    &'a Hotbar,
+    Option<&'a LastAttack>,
+);
+
+pub fn handle(
+    receiver: Res<InteractEntityReceiver>,
+    mut attackers: Query<AttackerQuery>,
+    targets: Query<(Entity, &PlayerIdentity, &Position, &GameModeComponent)>,
+    mobs: Query<(Entity, &EntityIdentity, &Position), With<CombatProperties>>,
+    writers: Query<(Entity, &StreamWriter), With<PlayerIdentity>>,
+    mut damage_msgs: MessageWriter<PlayerDamaged>,
+    mut entity_damage_msgs: MessageWriter<EntityDamaged>,
+    mut commands: Commands,
+) {
+    for (event, eid) in receiver.0.try_iter() {
+        if !event.is_attack() {
+            continue;
+        }
+        let Ok((identity, position, attributes, gamemode, mut inventory, hotbar, last_attack)) =
+            attackers.get_mut(eid)
+        else {
+            continue;
+        };
+        if gamemode.0 == GameMode::Spectator {
+            continue;
+        }
+        // Mobs don't have a game mode
+        let target = targets
+            .iter()
+            .find(|(_, target, _, _)| target.short_uuid == event.entity_id.0)
+            .map(|(target, _, position, gamemode)| (target, position, Some(gamemode.0)))
+            .or_else(|| {
+                mobs.iter()
+                    .find(|(_, target, _)| target.entity_id == event.entity_id.0)
+                    .map(|(target, _, position)| (target, position, None))
+            });
+        let Some((target, target_position, target_gamemode)) = target else {
+            continue;
+        };
+        if target == eid || target_gamemode == Some(GameMode::Spectator) {
+            continue;
+        }
+        let reach = attributes.value(&Attribute::ENTITY_INTERACTION_RANGE) + REACH_MARGIN;
+        if position.coords.distance(target_position.coords) > reach {
+            continue;
+        }
+
+        // Like vanilla, the weapon needs 20 / attack speed ticks to recharge fully
+        let now = Instant::now();
+        let recharge = 1.0 / attributes.value(&Attribute::ATTACK_SPEED).max(f64::EPSILON);
+        let charge = last_attack.map_or(1.0, |last| {
+            ((now - last.0).as_secs_f64() / recharge).clamp(0.0, 1.0) as f32
+        });
+        commands.entity(eid).insert(LastAttack(now));
+
+        let slot = hotbar.get_selected_inventory_index();
+        let weapon = inventory.get_item(slot).ok().flatten().cloned();
+        let bonus = weapon.as_ref().map_or(0.0, attack_damage_bonus);
+        let base = attributes.value(&Attribute::ATTACK_DAMAGE) as f32;
+        let amount = base * (0.2 + charge * charge * 0.8) + bonus * charge;
+        if amount <= 0.0 {
+            continue;
+        }
+        if target_gamemode.is_some() {
+            damage_msgs.write(
+                PlayerDamaged::new(target, amount, DamageType::PlayerAttack)
+                    .with_attacker(Some(eid)),
+            );
+        } else {
+            entity_damage_msgs.write(EntityDamaged {
+                entity: target,
+                amount,
+                damage_type: DamageType::PlayerAttack,
+                attacker: Some(eid),
+            });
+        }
+
+        // Weapons lose durability for every hit, which unbreaking can save
+        let Some(mut weapon) = weapon else {
+            continue;
+        };
+        if gamemode.0 == GameMode::Creative || weapon.max_damage().is_none() {
+            continue;
+        }
+        let result = if hurt_item(&mut weapon, 1, false, &mut rand::thread_rng()) {
+            broadcast_packet_all(
+                &EntityStatus {
+                    entity_id: identity.short_uuid,
+                    status: MAINHAND_BREAK_STATUS,
+                },
+                writers.iter(),
+            );
+            inventory.clear_slot_with_update(slot, eid)
+        } else {
+            inventory.set_item_with_update(slot, weapon, eid)
+        };
+        if let Err(err) = result {
+            error!(
+                "Failed to damage weapon of {}: {:?}",
+                identity.username, err
+            );
+        }
+    }
+}
@@ -3,9 +3,12 @@ use bevy_ecs::schedule::Schedule;
 mod change_game_mode;
 mod chat_message;
 mod chunk_batch_ack;
-mod command;
-mod command_suggestions;
+mod client_command;
+pub(crate) mod command;
+pub(crate) mod command_suggestions;
 mod confirm_player_teleport;
+mod container_button_click;
+mod interact;
 mod keep_alive;
 mod pick_item_from_block;
 mod place_block;
@@ -14,15 +17,18 @@ mod player_action;
 mod player_command;
 pub mod player_input;
 mod player_loaded;
+mod rename_item;
 mod set_player_position;
 mod set_player_position_and_rotation;
 mod set_player_rotation;
 mod swing_arm;
+mod use_item;
 
 pub fn register_packet_handlers(schedule: &mut Schedule) {
     // Added separately so if we mess up the signature of one of the systems we can know exactly
     // which one
     schedule.add_systems(chunk_batch_ack::handle);
+    schedule.add_systems(client_command::handle);
     schedule.add_systems(confirm_player_teleport::handle);
     schedule.add_systems(keep_alive::handle);
     schedule.add_systems(place_block::handle);
@@ -35,6 +41,9 @@ pub fn register_packet_handlers(schedule: &mut Schedule) {
     schedule.add_systems(swing_arm::handle);
     schedule.add_systems(update_survival_mode_slot::handle);
     schedule.add_systems(close_container::handle);
+    schedule.add_systems(container_button_click::handle);
+    schedule.add_systems(rename_item::handle);
+    schedule.add_systems(interact::handle);
     schedule.add_systems(player_loaded::handle);
     schedule.add_systems(command::handle);
     schedule.add_systems(command_suggestions::handle);
@@ -44,6 +53,7 @@ pub fn register_packet_handlers(schedule: &mut Schedule) {
     schedule.add_systems(player_abilities::handle);
     schedule.add_systems(change_game_mode::handle);
     schedule.add_systems(pick_item_from_block::handle);
+    schedule.add_systems(use_item::handle);
 }
 
 pub mod set_creative_mode_slot;
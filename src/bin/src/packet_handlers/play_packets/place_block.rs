@@ -1,26 +1,46 @@
-use bevy_ecs::prelude::{Entity, Query, Res};
+use bevy_ecs::prelude::{Entity, MessageWriter, Query, Res, ResMut};
+use ferrumc_components::player::gamemode::{GameMode, GameModeComponent};
+use ferrumc_components::player::sneak::SneakState;
 use ferrumc_core::collisions::bounds::CollisionBounds;
+use ferrumc_core::identity::player_identity::PlayerIdentity;
 use ferrumc_core::transform::position::Position;
+use ferrumc_core::transform::rotation::Rotation;
+use ferrumc_core::world_border::WorldBorders;
+use ferrumc_messages::{BlockPlacedEvent, PlaySound, PlayerUsedBed, PlayerUsedContainer, PrimeTnt};
 use ferrumc_net::connection::StreamWriter;
+use ferrumc_net::packets::incoming::place_block::PlaceBlock;
 use ferrumc_net::packets::outgoing::block_change_ack::BlockChangeAck;
-use ferrumc_net::packets::outgoing::block_update::BlockUpdate;
 use ferrumc_net::PlaceBlockReceiver;
-use ferrumc_net_codec::net_types::network_position::NetworkPosition;
-use ferrumc_net_codec::net_types::var_int::VarInt;
 use ferrumc_state::GlobalStateResource;
-use ferrumc_world::pos::BlockPos;
+use ferrumc_world::pos::{BlockPos, Direction};
 use tracing::{debug, error, trace};
 
-use ferrumc_config::server_config::get_global_config;
+use crate::systems::block_interactions::placement::{placement_states, PlacementContext};
+use crate::systems::block_interactions::{use_block, use_sound, BlockUse};
+use crate::systems::block_ticks::{broadcast_block_changes, BlockBehaviours};
+use crate::systems::redstone::RedstoneEngine;
+use crate::systems::sounds::{play_block_sound, BlockSound};
+
 use ferrumc_core::mq;
+use ferrumc_data::items::Item;
+use ferrumc_inventories::enchanting::hurt_item;
 use ferrumc_inventories::hotbar::Hotbar;
 use ferrumc_inventories::inventory::Inventory;
+use ferrumc_macros::match_block;
+use ferrumc_net::broadcast::broadcast_packet_all;
+use ferrumc_net::packets::outgoing::entity_event::EntityStatus;
+use ferrumc_net_codec::net_types::var_int::VarInt;
 use ferrumc_text::{Color, NamedColor, TextComponentBuilder};
 use ferrumc_world::block_state_id::BlockStateId;
+use ferrumc_world::errors::WorldError;
+use ferrumc_world::{World, DEFAULT_DIMENSION};
 use once_cell::sync::Lazy;
 use std::collections::HashMap;
 use std::str::FromStr;
 
+/// The entity status that plays the break animation of the item in the main hand.
+const MAINHAND_BREAK_STATUS: u8 = 47;
+
 const ITEM_TO_BLOCK_MAPPING_FILE: &str =
     include_str!("../../../../../assets/data/item_to_block_mapping.json");
 static ITEM_TO_BLOCK_MAPPING: Lazy<HashMap<i32, BlockStateId>> = Lazy::new(|| {
@@ -37,14 +57,35 @@ static ITEM_TO_BLOCK_MAPPING: Lazy<HashMap<i32, BlockStateId>> = Lazy::new(|| {
         .collect()
 });
 
+#[expect(clippy::too_many_arguments)]
 pub fn handle(
     receiver: Res<PlaceBlockReceiver>,
     state: Res<GlobalStateResource>,
-    query: Query<(Entity, &StreamWriter, &Inventory, &Hotbar, &Position)>,
+    mut query: Query<(
+        Entity,
+        &PlayerIdentity,
+        &StreamWriter,
+        &mut Inventory,
+        &Hotbar,
+        &Rotation,
+        &SneakState,
+        &GameModeComponent,
+    )>,
+    players: Query<(Entity, &StreamWriter, &Position)>,
     pos_q: Query<(&Position, &CollisionBounds)>,
+    behaviours: Res<BlockBehaviours>,
+    borders: Res<WorldBorders>,
+    mut redstone: ResMut<RedstoneEngine>,
+    mut placed_events: MessageWriter<BlockPlacedEvent>,
+    mut bed_events: MessageWriter<PlayerUsedBed>,
+    mut container_events: MessageWriter<PlayerUsedContainer>,
+    mut lit_tnt: MessageWriter<PrimeTnt>,
+    mut sounds: MessageWriter<PlaySound>,
 ) {
     'ev_loop: for (event, eid) in receiver.0.try_iter() {
-        let Ok((entity, conn, inventory, hotbar, _)) = query.get(eid) else {
+        let Ok((entity, identity, conn, mut inventory, hotbar, rotation, sneak, gamemode)) =
+            query.get_mut(eid)
+        else {
             debug!("Could not get connection for entity {:?}", eid);
             continue;
         };
@@ -54,25 +95,139 @@ pub fn handle(
         }
         match event.hand.0 {
             0 => {
-                let Ok(slot) = hotbar.get_selected_item(inventory) else {
+                let Ok(slot) = hotbar.get_selected_item(&inventory) else {
                     error!("Could not fetch {:?}", eid);
                     continue 'ev_loop;
                 };
-                if let Some(selected_item) = slot {
-                    let Some(item_id) = selected_item.item_id else {
-                        error!("Selected item has no item ID");
-                        continue 'ev_loop;
+                let clicked: BlockPos = event.position.into();
+
+                // Sneaking with an item in hand skips using the block and places the item instead
+                if !(sneak.is_sneaking && slot.is_some()) {
+                    let block_clicked = match state
+                        .0
+                        .world
+                        .get_block_and_fetch(clicked, DEFAULT_DIMENSION)
+                    {
+                        Ok(block) => block,
+                        Err(err) => {
+                            error!("Failed to fetch clicked block at {}: {:?}", clicked, err);
+                            continue 'ev_loop;
+                        }
                     };
-                    let Some(mapped_block_state_id) = ITEM_TO_BLOCK_MAPPING.get(&item_id.0 .0)
-                    else {
-                        error!("No block mapping found for item ID: {}", item_id.0);
+                    trace!("Block clicked: {:?}", block_clicked);
+
+                    // Flint and steel and fire charges light TNT
+                    let lighter = slot.and_then(|slot| slot.item()).filter(|item| {
+                        **item == Item::FLINT_AND_STEEL || **item == Item::FIRE_CHARGE
+                    });
+                    if lighter.is_some() && match_block!("tnt", block_clicked) {
+                        lit_tnt.write(PrimeTnt {
+                            position: clicked,
+                            igniter: Some(entity),
+                        });
+                        if gamemode.0 != GameMode::Creative {
+                            let slot = hotbar.get_selected_inventory_index();
+                            use_lighter(&mut inventory, slot, entity, identity, &players);
+                        }
+                        send_ack(conn, &event);
                         continue 'ev_loop;
-                    };
-                    debug!(
-                        "Placing block with item ID: {}, mapped to block state ID: {}",
-                        item_id.0, mapped_block_state_id
-                    );
-                    let pos: BlockPos = event.position.into();
+                    }
+
+                    match use_block(&state.0.world, clicked, block_clicked, rotation.yaw) {
+                        BlockUse::Pass => {}
+                        BlockUse::Used(changes) => {
+                            for (pos, block) in &changes {
+                                if let Err(err) = state.0.world.set_block_and_fetch(
+                                    *pos,
+                                    DEFAULT_DIMENSION,
+                                    *block,
+                                ) {
+                                    error!("Failed to set block at {}: {:?}", pos, err);
+                                    continue 'ev_loop;
+                                }
+                                behaviours.0.notify_neighbours(
+                                    &state.0.world,
+                                    DEFAULT_DIMENSION,
+                                    *pos,
+                                );
+                                redstone.mark_changed(*pos);
+                            }
+                            if let Some(sound) = changes.first().and_then(|(pos, used)| {
+                                use_sound(*pos, block_clicked, *used, entity)
+                            }) {
+                                sounds.write(sound);
+                            }
+                            send_ack(conn, &event);
+                            broadcast_block_changes(&state.0, &changes, &players);
+                            continue 'ev_loop;
+                        }
+                        BlockUse::Sleep { foot, head } => {
+                            bed_events.write(PlayerUsedBed {
+                                player: entity,
+                                foot,
+                                head,
+                            });
+                            send_ack(conn, &event);
+                            continue 'ev_loop;
+                        }
+                        BlockUse::OpenContainer(menu) => {
+                            container_events.write(PlayerUsedContainer {
+                                player: entity,
+                                position: clicked,
+                                menu,
+                            });
+                            send_ack(conn, &event);
+                            continue 'ev_loop;
+                        }
+                    }
+                }
+
+                let Some(selected_item) = slot else {
+                    continue 'ev_loop;
+                };
+                let Some(item_id) = selected_item.item_id else {
+                    error!("Selected item has no item ID");
+                    continue 'ev_loop;
+                };
+                let Some(mapped_block_state_id) = ITEM_TO_BLOCK_MAPPING.get(&item_id.0 .0) else {
+                    error!("No block mapping found for item ID: {}", item_id.0);
+                    continue 'ev_loop;
+                };
+                debug!(
+                    "Placing block with item ID: {}, mapped to block state ID: {}",
+                    item_id.0, mapped_block_state_id
+                );
+                let Some(face) = Direction::from_face(event.face.0) else {
+                    debug!("Invalid block face: {}", event.face.0);
+                    continue 'ev_loop;
+                };
+                let offset_pos = clicked + face;
+
+                // Make sure the chunk is loaded so the placement can look at the blocks around it
+                if let Err(err) = ferrumc_utils::world::load_or_generate_mut(
+                    &state.0,
+                    offset_pos.chunk(),
+                    DEFAULT_DIMENSION,
+                ) {
+                    error!("Failed to load chunk for block placement: {:?}", err);
+                    continue 'ev_loop;
+                }
+
+                let ctx = PlacementContext {
+                    clicked,
+                    face,
+                    cursor: (event.cursor_x, event.cursor_y, event.cursor_z),
+                    yaw: rotation.yaw,
+                    pitch: rotation.pitch,
+                };
+                let Some(states) =
+                    placement_states(&state.0.world, offset_pos, *mapped_block_state_id, &ctx)
+                else {
+                    trace!("No room to place block at {}", offset_pos);
+                    continue 'ev_loop;
+                };
+
+                for (pos, _) in &states {
                     if pos.pos.y >= 319 {
                         mq::queue(
                             TextComponentBuilder::new(
@@ -99,90 +254,65 @@ pub fn handle(
                         );
                         trace!("Block placement out of bounds: {}", pos);
                         continue 'ev_loop;
+                    } else if !borders
+                        .get(DEFAULT_DIMENSION)
+                        .contains_block(pos.pos.x, pos.pos.z)
+                    {
+                        mq::queue(
+                            TextComponentBuilder::new(
+                                "Cannot place block outside the world border.".to_string(),
+                            )
+                            .color(Color::Named(NamedColor::Red))
+                            .bold()
+                            .build(),
+                            true,
+                            entity,
+                        );
+                        trace!("Block placement outside the world border: {}", pos);
+                        continue 'ev_loop;
                     }
-                    let offset_pos = pos
-                        + match event.face.0 {
-                            0 => (0, -1, 0),
-                            1 => (0, 1, 0),
-                            2 => (0, 0, -1),
-                            3 => (0, 0, 1),
-                            4 => (-1, 0, 0),
-                            5 => (1, 0, 0),
-                            _ => (0, 0, 0),
-                        };
-
-                    let mut chunk = ferrumc_utils::world::load_or_generate_mut(
-                        &state.0,
-                        offset_pos.chunk(),
-                        "overworld",
-                    )
-                    .expect("Failed to load or generate chunk");
-                    let block_clicked = chunk.get_block(offset_pos.chunk_block_pos());
-                    trace!("Block clicked: {:?}", block_clicked);
 
                     // Check if the block collides with any entities
-                    let does_collide = {
-                        pos_q.into_iter().any(|(pos, bounds)| {
-                            bounds.collides(
-                                (pos.x, pos.y, pos.z),
-                                &CollisionBounds {
-                                    x_offset_start: 0.0,
-                                    x_offset_end: 1.0,
-                                    y_offset_start: 0.0,
-                                    y_offset_end: 1.0,
-                                    z_offset_start: 0.0,
-                                    z_offset_end: 1.0,
-                                },
-                                (
-                                    offset_pos.pos.x as f64,
-                                    offset_pos.pos.y as f64,
-                                    offset_pos.pos.z as f64,
-                                ),
-                            )
-                        })
-                    };
-
+                    let does_collide = pos_q.iter().any(|(entity_pos, bounds)| {
+                        bounds.collides(
+                            (entity_pos.x, entity_pos.y, entity_pos.z),
+                            &CollisionBounds {
+                                x_offset_start: 0.0,
+                                x_offset_end: 1.0,
+                                y_offset_start: 0.0,
+                                y_offset_end: 1.0,
+                                z_offset_start: 0.0,
+                                z_offset_end: 1.0,
+                            },
+                            (pos.pos.x as f64, pos.pos.y as f64, pos.pos.z as f64),
+                        )
+                    });
                     if does_collide {
                         trace!("Block placement collided with entity");
                         continue 'ev_loop;
                     }
+                }
 
-                    chunk.set_block(offset_pos.chunk_block_pos(), *mapped_block_state_id);
-                    let ack_packet = BlockChangeAck {
-                        sequence: event.sequence,
-                    };
-
-                    let chunk_packet = BlockUpdate {
-                        location: NetworkPosition {
-                            x: offset_pos.pos.x,
-                            y: offset_pos.pos.y as i16,
-                            z: offset_pos.pos.z,
-                        },
-                        block_state_id: VarInt::from(*mapped_block_state_id),
-                    };
-
-                    if let Err(err) = conn.send_packet_ref(&ack_packet) {
-                        error!("Failed to send block change ack packet: {:?}", err);
-                        continue 'ev_loop;
-                    }
+                if let Err(err) = place_states(&state.0.world, &states) {
+                    error!("Failed to place block at {}: {:?}", offset_pos, err);
+                    continue 'ev_loop;
+                }
+                for (pos, block) in &states {
+                    behaviours
+                        .0
+                        .notify_neighbours(&state.0.world, DEFAULT_DIMENSION, *pos);
+                    placed_events.write(BlockPlacedEvent {
+                        player: entity,
+                        position: *pos,
+                        block: *block,
+                    });
+                }
 
-                    let offset_chunk = offset_pos.chunk();
-                    let (offset_chunk_x, offset_chunk_z) = (offset_chunk.x(), offset_chunk.z());
-                    let render_distance = get_global_config().chunk_render_distance as i32;
-                    for (_, conn, _, _, pos) in query.iter() {
-                        let chunk = pos.chunk();
-                        let (chunk_x, chunk_z) = (chunk.x, chunk.y);
-
-                        // Only send block update if the player is within the render distance of the block being updated
-                        if (offset_chunk_x - chunk_x).abs() <= render_distance
-                            && (offset_chunk_z - chunk_z).abs() <= render_distance
-                        {
-                            if let Err(err) = conn.send_packet_ref(&chunk_packet) {
-                                error!("Failed to send block update packet: {:?}", err);
-                            }
-                        }
-                    }
+                if let Some((pos, block)) = states.first() {
+                    play_block_sound(&mut sounds, *pos, *block, BlockSound::Place, entity);
                 }
+                send_ack(conn, &event);
+                broadcast_block_changes(&state.0, &states, &players);
             }
             1 => {
                 trace!("Offhand block placement not implemented");
@@ -193,3 +323,72 @@ pub fn handle(
         }
     }
 }
+
+/// Sets every block of a placement, such as both halves of a bed or door. If one of them can't be
+/// set, the ones already set are put back so no half blocks are left behind.
+fn place_states(world: &World, states: &[(BlockPos, BlockStateId)]) -> Result<(), WorldError> {
+    let mut replaced = Vec::with_capacity(states.len());
+    for (pos, block) in states {
+        let result = world
+            .get_block_and_fetch(*pos, DEFAULT_DIMENSION)
+            .and_then(|old| {
+                world.set_block_and_fetch(*pos, DEFAULT_DIMENSION, *block)?;
+                replaced.push((*pos, old));
+                Ok(())
+            });
+        if let Err(err) = result {
+            for (pos, old) in replaced.into_iter().rev() {
+                if let Err(err) = world.set_block_and_fetch(pos, DEFAULT_DIMENSION, old) {
+                    error!("Failed to undo block placement at {}: {:?}", pos, err);
+                }
+            }
+            return Err(err);
+        }
+    }
+    Ok(())
+}
+
+/// Damages the flint and steel or uses up the fire charge in `slot`.
+fn use_lighter(
+    inventory: &mut Inventory,
+    slot: usize,
+    entity: Entity,
+    identity: &PlayerIdentity,
+    players: &Query<(Entity, &StreamWriter, &Position)>,
+) {
+    let Ok(Some(held)) = inventory.get_item(slot) else {
+        return;
+    };
+    let mut held = held.clone();
+    let result = if held.max_damage().is_some() {
+        if hurt_item(&mut held, 1, false, &mut rand::thread_rng()) {
+            broadcast_packet_all(
+                &EntityStatus {
+                    entity_id: identity.short_uuid,
+                    status: MAINHAND_BREAK_STATUS,
+                },
+                players.iter().map(|(entity, writer, _)| (entity, writer)),
+            );
+            inventory.clear_slot_with_update(slot, entity)
+        } else {
+            inventory.set_item_with_update(slot, held, entity)
+        }
+    } else if held.count.0 > 1 {
+        held.count = VarInt::new(held.count.0 - 1);
+        inventory.set_item_with_update(slot, held, entity)
+    } else {
+        inventory.clear_slot_with_update(slot, entity)
+    };
+    if let Err(err) = result {
+        error!("Failed to use lighter of {}: {:?}", identity.username, err);
+    }
+}
+
+fn send_ack(conn: &StreamWriter, event: &PlaceBlock) {
+    let ack_packet = BlockChangeAck {
+        sequence: event.sequence,
+    };
+    if let Err(err) = conn.send_packet_ref(&ack_packet) {
+        error!("Failed to send block change ack packet: {:?}", err);
+    }
+}
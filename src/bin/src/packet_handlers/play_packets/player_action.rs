@@ -1,8 +1,15 @@
 use crate::errors::BinaryError;
-use bevy_ecs::prelude::{Entity, MessageWriter, Query, Res};
+use crate::systems::item_entities::spawn_item;
+use bevy_ecs::prelude::{Commands, Entity, MessageWriter, Query, Res};
 use ferrumc_components::player::abilities::PlayerAbilities;
+use ferrumc_core::transform::position::Position;
+use ferrumc_core::transform::rotation::Rotation;
+use ferrumc_core::transform::velocity::Velocity;
+use ferrumc_entities::components::ItemEntity;
+use ferrumc_inventories::hotbar::Hotbar;
+use ferrumc_inventories::inventory::Inventory;
 use ferrumc_messages::player_digging::*;
-use ferrumc_messages::BlockBrokenEvent;
+use ferrumc_messages::{BlockBrokenEvent, PlayerReleasedItem};
 
 use ferrumc_net::connection::StreamWriter;
 use ferrumc_net::packets::outgoing::block_change_ack::BlockChangeAck;
@@ -11,19 +18,39 @@ use ferrumc_net::PlayerActionReceiver;
 use ferrumc_net_codec::net_types::var_int::VarInt;
 use ferrumc_state::GlobalStateResource;
 use ferrumc_world::{block_state_id::BlockStateId, pos::BlockPos};
+use rand::Rng;
+use std::f32::consts::TAU;
 use tracing::{error, warn};
 
+/// Player action statuses for the drop key (Q and Ctrl+Q).
+const DROP_STACK: i32 = 3;
+const DROP_ITEM: i32 = 4;
+
+/// Player action status for letting go of the use key, like when shooting a bow.
+const RELEASE_USE_ITEM: i32 = 5;
+
+type DropperQuery<'a> = (&'a mut Inventory, &'a Hotbar, &'a Position, &'a Rotation);
+
 pub fn handle(
     receiver: Res<PlayerActionReceiver>,
     state: Res<GlobalStateResource>,
     broadcast_query: Query<(Entity, &StreamWriter)>,
     player_query: Query<&PlayerAbilities>,
-    (mut start_dig_events, mut cancel_dig_events, mut finish_dig_events, mut block_break_events): (
+    (
+        mut start_dig_events,
+        mut cancel_dig_events,
+        mut finish_dig_events,
+        mut block_break_events,
+        mut release_events,
+    ): (
         MessageWriter<PlayerStartedDigging>,
         MessageWriter<PlayerCancelledDigging>,
         MessageWriter<PlayerFinishedDigging>,
         MessageWriter<BlockBrokenEvent>,
+        MessageWriter<PlayerReleasedItem>,
     ),
+    mut droppers: Query<DropperQuery>,
+    mut commands: Commands,
 ) {
     // https://minecraft.wiki/w/Minecraft_Wiki:Projects/wiki.vg_merge/Protocol?oldid=2773393#Player_Action
     for (event, trigger_eid) in receiver.0.try_iter() {
@@ -36,6 +63,28 @@ pub fn handle(
             continue;
         };
 
+        // Dropping items works the same in every gamemode
+        if event.status.0 == DROP_STACK || event.status.0 == DROP_ITEM {
+            if let Ok((mut inventory, hotbar, position, rotation)) = droppers.get_mut(trigger_eid) {
+                drop_held_item(
+                    &mut commands,
+                    trigger_eid,
+                    &mut inventory,
+                    hotbar,
+                    position,
+                    rotation,
+                    event.status.0 == DROP_STACK,
+                );
+            }
+            continue;
+        }
+        if event.status.0 == RELEASE_USE_ITEM {
+            release_events.write(PlayerReleasedItem {
+                player: trigger_eid,
+            });
+            continue;
+        }
+
         let pos: BlockPos = event.location.clone().into();
         if abilities.creative_mode {
             // --- CREATIVE MODE LOGIC ---
@@ -107,8 +156,58 @@ pub fn handle(
                         sequence: event.sequence,
                     });
                 }
-                _ => {} // Other statuses (drop item, etc.) are handled by different packets
+                _ => {} // Other statuses (swapping hands, etc.) are handled by different packets
             }
         }
     }
 }
+
+/// Throws the held item, or the whole held stack, out in front of the player.
+fn drop_held_item(
+    commands: &mut Commands,
+    player: Entity,
+    inventory: &mut Inventory,
+    hotbar: &Hotbar,
+    position: &Position,
+    rotation: &Rotation,
+    whole_stack: bool,
+) {
+    let index = hotbar.get_selected_inventory_index();
+    let Ok(Some(held)) = inventory.get_item(index) else {
+        return;
+    };
+    let mut dropped = held.clone();
+    let mut left = held.clone();
+    let count = if whole_stack { held.count.0 } else { 1 };
+    dropped.count = VarInt::new(count);
+    left.count = VarInt::new(held.count.0 - count);
+
+    let res = if left.count.0 > 0 {
+        inventory.set_item_with_update(index, left, player)
+    } else {
+        inventory.clear_slot_with_update(index, player)
+    };
+    if let Err(err) = res {
+        error!("Failed to remove dropped item from {:?}: {:?}", player, err);
+        return;
+    }
+
+    // Same throw as vanilla: forwards along the look direction, with a little random spread
+    let mut rng = rand::thread_rng();
+    let (yaw, pitch) = (rotation.yaw.to_radians(), rotation.pitch.to_radians());
+    let spread_angle = rng.gen::<f32>() * TAU;
+    let spread = 0.02 * rng.gen::<f32>();
+    let velocity = Velocity::new(
+        (-yaw.sin() * pitch.cos() * 0.3 + spread_angle.cos() * spread) as f64,
+        (-pitch.sin() * 0.3 + 0.1 + (rng.gen::<f32>() - rng.gen::<f32>()) * 0.1) as f64,
+        (yaw.cos() * pitch.cos() * 0.3 + spread_angle.sin() * spread) as f64,
+    );
+    let spawn_at = Position::new(position.x, position.y + 1.62 - 0.3, position.z);
+    spawn_item(
+        commands,
+        spawn_at,
+        velocity,
+        dropped,
+        ItemEntity::THROWN_PICKUP_DELAY,
+    );
+}
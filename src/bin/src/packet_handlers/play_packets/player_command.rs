@@ -1,11 +1,14 @@
-use bevy_ecs::prelude::{Entity, Query, Res};
+use crate::systems::sleeping::{wake_up, Sleeping};
+use bevy_ecs::prelude::{Commands, Entity, Query, Res};
 use ferrumc_core::identity::player_identity::PlayerIdentity;
+use ferrumc_core::transform::position::Position;
 use ferrumc_net::broadcast::broadcast_packet_except;
 use ferrumc_net::connection::StreamWriter;
 use ferrumc_net::packets::incoming::player_command::PlayerCommandAction;
 use ferrumc_net::packets::outgoing::entity_metadata::{EntityMetadata, EntityMetadataPacket};
 use ferrumc_net::PlayerCommandPacketReceiver;
 use ferrumc_net_codec::net_types::var_int::VarInt;
+use ferrumc_state::GlobalStateResource;
 use tracing::log::trace;
 
 /// Handles PlayerCommand packets (sprinting, leave bed, etc.)
@@ -14,6 +17,10 @@ pub fn handle(
     receiver: Res<PlayerCommandPacketReceiver>,
     conn_query: Query<(Entity, &StreamWriter)>,
     identity_query: Query<&PlayerIdentity>,
+    sleeping_query: Query<&Sleeping>,
+    players: Query<(Entity, &StreamWriter, &Position)>,
+    state: Res<GlobalStateResource>,
+    mut commands: Commands,
 ) {
     for (event, eid) in receiver.0.try_iter() {
         // Get the sender's identity to use the correct entity ID
@@ -41,6 +48,18 @@ pub fn handle(
                     EntityMetadataPacket::new(entity_id, [EntityMetadata::entity_clear_state()]);
                 broadcast_packet_except(eid, &packet, conn_query.iter());
             }
+            PlayerCommandAction::LeaveBed => {
+                if let Ok(sleeping) = sleeping_query.get(eid) {
+                    wake_up(
+                        &mut commands,
+                        &state.0,
+                        eid,
+                        identity.short_uuid,
+                        sleeping,
+                        &players,
+                    );
+                }
+            }
             _ => {}
         }
     }
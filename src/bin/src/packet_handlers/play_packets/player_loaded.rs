@@ -1,9 +1,12 @@
 use bevy_ecs::prelude::{Entity, Query, Res};
 use ferrumc_core::transform::position::Position;
+use ferrumc_core::transform::rotation::Rotation;
+use ferrumc_core::world_metadata::WorldInfo;
 use ferrumc_macros::block;
 use ferrumc_net::connection::StreamWriter;
 use ferrumc_net::packets::outgoing::synchronize_player_position::SynchronizePlayerPositionPacket;
 use ferrumc_net::PlayerLoadedReceiver;
+use ferrumc_net_codec::net_types::var_int::VarInt;
 use ferrumc_state::GlobalStateResource;
 use ferrumc_world::block_state_id::BlockStateId;
 use ferrumc_world::pos::BlockPos;
@@ -12,6 +15,7 @@ use tracing::warn;
 pub fn handle(
     ev: Res<PlayerLoadedReceiver>,
     state: Res<GlobalStateResource>,
+    info: Res<WorldInfo>,
     query: Query<(Entity, &Position, &StreamWriter)>,
 ) {
     for (_, player) in ev.0.try_iter() {
@@ -50,8 +54,12 @@ pub fn handle(
                     player_pos.z,
                     head_block
                 );
-                // Teleport the player to the world center if their head block is not air
-                let packet = SynchronizePlayerPositionPacket::default();
+                // Teleport the player to the world spawn if their head block is not air
+                let packet = SynchronizePlayerPositionPacket::from_position_rotation(
+                    &info.spawn_position(),
+                    &Rotation::default(),
+                    VarInt::new(0),
+                );
                 if let Err(e) = conn.send_packet_ref(&packet) {
                     tracing::error!(
                         "Failed to send synchronize player position packet for player {}: {:?}",
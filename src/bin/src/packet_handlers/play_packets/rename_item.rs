@@ -0,0 +1,29 @@
+use crate::systems::containers::{refresh, send_refreshed, ContainerUserQuery};
+use bevy_ecs::prelude::{Query, Res};
+use ferrumc_components::player::gamemode::GameMode;
+use ferrumc_inventories::container::Menu;
+use ferrumc_inventories::enchanting::MAX_NAME_LENGTH;
+use ferrumc_net::RenameItemReceiver;
+
+/// Sets the name the player typed into an anvil.
+pub fn handle(receiver: Res<RenameItemReceiver>, mut players: Query<ContainerUserQuery>) {
+    for (event, eid) in receiver.0.try_iter() {
+        let Ok((writer, _, _, Some(mut container), _, seed, gamemode, _)) = players.get_mut(eid)
+        else {
+            continue;
+        };
+        if event.name.chars().count() > MAX_NAME_LENGTH {
+            continue;
+        }
+        let Menu::Anvil { name, .. } = &mut container.menu else {
+            continue;
+        };
+        let new_name = (!event.name.trim().is_empty()).then_some(event.name);
+        if *name == new_name {
+            continue;
+        }
+        *name = new_name;
+        refresh(&mut container, seed.0, gamemode.0 == GameMode::Creative);
+        send_refreshed(writer, &container, seed.0);
+    }
+}
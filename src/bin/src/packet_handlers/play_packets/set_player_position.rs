@@ -3,25 +3,50 @@ use bevy_ecs::prelude::{MessageWriter, Query, Res};
 use ferrumc_components::player::teleport_tracker::TeleportTracker;
 use ferrumc_core::transform::grounded::OnGround;
 use ferrumc_core::transform::position::Position;
+use ferrumc_core::transform::rotation::Rotation;
+use ferrumc_core::world_border::WorldBorders;
 use ferrumc_messages::chunk_calc::ChunkCalc;
+use ferrumc_messages::teleport_player::TeleportPlayer;
 use ferrumc_net::packets::packet_messages::Movement;
 use ferrumc_net::SetPlayerPositionPacketReceiver;
+use ferrumc_world::DEFAULT_DIMENSION;
 use tracing::trace;
 
 pub fn handle(
     receiver: Res<SetPlayerPositionPacketReceiver>,
-    mut query: Query<(&mut Position, &mut OnGround, &TeleportTracker)>,
+    mut query: Query<(&mut Position, &Rotation, &mut OnGround, &TeleportTracker)>,
     mut movement_messages: MessageWriter<Movement>,
     mut chunk_calc_messages: MessageWriter<ChunkCalc>,
+    mut teleport_messages: MessageWriter<TeleportPlayer>,
+    borders: Res<WorldBorders>,
 ) {
     for (event, eid) in receiver.0.try_iter() {
-        if let Ok((mut pos, mut ground, tracker)) = query.get_mut(eid) {
+        if let Ok((mut pos, rot, mut ground, tracker)) = query.get_mut(eid) {
             if tracker.waiting_for_confirm {
                 // Ignore position updates while waiting for teleport confirmation
                 continue;
             }
             let new_pos = Position::new(event.x, event.feet_y, event.z);
 
+            // Clients stop at the border on their own, so only modified ones get past it
+            if borders
+                .get(DEFAULT_DIMENSION)
+                .blocks_move((pos.x, pos.z), (new_pos.x, new_pos.z))
+            {
+                teleport_messages.write(TeleportPlayer {
+                    entity: eid,
+                    x: pos.x,
+                    y: pos.y,
+                    z: pos.z,
+                    vel_x: 0.0,
+                    vel_y: 0.0,
+                    vel_z: 0.0,
+                    yaw: rot.yaw,
+                    pitch: rot.pitch,
+                });
+                continue;
+            }
+
             // Check if chunk changed
             let old_chunk = (pos.x as i32 >> 4, pos.z as i32 >> 4);
             let new_chunk = (new_pos.x as i32 >> 4, new_pos.z as i32 >> 4);
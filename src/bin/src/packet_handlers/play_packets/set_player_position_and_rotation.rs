@@ -4,14 +4,19 @@ use ferrumc_components::player::teleport_tracker::TeleportTracker;
 use ferrumc_core::transform::grounded::OnGround;
 use ferrumc_core::transform::position::Position;
 use ferrumc_core::transform::rotation::Rotation;
+use ferrumc_core::world_border::WorldBorders;
 use ferrumc_messages::chunk_calc::ChunkCalc;
+use ferrumc_messages::teleport_player::TeleportPlayer;
 use ferrumc_net::packets::packet_messages::Movement;
 use ferrumc_net::SetPlayerPositionAndRotationPacketReceiver;
+use ferrumc_world::DEFAULT_DIMENSION;
 
 pub fn handle(
     receiver: Res<SetPlayerPositionAndRotationPacketReceiver>,
     mut movement_messages: MessageWriter<Movement>,
     mut chunk_calc_messages: MessageWriter<ChunkCalc>,
+    mut teleport_messages: MessageWriter<TeleportPlayer>,
+    borders: Res<WorldBorders>,
     mut query: Query<(
         &mut Position,
         &mut Rotation,
@@ -29,6 +34,25 @@ pub fn handle(
             let new_rot = Rotation::new(event.yaw, event.pitch);
             let on_ground = event.flags & 0x01 != 0;
 
+            // Clients stop at the border on their own, so only modified ones get past it
+            if borders
+                .get(DEFAULT_DIMENSION)
+                .blocks_move((pos.x, pos.z), (new_pos.x, new_pos.z))
+            {
+                teleport_messages.write(TeleportPlayer {
+                    entity: eid,
+                    x: pos.x,
+                    y: pos.y,
+                    z: pos.z,
+                    vel_x: 0.0,
+                    vel_y: 0.0,
+                    vel_z: 0.0,
+                    yaw: rot.yaw,
+                    pitch: rot.pitch,
+                });
+                continue;
+            }
+
             // Check if chunk changed
             let old_chunk = (pos.x as i32 >> 4, pos.z as i32 >> 4);
             let new_chunk = (new_pos.x as i32 >> 4, new_pos.z as i32 >> 4);
@@ -1,49 +1,102 @@
 use crate::packet_handlers::player::update_crafting::update_player_crafting_grid;
-use bevy_ecs::prelude::{Query, Res};
+use crate::systems::containers::{self, rebuild_item};
+use bevy_ecs::prelude::{MessageWriter, Query, Res};
+use ferrumc_components::player::experience::{EnchantmentSeed, Experience};
+use ferrumc_components::player::gamemode::{GameMode, GameModeComponent};
+use ferrumc_inventories::container::{CarriedItem, OpenContainer};
 use ferrumc_inventories::defined_slots;
 use ferrumc_inventories::inventory::Inventory;
-use ferrumc_inventories::item::ItemID;
 use ferrumc_inventories::slot::InventorySlot;
+use ferrumc_messages::PlayerGainedXP;
+use ferrumc_net::connection::StreamWriter;
+use ferrumc_net_codec::net_types::prefixed_optional::PrefixedOptional;
 use tracing::error;
 
+type ClickingPlayerQuery<'a> = (
+    &'a StreamWriter,
+    &'a mut Inventory,
+    &'a mut CarriedItem,
+    Option<&'a mut OpenContainer>,
+    &'a mut Experience,
+    &'a EnchantmentSeed,
+    &'a GameModeComponent,
+);
+
 pub fn handle(
     receiver: Res<ferrumc_net::ClickContainerReceiver>,
-    mut inventories: Query<&mut Inventory>,
+    mut players: Query<ClickingPlayerQuery>,
+    mut xp_events: MessageWriter<PlayerGainedXP>,
 ) {
     for (event, eid) in receiver.0.try_iter() {
+        let Ok((writer, mut inventory, mut carried, container, mut experience, seed, gamemode)) =
+            players.get_mut(eid)
+        else {
+            error!("Failed to get inventory for entity {eid}");
+            continue;
+        };
+
+        // Clicks in other windows, like an enchanting table's
+        if event.window_id.0 != 0 {
+            let open =
+                container.filter(|container| container.window_id as i32 == event.window_id.0);
+            if let Some(mut container) = open {
+                containers::click(
+                    &event,
+                    eid,
+                    writer,
+                    &mut container,
+                    &mut inventory,
+                    &mut carried,
+                    &mut experience,
+                    seed.0,
+                    gamemode.0 == GameMode::Creative,
+                    &mut xp_events,
+                );
+            }
+            continue;
+        }
+
         // TODO: actually verify that the inventory is synced, this code assumes that the ClickContainer packet is 100% truthful
-        // TODO: when actually implementing this correctly, make sure that if the client sends an out of bounds slot id the entire server doesnt crash
-
-        if let Ok(mut inventory) = inventories.get_mut(eid) {
-            for slot in event.changed_slots.data {
-                if let Some(new_data) = slot.data.to_option() {
-                    inventory
-                        .set_item(
-                            slot.number as _,
-                            InventorySlot {
-                                count: new_data.item_count,
-                                item_id: Some(ItemID(new_data.item_id)),
-                                components_to_add: None,
-                                components_to_remove: None,
-                                components_to_add_count: None,
-                                components_to_remove_count: None,
-                            },
-                        )
-                        .expect("failed to write to inventory");
-                } else {
-                    inventory
-                        .clear_slot_with_update(slot.number as _, eid)
-                        .expect("failed to clear item in inventory");
-                }
-
-                if (defined_slots::player::CRAFT_SLOT_1..=defined_slots::player::CRAFT_SLOT_4)
-                    .contains(&(slot.number as u8))
-                {
-                    update_player_crafting_grid(&mut inventory, eid);
-                }
+
+        // The items the click moved around, to get their components back from
+        let mut known = vec![carried.0.clone()];
+        for slot in &event.changed_slots.data {
+            if let Some(Some(item)) = inventory.slots.get(slot.number as usize) {
+                known.push(item.clone());
             }
-        } else {
-            error!("Failed to get inventory for entity {eid}");
         }
+
+        for slot in &event.changed_slots.data {
+            if slot.number < 0 || slot.number as usize >= inventory.slots.len() {
+                error!("Invalid slot {} clicked by {eid}", slot.number);
+                continue;
+            }
+            if let PrefixedOptional::Some(new_data) = &slot.data {
+                // The slot's own contents are the most likely to be the same item
+                let mut candidates: Vec<InventorySlot> = inventory.slots[slot.number as usize]
+                    .iter()
+                    .cloned()
+                    .collect();
+                candidates.extend(known.iter().cloned());
+                inventory
+                    .set_item(slot.number as _, rebuild_item(new_data, &candidates))
+                    .expect("failed to write to inventory");
+            } else {
+                inventory
+                    .clear_slot_with_update(slot.number as _, eid)
+                    .expect("failed to clear item in inventory");
+            }
+
+            if (defined_slots::player::CRAFT_SLOT_1..=defined_slots::player::CRAFT_SLOT_4)
+                .contains(&(slot.number as u8))
+            {
+                update_player_crafting_grid(&mut inventory, eid);
+            }
+        }
+
+        carried.0 = match &event.carried_item {
+            PrefixedOptional::Some(hashed) => rebuild_item(hashed, &known),
+            PrefixedOptional::None => InventorySlot::empty(),
+        };
     }
 }
@@ -0,0 +1,156 @@
+//! Players using the item in their hand while not looking at a block.
+//!
+//! Snowballs, eggs and ender pearls are thrown straight away. Bows and tridents are drawn back
+//! until the player lets go, which is handled by `projectiles::release_used_items`.
+
+use crate::systems::projectiles::{find_ammo, launch, launch_sound, UsingItem};
+use bevy_ecs::prelude::{Commands, Component, MessageWriter, Query, Res};
+use ferrumc_components::player::gamemode::{GameMode, GameModeComponent};
+use ferrumc_core::identity::player_identity::PlayerIdentity;
+use ferrumc_core::transform::position::Position;
+use ferrumc_core::transform::rotation::Rotation;
+use ferrumc_data::items::Item;
+use ferrumc_entities::components::{Projectile, ProjectileKind};
+use ferrumc_inventories::defined_slots::player;
+use ferrumc_inventories::hotbar::Hotbar;
+use ferrumc_inventories::inventory::Inventory;
+use ferrumc_messages::PlaySound;
+use ferrumc_net::connection::StreamWriter;
+use ferrumc_net::packets::incoming::use_item::Hand;
+use ferrumc_net::packets::outgoing::block_change_ack::BlockChangeAck;
+use ferrumc_net::packets::outgoing::set_cooldown::SetCooldownPacket;
+use ferrumc_net::UseItemReceiver;
+use ferrumc_net_codec::net_types::var_int::VarInt;
+use std::time::{Duration, Instant};
+use tracing::error;
+
+/// How fast thrown items fly, in blocks per tick.
+const THROW_SPEED: f32 = 1.5;
+
+/// Ticks a player has to wait between throwing ender pearls.
+const ENDER_PEARL_COOLDOWN: i32 = 20;
+
+/// When a player can throw their next ender pearl.
+#[derive(Component)]
+pub struct EnderPearlCooldown(pub Instant);
+
+type UserQuery<'a> = (
+    &'a PlayerIdentity,
+    &'a StreamWriter,
+    &'a Position,
+    &'a GameModeComponent,
+    &'a mut Inventory,
+    &'a Hotbar,
+    Option<&'a EnderPearlCooldown>,
+);
+
+pub fn handle(
+    receiver: Res<UseItemReceiver>,
+    mut users: Query<UserQuery>,
+    mut sounds: MessageWriter<PlaySound>,
+    mut commands: Commands,
+) {
+    for (event, eid) in receiver.0.try_iter() {
+        let Ok((identity, writer, position, gamemode, mut inventory, hotbar, cooldown)) =
+            users.get_mut(eid)
+        else {
+            continue;
+        };
+        if let Err(err) = writer.send_packet_ref(&BlockChangeAck {
+            sequence: event.sequence,
+        }) {
+            error!(
+                "Failed to acknowledge item use of {}: {:?}",
+                identity.username, err
+            );
+        }
+        if gamemode.0 == GameMode::Spectator {
+            continue;
+        }
+
+        let slot = match event.hand {
+            Hand::MainHand => hotbar.get_selected_inventory_index(),
+            Hand::OffHand => player::OFFHAND_SLOT as usize,
+        };
+        let Ok(Some(held)) = inventory.get_item(slot) else {
+            continue;
+        };
+        let held = held.clone();
+        let Some(item) = held.item() else {
+            continue;
+        };
+        let creative = gamemode.0 == GameMode::Creative;
+
+        if *item == Item::BOW || *item == Item::TRIDENT {
+            // Bows need arrows, and tridents that are about to break can't be thrown
+            let usable = if *item == Item::BOW {
+                creative || find_ammo(&inventory).is_some()
+            } else {
+                held.max_damage()
+                    .is_none_or(|max_damage| held.damage() < max_damage - 1)
+            };
+            if usable {
+                commands.entity(eid).insert(UsingItem {
+                    slot,
+                    item: held.item_id.expect("Held item has an item ID"),
+                    since: Instant::now(),
+                });
+            }
+            continue;
+        }
+
+        let kind = if *item == Item::SNOWBALL {
+            ProjectileKind::Snowball
+        } else if *item == Item::EGG {
+            ProjectileKind::Egg
+        } else if *item == Item::ENDER_PEARL {
+            ProjectileKind::EnderPearl
+        } else {
+            continue;
+        };
+
+        if kind == ProjectileKind::EnderPearl {
+            let now = Instant::now();
+            if cooldown.is_some_and(|cooldown| cooldown.0 > now) {
+                continue;
+            }
+            let ticks = Duration::from_millis(ENDER_PEARL_COOLDOWN as u64 * 50);
+            commands.entity(eid).insert(EnderPearlCooldown(now + ticks));
+            if let Err(err) = writer.send_packet_ref(&SetCooldownPacket::new(
+                Item::ENDER_PEARL.registry_key,
+                ENDER_PEARL_COOLDOWN,
+            )) {
+                error!(
+                    "Failed to send ender pearl cooldown to {}: {:?}",
+                    identity.username, err
+                );
+            }
+        }
+
+        if !creative {
+            let result = if held.count.0 > 1 {
+                let mut left = held.clone();
+                left.count = VarInt::new(held.count.0 - 1);
+                inventory.set_item_with_update(slot, left, eid)
+            } else {
+                inventory.clear_slot_with_update(slot, eid)
+            };
+            if let Err(err) = result {
+                error!("Failed to use item of {}: {:?}", identity.username, err);
+                continue;
+            }
+        }
+
+        // Thrown in the direction the player was looking when they used it
+        let rotation = Rotation::new(event.yaw, event.pitch);
+        sounds.write(launch_sound(kind, 0.0, position.coords));
+        launch(
+            &mut commands,
+            Projectile::new(kind, Some(eid)),
+            identity.short_uuid,
+            position,
+            &rotation,
+            THROW_SPEED,
+        );
+    }
+}
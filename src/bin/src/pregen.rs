@@ -0,0 +1,194 @@
+//! World pregeneration, generating and saving chunks ahead of time so players don't have to wait
+//! for them.
+//!
+//! Chunks are generated from the centre outwards. Progress is saved to `pregen.json` in the server
+//! root after every batch, so a paused or interrupted pregeneration can pick up where it left off,
+//! even after a restart.
+
+use crate::errors::BinaryError;
+use clap::ValueEnum;
+use ferrumc_general_purpose::paths::get_root_path;
+use ferrumc_state::GlobalState;
+use ferrumc_world::pos::ChunkPos;
+use ferrumc_world::DEFAULT_DIMENSION;
+use indicatif::ProgressBar;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tracing::error;
+
+/// The area around the centre to generate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "snake_case")]
+pub enum PregenShape {
+    Square,
+    Circle,
+}
+
+/// A pregeneration and how far along it is.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PregenTask {
+    /// The chunk to generate around.
+    pub center: (i32, i32),
+    /// How many chunks to generate in each direction.
+    pub radius: u32,
+    pub shape: PregenShape,
+    pub dimension: String,
+    /// How many of the chunks from [`PregenTask::positions`] are done.
+    pub done: u64,
+}
+
+impl PregenTask {
+    pub fn new(center: (i32, i32), radius: u32, shape: PregenShape) -> Self {
+        Self {
+            center,
+            radius,
+            shape,
+            dimension: DEFAULT_DIMENSION.to_string(),
+            done: 0,
+        }
+    }
+
+    /// Every chunk to generate, ring by ring from the centre outwards.
+    pub fn positions(&self) -> impl Iterator<Item = ChunkPos> + '_ {
+        let radius = self.radius as i32;
+        (0..=radius)
+            .flat_map(|ring| {
+                (-ring..=ring).flat_map(move |x| {
+                    (-ring..=ring)
+                        .filter(move |z| x.abs() == ring || z.abs() == ring)
+                        .map(move |z| (x, z))
+                })
+            })
+            .filter(move |(x, z)| match self.shape {
+                PregenShape::Square => true,
+                PregenShape::Circle => x * x + z * z <= radius * radius,
+            })
+            .map(move |(x, z)| ChunkPos::new(self.center.0 + x, self.center.1 + z))
+    }
+
+    /// How many chunks this pregeneration covers in total.
+    pub fn total(&self) -> u64 {
+        self.positions().count() as u64
+    }
+
+    fn path() -> PathBuf {
+        get_root_path().join("pregen.json")
+    }
+
+    /// Loads the saved pregeneration, if there is one.
+    pub fn load() -> Result<Option<Self>, BinaryError> {
+        let path = Self::path();
+        if !path.exists() {
+            return Ok(None);
+        }
+        let task = serde_json::from_str(&std::fs::read_to_string(path)?)
+            .map_err(|e| BinaryError::Custom(format!("Invalid pregen.json: {e}")))?;
+        Ok(Some(task))
+    }
+
+    pub fn save(&self) -> Result<(), BinaryError> {
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| BinaryError::Custom(format!("Could not serialize pregen task: {e}")))?;
+        std::fs::write(Self::path(), json)?;
+        Ok(())
+    }
+
+    /// Deletes the saved pregeneration.
+    pub fn clear() -> Result<(), BinaryError> {
+        let path = Self::path();
+        if path.exists() {
+            std::fs::remove_file(path)?;
+        }
+        Ok(())
+    }
+}
+
+/// Generates the chunks of `task` that don't exist yet, in parallel on the thread pool, until it
+/// is done or `stop` is set. Progress is saved after every batch.
+///
+/// `next_batch_size` is called before every batch and may block for a while to throttle the
+/// pregeneration.
+///
+/// Returns true if the task finished, in which case its saved progress is deleted.
+pub fn run(
+    state: &GlobalState,
+    task: &mut PregenTask,
+    next_batch_size: impl Fn() -> usize,
+    stop: &AtomicBool,
+    progress: &ProgressBar,
+) -> Result<bool, BinaryError> {
+    progress.set_length(task.total());
+    progress.set_position(task.done);
+
+    let plan = task.clone();
+    let mut positions = plan.positions().skip(task.done as usize);
+    loop {
+        if stop.load(Ordering::Relaxed) || state.shut_down.load(Ordering::Relaxed) {
+            task.save()?;
+            state.world.sync()?;
+            return Ok(false);
+        }
+
+        let chunks: Vec<ChunkPos> = positions.by_ref().take(next_batch_size().max(1)).collect();
+        if chunks.is_empty() {
+            PregenTask::clear()?;
+            state.world.sync()?;
+            return Ok(true);
+        }
+
+        let mut batch = state.thread_pool.batch();
+        for pos in chunks.iter().copied() {
+            let state = state.clone();
+            let dimension = task.dimension.clone();
+            batch.execute(move || -> Result<(), BinaryError> {
+                if !state.world.chunk_exists(pos, &dimension)? {
+                    let chunk = state.terrain_generator.generate_chunk(pos)?;
+                    state.world.insert_chunk(pos, &dimension, chunk)?;
+                }
+                Ok(())
+            });
+        }
+        for (pos, result) in chunks.iter().zip(batch.wait()) {
+            if let Err(e) = result {
+                error!("Error pregenerating chunk {}: {}", pos, e);
+            }
+        }
+
+        task.done += chunks.len() as u64;
+        progress.set_position(task.done);
+        task.save()?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_positions_start_at_center() {
+        let task = PregenTask::new((10, -4), 2, PregenShape::Square);
+        let positions: Vec<ChunkPos> = task.positions().collect();
+        assert_eq!(positions.len(), 25);
+        assert_eq!(positions[0], ChunkPos::new(10, -4));
+        // The first ring comes before the second one
+        assert!(positions[1..9]
+            .iter()
+            .all(|pos| (pos.x() - 10).abs() <= 1 && (pos.z() + 4).abs() <= 1));
+    }
+
+    #[test]
+    fn test_circle_skips_corners() {
+        let task = PregenTask::new((0, 0), 2, PregenShape::Circle);
+        assert_eq!(task.total(), 13);
+        assert!(!task.positions().any(|pos| pos == ChunkPos::new(2, 2)));
+    }
+
+    #[test]
+    fn test_task_roundtrip() {
+        let mut task = PregenTask::new((1, 2), 3, PregenShape::Circle);
+        task.done = 7;
+        let json = serde_json::to_string(&task).unwrap();
+        assert_eq!(serde_json::from_str::<PregenTask>(&json).unwrap(), task);
+    }
+}
@@ -5,11 +5,14 @@ use ferrumc_core::conn::force_player_recount_event::ForcePlayerRecount;
 use ferrumc_messages::chunk_calc::ChunkCalc;
 use ferrumc_messages::entity_update::SendEntityUpdate;
 use ferrumc_messages::particle::SendParticle;
+use ferrumc_messages::pregen::PregenCommand;
 use ferrumc_messages::teleport_player::TeleportPlayer;
 use ferrumc_messages::{
-    BlockBrokenEvent, PlayerCancelledDigging, PlayerDamaged, PlayerDied, PlayerEating,
-    PlayerFinishedDigging, PlayerGainedXP, PlayerGameModeChanged, PlayerJoined, PlayerLeft,
-    PlayerLeveledUp, PlayerStartedDigging, SpawnEntityCommand, SpawnEntityEvent,
+    AddEffect, BlockBrokenEvent, BlockPlacedEvent, EntityDamaged, Explosion, PlaySound,
+    PlayerCancelledDigging, PlayerDamaged, PlayerDied, PlayerEating, PlayerFinishedDigging,
+    PlayerGainedXP, PlayerGameModeChanged, PlayerJoined, PlayerLeft, PlayerLeveledUp,
+    PlayerReleasedItem, PlayerStartedDigging, PlayerUsedBed, PlayerUsedContainer, PrimeTnt,
+    RemoveEffect, SendTitle, SpawnEntityCommand, SpawnEntityEvent,
 };
 use ferrumc_net::packets::packet_messages::Movement;
 
@@ -24,17 +27,29 @@ pub fn register_messages(world: &mut World) {
     MessageRegistry::register_message::<PlayerJoined>(world);
     MessageRegistry::register_message::<PlayerDamaged>(world);
     MessageRegistry::register_message::<PlayerDied>(world);
+    MessageRegistry::register_message::<EntityDamaged>(world);
     MessageRegistry::register_message::<PlayerStartedDigging>(world);
     MessageRegistry::register_message::<PlayerCancelledDigging>(world);
     MessageRegistry::register_message::<PlayerFinishedDigging>(world);
     MessageRegistry::register_message::<PlayerEating>(world);
     MessageRegistry::register_message::<PlayerGainedXP>(world);
     MessageRegistry::register_message::<PlayerLeveledUp>(world);
+    MessageRegistry::register_message::<PlayerReleasedItem>(world);
     MessageRegistry::register_message::<PlayerGameModeChanged>(world);
     MessageRegistry::register_message::<SpawnEntityCommand>(world);
     MessageRegistry::register_message::<SpawnEntityEvent>(world);
     MessageRegistry::register_message::<SendEntityUpdate>(world);
     MessageRegistry::register_message::<SendParticle>(world);
+    MessageRegistry::register_message::<PlaySound>(world);
+    MessageRegistry::register_message::<SendTitle>(world);
     MessageRegistry::register_message::<BlockBrokenEvent>(world);
+    MessageRegistry::register_message::<BlockPlacedEvent>(world);
+    MessageRegistry::register_message::<Explosion>(world);
+    MessageRegistry::register_message::<PrimeTnt>(world);
     MessageRegistry::register_message::<TeleportPlayer>(world);
+    MessageRegistry::register_message::<PlayerUsedBed>(world);
+    MessageRegistry::register_message::<PlayerUsedContainer>(world);
+    MessageRegistry::register_message::<PregenCommand>(world);
+    MessageRegistry::register_message::<AddEffect>(world);
+    MessageRegistry::register_message::<RemoveEffect>(world);
 }
@@ -1,25 +1,63 @@
+use crate::console::ConsoleCommandRecv;
+use crate::errors::BinaryError;
+use crate::launch::{load_scoreboard, load_world_metadata};
+use crate::systems::block_ticks::BlockBehaviours;
+use crate::systems::chunk_pipeline::ChunkPipeline;
 use crate::systems::new_connections::NewConnectionRecv;
+use crate::systems::pregen::PregenRunner;
+use crate::systems::rcon::PendingRconResponses;
+use crate::systems::redstone::RedstoneEngine;
 use bevy_ecs::prelude::World;
 use crossbeam_channel::Receiver;
 use ferrumc_config::server_config::get_global_config;
 use ferrumc_core::chunks::world_sync_tracker::WorldSyncTracker;
+use ferrumc_core::scoreboard::Scoreboard;
 use ferrumc_core::time::WorldTime;
+use ferrumc_core::world_border::{WorldBorder, WorldBorders};
 use ferrumc_entities::components::PhysicalRegistry;
 use ferrumc_net::connection::NewConnection;
+use ferrumc_net::rcon::{RconCommand, RconCommandReceiver};
 use ferrumc_performance::ServerPerformance;
 use ferrumc_state::GlobalStateResource;
 
 pub fn register_resources(
     world: &mut World,
     new_conn_recv: Receiver<NewConnection>,
+    console_recv: ConsoleCommandRecv,
+    rcon_recv: Receiver<RconCommand>,
     global_state: GlobalStateResource,
-) {
+) -> Result<(), BinaryError> {
     world.insert_resource(NewConnectionRecv(new_conn_recv));
+    world.insert_resource(console_recv);
+    world.insert_resource(RconCommandReceiver(rcon_recv));
+    world.insert_resource(PendingRconResponses::default());
+    let metadata = load_world_metadata(&global_state.0.world)?;
+    world.insert_resource(WorldTime::new(metadata.day_time, metadata.world_age));
+    world.insert_resource(metadata.weather);
+    world.insert_resource(metadata.game_rules);
+    world.insert_resource(global_state.0.info);
+    world.insert_resource(Scoreboard::new(load_scoreboard(&global_state.0.world)?));
     world.insert_resource(global_state);
     world.insert_resource(WorldSyncTracker {
         last_synced: std::time::Instant::now(),
     });
-    world.insert_resource(WorldTime::default());
+    world.insert_resource(world_borders());
     world.insert_resource(ServerPerformance::new(get_global_config().tps));
     world.insert_resource(PhysicalRegistry::new());
+    world.insert_resource(BlockBehaviours::default());
+    world.insert_resource(RedstoneEngine::default());
+    world.insert_resource(ChunkPipeline::default());
+    world.insert_resource(PregenRunner::default());
+    Ok(())
+}
+
+/// The border every dimension starts with, from the config.
+fn world_borders() -> WorldBorders {
+    let config = &get_global_config().world_border;
+    let mut border = WorldBorder::new(config.center_x, config.center_z, config.diameter);
+    border.damage_per_block = config.damage_per_block;
+    border.safe_zone = config.safe_zone;
+    border.warning_blocks = config.warning_blocks;
+    border.warning_time = config.warning_time;
+    WorldBorders::new(border)
 }
@@ -0,0 +1,271 @@
+//! Attributes.
+//!
+//! Everything that changes a player's attributes adds modifiers to their `Attributes`, and
+//! whenever those change they're sent to every player. Equipment is handled here: armor, the
+//! held items and their enchantments give modifiers while they're in the right slot.
+
+use bevy_ecs::prelude::{Changed, Entity, Or, Query, With};
+use ferrumc_components::attributes::{
+    AttributeModifier, Attributes, EquipmentModifiers, ModifierOperation,
+};
+use ferrumc_components::health::{Absorption, Health};
+use ferrumc_components::player::hunger::Hunger;
+use ferrumc_core::identity::player_identity::PlayerIdentity;
+use ferrumc_data::generated::attributes::Attribute;
+use ferrumc_data::generated::enchantments::{Enchantment, EnchantmentSlot};
+use ferrumc_data::generated::items::{AttributeModifierSlot, DataComponent};
+use ferrumc_inventories::components::ItemComponent;
+use ferrumc_inventories::defined_slots::player;
+use ferrumc_inventories::hotbar::Hotbar;
+use ferrumc_inventories::inventory::Inventory;
+use ferrumc_inventories::slot::InventorySlot;
+use ferrumc_net::broadcast::broadcast_packet_all;
+use ferrumc_net::connection::StreamWriter;
+use ferrumc_net::packets::outgoing::set_health::SetHealth;
+use ferrumc_net::packets::outgoing::update_attributes::UpdateAttributesPacket;
+use tracing::error;
+
+/// The slots equipment gives modifiers from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EquipmentSlot {
+    MainHand,
+    OffHand,
+    Head,
+    Chest,
+    Legs,
+    Feet,
+}
+
+impl EquipmentSlot {
+    const ALL: [EquipmentSlot; 6] = [
+        Self::MainHand,
+        Self::OffHand,
+        Self::Head,
+        Self::Chest,
+        Self::Legs,
+        Self::Feet,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::MainHand => "mainhand",
+            Self::OffHand => "offhand",
+            Self::Head => "head",
+            Self::Chest => "chest",
+            Self::Legs => "legs",
+            Self::Feet => "feet",
+        }
+    }
+
+    fn inventory_index(self, hotbar: &Hotbar) -> usize {
+        match self {
+            Self::MainHand => hotbar.get_selected_inventory_index(),
+            Self::OffHand => player::OFFHAND_SLOT as usize,
+            Self::Head => player::HEAD_SLOT as usize,
+            Self::Chest => player::CHEST_SLOT as usize,
+            Self::Legs => player::LEGS_SLOT as usize,
+            Self::Feet => player::FEET_SLOT as usize,
+        }
+    }
+
+    fn is_hand(self) -> bool {
+        matches!(self, Self::MainHand | Self::OffHand)
+    }
+
+    /// Whether the slot is part of a slot group, by the name items use for it.
+    fn in_group(self, group: &str) -> bool {
+        match group {
+            "any" => true,
+            "hand" => self.is_hand(),
+            "armor" => !self.is_hand(),
+            group => group == self.name(),
+        }
+    }
+
+    /// Whether the slot is part of a slot group, by its protocol ID.
+    fn in_group_id(self, id: i32) -> bool {
+        let group = match id {
+            0 => "any",
+            1 => "mainhand",
+            2 => "offhand",
+            3 => "hand",
+            4 => "feet",
+            5 => "legs",
+            6 => "chest",
+            7 => "head",
+            8 => "armor",
+            _ => return false,
+        };
+        self.in_group(group)
+    }
+
+    fn in_enchantment_slot(self, slot: EnchantmentSlot) -> bool {
+        let group = match slot {
+            EnchantmentSlot::MAINHAND => "mainhand",
+            EnchantmentSlot::OFFHAND => "offhand",
+            EnchantmentSlot::HEAD => "head",
+            EnchantmentSlot::CHEST => "chest",
+            EnchantmentSlot::LEGS => "legs",
+            EnchantmentSlot::FEET => "feet",
+            EnchantmentSlot::ARMOR => "armor",
+            EnchantmentSlot::ANY => "any",
+            EnchantmentSlot::HAND => "hand",
+        };
+        self.in_group(group)
+    }
+}
+
+/// The modifiers an item gives while it's in the given slot.
+fn item_modifiers(
+    stack: &InventorySlot,
+    slot: EquipmentSlot,
+) -> Vec<(&'static Attribute, AttributeModifier)> {
+    let Some(item) = stack.item() else {
+        return Vec::new();
+    };
+    // Broken items stop working until they're repaired
+    if let Some(max_damage) = stack.max_damage() {
+        if !stack.is_unbreakable() && stack.damage() >= max_damage {
+            return Vec::new();
+        }
+    }
+
+    let mut modifiers = Vec::new();
+    if let Some(ItemComponent::AttributeModifiers(custom)) =
+        stack.component(DataComponent::AttributeModifiers)
+    {
+        for modifier in custom {
+            if !slot.in_group_id(modifier.slot.0) {
+                continue;
+            }
+            let attribute = u16::try_from(modifier.attribute.0)
+                .ok()
+                .and_then(Attribute::from_id);
+            let operation = ModifierOperation::from_id(modifier.operation.0);
+            if let (Some(attribute), Some(operation)) = (attribute, operation) {
+                modifiers.push((
+                    attribute,
+                    AttributeModifier::new(modifier.id.clone(), modifier.amount, operation),
+                ));
+            }
+        }
+    } else if !stack
+        .components_to_remove
+        .iter()
+        .any(|id| id.0 == DataComponent::AttributeModifiers.id())
+    {
+        for modifier in item.attribute_modifiers() {
+            let applies = match modifier.slot {
+                AttributeModifierSlot::String(group) => slot.in_group(group),
+                AttributeModifierSlot::Any => true,
+            };
+            if applies {
+                modifiers.push((
+                    modifier.r#type,
+                    AttributeModifier::new(modifier.id, modifier.amount, modifier.operation.into()),
+                ));
+            }
+        }
+    }
+
+    for enchantment in stack.enchantments() {
+        let Some(data) = u16::try_from(enchantment.id.0)
+            .ok()
+            .and_then(Enchantment::from_id)
+        else {
+            continue;
+        };
+        if !data
+            .slots
+            .iter()
+            .any(|enchantment_slot| slot.in_enchantment_slot(*enchantment_slot))
+        {
+            continue;
+        }
+        let level = enchantment.level.0.clamp(1, u8::MAX as i32) as u8;
+        for effect in data.attribute_effects {
+            modifiers.push((
+                effect.attribute,
+                AttributeModifier::new(
+                    format!("{}/{}", effect.id, slot.name()),
+                    effect.amount.calculate(level) as f64,
+                    effect.operation.into(),
+                ),
+            ));
+        }
+    }
+    modifiers
+}
+
+/// Swaps out the modifiers from equipment when a player's inventory or held item changes.
+pub fn update_equipment_modifiers(
+    mut players: Query<
+        (
+            &Inventory,
+            &Hotbar,
+            &mut Attributes,
+            &mut EquipmentModifiers,
+        ),
+        Or<(Changed<Inventory>, Changed<Hotbar>)>,
+    >,
+) {
+    for (inventory, hotbar, mut attributes, mut equipment) in players.iter_mut() {
+        let mut modifiers = Vec::new();
+        for slot in EquipmentSlot::ALL {
+            if let Ok(Some(stack)) = inventory.get_item(slot.inventory_index(hotbar)) {
+                modifiers.extend(item_modifiers(stack, slot));
+            }
+        }
+        if modifiers == equipment.applied {
+            continue;
+        }
+
+        for (attribute, modifier) in &equipment.applied {
+            attributes.remove_modifier(attribute, &modifier.id);
+        }
+        for (attribute, modifier) in &modifiers {
+            attributes.add_modifier(attribute, modifier.clone());
+        }
+        equipment.applied = modifiers;
+    }
+}
+
+/// Sends changed attributes to every player, and keeps health and absorption within their
+/// maximums.
+pub fn sync_attributes(
+    mut players: Query<
+        (
+            &PlayerIdentity,
+            &Attributes,
+            &mut Health,
+            &mut Absorption,
+            &Hunger,
+            &StreamWriter,
+        ),
+        Changed<Attributes>,
+    >,
+    writers: Query<(Entity, &StreamWriter), With<PlayerIdentity>>,
+) {
+    for (identity, attributes, mut health, mut absorption, hunger, writer) in players.iter_mut() {
+        let packet = UpdateAttributesPacket::from_attributes(identity.short_uuid, attributes);
+        broadcast_packet_all(&packet, writers.iter());
+
+        let max_absorption = attributes.value(&Attribute::MAX_ABSORPTION) as f32;
+        if absorption.amount > max_absorption {
+            absorption.amount = max_absorption;
+        }
+
+        let max_health = attributes.value(&Attribute::MAX_HEALTH) as f32;
+        if health.max == max_health {
+            continue;
+        }
+        health.max = max_health;
+        if health.current > max_health {
+            health.current = max_health;
+            let packet = SetHealth::new(health.current, hunger.level as i32, hunger.saturation);
+            if let Err(err) = writer.send_packet_ref(&packet) {
+                error!("Failed to send health to {}: {:?}", identity.username, err);
+            }
+        }
+    }
+}
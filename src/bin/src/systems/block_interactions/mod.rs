@@ -0,0 +1,413 @@
+//! Right-clicking blocks.
+//!
+//! When a player right-clicks a block, [`use_block`] gets the first chance to react to it (opening
+//! a door, flipping a lever, ...). Only if the block doesn't do anything is the held item placed,
+//! with its orientation worked out by [`placement::placement_states`].
+
+pub mod placement;
+
+use bevy_ecs::prelude::Entity;
+use bevy_math::DVec3;
+use ferrumc_core::sound::SoundCategory;
+use ferrumc_data::generated::sounds::Sound;
+use ferrumc_inventories::container::Menu;
+use ferrumc_messages::PlaySound;
+use ferrumc_world::block_state_id::BlockStateId;
+use ferrumc_world::pos::{BlockPos, Direction};
+use ferrumc_world::World;
+use ferrumc_world::DEFAULT_DIMENSION;
+use rand::Rng;
+
+/// What happened when a player right-clicked a block.
+pub enum BlockUse {
+    /// The block doesn't react to being used, so the held item should be placed instead.
+    Pass,
+    /// The block reacted and these blocks need to be changed. Can be empty, e.g. when pressing a
+    /// button that's already pressed.
+    Used(Vec<(BlockPos, BlockStateId)>),
+    /// The player wants to sleep in the bed with these halves.
+    Sleep { foot: BlockPos, head: BlockPos },
+    /// The block has a window the player should see, like an enchanting table.
+    OpenContainer(Menu),
+}
+
+/// Works out how the block at `pos` reacts to being right-clicked by a player looking in the
+/// direction given by `yaw`. Doesn't change anything, the caller applies the result.
+pub fn use_block(world: &World, pos: BlockPos, block: BlockStateId, yaw: f32) -> BlockUse {
+    let Some(name) = block
+        .name()
+        .and_then(|name| name.strip_prefix("minecraft:"))
+    else {
+        return BlockUse::Pass;
+    };
+
+    // Iron doors and trapdoors can only be opened with redstone
+    if name.ends_with("_door") && name != "iron_door" {
+        toggle_door(world, pos, block)
+    } else if name.ends_with("_trapdoor") && name != "iron_trapdoor" {
+        toggle(pos, block, "open")
+    } else if name.ends_with("_fence_gate") {
+        toggle_fence_gate(pos, block, yaw)
+    } else if name == "lever" {
+        toggle(pos, block, "powered")
+    } else if name.ends_with("_button") {
+        press_button(pos, block)
+    } else if name.ends_with("_bed") {
+        bed_halves(pos, block)
+    } else if name == "note_block" {
+        tune_note_block(pos, block)
+    } else if let Some(menu) = Menu::for_block(name) {
+        BlockUse::OpenContainer(menu)
+    } else {
+        BlockUse::Pass
+    }
+}
+
+/// Flips a boolean property.
+fn toggle(pos: BlockPos, block: BlockStateId, key: &str) -> BlockUse {
+    let value = block.property(key) == Some("true");
+    match block.with_property(key, if value { "false" } else { "true" }) {
+        Some(toggled) => BlockUse::Used(vec![(pos, toggled)]),
+        None => BlockUse::Pass,
+    }
+}
+
+/// Opens or closes both halves of a door.
+fn toggle_door(world: &World, pos: BlockPos, block: BlockStateId) -> BlockUse {
+    let BlockUse::Used(mut changes) = toggle(pos, block, "open") else {
+        return BlockUse::Pass;
+    };
+
+    let other_half = if block.property("half") == Some("lower") {
+        pos + Direction::Up
+    } else {
+        pos + Direction::Down
+    };
+    if let Some(other) = world.get_loaded_block(other_half, DEFAULT_DIMENSION) {
+        if other.name() == block.name() {
+            if let BlockUse::Used(other_changes) = toggle(other_half, other, "open") {
+                changes.extend(other_changes);
+            }
+        }
+    }
+    BlockUse::Used(changes)
+}
+
+/// Opens or closes a fence gate. Gates always open away from the player.
+fn toggle_fence_gate(pos: BlockPos, block: BlockStateId, yaw: f32) -> BlockUse {
+    if block.property("open") == Some("true") {
+        return toggle(pos, block, "open");
+    }
+
+    let looking = Direction::from_yaw(yaw);
+    let mut opened = block;
+    if block.property("facing") == Some(looking.opposite().as_str()) {
+        opened = opened
+            .with_property("facing", looking.as_str())
+            .unwrap_or(opened);
+    }
+    match opened.with_property("open", "true") {
+        Some(opened) => BlockUse::Used(vec![(pos, opened)]),
+        None => BlockUse::Pass,
+    }
+}
+
+/// Presses a button. The redstone engine releases it again after a delay.
+fn press_button(pos: BlockPos, block: BlockStateId) -> BlockUse {
+    if block.property("powered") == Some("true") {
+        return BlockUse::Used(Vec::new());
+    }
+    toggle(pos, block, "powered")
+}
+
+fn bed_halves(pos: BlockPos, block: BlockStateId) -> BlockUse {
+    let Some(facing) = block.property("facing").and_then(Direction::from_name) else {
+        return BlockUse::Pass;
+    };
+    // Beds face from the foot toward the head
+    if block.property("part") == Some("head") {
+        BlockUse::Sleep {
+            foot: pos + facing.opposite(),
+            head: pos,
+        }
+    } else {
+        BlockUse::Sleep {
+            foot: pos,
+            head: pos + facing,
+        }
+    }
+}
+
+/// Raises the note block's pitch by a semitone, wrapping around after two octaves.
+fn tune_note_block(pos: BlockPos, block: BlockStateId) -> BlockUse {
+    let note: u8 = block
+        .property("note")
+        .and_then(|note| note.parse().ok())
+        .unwrap_or(0);
+    match block.with_property("note", &((note + 1) % 25).to_string()) {
+        Some(tuned) => BlockUse::Used(vec![(pos, tuned)]),
+        None => BlockUse::Pass,
+    }
+}
+
+/// The sound a block makes when `player` uses it and it turns into `used`. Doors, trapdoors, gates
+/// and buttons aren't played back to the player, whose client already plays them.
+pub fn use_sound(
+    pos: BlockPos,
+    block: BlockStateId,
+    used: BlockStateId,
+    player: Entity,
+) -> Option<PlaySound> {
+    let name = block.name()?.strip_prefix("minecraft:")?;
+    let center = pos.pos.as_dvec3() + DVec3::splat(0.5);
+    let open = if used.property("open") == Some("true") {
+        "open"
+    } else {
+        "close"
+    };
+    let set = wood_sound_set(name);
+    let sound_name = if name.ends_with("_trapdoor") {
+        format!("block.{set}_trapdoor.{open}")
+    } else if name.ends_with("_door") {
+        format!("block.{set}_door.{open}")
+    } else if name.ends_with("_fence_gate") && set == "wooden" {
+        format!("block.fence_gate.{open}")
+    } else if name.ends_with("_fence_gate") {
+        format!("block.{set}_fence_gate.{open}")
+    } else if name.ends_with("_button") && name.contains("stone") {
+        "block.stone_button.click_on".to_string()
+    } else if name.ends_with("_button") {
+        format!("block.{set}_button.click_on")
+    } else if name == "lever" {
+        // Levers click higher when they're turned on
+        let pitch = if used.property("powered") == Some("true") {
+            0.6
+        } else {
+            0.5
+        };
+        let sound = PlaySound::at(Sound::BlockLeverClick, SoundCategory::Blocks, center);
+        return Some(sound.with_volume(0.3).with_pitch(pitch));
+    } else if name == "note_block" {
+        return note_block_sound(center, used);
+    } else {
+        return None;
+    };
+
+    let sound = Sound::from_name(&sound_name)?;
+    let pitch = if name.ends_with("_button") {
+        1.0
+    } else {
+        rand::thread_rng().gen_range(0.9..1.0)
+    };
+    Some(
+        PlaySound::at(sound, SoundCategory::Blocks, center)
+            .with_pitch(pitch)
+            .except(player),
+    )
+}
+
+/// Which set of door, trapdoor, gate and button sounds a block uses.
+fn wood_sound_set(name: &str) -> &'static str {
+    if name.starts_with("cherry") {
+        "cherry_wood"
+    } else if name.starts_with("bamboo") {
+        "bamboo_wood"
+    } else if name.starts_with("crimson") || name.starts_with("warped") {
+        "nether_wood"
+    } else if name.contains("copper") {
+        "copper"
+    } else if name.starts_with("iron") {
+        "iron"
+    } else {
+        "wooden"
+    }
+}
+
+/// Plays the note a note block is tuned to, two octaves from F#3 to F#5.
+fn note_block_sound(center: DVec3, block: BlockStateId) -> Option<PlaySound> {
+    let instrument = block.property("instrument")?;
+    let note: i32 = block.property("note")?.parse().ok()?;
+    let sound = Sound::from_name(&format!("block.note_block.{instrument}"))?;
+    let pitch = 2f32.powf((note - 12) as f32 / 12.0);
+    Some(
+        PlaySound::at(sound, SoundCategory::Records, center)
+            .with_volume(3.0)
+            .with_pitch(pitch),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ferrumc_macros::block;
+    use ferrumc_state::{create_test_state, GlobalStateResource};
+    use ferrumc_world::pos::ChunkPos;
+
+    fn pos() -> BlockPos {
+        BlockPos::of(8, 64, 8)
+    }
+
+    fn create_empty_chunk(state: &GlobalStateResource) {
+        ferrumc_utils::world::load_or_generate_mut(
+            &state.0,
+            ChunkPos::new(0, 0),
+            DEFAULT_DIMENSION,
+        )
+        .expect("Failed to load or generate chunk")
+        .fill(block!("air"));
+    }
+
+    fn used(result: BlockUse) -> Vec<(BlockPos, BlockStateId)> {
+        match result {
+            BlockUse::Used(changes) => changes,
+            _ => panic!("block wasn't used"),
+        }
+    }
+
+    #[test]
+    fn test_toggles() {
+        let (state, _temp_dir) = create_test_state();
+        create_empty_chunk(&state);
+
+        let cases = [
+            (
+                "lever on",
+                block!("lever", { face: "wall", facing: "north", powered: false }),
+                block!("lever", { face: "wall", facing: "north", powered: true }),
+            ),
+            (
+                "lever off",
+                block!("lever", { face: "wall", facing: "north", powered: true }),
+                block!("lever", { face: "wall", facing: "north", powered: false }),
+            ),
+            (
+                "trapdoor",
+                block!("oak_trapdoor", { facing: "north", half: "bottom", open: false, powered: false, waterlogged: false }),
+                block!("oak_trapdoor", { facing: "north", half: "bottom", open: true, powered: false, waterlogged: false }),
+            ),
+            (
+                "button",
+                block!("oak_button", { face: "wall", facing: "east", powered: false }),
+                block!("oak_button", { face: "wall", facing: "east", powered: true }),
+            ),
+            (
+                "note block",
+                block!("note_block", { instrument: "harp", note: 3, powered: false }),
+                block!("note_block", { instrument: "harp", note: 4, powered: false }),
+            ),
+            (
+                "note block wrapping around",
+                block!("note_block", { instrument: "harp", note: 24, powered: false }),
+                block!("note_block", { instrument: "harp", note: 0, powered: false }),
+            ),
+        ];
+        for (name, block, expected) in cases {
+            assert_eq!(
+                used(use_block(&state.0.world, pos(), block, 0.0)),
+                vec![(pos(), expected)],
+                "{name}"
+            );
+        }
+
+        // Already pressed buttons don't change
+        let pressed = block!("stone_button", { face: "wall", facing: "east", powered: true });
+        assert!(used(use_block(&state.0.world, pos(), pressed, 0.0)).is_empty());
+
+        // Iron doors and trapdoors need redstone
+        let iron_door = block!("iron_door", { facing: "north", half: "lower", hinge: "left", open: false, powered: false });
+        let iron_trapdoor = block!("iron_trapdoor", { facing: "north", half: "bottom", open: false, powered: false, waterlogged: false });
+        for block in [iron_door, iron_trapdoor] {
+            assert!(matches!(
+                use_block(&state.0.world, pos(), block, 0.0),
+                BlockUse::Pass
+            ));
+        }
+    }
+
+    #[test]
+    fn test_fence_gates() {
+        let (state, _temp_dir) = create_test_state();
+        create_empty_chunk(&state);
+        let closed = block!("oak_fence_gate", { facing: "north", in_wall: false, open: false, powered: false });
+
+        let cases = [
+            // Looking the same way as the gate
+            (
+                180.0,
+                block!("oak_fence_gate", { facing: "north", in_wall: false, open: true, powered: false }),
+            ),
+            // Looking at the front of the gate, so it swings around to open away
+            (
+                0.0,
+                block!("oak_fence_gate", { facing: "south", in_wall: false, open: true, powered: false }),
+            ),
+            // Looking at it from the side
+            (
+                90.0,
+                block!("oak_fence_gate", { facing: "north", in_wall: false, open: true, powered: false }),
+            ),
+        ];
+        for (yaw, expected) in cases {
+            assert_eq!(
+                used(use_block(&state.0.world, pos(), closed, yaw)),
+                vec![(pos(), expected)],
+                "yaw {yaw}"
+            );
+        }
+
+        // Closing keeps the direction it opened in
+        let open = block!("oak_fence_gate", { facing: "south", in_wall: false, open: true, powered: false });
+        let closed_south = block!("oak_fence_gate", { facing: "south", in_wall: false, open: false, powered: false });
+        assert_eq!(
+            used(use_block(&state.0.world, pos(), open, 180.0)),
+            vec![(pos(), closed_south)]
+        );
+    }
+
+    #[test]
+    fn test_doors() {
+        let (state, _temp_dir) = create_test_state();
+        create_empty_chunk(&state);
+        let world = &state.0.world;
+        let lower = block!("oak_door", { facing: "north", half: "lower", hinge: "left", open: false, powered: false });
+        let upper = block!("oak_door", { facing: "north", half: "upper", hinge: "left", open: false, powered: false });
+        let open_lower = block!("oak_door", { facing: "north", half: "lower", hinge: "left", open: true, powered: false });
+        let open_upper = block!("oak_door", { facing: "north", half: "upper", hinge: "left", open: true, powered: false });
+        world.set_loaded_block(pos(), DEFAULT_DIMENSION, lower);
+        world.set_loaded_block(pos() + Direction::Up, DEFAULT_DIMENSION, upper);
+
+        // Either half opens both
+        assert_eq!(
+            used(use_block(world, pos(), lower, 0.0)),
+            vec![(pos(), open_lower), (pos() + Direction::Up, open_upper)]
+        );
+        assert_eq!(
+            used(use_block(world, pos() + Direction::Up, upper, 0.0)),
+            vec![(pos() + Direction::Up, open_upper), (pos(), open_lower)]
+        );
+
+        // A lone half still opens
+        world.set_loaded_block(pos() + Direction::Up, DEFAULT_DIMENSION, block!("air"));
+        assert_eq!(
+            used(use_block(world, pos(), lower, 0.0)),
+            vec![(pos(), open_lower)]
+        );
+    }
+
+    #[test]
+    fn test_bed_halves() {
+        let (state, _temp_dir) = create_test_state();
+        create_empty_chunk(&state);
+        let foot = block!("red_bed", { facing: "east", occupied: false, part: "foot" });
+        let head = block!("red_bed", { facing: "east", occupied: false, part: "head" });
+
+        for (clicked, block) in [(pos(), foot), (pos() + Direction::East, head)] {
+            let BlockUse::Sleep { foot, head } = use_block(&state.0.world, clicked, block, 0.0)
+            else {
+                panic!("bed wasn't slept in");
+            };
+            assert_eq!(foot, pos());
+            assert_eq!(head, pos() + Direction::East);
+        }
+    }
+}
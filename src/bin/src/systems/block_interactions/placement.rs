@@ -0,0 +1,610 @@
+//! Orienting blocks as they're placed.
+//!
+//! Items map to a block's default state, which is then adjusted based on the face the player
+//! clicked, where on that face they clicked, and which way they're looking. Blocks made of two
+//! parts (doors, beds, tall plants) get their second part added here too.
+
+use ferrumc_macros::match_block;
+use ferrumc_world::block_state_id::BlockStateId;
+use ferrumc_world::pos::{BlockPos, Direction};
+use ferrumc_world::vanilla_chunk_format::BlockData;
+use ferrumc_world::World;
+use ferrumc_world::DEFAULT_DIMENSION;
+
+/// Where and how the player clicked to place a block.
+pub struct PlacementContext {
+    /// The block that was clicked, not the one being placed.
+    pub clicked: BlockPos,
+    /// The face of the clicked block.
+    pub face: Direction,
+    /// Where on the clicked block the player clicked, from 0 to 1 on each axis.
+    pub cursor: (f32, f32, f32),
+    pub yaw: f32,
+    pub pitch: f32,
+}
+
+impl PlacementContext {
+    /// The horizontal direction the player is looking in.
+    fn horizontal(&self) -> Direction {
+        Direction::from_yaw(self.yaw)
+    }
+
+    /// Whether the player clicked the upper half of the space the block is placed in. Used for
+    /// slabs, stairs and trapdoors.
+    fn upper_half(&self) -> bool {
+        match self.face {
+            Direction::Down => true,
+            Direction::Up => false,
+            _ => self.cursor.1 > 0.5,
+        }
+    }
+}
+
+/// Returns every block that needs to be set to place `block` at `pos`, oriented for the given
+/// context. Returns `None` if there's no room for the block, e.g. no space above a door.
+pub fn placement_states(
+    world: &World,
+    pos: BlockPos,
+    block: BlockStateId,
+    ctx: &PlacementContext,
+) -> Option<Vec<(BlockPos, BlockStateId)>> {
+    let state = orient(world, pos, block, ctx);
+    let mut states = vec![(pos, state)];
+
+    // The second half of two block tall blocks goes above
+    if state.property("half") == Some("lower") {
+        let upper_pos = pos + Direction::Up;
+        if !is_replaceable(world, upper_pos) {
+            return None;
+        }
+        states.push((upper_pos, state.with_property("half", "upper")?));
+    }
+
+    // Beds are placed foot first, with the head in the direction the player is looking
+    if state.property("part") == Some("foot") {
+        let facing = state.property("facing").and_then(Direction::from_name)?;
+        let head_pos = pos + facing;
+        if !is_replaceable(world, head_pos) {
+            return None;
+        }
+        states.push((head_pos, state.with_property("part", "head")?));
+    }
+
+    Some(states)
+}
+
+fn is_replaceable(world: &World, pos: BlockPos) -> bool {
+    world
+        .get_loaded_block(pos, DEFAULT_DIMENSION)
+        .is_some_and(|block| {
+            match_block!("air", block)
+                || match_block!("cave_air", block)
+                || match_block!("water", block)
+                || match_block!("lava", block)
+                || match_block!("short_grass", block)
+                || match_block!("fern", block)
+        })
+}
+
+/// Sets a property if the block has it and the value is valid, otherwise leaves it unchanged.
+fn with(block: BlockStateId, key: &str, value: &str) -> BlockStateId {
+    block.with_property(key, value).unwrap_or(block)
+}
+
+fn orient(
+    world: &World,
+    pos: BlockPos,
+    block: BlockStateId,
+    ctx: &PlacementContext,
+) -> BlockStateId {
+    let Some(name) = block
+        .name()
+        .and_then(|name| name.strip_prefix("minecraft:"))
+    else {
+        return block;
+    };
+
+    // Torches placed against the side of a block become wall torches
+    if ctx.face.is_horizontal() {
+        if let Some(wall) = wall_variant(name, ctx.face) {
+            return wall;
+        }
+    }
+
+    let mut state = block;
+    if block.property("facing").is_some() {
+        state = with(state, "facing", facing(name, block, ctx).as_str());
+    }
+    if block.property("face").is_some() {
+        let face = match ctx.face {
+            Direction::Up => "floor",
+            Direction::Down => "ceiling",
+            _ => "wall",
+        };
+        state = with(state, "face", face);
+    }
+    if block.property("axis").is_some() {
+        state = with(state, "axis", ctx.face.axis());
+    }
+
+    match block.property("half") {
+        Some("top" | "bottom") => {
+            state = with(
+                state,
+                "half",
+                if ctx.upper_half() { "top" } else { "bottom" },
+            );
+        }
+        Some("upper" | "lower") => state = with(state, "half", "lower"),
+        _ => {}
+    }
+    if name.ends_with("_slab") {
+        state = with(
+            state,
+            "type",
+            if ctx.upper_half() { "top" } else { "bottom" },
+        );
+    }
+    if name.ends_with("_bed") {
+        state = with(state, "part", "foot");
+    }
+    if name.ends_with("_door") {
+        let facing = state.property("facing").and_then(Direction::from_name);
+        if let Some(facing) = facing {
+            state = with(state, "hinge", door_hinge(world, pos, facing, ctx));
+        }
+    }
+
+    state
+}
+
+/// Which way a block's `facing` property should point when placed.
+fn facing(name: &str, block: BlockStateId, ctx: &PlacementContext) -> Direction {
+    let horizontal = ctx.horizontal();
+
+    // Blocks that face the same way as the player
+    if name.ends_with("_stairs")
+        || name.ends_with("_door")
+        || name.ends_with("_fence_gate")
+        || name.ends_with("_bed")
+    {
+        return horizontal;
+    }
+
+    // Blocks attached to the face that was clicked
+    if name == "lever" || name.ends_with("_button") {
+        return if ctx.face.is_horizontal() {
+            ctx.face
+        } else {
+            horizontal
+        };
+    }
+    if name.ends_with("_trapdoor") || name == "ladder" {
+        return if ctx.face.is_horizontal() {
+            ctx.face
+        } else {
+            horizontal.opposite()
+        };
+    }
+    if name == "end_rod"
+        || name == "lightning_rod"
+        || name.ends_with("amethyst_bud")
+        || name == "amethyst_cluster"
+    {
+        return ctx.face;
+    }
+
+    // Hoppers point into the block they were placed against, but never up
+    if name == "hopper" {
+        return match ctx.face.opposite() {
+            Direction::Up => Direction::Down,
+            facing => facing,
+        };
+    }
+
+    let looking = Direction::from_rotation(ctx.yaw, ctx.pitch);
+    if name == "observer" {
+        return looking;
+    }
+
+    // Everything else faces the player, including up and down if the block allows it (pistons,
+    // dispensers)
+    if looking.is_horizontal() || block.with_property("facing", "up").is_none() {
+        horizontal.opposite()
+    } else {
+        looking.opposite()
+    }
+}
+
+/// The wall version of a torch, facing away from the clicked block.
+fn wall_variant(name: &str, face: Direction) -> Option<BlockStateId> {
+    let wall_name = match name {
+        "torch" => "wall_torch",
+        "soul_torch" => "soul_wall_torch",
+        "redstone_torch" => "redstone_wall_torch",
+        _ => return None,
+    };
+
+    let mut properties = std::collections::BTreeMap::new();
+    properties.insert("facing".to_string(), face.as_str().to_string());
+    if wall_name == "redstone_wall_torch" {
+        properties.insert("lit".to_string(), "true".to_string());
+    }
+    Some(
+        BlockData {
+            name: format!("minecraft:{wall_name}"),
+            properties: Some(properties),
+        }
+        .to_block_state_id(),
+    )
+}
+
+/// Picks the hinge side for a door so it ends up next to the wall or forms a double door, falling
+/// back to the side of the block the player clicked.
+fn door_hinge(
+    world: &World,
+    pos: BlockPos,
+    facing: Direction,
+    ctx: &PlacementContext,
+) -> &'static str {
+    let is_door = |pos: BlockPos| {
+        world
+            .get_loaded_block(pos, DEFAULT_DIMENSION)
+            .is_some_and(|block| {
+                block.name().is_some_and(|name| name.ends_with("_door"))
+                    && block.property("half") == Some("lower")
+            })
+    };
+    let door_left = is_door(pos + facing.rotate_counter_clockwise());
+    let door_right = is_door(pos + facing.rotate_clockwise());
+    if door_left && !door_right {
+        return "right";
+    }
+    if door_right && !door_left {
+        return "left";
+    }
+
+    // Where the click landed, relative to the block being placed
+    let click_x = ctx.clicked.pos.x as f32 + ctx.cursor.0 - pos.pos.x as f32;
+    let click_z = ctx.clicked.pos.z as f32 + ctx.cursor.2 - pos.pos.z as f32;
+    let (step_x, _, step_z) = facing.offset();
+    let left = (step_x >= 0 || click_z >= 0.5)
+        && (step_x <= 0 || click_z <= 0.5)
+        && (step_z >= 0 || click_x <= 0.5)
+        && (step_z <= 0 || click_x >= 0.5);
+    if left {
+        "left"
+    } else {
+        "right"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ferrumc_macros::block;
+    use ferrumc_state::{create_test_state, GlobalStateResource};
+    use ferrumc_world::pos::ChunkPos;
+
+    /// Where the tested blocks are placed.
+    fn pos() -> BlockPos {
+        BlockPos::of(8, 64, 8)
+    }
+
+    fn create_empty_chunk(state: &GlobalStateResource) {
+        ferrumc_utils::world::load_or_generate_mut(
+            &state.0,
+            ChunkPos::new(0, 0),
+            DEFAULT_DIMENSION,
+        )
+        .expect("Failed to load or generate chunk")
+        .fill(block!("air"));
+    }
+
+    /// Slabs are built by hand since `type` can't be used as a key in `block!`.
+    fn oak_slab(kind: &str) -> BlockStateId {
+        BlockData {
+            name: "minecraft:oak_slab".to_string(),
+            properties: Some(
+                [("type", kind), ("waterlogged", "false")]
+                    .into_iter()
+                    .map(|(key, value)| (key.to_string(), value.to_string()))
+                    .collect(),
+            ),
+        }
+        .to_block_state_id()
+    }
+
+    /// A click on `face` of the block next to `pos()`, at `cursor_y` up that face.
+    fn click(face: Direction, cursor_y: f32, yaw: f32, pitch: f32) -> PlacementContext {
+        PlacementContext {
+            clicked: pos() + face.opposite(),
+            face,
+            cursor: (0.5, cursor_y, 0.5),
+            yaw,
+            pitch,
+        }
+    }
+
+    #[test]
+    fn test_orientation() {
+        let (state, _temp_dir) = create_test_state();
+        create_empty_chunk(&state);
+
+        let stairs = block!("oak_stairs", { facing: "north", half: "bottom", shape: "straight", waterlogged: false });
+        let slab = oak_slab("bottom");
+        let log = block!("oak_log", { axis: "y" });
+        let piston = block!("piston", { extended: false, facing: "north" });
+        let observer = block!("observer", { facing: "south", powered: false });
+        let hopper = block!("hopper", { enabled: true, facing: "down" });
+        let lever = block!("lever", { face: "wall", facing: "north", powered: false });
+        let trapdoor = block!("oak_trapdoor", { facing: "north", half: "bottom", open: false, powered: false, waterlogged: false });
+
+        let cases = [
+            // Stairs face the way the player is looking
+            (
+                "stairs looking south",
+                stairs,
+                click(Direction::Up, 1.0, 0.0, 0.0),
+                block!("oak_stairs", { facing: "south", half: "bottom", shape: "straight", waterlogged: false }),
+            ),
+            (
+                "stairs looking west",
+                stairs,
+                click(Direction::Up, 1.0, 90.0, 0.0),
+                block!("oak_stairs", { facing: "west", half: "bottom", shape: "straight", waterlogged: false }),
+            ),
+            (
+                "stairs looking north",
+                stairs,
+                click(Direction::Up, 1.0, 180.0, 0.0),
+                block!("oak_stairs", { facing: "north", half: "bottom", shape: "straight", waterlogged: false }),
+            ),
+            (
+                "stairs looking east",
+                stairs,
+                click(Direction::Up, 1.0, -90.0, 0.0),
+                block!("oak_stairs", { facing: "east", half: "bottom", shape: "straight", waterlogged: false }),
+            ),
+            (
+                "stairs under a ceiling",
+                stairs,
+                click(Direction::Down, 0.0, 0.0, 0.0),
+                block!("oak_stairs", { facing: "south", half: "top", shape: "straight", waterlogged: false }),
+            ),
+            (
+                "stairs on the top of a side",
+                stairs,
+                click(Direction::East, 0.75, 0.0, 0.0),
+                block!("oak_stairs", { facing: "south", half: "top", shape: "straight", waterlogged: false }),
+            ),
+            (
+                "stairs on the bottom of a side",
+                stairs,
+                click(Direction::East, 0.25, 0.0, 0.0),
+                block!("oak_stairs", { facing: "south", half: "bottom", shape: "straight", waterlogged: false }),
+            ),
+            // Slabs go in the half that was clicked
+            (
+                "slab on a floor",
+                slab,
+                click(Direction::Up, 1.0, 0.0, 0.0),
+                oak_slab("bottom"),
+            ),
+            (
+                "slab under a ceiling",
+                slab,
+                click(Direction::Down, 0.0, 0.0, 0.0),
+                oak_slab("top"),
+            ),
+            (
+                "slab on the top of a side",
+                slab,
+                click(Direction::North, 0.75, 0.0, 0.0),
+                oak_slab("top"),
+            ),
+            (
+                "slab on the bottom of a side",
+                slab,
+                click(Direction::North, 0.25, 0.0, 0.0),
+                oak_slab("bottom"),
+            ),
+            // Logs line up with the clicked face
+            (
+                "log on a floor",
+                log,
+                click(Direction::Up, 1.0, 0.0, 0.0),
+                block!("oak_log", { axis: "y" }),
+            ),
+            (
+                "log on a north face",
+                log,
+                click(Direction::North, 0.5, 0.0, 0.0),
+                block!("oak_log", { axis: "z" }),
+            ),
+            (
+                "log on an east face",
+                log,
+                click(Direction::East, 0.5, 0.0, 0.0),
+                block!("oak_log", { axis: "x" }),
+            ),
+            // Pistons face the player, including up and down
+            (
+                "piston looking south",
+                piston,
+                click(Direction::Up, 1.0, 0.0, 0.0),
+                block!("piston", { extended: false, facing: "north" }),
+            ),
+            (
+                "piston looking down",
+                piston,
+                click(Direction::Up, 1.0, 0.0, 60.0),
+                block!("piston", { extended: false, facing: "up" }),
+            ),
+            (
+                "piston looking up",
+                piston,
+                click(Direction::Down, 0.0, 0.0, -60.0),
+                block!("piston", { extended: false, facing: "down" }),
+            ),
+            // Observers face the way the player is looking
+            (
+                "observer looking down",
+                observer,
+                click(Direction::Up, 1.0, 0.0, 60.0),
+                block!("observer", { facing: "down", powered: false }),
+            ),
+            (
+                "observer looking west",
+                observer,
+                click(Direction::Up, 1.0, 90.0, 0.0),
+                block!("observer", { facing: "west", powered: false }),
+            ),
+            // Hoppers point into the clicked block, but never up
+            (
+                "hopper on a side",
+                hopper,
+                click(Direction::West, 0.5, 0.0, 0.0),
+                block!("hopper", { enabled: true, facing: "east" }),
+            ),
+            (
+                "hopper under a ceiling",
+                hopper,
+                click(Direction::Down, 0.0, 0.0, 0.0),
+                block!("hopper", { enabled: true, facing: "down" }),
+            ),
+            // Levers and trapdoors attach to the clicked face
+            (
+                "lever on a side",
+                lever,
+                click(Direction::West, 0.5, 0.0, 0.0),
+                block!("lever", { face: "wall", facing: "west", powered: false }),
+            ),
+            (
+                "lever on a floor",
+                lever,
+                click(Direction::Up, 1.0, 180.0, 0.0),
+                block!("lever", { face: "floor", facing: "north", powered: false }),
+            ),
+            (
+                "lever on a ceiling",
+                lever,
+                click(Direction::Down, 0.0, 180.0, 0.0),
+                block!("lever", { face: "ceiling", facing: "north", powered: false }),
+            ),
+            (
+                "trapdoor on the top of a side",
+                trapdoor,
+                click(Direction::South, 0.75, 0.0, 0.0),
+                block!("oak_trapdoor", { facing: "south", half: "top", open: false, powered: false, waterlogged: false }),
+            ),
+            (
+                "trapdoor on a floor",
+                trapdoor,
+                click(Direction::Up, 1.0, 0.0, 0.0),
+                block!("oak_trapdoor", { facing: "north", half: "bottom", open: false, powered: false, waterlogged: false }),
+            ),
+            // Torches on a side become wall torches
+            (
+                "torch on a side",
+                block!("torch"),
+                click(Direction::East, 0.5, 0.0, 0.0),
+                block!("wall_torch", { facing: "east" }),
+            ),
+            (
+                "torch on a floor",
+                block!("torch"),
+                click(Direction::Up, 1.0, 0.0, 0.0),
+                block!("torch"),
+            ),
+        ];
+
+        for (name, block, ctx, expected) in cases {
+            assert_eq!(
+                placement_states(&state.0.world, pos(), block, &ctx),
+                Some(vec![(pos(), expected)]),
+                "{name}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_doors() {
+        let (state, _temp_dir) = create_test_state();
+        create_empty_chunk(&state);
+        let world = &state.0.world;
+        let door = block!("oak_door", { facing: "north", half: "upper", hinge: "left", open: false, powered: false });
+
+        let cases = [
+            ("click in the middle", (0.5, 1.0, 0.5), "left"),
+            ("click on the north side", (0.5, 1.0, 0.25), "right"),
+            ("click on the south side", (0.5, 1.0, 0.75), "left"),
+        ];
+        for (name, cursor, hinge) in cases {
+            let ctx = PlacementContext {
+                cursor,
+                yaw: 90.0,
+                ..click(Direction::Up, 1.0, 0.0, 0.0)
+            };
+            let lower = block!("oak_door", { facing: "west", half: "lower", hinge: "left", open: false, powered: false });
+            let lower = lower.with_property("hinge", hinge).unwrap();
+            let upper = lower.with_property("half", "upper").unwrap();
+            assert_eq!(
+                placement_states(world, pos(), door, &ctx),
+                Some(vec![(pos(), lower), (pos() + Direction::Up, upper)]),
+                "{name}"
+            );
+        }
+
+        // A door to the left makes a double door
+        world.set_loaded_block(
+            pos() + Direction::South,
+            DEFAULT_DIMENSION,
+            block!("oak_door", { facing: "west", half: "lower", hinge: "left", open: false, powered: false }),
+        );
+        let states = placement_states(world, pos(), door, &click(Direction::Up, 1.0, 90.0, 0.0))
+            .expect("door should fit");
+        assert_eq!(states[0].1.property("hinge"), Some("right"));
+
+        // No room for the upper half
+        world.set_loaded_block(pos() + Direction::Up, DEFAULT_DIMENSION, block!("stone"));
+        assert_eq!(
+            placement_states(world, pos(), door, &click(Direction::Up, 1.0, 90.0, 0.0)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_beds() {
+        let (state, _temp_dir) = create_test_state();
+        create_empty_chunk(&state);
+        let world = &state.0.world;
+        let bed = block!("red_bed", { facing: "north", occupied: false, part: "head" });
+
+        let cases = [
+            (0.0, Direction::South),
+            (90.0, Direction::West),
+            (180.0, Direction::North),
+            (-90.0, Direction::East),
+        ];
+        for (yaw, facing) in cases {
+            let foot = block!("red_bed", { facing: "north", occupied: false, part: "foot" })
+                .with_property("facing", facing.as_str())
+                .unwrap();
+            let head = foot.with_property("part", "head").unwrap();
+            assert_eq!(
+                placement_states(world, pos(), bed, &click(Direction::Up, 1.0, yaw, 0.0)),
+                Some(vec![(pos(), foot), (pos() + facing, head)]),
+                "{}",
+                facing.as_str()
+            );
+        }
+
+        // No room for the head
+        world.set_loaded_block(pos() + Direction::South, DEFAULT_DIMENSION, block!("stone"));
+        assert_eq!(
+            placement_states(world, pos(), bed, &click(Direction::Up, 1.0, 0.0, 0.0)),
+            None
+        );
+    }
+}
@@ -0,0 +1,322 @@
+//! Tick behaviours for vanilla blocks.
+//!
+//! There's no lighting engine yet, so anything that depends on light level in vanilla (grass
+//! spreading, crops needing light) uses [`is_open`] as a rough stand-in for "lets light through".
+
+use ferrumc_data::blocks::types::Block;
+use ferrumc_macros::{block, match_block};
+use ferrumc_world::block_state_id::BlockStateId;
+use ferrumc_world::block_tick::{
+    BlockBehaviour, BlockBehaviourRegistry, BlockTickContext, NEIGHBOUR_OFFSETS,
+};
+use rand::Rng;
+
+/// The lowest y level a falling block can reach.
+const MIN_Y: i32 = -64;
+
+const CROPS: &[&str] = &["wheat", "carrots", "potatoes", "beetroots"];
+
+const COLUMN_PLANTS: &[&str] = &["sugar_cane", "cactus"];
+
+const LEAVES: &[&str] = &[
+    "oak_leaves",
+    "spruce_leaves",
+    "birch_leaves",
+    "jungle_leaves",
+    "acacia_leaves",
+    "dark_oak_leaves",
+    "mangrove_leaves",
+    "cherry_leaves",
+    "pale_oak_leaves",
+    "azalea_leaves",
+    "flowering_azalea_leaves",
+];
+
+const FALLING_BLOCKS: &[&str] = &[
+    "sand",
+    "red_sand",
+    "gravel",
+    "anvil",
+    "chipped_anvil",
+    "damaged_anvil",
+    "white_concrete_powder",
+    "orange_concrete_powder",
+    "magenta_concrete_powder",
+    "light_blue_concrete_powder",
+    "yellow_concrete_powder",
+    "lime_concrete_powder",
+    "pink_concrete_powder",
+    "gray_concrete_powder",
+    "light_gray_concrete_powder",
+    "cyan_concrete_powder",
+    "purple_concrete_powder",
+    "blue_concrete_powder",
+    "brown_concrete_powder",
+    "green_concrete_powder",
+    "red_concrete_powder",
+    "black_concrete_powder",
+];
+
+/// Builds the registry with all the block behaviours the server knows about.
+pub fn default_registry() -> BlockBehaviourRegistry {
+    let mut registry = BlockBehaviourRegistry::new();
+
+    registry.register_all(CROPS, BlockBehaviour::new().random_tick(grow_crop));
+    registry.register(
+        "farmland",
+        BlockBehaviour::new().random_tick(hydrate_farmland),
+    );
+    registry.register_all(
+        &["grass_block", "mycelium"],
+        BlockBehaviour::new().random_tick(spread_grass),
+    );
+    registry.register_all(
+        COLUMN_PLANTS,
+        BlockBehaviour::new().random_tick(grow_column),
+    );
+    registry.register_all(
+        LEAVES,
+        BlockBehaviour::new()
+            .random_tick(decay_leaves)
+            .scheduled_tick(update_leaf_distance)
+            .update_delay(1),
+    );
+    registry.register_all(
+        FALLING_BLOCKS,
+        BlockBehaviour::new().scheduled_tick(fall).update_delay(2),
+    );
+
+    registry
+}
+
+fn is_air(block: BlockStateId) -> bool {
+    match_block!("air", block) || match_block!("cave_air", block) || match_block!("void_air", block)
+}
+
+fn is_water(block: BlockStateId) -> bool {
+    match_block!("water", block)
+}
+
+/// Whether the block would let light through to the block below it.
+fn is_open(block: BlockStateId) -> bool {
+    if is_air(block) {
+        return true;
+    }
+    let Some(name) = block.name() else {
+        return false;
+    };
+    name.ends_with("leaves")
+        || name.ends_with("glass")
+        || name.ends_with("glass_pane")
+        || Block::by_name(name.trim_start_matches("minecraft:")).is_some_and(|b| b.hardness == 0.0)
+}
+
+fn is_log(block: BlockStateId) -> bool {
+    block.name().is_some_and(|name| {
+        name.ends_with("_log")
+            || name.ends_with("_wood")
+            || name.ends_with("_stem")
+            || name.ends_with("_hyphae")
+    })
+}
+
+fn int_property(block: BlockStateId, key: &str) -> Option<u8> {
+    block.property(key)?.parse().ok()
+}
+
+fn grow_crop(ctx: &mut BlockTickContext) {
+    let Some(age) = int_property(ctx.block, "age") else {
+        return;
+    };
+    let max_age = if match_block!("beetroots", ctx.block) {
+        3
+    } else {
+        7
+    };
+    if age >= max_age {
+        return;
+    }
+
+    let Some(soil) = ctx.get_relative((0, -1, 0)) else {
+        return;
+    };
+    if !match_block!("farmland", soil) {
+        return;
+    }
+    // Vanilla looks at the moisture of the surrounding farmland too, this only checks the block
+    // underneath. The odds match a crop on hydrated or dry farmland.
+    let odds = if soil.property("moisture") == Some("7") {
+        7
+    } else {
+        13
+    };
+    if rand::thread_rng().gen_range(0..odds) != 0 {
+        return;
+    }
+
+    if let Some(grown) = ctx.block.with_property("age", &(age + 1).to_string()) {
+        ctx.set_self(grown);
+    }
+}
+
+fn hydrate_farmland(ctx: &mut BlockTickContext) {
+    let moisture = int_property(ctx.block, "moisture").unwrap_or(0);
+
+    let near_water = (-4..=4).any(|x| {
+        (0..=1).any(|y| (-4..=4).any(|z| ctx.get_relative((x, y, z)).is_some_and(is_water)))
+    });
+
+    let new_state = if near_water {
+        if moisture == 7 {
+            return;
+        }
+        ctx.block.with_property("moisture", "7")
+    } else if moisture > 0 {
+        ctx.block
+            .with_property("moisture", &(moisture - 1).to_string())
+    } else {
+        // Dry farmland only turns back into dirt if nothing is planted on it
+        let crop_above = ctx
+            .get_relative((0, 1, 0))
+            .and_then(|above| above.name())
+            .and_then(|name| name.strip_prefix("minecraft:"))
+            .is_some_and(|name| CROPS.contains(&name));
+        if crop_above {
+            return;
+        }
+        Some(block!("dirt"))
+    };
+
+    if let Some(new_state) = new_state {
+        ctx.set_self(new_state);
+    }
+}
+
+fn spread_grass(ctx: &mut BlockTickContext) {
+    let Some(above) = ctx.get_relative((0, 1, 0)) else {
+        return;
+    };
+    if !is_open(above) {
+        ctx.set_self(block!("dirt"));
+        return;
+    }
+
+    let spread_to = if match_block!("mycelium", ctx.block) {
+        block!("mycelium", {snowy: false})
+    } else {
+        block!("grass_block", {snowy: false})
+    };
+
+    let mut rng = rand::thread_rng();
+    for _ in 0..4 {
+        let target = ctx.pos
+            + (
+                rng.gen_range(-1..=1),
+                rng.gen_range(-3..=1),
+                rng.gen_range(-1..=1),
+            );
+        if !ctx
+            .get_block(target)
+            .is_some_and(|b| match_block!("dirt", b))
+        {
+            continue;
+        }
+        if !ctx.get_block(target + (0, 1, 0)).is_some_and(is_open) {
+            continue;
+        }
+        ctx.set_block(target, spread_to);
+    }
+}
+
+/// Sugar cane and cactus grow up to three blocks tall, adding a block every 16 random ticks.
+fn grow_column(ctx: &mut BlockTickContext) {
+    if !ctx.get_relative((0, 1, 0)).is_some_and(is_air) {
+        return;
+    }
+    let Some(name) = ctx.block.name() else {
+        return;
+    };
+
+    let height = 1
+        + (1..3)
+            .take_while(|dy| ctx.get_relative((0, -dy, 0)).and_then(|b| b.name()) == Some(name))
+            .count();
+    if height >= 3 {
+        return;
+    }
+
+    let age = int_property(ctx.block, "age").unwrap_or(0);
+    if age < 15 {
+        if let Some(aged) = ctx.block.with_property("age", &(age + 1).to_string()) {
+            ctx.set_self(aged);
+        }
+        return;
+    }
+
+    let Some(young) = ctx.block.with_property("age", "0") else {
+        return;
+    };
+    ctx.set_self(young);
+    ctx.set_block(ctx.pos + (0, 1, 0), young);
+}
+
+/// Recalculates how far these leaves are from a log. Leaves more than 6 blocks away decay.
+fn update_leaf_distance(ctx: &mut BlockTickContext) {
+    let distance = NEIGHBOUR_OFFSETS
+        .iter()
+        .filter_map(|offset| ctx.get_relative(*offset))
+        .map(|neighbour| {
+            if is_log(neighbour) {
+                1
+            } else {
+                int_property(neighbour, "distance").map_or(7, |d| d.saturating_add(1))
+            }
+        })
+        .min()
+        .unwrap_or(7)
+        .min(7);
+
+    if int_property(ctx.block, "distance") == Some(distance) {
+        return;
+    }
+    if let Some(updated) = ctx.block.with_property("distance", &distance.to_string()) {
+        ctx.set_self(updated);
+    }
+}
+
+fn decay_leaves(ctx: &mut BlockTickContext) {
+    if ctx.block.property("persistent") == Some("false")
+        && ctx.block.property("distance") == Some("7")
+    {
+        ctx.set_self(block!("air"));
+    }
+}
+
+/// Drops the block straight down to the first thing it can land on.
+///
+/// There are no falling block entities yet, so the block moves in a single tick instead of
+/// falling over time.
+fn fall(ctx: &mut BlockTickContext) {
+    let falling = ctx.block;
+    let can_fall_into = |block: BlockStateId| {
+        match_block!("air", block)
+            || match_block!("cave_air", block)
+            || match_block!("water", block)
+            || match_block!("lava", block)
+    };
+
+    let mut target = ctx.pos;
+    while target.pos.y > MIN_Y {
+        let below = target + (0, -1, 0);
+        if !ctx.get_block(below).is_some_and(can_fall_into) {
+            break;
+        }
+        target = below;
+    }
+    if target == ctx.pos {
+        return;
+    }
+
+    ctx.set_self(block!("air"));
+    ctx.set_block(target, falling);
+}
@@ -0,0 +1,174 @@
+use bevy_ecs::prelude::{Entity, MessageReader, Query, Res, Resource};
+use ferrumc_config::server_config::get_global_config;
+use ferrumc_core::chunks::chunk_receiver::ChunkReceiver;
+use ferrumc_core::game_rules::{GameRule, GameRules};
+use ferrumc_core::transform::position::Position;
+use ferrumc_messages::BlockBrokenEvent;
+use ferrumc_net::connection::StreamWriter;
+use ferrumc_net::packets::outgoing::block_update::BlockUpdate;
+use ferrumc_net_codec::net_types::var_int::VarInt;
+use ferrumc_state::{GlobalState, GlobalStateResource};
+use ferrumc_world::block_state_id::BlockStateId;
+use ferrumc_world::block_tick::{BlockBehaviourRegistry, BlockTickContext};
+use ferrumc_world::pos::{BlockPos, ChunkPos};
+use ferrumc_world::DEFAULT_DIMENSION;
+use std::collections::{HashMap, HashSet};
+use tracing::error;
+
+pub mod behaviours;
+
+/// The block behaviours used by the tick systems.
+#[derive(Resource)]
+pub struct BlockBehaviours(pub BlockBehaviourRegistry);
+
+impl Default for BlockBehaviours {
+    fn default() -> Self {
+        Self(behaviours::default_registry())
+    }
+}
+
+/// Runs due scheduled ticks and hands out random ticks in every chunk a player has loaded.
+pub fn tick_blocks(
+    state: Res<GlobalStateResource>,
+    behaviours: Res<BlockBehaviours>,
+    game_rules: Res<GameRules>,
+    receivers: Query<&ChunkReceiver>,
+    players: Query<(Entity, &StreamWriter, &Position)>,
+) {
+    let world = &state.0.world;
+    let registry = &behaviours.0;
+    let random_tick_speed = game_rules.get_int(GameRule::RandomTickSpeed).max(0) as u32;
+
+    let loaded: HashSet<(i32, i32)> = receivers
+        .iter()
+        .flat_map(|receiver| receiver.loaded.iter().copied())
+        .collect();
+    if loaded.is_empty() {
+        return;
+    }
+
+    // Collect everything that needs ticking first so no chunk is locked while behaviours run,
+    // since they're free to read and write neighbouring chunks.
+    let mut scheduled = Vec::new();
+    let mut random = Vec::new();
+    let mut picks = Vec::new();
+    let mut rng = rand::thread_rng();
+    for (x, z) in loaded {
+        let chunk_pos = ChunkPos::new(x, z);
+        let Some(mut chunk) = world
+            .get_cache()
+            .get_mut(&(chunk_pos, DEFAULT_DIMENSION.to_string()))
+        else {
+            continue;
+        };
+
+        for tick in chunk.scheduled_ticks_mut().advance() {
+            scheduled.push((chunk_pos.chunk_block(tick.pos()), tick.block));
+        }
+
+        chunk.pick_random_ticks(
+            random_tick_speed,
+            &mut rng,
+            |block| registry.ticks_randomly(block),
+            &mut picks,
+        );
+        random.extend(
+            picks
+                .drain(..)
+                .map(|(pos, block)| (chunk_pos.chunk_block(pos), block)),
+        );
+    }
+
+    let mut changes = Vec::new();
+    for (pos, scheduled_for) in scheduled {
+        // The block may have been replaced since the tick was scheduled
+        let Some(current) = world.get_loaded_block(pos, DEFAULT_DIMENSION) else {
+            continue;
+        };
+        if !registry.same_block(current, scheduled_for) {
+            continue;
+        }
+        let Some(tick) = registry.get(current).and_then(|b| b.scheduled_tick) else {
+            continue;
+        };
+        tick(&mut BlockTickContext::new(
+            world,
+            DEFAULT_DIMENSION,
+            registry,
+            pos,
+            current,
+            &mut changes,
+        ));
+    }
+
+    for (pos, picked) in random {
+        // An earlier tick this pass may have changed the block
+        if world.get_loaded_block(pos, DEFAULT_DIMENSION) != Some(picked) {
+            continue;
+        }
+        let Some(tick) = registry.get(picked).and_then(|b| b.random_tick) else {
+            continue;
+        };
+        tick(&mut BlockTickContext::new(
+            world,
+            DEFAULT_DIMENSION,
+            registry,
+            pos,
+            picked,
+            &mut changes,
+        ));
+    }
+
+    broadcast_block_changes(&state.0, &changes, &players);
+}
+
+/// Schedules ticks for blocks next to ones that were broken, so e.g. sand falls and leaves decay.
+pub fn notify_broken_blocks(
+    mut events: MessageReader<BlockBrokenEvent>,
+    state: Res<GlobalStateResource>,
+    behaviours: Res<BlockBehaviours>,
+) {
+    for event in events.read() {
+        behaviours
+            .0
+            .notify_neighbours(&state.0.world, DEFAULT_DIMENSION, event.position);
+    }
+}
+
+/// Sends block updates for the changed blocks to every player within render distance of them.
+/// If a block changed more than once, only its final state is sent.
+pub fn broadcast_block_changes(
+    state: &GlobalState,
+    changes: &[(BlockPos, BlockStateId)],
+    players: &Query<(Entity, &StreamWriter, &Position)>,
+) {
+    if changes.is_empty() {
+        return;
+    }
+
+    let latest: HashMap<BlockPos, BlockStateId> = changes.iter().copied().collect();
+    let render_distance = get_global_config().chunk_render_distance as i32;
+
+    for (eid, conn, player_pos) in players.iter() {
+        if !state.players.is_connected(eid) {
+            continue;
+        }
+        let player_chunk = player_pos.chunk();
+        for (pos, block) in &latest {
+            let chunk = pos.chunk();
+            if (chunk.x() - player_chunk.x).abs() > render_distance
+                || (chunk.z() - player_chunk.y).abs() > render_distance
+            {
+                continue;
+            }
+            let packet = BlockUpdate {
+                location: (*pos).into(),
+                block_state_id: VarInt::from(*block),
+            };
+            if let Err(err) = conn.send_packet_ref(&packet) {
+                error!("Failed to send block update packet: {:?}", err);
+                break;
+            }
+        }
+    }
+}
@@ -0,0 +1,222 @@
+//! Loads, generates and encodes chunks on the thread pool, off the main schedule.
+//!
+//! Encoded chunk packets are cached and shared between players until a section of the chunk
+//! changes, which is tracked through the chunk's network dirty flags. Finished chunks wait in a
+//! queue per player until the chunk sending system has room to send them. Packets are kept
+//! separately with and without compression, as each connection decides that for itself.
+//!
+//! Chunks that fail to encode are handed back to the players waiting for them, so they can be
+//! requested again a few times before they are left for the next time the player loads them.
+
+use crate::errors::BinaryError;
+use bevy_ecs::prelude::{Entity, Resource};
+use crossbeam_channel::{Receiver, Sender};
+use ferrumc_config::server_config::get_global_config;
+use ferrumc_net::compression::compress_packet;
+use ferrumc_net::packets::outgoing::chunk_and_light_data::ChunkAndLightData;
+use ferrumc_net_codec::encode::NetEncodeOpts;
+use ferrumc_state::GlobalState;
+use ferrumc_world::pos::ChunkPos;
+use std::collections::HashMap;
+use tracing::error;
+
+/// A chunk in a dimension, the same key the world's chunk cache uses.
+type ChunkKey = (ChunkPos, String);
+
+/// A chunk's packet, and whether it is compressed.
+type PacketKey = (ChunkKey, bool);
+
+/// How many cached packets to allow before dropping the ones of unloaded chunks.
+const INITIAL_PRUNE_AT: usize = 1024;
+
+/// How many times in a row a chunk may fail to encode before it stops being retried.
+const MAX_ENCODE_ATTEMPTS: u32 = 3;
+
+#[derive(Resource)]
+pub struct ChunkPipeline {
+    /// Encoded chunk packets, ready to be sent as-is.
+    cache: HashMap<PacketKey, Vec<u8>>,
+    /// Chunks being encoded, and the players waiting for them.
+    in_flight: HashMap<PacketKey, Vec<Entity>>,
+    /// Encoded chunks waiting to be sent, by player.
+    ready: HashMap<Entity, Vec<(ChunkPos, Vec<u8>)>>,
+    /// Chunks that failed to encode, by the player that was waiting for them, and whether they
+    /// should be requested again.
+    failed: HashMap<Entity, Vec<(ChunkPos, bool)>>,
+    /// How many times in a row chunks failed to encode.
+    attempts: HashMap<PacketKey, u32>,
+    results_send: Sender<(PacketKey, Result<Vec<u8>, BinaryError>)>,
+    results_recv: Receiver<(PacketKey, Result<Vec<u8>, BinaryError>)>,
+    prune_at: usize,
+}
+
+impl Default for ChunkPipeline {
+    fn default() -> Self {
+        let (results_send, results_recv) = crossbeam_channel::unbounded();
+        Self {
+            cache: HashMap::new(),
+            in_flight: HashMap::new(),
+            ready: HashMap::new(),
+            failed: HashMap::new(),
+            attempts: HashMap::new(),
+            results_send,
+            results_recv,
+            prune_at: INITIAL_PRUNE_AT,
+        }
+    }
+}
+
+impl ChunkPipeline {
+    /// Requests a chunk for `player`.
+    ///
+    /// The chunk is ready right away if an up-to-date packet is cached. Otherwise it is encoded
+    /// in the background, unless it already is, and becomes ready in a later [`Self::poll`].
+    /// `compress` is whether the player's connection uses compression.
+    pub fn request(
+        &mut self,
+        state: &GlobalState,
+        player: Entity,
+        pos: ChunkPos,
+        dimension: &str,
+        compress: bool,
+    ) {
+        let chunk = (pos, dimension.to_string());
+        let key = (chunk.clone(), compress);
+
+        // While a chunk is being encoded its dirty flags are already cleared, so the cached
+        // packet can't be trusted until the new one arrives
+        if let Some(waiting) = self.in_flight.get_mut(&key) {
+            if !waiting.contains(&player) {
+                waiting.push(player);
+            }
+            return;
+        }
+
+        if let Some(packet) = self.cache.get(&key) {
+            // Chunks that left the world's cache are encoded again, since they lose their flags
+            let up_to_date = state
+                .world
+                .get_cache()
+                .get(&chunk)
+                .is_some_and(|chunk| !chunk.is_network_dirty());
+            if up_to_date {
+                let packet = packet.clone();
+                self.ready.entry(player).or_default().push((pos, packet));
+                return;
+            }
+            self.cache.remove(&key);
+        }
+
+        self.in_flight.insert(key.clone(), vec![player]);
+        let task_state = state.clone();
+        let results = self.results_send.clone();
+        // The handle is dropped so the task runs in the background, the result comes back
+        // through the channel instead
+        drop(state.thread_pool.oneshot(move || {
+            let result = encode_chunk(&task_state, &key.0, key.1);
+            // The pipeline only goes away when the server shuts down
+            let _ = results.send((key, result));
+        }));
+    }
+
+    /// Makes the chunks that finished encoding since the last call ready for the players that
+    /// requested them, or hands them back through [`Self::take_failed`] if encoding failed.
+    pub fn poll(&mut self, state: &GlobalState) {
+        for (key, result) in self.results_recv.try_iter() {
+            let waiting = self.in_flight.remove(&key).unwrap_or_default();
+            let ((pos, dimension), _) = &key;
+            let packet = match result {
+                Ok(packet) => packet,
+                Err(err) => {
+                    let attempts = self.attempts.entry(key.clone()).or_default();
+                    *attempts += 1;
+                    let retry = *attempts < MAX_ENCODE_ATTEMPTS;
+                    if retry {
+                        error!("Failed to encode chunk at {pos} in {dimension}, retrying: {err}");
+                    } else {
+                        error!("Failed to encode chunk at {pos} in {dimension}, giving up: {err}");
+                        self.attempts.remove(&key);
+                    }
+                    for player in waiting {
+                        self.failed.entry(player).or_default().push((*pos, retry));
+                    }
+                    continue;
+                }
+            };
+            self.attempts.remove(&key);
+            for player in waiting {
+                self.ready
+                    .entry(player)
+                    .or_default()
+                    .push((*pos, packet.clone()));
+            }
+            self.cache.insert(key, packet);
+        }
+
+        if self.cache.len() >= self.prune_at {
+            self.prune(state);
+        }
+    }
+
+    /// Takes up to `count` of the player's ready chunks, lowest `priority` first. Chunks that
+    /// don't pass `keep` are thrown away.
+    pub fn take_ready(
+        &mut self,
+        player: Entity,
+        count: usize,
+        keep: impl Fn(ChunkPos) -> bool,
+        priority: impl Fn(ChunkPos) -> f32,
+    ) -> Vec<Vec<u8>> {
+        let Some(ready) = self.ready.get_mut(&player) else {
+            return Vec::new();
+        };
+        ready.retain(|(pos, _)| keep(*pos));
+        // Highest priority last, so the batch can be split off the end
+        ready.sort_by(|(a, _), (b, _)| priority(*b).total_cmp(&priority(*a)));
+        let batch = ready.split_off(ready.len().saturating_sub(count));
+        batch.into_iter().map(|(_, packet)| packet).collect()
+    }
+
+    /// Takes the chunks that failed to encode for the player, and whether to request them again
+    /// right away. The ones that shouldn't be are only worth requesting once the player loads
+    /// them again.
+    pub fn take_failed(&mut self, player: Entity) -> Vec<(ChunkPos, bool)> {
+        self.failed.remove(&player).unwrap_or_default()
+    }
+
+    /// Forgets the ready and failed chunks of players that are gone.
+    pub fn retain_players(&mut self, exists: impl Fn(Entity) -> bool) {
+        self.ready.retain(|player, _| exists(*player));
+        self.failed.retain(|player, _| exists(*player));
+    }
+
+    /// Drops the cached packets of chunks that left the world's cache, as those have to be
+    /// encoded again anyway.
+    fn prune(&mut self, state: &GlobalState) {
+        let chunks = state.world.get_cache();
+        self.cache
+            .retain(|(chunk, _), _| chunks.contains_key(chunk));
+        self.prune_at = (self.cache.len() * 2).max(INITIAL_PRUNE_AT);
+    }
+}
+
+fn encode_chunk(
+    state: &GlobalState,
+    (pos, dimension): &ChunkKey,
+    compress: bool,
+) -> Result<Vec<u8>, BinaryError> {
+    let mut chunk = ferrumc_utils::world::load_or_generate_mut(state, *pos, dimension)?;
+    // Cleared while still holding the chunk, so no change can slip in before it is encoded
+    chunk.mark_network_clean();
+    let chunk = chunk.downgrade();
+
+    let packet = ChunkAndLightData::from_chunk(*pos, &chunk)?;
+    // Connections that use compression all share the configured threshold
+    let threshold = get_global_config().network_compression_threshold;
+    Ok(compress_packet(
+        &packet,
+        compress,
+        &NetEncodeOpts::WithLength,
+        threshold.max(0) as usize,
+    )?)
+}
@@ -1,35 +1,39 @@
-use bevy_ecs::prelude::{Entity, Query, Res};
-use bevy_math::{IVec2, IVec3};
+use crate::systems::chunk_pipeline::ChunkPipeline;
+use bevy_ecs::prelude::{Entity, Query, Res, ResMut};
+use bevy_math::{IVec2, IVec3, Vec2};
 use ferrumc_components::player::client_information::ClientInformationComponent;
 use ferrumc_config::server_config::get_global_config;
 use ferrumc_core::chunks::chunk_receiver::ChunkReceiver;
 use ferrumc_core::transform::position::Position;
-use ferrumc_net::compression::compress_packet;
+use ferrumc_core::transform::rotation::Rotation;
 use ferrumc_net::connection::StreamWriter;
-use ferrumc_net::packets::outgoing::chunk_and_light_data::ChunkAndLightData;
 use ferrumc_net::packets::outgoing::chunk_batch_finish::ChunkBatchFinish;
 use ferrumc_net::packets::outgoing::chunk_batch_start::ChunkBatchStart;
 use ferrumc_net::packets::outgoing::set_center_chunk::SetCenterChunk;
-use ferrumc_net_codec::encode::NetEncodeOpts;
 use ferrumc_state::GlobalStateResource;
 use ferrumc_world::pos::ChunkPos;
 use std::cmp::max;
 use std::sync::atomic::Ordering;
 
-// Just take the needed chunks from the ChunkReceiver and send them
-// calculating which chunks are required is figured out elsewhere
-// TODO: Respect chunks_per_tick limit
+// Just take the needed chunks from the ChunkReceiver and request them from the pipeline,
+// sending whatever is ready as fast as the client asks for. Calculating which chunks are
+// required is figured out elsewhere
 pub fn handle(
     mut query: Query<(
         Entity,
         &StreamWriter,
         &mut ChunkReceiver,
         &Position,
+        &Rotation,
         &ClientInformationComponent,
     )>,
+    mut pipeline: ResMut<ChunkPipeline>,
     state: Res<GlobalStateResource>,
 ) {
-    for (eid, conn, mut chunk_receiver, pos, client_info) in query.iter_mut() {
+    // Chunks that finished encoding since the last tick
+    pipeline.poll(&state.0);
+
+    for (eid, conn, mut chunk_receiver, pos, rot, client_info) in query.iter_mut() {
         if !state.0.players.is_connected(eid) {
             continue; // Skip if the player is not connected
         }
@@ -43,12 +47,15 @@ pub fn handle(
             hard_limit => hard_limit as usize,
         };
 
-        if chunk_receiver.dirty.is_empty() && chunk_receiver.loading.is_empty() {
-            continue;
-        }
-
         let chunk_receiver = &mut *chunk_receiver;
 
+        let center = IVec2::new(
+            pos.coords.x.floor() as i32 >> 4,
+            pos.coords.z.floor() as i32 >> 4,
+        );
+        let yaw = rot.yaw.to_radians();
+        let look = Vec2::new(-yaw.sin(), yaw.cos());
+
         let mut dirty_chunks = Vec::new();
         let mut sent_chunks = 0;
 
@@ -63,8 +70,13 @@ pub fn handle(
 
         let mut needed_chunks: Vec<(i32, i32)> = Vec::new();
 
-        if sent_chunks < chunk_receiver.chunks_per_tick as usize {
-            // Then handle loading chunks
+        if sent_chunks < chunk_per_tick {
+            // Then handle loading chunks, the ones the player is most likely to see first
+            chunk_receiver.loading.make_contiguous().sort_by(|a, b| {
+                let a = chunk_priority(IVec2::new(a.0, a.1), center, look);
+                let b = chunk_priority(IVec2::new(b.0, b.1), center, look);
+                a.total_cmp(&b)
+            });
             while let Some(coords) = chunk_receiver.loading.pop_front() {
                 needed_chunks.push(coords);
                 sent_chunks += 1;
@@ -76,32 +88,11 @@ pub fn handle(
 
         needed_chunks.extend(dirty_chunks);
 
-        if needed_chunks.is_empty() {
-            continue;
-        };
-
-        let mut batch = state.0.thread_pool.batch();
-
-        conn.send_packet(ChunkBatchStart {})
-            .expect("Failed to send ChunkBatchStart");
-
-        let center_chunk: IVec3 = pos.coords.floor().as_ivec3() >> 4;
-
-        conn.send_packet(SetCenterChunk {
-            x: center_chunk.x.into(),
-            z: center_chunk.z.into(),
-        })
-        .expect("Failed to send SetCenterChunk");
-
         for coordinates in needed_chunks
             .into_iter()
             .filter(|coord| {
                 let chunk_pos = IVec2::new(coord.0, coord.1);
-                let player_chunk_pos = IVec2::new(
-                    pos.coords.x.floor() as i32 >> 4,
-                    pos.coords.z.floor() as i32 >> 4,
-                );
-                let distance = chunk_pos.distance_squared(player_chunk_pos);
+                let distance = chunk_pos.distance_squared(center);
                 let view_distance = max(
                     client_info.view_distance as u32,
                     get_global_config().chunk_render_distance,
@@ -113,39 +104,58 @@ pub fn handle(
             chunk_receiver
                 .loaded
                 .insert((coordinates.x(), coordinates.z()));
-            let state = state.clone();
-            let is_compressed = conn.compress.load(Ordering::Relaxed);
-            batch.execute({
-                move || {
-                    let chunk = ferrumc_utils::world::load_or_generate_chunk(
-                        &state.0,
-                        coordinates,
-                        "overworld",
-                    )
-                    .expect("Failed to load or generate chunk");
-                    let packet = ChunkAndLightData::from_chunk(coordinates, &chunk)
-                        .expect("Failed to create ChunkAndLightData");
-                    compress_packet(
-                        &packet,
-                        is_compressed,
-                        &NetEncodeOpts::WithLength,
-                        get_global_config().network_compression_threshold as usize,
-                    )
-                    .expect("Failed to compress ChunkAndLightData packet")
-                }
-            });
+            pipeline.request(
+                &state.0,
+                eid,
+                coordinates,
+                "overworld",
+                conn.compress.load(Ordering::Relaxed),
+            );
         }
-        let packets = batch.wait();
-        let packets_len = packets.len();
-        for packet in packets {
-            conn.send_raw_packet(packet)
-                .expect("Failed to send ChunkAndLightData");
+
+        // Chunks that failed to encode are sent again later, either from the next tick or once
+        // the player loads them again
+        for (coords, retry) in pipeline.take_failed(eid) {
+            let coords = (coords.x(), coords.z());
+            if chunk_receiver.loaded.remove(&coords) && retry {
+                chunk_receiver.loading.push_back(coords);
+            }
         }
 
-        conn.send_packet(ChunkBatchFinish {
-            batch_size: packets_len.into(),
-        })
-        .expect("Failed to send ChunkBatchFinish");
+        let batch_size = chunk_receiver.next_batch_size();
+        let packets = pipeline.take_ready(
+            eid,
+            batch_size,
+            // Skip chunks the player moved away from while they were being encoded
+            |coords| chunk_receiver.loaded.contains(&(coords.x(), coords.z())),
+            |coords| chunk_priority(IVec2::new(coords.x(), coords.z()), center, look),
+        );
+
+        if !packets.is_empty() {
+            chunk_receiver.batch_sent(packets.len());
+
+            conn.send_packet(ChunkBatchStart {})
+                .expect("Failed to send ChunkBatchStart");
+
+            let center_chunk: IVec3 = pos.coords.floor().as_ivec3() >> 4;
+
+            conn.send_packet(SetCenterChunk {
+                x: center_chunk.x.into(),
+                z: center_chunk.z.into(),
+            })
+            .expect("Failed to send SetCenterChunk");
+
+            let packets_len = packets.len();
+            for packet in packets {
+                conn.send_raw_packet(packet)
+                    .expect("Failed to send ChunkAndLightData");
+            }
+
+            conn.send_packet(ChunkBatchFinish {
+                batch_size: packets_len.into(),
+            })
+            .expect("Failed to send ChunkBatchFinish");
+        }
 
         // Tell the client to unload chunks that are no longer needed
 
@@ -158,4 +168,35 @@ pub fn handle(
                 .expect("Failed to send UnloadChunk packet");
         }
     }
+
+    pipeline.retain_players(|player| query.contains(player));
+}
+
+/// Chunks with a lower priority are sent first. Closer chunks come first, and at the same
+/// distance the ones in front of the player come before the ones behind them.
+fn chunk_priority(chunk: IVec2, center: IVec2, look: Vec2) -> f32 {
+    let offset = (chunk - center).as_vec2();
+    // 1 straight ahead, -1 straight behind
+    let facing = offset.normalize_or_zero().dot(look);
+    offset.length_squared() * (1.5 - facing * 0.5)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chunk_priority() {
+        let center = IVec2::new(4, 4);
+        // Looking towards positive z
+        let look = Vec2::new(0.0, 1.0);
+        let ahead = chunk_priority(IVec2::new(4, 6), center, look);
+        let behind = chunk_priority(IVec2::new(4, 2), center, look);
+        let side = chunk_priority(IVec2::new(6, 4), center, look);
+        let far_ahead = chunk_priority(IVec2::new(4, 9), center, look);
+
+        assert_eq!(chunk_priority(center, center, look), 0.0);
+        assert!(ahead < side && side < behind);
+        assert!(behind < far_ahead);
+    }
 }
@@ -13,7 +13,7 @@ pub fn handle(state: Res<GlobalStateResource>, query: Query<&ChunkReceiver>) {
             let ((pos, dim), chunk) = chunk_candidate.pair();
             removed += 1;
             // Write chunks back to the world storage
-            if chunk.sections.iter().any(|section| section.dirty) {
+            if chunk.is_dirty() {
                 state
                     .0
                     .world
@@ -58,7 +58,7 @@ pub fn handle(state: Res<GlobalStateResource>, query: Query<&ChunkReceiver>) {
             .remove(&(*chunk_pos, "overworld".to_string()));
         match removed_chunk {
             Some(((pos, dim), chunk)) => {
-                let dirty = chunk.sections.iter().any(|section| section.dirty);
+                let dirty = chunk.is_dirty();
                 if dirty {
                     state
                         .0
@@ -1,3 +1,5 @@
+use crate::systems::containers::{drop_item, leftover_inputs};
+use crate::systems::item_entities::pickup_slots;
 use bevy_ecs::prelude::{Commands, Entity, MessageWriter, Query, Res};
 use ferrumc_components::player::offline_player_data::OfflinePlayerData;
 use ferrumc_components::{
@@ -5,13 +7,14 @@ use ferrumc_components::{
     health::Health,
     player::{
         abilities::PlayerAbilities, experience::Experience, gamemode::GameModeComponent,
-        gameplay_state::ender_chest::EnderChest, hunger::Hunger,
+        gameplay_state::ender_chest::EnderChest, hunger::Hunger, respawn_point::RespawnPoint,
     },
 };
 use ferrumc_core::{
     identity::player_identity::PlayerIdentity,
     transform::{position::Position, rotation::Rotation},
 };
+use ferrumc_inventories::container::{CarriedItem, OpenContainer};
 use ferrumc_inventories::inventory::Inventory;
 use ferrumc_messages::player_leave::PlayerLeft;
 use ferrumc_net::connection::StreamWriter;
@@ -34,6 +37,8 @@ type PlayerCacheQuery<'a> = (
     &'a Experience,
     &'a EnderChest,
     &'a ActiveEffects,
+    &'a RespawnPoint,
+    (Option<&'a OpenContainer>, &'a CarriedItem),
 );
 
 // This query is a "fallback" for half-connected players
@@ -68,6 +73,8 @@ pub fn connection_killer(
             exp,
             echest,
             effects,
+            respawn_point,
+            (container, carried),
         )) = full_player_query.get(disconnecting_entity)
         {
             // --- SUCCESS: This is a fully-joined player ---
@@ -103,18 +110,34 @@ pub fn connection_killer(
                 );
             }
 
+            // Items left in an open window or on the cursor go back into the inventory
+            let mut inventory = inv.clone();
+            let leftovers = container
+                .into_iter()
+                .flat_map(leftover_inputs)
+                .chain([carried.0.clone()]);
+            for stack in leftovers.filter(|stack| stack.item_id.is_some()) {
+                let left = inventory.insert_stack(&stack, stack.max_stack_size(), pickup_slots());
+                if left > 0 {
+                    let mut dropped = stack;
+                    dropped.count.0 = left;
+                    drop_item(&mut cmd, pos, dropped);
+                }
+            }
+
             // Save data to cache
             let data_to_cache = OfflinePlayerData {
                 abilities: *abilities,
                 gamemode: gamemode.0,
                 position: (*pos).into(),
                 rotation: *rot,
-                inventory: inv.clone(),
+                inventory,
                 health: *health,
                 hunger: *hunger,
                 experience: *exp,
                 ender_chest: echest.clone(),
                 active_effects: effects.clone(),
+                respawn_point: *respawn_point,
             };
             if let Err(err) = state
                 .0
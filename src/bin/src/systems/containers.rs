@@ -0,0 +1,527 @@
+//! Enchanting tables, anvils and grindstones.
+//!
+//! Right-clicking one of these blocks gives the player an [`OpenContainer`]. Clicks in its window
+//! go through [`click`], which keeps the server's copy of the slots in sync and works out the
+//! table's offers or the anvil's and grindstone's result again whenever an input changes. Taking
+//! the result is decided by the server alone, so it can charge for it. Whatever is left in the
+//! window goes back to the player when it's closed.
+
+use crate::systems::item_entities::{pickup_slots, spawn_item};
+use bevy_ecs::prelude::{Commands, Entity, MessageReader, MessageWriter, Query, Res};
+use bevy_math::DVec3;
+use ferrumc_components::player::experience::{EnchantmentSeed, Experience};
+use ferrumc_components::player::gamemode::{GameMode, GameModeComponent};
+use ferrumc_core::transform::position::Position;
+use ferrumc_core::transform::velocity::Velocity;
+use ferrumc_data::tags::TagData;
+use ferrumc_entities::components::ItemEntity;
+use ferrumc_inventories::components::RawNbt;
+use ferrumc_inventories::container::{CarriedItem, Menu, OpenContainer};
+use ferrumc_inventories::enchanting::{
+    anvil, can_enchant_at_table, enchanting_offers, grindstone, grindstone_experience,
+};
+use ferrumc_inventories::inventory::Inventory;
+use ferrumc_inventories::item::ItemID;
+use ferrumc_inventories::slot::InventorySlot;
+use ferrumc_messages::{PlayerGainedXP, PlayerUsedContainer};
+use ferrumc_net::connection::StreamWriter;
+use ferrumc_net::packets::incoming::click_container::{ClickContainer, HashedItem};
+use ferrumc_net::packets::outgoing::close_container::CloseContainerPacket;
+use ferrumc_net::packets::outgoing::open_screen::OpenScreen;
+use ferrumc_net::packets::outgoing::set_container_content::SetContainerContent;
+use ferrumc_net::packets::outgoing::set_container_property::SetContainerProperty;
+use ferrumc_net::packets::outgoing::set_container_slot::SetContainerSlot;
+use ferrumc_net_codec::net_types::length_prefixed_vec::LengthPrefixedVec;
+use ferrumc_net_codec::net_types::prefixed_optional::PrefixedOptional;
+use ferrumc_net_codec::net_types::var_int::VarInt;
+use ferrumc_state::{GlobalState, GlobalStateResource};
+use ferrumc_text::{ComponentBuilder, TextComponent};
+use ferrumc_world::pos::BlockPos;
+use ferrumc_world::DEFAULT_DIMENSION;
+use tracing::error;
+
+/// Players further than this from the block have its window closed.
+const MAX_DISTANCE: f64 = 8.0;
+
+/// The player components opening and using a window needs.
+pub(crate) type ContainerUserQuery<'a> = (
+    &'a StreamWriter,
+    &'a mut Inventory,
+    &'a mut CarriedItem,
+    Option<&'a mut OpenContainer>,
+    &'a mut Experience,
+    &'a mut EnchantmentSeed,
+    &'a GameModeComponent,
+    &'a Position,
+);
+
+/// Opens the window of the block a player right-clicked, closing the one they had open.
+pub fn open_containers(
+    mut events: MessageReader<PlayerUsedContainer>,
+    state: Res<GlobalStateResource>,
+    mut players: Query<ContainerUserQuery>,
+    mut commands: Commands,
+) {
+    for event in events.read() {
+        let Ok((writer, mut inventory, mut carried, open, _, seed, gamemode, position)) =
+            players.get_mut(event.player)
+        else {
+            continue;
+        };
+        if gamemode.0 == GameMode::Spectator {
+            continue;
+        }
+        if let Some(open) = open {
+            let cursor = std::mem::take(&mut carried.0);
+            let stacks = leftover_inputs(&open).chain([cursor]);
+            give_back(
+                &mut commands,
+                event.player,
+                stacks,
+                &mut inventory,
+                position,
+            );
+        }
+
+        let mut menu = event.menu.clone();
+        if let Menu::EnchantingTable { bookshelves, .. } = &mut menu {
+            *bookshelves = count_bookshelves(&state.0, event.position);
+        }
+        let mut container = OpenContainer::new(event.position, menu);
+        refresh(&mut container, seed.0, gamemode.0 == GameMode::Creative);
+
+        let title = ComponentBuilder::translate(container.menu.title(), vec![]);
+        let packet = OpenScreen::new(container.window_id, container.menu.menu_type(), title);
+        if let Err(err) = writer.send_packet_ref(&packet) {
+            error!("Failed to open window for {:?}: {:?}", event.player, err);
+            continue;
+        }
+        send_contents(writer, &container, &inventory, &carried);
+        send_properties(writer, &container, seed.0);
+        commands.entity(event.player).insert(container);
+    }
+}
+
+/// Counts the bookshelves powering an enchanting table. They have to be two blocks away, at the
+/// table's height or one above, with nothing in between.
+fn count_bookshelves(state: &GlobalState, table: BlockPos) -> u32 {
+    let block_in = |pos: BlockPos, tag: &str| {
+        let Some(tag) = TagData::get_block_tag(tag) else {
+            return false;
+        };
+        state
+            .world
+            .get_loaded_block(pos, DEFAULT_DIMENSION)
+            .and_then(|block| block.name())
+            .is_some_and(|name| tag.values.contains(&name))
+    };
+
+    let mut count = 0;
+    for dx in -2..=2i32 {
+        for dz in -2..=2i32 {
+            if dx.abs() != 2 && dz.abs() != 2 {
+                continue;
+            }
+            for dy in 0..=1 {
+                let provider =
+                    block_in(table + (dx, dy, dz), "minecraft:enchantment_power_provider");
+                let transmitter = block_in(
+                    table + (dx / 2, dy / 2, dz / 2),
+                    "minecraft:enchantment_power_transmitter",
+                );
+                if provider && transmitter {
+                    count += 1;
+                }
+            }
+        }
+    }
+    count
+}
+
+/// Works out the table's offers or the result of an anvil or grindstone again, after its inputs
+/// changed.
+pub(crate) fn refresh(container: &mut OpenContainer, seed: i32, creative: bool) {
+    let OpenContainer { menu, slots, .. } = container;
+    match menu {
+        Menu::EnchantingTable {
+            bookshelves,
+            offers,
+        } => {
+            *offers = if can_enchant_at_table(&slots[0]) {
+                enchanting_offers(&slots[0], *bookshelves, seed)
+            } else {
+                Default::default()
+            };
+        }
+        Menu::Anvil { name, result } => {
+            let name = name
+                .as_deref()
+                .map(|name| RawNbt(TextComponent::from(name).serialize_nbt()));
+            *result = anvil(&slots[0], &slots[1], name.as_ref(), creative);
+            slots[2] = result
+                .as_ref()
+                .map(|result| result.output.clone())
+                .unwrap_or_default();
+        }
+        Menu::Grindstone => {
+            slots[2] = grindstone(&slots[0], &slots[1]).unwrap_or_default();
+        }
+    }
+}
+
+/// Sends every slot of the window, including the player's and the item on their cursor.
+pub(crate) fn send_contents(
+    writer: &StreamWriter,
+    container: &OpenContainer,
+    inventory: &Inventory,
+    carried: &CarriedItem,
+) {
+    let player_slots = (0..container.total_slots())
+        .filter_map(|slot| container.player_slot(slot))
+        .map(|slot| inventory.slots[slot].clone().unwrap_or_default());
+    let packet = SetContainerContent {
+        window_id: VarInt::new(container.window_id as i32),
+        state_id: VarInt::new(0),
+        slots: LengthPrefixedVec::new(
+            container
+                .slots
+                .iter()
+                .cloned()
+                .chain(player_slots)
+                .collect(),
+        ),
+        carried_item: carried.0.clone(),
+    };
+    if let Err(err) = writer.send_packet_ref(&packet) {
+        error!("Failed to send window contents: {:?}", err);
+    }
+}
+
+/// Sends the values the window shows: the level costs and hints of an enchanting table, or the
+/// cost of an anvil.
+pub(crate) fn send_properties(writer: &StreamWriter, container: &OpenContainer, seed: i32) {
+    let mut properties = Vec::new();
+    match &container.menu {
+        Menu::EnchantingTable { offers, .. } => {
+            for (i, offer) in offers.iter().enumerate() {
+                let (id, level) = offer.hint.map_or((-1, -1), |(enchantment, level)| {
+                    (enchantment.id as i16, level as i16)
+                });
+                properties.push((i as i16, offer.cost as i16));
+                properties.push((4 + i as i16, id));
+                properties.push((7 + i as i16, level));
+            }
+            // Only used for the text shown on the table
+            properties.push((3, (seed & -16) as i16));
+        }
+        Menu::Anvil { result, .. } => {
+            let cost = result.as_ref().map_or(0, |result| result.cost);
+            properties.push((0, cost as i16));
+        }
+        Menu::Grindstone => {}
+    }
+
+    for (property, value) in properties {
+        let packet = SetContainerProperty::new(container.window_id, property, value);
+        if let Err(err) = writer.send_packet_ref(&packet) {
+            error!("Failed to send window property: {:?}", err);
+        }
+    }
+}
+
+/// Sends what changes when the inputs do: the result and the window's values.
+pub(crate) fn send_refreshed(writer: &StreamWriter, container: &OpenContainer, seed: i32) {
+    if let Some(output) = container.menu.output_slot() {
+        let packet = SetContainerSlot {
+            window_id: VarInt::new(container.window_id as i32),
+            state_id: VarInt::new(0),
+            slot_index: output as i16,
+            slot: container.slots[output].clone(),
+        };
+        if let Err(err) = writer.send_packet_ref(&packet) {
+            error!("Failed to send window result: {:?}", err);
+        }
+    }
+    send_properties(writer, container, seed);
+}
+
+/// The client only sends hashes of an item's components, so the full item has to come from the
+/// server's copy of one it moved around. The ones it's most likely to be come first in `known`.
+pub(crate) fn rebuild_item(hashed: &HashedItem, known: &[InventorySlot]) -> InventorySlot {
+    let mut item = known
+        .iter()
+        .find(|slot| slot.item_id.is_some_and(|id| id.0 == hashed.item_id))
+        .cloned()
+        .unwrap_or_else(|| InventorySlot {
+            item_id: Some(ItemID(hashed.item_id)),
+            ..Default::default()
+        });
+    item.count = hashed.item_count;
+    item
+}
+
+fn window_item(container: &OpenContainer, inventory: &Inventory, slot: usize) -> InventorySlot {
+    match container.player_slot(slot) {
+        Some(slot) => inventory.slots[slot].clone().unwrap_or_default(),
+        None => container.slots[slot].clone(),
+    }
+}
+
+/// Handles a click in an open window. Clicks the server doesn't agree with, like taking an anvil's
+/// result without enough levels, are undone by sending the whole window again.
+#[expect(clippy::too_many_arguments)]
+pub(crate) fn click(
+    event: &ClickContainer,
+    player: Entity,
+    writer: &StreamWriter,
+    container: &mut OpenContainer,
+    inventory: &mut Inventory,
+    carried: &mut CarriedItem,
+    experience: &mut Experience,
+    seed: i32,
+    creative: bool,
+    xp_events: &mut MessageWriter<PlayerGainedXP>,
+) {
+    let output = container.menu.output_slot();
+    let applied = if output.is_some_and(|output| event.slot as usize == output) {
+        take_output(
+            event, player, container, inventory, carried, experience, creative, xp_events,
+        )
+    } else {
+        apply_changes(event, container, inventory, carried)
+    };
+
+    if applied {
+        refresh(container, seed, creative);
+        send_refreshed(writer, container, seed);
+    }
+    // Taking the result changes more than the client expects, so it's always sent again. The
+    // XP bar is sent again by `sync_experience`, since it was borrowed mutably.
+    if !applied || output.is_some_and(|output| event.slot as usize == output) {
+        send_contents(writer, container, inventory, carried);
+    }
+}
+
+/// Applies the slots the client says changed, with the full items from the server's copy.
+fn apply_changes(
+    event: &ClickContainer,
+    container: &mut OpenContainer,
+    inventory: &mut Inventory,
+    carried: &mut CarriedItem,
+) -> bool {
+    let output = container.menu.output_slot();
+    let mut known = vec![carried.0.clone()];
+    for changed in &event.changed_slots.data {
+        let Ok(slot) = usize::try_from(changed.number) else {
+            return false;
+        };
+        // Nothing can be put into the result slot
+        if slot >= container.total_slots() || Some(slot) == output {
+            return false;
+        }
+        known.push(window_item(container, inventory, slot));
+    }
+
+    for changed in &event.changed_slots.data {
+        let slot = changed.number as usize;
+        let new = match &changed.data {
+            PrefixedOptional::Some(hashed) => {
+                // The slot's own contents are the most likely to be the same item
+                let previous = window_item(container, inventory, slot);
+                let mut candidates = vec![previous];
+                candidates.extend(known.iter().cloned());
+                rebuild_item(hashed, &candidates)
+            }
+            PrefixedOptional::None => InventorySlot::empty(),
+        };
+        match container.player_slot(slot) {
+            Some(slot) => inventory.slots[slot] = new.item_id.is_some().then_some(new),
+            None => container.slots[slot] = new,
+        }
+    }
+    carried.0 = match &event.carried_item {
+        PrefixedOptional::Some(hashed) => rebuild_item(hashed, &known),
+        PrefixedOptional::None => InventorySlot::empty(),
+    };
+    true
+}
+
+/// Gives the player the result of an anvil or grindstone and uses up its inputs. Returns whether
+/// the player could take it.
+#[expect(clippy::too_many_arguments)]
+fn take_output(
+    event: &ClickContainer,
+    player: Entity,
+    container: &mut OpenContainer,
+    inventory: &mut Inventory,
+    carried: &mut CarriedItem,
+    experience: &mut Experience,
+    creative: bool,
+    xp_events: &mut MessageWriter<PlayerGainedXP>,
+) -> bool {
+    let Some(output) = container.menu.output_slot() else {
+        return false;
+    };
+    let result = container.slots[output].clone();
+    if result.item_id.is_none() {
+        return false;
+    }
+    if let Menu::Anvil {
+        result: Some(anvil),
+        ..
+    } = &container.menu
+    {
+        if !creative && experience.level < anvil.cost.max(0) as u32 {
+            return false;
+        }
+    }
+
+    let max_stack = result.max_stack_size();
+    match event.mode.0 {
+        // Clicking puts it on the cursor
+        0 => {
+            if carried.0.item_id.is_none() {
+                carried.0 = result;
+            } else if carried.0.stacks_with(&result)
+                && carried.0.count.0 + result.count.0 <= max_stack
+            {
+                carried.0.count.0 += result.count.0;
+            } else {
+                return false;
+            }
+        }
+        // Shift-clicking moves it into the inventory, if all of it fits
+        1 => {
+            let mut moved = inventory.clone();
+            if moved.insert_stack(&result, max_stack, pickup_slots()) > 0 {
+                return false;
+            }
+            *inventory = moved;
+        }
+        _ => return false,
+    }
+
+    match &container.menu {
+        Menu::Anvil {
+            result: Some(anvil),
+            ..
+        } => {
+            if !creative {
+                experience.add_levels(-anvil.cost.max(0));
+            }
+            let material = &mut container.slots[1];
+            match anvil.material_used {
+                Some(used) if material.count.0 > used => material.count.0 -= used,
+                _ => *material = InventorySlot::empty(),
+            }
+        }
+        Menu::Grindstone => {
+            let amount = grindstone_experience(
+                &container.slots[0],
+                &container.slots[1],
+                &mut rand::thread_rng(),
+            );
+            if amount > 0 {
+                xp_events.write(PlayerGainedXP {
+                    player,
+                    amount: amount as u32,
+                });
+            }
+            container.slots[1] = InventorySlot::empty();
+        }
+        _ => return false,
+    }
+    container.slots[0] = InventorySlot::empty();
+    container.slots[output] = InventorySlot::empty();
+    true
+}
+
+/// Closes windows whose block is gone or that the player walked away from.
+pub fn close_invalid_containers(
+    state: Res<GlobalStateResource>,
+    mut players: Query<(Entity, ContainerUserQuery)>,
+    mut commands: Commands,
+) {
+    for (player, (writer, mut inventory, mut carried, container, _, _, _, position)) in &mut players
+    {
+        let Some(container) = container else {
+            continue;
+        };
+        let block = container.position;
+        let center = DVec3::new(
+            block.pos.x as f64 + 0.5,
+            block.pos.y as f64 + 0.5,
+            block.pos.z as f64 + 0.5,
+        );
+        let still_there = state
+            .0
+            .world
+            .get_loaded_block(block, DEFAULT_DIMENSION)
+            .and_then(|block| block.name())
+            .and_then(Menu::for_block)
+            .is_some_and(|menu| menu.menu_type() == container.menu.menu_type());
+        if still_there && position.coords.distance(center) <= MAX_DISTANCE {
+            continue;
+        }
+
+        if let Err(err) = writer.send_packet_ref(&CloseContainerPacket::new(container.window_id)) {
+            error!("Failed to close window for {:?}: {:?}", player, err);
+        }
+        let cursor = std::mem::take(&mut carried.0);
+        let stacks = leftover_inputs(&container).chain([cursor]);
+        give_back(&mut commands, player, stacks, &mut inventory, position);
+        commands.entity(player).remove::<OpenContainer>();
+    }
+}
+
+/// The inputs left in a window, which go back to the player when it's closed. The result of an
+/// anvil or grindstone is just thrown away.
+pub(crate) fn leftover_inputs(
+    container: &OpenContainer,
+) -> impl Iterator<Item = InventorySlot> + '_ {
+    let output = container.menu.output_slot();
+    container
+        .slots
+        .iter()
+        .enumerate()
+        .filter(move |(slot, _)| Some(*slot) != output)
+        .map(|(_, stack)| stack.clone())
+}
+
+/// Gives items back to a player, dropping whatever doesn't fit.
+pub(crate) fn give_back(
+    commands: &mut Commands,
+    player: Entity,
+    stacks: impl IntoIterator<Item = InventorySlot>,
+    inventory: &mut Inventory,
+    position: &Position,
+) {
+    for stack in stacks {
+        if stack.item_id.is_none() || stack.count.0 <= 0 {
+            continue;
+        }
+        let left = inventory.insert_stack_with_update(
+            &stack,
+            stack.max_stack_size(),
+            pickup_slots(),
+            player,
+        );
+        if left > 0 {
+            let mut dropped = stack;
+            dropped.count = VarInt::new(left);
+            drop_item(commands, position, dropped);
+        }
+    }
+}
+
+/// Drops an item at a player's feet, for items they couldn't take back.
+pub(crate) fn drop_item(commands: &mut Commands, position: &Position, stack: InventorySlot) {
+    let at = Position::new(position.x, position.y + 0.5, position.z);
+    spawn_item(
+        commands,
+        at,
+        Velocity::new(0.0, 0.2, 0.0),
+        stack,
+        ItemEntity::THROWN_PICKUP_DELAY,
+    );
+}
@@ -1,4 +1,5 @@
-use bevy_ecs::prelude::{Commands, Entity, Query, ResMut};
+use bevy_ecs::prelude::{Commands, Entity, Query, Res, ResMut};
+use ferrumc_core::game_rules::{GameRule, GameRules};
 use ferrumc_core::time::{LastSentTimeUpdate, WorldTime};
 use ferrumc_net::connection::StreamWriter;
 use ferrumc_net::packets::outgoing::update_time::UpdateTimePacket;
@@ -6,16 +7,18 @@ use tracing::warn;
 
 pub fn tick_daylight_cycle(
     mut world_time: ResMut<WorldTime>,
+    game_rules: Res<GameRules>,
     players: Query<(Entity, &StreamWriter)>,
     mut last_sent_time: Query<&mut LastSentTimeUpdate>,
     mut commands: Commands,
 ) {
-    world_time.advance_tick();
+    let daylight_cycle = game_rules.get_bool(GameRule::DoDaylightCycle);
+    world_time.advance_tick(daylight_cycle);
 
     let packet = UpdateTimePacket {
-        world_age: 0,
+        world_age: world_time.world_age(),
         time_of_day: world_time.current_time() as _,
-        time_of_day_increasing: true,
+        time_of_day_increasing: daylight_cycle,
     };
 
     for (eid, writer) in players.iter() {
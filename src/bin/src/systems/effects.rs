@@ -0,0 +1,265 @@
+//! Status effects.
+//!
+//! Effects are added and removed through the `AddEffect` and `RemoveEffect` messages, and count
+//! down every tick until they run out. Most effects are drawn by the client and only need to be
+//! sent to it, the rest are applied here: periodic healing and damage, hunger and saturation,
+//! and the attribute modifiers of effects like speed and strength, which are synced along with
+//! the rest of the player's attributes.
+
+use bevy_ecs::prelude::{Entity, Local, MessageReader, MessageWriter, Query, With};
+use ferrumc_components::active_effects::{ActiveEffects, EffectState};
+use ferrumc_components::attributes::Attributes;
+use ferrumc_components::health::{Absorption, Health};
+use ferrumc_components::player::gamemode::{GameMode, GameModeComponent};
+use ferrumc_components::player::hunger::Hunger;
+use ferrumc_core::identity::player_identity::PlayerIdentity;
+use ferrumc_data::generated::damage_types::DamageType;
+use ferrumc_data::generated::effects::Effect;
+use ferrumc_messages::player_damage::{PlayerDamaged, PlayerDied};
+use ferrumc_messages::{AddEffect, PlayerJoined, RemoveEffect};
+use ferrumc_net::broadcast::broadcast_packet_all;
+use ferrumc_net::connection::StreamWriter;
+use ferrumc_net::packets::outgoing::remove_mob_effect::RemoveMobEffectPacket;
+use ferrumc_net::packets::outgoing::set_health::SetHealth;
+use ferrumc_net::packets::outgoing::update_mob_effect::UpdateMobEffectPacket;
+use tracing::error;
+
+/// The most exhaustion a player can build up, the same as vanilla.
+const MAX_EXHAUSTION: f32 = 40.0;
+
+type EffectTargetQuery<'a> = (
+    &'a PlayerIdentity,
+    &'a mut ActiveEffects,
+    &'a mut Attributes,
+    &'a mut Health,
+    &'a mut Absorption,
+    &'a mut Hunger,
+    &'a GameModeComponent,
+    &'a StreamWriter,
+);
+
+/// Applies added and removed effects and sends them to the players.
+pub fn apply_effects(
+    mut added: MessageReader<AddEffect>,
+    mut removed: MessageReader<RemoveEffect>,
+    mut targets: Query<EffectTargetQuery>,
+    writers: Query<(Entity, &StreamWriter), With<PlayerIdentity>>,
+    mut damage_msgs: MessageWriter<PlayerDamaged>,
+) {
+    for event in added.read() {
+        let Ok((
+            identity,
+            mut effects,
+            mut attributes,
+            mut health,
+            mut absorption,
+            hunger,
+            _,
+            writer,
+        )) = targets.get_mut(event.entity)
+        else {
+            continue;
+        };
+
+        match event.effect {
+            Effect::InstantHealth => {
+                heal(
+                    &mut health,
+                    &hunger,
+                    writer,
+                    (4 << event.state.amplifier.min(29)) as f32,
+                );
+            }
+            Effect::InstantDamage => {
+                damage_msgs.write(PlayerDamaged::new(
+                    event.entity,
+                    (6 << event.state.amplifier.min(28)) as f32,
+                    DamageType::Magic,
+                ));
+            }
+            effect => {
+                if effects.add(effect, event.state) {
+                    broadcast_packet_all(
+                        &effect_packet(identity.short_uuid, effect, &event.state),
+                        writers.iter(),
+                    );
+                    attributes.add_effect_modifiers(effect, event.state.amplifier);
+                    if effect == Effect::Absorption {
+                        let amount = (event.state.amplifier as f32 + 1.0) * 4.0;
+                        absorption.amount = absorption.amount.max(amount);
+                    }
+                }
+            }
+        }
+    }
+
+    for event in removed.read() {
+        let Ok((identity, mut effects, mut attributes, ..)) = targets.get_mut(event.entity) else {
+            continue;
+        };
+        let removed = match event.effect {
+            Some(effect) => effects
+                .remove(effect)
+                .map(|_| vec![effect])
+                .unwrap_or_default(),
+            None => effects.clear(),
+        };
+        send_removed(identity.short_uuid, &mut attributes, &removed, &writers);
+    }
+}
+
+/// Counts down every active effect and applies the ones that do something each tick.
+pub fn tick_effects(
+    mut targets: Query<(Entity, EffectTargetQuery)>,
+    writers: Query<(Entity, &StreamWriter), With<PlayerIdentity>>,
+    mut damage_msgs: MessageWriter<PlayerDamaged>,
+    mut ticks: Local<u64>,
+) {
+    *ticks += 1;
+
+    for (
+        entity,
+        (identity, mut effects, mut attributes, mut health, _, mut hunger, gamemode, writer),
+    ) in targets.iter_mut()
+    {
+        if effects.is_empty() {
+            continue;
+        }
+        let invulnerable = matches!(gamemode.0, GameMode::Creative | GameMode::Spectator);
+
+        for (effect, state) in effects.iter() {
+            let amplifier = state.amplifier as u32;
+            // Infinite effects have no duration to count from, so use the server's ticks
+            let elapsed = if state.is_infinite() {
+                *ticks
+            } else {
+                state.duration_ticks as u64
+            };
+            let every = |interval: u32| {
+                let interval = interval.checked_shr(amplifier).unwrap_or(0);
+                interval == 0 || elapsed % interval as u64 == 0
+            };
+
+            match effect {
+                Effect::Regeneration if every(50) && health.current < health.max => {
+                    heal(&mut health, &hunger, writer, 1.0);
+                }
+                Effect::Poison if every(25) && health.current > 1.0 && !invulnerable => {
+                    damage_msgs.write(PlayerDamaged::new(entity, 1.0, DamageType::Magic));
+                }
+                Effect::Wither if every(40) && !invulnerable => {
+                    damage_msgs.write(PlayerDamaged::new(entity, 1.0, DamageType::Wither));
+                }
+                Effect::Hunger => {
+                    hunger.exhaustion =
+                        (hunger.exhaustion + 0.005 * (amplifier + 1) as f32).min(MAX_EXHAUSTION);
+                }
+                Effect::Saturation if hunger.saturation < 20.0 || hunger.level < 20 => {
+                    let amount = amplifier.saturating_add(1).min(20) as u8;
+                    hunger.level = (hunger.level + amount).min(20);
+                    hunger.saturation =
+                        (hunger.saturation + amount as f32 * 2.0).min(hunger.level as f32);
+                    send_health(&health, &hunger, writer);
+                }
+                _ => {}
+            }
+        }
+
+        let expired = effects.tick();
+        // Only touch the attributes when something ran out, so they aren't synced every tick
+        if !expired.is_empty() {
+            send_removed(identity.short_uuid, &mut attributes, &expired, &writers);
+        }
+    }
+}
+
+/// Sends players their effects when they join, and gives them back their modifiers.
+pub fn send_effects_to_joining_players(
+    mut events: MessageReader<PlayerJoined>,
+    mut players: Query<(
+        &PlayerIdentity,
+        &ActiveEffects,
+        &mut Attributes,
+        &StreamWriter,
+    )>,
+) {
+    for event in events.read() {
+        let Ok((identity, effects, mut attributes, writer)) = players.get_mut(event.entity) else {
+            continue;
+        };
+
+        for (effect, state) in effects.iter() {
+            attributes.add_effect_modifiers(effect, state.amplifier);
+            if let Err(err) =
+                writer.send_packet_ref(&effect_packet(identity.short_uuid, effect, state))
+            {
+                error!("Failed to send effects to {:?}: {:?}", event.entity, err);
+            }
+        }
+    }
+}
+
+/// Players lose all their effects when they die.
+pub fn clear_effects_on_death(
+    mut events: MessageReader<PlayerDied>,
+    mut players: Query<(&PlayerIdentity, &mut ActiveEffects, &mut Attributes)>,
+    writers: Query<(Entity, &StreamWriter), With<PlayerIdentity>>,
+) {
+    for event in events.read() {
+        let Ok((identity, mut effects, mut attributes)) = players.get_mut(event.player) else {
+            continue;
+        };
+        let removed = effects.clear();
+        send_removed(identity.short_uuid, &mut attributes, &removed, &writers);
+    }
+}
+
+fn effect_packet(entity_id: i32, effect: Effect, state: &EffectState) -> UpdateMobEffectPacket {
+    let mut flags = 0;
+    if state.ambient {
+        flags |= UpdateMobEffectPacket::FLAG_AMBIENT;
+    }
+    if state.show_particles {
+        flags |= UpdateMobEffectPacket::FLAG_SHOW_PARTICLES;
+    }
+    if state.show_icon {
+        flags |= UpdateMobEffectPacket::FLAG_SHOW_ICON;
+    }
+    UpdateMobEffectPacket::new(
+        entity_id,
+        effect.id(),
+        state.amplifier,
+        state.duration_ticks,
+        flags,
+    )
+}
+
+pub(crate) fn send_removed(
+    entity_id: i32,
+    attributes: &mut Attributes,
+    removed: &[Effect],
+    writers: &Query<(Entity, &StreamWriter), With<PlayerIdentity>>,
+) {
+    for &effect in removed {
+        attributes.remove_effect_modifiers(effect);
+        broadcast_packet_all(
+            &RemoveMobEffectPacket::new(entity_id, effect.id()),
+            writers.iter(),
+        );
+    }
+}
+
+fn heal(health: &mut Health, hunger: &Hunger, writer: &StreamWriter, amount: f32) {
+    if health.current <= 0.0 || health.current >= health.max {
+        return;
+    }
+    health.current = (health.current + amount).min(health.max);
+    send_health(health, hunger, writer);
+}
+
+pub(crate) fn send_health(health: &Health, hunger: &Hunger, writer: &StreamWriter) {
+    let packet = SetHealth::new(health.current, hunger.level as i32, hunger.saturation);
+    if let Err(err) = writer.send_packet_ref(&packet) {
+        error!("Failed to send health: {:?}", err);
+    }
+}
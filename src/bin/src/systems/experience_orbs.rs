@@ -0,0 +1,371 @@
+//! Experience orbs.
+//!
+//! Mobs killed by players, mined ores and dying players drop their XP as orbs. Orbs fly towards
+//! the closest player within eight blocks, merge with orbs of the same value they touch, and give
+//! their XP to players who walk into them, repairing items with mending first. Like items, they
+//! despawn after five minutes.
+
+use crate::systems::item_entities::{PICKUP_HEIGHT, PICKUP_REACH};
+use bevy_ecs::prelude::{Commands, Component, Entity, MessageReader, MessageWriter, Query, With};
+use bevy_ecs::world::World;
+use bevy_math::{DVec3, Vec3A};
+use ferrumc_components::player::gamemode::{GameMode, GameModeComponent};
+use ferrumc_core::identity::entity_identity::EntityIdentity;
+use ferrumc_core::identity::player_identity::PlayerIdentity;
+use ferrumc_core::transform::grounded::OnGround;
+use ferrumc_core::transform::position::Position;
+use ferrumc_core::transform::rotation::Rotation;
+use ferrumc_core::transform::velocity::Velocity;
+use ferrumc_data::generated::entities::{EntityType as VanillaEntityType, MobCategory};
+use ferrumc_entities::bundles::ExperienceOrbBundle;
+use ferrumc_entities::components::ExperienceOrb;
+use ferrumc_entities::markers::{HasCollisions, HasGravity};
+use ferrumc_inventories::defined_slots::player;
+use ferrumc_inventories::enchanting::{can_mend, mend_item};
+use ferrumc_inventories::hotbar::Hotbar;
+use ferrumc_inventories::inventory::Inventory;
+use ferrumc_messages::{PlayerGainedXP, PlayerJoined};
+use ferrumc_net::broadcast::broadcast_packet_all;
+use ferrumc_net::connection::StreamWriter;
+use ferrumc_net::packets::outgoing::entity_metadata::{EntityMetadata, EntityMetadataPacket};
+use ferrumc_net::packets::outgoing::remove_entities::RemoveEntitiesPacket;
+use ferrumc_net::packets::outgoing::spawn_entity::SpawnEntityPacket;
+use ferrumc_net::packets::outgoing::take_item_entity::TakeItemEntityPacket;
+use ferrumc_net_codec::net_types::var_int::VarInt;
+use rand::Rng;
+use tracing::error;
+
+/// Orbs closer than this (in blocks, on each axis) merge into one.
+const MERGE_RADIUS: f64 = 0.5;
+
+/// How close a player has to be for orbs to fly towards them.
+const FOLLOW_RANGE: f64 = 8.0;
+
+/// Orbs fly towards the middle of a player rather than their feet.
+const FOLLOW_HEIGHT: f64 = 0.81;
+
+/// Ticks a player has to wait between picking up orbs, the same as vanilla.
+const PICKUP_COOLDOWN: u32 = 2;
+
+/// Ticks until a player can pick up another orb.
+#[derive(Component)]
+pub struct OrbPickupCooldown(pub u32);
+
+/// Spawns orbs worth `amount` XP at a position and shows them to every connected player.
+pub fn spawn_orbs(commands: &mut Commands, position: Position, amount: u32) {
+    let mut rng = rand::thread_rng();
+    for value in ExperienceOrb::split(amount) {
+        let velocity = Velocity::new(
+            rng.gen_range(-0.2..0.2),
+            rng.gen_range(0.0..0.4),
+            rng.gen_range(-0.2..0.2),
+        );
+        let bundle = ExperienceOrbBundle::new(position, velocity, value);
+        let spawn_packet = SpawnEntityPacket::new(
+            bundle.identity.entity_id,
+            bundle.identity.uuid.as_u128(),
+            VanillaEntityType::EXPERIENCE_ORB.id as i32,
+            &position,
+            &Rotation::default(),
+        )
+        .with_velocity(&velocity);
+        let metadata_packet = EntityMetadataPacket::new(
+            VarInt::new(bundle.identity.entity_id),
+            [EntityMetadata::experience_orb_value(value)],
+        );
+
+        commands.spawn((bundle, HasGravity, HasCollisions));
+        commands.queue(move |world: &mut World| {
+            let mut writers = world.query::<(Entity, &StreamWriter)>();
+            broadcast_packet_all(&spawn_packet, writers.iter(world));
+            broadcast_packet_all(&metadata_packet, writers.iter(world));
+        });
+    }
+}
+
+/// The XP a block drops when it's mined without silk touch, like ores.
+pub fn block_experience(block: &str, rng: &mut impl Rng) -> u32 {
+    match block.strip_prefix("minecraft:").unwrap_or(block) {
+        "coal_ore" | "deepslate_coal_ore" => rng.gen_range(0..=2),
+        "diamond_ore" | "deepslate_diamond_ore" | "emerald_ore" | "deepslate_emerald_ore" => {
+            rng.gen_range(3..=7)
+        }
+        "lapis_ore" | "deepslate_lapis_ore" | "nether_quartz_ore" => rng.gen_range(2..=5),
+        "redstone_ore" | "deepslate_redstone_ore" => rng.gen_range(1..=5),
+        "nether_gold_ore" => rng.gen_range(0..=1),
+        "spawner" => rng.gen_range(15..=43),
+        "sculk" => 1,
+        "sculk_catalyst" | "sculk_shrieker" | "sculk_sensor" | "calibrated_sculk_sensor" => 5,
+        _ => 0,
+    }
+}
+
+/// The XP a mob drops when a player kills it. Monsters drop more than animals, baby animals drop
+/// nothing and baby monsters drop more.
+pub fn mob_experience(mob: &VanillaEntityType, baby: bool, rng: &mut impl Rng) -> u32 {
+    let experience = match mob.resource_name {
+        "ender_dragon" => 500,
+        "wither" => 50,
+        "piglin_brute" | "ravager" => 20,
+        "blaze" | "breeze" | "elder_guardian" | "evoker" | "guardian" => 10,
+        "endermite" | "vex" => 3,
+        _ => match mob.category {
+            MobCategory::MONSTER => 5,
+            MobCategory::CREATURE
+            | MobCategory::AXOLOTLS
+            | MobCategory::UNDERGROUND_WATER_CREATURE
+            | MobCategory::WATER_CREATURE
+            | MobCategory::WATER_AMBIENT => rng.gen_range(1..=3),
+            MobCategory::AMBIENT | MobCategory::MISC => 0,
+        },
+    };
+    match (baby, mob.category) {
+        (false, _) => experience,
+        (true, MobCategory::MONSTER) => experience * 5 / 2,
+        (true, _) => 0,
+    }
+}
+
+/// Ages orbs, moves them towards nearby players and despawns the ones that have been around too
+/// long.
+pub fn tick_orbs(
+    mut orbs: Query<(
+        Entity,
+        &EntityIdentity,
+        &mut ExperienceOrb,
+        &Position,
+        &mut Velocity,
+        &OnGround,
+    )>,
+    players: Query<(&Position, &GameModeComponent), With<PlayerIdentity>>,
+    writers: Query<(Entity, &StreamWriter)>,
+    mut commands: Commands,
+) {
+    let mut despawned = Vec::new();
+    for (entity, identity, mut orb, pos, mut vel, grounded) in orbs.iter_mut() {
+        orb.age += 1;
+        if orb.age >= ExperienceOrb::DESPAWN_AGE {
+            commands.entity(entity).despawn();
+            despawned.push(identity.entity_id);
+            continue;
+        }
+
+        let closest = players
+            .iter()
+            .filter(|(_, gamemode)| gamemode.0 != GameMode::Spectator)
+            .map(|(player, _)| player.coords + DVec3::new(0.0, FOLLOW_HEIGHT, 0.0) - pos.coords)
+            .filter(|offset| offset.length_squared() < FOLLOW_RANGE * FOLLOW_RANGE)
+            .min_by(|a, b| a.length_squared().total_cmp(&b.length_squared()));
+        if let Some(offset) = closest {
+            // Orbs speed up the closer they get, the same as vanilla
+            let pull = 1.0 - offset.length() / FOLLOW_RANGE;
+            **vel += (offset.normalize_or_zero() * pull * pull * 0.1).as_vec3a();
+        }
+
+        // Like items, only touch the velocity when it's non-zero, so resting orbs aren't marked
+        // as changed
+        if **vel != Vec3A::ZERO {
+            let friction = if grounded.0 { 0.6 * 0.98 } else { 0.98 };
+            **vel *= Vec3A::new(friction, 0.98, friction);
+            if vel.length_squared() < 1.0e-6 {
+                **vel = Vec3A::ZERO;
+            }
+        }
+    }
+
+    if !despawned.is_empty() {
+        broadcast_packet_all(&RemoveEntitiesPacket::from_ids(despawned), writers.iter());
+    }
+}
+
+/// Combines orbs of the same value lying next to each other.
+pub fn merge_orbs(
+    mut orbs: Query<(Entity, &EntityIdentity, &Position, &mut ExperienceOrb)>,
+    writers: Query<(Entity, &StreamWriter)>,
+    mut commands: Commands,
+) {
+    let snapshot: Vec<_> = orbs
+        .iter()
+        .map(|(entity, _, pos, orb)| (entity, *pos, orb.value, orb.count))
+        .collect();
+    if snapshot.len() < 2 {
+        return;
+    }
+
+    let mut merged = vec![false; snapshot.len()];
+    let mut despawned = Vec::new();
+    for (i, (target, target_pos, value, count)) in snapshot.iter().enumerate() {
+        if merged[i] {
+            continue;
+        }
+        let mut total = *count;
+        for (j, (source, source_pos, source_value, source_count)) in
+            snapshot.iter().enumerate().skip(i + 1)
+        {
+            if merged[j] || source_value != value {
+                continue;
+            }
+            let offset = (target_pos.coords - source_pos.coords).abs();
+            if offset.max_element() > MERGE_RADIUS {
+                continue;
+            }
+
+            total += source_count;
+            merged[j] = true;
+            commands.entity(*source).despawn();
+            if let Ok((_, identity, _, _)) = orbs.get(*source) {
+                despawned.push(identity.entity_id);
+            }
+        }
+
+        if total != *count {
+            if let Ok((_, _, _, mut orb)) = orbs.get_mut(*target) {
+                orb.count = total;
+            }
+        }
+    }
+
+    if !despawned.is_empty() {
+        broadcast_packet_all(&RemoveEntitiesPacket::from_ids(despawned), writers.iter());
+    }
+}
+
+type CollectorQuery<'a> = (
+    Entity,
+    &'a PlayerIdentity,
+    &'a Position,
+    &'a GameModeComponent,
+    &'a mut Inventory,
+    &'a Hotbar,
+    Option<&'a mut OrbPickupCooldown>,
+);
+
+/// Gives players the XP of orbs they're standing on, one orb at a time.
+pub fn pickup_orbs(
+    mut orbs: Query<(Entity, &EntityIdentity, &Position, &mut ExperienceOrb)>,
+    mut collectors: Query<CollectorQuery>,
+    writers: Query<(Entity, &StreamWriter)>,
+    mut xp_events: MessageWriter<PlayerGainedXP>,
+    mut commands: Commands,
+) {
+    for (.., cooldown) in collectors.iter_mut() {
+        if let Some(mut cooldown) = cooldown.filter(|cooldown| cooldown.0 > 0) {
+            cooldown.0 -= 1;
+        }
+    }
+
+    let mut despawned = Vec::new();
+    for (orb_entity, identity, orb_pos, mut orb) in orbs.iter_mut() {
+        for (player, player_identity, player_pos, gamemode, mut inventory, hotbar, cooldown) in
+            collectors.iter_mut()
+        {
+            if gamemode.0 == GameMode::Spectator || cooldown.as_ref().is_some_and(|c| c.0 > 0) {
+                continue;
+            }
+            let offset = orb_pos.coords - player_pos.coords;
+            if offset.x.abs() > PICKUP_REACH
+                || offset.z.abs() > PICKUP_REACH
+                || !PICKUP_HEIGHT.contains(&offset.y)
+            {
+                continue;
+            }
+
+            match cooldown {
+                Some(mut cooldown) => cooldown.0 = PICKUP_COOLDOWN,
+                None => {
+                    commands
+                        .entity(player)
+                        .insert(OrbPickupCooldown(PICKUP_COOLDOWN));
+                }
+            }
+            let packet =
+                TakeItemEntityPacket::new(identity.entity_id, player_identity.short_uuid, 1);
+            broadcast_packet_all(&packet, writers.iter());
+
+            let left = mend_items(&mut inventory, hotbar, player, orb.value);
+            if left > 0 {
+                xp_events.write(PlayerGainedXP {
+                    player,
+                    amount: left,
+                });
+            }
+
+            orb.count -= 1;
+            if orb.count == 0 {
+                commands.entity(orb_entity).despawn();
+                despawned.push(identity.entity_id);
+            }
+            break;
+        }
+    }
+
+    if !despawned.is_empty() {
+        broadcast_packet_all(&RemoveEntitiesPacket::from_ids(despawned), writers.iter());
+    }
+}
+
+/// Repairs the damaged mending items a player has equipped or is holding with some XP, picking
+/// them at random like vanilla. Returns the XP that's left over.
+fn mend_items(inventory: &mut Inventory, hotbar: &Hotbar, entity: Entity, mut xp: u32) -> u32 {
+    let mut rng = rand::thread_rng();
+    let mut slots = vec![
+        hotbar.get_selected_inventory_index(),
+        player::OFFHAND_SLOT as usize,
+        player::HEAD_SLOT as usize,
+        player::CHEST_SLOT as usize,
+        player::LEGS_SLOT as usize,
+        player::FEET_SLOT as usize,
+    ];
+    slots.retain(|slot| matches!(inventory.get_item(*slot), Ok(Some(stack)) if can_mend(stack)));
+
+    while xp > 0 && !slots.is_empty() {
+        let slot = slots.swap_remove(rng.gen_range(0..slots.len()));
+        let Ok(Some(stack)) = inventory.get_item(slot) else {
+            continue;
+        };
+        let mut stack = stack.clone();
+        xp = mend_item(&mut stack, xp);
+        if let Err(err) = inventory.set_item_with_update(slot, stack, entity) {
+            error!("Failed to mend item of {:?}: {:?}", entity, err);
+        }
+    }
+    xp
+}
+
+/// Shows the orbs already lying around to players that just joined.
+pub fn send_orbs_to_joining_players(
+    mut events: MessageReader<PlayerJoined>,
+    orbs: Query<(&EntityIdentity, &Position, &Velocity, &ExperienceOrb)>,
+    writers: Query<&StreamWriter, With<PlayerIdentity>>,
+) {
+    for event in events.read() {
+        let Ok(writer) = writers.get(event.entity) else {
+            continue;
+        };
+        for (identity, pos, vel, orb) in orbs.iter() {
+            let spawn_packet = SpawnEntityPacket::new(
+                identity.entity_id,
+                identity.uuid.as_u128(),
+                VanillaEntityType::EXPERIENCE_ORB.id as i32,
+                pos,
+                &Rotation::default(),
+            )
+            .with_velocity(vel);
+            let metadata_packet = EntityMetadataPacket::new(
+                VarInt::new(identity.entity_id),
+                [EntityMetadata::experience_orb_value(orb.value)],
+            );
+            if let Err(err) = writer.send_packet_ref(&spawn_packet) {
+                error!("Failed to send orb spawn to {:?}: {:?}", event.entity, err);
+                break;
+            }
+            if let Err(err) = writer.send_packet_ref(&metadata_packet) {
+                error!(
+                    "Failed to send orb metadata to {:?}: {:?}",
+                    event.entity, err
+                );
+                break;
+            }
+        }
+    }
+}
@@ -0,0 +1,757 @@
+//! Explosions, primed TNT and creepers.
+//!
+//! Explosions work like vanilla: rays are cast from the center in every direction, losing
+//! strength to the blast resistance of the blocks they pass through, and destroy every block they
+//! still have strength left for. Entities are hurt and pushed away depending on how close they are
+//! and how much of them the explosion can see.
+
+use crate::systems::block_ticks::broadcast_block_changes;
+use crate::systems::item_entities::drop_block_items;
+use crate::systems::physics::collisions::is_solid_block;
+use bevy_ecs::prelude::{
+    Commands, Entity, Has, MessageReader, MessageWriter, Query, Res, With, Without, World,
+};
+use bevy_math::{DVec3, IVec3, Vec3A};
+use ferrumc_components::attributes::Attributes;
+use ferrumc_components::player::abilities::PlayerAbilities;
+use ferrumc_components::player::gamemode::{GameMode, GameModeComponent};
+use ferrumc_core::game_rules::{GameRule, GameRules};
+use ferrumc_core::identity::entity_identity::EntityIdentity;
+use ferrumc_core::identity::player_identity::PlayerIdentity;
+use ferrumc_core::sound::SoundCategory;
+use ferrumc_core::transform::grounded::OnGround;
+use ferrumc_core::transform::position::Position;
+use ferrumc_core::transform::rotation::Rotation;
+use ferrumc_core::transform::velocity::Velocity;
+use ferrumc_data::blocks::types::Block;
+use ferrumc_data::generated::attributes::Attribute;
+use ferrumc_data::generated::damage_types::DamageType;
+use ferrumc_data::generated::entities::EntityType as VanillaEntityType;
+use ferrumc_data::generated::sounds::Sound;
+use ferrumc_entities::bundles::PrimedTntBundle;
+use ferrumc_entities::collision::raycast_blocks;
+use ferrumc_entities::components::{
+    BoundingBox, CombatProperties, CreeperFuse, EntityMetadata, ExperienceOrb, ItemEntity,
+    PhysicalRegistry, PrimedTnt, Projectile,
+};
+use ferrumc_entities::markers::entity_types::Creeper;
+use ferrumc_entities::markers::{HasCollisions, HasGravity};
+use ferrumc_loot::LootContext;
+use ferrumc_macros::match_block;
+use ferrumc_messages::{
+    BlockBrokenEvent, BlockInteraction, EntityDamaged, Explosion, PlaySound, PlayerDamaged,
+    PlayerJoined, PrimeTnt,
+};
+use ferrumc_net::broadcast::broadcast_packet_all;
+use ferrumc_net::connection::StreamWriter;
+use ferrumc_net::packets::outgoing::entity_metadata::{
+    EntityMetadata as Metadata, EntityMetadataPacket,
+};
+use ferrumc_net::packets::outgoing::explode::{ExplodePacket, Knockback};
+use ferrumc_net::packets::outgoing::remove_entities::RemoveEntitiesPacket;
+use ferrumc_net::packets::outgoing::spawn_entity::SpawnEntityPacket;
+use ferrumc_net_codec::net_types::var_int::VarInt;
+use ferrumc_particles::ParticleType;
+use ferrumc_state::{GlobalState, GlobalStateResource};
+use ferrumc_world::block_state_id::BlockStateId;
+use ferrumc_world::pos::BlockPos;
+use ferrumc_world::DEFAULT_DIMENSION;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::{HashMap, HashSet};
+use std::f64::consts::TAU;
+use tracing::error;
+
+/// How far apart the points along each ray are checked.
+const RAY_STEP: f32 = 0.3;
+
+/// How much strength a ray loses with every step, even through air.
+const RAY_DECAY: f32 = 0.225_000_01;
+
+/// Water and lava stop almost every explosion, so explosions under water don't break blocks.
+const FLUID_BLAST_RESISTANCE: f32 = 100.0;
+
+/// Players further away than this don't see or hear the explosion.
+const EXPLOSION_VIEW_DISTANCE: f64 = 64.0;
+
+/// Items and experience orbs are destroyed by explosions that deal at least this much damage.
+const ITEM_HEALTH: f32 = 5.0;
+
+/// Creepers start swelling when a player gets this close...
+const CREEPER_SWELL_DISTANCE: f64 = 3.0;
+
+/// ...and only calm down again once they're this far away.
+const CREEPER_CALM_DISTANCE: f64 = 7.0;
+
+/// The blocks an explosion at `center` destroys, in no particular order.
+///
+/// `resistance` gives the blast resistance of the block at a position, or `None` if there's
+/// nothing there for the explosion to destroy.
+pub fn blocks_in_blast(
+    center: DVec3,
+    radius: f32,
+    rng: &mut impl Rng,
+    mut resistance: impl FnMut(IVec3) -> Option<f32>,
+) -> Vec<IVec3> {
+    let mut destroyed = HashSet::new();
+    // Rays go out through every point on the surface of a 16x16x16 cube
+    for x in 0..16 {
+        for y in 0..16 {
+            for z in 0..16 {
+                if ![x, y, z].iter().any(|&i| i == 0 || i == 15) {
+                    continue;
+                }
+                let direction = (DVec3::new(x as f64, y as f64, z as f64) / 15.0 * 2.0
+                    - DVec3::ONE)
+                    .normalize();
+                let mut strength = radius * (0.7 + rng.gen::<f32>() * 0.6);
+                let mut point = center;
+                while strength > 0.0 {
+                    let block = point.floor().as_ivec3();
+                    if let Some(resistance) = resistance(block) {
+                        strength -= (resistance + 0.3) * 0.3;
+                        if strength > 0.0 {
+                            destroyed.insert(block);
+                        }
+                    }
+                    point += direction * RAY_STEP as f64;
+                    strength -= RAY_DECAY;
+                }
+            }
+        }
+    }
+    destroyed.into_iter().collect()
+}
+
+/// How much of the box from `min` to `max` can be seen from `center`, from 0 to 1, the same as
+/// vanilla. Points spread through the box are checked for blocks between them and the center.
+pub fn exposure(
+    center: DVec3,
+    min: DVec3,
+    max: DVec3,
+    mut is_solid: impl FnMut(IVec3) -> bool,
+) -> f32 {
+    let size = max - min;
+    let step = DVec3::ONE / (size * 2.0 + DVec3::ONE);
+    // Centers the grid of points on the box horizontally
+    let offset_x = (1.0 - (1.0 / step.x).floor() * step.x) / 2.0;
+    let offset_z = (1.0 - (1.0 / step.z).floor() * step.z) / 2.0;
+
+    let mut seen = 0;
+    let mut total = 0;
+    let mut x = 0.0;
+    while x <= 1.0 {
+        let mut y = 0.0;
+        while y <= 1.0 {
+            let mut z = 0.0;
+            while z <= 1.0 {
+                let point = min + size * DVec3::new(x, y, z) + DVec3::new(offset_x, 0.0, offset_z);
+                if raycast_blocks(point, center, &mut is_solid).is_none() {
+                    seen += 1;
+                }
+                total += 1;
+                z += step.z;
+            }
+            y += step.y;
+        }
+        x += step.x;
+    }
+    if total == 0 {
+        return 0.0;
+    }
+    seen as f32 / total as f32
+}
+
+/// The damage an explosion deals to an entity, given how far away the entity is compared to the
+/// explosion's reach of twice its radius and how much of the entity it can see.
+pub fn explosion_damage(radius: f32, distance: f64, exposure: f32) -> f32 {
+    let reach = radius as f64 * 2.0;
+    let impact = (1.0 - distance / reach) * exposure as f64;
+    ((impact * impact + impact) / 2.0 * 7.0 * reach + 1.0) as f32
+}
+
+/// How much the block slows down an explosion, or `None` for air.
+fn blast_resistance(block: BlockStateId) -> Option<f32> {
+    let name = block.name()?.trim_start_matches("minecraft:");
+    if match_block!("air", block) || match_block!("cave_air", block) {
+        return None;
+    }
+    let mut resistance = Block::by_name(name).map_or(0.0, |block| block.blast_resistance);
+    if block.property("waterlogged") == Some("true") {
+        resistance = resistance.max(FLUID_BLAST_RESISTANCE);
+    }
+    Some(resistance)
+}
+
+fn block_center(pos: IVec3) -> Position {
+    Position::new(pos.x as f64 + 0.5, pos.y as f64, pos.z as f64 + 0.5)
+}
+
+/// Spawns a lit block of TNT and shows it to every connected player. It hops up a little in a
+/// random direction, like vanilla.
+pub fn spawn_primed_tnt(commands: &mut Commands, position: Position, tnt: PrimedTnt) {
+    let angle = rand::thread_rng().gen::<f64>() * TAU;
+    let velocity = Velocity::new(-angle.sin() * 0.02, 0.2, -angle.cos() * 0.02);
+    let fuse = tnt.fuse;
+    let bundle = PrimedTntBundle::new(tnt, position, velocity);
+    let spawn_packet = SpawnEntityPacket::new(
+        bundle.identity.entity_id,
+        bundle.identity.uuid.as_u128(),
+        VanillaEntityType::TNT.id as i32,
+        &position,
+        &Rotation::default(),
+    )
+    .with_velocity(&velocity);
+    let metadata_packet = EntityMetadataPacket::new(
+        VarInt::new(bundle.identity.entity_id),
+        [Metadata::primed_tnt_fuse(fuse)],
+    );
+
+    commands.spawn((bundle, HasGravity, HasCollisions));
+    commands.queue(move |world: &mut World| {
+        let mut writers = world.query::<(Entity, &StreamWriter)>();
+        broadcast_packet_all(&spawn_packet, writers.iter(world));
+        broadcast_packet_all(&metadata_packet, writers.iter(world));
+    });
+}
+
+/// Replaces TNT blocks that were lit with primed TNT.
+pub fn prime_tnt_blocks(
+    mut events: MessageReader<PrimeTnt>,
+    state: Res<GlobalStateResource>,
+    game_rules: Res<GameRules>,
+    players: Query<(Entity, &StreamWriter, &Position)>,
+    mut broken: MessageWriter<BlockBrokenEvent>,
+    mut sounds: MessageWriter<PlaySound>,
+    mut commands: Commands,
+) {
+    if !game_rules.get_bool(GameRule::TntExplodes) {
+        events.clear();
+        return;
+    }
+    let mut changes = Vec::new();
+    for event in events.read() {
+        // It may have been lit twice, or broken in the meantime
+        let is_tnt = state
+            .0
+            .world
+            .get_loaded_block(event.position, DEFAULT_DIMENSION)
+            .is_some_and(|block| match_block!("tnt", block));
+        if !is_tnt {
+            continue;
+        }
+        if let Err(err) = state.0.world.set_block_and_fetch(
+            event.position,
+            DEFAULT_DIMENSION,
+            BlockStateId::default(),
+        ) {
+            error!("Failed to remove lit TNT at {}: {:?}", event.position, err);
+            continue;
+        }
+        changes.push((event.position, BlockStateId::default()));
+        broken.write(BlockBrokenEvent {
+            position: event.position,
+        });
+        let position = block_center(event.position.pos);
+        sounds.write(PlaySound::at(
+            Sound::EntityTntPrimed,
+            SoundCategory::Blocks,
+            position.coords,
+        ));
+        spawn_primed_tnt(
+            &mut commands,
+            position,
+            PrimedTnt::new(PrimedTnt::DEFAULT_FUSE, event.igniter),
+        );
+    }
+    broadcast_block_changes(&state.0, &changes, &players);
+}
+
+/// Counts down primed TNT and blows it up once its fuse runs out.
+pub fn tick_tnt(
+    mut tnt: Query<(
+        Entity,
+        &EntityIdentity,
+        &Position,
+        &mut Velocity,
+        &mut PrimedTnt,
+    )>,
+    writers: Query<(Entity, &StreamWriter), With<PlayerIdentity>>,
+    game_rules: Res<GameRules>,
+    mut explosions: MessageWriter<Explosion>,
+    mut commands: Commands,
+) {
+    let mut despawned = Vec::new();
+    for (entity, identity, pos, mut vel, mut primed) in tnt.iter_mut() {
+        if **vel != Vec3A::ZERO {
+            **vel *= 0.98;
+        }
+        primed.fuse = primed.fuse.saturating_sub(1);
+        if primed.fuse > 0 {
+            continue;
+        }
+        commands.entity(entity).despawn();
+        despawned.push(identity.entity_id);
+        if !game_rules.get_bool(GameRule::TntExplodes) {
+            continue;
+        }
+        let block_interaction = if game_rules.get_bool(GameRule::TntExplosionDropDecay) {
+            BlockInteraction::DestroyWithDecay
+        } else {
+            BlockInteraction::Destroy
+        };
+        explosions.write(Explosion {
+            // Explodes from just above the bottom of the block, like vanilla
+            position: pos.coords + DVec3::new(0.0, 0.0625, 0.0),
+            radius: PrimedTnt::EXPLOSION_RADIUS,
+            source: Some(entity),
+            igniter: primed.igniter,
+            block_interaction,
+        });
+    }
+    if !despawned.is_empty() {
+        broadcast_packet_all(&RemoveEntitiesPacket::from_ids(despawned), writers.iter());
+    }
+}
+
+/// Shows the primed TNT that's already burning to players that just joined.
+pub fn send_tnt_to_joining_players(
+    mut events: MessageReader<PlayerJoined>,
+    tnt: Query<(&EntityIdentity, &Position, &Velocity, &PrimedTnt)>,
+    writers: Query<&StreamWriter, With<PlayerIdentity>>,
+) {
+    for event in events.read() {
+        let Ok(writer) = writers.get(event.entity) else {
+            continue;
+        };
+        for (identity, pos, vel, primed) in tnt.iter() {
+            let spawn_packet = SpawnEntityPacket::new(
+                identity.entity_id,
+                identity.uuid.as_u128(),
+                VanillaEntityType::TNT.id as i32,
+                pos,
+                &Rotation::default(),
+            )
+            .with_velocity(vel);
+            let metadata_packet = EntityMetadataPacket::new(
+                VarInt::new(identity.entity_id),
+                [Metadata::primed_tnt_fuse(primed.fuse)],
+            );
+            if let Err(err) = writer.send_packet_ref(&spawn_packet) {
+                error!("Failed to send TNT spawn to {:?}: {:?}", event.entity, err);
+                break;
+            }
+            if let Err(err) = writer.send_packet_ref(&metadata_packet) {
+                error!(
+                    "Failed to send TNT metadata to {:?}: {:?}",
+                    event.entity, err
+                );
+                break;
+            }
+        }
+    }
+}
+
+type CreeperQuery<'a> = (
+    Entity,
+    &'a EntityIdentity,
+    &'a Position,
+    &'a CombatProperties,
+    Option<&'a mut CreeperFuse>,
+);
+
+/// Swells creepers up while a player is close and blows them up once they've swelled for long
+/// enough.
+pub fn tick_creepers(
+    mut creepers: Query<CreeperQuery, With<Creeper>>,
+    players: Query<(&Position, &GameModeComponent), With<PlayerIdentity>>,
+    writers: Query<(Entity, &StreamWriter), With<PlayerIdentity>>,
+    game_rules: Res<GameRules>,
+    mut explosions: MessageWriter<Explosion>,
+    mut commands: Commands,
+) {
+    let mut despawned = Vec::new();
+    for (entity, identity, pos, combat, fuse) in creepers.iter_mut() {
+        let Some(mut fuse) = fuse else {
+            commands.entity(entity).insert(CreeperFuse::default());
+            continue;
+        };
+        if combat.is_dead() {
+            continue;
+        }
+
+        let nearest = players
+            .iter()
+            .filter(|(_, gamemode)| !matches!(gamemode.0, GameMode::Creative | GameMode::Spectator))
+            .map(|(player_pos, _)| player_pos.coords.distance(pos.coords))
+            .fold(f64::INFINITY, f64::min);
+        let swelling = if fuse.swelling {
+            nearest <= CREEPER_CALM_DISTANCE
+        } else {
+            nearest <= CREEPER_SWELL_DISTANCE
+        };
+        if swelling != fuse.swelling {
+            fuse.swelling = swelling;
+            broadcast_packet_all(
+                &EntityMetadataPacket::new(
+                    VarInt::new(identity.entity_id),
+                    [Metadata::creeper_swell_direction(if swelling {
+                        1
+                    } else {
+                        -1
+                    })],
+                ),
+                writers.iter(),
+            );
+        }
+        if !fuse.tick() {
+            continue;
+        }
+
+        commands.entity(entity).despawn();
+        despawned.push(identity.entity_id);
+        let block_interaction = if !game_rules.get_bool(GameRule::MobGriefing) {
+            BlockInteraction::Keep
+        } else if game_rules.get_bool(GameRule::MobExplosionDropDecay) {
+            BlockInteraction::DestroyWithDecay
+        } else {
+            BlockInteraction::Destroy
+        };
+        explosions.write(Explosion {
+            position: pos.coords,
+            radius: CreeperFuse::EXPLOSION_RADIUS,
+            source: Some(entity),
+            igniter: Some(entity),
+            block_interaction,
+        });
+    }
+    if !despawned.is_empty() {
+        broadcast_packet_all(&RemoveEntitiesPacket::from_ids(despawned), writers.iter());
+    }
+}
+
+type TargetQuery<'a> = (
+    Entity,
+    &'a EntityIdentity,
+    &'a Position,
+    &'a EntityMetadata,
+    Option<&'a mut Velocity>,
+    Option<&'a mut OnGround>,
+    Has<CombatProperties>,
+    Has<ItemEntity>,
+    Has<ExperienceOrb>,
+    Has<PrimedTnt>,
+);
+
+type PlayerTargetQuery<'a> = (
+    Entity,
+    &'a Position,
+    &'a GameModeComponent,
+    &'a PlayerAbilities,
+    &'a Attributes,
+);
+
+/// How far from the explosion an entity is compared to its reach, and which way it's pushed.
+struct Impact {
+    distance: f64,
+    direction: DVec3,
+    exposure: f32,
+}
+
+impl Impact {
+    /// Works out how an explosion affects an entity at `pos` with the given bounding box. `eye`
+    /// is where the entity gets pushed from, relative to its feet. Returns `None` if the entity
+    /// is out of reach.
+    fn of(
+        explosion: &Explosion,
+        state: &GlobalState,
+        pos: DVec3,
+        eye: f64,
+        bounding_box: Option<&BoundingBox>,
+    ) -> Option<Self> {
+        let reach = explosion.radius as f64 * 2.0;
+        let distance = pos.distance(explosion.position) / reach;
+        if distance > 1.0 {
+            return None;
+        }
+        let direction = (pos + DVec3::new(0.0, eye, 0.0) - explosion.position).try_normalize()?;
+        let exposure = bounding_box.map_or(1.0, |bounding_box| {
+            exposure(
+                explosion.position,
+                pos + bounding_box.min.as_dvec3(),
+                pos + bounding_box.max.as_dvec3(),
+                |block| is_solid_block(state, block),
+            )
+        });
+        Some(Self {
+            distance,
+            direction,
+            exposure,
+        })
+    }
+
+    fn damage(&self, explosion: &Explosion) -> f32 {
+        explosion_damage(
+            explosion.radius,
+            self.distance * explosion.radius as f64 * 2.0,
+            self.exposure,
+        )
+    }
+
+    fn knockback(&self) -> DVec3 {
+        self.direction * (1.0 - self.distance) * self.exposure as f64
+    }
+}
+
+/// Blows up blocks and pushes and hurts the entities around explosions.
+#[expect(clippy::too_many_arguments)]
+pub fn explode(
+    mut events: MessageReader<Explosion>,
+    state: Res<GlobalStateResource>,
+    game_rules: Res<GameRules>,
+    registry: Res<PhysicalRegistry>,
+    mut targets: Query<TargetQuery, (Without<PlayerIdentity>, Without<Projectile>)>,
+    players: Query<PlayerTargetQuery, With<PlayerIdentity>>,
+    viewers: Query<(Entity, &StreamWriter, &Position)>,
+    mut writers: (
+        MessageWriter<PlayerDamaged>,
+        MessageWriter<EntityDamaged>,
+        MessageWriter<BlockBrokenEvent>,
+    ),
+    mut commands: Commands,
+) {
+    let (player_damage, entity_damage, broken) = &mut writers;
+    let mut rng = rand::thread_rng();
+    for explosion in events.read() {
+        let damage_type = if explosion.igniter.is_some() {
+            DamageType::PlayerExplosion
+        } else {
+            DamageType::Explosion
+        };
+
+        // Blocks
+        let mut changes = Vec::new();
+        if explosion.block_interaction != BlockInteraction::Keep {
+            let world = &state.0.world;
+            let mut resistances = HashMap::new();
+            let mut destroyed =
+                blocks_in_blast(explosion.position, explosion.radius, &mut rng, |pos| {
+                    // Unloaded blocks stop the explosion
+                    let Some(block) = world
+                        .get_loaded_block(BlockPos::of(pos.x, pos.y, pos.z), DEFAULT_DIMENSION)
+                    else {
+                        return Some(f32::INFINITY);
+                    };
+                    *resistances
+                        .entry(block)
+                        .or_insert_with(|| blast_resistance(block))
+                });
+            destroyed.shuffle(&mut rng);
+
+            let decay = explosion.block_interaction == BlockInteraction::DestroyWithDecay;
+            for pos in destroyed {
+                let block_pos = BlockPos::of(pos.x, pos.y, pos.z);
+                let Some(block) = world.get_loaded_block(block_pos, DEFAULT_DIMENSION) else {
+                    continue;
+                };
+                // Fluids only ever get here from explosions strong enough to go through them,
+                // and stay where they are
+                if match_block!("water", block) || match_block!("lava", block) {
+                    continue;
+                }
+                if let Err(err) =
+                    world.set_block_and_fetch(block_pos, DEFAULT_DIMENSION, BlockStateId::default())
+                {
+                    error!("Failed to destroy block at {}: {:?}", block_pos, err);
+                    continue;
+                }
+                changes.push((block_pos, BlockStateId::default()));
+                broken.write(BlockBrokenEvent {
+                    position: block_pos,
+                });
+
+                if match_block!("tnt", block) {
+                    // TNT caught in an explosion goes off sooner than lit TNT
+                    if game_rules.get_bool(GameRule::TntExplodes) {
+                        let fuse = PrimedTnt::DEFAULT_FUSE;
+                        let fuse = rng.gen_range(0..fuse / 4) + fuse / 8;
+                        spawn_primed_tnt(
+                            &mut commands,
+                            block_center(pos),
+                            PrimedTnt::new(fuse, explosion.igniter),
+                        );
+                    }
+                } else if game_rules.get_bool(GameRule::DoTileDrops) {
+                    let ctx = LootContext {
+                        block: Some(block),
+                        explosion_radius: decay.then_some(explosion.radius),
+                        ..Default::default()
+                    };
+                    drop_block_items(&mut commands, block_pos, &ctx);
+                }
+            }
+        }
+
+        // Entities
+        let mut removed = Vec::new();
+        for (entity, identity, pos, metadata, vel, grounded, living, item, orb, tnt) in
+            targets.iter_mut()
+        {
+            if Some(entity) == explosion.source {
+                continue;
+            }
+            let physical = registry.get(metadata.protocol_id(), false);
+            // Primed TNT is pushed from its feet so it flies up and away, everything else from
+            // its eyes
+            let eye = match (tnt, physical) {
+                (false, Some(physical)) => physical.eye_height as f64,
+                _ => 0.0,
+            };
+            let Some(impact) = Impact::of(
+                explosion,
+                &state.0,
+                pos.coords,
+                eye,
+                physical.map(|physical| &physical.bounding_box),
+            ) else {
+                continue;
+            };
+            let damage = impact.damage(explosion);
+            if living {
+                entity_damage.write(EntityDamaged {
+                    entity,
+                    amount: damage,
+                    damage_type,
+                    attacker: explosion.igniter,
+                });
+            } else if (item || orb) && damage >= ITEM_HEALTH {
+                commands.entity(entity).despawn();
+                removed.push(identity.entity_id);
+                continue;
+            }
+            if let Some(mut vel) = vel {
+                **vel += impact.knockback().as_vec3a();
+                if let Some(mut grounded) = grounded {
+                    grounded.0 = false;
+                }
+            }
+        }
+        if !removed.is_empty() {
+            broadcast_packet_all(
+                &RemoveEntitiesPacket::from_ids(removed),
+                viewers.iter().map(|(entity, writer, _)| (entity, writer)),
+            );
+        }
+
+        let player_physical = registry.get(VanillaEntityType::PLAYER.id, false);
+        let mut knockbacks = HashMap::new();
+        for (player, pos, gamemode, abilities, attributes) in players.iter() {
+            if gamemode.0 == GameMode::Spectator || Some(player) == explosion.source {
+                continue;
+            }
+            let eye = player_physical.map_or(0.0, |physical| physical.eye_height as f64);
+            let Some(impact) = Impact::of(
+                explosion,
+                &state.0,
+                pos.coords,
+                eye,
+                player_physical.map(|physical| &physical.bounding_box),
+            ) else {
+                continue;
+            };
+            player_damage.write(
+                PlayerDamaged::new(player, impact.damage(explosion), damage_type)
+                    .with_attacker(explosion.source),
+            );
+            // Blast protection makes players fly less far
+            let resistance = attributes.value(&Attribute::EXPLOSION_KNOCKBACK_RESISTANCE);
+            let knockback = impact.knockback() * (1.0 - resistance).max(0.0);
+            // Creative players flying around aren't pushed
+            if gamemode.0 != GameMode::Creative || !abilities.flying {
+                knockbacks.insert(player, knockback);
+            }
+        }
+
+        broadcast_block_changes(&state.0, &changes, &viewers);
+        // Smaller explosions and ones that don't break anything have a smaller cloud of smoke
+        let particle =
+            if explosion.radius < 2.0 || explosion.block_interaction == BlockInteraction::Keep {
+                ParticleType::Explosion
+            } else {
+                ParticleType::ExplosionEmitter
+            };
+        let center = explosion.position;
+        for (viewer, writer, pos) in viewers.iter() {
+            if pos.coords.distance(center) > EXPLOSION_VIEW_DISTANCE {
+                continue;
+            }
+            let knockback = knockbacks.get(&viewer).map(|knockback| Knockback {
+                x: knockback.x,
+                y: knockback.y,
+                z: knockback.z,
+            });
+            let packet =
+                ExplodePacket::new(center.x, center.y, center.z, knockback, particle.clone());
+            if let Err(err) = writer.send_packet_ref(&packet) {
+                error!("Failed to send explosion to {:?}: {:?}", viewer, err);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_blocks_in_blast() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let center = DVec3::splat(0.5);
+
+        // Dirt all around: a rough ball of blocks is destroyed
+        let dirt = blocks_in_blast(center, 4.0, &mut rng, |_| Some(0.5));
+        assert!(dirt.contains(&IVec3::ZERO));
+        assert!(dirt.len() > 50);
+        assert!(dirt
+            .iter()
+            .all(|block| (block.as_dvec3() + DVec3::splat(0.5)).distance(center) < 5.0));
+
+        // Bedrock and open air are never destroyed
+        assert!(blocks_in_blast(center, 4.0, &mut rng, |_| Some(3_600_000.0)).is_empty());
+        assert!(blocks_in_blast(center, 4.0, &mut rng, |_| None).is_empty());
+
+        // Obsidian shields the dirt behind it
+        let shielded = blocks_in_blast(center, 4.0, &mut rng, |block| {
+            if block.x == 2 {
+                Some(1200.0)
+            } else {
+                Some(0.5)
+            }
+        });
+        assert!(!shielded.is_empty());
+        assert!(shielded.iter().all(|block| block.x < 2));
+    }
+
+    #[test]
+    fn test_exposure() {
+        let center = DVec3::new(0.5, 0.5, 0.5);
+        let (min, max) = (DVec3::new(3.2, 0.0, 0.2), DVec3::new(3.8, 1.8, 0.8));
+        assert_eq!(exposure(center, min, max, |_| false), 1.0);
+        assert_eq!(exposure(center, min, max, |block| block.x == 2), 0.0);
+        // A wall covering the bottom of the entity hides part of it
+        let partial = exposure(center, min, max, |block| block.x == 2 && block.y == 0);
+        assert!(partial > 0.0 && partial < 1.0);
+    }
+
+    #[test]
+    fn test_explosion_damage() {
+        // Standing right in TNT takes 57 damage, the same as vanilla
+        assert_eq!(explosion_damage(4.0, 0.0, 1.0), 57.0);
+        // Being just out of reach or hidden only deals the minimum of 1
+        assert_eq!(explosion_damage(4.0, 8.0, 1.0), 1.0);
+        assert_eq!(explosion_damage(4.0, 2.0, 0.0), 1.0);
+        assert!(explosion_damage(3.0, 2.0, 1.0) < explosion_damage(4.0, 2.0, 1.0));
+    }
+}
@@ -0,0 +1,183 @@
+//! Boss bars, titles and the tab list.
+//!
+//! Boss bars and the tab list header and footer are components, so only what changed about them
+//! is sent. Titles are one-off, so they're sent from [`SendTitle`] messages instead.
+
+use bevy_ecs::prelude::{
+    Changed, DetectChanges, Entity, Local, MessageReader, Query, Ref, RemovedComponents,
+};
+use ferrumc_core::boss_bar::BossBar;
+use ferrumc_core::tab_list::TabListText;
+use ferrumc_messages::{SendTitle, TitleAction};
+use ferrumc_net::broadcast::broadcast_packet_all;
+use ferrumc_net::connection::StreamWriter;
+use ferrumc_net::packets::outgoing::boss_event::BossEventPacket;
+use ferrumc_net::packets::outgoing::clear_titles::ClearTitlesPacket;
+use ferrumc_net::packets::outgoing::set_action_bar_text::SetActionBarTextPacket;
+use ferrumc_net::packets::outgoing::set_subtitle_text::SetSubtitleTextPacket;
+use ferrumc_net::packets::outgoing::set_title_text::SetTitleTextPacket;
+use ferrumc_net::packets::outgoing::set_titles_animation::SetTitlesAnimationPacket;
+use ferrumc_net::packets::outgoing::tab_list_header_footer::TabListHeaderFooterPacket;
+use ferrumc_net_codec::encode::NetEncode;
+use ferrumc_text::TextComponent;
+use std::collections::{HashMap, HashSet};
+
+/// Sends a packet to the players that are still connected.
+fn send_to<'a, P>(
+    packet: &P,
+    players: impl IntoIterator<Item = &'a Entity>,
+    writers: &Query<(Entity, &StreamWriter)>,
+) where
+    P: NetEncode + Send,
+{
+    broadcast_packet_all(
+        packet,
+        players
+            .into_iter()
+            .filter_map(|player| writers.get(*player).ok()),
+    );
+}
+
+/// A boss bar as its viewers last saw it.
+pub struct SentBossBar {
+    bar: BossBar,
+    shown_to: HashSet<Entity>,
+}
+
+/// Shows, updates and hides boss bars for their viewers whenever they change, and hides the ones
+/// that were despawned.
+pub fn sync_boss_bars(
+    bars: Query<(Entity, Ref<BossBar>)>,
+    writers: Query<(Entity, &StreamWriter)>,
+    mut sent: Local<HashMap<Entity, SentBossBar>>,
+) {
+    sent.retain(|entity, old| {
+        if bars.contains(*entity) {
+            return true;
+        }
+        send_to(
+            &BossEventPacket::remove(old.bar.uuid),
+            &old.shown_to,
+            &writers,
+        );
+        false
+    });
+
+    for (entity, bar) in bars.iter() {
+        if !bar.is_changed() && sent.contains_key(&entity) {
+            continue;
+        }
+
+        let (old_bar, old_shown) = match sent.remove(&entity) {
+            Some(old) if old.bar.uuid == bar.uuid => (Some(old.bar), old.shown_to),
+            // Clients know the bar by its UUID, so a new one is a different bar
+            Some(old) => {
+                send_to(
+                    &BossEventPacket::remove(old.bar.uuid),
+                    &old.shown_to,
+                    &writers,
+                );
+                (None, HashSet::new())
+            }
+            None => (None, HashSet::new()),
+        };
+        let shown_to: HashSet<Entity> = if bar.visible {
+            bar.viewers()
+                .filter(|viewer| writers.contains(*viewer))
+                .collect()
+        } else {
+            HashSet::new()
+        };
+
+        send_to(
+            &BossEventPacket::remove(bar.uuid),
+            old_shown.difference(&shown_to),
+            &writers,
+        );
+        send_to(
+            &BossEventPacket::add(&bar),
+            shown_to.difference(&old_shown),
+            &writers,
+        );
+
+        if let Some(old_bar) = old_bar {
+            let mut updates = Vec::new();
+            if old_bar.progress != bar.progress {
+                updates.push(BossEventPacket::update_progress(&bar));
+            }
+            if old_bar.title != bar.title {
+                updates.push(BossEventPacket::update_title(&bar));
+            }
+            if old_bar.color != bar.color || old_bar.style != bar.style {
+                updates.push(BossEventPacket::update_style(&bar));
+            }
+            if old_bar.flags() != bar.flags() {
+                updates.push(BossEventPacket::update_flags(&bar));
+            }
+            for update in &updates {
+                send_to(update, shown_to.intersection(&old_shown), &writers);
+            }
+        }
+
+        sent.insert(
+            entity,
+            SentBossBar {
+                bar: BossBar::clone(&bar),
+                shown_to,
+            },
+        );
+    }
+}
+
+/// Sends titles, subtitles and action bar text to the players they're for.
+pub fn send_titles(mut events: MessageReader<SendTitle>, writers: Query<(Entity, &StreamWriter)>) {
+    for event in events.read() {
+        let players = &event.players;
+        match &event.action {
+            TitleAction::Title(text) => {
+                send_to(&SetTitleTextPacket::new(text.clone()), players, &writers)
+            }
+            TitleAction::Subtitle(text) => {
+                send_to(&SetSubtitleTextPacket::new(text.clone()), players, &writers)
+            }
+            TitleAction::ActionBar(text) => send_to(
+                &SetActionBarTextPacket::new(text.clone()),
+                players,
+                &writers,
+            ),
+            TitleAction::Times(times) => send_to(
+                &SetTitlesAnimationPacket::new(times.fade_in, times.stay, times.fade_out),
+                players,
+                &writers,
+            ),
+            TitleAction::Clear => send_to(&ClearTitlesPacket::new(false), players, &writers),
+            TitleAction::Reset => send_to(&ClearTitlesPacket::new(true), players, &writers),
+        }
+    }
+}
+
+/// Sends players their tab list header and footer when it changes, and clears it when it's
+/// removed.
+pub fn sync_tab_lists(
+    changed: Query<(Entity, &TabListText), Changed<TabListText>>,
+    mut removed: RemovedComponents<TabListText>,
+    writers: Query<(Entity, &StreamWriter)>,
+) {
+    for (player, text) in changed.iter() {
+        send_to(
+            &TabListHeaderFooterPacket::new(text.header.clone(), text.footer.clone()),
+            [&player],
+            &writers,
+        );
+    }
+    for player in removed.read() {
+        if changed.contains(player) {
+            continue;
+        }
+        send_to(
+            &TabListHeaderFooterPacket::new(TextComponent::default(), TextComponent::default()),
+            [&player],
+            &writers,
+        );
+    }
+}
@@ -0,0 +1,141 @@
+//! Players' food bar.
+//!
+//! Exhaustion from taking damage and the hunger effect wears down saturation and then food, and
+//! players with a full enough food bar heal over time while the `naturalRegeneration` game rule
+//! is on, trading some of their food for it, like vanilla.
+
+use crate::systems::effects::send_health;
+use bevy_ecs::prelude::{Local, Query, Res};
+use ferrumc_components::health::Health;
+use ferrumc_components::player::gamemode::{GameMode, GameModeComponent};
+use ferrumc_components::player::hunger::Hunger;
+use ferrumc_core::game_rules::{GameRule, GameRules};
+use ferrumc_net::connection::StreamWriter;
+
+/// How much exhaustion uses up one point of saturation or food.
+const EXHAUSTION_PER_POINT: f32 = 4.0;
+
+/// The most exhaustion a player can build up, the same as vanilla.
+const MAX_EXHAUSTION: f32 = 40.0;
+
+/// The food level players heal at while they have saturation.
+const SATURATED_FOOD_LEVEL: u8 = 20;
+
+/// The food level players slowly heal at without saturation.
+const HEALING_FOOD_LEVEL: u8 = 18;
+
+pub fn tick_food(
+    mut players: Query<(&mut Health, &mut Hunger, &GameModeComponent, &StreamWriter)>,
+    game_rules: Res<GameRules>,
+    mut ticks: Local<u64>,
+) {
+    *ticks += 1;
+    let natural_regeneration = game_rules.get_bool(GameRule::NaturalRegeneration);
+
+    for (mut health, mut hunger, gamemode, writer) in players.iter_mut() {
+        if health.current <= 0.0 || matches!(gamemode.0, GameMode::Creative | GameMode::Spectator) {
+            continue;
+        }
+        let before = *hunger;
+        let healed = tick(&mut hunger, &health, natural_regeneration, *ticks);
+        if healed > 0.0 {
+            health.current = (health.current + healed).min(health.max);
+        }
+        if healed > 0.0 || hunger.level != before.level || hunger.saturation != before.saturation {
+            send_health(&health, &hunger, writer);
+        }
+    }
+}
+
+/// Uses up a player's exhaustion and returns how much they heal this tick.
+fn tick(hunger: &mut Hunger, health: &Health, natural_regeneration: bool, ticks: u64) -> f32 {
+    if hunger.exhaustion > EXHAUSTION_PER_POINT {
+        hunger.exhaustion -= EXHAUSTION_PER_POINT;
+        if hunger.saturation > 0.0 {
+            hunger.saturation = (hunger.saturation - 1.0).max(0.0);
+        } else {
+            hunger.level = hunger.level.saturating_sub(1);
+        }
+    }
+
+    if !natural_regeneration || health.current >= health.max {
+        return 0.0;
+    }
+    if hunger.level >= SATURATED_FOOD_LEVEL && hunger.saturation > 0.0 {
+        if ticks % 10 != 0 {
+            return 0.0;
+        }
+        let used = hunger.saturation.min(6.0);
+        add_exhaustion(hunger, used);
+        used / 6.0
+    } else if hunger.level >= HEALING_FOOD_LEVEL {
+        if ticks % 80 != 0 {
+            return 0.0;
+        }
+        add_exhaustion(hunger, 6.0);
+        1.0
+    } else {
+        0.0
+    }
+}
+
+fn add_exhaustion(hunger: &mut Hunger, amount: f32) {
+    hunger.exhaustion = (hunger.exhaustion + amount).min(MAX_EXHAUSTION);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hurt() -> Health {
+        Health {
+            current: 10.0,
+            max: 20.0,
+        }
+    }
+
+    #[test]
+    fn test_saturated_regeneration() {
+        let mut hunger = Hunger::default();
+        assert_eq!(tick(&mut hunger, &hurt(), true, 9), 0.0);
+        assert_eq!(tick(&mut hunger, &hurt(), true, 10), 5.0 / 6.0);
+        assert_eq!(hunger.exhaustion, 5.0);
+
+        // The game rule and full health both stop it
+        assert_eq!(tick(&mut Hunger::default(), &hurt(), false, 10), 0.0);
+        let full = Health {
+            current: 20.0,
+            max: 20.0,
+        };
+        assert_eq!(tick(&mut Hunger::default(), &full, true, 10), 0.0);
+    }
+
+    #[test]
+    fn test_slow_regeneration() {
+        let mut hunger = Hunger {
+            level: 18,
+            saturation: 0.0,
+            exhaustion: 0.0,
+        };
+        assert_eq!(tick(&mut hunger, &hurt(), true, 10), 0.0);
+        assert_eq!(tick(&mut hunger, &hurt(), true, 80), 1.0);
+        assert_eq!(hunger.exhaustion, 6.0);
+
+        hunger.level = 17;
+        assert_eq!(tick(&mut hunger, &hurt(), true, 160), 0.0);
+    }
+
+    #[test]
+    fn test_exhaustion_uses_saturation_then_food() {
+        let mut hunger = Hunger {
+            level: 10,
+            saturation: 1.0,
+            exhaustion: 9.0,
+        };
+        tick(&mut hunger, &hurt(), false, 1);
+        assert_eq!((hunger.level, hunger.saturation), (10, 0.0));
+        tick(&mut hunger, &hurt(), false, 2);
+        assert_eq!((hunger.level, hunger.saturation), (9, 0.0));
+        assert_eq!(hunger.exhaustion, 1.0);
+    }
+}
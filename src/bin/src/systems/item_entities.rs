@@ -0,0 +1,311 @@
+//! Item entities.
+//!
+//! Broken blocks and players pressing the drop key spawn item entities. Items that land near each
+//! other merge into one stack, players pick them up by walking into them, and they despawn after
+//! five minutes.
+
+use bevy_ecs::prelude::{Commands, Entity, MessageReader, Query, With, World};
+use bevy_math::Vec3A;
+use ferrumc_components::player::gamemode::{GameMode, GameModeComponent};
+use ferrumc_core::identity::entity_identity::EntityIdentity;
+use ferrumc_core::identity::player_identity::PlayerIdentity;
+use ferrumc_core::transform::grounded::OnGround;
+use ferrumc_core::transform::position::Position;
+use ferrumc_core::transform::rotation::Rotation;
+use ferrumc_core::transform::velocity::Velocity;
+use ferrumc_data::blocks::types::Block;
+use ferrumc_data::generated::entities::EntityType as VanillaEntityType;
+use ferrumc_entities::bundles::ItemBundle;
+use ferrumc_entities::components::ItemEntity;
+use ferrumc_entities::markers::{HasCollisions, HasGravity};
+use ferrumc_inventories::inventory::Inventory;
+use ferrumc_inventories::slot::InventorySlot;
+use ferrumc_loot::LootContext;
+use ferrumc_messages::PlayerJoined;
+use ferrumc_net::broadcast::broadcast_packet_all;
+use ferrumc_net::connection::StreamWriter;
+use ferrumc_net::packets::outgoing::entity_metadata::{EntityMetadata, EntityMetadataPacket};
+use ferrumc_net::packets::outgoing::remove_entities::RemoveEntitiesPacket;
+use ferrumc_net::packets::outgoing::spawn_entity::SpawnEntityPacket;
+use ferrumc_net::packets::outgoing::take_item_entity::TakeItemEntityPacket;
+use ferrumc_net_codec::net_types::var_int::VarInt;
+use ferrumc_world::pos::BlockPos;
+use rand::Rng;
+use tracing::error;
+
+/// Items closer than this (in blocks, on each axis) merge into one stack.
+const MERGE_RADIUS: f64 = 0.5;
+
+/// How far a player reaches horizontally when picking up items.
+pub(crate) const PICKUP_REACH: f64 = 1.3;
+
+/// Players pick up items from half a block below their feet up to just above their head.
+pub(crate) const PICKUP_HEIGHT: std::ops::Range<f64> = -0.5..2.3;
+
+/// Inventory slots items are picked up into, hotbar first.
+pub(crate) fn pickup_slots() -> impl Iterator<Item = usize> + Clone {
+    (36..45).chain(9..36)
+}
+
+/// Spawns an item entity and shows it to every connected player.
+pub fn spawn_item(
+    commands: &mut Commands,
+    position: Position,
+    velocity: Velocity,
+    stack: InventorySlot,
+    pickup_delay: u32,
+) {
+    let bundle = ItemBundle::new(position, velocity, stack.clone(), pickup_delay);
+    let spawn_packet = SpawnEntityPacket::new(
+        bundle.identity.entity_id,
+        bundle.identity.uuid.as_u128(),
+        bundle.metadata.protocol_id() as i32,
+        &position,
+        &Rotation::default(),
+    )
+    .with_velocity(&velocity);
+    let metadata_packet = EntityMetadataPacket::new(
+        VarInt::new(bundle.identity.entity_id),
+        [EntityMetadata::item_entity_item(stack)],
+    );
+
+    commands.spawn((bundle, HasGravity, HasCollisions));
+    commands.queue(move |world: &mut World| {
+        let mut writers = world.query::<(Entity, &StreamWriter)>();
+        broadcast_packet_all(&spawn_packet, writers.iter(world));
+        broadcast_packet_all(&metadata_packet, writers.iter(world));
+    });
+}
+
+/// Drops the loot of the block in `ctx` around `pos`.
+pub fn drop_block_items(commands: &mut Commands, pos: BlockPos, ctx: &LootContext) {
+    let Some(table) = ctx
+        .block
+        .and_then(|block| block.name())
+        .and_then(|name| Block::by_name(name.trim_start_matches("minecraft:")))
+        .and_then(|block| block.loot_table)
+    else {
+        return;
+    };
+
+    let mut rng = rand::thread_rng();
+    for stack in ferrumc_loot::generate(table, ctx, &mut rng) {
+        let position = Position::new(
+            pos.pos.x as f64 + 0.5 + rng.gen_range(-0.25..0.25),
+            pos.pos.y as f64 + 0.5 + rng.gen_range(-0.25..0.25),
+            pos.pos.z as f64 + 0.5 + rng.gen_range(-0.25..0.25),
+        );
+        let velocity = Velocity::new(rng.gen_range(-0.1..0.1), 0.2, rng.gen_range(-0.1..0.1));
+        spawn_item(
+            commands,
+            position,
+            velocity,
+            stack,
+            ItemEntity::BLOCK_PICKUP_DELAY,
+        );
+    }
+}
+
+/// Ages items, slows them down and despawns the ones that have been around too long.
+pub fn tick_items(
+    mut items: Query<(
+        Entity,
+        &EntityIdentity,
+        &mut ItemEntity,
+        &mut Velocity,
+        &OnGround,
+    )>,
+    players: Query<(Entity, &StreamWriter)>,
+    mut commands: Commands,
+) {
+    let mut despawned = Vec::new();
+    for (entity, identity, mut item, mut vel, grounded) in items.iter_mut() {
+        item.age += 1;
+        item.pickup_delay = item.pickup_delay.saturating_sub(1);
+        if item.age >= ItemEntity::DESPAWN_AGE {
+            commands.entity(entity).despawn();
+            despawned.push(identity.entity_id);
+            continue;
+        }
+
+        // Only touch the velocity when it's non-zero, so resting items aren't marked as changed
+        // and skipped by the collision system
+        if **vel != Vec3A::ZERO {
+            // Air resistance, plus block friction while sliding along the ground
+            let friction = if grounded.0 { 0.6 * 0.98 } else { 0.98 };
+            **vel *= Vec3A::new(friction, 0.98, friction);
+            if vel.length_squared() < 1.0e-6 {
+                **vel = Vec3A::ZERO;
+            }
+        }
+    }
+
+    if !despawned.is_empty() {
+        broadcast_packet_all(&RemoveEntitiesPacket::from_ids(despawned), players.iter());
+    }
+}
+
+/// Combines stacks of the same item lying next to each other.
+pub fn merge_items(
+    mut items: Query<(Entity, &EntityIdentity, &Position, &mut ItemEntity)>,
+    players: Query<(Entity, &StreamWriter)>,
+    mut commands: Commands,
+) {
+    let snapshot: Vec<_> = items
+        .iter()
+        .filter(|(_, _, _, item)| item.pickup_delay == 0)
+        .map(|(entity, _, pos, item)| (entity, *pos, item.stack.clone()))
+        .collect();
+    if snapshot.len() < 2 {
+        return;
+    }
+
+    let mut merged = vec![false; snapshot.len()];
+    let mut despawned = Vec::new();
+    for (i, (target, target_pos, target_stack)) in snapshot.iter().enumerate() {
+        if merged[i] {
+            continue;
+        }
+        let max_stack = target_stack.max_stack_size();
+        let mut total = target_stack.count.0;
+
+        for (j, (source, source_pos, source_stack)) in snapshot.iter().enumerate().skip(i + 1) {
+            if merged[j] || !target_stack.stacks_with(source_stack) {
+                continue;
+            }
+            let offset = (target_pos.coords - source_pos.coords).abs();
+            if offset.max_element() > MERGE_RADIUS {
+                continue;
+            }
+            if total + source_stack.count.0 > max_stack {
+                continue;
+            }
+
+            total += source_stack.count.0;
+            merged[j] = true;
+            commands.entity(*source).despawn();
+            if let Ok((_, identity, _, _)) = items.get(*source) {
+                despawned.push(identity.entity_id);
+            }
+        }
+
+        if total == target_stack.count.0 {
+            continue;
+        }
+        let Ok((_, identity, _, mut item)) = items.get_mut(*target) else {
+            continue;
+        };
+        item.stack.count = VarInt::new(total);
+        let packet = EntityMetadataPacket::new(
+            VarInt::new(identity.entity_id),
+            [EntityMetadata::item_entity_item(item.stack.clone())],
+        );
+        broadcast_packet_all(&packet, players.iter());
+    }
+
+    if !despawned.is_empty() {
+        broadcast_packet_all(&RemoveEntitiesPacket::from_ids(despawned), players.iter());
+    }
+}
+
+/// Moves items players are standing on into their inventory.
+pub fn pickup_items(
+    mut items: Query<(Entity, &EntityIdentity, &Position, &mut ItemEntity)>,
+    mut collectors: Query<(
+        Entity,
+        &PlayerIdentity,
+        &Position,
+        &GameModeComponent,
+        &mut Inventory,
+    )>,
+    players: Query<(Entity, &StreamWriter)>,
+    mut commands: Commands,
+) {
+    let mut despawned = Vec::new();
+    for (item_entity, identity, item_pos, mut item) in items.iter_mut() {
+        if item.pickup_delay > 0 {
+            continue;
+        }
+
+        for (player, player_identity, player_pos, gamemode, mut inventory) in collectors.iter_mut()
+        {
+            if gamemode.0 == GameMode::Spectator {
+                continue;
+            }
+            let offset = item_pos.coords - player_pos.coords;
+            if offset.x.abs() > PICKUP_REACH
+                || offset.z.abs() > PICKUP_REACH
+                || !PICKUP_HEIGHT.contains(&offset.y)
+            {
+                continue;
+            }
+
+            let max_stack = item.stack.max_stack_size();
+            let left =
+                inventory.insert_stack_with_update(&item.stack, max_stack, pickup_slots(), player);
+            let picked = item.count() - left;
+            if picked == 0 {
+                continue;
+            }
+
+            let packet =
+                TakeItemEntityPacket::new(identity.entity_id, player_identity.short_uuid, picked);
+            broadcast_packet_all(&packet, players.iter());
+
+            if left == 0 {
+                commands.entity(item_entity).despawn();
+                despawned.push(identity.entity_id);
+            } else {
+                item.stack.count = VarInt::new(left);
+                let packet = EntityMetadataPacket::new(
+                    VarInt::new(identity.entity_id),
+                    [EntityMetadata::item_entity_item(item.stack.clone())],
+                );
+                broadcast_packet_all(&packet, players.iter());
+            }
+            break;
+        }
+    }
+
+    if !despawned.is_empty() {
+        broadcast_packet_all(&RemoveEntitiesPacket::from_ids(despawned), players.iter());
+    }
+}
+
+/// Shows the items already lying around to players that just joined.
+pub fn send_items_to_joining_players(
+    mut events: MessageReader<PlayerJoined>,
+    items: Query<(&EntityIdentity, &Position, &Velocity, &ItemEntity)>,
+    writers: Query<&StreamWriter, With<PlayerIdentity>>,
+) {
+    for event in events.read() {
+        let Ok(writer) = writers.get(event.entity) else {
+            continue;
+        };
+        for (identity, pos, vel, item) in items.iter() {
+            let spawn_packet = SpawnEntityPacket::new(
+                identity.entity_id,
+                identity.uuid.as_u128(),
+                VanillaEntityType::ITEM.id as i32,
+                pos,
+                &Rotation::default(),
+            )
+            .with_velocity(vel);
+            let metadata_packet = EntityMetadataPacket::new(
+                VarInt::new(identity.entity_id),
+                [EntityMetadata::item_entity_item(item.stack.clone())],
+            );
+            if let Err(err) = writer.send_packet_ref(&spawn_packet) {
+                error!("Failed to send item spawn to {:?}: {:?}", event.entity, err);
+                break;
+            }
+            if let Err(err) = writer.send_packet_ref(&metadata_packet) {
+                error!(
+                    "Failed to send item metadata to {:?}: {:?}",
+                    event.entity, err
+                );
+                break;
+            }
+        }
+    }
+}
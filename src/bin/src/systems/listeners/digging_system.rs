@@ -2,11 +2,31 @@ use bevy_ecs::prelude::*;
 use ferrumc_world::pos::BlockPos;
 use std::time::{Duration, Instant};
 
+use crate::systems::experience_orbs::{block_experience, spawn_orbs};
+use crate::systems::item_entities::drop_block_items;
+use crate::systems::sounds::{play_block_sound, BlockSound};
 use crate::BinaryError;
+use ferrumc_components::active_effects::ActiveEffects;
+use ferrumc_components::attributes::Attributes;
 use ferrumc_components::player::abilities::PlayerAbilities;
+use ferrumc_components::player::gamemode::{GameMode, GameModeComponent};
 use ferrumc_components::player::gameplay_state::digging::PlayerDigging;
+use ferrumc_core::game_rules::{GameRule, GameRules};
+use ferrumc_core::transform::grounded::OnGround;
+use ferrumc_core::transform::position::Position;
 use ferrumc_data::blocks::types::Block;
+use ferrumc_data::generated::attributes::Attribute;
+use ferrumc_data::generated::effects::Effect;
+use ferrumc_data::generated::enchantments::Enchantment;
+use ferrumc_data::items::Item;
+use ferrumc_data::tags::TagData;
+use ferrumc_inventories::enchanting::{hurt_item, resolve};
+use ferrumc_inventories::hotbar::Hotbar;
+use ferrumc_inventories::inventory::Inventory;
+use ferrumc_inventories::slot::InventorySlot;
+use ferrumc_loot::LootContext;
 use ferrumc_messages::player_digging::*;
+use ferrumc_messages::PlaySound;
 use ferrumc_net::connection::StreamWriter;
 use ferrumc_net::packets::outgoing::{block_change_ack::BlockChangeAck, block_update::BlockUpdate};
 use ferrumc_net_codec::net_types::var_int::VarInt;
@@ -14,6 +34,9 @@ use ferrumc_state::GlobalStateResource;
 use ferrumc_world::block_state_id::BlockStateId;
 use tracing::{debug, error, warn};
 
+/// How much of the break time a client has to wait, to allow for lag. The same as vanilla.
+const DIGGING_LENIENCY: f32 = 0.7;
+
 // A query for just the components needed to acknowledge a dig packet
 type DiggingPlayerQuery<'a> = (Entity, &'a StreamWriter, Option<&'a PlayerDigging>);
 
@@ -23,6 +46,7 @@ pub fn handle_start_digging(
     mut commands: Commands,
     mut events: MessageReader<PlayerStartedDigging>,
     mut player_query: Query<DiggingPlayerQuery, With<PlayerAbilities>>,
+    miners: Query<MinerQuery>,
     state: Res<GlobalStateResource>,
 ) {
     for event in events.read() {
@@ -90,15 +114,21 @@ pub fn handle_start_digging(
         }
 
         // --- 5. Calculate break time ---
-        // TODO: This is a placeholder. A real calculation would
-        // check for tools, effects, etc.
         let break_time = if hardness == 0.0 {
             // Instabreak blocks like air, grass, flowers
             Duration::from_millis(0)
         } else {
-            // Placeholder: 1.5s per hardness
-            // TODO: replace with real formula
-            Duration::from_secs_f32(hardness * 1.5)
+            match miners.get(event.player) {
+                Ok((inventory, hotbar, attributes, effects, on_ground)) => break_time(
+                    block_name,
+                    hardness,
+                    held_item(inventory, hotbar),
+                    attributes,
+                    effects,
+                    on_ground.0,
+                ),
+                Err(_) => Duration::from_secs_f32(hardness * 1.5),
+            }
         };
 
         // --- 6. Add the component ----
@@ -153,6 +183,7 @@ pub fn handle_cancel_digging(
 
 /// Handles the PlayerFinishDiggingEvent.
 /// This system checks the timer and breaks the block.
+#[expect(clippy::too_many_arguments)]
 pub fn handle_finish_digging(
     mut commands: Commands,
     mut events: MessageReader<PlayerFinishedDigging>,
@@ -160,6 +191,9 @@ pub fn handle_finish_digging(
     mut player_query: Query<DiggingPlayerQuery>,
     broadcast_query: Query<(Entity, &StreamWriter)>, // For broadcasting the break
     mut block_break_writer: MessageWriter<ferrumc_messages::BlockBrokenEvent>,
+    mut held_items: Query<(&mut Inventory, &Hotbar, &GameModeComponent)>,
+    game_rules: Res<GameRules>,
+    mut sounds: MessageWriter<PlaySound>,
 ) {
     for event in events.read() {
         let Ok((_player_entity, writer, digging_opt)) = player_query.get_mut(event.player) else {
@@ -246,13 +280,37 @@ pub fn handle_finish_digging(
 
             // We wrap the block-breaking logic in its own function
             // to handle the errors cleanly (replaces `try` block).
-            if let Err(e) = break_block(
+            // The tool and its enchantments decide what the block drops, e.g. shears on leaves
+            let held = held_items.get_mut(event.player).ok();
+            let tool = held
+                .as_ref()
+                .and_then(|(inventory, hotbar, _)| held_item(inventory, hotbar))
+                .cloned();
+
+            match break_block(
+                &mut commands,
+                tool.as_ref(),
+                game_rules.get_bool(GameRule::DoTileDrops),
                 &state,
                 &broadcast_query,
                 &event.position,
                 &mut block_break_writer,
             ) {
-                error!("Error handling finished digging: {:?}", e);
+                Ok(broken) => {
+                    play_block_sound(
+                        &mut sounds,
+                        event.position.clone().into(),
+                        broken,
+                        BlockSound::Break,
+                        event.player,
+                    );
+                    if let (Some((mut inventory, hotbar, gamemode)), Some(tool)) = (held, tool) {
+                        if gamemode.0 != GameMode::Creative {
+                            wear_tool(&mut inventory, hotbar, tool, broken, event.player);
+                        }
+                    }
+                }
+                Err(e) => error!("Error handling finished digging: {:?}", e),
             }
         }
 
@@ -270,17 +328,53 @@ pub fn handle_finish_digging(
     }
 }
 
-/// Helper function to contain the block-breaking logic (replaces `try` block)
+/// Helper function to contain the block-breaking logic (replaces `try` block). Returns the block
+/// that was broken.
 fn break_block(
+    commands: &mut Commands,
+    tool: Option<&InventorySlot>,
+    drop_items: bool,
     state: &Res<GlobalStateResource>,
     broadcast_query: &Query<(Entity, &StreamWriter)>,
     position: &ferrumc_net_codec::net_types::network_position::NetworkPosition,
     block_break_writer: &mut MessageWriter<ferrumc_messages::BlockBrokenEvent>,
-) -> Result<(), BinaryError> {
+) -> Result<BlockStateId, BinaryError> {
     let pos: BlockPos = position.clone().into();
     let mut chunk = ferrumc_utils::world::load_or_generate_mut(&state.0, pos.chunk(), "overworld")
         .expect("Failed to load or generate chunk");
+    let broken = chunk.get_block(pos.chunk_block_pos());
     chunk.set_block(pos.chunk_block_pos(), BlockStateId::default());
+    let tool_item = tool.and_then(|tool| tool.item());
+    let harvested = broken
+        .name()
+        .is_none_or(|name| can_harvest(name, tool_item));
+    if drop_items && harvested {
+        let enchantments: Vec<_> = tool
+            .map(|tool| resolve(tool.enchantments()))
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(enchantment, level)| (enchantment.name, level.max(0) as u32))
+            .collect();
+        let ctx = LootContext::block(
+            broken,
+            tool_item.map(|item| item.registry_key),
+            &enchantments,
+        );
+        drop_block_items(commands, pos, &ctx);
+
+        // Ores drop XP too, unless they were mined whole with silk touch
+        let silk_touch =
+            tool.is_some_and(|tool| tool.enchantment_level(Enchantment::SILK_TOUCH.id as i32) > 0);
+        if let Some(name) = broken.name().filter(|_| !silk_touch) {
+            let amount = block_experience(name, &mut rand::thread_rng());
+            let center = Position::new(
+                pos.pos.x as f64 + 0.5,
+                pos.pos.y as f64 + 0.5,
+                pos.pos.z as f64 + 0.5,
+            );
+            spawn_orbs(commands, center, amount);
+        }
+    }
 
     // Send block broken event for un-grounding system
     debug!("Sending BlockBrokenEvent for block at {:?}", pos.pos);
@@ -298,5 +392,107 @@ fn break_block(
         conn.send_packet_ref(&block_update_packet)
             .map_err(BinaryError::Net)?;
     }
-    Ok(())
+    Ok(broken)
+}
+
+// The components needed to work out how fast a player breaks blocks
+type MinerQuery<'a> = (
+    &'a Inventory,
+    &'a Hotbar,
+    &'a Attributes,
+    &'a ActiveEffects,
+    &'a OnGround,
+);
+
+fn held_item<'a>(inventory: &'a Inventory, hotbar: &Hotbar) -> Option<&'a InventorySlot> {
+    hotbar.get_selected_item(inventory).ok().flatten()
+}
+
+/// How long it takes a player to break a block, the same as vanilla. The right tool and efficiency
+/// speed it up, as does haste, while mining fatigue and being in the air slow it down.
+fn break_time(
+    block: &str,
+    hardness: f32,
+    held: Option<&InventorySlot>,
+    attributes: &Attributes,
+    effects: &ActiveEffects,
+    on_ground: bool,
+) -> Duration {
+    let item = held.and_then(|held| held.item());
+    let mut speed = item
+        .and_then(|item| item.tool())
+        .map_or(1.0, |tool| tool.mining_speed(block));
+    if speed > 1.0 {
+        speed += attributes.value(&Attribute::MINING_EFFICIENCY) as f32;
+    }
+    if let Some(amplifier) = effects.amplifier(Effect::Haste) {
+        speed *= 1.0 + (amplifier as f32 + 1.0) * 0.2;
+    }
+    if let Some(amplifier) = effects.amplifier(Effect::MiningFatigue) {
+        speed *= match amplifier {
+            0 => 0.3,
+            1 => 0.09,
+            2 => 0.0027,
+            _ => 8.1e-4,
+        };
+    }
+    speed *= attributes.value(&Attribute::BLOCK_BREAK_SPEED) as f32;
+    if !on_ground {
+        speed /= 5.0;
+    }
+
+    let divisor = if can_harvest(block, item) {
+        30.0
+    } else {
+        100.0
+    };
+    let progress_per_tick = speed / hardness / divisor;
+    if progress_per_tick >= 1.0 {
+        return Duration::from_millis(0);
+    }
+    let ticks = (1.0 / progress_per_tick).ceil();
+    // Like vanilla, the client may finish a little early to make up for lag
+    Duration::from_secs_f32(ticks * 0.05 * DIGGING_LENIENCY)
+}
+
+/// Whether a block drops anything when it's broken with an item. Which blocks need the right tool
+/// isn't in the data, so blocks mined with a pickaxe are assumed to need one, like most of them do.
+fn can_harvest(block: &str, item: Option<&Item>) -> bool {
+    let needs_tool = TagData::get_block_tag("minecraft:mineable/pickaxe")
+        .is_some_and(|tag| tag.values.contains(&block));
+    !needs_tool
+        || item
+            .and_then(|item| item.tool())
+            .is_some_and(|tool| tool.is_correct_for_drops(block))
+}
+
+/// Damages the tool a player broke a block with. Blocks that break instantly don't wear it out.
+fn wear_tool(
+    inventory: &mut Inventory,
+    hotbar: &Hotbar,
+    mut tool: InventorySlot,
+    broken: BlockStateId,
+    player: Entity,
+) {
+    let Some(damage) = tool
+        .item()
+        .and_then(|item| item.tool())
+        .map(|tool| tool.damage_per_block as i32)
+    else {
+        return;
+    };
+    let instant = Block::by_id(broken.raw()).is_none_or(|block| block.hardness == 0.0);
+    if instant || damage == 0 {
+        return;
+    }
+
+    let slot = hotbar.get_selected_inventory_index();
+    let result = if hurt_item(&mut tool, damage, false, &mut rand::thread_rng()) {
+        inventory.clear_slot_with_update(slot, player)
+    } else {
+        inventory.set_item_with_update(slot, tool, player)
+    };
+    if let Err(err) = result {
+        error!("Failed to damage tool of {:?}: {:?}", player, err);
+    }
 }
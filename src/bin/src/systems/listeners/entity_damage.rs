@@ -0,0 +1,112 @@
+//! Mobs taking damage and dying.
+//!
+//! A mob that runs out of health plays its death animation and is removed once it's over, at
+//! which point it drops XP if a player killed it and the `doMobLoot` game rule is on, like vanilla.
+
+use crate::systems::experience_orbs::{mob_experience, spawn_orbs};
+use bevy_ecs::prelude::{Commands, Component, Entity, Has, MessageReader, Query, Res, With};
+use ferrumc_core::game_rules::{GameRule, GameRules};
+use ferrumc_core::identity::entity_identity::EntityIdentity;
+use ferrumc_core::identity::player_identity::PlayerIdentity;
+use ferrumc_core::transform::position::Position;
+use ferrumc_entities::components::{Baby, CombatProperties, EntityMetadata};
+use ferrumc_messages::EntityDamaged;
+use ferrumc_net::broadcast::broadcast_packet_all;
+use ferrumc_net::connection::StreamWriter;
+use ferrumc_net::packets::outgoing::damage_event::DamageEventPacket;
+use ferrumc_net::packets::outgoing::entity_event::EntityStatus;
+use ferrumc_net::packets::outgoing::remove_entities::RemoveEntitiesPacket;
+
+/// Ticks a mob spends playing its death animation before it's removed, the same as vanilla.
+const DEATH_TICKS: u32 = 20;
+
+/// The entity status that plays the death animation and sound.
+const DEATH_STATUS: u8 = 3;
+
+/// A mob that died and is playing its death animation.
+#[derive(Component)]
+pub struct Dying {
+    ticks: u32,
+    /// Mobs only drop XP when a player killed them.
+    killed_by_player: bool,
+}
+
+pub fn handle(
+    mut events: MessageReader<EntityDamaged>,
+    mut entities: Query<(&EntityIdentity, &mut CombatProperties)>,
+    players: Query<(), With<PlayerIdentity>>,
+    writers: Query<(Entity, &StreamWriter), With<PlayerIdentity>>,
+    mut commands: Commands,
+) {
+    for event in events.read() {
+        let Ok((identity, mut combat)) = entities.get_mut(event.entity) else {
+            continue;
+        };
+        if !combat.hurt(event.amount) {
+            continue;
+        }
+        broadcast_packet_all(
+            &DamageEventPacket::new(identity.entity_id, event.damage_type.id()),
+            writers.iter(),
+        );
+
+        if combat.is_dead() {
+            broadcast_packet_all(
+                &EntityStatus {
+                    entity_id: identity.entity_id,
+                    status: DEATH_STATUS,
+                },
+                writers.iter(),
+            );
+            commands.entity(event.entity).insert(Dying {
+                ticks: 0,
+                killed_by_player: event
+                    .attacker
+                    .is_some_and(|attacker| players.contains(attacker)),
+            });
+        }
+    }
+}
+
+/// Counts down how long mobs stay invulnerable after being hurt.
+pub fn tick_invulnerability(mut entities: Query<&mut CombatProperties>) {
+    for mut combat in entities.iter_mut() {
+        if combat.invulnerability_ticks > 0 {
+            combat.tick();
+        }
+    }
+}
+
+/// Removes mobs once their death animation is over.
+pub fn tick_dying(
+    mut dying: Query<(
+        Entity,
+        &EntityIdentity,
+        &EntityMetadata,
+        &Position,
+        Has<Baby>,
+        &mut Dying,
+    )>,
+    writers: Query<(Entity, &StreamWriter), With<PlayerIdentity>>,
+    game_rules: Res<GameRules>,
+    mut commands: Commands,
+) {
+    let mob_loot = game_rules.get_bool(GameRule::DoMobLoot);
+    let mut removed = Vec::new();
+    for (entity, identity, metadata, position, baby, mut dying) in dying.iter_mut() {
+        dying.ticks += 1;
+        if dying.ticks < DEATH_TICKS {
+            continue;
+        }
+        if dying.killed_by_player && mob_loot {
+            let amount = mob_experience(metadata.vanilla_data(), baby, &mut rand::thread_rng());
+            spawn_orbs(&mut commands, *position, amount);
+        }
+        commands.entity(entity).despawn();
+        removed.push(identity.entity_id);
+    }
+
+    if !removed.is_empty() {
+        broadcast_packet_all(&RemoveEntitiesPacket::from_ids(removed), writers.iter());
+    }
+}
@@ -1,7 +1,14 @@
+use bevy_ecs::schedule::IntoScheduleConfigs;
+use ferrumc_messages::player_damage::DamageSystems;
+
 pub mod digging_system;
+pub mod entity_damage;
 pub mod entity_spawn;
 pub mod gamemode_change;
+pub mod player_damage;
+pub mod player_death;
 pub mod player_despawn;
+pub mod player_experience;
 pub mod player_join_message;
 pub mod player_leave_message;
 pub mod player_spawn;
@@ -11,6 +18,10 @@ pub fn register_gameplay_listeners(schedule: &mut bevy_ecs::schedule::Schedule)
     schedule.add_systems(player_leave_message::handle);
     schedule.add_systems(player_join_message::handle);
     schedule.add_systems(player_spawn::handle);
+    schedule.add_systems((
+        player_death::sync_health_on_join,
+        player_death::sync_immediate_respawn,
+    ));
     schedule.add_systems(player_despawn::handle);
     schedule.add_systems(gamemode_change::handle);
     schedule.add_systems(entity_spawn::spawn_command_processor);
@@ -19,4 +30,27 @@ pub fn register_gameplay_listeners(schedule: &mut bevy_ecs::schedule::Schedule)
     schedule.add_systems(digging_system::handle_cancel_digging);
     schedule.add_systems(digging_system::handle_finish_digging);
     schedule.add_systems(player_tp::teleport_player);
+    schedule.add_systems((
+        player_damage::tick_hurt_cooldown.before(DamageSystems),
+        player_damage::handle.in_set(DamageSystems),
+        player_damage::sync_absorption.after(DamageSystems),
+        player_death::drop_inventory_on_death.after(DamageSystems),
+        player_death::broadcast_death_message.after(DamageSystems),
+    ));
+    schedule.add_systems(
+        (
+            entity_damage::handle,
+            entity_damage::tick_invulnerability,
+            entity_damage::tick_dying,
+        )
+            .chain(),
+    );
+    schedule.add_systems(
+        (
+            player_experience::handle_gained_xp,
+            player_experience::drop_experience_on_death.after(DamageSystems),
+            player_experience::sync_experience,
+        )
+            .chain(),
+    );
 }
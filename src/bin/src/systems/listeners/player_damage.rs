@@ -0,0 +1,365 @@
+//! Applies damage to players' health.
+//!
+//! Damage goes through the same steps as in vanilla, each of which can be skipped by the damage
+//! type's tags: helmets block some of the damage from falling blocks, armor points and toughness
+//! reduce the rest, then resistance and protection enchantments, and finally absorption hearts
+//! take what they can. Armor loses durability for the damage it blocks. Players can't hurt their
+//! teammates unless the team has friendly fire on.
+//!
+//! After being hurt, players only take the extra damage of bigger hits for a short while, and
+//! the `fallDamage`, `fireDamage` and `drowningDamage` game rules turn those kinds of damage off.
+
+use bevy_ecs::prelude::{Changed, Entity, MessageReader, MessageWriter, Query, Res, With};
+use ferrumc_components::active_effects::ActiveEffects;
+use ferrumc_components::attributes::Attributes;
+use ferrumc_components::health::{Absorption, Health, HurtCooldown};
+use ferrumc_components::player::gamemode::{GameMode, GameModeComponent};
+use ferrumc_components::player::hunger::Hunger;
+use ferrumc_core::game_rules::{GameRule, GameRules};
+use ferrumc_core::identity::player_identity::PlayerIdentity;
+use ferrumc_core::scoreboard::Scoreboard;
+use ferrumc_data::generated::attributes::Attribute;
+use ferrumc_data::generated::damage_types::DamageType;
+use ferrumc_data::generated::effects::Effect;
+use ferrumc_data::generated::enchantments::Enchantment;
+use ferrumc_inventories::defined_slots::player;
+use ferrumc_inventories::enchanting::hurt_item;
+use ferrumc_inventories::inventory::Inventory;
+use ferrumc_messages::player_damage::{PlayerDamaged, PlayerDied};
+use ferrumc_net::broadcast::broadcast_packet_all;
+use ferrumc_net::connection::StreamWriter;
+use ferrumc_net::packets::outgoing::damage_event::DamageEventPacket;
+use ferrumc_net::packets::outgoing::entity_event::EntityStatus;
+use ferrumc_net::packets::outgoing::entity_metadata::{EntityMetadata, EntityMetadataPacket};
+use ferrumc_net::packets::outgoing::set_health::SetHealth;
+use ferrumc_net_codec::net_types::var_int::VarInt;
+use tracing::error;
+
+/// The most exhaustion a player can build up, the same as vanilla.
+const MAX_EXHAUSTION: f32 = 40.0;
+
+/// The armor slots, along with the entity status that plays their break animation.
+const ARMOR_SLOTS: [(u8, u8); 4] = [
+    (player::HEAD_SLOT, 49),
+    (player::CHEST_SLOT, 50),
+    (player::LEGS_SLOT, 51),
+    (player::FEET_SLOT, 52),
+];
+
+type DamageTargetQuery<'a> = (
+    &'a PlayerIdentity,
+    &'a mut Health,
+    &'a mut Hunger,
+    &'a mut Absorption,
+    &'a mut HurtCooldown,
+    &'a mut Inventory,
+    &'a Attributes,
+    &'a ActiveEffects,
+    &'a GameModeComponent,
+    &'a StreamWriter,
+);
+
+pub fn handle(
+    mut events: MessageReader<PlayerDamaged>,
+    mut players: Query<DamageTargetQuery>,
+    writers: Query<(Entity, &StreamWriter), With<PlayerIdentity>>,
+    attackers: Query<&PlayerIdentity>,
+    scoreboard: Res<Scoreboard>,
+    rules: Res<GameRules>,
+    mut died_msgs: MessageWriter<PlayerDied>,
+) {
+    for event in events.read() {
+        if event.cancelled {
+            continue;
+        }
+        let Ok((
+            identity,
+            mut health,
+            mut hunger,
+            mut absorption,
+            mut cooldown,
+            mut inventory,
+            attributes,
+            effects,
+            gamemode,
+            writer,
+        )) = players.get_mut(event.player)
+        else {
+            continue;
+        };
+        let damage_type = event.damage_type;
+        if health.current <= 0.0 || event.amount <= 0.0 {
+            continue;
+        }
+        if matches!(gamemode.0, GameMode::Creative | GameMode::Spectator)
+            && !damage_type.has_tag("bypasses_invulnerability")
+        {
+            continue;
+        }
+        if damage_type.has_tag("is_fire") && effects.has(Effect::FireResistance) {
+            continue;
+        }
+        if !rules_allow(&rules, damage_type) {
+            continue;
+        }
+        let attacker = event
+            .attacker
+            .and_then(|attacker| attackers.get(attacker).ok());
+        if attacker
+            .is_some_and(|attacker| !scoreboard.can_hurt(&attacker.username, &identity.username))
+        {
+            continue;
+        }
+
+        let hurt_animation = !cooldown.is_active();
+        let Some(mut amount) = cooldown.hurt(event.amount) else {
+            continue;
+        };
+        if damage_type.has_tag("damages_helmet") && has_item(&inventory, player::HEAD_SLOT) {
+            hurt_armor(
+                &mut inventory,
+                event.player,
+                identity,
+                &ARMOR_SLOTS[..1],
+                amount,
+                &writers,
+            );
+            amount *= 0.75;
+        }
+        if !damage_type.has_tag("bypasses_armor") {
+            hurt_armor(
+                &mut inventory,
+                event.player,
+                identity,
+                &ARMOR_SLOTS,
+                amount,
+                &writers,
+            );
+            amount = damage_after_armor(
+                amount,
+                attributes.value(&Attribute::ARMOR) as f32,
+                attributes.value(&Attribute::ARMOR_TOUGHNESS) as f32,
+            );
+        }
+        if !damage_type.has_tag("bypasses_effects") {
+            if let Some(amplifier) = effects.amplifier(Effect::Resistance) {
+                if !damage_type.has_tag("bypasses_resistance") {
+                    amount = damage_after_resistance(amount, amplifier);
+                }
+            }
+            if !damage_type.has_tag("bypasses_enchantments") {
+                let protection = protection(&inventory, damage_type);
+                amount = damage_after_protection(amount, protection);
+            }
+        }
+
+        let absorbed = amount.min(absorption.amount);
+        if absorbed > 0.0 {
+            absorption.amount -= absorbed;
+            amount -= absorbed;
+        }
+
+        // The hurt animation plays even if all of the damage was blocked
+        if hurt_animation {
+            broadcast_packet_all(
+                &DamageEventPacket::new(identity.short_uuid, damage_type.id()),
+                writers.iter(),
+            );
+        }
+        if amount <= 0.0 {
+            continue;
+        }
+
+        hunger.exhaustion = (hunger.exhaustion + damage_type.exhaustion()).min(MAX_EXHAUSTION);
+        health.current = (health.current - amount).max(0.0);
+        let packet = SetHealth::new(health.current, hunger.level as i32, hunger.saturation);
+        if let Err(err) = writer.send_packet_ref(&packet) {
+            error!("Failed to send health to {:?}: {:?}", event.player, err);
+        }
+
+        if health.current <= 0.0 {
+            died_msgs.write(PlayerDied {
+                player: event.player,
+                damage_type,
+                attacker: event.attacker,
+            });
+        }
+    }
+}
+
+/// Counts down how long players only take damage from bigger hits after being hurt.
+pub fn tick_hurt_cooldown(mut players: Query<&mut HurtCooldown>) {
+    for mut cooldown in players.iter_mut() {
+        if cooldown.is_active() {
+            cooldown.tick();
+        }
+    }
+}
+
+/// Whether the game rules let a kind of damage hurt players.
+fn rules_allow(rules: &GameRules, damage_type: DamageType) -> bool {
+    let rule = if damage_type.has_tag("is_fall") {
+        GameRule::FallDamage
+    } else if damage_type.has_tag("is_fire") {
+        GameRule::FireDamage
+    } else if damage_type.has_tag("is_drowning") {
+        GameRule::DrowningDamage
+    } else {
+        return true;
+    };
+    rules.get_bool(rule)
+}
+
+/// Sends players' absorption hearts to everyone when they change.
+pub fn sync_absorption(
+    players: Query<(&PlayerIdentity, &Absorption), Changed<Absorption>>,
+    writers: Query<(Entity, &StreamWriter), With<PlayerIdentity>>,
+) {
+    for (identity, absorption) in players.iter() {
+        let packet = EntityMetadataPacket::new(
+            VarInt::new(identity.short_uuid),
+            [EntityMetadata::player_absorption(absorption.amount)],
+        );
+        broadcast_packet_all(&packet, writers.iter());
+    }
+}
+
+fn has_item(inventory: &Inventory, slot: u8) -> bool {
+    matches!(inventory.get_item(slot as usize), Ok(Some(_)))
+}
+
+/// Damages the armor in the given slots for blocking an amount of damage.
+fn hurt_armor(
+    inventory: &mut Inventory,
+    entity: Entity,
+    identity: &PlayerIdentity,
+    slots: &[(u8, u8)],
+    amount: f32,
+    writers: &Query<(Entity, &StreamWriter), With<PlayerIdentity>>,
+) {
+    let durability = (amount / 4.0).floor().max(1.0) as i32;
+    let mut rng = rand::thread_rng();
+
+    for &(slot, status) in slots {
+        let Ok(Some(stack)) = inventory.get_item(slot as usize) else {
+            continue;
+        };
+        if stack.max_damage().is_none() || stack.is_unbreakable() {
+            continue;
+        }
+
+        let mut stack = stack.clone();
+        let result = if hurt_item(&mut stack, durability, true, &mut rng) {
+            broadcast_packet_all(
+                &EntityStatus {
+                    entity_id: identity.short_uuid,
+                    status,
+                },
+                writers.iter(),
+            );
+            inventory.clear_slot_with_update(slot as usize, entity)
+        } else {
+            inventory.set_item_with_update(slot as usize, stack, entity)
+        };
+        if let Err(err) = result {
+            error!("Failed to damage armor of {}: {:?}", identity.username, err);
+        }
+    }
+}
+
+/// The total protection from the enchantments on a player's armor, the same as vanilla's EPF.
+fn protection(inventory: &Inventory, damage_type: DamageType) -> f32 {
+    let mut protection = 0.0;
+    for (slot, _) in ARMOR_SLOTS {
+        let Ok(Some(stack)) = inventory.get_item(slot as usize) else {
+            continue;
+        };
+        for enchantment in stack.enchantments() {
+            let Some(data) = u16::try_from(enchantment.id.0)
+                .ok()
+                .and_then(Enchantment::from_id)
+            else {
+                continue;
+            };
+            let level = enchantment.level.0.clamp(1, u8::MAX as i32) as u8;
+            for effect in data.damage_protection {
+                let applies = effect
+                    .tags
+                    .iter()
+                    .all(|(tag, expected)| damage_type.has_tag(tag) == *expected);
+                if applies {
+                    protection += effect.amount.calculate(level);
+                }
+            }
+        }
+    }
+    protection
+}
+
+/// The damage left after armor. Toughness makes armor hold up better against big hits, but it
+/// always blocks at least a fifth of what its points would.
+fn damage_after_armor(amount: f32, armor: f32, toughness: f32) -> f32 {
+    let toughness = 2.0 + toughness / 4.0;
+    let effective = (armor - amount / toughness).clamp(armor * 0.2, 20.0);
+    amount * (1.0 - effective / 25.0)
+}
+
+/// The damage left after resistance, which blocks a fifth of it per level.
+fn damage_after_resistance(amount: f32, amplifier: u8) -> f32 {
+    let blocked = (amplifier as f32 + 1.0) * 5.0;
+    (amount * (25.0 - blocked) / 25.0).max(0.0)
+}
+
+/// The damage left after protection enchantments, which can block at most 80% of it.
+fn damage_after_protection(amount: f32, protection: f32) -> f32 {
+    if protection <= 0.0 {
+        return amount;
+    }
+    amount * (1.0 - protection.clamp(0.0, 20.0) / 25.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ferrumc_core::game_rules::GameRuleValue;
+
+    #[test]
+    fn test_armor_reduction() {
+        // Full diamond armor against a 10 damage hit
+        assert!((damage_after_armor(10.0, 20.0, 8.0) - 3.0).abs() < 1e-5);
+        // Armor always blocks at least a fifth of its points
+        assert!((damage_after_armor(100.0, 10.0, 0.0) - 92.0).abs() < 1e-4);
+        assert_eq!(damage_after_armor(5.0, 0.0, 0.0), 5.0);
+    }
+
+    #[test]
+    fn test_magic_reduction() {
+        assert_eq!(damage_after_resistance(10.0, 0), 8.0);
+        assert_eq!(damage_after_resistance(10.0, 4), 0.0);
+        assert_eq!(damage_after_resistance(10.0, 9), 0.0);
+        assert_eq!(damage_after_protection(10.0, 0.0), 10.0);
+        assert!((damage_after_protection(10.0, 30.0) - 2.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_damage_rules() {
+        let mut rules = GameRules::default();
+        assert!(rules_allow(&rules, DamageType::Fall));
+        rules.set(GameRule::FallDamage, GameRuleValue::Bool(false));
+        rules.set(GameRule::FireDamage, GameRuleValue::Bool(false));
+        assert!(!rules_allow(&rules, DamageType::Fall));
+        assert!(!rules_allow(&rules, DamageType::Lava));
+        assert!(rules_allow(&rules, DamageType::Drown));
+        assert!(rules_allow(&rules, DamageType::Arrow));
+    }
+
+    #[test]
+    fn test_protection_tags() {
+        let feather_falling = &Enchantment::FEATHER_FALLING.damage_protection[0];
+        assert_eq!(feather_falling.amount.calculate(4), 12.0);
+        assert!(feather_falling.tags.contains(&("is_fall", true)));
+        assert!(DamageType::Fall.has_tag("minecraft:is_fall"));
+        assert!(DamageType::Fall.has_tag("bypasses_armor"));
+        assert!(!DamageType::Arrow.has_tag("bypasses_armor"));
+    }
+}
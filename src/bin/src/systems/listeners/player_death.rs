@@ -0,0 +1,156 @@
+//! What happens around a player's death: the death message, dropping their items, and the
+//! death screen, which is skipped with the `doImmediateRespawn` game rule and shown again to
+//! players who left while dead.
+
+use crate::systems::containers::drop_item;
+use bevy_ecs::prelude::{Commands, Entity, MessageReader, Query, Res, With};
+use ferrumc_components::health::Health;
+use ferrumc_components::player::gamemode::{GameMode, GameModeComponent};
+use ferrumc_components::player::hunger::Hunger;
+use ferrumc_core::game_rules::{GameRule, GameRules};
+use ferrumc_core::identity::player_identity::PlayerIdentity;
+use ferrumc_core::mq;
+use ferrumc_core::transform::position::Position;
+use ferrumc_entities::components::EntityMetadata;
+use ferrumc_inventories::inventory::Inventory;
+use ferrumc_messages::player_damage::PlayerDied;
+use ferrumc_messages::player_join::PlayerJoined;
+use ferrumc_net::broadcast::broadcast_packet_all;
+use ferrumc_net::connection::StreamWriter;
+use ferrumc_net::packets::outgoing::game_event::GameEventPacket;
+use ferrumc_net::packets::outgoing::set_health::SetHealth;
+use ferrumc_text::{ComponentBuilder, TextComponent};
+use tracing::error;
+
+/// Tells everyone how a player died, unless the `showDeathMessages` game rule is off.
+pub fn broadcast_death_message(
+    mut events: MessageReader<PlayerDied>,
+    players: Query<&PlayerIdentity>,
+    attackers: Query<(Option<&PlayerIdentity>, Option<&EntityMetadata>)>,
+    game_rules: Res<GameRules>,
+) {
+    for event in events.read() {
+        if !game_rules.get_bool(GameRule::ShowDeathMessages) {
+            continue;
+        }
+        let Ok(identity) = players.get(event.player) else {
+            continue;
+        };
+        let mut with = vec![TextComponent::from(identity.username.clone())];
+        if let Some(name) = event
+            .attacker
+            .and_then(|attacker| attackers.get(attacker).ok())
+            .and_then(|(player, mob)| entity_name(player, mob))
+        {
+            with.push(name);
+        }
+        let key = format!("death.attack.{}", event.damage_type.message_id());
+        mq::broadcast(ComponentBuilder::translate(key, with), false);
+    }
+}
+
+/// Players drop everything in their inventory where they died, unless the `keepInventory` game
+/// rule is on.
+pub fn drop_inventory_on_death(
+    mut events: MessageReader<PlayerDied>,
+    mut players: Query<(
+        &PlayerIdentity,
+        &mut Inventory,
+        &Position,
+        &GameModeComponent,
+    )>,
+    game_rules: Res<GameRules>,
+    mut commands: Commands,
+) {
+    for event in events.read() {
+        if game_rules.get_bool(GameRule::KeepInventory) {
+            continue;
+        }
+        let Ok((identity, mut inventory, position, gamemode)) = players.get_mut(event.player)
+        else {
+            continue;
+        };
+        if gamemode.0 == GameMode::Spectator {
+            continue;
+        }
+        for slot in 0..inventory.slots.len() {
+            let Some(stack) = inventory.slots[slot].clone() else {
+                continue;
+            };
+            if stack.item_id.is_some() {
+                drop_item(&mut commands, position, stack);
+            }
+            if let Err(err) = inventory.clear_slot_with_update(slot, event.player) {
+                error!(
+                    "Failed to clear slot {} of {}: {:?}",
+                    slot, identity.username, err
+                );
+            }
+        }
+    }
+}
+
+/// Sends joining players their saved health, so players who left while dead get the death screen
+/// and can respawn.
+pub fn sync_health_on_join(
+    mut events: MessageReader<PlayerJoined>,
+    players: Query<(&StreamWriter, &Health, &Hunger)>,
+) {
+    for event in events.read() {
+        let Ok((writer, health, hunger)) = players.get(event.entity) else {
+            continue;
+        };
+        let packet = SetHealth::new(health.current, hunger.level as i32, hunger.saturation);
+        if let Err(err) = writer.send_packet_ref(&packet) {
+            error!(
+                "Failed to send health to {}: {:?}",
+                event.identity.username, err
+            );
+        }
+    }
+}
+
+/// Tells players whether to skip the death screen, when they join and whenever the
+/// `doImmediateRespawn` game rule might have changed.
+pub fn sync_immediate_respawn(
+    mut events: MessageReader<PlayerJoined>,
+    game_rules: Res<GameRules>,
+    writers: Query<(Entity, &StreamWriter), With<PlayerIdentity>>,
+) {
+    let immediate = game_rules.get_bool(GameRule::DoImmediateRespawn);
+    let packet = GameEventPacket::new(
+        GameEventPacket::DISABLE_RESPAWN_SCREEN,
+        if immediate { 1.0 } else { 0.0 },
+    );
+    if game_rules.is_changed() {
+        events.clear();
+        broadcast_packet_all(&packet, writers.iter());
+        return;
+    }
+    for event in events.read() {
+        let Ok((_, writer)) = writers.get(event.entity) else {
+            continue;
+        };
+        if let Err(err) = writer.send_packet_ref(&packet) {
+            error!(
+                "Failed to send respawn screen rule to {}: {:?}",
+                event.identity.username, err
+            );
+        }
+    }
+}
+
+/// The name of a killer in death messages.
+fn entity_name(
+    player: Option<&PlayerIdentity>,
+    mob: Option<&EntityMetadata>,
+) -> Option<TextComponent> {
+    if let Some(player) = player {
+        return Some(TextComponent::from(player.username.clone()));
+    }
+    let resource_name = mob?.vanilla_data().resource_name;
+    Some(ComponentBuilder::translate(
+        format!("entity.minecraft.{resource_name}"),
+        vec![],
+    ))
+}
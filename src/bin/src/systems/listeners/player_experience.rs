@@ -0,0 +1,66 @@
+//! Players gaining and losing XP.
+
+use crate::systems::experience_orbs::spawn_orbs;
+use bevy_ecs::prelude::{Changed, Commands, MessageReader, MessageWriter, Query, Res};
+use ferrumc_components::player::experience::Experience;
+use ferrumc_components::player::gamemode::{GameMode, GameModeComponent};
+use ferrumc_core::game_rules::{GameRule, GameRules};
+use ferrumc_core::transform::position::Position;
+use ferrumc_messages::player_damage::PlayerDied;
+use ferrumc_messages::{PlayerGainedXP, PlayerLeveledUp};
+use ferrumc_net::connection::StreamWriter;
+use ferrumc_net::packets::outgoing::set_experience::SetExperience;
+use tracing::error;
+
+/// Adds the XP players collected to their `Experience`.
+pub fn handle_gained_xp(
+    mut events: MessageReader<PlayerGainedXP>,
+    mut players: Query<&mut Experience>,
+    mut level_msgs: MessageWriter<PlayerLeveledUp>,
+) {
+    for event in events.read() {
+        let Ok(mut experience) = players.get_mut(event.player) else {
+            continue;
+        };
+        let level = experience.level;
+        experience.add_points(event.amount.min(i32::MAX as u32) as i32);
+        if experience.level > level {
+            level_msgs.write(PlayerLeveledUp {
+                player: event.player,
+                new_level: experience.level,
+            });
+        }
+    }
+}
+
+/// Players drop some of their XP as orbs when they die and lose the rest, unless the
+/// `keepInventory` game rule is on.
+pub fn drop_experience_on_death(
+    mut events: MessageReader<PlayerDied>,
+    mut players: Query<(&mut Experience, &Position, &GameModeComponent)>,
+    game_rules: Res<GameRules>,
+    mut commands: Commands,
+) {
+    for event in events.read() {
+        if game_rules.get_bool(GameRule::KeepInventory) {
+            continue;
+        }
+        let Ok((mut experience, position, gamemode)) = players.get_mut(event.player) else {
+            continue;
+        };
+        if gamemode.0 != GameMode::Spectator {
+            spawn_orbs(&mut commands, *position, experience.death_drop());
+        }
+        *experience = Experience::default();
+    }
+}
+
+/// Sends players their XP bar whenever it changes, including when they join.
+pub fn sync_experience(players: Query<(&StreamWriter, &Experience), Changed<Experience>>) {
+    for (writer, experience) in players.iter() {
+        let packet = SetExperience::new(experience.progress, experience.level, experience.total_xp);
+        if let Err(err) = writer.send_packet_ref(&packet) {
+            error!("Failed to send experience: {:?}", err);
+        }
+    }
+}
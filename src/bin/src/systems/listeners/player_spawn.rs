@@ -15,7 +15,7 @@ use ferrumc_net::packets::outgoing::spawn_entity::SpawnEntityPacket;
 use ferrumc_state::GlobalStateResource;
 use tracing::{error, trace};
 
-const PLAYER_TYPE_ID: i32 =
+pub(crate) const PLAYER_TYPE_ID: i32 =
     get_registry_entry!("minecraft:entity_type.entries.minecraft:player") as i32;
 
 /// Listens for `PlayerJoined` events and handles spawning players for each other.
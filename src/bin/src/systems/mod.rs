@@ -1,10 +1,22 @@
 use bevy_ecs::schedule::IntoScheduleConfigs;
+use ferrumc_commands::infrastructure::CommandSystems;
+pub mod attributes;
+pub mod block_interactions;
+pub mod block_ticks;
 mod chunk_calculator;
+pub mod chunk_pipeline;
 mod chunk_sending;
 pub mod chunk_unloader;
 pub mod connection_killer;
+pub mod containers;
 pub mod day_cycle;
+pub mod effects;
 pub mod emit_player_joined;
+pub mod experience_orbs;
+pub mod explosions;
+pub mod hud;
+pub mod hunger;
+pub mod item_entities;
 pub mod keep_alive_system;
 pub mod lan_pinger;
 pub mod listeners;
@@ -14,9 +26,19 @@ pub mod new_connections;
 mod particles;
 pub mod physics;
 mod player_swimming;
+pub mod pregen;
+pub mod projectiles;
+pub mod query;
+pub mod rcon;
+pub mod redstone;
+pub mod scoreboard;
 mod send_entity_updates;
 pub mod shutdown_systems;
+pub mod sleeping;
+pub mod sounds;
 pub(crate) mod update_player_ping;
+pub mod weather;
+pub mod world_border;
 pub mod world_sync;
 
 pub fn register_game_systems(schedule: &mut bevy_ecs::schedule::Schedule) {
@@ -32,13 +54,116 @@ pub fn register_game_systems(schedule: &mut bevy_ecs::schedule::Schedule) {
             .chain(),
     );
     schedule.add_systems(mq::process);
+    schedule.add_systems((
+        rcon::dispatch_rcon_commands.before(CommandSystems),
+        rcon::send_rcon_responses.after(CommandSystems),
+    ));
+    schedule.add_systems(pregen::handle_pregen_commands.after(CommandSystems));
     schedule.add_systems(player_swimming::detect_player_swimming);
+    schedule.add_systems((
+        world_border::send_border_to_joining_players,
+        world_border::sync_world_borders.after(CommandSystems),
+        world_border::damage_outside_border,
+    ));
+
+    schedule.add_systems((
+        weather::send_weather_to_joining_players,
+        weather::tick_weather.after(CommandSystems),
+        weather::strike_lightning,
+        weather::tick_lightning,
+    ));
+
+    schedule.add_systems((
+        effects::send_effects_to_joining_players,
+        (effects::apply_effects, effects::tick_effects)
+            .chain()
+            .after(CommandSystems),
+        effects::clear_effects_on_death,
+    ));
+    schedule.add_systems(hunger::tick_food.after(effects::tick_effects));
+    schedule.add_systems((
+        attributes::update_equipment_modifiers,
+        attributes::sync_attributes
+            .after(attributes::update_equipment_modifiers)
+            .after(effects::send_effects_to_joining_players)
+            .after(effects::tick_effects)
+            .after(effects::clear_effects_on_death),
+    ));
 
     schedule.add_systems(send_entity_updates::handle);
 
-    schedule.add_systems(day_cycle::tick_daylight_cycle);
+    schedule.add_systems(
+        (
+            sleeping::handle_bed_use,
+            sleeping::tick_sleeping,
+            day_cycle::tick_daylight_cycle,
+        )
+            .chain(),
+    );
+    schedule.add_systems((block_ticks::notify_broken_blocks, block_ticks::tick_blocks).chain());
+    schedule.add_systems(
+        (
+            containers::open_containers,
+            containers::close_invalid_containers,
+        )
+            .chain(),
+    );
+    schedule.add_systems(
+        (
+            item_entities::send_items_to_joining_players,
+            item_entities::tick_items,
+            item_entities::merge_items,
+            item_entities::pickup_items,
+        )
+            .chain(),
+    );
+    schedule.add_systems(
+        (
+            experience_orbs::send_orbs_to_joining_players,
+            experience_orbs::tick_orbs,
+            experience_orbs::merge_orbs,
+            experience_orbs::pickup_orbs,
+        )
+            .chain(),
+    );
+    schedule.add_systems((
+        projectiles::send_projectiles_to_joining_players,
+        projectiles::release_used_items,
+        projectiles::tick_projectiles.before(physics::velocity::handle),
+        (
+            projectiles::fly_projectiles,
+            projectiles::pickup_projectiles,
+        )
+            .chain()
+            .after(physics::velocity::handle),
+    ));
+    schedule.add_systems((
+        explosions::send_tnt_to_joining_players,
+        (
+            explosions::prime_tnt_blocks,
+            explosions::tick_tnt,
+            explosions::tick_creepers,
+            explosions::explode,
+        )
+            .chain(),
+    ));
+
+    schedule.add_systems((sounds::play_footsteps, sounds::play_level_up_sounds));
+    schedule.add_systems(
+        (
+            scoreboard::track_criteria,
+            scoreboard::count_deaths,
+            scoreboard::sync_scoreboard,
+        )
+            .chain()
+            .after(CommandSystems),
+    );
+    schedule.add_systems(
+        (hud::sync_boss_bars, hud::send_titles, hud::sync_tab_lists).after(CommandSystems),
+    );
 
     // Should always be last
     schedule.add_systems(connection_killer::connection_killer);
     schedule.add_systems(particles::handle);
+    schedule.add_systems(sounds::play_sounds);
 }
@@ -39,6 +39,10 @@ pub fn process(query: Query<(Entity, &StreamWriter)>, state: Res<GlobalStateReso
 
             // None => broadcast to Everyone
             None => {
+                // Broadcasts such as chat are shown in the server console too
+                if !entry.overlay {
+                    ferrumc_logging::console::print_line(&entry.message.clone().to_ansi_string());
+                }
                 for (receiver, writer) in query {
                     send(writer, receiver, &state, entry.clone());
                 }
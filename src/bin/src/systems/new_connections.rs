@@ -1,15 +1,17 @@
 use bevy_ecs::prelude::{Commands, Res, Resource};
 use crossbeam_channel::Receiver;
+use ferrumc_components::attributes::{Attributes, EquipmentModifiers};
+use ferrumc_components::health::{Absorption, HurtCooldown};
 use ferrumc_components::player::teleport_tracker::TeleportTracker;
 use ferrumc_components::player::{
-    gamemode::GameModeComponent, offline_player_data::OfflinePlayerData,
-    pending_events::PendingPlayerJoin, player_bundle::PlayerBundle, sneak::SneakState,
-    swimming::SwimmingState,
+    experience::EnchantmentSeed, gamemode::GameModeComponent, pending_events::PendingPlayerJoin,
+    player_bundle::PlayerBundle, sneak::SneakState, swimming::SwimmingState,
 };
 use ferrumc_core::{
     chunks::chunk_receiver::ChunkReceiver, conn::keepalive::KeepAliveTracker,
     transform::grounded::OnGround,
 };
+use ferrumc_inventories::container::CarriedItem;
 use ferrumc_inventories::hotbar::Hotbar;
 use ferrumc_net::connection::{DisconnectHandle, NewConnection};
 use ferrumc_state::GlobalStateResource;
@@ -30,22 +32,8 @@ pub fn accept_new_connections(
     while let Ok(new_connection) = new_connections.0.try_recv() {
         let return_sender = new_connection.entity_return;
 
-        // --- 1. Load all data from cache ---
-        let offline_data = match state
-            .0
-            .world
-            .load_player_data(new_connection.player_identity.uuid)
-        {
-            Ok(data) => data,
-            Err(err) => {
-                error!(
-                    "Error loading player data for {}: {:?}",
-                    new_connection.player_identity.username, err
-                );
-                None
-            }
-        };
-        let player_data = offline_data.unwrap_or(OfflinePlayerData::default());
+        // --- 1. Take the data loaded during login ---
+        let player_data = new_connection.player_data.unwrap_or_default();
         // --- 2. Build the PlayerBundle ---
         let player_bundle = PlayerBundle {
             identity: new_connection.player_identity.clone(),
@@ -55,13 +43,21 @@ pub fn accept_new_connections(
             rotation: player_data.rotation,
             on_ground: OnGround::default(),
             chunk_receiver: ChunkReceiver::default(),
+            respawn_point: player_data.respawn_point,
             inventory: player_data.inventory,
             hotbar: Hotbar::default(),
             ender_chest: player_data.ender_chest,
+            carried_item: CarriedItem::default(),
             health: player_data.health,
+            absorption: Absorption::default(),
+            hurt_cooldown: HurtCooldown::default(),
             hunger: player_data.hunger,
             experience: player_data.experience,
+            enchantment_seed: EnchantmentSeed(rand::random()),
             active_effects: player_data.active_effects,
+            // Modifiers from effects and equipment are added back once the player has joined
+            attributes: Attributes::player(),
+            equipment_modifiers: EquipmentModifiers::default(),
             swimming: SwimmingState::default(),
             sneak: SneakState::default(),
         };
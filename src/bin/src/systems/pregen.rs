@@ -0,0 +1,178 @@
+//! Runs pregenerations started with `/pregen` on a background thread, slowing them down while
+//! players are online so chunk sending keeps up.
+
+use crate::pregen::{PregenShape, PregenTask};
+use bevy_ecs::prelude::*;
+use ferrumc_messages::pregen::{PregenAction, PregenCommand};
+use ferrumc_state::{GlobalState, GlobalStateResource};
+use indicatif::ProgressBar;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::Duration;
+use tracing::{error, info};
+
+/// Chunks generated per batch while nobody is online.
+const IDLE_BATCH_SIZE: usize = 256;
+/// Chunks generated per batch while players are online.
+const BUSY_BATCH_SIZE: usize = 16;
+/// Pause between batches while players are online, leaving the thread pool to their chunks.
+const BUSY_BATCH_DELAY: Duration = Duration::from_millis(250);
+
+struct RunningPregen {
+    stop: Arc<AtomicBool>,
+    /// Throw the progress away once stopped, instead of keeping it for `/pregen resume`.
+    cancel: Arc<AtomicBool>,
+    /// Hidden, only used to keep track of the progress and estimate the time left.
+    progress: ProgressBar,
+    handle: JoinHandle<()>,
+}
+
+/// The pregeneration running in the background, if any.
+#[derive(Resource, Default)]
+pub struct PregenRunner {
+    running: Option<RunningPregen>,
+}
+
+impl PregenRunner {
+    fn start(&mut self, state: GlobalState, mut task: PregenTask) -> std::io::Result<()> {
+        let stop = Arc::new(AtomicBool::new(false));
+        let cancel = Arc::new(AtomicBool::new(false));
+        let progress = ProgressBar::hidden();
+
+        let handle = std::thread::Builder::new()
+            .name("PregenThread".to_string())
+            .spawn({
+                let stop = stop.clone();
+                let cancel = cancel.clone();
+                let progress = progress.clone();
+                move || {
+                    let batch_size = || {
+                        if state.players.player_list.is_empty() {
+                            IDLE_BATCH_SIZE
+                        } else {
+                            std::thread::sleep(BUSY_BATCH_DELAY);
+                            BUSY_BATCH_SIZE
+                        }
+                    };
+                    match crate::pregen::run(&state, &mut task, batch_size, &stop, &progress) {
+                        Ok(true) => info!("Pregeneration complete, generated {} chunks", task.done),
+                        Ok(false) if cancel.load(Ordering::Relaxed) => {
+                            if let Err(e) = PregenTask::clear() {
+                                error!("Could not delete the cancelled pregeneration: {}", e);
+                            }
+                        }
+                        Ok(false) => info!("Pregeneration paused at {} chunks", task.done),
+                        Err(e) => error!("Pregeneration failed: {}", e),
+                    }
+                }
+            })?;
+
+        self.running = Some(RunningPregen {
+            stop,
+            cancel,
+            progress,
+            handle,
+        });
+        Ok(())
+    }
+}
+
+pub fn handle_pregen_commands(
+    mut messages: MessageReader<PregenCommand>,
+    mut runner: ResMut<PregenRunner>,
+    state: Res<GlobalStateResource>,
+) {
+    // Forget pregenerations that finished, paused or failed on their own
+    if runner
+        .running
+        .as_ref()
+        .is_some_and(|running| running.handle.is_finished())
+    {
+        runner.running = None;
+    }
+
+    for message in messages.read() {
+        let reply = |text: String| message.sender.send_message(text.into(), false);
+
+        match message.action {
+            PregenAction::Start { .. } | PregenAction::Resume if runner.running.is_some() => {
+                reply("A pregeneration is already running, pause or cancel it first.".into());
+            }
+            PregenAction::Start {
+                center,
+                radius,
+                circle,
+            } => {
+                let shape = if circle {
+                    PregenShape::Circle
+                } else {
+                    PregenShape::Square
+                };
+                let task = PregenTask::new(center, radius, shape);
+                let total = task.total();
+                match runner.start(state.0.clone(), task) {
+                    Ok(()) => reply(format!(
+                        "Pregenerating {} chunks around chunk ({}, {}).",
+                        total, center.0, center.1
+                    )),
+                    Err(e) => reply(format!("Could not start the pregeneration: {e}")),
+                }
+            }
+            PregenAction::Resume => match PregenTask::load() {
+                Ok(Some(task)) => {
+                    let (done, total) = (task.done, task.total());
+                    match runner.start(state.0.clone(), task) {
+                        Ok(()) => reply(format!("Resumed pregeneration at {done}/{total} chunks.")),
+                        Err(e) => reply(format!("Could not resume the pregeneration: {e}")),
+                    }
+                }
+                Ok(None) => reply("There is no pregeneration to resume.".into()),
+                Err(e) => reply(format!("Could not load the pregeneration: {e}")),
+            },
+            PregenAction::Pause => match &runner.running {
+                Some(running) => {
+                    running.stop.store(true, Ordering::Relaxed);
+                    reply("Pausing the pregeneration, use /pregen resume to continue it.".into());
+                }
+                None => reply("No pregeneration is running.".into()),
+            },
+            PregenAction::Cancel => match &runner.running {
+                Some(running) => {
+                    running.cancel.store(true, Ordering::Relaxed);
+                    running.stop.store(true, Ordering::Relaxed);
+                    reply("Cancelled the pregeneration.".into());
+                }
+                None => match PregenTask::clear() {
+                    Ok(()) => reply("Cancelled the paused pregeneration, if there was one.".into()),
+                    Err(e) => reply(format!("Could not cancel the pregeneration: {e}")),
+                },
+            },
+            PregenAction::Status => match &runner.running {
+                Some(running) if running.stop.load(Ordering::Relaxed) => {
+                    reply("The pregeneration is stopping.".into());
+                }
+                Some(running) => {
+                    let progress = &running.progress;
+                    let total = progress.length().unwrap_or_default();
+                    reply(format!(
+                        "Pregenerated {}/{} chunks ({:.1}%), about {}s left.",
+                        progress.position(),
+                        total,
+                        progress.position() as f64 * 100.0 / total.max(1) as f64,
+                        progress.eta().as_secs()
+                    ));
+                }
+                None => match PregenTask::load() {
+                    Ok(Some(task)) => reply(format!(
+                        "The pregeneration is paused at {}/{} chunks.",
+                        task.done,
+                        task.total()
+                    )),
+                    Ok(None) => reply("No pregeneration is running.".into()),
+                    Err(e) => reply(format!("Could not load the pregeneration: {e}")),
+                },
+            },
+        }
+    }
+}
@@ -0,0 +1,853 @@
+//! Projectiles.
+//!
+//! Players shoot arrows from bows, which do more damage the longer the bow was drawn, and throw
+//! snowballs, eggs, ender pearls and tridents. Projectiles check everything between where they
+//! are and where they'll be next tick, so they don't fly through thin walls or small mobs. What
+//! they hit is damaged like any other attack. Arrows and tridents stick in the blocks they hit
+//! and can be picked up again, tridents with loyalty fly back to their owner, ender pearls
+//! teleport whoever threw them and eggs sometimes hatch chicks.
+
+use crate::systems::item_entities::{pickup_slots, spawn_item, PICKUP_HEIGHT, PICKUP_REACH};
+use crate::systems::physics::collisions::is_solid_block;
+use bevy_ecs::prelude::{
+    Commands, Component, Entity, Has, MessageReader, MessageWriter, Query, Res, With, Without,
+    World,
+};
+use bevy_math::{DVec3, IVec3, Vec3A};
+use ferrumc_components::player::gamemode::{GameMode, GameModeComponent};
+use ferrumc_core::identity::entity_identity::EntityIdentity;
+use ferrumc_core::identity::player_identity::PlayerIdentity;
+use ferrumc_core::sound::SoundCategory;
+use ferrumc_core::transform::position::Position;
+use ferrumc_core::transform::rotation::Rotation;
+use ferrumc_core::transform::velocity::Velocity;
+use ferrumc_data::enchantments::Enchantment;
+use ferrumc_data::generated::damage_types::DamageType;
+use ferrumc_data::generated::entities::EntityType as VanillaEntityType;
+use ferrumc_data::generated::sounds::Sound;
+use ferrumc_data::items::Item;
+use ferrumc_entities::bundles::{ChickenBundle, ProjectileBundle};
+use ferrumc_entities::collision::{raycast_blocks, segment_box_intersection};
+use ferrumc_entities::components::{
+    Baby, CombatProperties, EntityMetadata, ItemEntity, PhysicalRegistry, Pickup, Projectile,
+    ProjectileKind,
+};
+use ferrumc_entities::markers::entity_types::Chicken;
+use ferrumc_entities::markers::{HasCollisions, HasGravity, HasWaterDrag};
+use ferrumc_inventories::defined_slots::player;
+use ferrumc_inventories::enchanting::hurt_item;
+use ferrumc_inventories::inventory::Inventory;
+use ferrumc_inventories::item::ItemID;
+use ferrumc_inventories::slot::InventorySlot;
+use ferrumc_macros::match_block;
+use ferrumc_messages::entity_update::SendEntityUpdate;
+use ferrumc_messages::teleport_player::TeleportPlayer;
+use ferrumc_messages::{
+    EntityDamaged, EntityType, PlaySound, PlayerDamaged, PlayerJoined, PlayerReleasedItem,
+    SpawnEntityEvent,
+};
+use ferrumc_net::broadcast::broadcast_packet_all;
+use ferrumc_net::connection::StreamWriter;
+use ferrumc_net::packets::outgoing::entity_event::EntityStatus;
+use ferrumc_net::packets::outgoing::entity_metadata::{
+    EntityMetadata as Metadata, EntityMetadataPacket,
+};
+use ferrumc_net::packets::outgoing::remove_entities::RemoveEntitiesPacket;
+use ferrumc_net::packets::outgoing::spawn_entity::SpawnEntityPacket;
+use ferrumc_net::packets::outgoing::take_item_entity::TakeItemEntityPacket;
+use ferrumc_net_codec::net_types::var_int::VarInt;
+use ferrumc_state::{GlobalState, GlobalStateResource};
+use ferrumc_world::block_state_id::BlockStateId;
+use ferrumc_world::pos::{ChunkBlockPos, ChunkPos};
+use ferrumc_world::DEFAULT_DIMENSION;
+use rand::Rng;
+use std::time::Instant;
+use tracing::error;
+
+/// Projectiles are shot from just below the shooter's eyes.
+const LAUNCH_HEIGHT: f64 = 1.62 - 0.1;
+
+/// Loyal tridents fly back towards their owner's eyes.
+const EYE_HEIGHT: f64 = 1.62;
+
+/// How much projectiles stray from where they're aimed, the same for every kind like vanilla.
+const INACCURACY: f32 = 1.0;
+
+/// Projectiles can't hit whoever shot them for this many ticks, so they don't hit them on the way
+/// out of their hitbox.
+const OWNER_GRACE_TICKS: u32 = 5;
+
+/// The hitboxes projectiles can hit are this much bigger than the entity, the same as vanilla.
+const HIT_MARGIN: f64 = 0.3;
+
+/// Projectiles that fall this far below the world are removed.
+const VOID_Y: f64 = -128.0;
+
+/// Tridents have to be held back this many ticks before they're thrown.
+const TRIDENT_CHARGE_TICKS: u64 = 10;
+
+/// Loyal tridents stay stuck in a block for this many ticks before flying back.
+const LOYALTY_DELAY_TICKS: u32 = 4;
+
+/// Ender pearls hurt whoever threw them by this much when they land.
+const ENDER_PEARL_DAMAGE: f32 = 5.0;
+
+/// Snowballs only hurt blazes.
+const SNOWBALL_BLAZE_DAMAGE: f32 = 3.0;
+
+/// The entity statuses that play the break animation of the item in either hand.
+const MAINHAND_BREAK_STATUS: u8 = 47;
+const OFFHAND_BREAK_STATUS: u8 = 48;
+
+/// A player drawing a bow or holding a trident back, ready to throw it.
+#[derive(Component)]
+pub struct UsingItem {
+    /// The inventory slot of the item being used.
+    pub slot: usize,
+    pub item: ItemID,
+    pub since: Instant,
+}
+
+/// How far a bow is drawn after being held for some ticks, from 0 to 1.
+fn bow_power(ticks: u64) -> f32 {
+    let seconds = ticks as f32 / 20.0;
+    ((seconds * seconds + seconds * 2.0) / 3.0).min(1.0)
+}
+
+/// The items bows can shoot.
+fn is_ammo(stack: &InventorySlot) -> bool {
+    stack.item().is_some_and(|item| {
+        *item == Item::ARROW || *item == Item::TIPPED_ARROW || *item == Item::SPECTRAL_ARROW
+    })
+}
+
+/// Finds the arrows a bow shoots: the ones in the off hand, then the first ones in the hotbar
+/// and the rest of the inventory.
+pub fn find_ammo(inventory: &Inventory) -> Option<usize> {
+    std::iter::once(player::OFFHAND_SLOT as usize)
+        .chain(pickup_slots())
+        .find(|slot| matches!(inventory.get_item(*slot), Ok(Some(stack)) if is_ammo(stack)))
+}
+
+/// The rotation projectiles have while flying with some velocity, which is the other way around
+/// to how players look.
+fn rotation_of(velocity: Vec3A) -> Rotation {
+    let horizontal = (velocity.x * velocity.x + velocity.z * velocity.z).sqrt();
+    Rotation {
+        yaw: velocity.x.atan2(velocity.z).to_degrees(),
+        pitch: velocity.y.atan2(horizontal).to_degrees(),
+    }
+}
+
+/// Shoots a projectile from a player's eyes in the direction they're looking, and shows it to
+/// every connected player.
+pub fn launch(
+    commands: &mut Commands,
+    projectile: Projectile,
+    shooter_id: i32,
+    position: &Position,
+    rotation: &Rotation,
+    speed: f32,
+) {
+    // Same as vanilla: along the look direction, with a little random spread
+    let mut rng = rand::thread_rng();
+    let (yaw, pitch) = (rotation.yaw.to_radians(), rotation.pitch.to_radians());
+    let direction = Vec3A::new(
+        -yaw.sin() * pitch.cos(),
+        -pitch.sin(),
+        yaw.cos() * pitch.cos(),
+    );
+    let mut spread = || (rng.gen::<f32>() - rng.gen::<f32>()) * 0.0172275 * INACCURACY;
+    let direction = direction.normalize_or_zero() + Vec3A::new(spread(), spread(), spread());
+    let velocity = Velocity {
+        vec: direction * speed,
+    };
+
+    let critical = projectile.critical;
+    let spawn_at = Position::new(position.x, position.y + LAUNCH_HEIGHT, position.z);
+    let bundle = ProjectileBundle::new(projectile, spawn_at, rotation_of(*velocity), velocity);
+    let spawn_packet = SpawnEntityPacket::new(
+        bundle.identity.entity_id,
+        bundle.identity.uuid.as_u128(),
+        bundle.metadata.protocol_id() as i32,
+        &spawn_at,
+        &bundle.rotation,
+    )
+    .with_data(shooter_id + 1)
+    .with_velocity(&velocity);
+    let metadata_packet = critical.then(|| {
+        EntityMetadataPacket::new(
+            VarInt::new(bundle.identity.entity_id),
+            [Metadata::arrow_critical()],
+        )
+    });
+
+    commands.spawn(bundle);
+    commands.queue(move |world: &mut World| {
+        let mut writers = world.query::<(Entity, &StreamWriter)>();
+        broadcast_packet_all(&spawn_packet, writers.iter(world));
+        if let Some(metadata_packet) = &metadata_packet {
+            broadcast_packet_all(metadata_packet, writers.iter(world));
+        }
+    });
+}
+
+/// The sound of a projectile being shot or thrown from `position`. `power` is how far the bow was
+/// drawn, from 0 to 1, which makes arrows sound higher.
+pub fn launch_sound(kind: ProjectileKind, power: f32, position: DVec3) -> PlaySound {
+    let mut rng = rand::thread_rng();
+    let sound = match kind {
+        ProjectileKind::Arrow => Sound::EntityArrowShoot,
+        ProjectileKind::Trident => Sound::ItemTridentThrow,
+        ProjectileKind::Snowball => Sound::EntitySnowballThrow,
+        ProjectileKind::Egg => Sound::EntityEggThrow,
+        ProjectileKind::EnderPearl => Sound::EntityEnderPearlThrow,
+    };
+    match kind {
+        ProjectileKind::Arrow => PlaySound::at(sound, SoundCategory::Players, position)
+            .with_pitch(1.0 / (rng.gen::<f32>() * 0.4 + 1.2) + power * 0.5),
+        ProjectileKind::Trident => PlaySound::at(sound, SoundCategory::Players, position),
+        _ => PlaySound::at(sound, SoundCategory::Neutral, position)
+            .with_volume(0.5)
+            .with_pitch(0.4 / (rng.gen::<f32>() * 0.4 + 0.8)),
+    }
+}
+
+/// The sound of a projectile hitting a block or an entity.
+fn hit_sound(kind: ProjectileKind, hit_block: bool, position: DVec3) -> Option<PlaySound> {
+    let sound = match kind {
+        ProjectileKind::Arrow => Sound::EntityArrowHit,
+        ProjectileKind::Trident if hit_block => Sound::ItemTridentHitGround,
+        ProjectileKind::Trident => Sound::ItemTridentHit,
+        _ => return None,
+    };
+    let pitch = 1.2 / (rand::thread_rng().gen::<f32>() * 0.2 + 0.9);
+    Some(PlaySound::at(sound, SoundCategory::Neutral, position).with_pitch(pitch))
+}
+
+type ShooterQuery<'a> = (
+    &'a PlayerIdentity,
+    &'a Position,
+    &'a Rotation,
+    &'a GameModeComponent,
+    &'a mut Inventory,
+    Option<&'a UsingItem>,
+);
+
+/// Shoots arrows from bows and throws tridents when players let go of them.
+pub fn release_used_items(
+    mut events: MessageReader<PlayerReleasedItem>,
+    mut shooters: Query<ShooterQuery>,
+    writers: Query<(Entity, &StreamWriter), With<PlayerIdentity>>,
+    mut sounds: MessageWriter<PlaySound>,
+    mut commands: Commands,
+) {
+    for event in events.read() {
+        let Ok((identity, position, rotation, gamemode, mut inventory, using)) =
+            shooters.get_mut(event.player)
+        else {
+            continue;
+        };
+        let Some(using) = using else {
+            continue;
+        };
+        commands.entity(event.player).remove::<UsingItem>();
+
+        // Switching to another item stops using the old one
+        let Ok(Some(used)) = inventory.get_item(using.slot) else {
+            continue;
+        };
+        if used.item_id != Some(using.item) {
+            continue;
+        }
+        let mut used = used.clone();
+        let ticks = using.since.elapsed().as_millis() as u64 / 50;
+        let creative = gamemode.0 == GameMode::Creative;
+
+        let (mut projectile, speed, power) = if used.item() == Some(&Item::BOW) {
+            let power = bow_power(ticks);
+            if power < 0.1 {
+                continue;
+            }
+            let ammo_slot = find_ammo(&inventory);
+            let ammo = match ammo_slot {
+                Some(slot) => inventory.get_item(slot).ok().flatten().cloned(),
+                // Creative players don't need arrows
+                None if creative => Some(InventorySlot {
+                    count: VarInt::new(1),
+                    item_id: Some(ItemID::new(Item::ARROW.id as i32)),
+                    ..Default::default()
+                }),
+                None => None,
+            };
+            let Some(mut ammo) = ammo else {
+                continue;
+            };
+
+            // Infinity only saves normal arrows
+            let infinite = creative
+                || (used.enchantment_level(Enchantment::INFINITY.id as i32) > 0
+                    && ammo.item() == Some(&Item::ARROW));
+            if let (Some(slot), false) = (ammo_slot, infinite) {
+                let result = if ammo.count.0 > 1 {
+                    let mut left = ammo.clone();
+                    left.count = VarInt::new(ammo.count.0 - 1);
+                    inventory.set_item_with_update(slot, left, event.player)
+                } else {
+                    inventory.clear_slot_with_update(slot, event.player)
+                };
+                if let Err(err) = result {
+                    error!("Failed to use arrow of {}: {:?}", identity.username, err);
+                    continue;
+                }
+            }
+            ammo.count = VarInt::new(1);
+
+            let mut arrow = Projectile::new(ProjectileKind::Arrow, Some(event.player));
+            let power_level = used.enchantment_level(Enchantment::POWER.id as i32);
+            if power_level > 0 {
+                arrow.base_damage += power_level as f32 * 0.5 + 0.5;
+            }
+            arrow.critical = power >= 1.0;
+            arrow.pickup = if infinite {
+                Pickup::CreativeOnly
+            } else {
+                Pickup::Allowed
+            };
+            arrow.stack = Some(ammo);
+            (arrow, power * 3.0, power)
+        } else if used.item() == Some(&Item::TRIDENT) {
+            // Riptide tridents launch their thrower instead, which isn't supported
+            if ticks < TRIDENT_CHARGE_TICKS
+                || used.enchantment_level(Enchantment::RIPTIDE.id as i32) > 0
+            {
+                continue;
+            }
+            let mut trident = Projectile::new(ProjectileKind::Trident, Some(event.player));
+            trident.loyalty = used.enchantment_level(Enchantment::LOYALTY.id as i32);
+            trident.pickup = if creative {
+                Pickup::CreativeOnly
+            } else {
+                Pickup::Allowed
+            };
+            (trident, 2.5, 0.0)
+        } else {
+            continue;
+        };
+
+        // Bows and tridents lose durability every time they're used. Tridents that would break
+        // can't be thrown, so only bows break here.
+        let broke = !creative && hurt_item(&mut used, 1, false, &mut rand::thread_rng());
+        let result = if projectile.kind == ProjectileKind::Trident {
+            // The trident itself is thrown, but creative players keep theirs
+            projectile.stack = Some(used);
+            if creative {
+                Ok(())
+            } else {
+                inventory.clear_slot_with_update(using.slot, event.player)
+            }
+        } else if broke {
+            inventory.clear_slot_with_update(using.slot, event.player)
+        } else {
+            inventory.set_item_with_update(using.slot, used, event.player)
+        };
+        if let Err(err) = result {
+            error!(
+                "Failed to update used item of {}: {:?}",
+                identity.username, err
+            );
+            continue;
+        }
+        if broke {
+            let status = if using.slot == player::OFFHAND_SLOT as usize {
+                OFFHAND_BREAK_STATUS
+            } else {
+                MAINHAND_BREAK_STATUS
+            };
+            broadcast_packet_all(
+                &EntityStatus {
+                    entity_id: identity.short_uuid,
+                    status,
+                },
+                writers.iter(),
+            );
+        }
+
+        sounds.write(launch_sound(projectile.kind, power, position.coords));
+        launch(
+            &mut commands,
+            projectile,
+            identity.short_uuid,
+            position,
+            rotation,
+            speed,
+        );
+    }
+}
+
+/// What a projectile hit this tick.
+enum Hit {
+    Block(IVec3, DVec3),
+    Entity {
+        entity: Entity,
+        player: bool,
+        blaze: bool,
+        point: DVec3,
+    },
+}
+
+type ProjectileQuery<'a> = (
+    Entity,
+    &'a EntityIdentity,
+    &'a mut Projectile,
+    &'a mut Position,
+    &'a mut Velocity,
+);
+
+type MobQuery<'a> = (
+    Entity,
+    &'a Position,
+    &'a EntityMetadata,
+    Has<Baby>,
+    &'a CombatProperties,
+);
+
+type PlayerQuery<'a> = (Entity, &'a Position, &'a Rotation, &'a GameModeComponent);
+
+/// Finds what projectiles hit this tick and deals with it, before they're moved.
+#[expect(clippy::too_many_arguments)]
+pub fn tick_projectiles(
+    mut projectiles: Query<ProjectileQuery>,
+    mobs: Query<MobQuery, Without<Projectile>>,
+    players: Query<PlayerQuery, (With<PlayerIdentity>, Without<Projectile>)>,
+    writers: Query<(Entity, &StreamWriter), With<PlayerIdentity>>,
+    state: Res<GlobalStateResource>,
+    registry: Res<PhysicalRegistry>,
+    (mut player_damage, mut entity_damage, mut teleports, mut spawns, mut updates, mut sounds): (
+        MessageWriter<PlayerDamaged>,
+        MessageWriter<EntityDamaged>,
+        MessageWriter<TeleportPlayer>,
+        MessageWriter<SpawnEntityEvent>,
+        MessageWriter<SendEntityUpdate>,
+        MessageWriter<PlaySound>,
+    ),
+    mut commands: Commands,
+) {
+    let mut rng = rand::thread_rng();
+    let mut despawned = Vec::new();
+    for (entity, identity, mut projectile, mut pos, mut vel) in projectiles.iter_mut() {
+        projectile.age += 1;
+        if pos.y < VOID_Y {
+            commands.entity(entity).despawn();
+            despawned.push(identity.entity_id);
+            continue;
+        }
+
+        if let Some(block) = projectile.stuck_in {
+            if is_solid_block(&state.0, block) {
+                projectile.ground_ticks += 1;
+                let returns = projectile.loyalty > 0
+                    && projectile.owner.is_some()
+                    && projectile.ground_ticks > LOYALTY_DELAY_TICKS;
+                // Tridents that can be picked up stay until they are
+                let stays = projectile.kind == ProjectileKind::Trident
+                    && projectile.pickup == Pickup::Allowed;
+                if returns {
+                    projectile.stuck_in = None;
+                    projectile.returning = true;
+                } else if !stays && projectile.ground_ticks >= Projectile::DESPAWN_TICKS {
+                    commands.entity(entity).despawn();
+                    despawned.push(identity.entity_id);
+                }
+                continue;
+            }
+            // The block it was stuck in is gone, so it falls again
+            projectile.stuck_in = None;
+            projectile.ground_ticks = 0;
+            **vel = Vec3A::new(rng.gen(), rng.gen(), rng.gen()) * 0.2;
+        }
+
+        if projectile.kind == ProjectileKind::Trident
+            && projectile.dealt_damage
+            && projectile.loyalty > 0
+        {
+            projectile.returning = true;
+        }
+        if projectile.returning {
+            let owner = projectile.owner.and_then(|owner| players.get(owner).ok());
+            let Some((_, owner_pos, _, _)) = owner else {
+                // The owner left, so it drops where it is
+                if let (Some(stack), Pickup::Allowed) = (&projectile.stack, projectile.pickup) {
+                    spawn_item(
+                        &mut commands,
+                        *pos,
+                        Velocity::zero(),
+                        stack.clone(),
+                        ItemEntity::BLOCK_PICKUP_DELAY,
+                    );
+                }
+                commands.entity(entity).despawn();
+                despawned.push(identity.entity_id);
+                continue;
+            };
+            // Same as vanilla: flies faster and straighter the higher the loyalty
+            let loyalty = projectile.loyalty as f64;
+            let offset = owner_pos.coords + DVec3::new(0.0, EYE_HEIGHT, 0.0) - pos.coords;
+            pos.y += offset.y * 0.015 * loyalty;
+            **vel = **vel * 0.95 + (offset.normalize_or_zero() * 0.05 * loyalty).as_vec3a();
+            continue;
+        }
+
+        let from = pos.coords;
+        let to = from + vel.as_dvec3();
+        let length = (to - from).length();
+        let block_hit = raycast_blocks(from, to, |block| is_solid_block(&state.0, block));
+        let mut nearest = block_hit.map_or(f64::INFINITY, |(_, point)| {
+            if length > 0.0 {
+                (point - from).length() / length
+            } else {
+                0.0
+            }
+        });
+        let mut hit = block_hit.map(|(block, point)| Hit::Block(block, point));
+
+        // Tridents only hit one entity
+        if !projectile.dealt_damage {
+            let is_owner = |target: Entity| {
+                projectile.owner == Some(target) && projectile.age <= OWNER_GRACE_TICKS
+            };
+            let mob_boxes = mobs
+                .iter()
+                .filter(|(target, .., combat)| !combat.is_dead() && !is_owner(*target))
+                .filter_map(|(target, target_pos, metadata, baby, _)| {
+                    let physical = registry.get_or_adult(metadata.protocol_id(), baby)?;
+                    let blaze = metadata.protocol_id() == VanillaEntityType::BLAZE.id;
+                    Some((target, false, blaze, target_pos, physical.bounding_box))
+                });
+            let player_box = registry
+                .get(VanillaEntityType::PLAYER.id, false)
+                .map(|physical| physical.bounding_box);
+            let player_boxes = players
+                .iter()
+                .filter(|(target, _, _, gamemode)| {
+                    gamemode.0 != GameMode::Spectator && !is_owner(*target)
+                })
+                .filter_map(|(target, target_pos, ..)| {
+                    Some((target, true, false, target_pos, player_box?))
+                });
+
+            for (target, player, blaze, target_pos, bounding_box) in mob_boxes.chain(player_boxes) {
+                let margin = DVec3::splat(HIT_MARGIN);
+                let min = target_pos.coords + bounding_box.min.as_dvec3() - margin;
+                let max = target_pos.coords + bounding_box.max.as_dvec3() + margin;
+                let Some(t) = segment_box_intersection(from, to, min, max) else {
+                    continue;
+                };
+                if t < nearest {
+                    nearest = t;
+                    hit = Some(Hit::Entity {
+                        entity: target,
+                        player,
+                        blaze,
+                        point: from + (to - from) * t,
+                    });
+                }
+            }
+        }
+
+        let Some(hit) = hit else {
+            continue;
+        };
+        let (hit_block, hit_point) = match hit {
+            Hit::Block(_, point) => (true, point),
+            Hit::Entity { point, .. } => (false, point),
+        };
+        if let Some(sound) = hit_sound(projectile.kind, hit_block, hit_point) {
+            sounds.write(sound);
+        }
+        let point = match hit {
+            Hit::Block(block, point) => {
+                if projectile.kind.sticks() {
+                    // Stop just short of the block, like vanilla
+                    pos.coords = point - vel.as_dvec3().normalize_or_zero() * 0.05;
+                    **vel = Vec3A::ZERO;
+                    projectile.stuck_in = Some(block);
+                    projectile.critical = false;
+                    updates.write(SendEntityUpdate(entity));
+                    continue;
+                }
+                point
+            }
+            Hit::Entity {
+                entity: target,
+                player,
+                blaze,
+                point,
+            } => {
+                let (mut amount, damage_type) = match projectile.kind {
+                    ProjectileKind::Arrow => (projectile.damage(vel.length()), DamageType::Arrow),
+                    ProjectileKind::Trident => (projectile.damage(0.0), DamageType::Trident),
+                    ProjectileKind::Snowball if blaze => {
+                        (SNOWBALL_BLAZE_DAMAGE, DamageType::Thrown)
+                    }
+                    _ => (0.0, DamageType::Thrown),
+                };
+                if projectile.critical {
+                    amount += rng.gen_range(0..(amount as i32 / 2 + 2)) as f32;
+                }
+                if amount > 0.0 {
+                    if player {
+                        player_damage.write(
+                            PlayerDamaged::new(target, amount, damage_type)
+                                .with_attacker(projectile.owner),
+                        );
+                    } else {
+                        entity_damage.write(EntityDamaged {
+                            entity: target,
+                            amount,
+                            damage_type,
+                            attacker: projectile.owner,
+                        });
+                    }
+                }
+                if projectile.kind == ProjectileKind::Trident {
+                    // Bounces off and falls to the ground
+                    projectile.dealt_damage = true;
+                    **vel *= Vec3A::new(-0.01, -0.1, -0.01);
+                    continue;
+                }
+                point
+            }
+        };
+
+        match projectile.kind {
+            ProjectileKind::Egg => hatch_chicks(&mut commands, Position::from(point), &mut rng),
+            ProjectileKind::EnderPearl => {
+                let owner = projectile.owner.and_then(|owner| players.get(owner).ok());
+                if let Some((owner, _, rotation, gamemode)) = owner {
+                    if gamemode.0 != GameMode::Spectator {
+                        teleports.write(TeleportPlayer {
+                            entity: owner,
+                            x: point.x,
+                            y: point.y,
+                            z: point.z,
+                            vel_x: 0.0,
+                            vel_y: 0.0,
+                            vel_z: 0.0,
+                            yaw: rotation.yaw,
+                            pitch: rotation.pitch,
+                        });
+                        player_damage.write(PlayerDamaged::new(
+                            owner,
+                            ENDER_PEARL_DAMAGE,
+                            DamageType::EnderPearl,
+                        ));
+                        if rng.gen_bool(0.05) {
+                            spawns.write(SpawnEntityEvent {
+                                entity_type: EntityType::Endermite,
+                                position: Position::from(point),
+                            });
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+        commands.entity(entity).despawn();
+        despawned.push(identity.entity_id);
+    }
+
+    if !despawned.is_empty() {
+        broadcast_packet_all(&RemoveEntitiesPacket::from_ids(despawned), writers.iter());
+    }
+}
+
+/// Eggs hatch a chick one time in eight, and four chicks one time in 256, like vanilla.
+fn hatch_chicks(commands: &mut Commands, position: Position, rng: &mut impl Rng) {
+    if !rng.gen_ratio(1, 8) {
+        return;
+    }
+    let count = if rng.gen_ratio(1, 32) { 4 } else { 1 };
+    for _ in 0..count {
+        let bundle = ChickenBundle::new(position);
+        let spawn_packet = SpawnEntityPacket::new(
+            bundle.identity.entity_id,
+            bundle.identity.uuid.as_u128(),
+            bundle.metadata.protocol_id() as i32,
+            &position,
+            &bundle.rotation,
+        );
+        let metadata_packet = EntityMetadataPacket::new(
+            VarInt::new(bundle.identity.entity_id),
+            [Metadata::ageable_mob_baby()],
+        );
+        commands.spawn((
+            bundle,
+            Chicken,
+            Baby,
+            HasGravity,
+            HasCollisions,
+            HasWaterDrag,
+        ));
+        commands.queue(move |world: &mut World| {
+            let mut writers = world.query::<(Entity, &StreamWriter)>();
+            broadcast_packet_all(&spawn_packet, writers.iter(world));
+            broadcast_packet_all(&metadata_packet, writers.iter(world));
+        });
+    }
+}
+
+fn in_water(state: &GlobalState, pos: &Position) -> bool {
+    let chunk = ferrumc_utils::world::load_or_generate_mut(
+        state,
+        ChunkPos::from(pos.coords),
+        DEFAULT_DIMENSION,
+    )
+    .expect("Failed to load or generate chunk");
+    match_block!(
+        "water",
+        chunk.get_block(ChunkBlockPos::from(pos.coords.floor().as_ivec3()))
+    )
+}
+
+/// Slows down flying projectiles and pulls them down after they've moved, then sends their new
+/// positions.
+pub fn fly_projectiles(
+    mut projectiles: Query<(Entity, &Projectile, &Position, &mut Rotation, &mut Velocity)>,
+    state: Res<GlobalStateResource>,
+    mut updates: MessageWriter<SendEntityUpdate>,
+) {
+    for (entity, projectile, pos, mut rot, mut vel) in projectiles.iter_mut() {
+        if projectile.stuck_in.is_some() {
+            continue;
+        }
+        updates.write(SendEntityUpdate(entity));
+        if projectile.returning {
+            *rot = rotation_of(**vel);
+            continue;
+        }
+        let drag = projectile.kind.drag(in_water(&state.0, pos));
+        **vel *= drag;
+        vel.y -= projectile.kind.gravity();
+        *rot = rotation_of(**vel);
+    }
+}
+
+/// Lets players pick up the arrows and tridents that have landed near them, and loyal tridents
+/// that made it back to their owner.
+pub fn pickup_projectiles(
+    projectiles: Query<(Entity, &EntityIdentity, &Projectile, &Position)>,
+    mut collectors: Query<(
+        Entity,
+        &PlayerIdentity,
+        &Position,
+        &GameModeComponent,
+        &mut Inventory,
+    )>,
+    writers: Query<(Entity, &StreamWriter), With<PlayerIdentity>>,
+    mut commands: Commands,
+) {
+    let mut despawned = Vec::new();
+    for (entity, identity, projectile, projectile_pos) in projectiles.iter() {
+        if projectile.stuck_in.is_none() && !projectile.returning {
+            continue;
+        }
+        for (player, player_identity, player_pos, gamemode, mut inventory) in collectors.iter_mut()
+        {
+            if gamemode.0 == GameMode::Spectator
+                || (projectile.returning && projectile.owner != Some(player))
+            {
+                continue;
+            }
+            let offset = projectile_pos.coords - player_pos.coords;
+            if offset.x.abs() > PICKUP_REACH
+                || offset.z.abs() > PICKUP_REACH
+                || !PICKUP_HEIGHT.contains(&offset.y)
+            {
+                continue;
+            }
+
+            let picked = match (projectile.pickup, &projectile.stack) {
+                (Pickup::Allowed, Some(stack)) => {
+                    let max_stack = stack.max_stack_size();
+                    let left = inventory.insert_stack_with_update(
+                        stack,
+                        max_stack,
+                        pickup_slots(),
+                        player,
+                    );
+                    left == 0
+                }
+                (Pickup::CreativeOnly, _) => gamemode.0 == GameMode::Creative,
+                _ => false,
+            };
+            if !picked {
+                continue;
+            }
+
+            let packet =
+                TakeItemEntityPacket::new(identity.entity_id, player_identity.short_uuid, 1);
+            broadcast_packet_all(&packet, writers.iter());
+            commands.entity(entity).despawn();
+            despawned.push(identity.entity_id);
+            break;
+        }
+    }
+
+    if !despawned.is_empty() {
+        broadcast_packet_all(&RemoveEntitiesPacket::from_ids(despawned), writers.iter());
+    }
+}
+
+/// Shows the projectiles already flying around or stuck in blocks to players that just joined.
+pub fn send_projectiles_to_joining_players(
+    mut events: MessageReader<PlayerJoined>,
+    projectiles: Query<
+        (
+            &EntityIdentity,
+            &EntityMetadata,
+            &Position,
+            &Rotation,
+            &Velocity,
+        ),
+        With<Projectile>,
+    >,
+    writers: Query<&StreamWriter, With<PlayerIdentity>>,
+) {
+    for event in events.read() {
+        let Ok(writer) = writers.get(event.entity) else {
+            continue;
+        };
+        for (identity, metadata, pos, rot, vel) in projectiles.iter() {
+            let packet = SpawnEntityPacket::new(
+                identity.entity_id,
+                identity.uuid.as_u128(),
+                metadata.protocol_id() as i32,
+                pos,
+                rot,
+            )
+            .with_velocity(vel);
+            if let Err(err) = writer.send_packet_ref(&packet) {
+                error!(
+                    "Failed to send projectile spawn to {:?}: {:?}",
+                    event.entity, err
+                );
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bow_power() {
+        assert_eq!(bow_power(0), 0.0);
+        assert!(bow_power(2) < 0.1);
+        assert!((bow_power(10) - 5.0 / 12.0).abs() < 1e-6);
+        assert_eq!(bow_power(20), 1.0);
+        assert_eq!(bow_power(100), 1.0);
+    }
+}
@@ -0,0 +1,222 @@
+//! GameSpy4 UDP query protocol, used by server list sites and monitoring tools.
+//!
+//! Clients first ask for a challenge token, then send it back with a basic or full stat request.
+//! See <https://minecraft.wiki/w/Query> for the packet layout.
+
+use ferrumc_config::server_config::get_global_config;
+use ferrumc_state::GlobalState;
+use rand::prelude::SliceRandom;
+use rand::Rng;
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::time::{Duration, Instant};
+use tokio::net::UdpSocket;
+use tracing::{debug, error};
+
+const MAGIC: [u8; 2] = [0xFE, 0xFD];
+const TYPE_HANDSHAKE: u8 = 9;
+const TYPE_STAT: u8 = 0;
+/// Challenge tokens stop being accepted after this long, like vanilla.
+const CHALLENGE_LIFETIME: Duration = Duration::from_secs(30);
+
+/// What the server reports about itself in stat responses.
+struct ServerInfo {
+    motd: String,
+    world: String,
+    players: Vec<String>,
+    max_players: u32,
+    host: String,
+    port: u16,
+}
+
+pub struct QueryServer {
+    socket: UdpSocket,
+    challenges: HashMap<SocketAddr, (i32, Instant)>,
+    state: GlobalState,
+}
+
+impl QueryServer {
+    pub async fn new(state: GlobalState) -> std::io::Result<Self> {
+        let cfg = get_global_config();
+
+        Ok(Self {
+            socket: UdpSocket::bind((cfg.host.as_str(), cfg.query.port)).await?,
+            challenges: HashMap::new(),
+            state,
+        })
+    }
+
+    /// Answers query requests until the runtime stops.
+    pub async fn run(&mut self) {
+        let mut buf = [0u8; 1460];
+        loop {
+            let (len, addr) = match self.socket.recv_from(&mut buf).await {
+                Ok(received) => received,
+                Err(err) => {
+                    error!("Failed receiving query UDP packet: {err}");
+                    continue;
+                }
+            };
+
+            let Some(response) = self.handle(&buf[..len], addr) else {
+                debug!("Ignoring invalid query packet from {addr}");
+                continue;
+            };
+            if let Err(err) = self.socket.send_to(&response, addr).await {
+                error!("Failed sending query UDP packet: {err}");
+            }
+        }
+    }
+
+    fn handle(&mut self, packet: &[u8], addr: SocketAddr) -> Option<Vec<u8>> {
+        let (magic, rest) = packet.split_first_chunk::<2>()?;
+        let (kind, rest) = rest.split_first()?;
+        let (session, payload) = rest.split_first_chunk::<4>()?;
+        if *magic != MAGIC {
+            return None;
+        }
+        let session = i32::from_be_bytes(*session) & 0x0F0F0F0F;
+
+        match *kind {
+            TYPE_HANDSHAKE => {
+                let token = rand::thread_rng().gen_range(0..i32::MAX);
+                self.challenges.insert(addr, (token, Instant::now()));
+                self.challenges
+                    .retain(|_, (_, issued)| issued.elapsed() < CHALLENGE_LIFETIME);
+
+                let mut response = header(TYPE_HANDSHAKE, session);
+                push_str(&mut response, &token.to_string());
+                Some(response)
+            }
+            TYPE_STAT => {
+                let (token, padding) = payload.split_first_chunk::<4>()?;
+                let (expected, issued) = self.challenges.get(&addr)?;
+                if i32::from_be_bytes(*token) != *expected || issued.elapsed() >= CHALLENGE_LIFETIME
+                {
+                    return None;
+                }
+
+                let info = self.server_info();
+                // Full stat requests pad the token with four extra bytes
+                Some(if padding.len() >= 4 {
+                    full_stat(session, &info)
+                } else {
+                    basic_stat(session, &info)
+                })
+            }
+            _ => None,
+        }
+    }
+
+    fn server_info(&self) -> ServerInfo {
+        let cfg = get_global_config();
+
+        ServerInfo {
+            motd: cfg
+                .motd
+                .choose(&mut rand::thread_rng())
+                .cloned()
+                .unwrap_or_default(),
+            world: cfg.world.clone(),
+            players: self
+                .state
+                .players
+                .player_list
+                .iter()
+                .map(|player| player.value().1.clone())
+                .collect(),
+            max_players: cfg.max_players,
+            host: cfg.host.clone(),
+            port: cfg.port,
+        }
+    }
+}
+
+fn header(kind: u8, session: i32) -> Vec<u8> {
+    let mut bytes = vec![kind];
+    bytes.extend_from_slice(&session.to_be_bytes());
+    bytes
+}
+
+/// Pushes a null-terminated string.
+fn push_str(bytes: &mut Vec<u8>, value: &str) {
+    bytes.extend_from_slice(value.as_bytes());
+    bytes.push(0);
+}
+
+fn basic_stat(session: i32, info: &ServerInfo) -> Vec<u8> {
+    let mut bytes = header(TYPE_STAT, session);
+    push_str(&mut bytes, &info.motd);
+    push_str(&mut bytes, "SMP");
+    push_str(&mut bytes, &info.world);
+    push_str(&mut bytes, &info.players.len().to_string());
+    push_str(&mut bytes, &info.max_players.to_string());
+    // The only little-endian field in the protocol
+    bytes.extend_from_slice(&info.port.to_le_bytes());
+    push_str(&mut bytes, &info.host);
+    bytes
+}
+
+fn full_stat(session: i32, info: &ServerInfo) -> Vec<u8> {
+    let mut bytes = header(TYPE_STAT, session);
+    bytes.extend_from_slice(b"splitnum\0\x80\0");
+
+    let pairs = [
+        ("hostname", info.motd.clone()),
+        ("gametype", "SMP".to_string()),
+        ("game_id", "MINECRAFT".to_string()),
+        ("version", "1.21.8".to_string()),
+        // There is no plugin API yet, so only the server software is listed
+        ("plugins", "FerrumC".to_string()),
+        ("map", info.world.clone()),
+        ("numplayers", info.players.len().to_string()),
+        ("maxplayers", info.max_players.to_string()),
+        ("hostport", info.port.to_string()),
+        ("hostip", info.host.clone()),
+    ];
+    for (key, value) in pairs {
+        push_str(&mut bytes, key);
+        push_str(&mut bytes, &value);
+    }
+    bytes.push(0);
+
+    bytes.extend_from_slice(b"\x01player_\0\0");
+    for player in &info.players {
+        push_str(&mut bytes, player);
+    }
+    bytes.push(0);
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn info() -> ServerInfo {
+        ServerInfo {
+            motd: "A FerrumC Server".to_string(),
+            world: "world".to_string(),
+            players: vec!["Steve".to_string(), "Alex".to_string()],
+            max_players: 20,
+            host: "0.0.0.0".to_string(),
+            port: 25565,
+        }
+    }
+
+    #[test]
+    fn test_basic_stat() {
+        let bytes = basic_stat(1, &info());
+        let mut expected = vec![0, 0, 0, 0, 1];
+        expected.extend_from_slice(b"A FerrumC Server\0SMP\0world\x002\x0020\0");
+        expected.extend_from_slice(&[0xDD, 0x63]);
+        expected.extend_from_slice(b"0.0.0.0\0");
+        assert_eq!(bytes, expected);
+    }
+
+    #[test]
+    fn test_full_stat_lists_players() {
+        let bytes = full_stat(1, &info());
+        assert!(bytes.starts_with(b"\0\0\0\0\x01splitnum\0\x80\0hostname\0A FerrumC Server\0"));
+        assert!(bytes.ends_with(b"\0\0\x01player_\0\0Steve\0Alex\0\0"));
+    }
+}
@@ -0,0 +1,61 @@
+use crate::packet_handlers::play_packets::command::resolve;
+use bevy_ecs::prelude::*;
+use ferrumc_commands::messages::{CommandDispatched, ResolvedCommandDispatched};
+use ferrumc_commands::{start_rcon_output, take_rcon_output, Sender};
+use ferrumc_net::rcon::RconCommandReceiver;
+use ferrumc_state::GlobalStateResource;
+use tokio::sync::oneshot;
+use tracing::info;
+
+/// RCON commands dispatched this tick, waiting for their output to be sent back.
+#[derive(Resource, Default)]
+pub struct PendingRconResponses(Vec<(u32, oneshot::Sender<String>)>);
+
+/// Dispatches the commands received over RCON. Runs before the command systems.
+pub fn dispatch_rcon_commands(
+    receiver: Res<RconCommandReceiver>,
+    mut pending: ResMut<PendingRconResponses>,
+    mut dispatch_msgs: MessageWriter<CommandDispatched>,
+    mut resolved_dispatch_msgs: MessageWriter<ResolvedCommandDispatched>,
+    state: Res<GlobalStateResource>,
+) {
+    for rcon_command in receiver.0.try_iter() {
+        let command = rcon_command
+            .command
+            .strip_prefix('/')
+            .unwrap_or(&rcon_command.command)
+            .to_string();
+        info!("Rcon issued server command: /{command}");
+
+        let sender = Sender::Rcon(rcon_command.request);
+        start_rcon_output(rcon_command.request);
+        dispatch_msgs.write(CommandDispatched {
+            command: command.clone(),
+            sender,
+        });
+
+        match resolve(command, sender, state.0.clone()) {
+            Err(err) => sender.send_message(*err, false),
+            Ok((command, ctx)) => {
+                resolved_dispatch_msgs.write(ResolvedCommandDispatched {
+                    command,
+                    ctx,
+                    sender,
+                });
+            }
+        }
+
+        pending
+            .0
+            .push((rcon_command.request, rcon_command.response));
+    }
+}
+
+/// Sends the captured output of this tick's RCON commands back to their clients. Runs after the
+/// command systems.
+pub fn send_rcon_responses(mut pending: ResMut<PendingRconResponses>) {
+    for (request, response) in pending.0.drain(..) {
+        // The client may have disconnected while waiting, which is fine
+        let _ = response.send(take_rcon_output(request));
+    }
+}
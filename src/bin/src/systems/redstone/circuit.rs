@@ -0,0 +1,1019 @@
+use crate::systems::redstone::components::*;
+use crate::systems::redstone::{RedstoneEngine, TORCH_BURNOUT_COOLDOWN, TORCH_BURNOUT_WINDOW};
+use ferrumc_world::block_state_id::BlockStateId;
+use ferrumc_world::block_tick::BlockBehaviourRegistry;
+use ferrumc_world::pos::{BlockPos, Direction};
+use ferrumc_world::World;
+use ferrumc_world::DEFAULT_DIMENSION;
+use std::collections::{HashMap, HashSet};
+use tracing::debug;
+
+/// How many times the dirty set is re-evaluated in a single redstone tick before the rest is left
+/// for the next one. Only instant components (dust, lamps turning on, pistons) can cause more
+/// passes, so this only kicks in for instant feedback loops.
+const MAX_PASSES: usize = 64;
+
+/// Runs one redstone tick against the world.
+pub(super) struct Circuit<'a> {
+    world: &'a World,
+    behaviours: &'a BlockBehaviourRegistry,
+    engine: &'a mut RedstoneEngine,
+    changes: &'a mut Vec<(BlockPos, BlockStateId)>,
+}
+
+impl<'a> Circuit<'a> {
+    pub(super) fn new(
+        world: &'a World,
+        behaviours: &'a BlockBehaviourRegistry,
+        engine: &'a mut RedstoneEngine,
+        changes: &'a mut Vec<(BlockPos, BlockStateId)>,
+    ) -> Self {
+        Self {
+            world,
+            behaviours,
+            engine,
+            changes,
+        }
+    }
+
+    /// Fires the delayed updates that are due, then re-evaluates dirty positions until nothing
+    /// changes anymore.
+    pub(super) fn tick(&mut self) {
+        for pos in self.engine.take_due() {
+            self.fire(pos);
+        }
+
+        for _ in 0..MAX_PASSES {
+            if self.engine.dirty.is_empty() {
+                break;
+            }
+            // Evaluate in a fixed order so the result never depends on where an update came from
+            let mut batch: Vec<BlockPos> = self.engine.dirty.drain().collect();
+            batch.sort_unstable_by_key(|pos| (pos.pos.y, pos.pos.x, pos.pos.z));
+
+            let mut updated_dust = HashSet::new();
+            for pos in batch {
+                self.evaluate(pos, &mut updated_dust);
+            }
+        }
+        if !self.engine.dirty.is_empty() {
+            debug!(
+                "Redstone didn't settle after {MAX_PASSES} passes, continuing next tick ({} dirty)",
+                self.engine.dirty.len()
+            );
+        }
+
+        if self.engine.tick % TORCH_BURNOUT_WINDOW == 0 {
+            self.engine.forget_torch_toggles();
+        }
+        self.engine.tick += 1;
+    }
+
+    fn get(&self, pos: BlockPos) -> Option<BlockStateId> {
+        self.world.get_loaded_block(pos, DEFAULT_DIMENSION)
+    }
+
+    /// Gets a block, treating unloaded chunks as air.
+    fn block(&self, pos: BlockPos) -> BlockStateId {
+        self.get(pos).unwrap_or_else(air)
+    }
+
+    fn set(&mut self, pos: BlockPos, block: BlockStateId) -> bool {
+        if self.get(pos) == Some(block) {
+            return false;
+        }
+        if !self.world.set_loaded_block(pos, DEFAULT_DIMENSION, block) {
+            return false;
+        }
+        self.changes.push((pos, block));
+        self.engine.mark_changed(pos);
+        self.behaviours
+            .notify_neighbours(self.world, DEFAULT_DIMENSION, pos);
+        true
+    }
+
+    /// Reacts to a change at or near `pos`. Anything that happens after a delay is scheduled and
+    /// handled by [`Self::fire`].
+    fn evaluate(&mut self, pos: BlockPos, updated_dust: &mut HashSet<BlockPos>) {
+        let Some(block) = self.get(pos) else {
+            return;
+        };
+        let Some(component) = component(block) else {
+            return;
+        };
+
+        match component {
+            Component::Dust => self.update_dust(pos, updated_dust),
+            Component::Torch => {
+                if flag(block, "lit") == self.torch_attachment_powered(pos, block) {
+                    self.engine.schedule(pos, 1);
+                }
+            }
+            Component::Repeater => self.update_repeater(pos, block),
+            Component::Comparator => {
+                if self.comparator_target(pos, block) != self.comparator_output(pos, block) {
+                    self.engine.schedule(pos, 1);
+                }
+            }
+            Component::Lamp => {
+                let powered = self.is_powered(pos);
+                let lit = flag(block, "lit");
+                if powered && !lit {
+                    if let Some(on) = block.with_property("lit", "true") {
+                        self.set(pos, on);
+                    }
+                } else if !powered && lit {
+                    self.engine.schedule(pos, 2);
+                }
+            }
+            Component::Piston { sticky } => self.update_piston(pos, block, sticky),
+            Component::Button { release_after } => {
+                if flag(block, "powered") {
+                    self.engine.schedule(pos, release_after);
+                }
+            }
+            Component::PressurePlate => {
+                let target = plate_target(block, self.engine.plate_entities(pos));
+                let current = plate_signal(block);
+                if target > current {
+                    if let Some(pressed) = with_plate_signal(block, target) {
+                        self.set(pos, pressed);
+                    }
+                } else if target < current {
+                    self.engine.schedule(pos, 10);
+                }
+            }
+            Component::Tnt => {
+                if self.is_powered(pos) {
+                    self.engine.lit_tnt.push(pos);
+                }
+            }
+            Component::Lever | Component::RedstoneBlock => {}
+        }
+    }
+
+    /// Runs a delayed update scheduled by [`Self::evaluate`]. The component re-checks its inputs
+    /// since they may have changed during the delay.
+    fn fire(&mut self, pos: BlockPos) {
+        let Some(block) = self.get(pos) else {
+            return;
+        };
+        let Some(component) = component(block) else {
+            return;
+        };
+
+        match component {
+            Component::Torch => {
+                let lit = !self.torch_attachment_powered(pos, block);
+                if lit == flag(block, "lit") {
+                    return;
+                }
+                // Torches that flicker too fast burn out and stay off for a while
+                if lit && self.engine.torch_burned_out(pos) {
+                    self.engine.schedule(pos, TORCH_BURNOUT_COOLDOWN);
+                    return;
+                }
+                if !lit {
+                    self.engine.torch_turned_off(pos);
+                }
+                if let Some(updated) = block.with_property("lit", bool_str(lit)) {
+                    self.set(pos, updated);
+                }
+            }
+            Component::Repeater => {
+                if flag(block, "locked") {
+                    return;
+                }
+                let input = self.repeater_input(pos, block);
+                if !flag(block, "powered") {
+                    // A repeater that starts turning on always finishes, so short pulses get
+                    // extended to the repeater's delay
+                    if let Some(on) = block.with_property("powered", "true") {
+                        self.set(pos, on);
+                    }
+                    if !input {
+                        self.engine.schedule(pos, repeater_delay(block));
+                    }
+                } else if !input {
+                    if let Some(off) = block.with_property("powered", "false") {
+                        self.set(pos, off);
+                    }
+                }
+            }
+            Component::Comparator => {
+                let target = self.comparator_target(pos, block);
+                let previous = self.engine.comparator_outputs.insert(pos, target);
+                let updated = block
+                    .with_property("powered", bool_str(target > 0))
+                    .unwrap_or(block);
+                if !self.set(pos, updated) && previous != Some(target) {
+                    // The output strength isn't part of the block state, so the neighbours need
+                    // to be told about it separately
+                    self.engine.mark_changed(pos);
+                }
+            }
+            Component::Lamp => {
+                if flag(block, "lit") && !self.is_powered(pos) {
+                    if let Some(off) = block.with_property("lit", "false") {
+                        self.set(pos, off);
+                    }
+                }
+            }
+            Component::Button { .. } => {
+                if let Some(released) = block.with_property("powered", "false") {
+                    self.set(pos, released);
+                }
+            }
+            Component::PressurePlate => {
+                let target = plate_target(block, self.engine.plate_entities(pos));
+                if target != plate_signal(block) {
+                    if let Some(updated) = with_plate_signal(block, target) {
+                        self.set(pos, updated);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // Signals
+    // -------------------------------------------------------------------------
+
+    /// The strong power the block at `pos` sends into its neighbour on the `toward` side. Strong
+    /// power is what makes a conductor (e.g. stone) powered.
+    fn strong_signal(&self, pos: BlockPos, toward: Direction) -> u8 {
+        let block = self.block(pos);
+        match component(block) {
+            Some(Component::Lever | Component::Button { .. }) => {
+                if flag(block, "powered") && attached_face(block) == Some(toward) {
+                    15
+                } else {
+                    0
+                }
+            }
+            Some(Component::PressurePlate) if toward == Direction::Down => plate_signal(block),
+            Some(Component::Torch) if toward == Direction::Up && flag(block, "lit") => 15,
+            Some(Component::Repeater | Component::Comparator | Component::Dust) => {
+                self.weak_signal(pos, toward)
+            }
+            _ => 0,
+        }
+    }
+
+    /// The power the block at `pos` gives to a component on its `toward` side.
+    fn weak_signal(&self, pos: BlockPos, toward: Direction) -> u8 {
+        let block = self.block(pos);
+        match component(block) {
+            Some(Component::Lever | Component::Button { .. }) => {
+                if flag(block, "powered") {
+                    15
+                } else {
+                    0
+                }
+            }
+            Some(Component::PressurePlate) => plate_signal(block),
+            Some(Component::Torch) => {
+                if flag(block, "lit") && attached_face(block) != Some(toward) {
+                    15
+                } else {
+                    0
+                }
+            }
+            Some(Component::RedstoneBlock) => 15,
+            Some(Component::Repeater) => {
+                if facing(block).map(Direction::opposite) == Some(toward) && flag(block, "powered")
+                {
+                    15
+                } else {
+                    0
+                }
+            }
+            Some(Component::Comparator) => {
+                if facing(block).map(Direction::opposite) == Some(toward) {
+                    self.comparator_output(pos, block)
+                } else {
+                    0
+                }
+            }
+            Some(Component::Dust) => {
+                let power = int_property(block, "power").unwrap_or(0);
+                match toward {
+                    Direction::Up => 0,
+                    Direction::Down => power,
+                    side if block.property(side.as_str()) != Some("none") => power,
+                    _ => 0,
+                }
+            }
+            _ => 0,
+        }
+    }
+
+    /// The strongest power going into the conductor at `pos`.
+    fn conductor_power(&self, pos: BlockPos, include_dust: bool) -> u8 {
+        Direction::ALL
+            .iter()
+            .filter(|dir| include_dust || !is_dust(self.block(pos + **dir)))
+            .map(|dir| self.strong_signal(pos + *dir, dir.opposite()))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// The power a component at `pos` receives from its `from` side.
+    fn input_power(&self, pos: BlockPos, from: Direction) -> u8 {
+        let neighbour = pos + from;
+        if is_conductor(self.block(neighbour)) {
+            self.conductor_power(neighbour, true)
+        } else {
+            self.weak_signal(neighbour, from.opposite())
+        }
+    }
+
+    fn is_powered(&self, pos: BlockPos) -> bool {
+        Direction::ALL
+            .iter()
+            .any(|dir| self.input_power(pos, *dir) > 0)
+    }
+
+    fn torch_attachment_powered(&self, pos: BlockPos, block: BlockStateId) -> bool {
+        attached_face(block).is_some_and(|face| self.input_power(pos, face) > 0)
+    }
+
+    // -------------------------------------------------------------------------
+    // Dust
+    // -------------------------------------------------------------------------
+
+    /// The dust that dust at `pos` connects to: directly beside it, or one block up or down a
+    /// step as long as no conductor cuts the connection.
+    fn dust_links(&self, pos: BlockPos) -> Vec<BlockPos> {
+        let covered = is_conductor(self.block(pos + Direction::Up));
+        let mut links = Vec::new();
+        for dir in Direction::HORIZONTAL {
+            let side = pos + dir;
+            let side_block = self.block(side);
+            if is_dust(side_block) {
+                links.push(side);
+                continue;
+            }
+            if !covered && is_dust(self.block(side + Direction::Up)) {
+                links.push(side + Direction::Up);
+            }
+            if !is_conductor(side_block) && is_dust(self.block(side + Direction::Down)) {
+                links.push(side + Direction::Down);
+            }
+        }
+        links
+    }
+
+    /// Power reaching the dust at `pos` from anything other than dust.
+    fn external_dust_power(&self, pos: BlockPos) -> u8 {
+        Direction::ALL
+            .iter()
+            .map(|dir| {
+                let neighbour = pos + *dir;
+                let block = self.block(neighbour);
+                if is_dust(block) {
+                    0
+                } else if is_conductor(block) {
+                    self.conductor_power(neighbour, false)
+                } else {
+                    self.weak_signal(neighbour, dir.opposite())
+                }
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Recalculates the power and shape of the whole dust network containing `start`.
+    ///
+    /// Vanilla updates dust one wire at a time and each wire notifies its neighbours, which causes
+    /// a huge amount of redundant updates on long lines. Here every wire's external input is
+    /// collected once and power is spread through the network with a breadth-first search,
+    /// strongest sources first, so each wire is visited a constant number of times.
+    fn update_dust(&mut self, start: BlockPos, updated: &mut HashSet<BlockPos>) {
+        if !updated.insert(start) {
+            return;
+        }
+
+        let mut network = vec![start];
+        let mut links = HashMap::new();
+        let mut index = 0;
+        while let Some(pos) = network.get(index).copied() {
+            let neighbours = self.dust_links(pos);
+            for neighbour in &neighbours {
+                if updated.insert(*neighbour) {
+                    network.push(*neighbour);
+                }
+            }
+            links.insert(pos, neighbours);
+            index += 1;
+        }
+
+        let mut levels: HashMap<BlockPos, u8> = network
+            .iter()
+            .map(|pos| (*pos, self.external_dust_power(*pos)))
+            .collect();
+        let mut buckets: [Vec<BlockPos>; 16] = Default::default();
+        for (pos, level) in &levels {
+            buckets[*level as usize].push(*pos);
+        }
+        for level in (2..=15u8).rev() {
+            for pos in std::mem::take(&mut buckets[level as usize]) {
+                if levels[&pos] != level {
+                    continue;
+                }
+                for neighbour in &links[&pos] {
+                    let Some(neighbour_level) = levels.get_mut(neighbour) else {
+                        continue;
+                    };
+                    if *neighbour_level < level - 1 {
+                        *neighbour_level = level - 1;
+                        buckets[level as usize - 1].push(*neighbour);
+                    }
+                }
+            }
+        }
+
+        for pos in network {
+            let block = self.block(pos);
+            if let Some(updated) = self.dust_state(pos, block, levels[&pos]) {
+                self.set(pos, updated);
+            }
+        }
+    }
+
+    /// The state dust at `pos` should have with the given power, including which sides it points
+    /// to.
+    fn dust_state(&self, pos: BlockPos, block: BlockStateId, power: u8) -> Option<BlockStateId> {
+        let covered = is_conductor(self.block(pos + Direction::Up));
+        let mut sides = Direction::HORIZONTAL.map(|dir| {
+            let side = pos + dir;
+            let side_block = self.block(side);
+            if !covered && is_dust(self.block(side + Direction::Up)) {
+                "up"
+            } else if is_dust(side_block)
+                || (!is_conductor(side_block) && is_dust(self.block(side + Direction::Down)))
+                || connects_to_dust(side_block, dir)
+            {
+                "side"
+            } else {
+                "none"
+            }
+        });
+
+        // North, east, south, west: indices 0 and 2 are one axis, 1 and 3 the other
+        let connected = |sides: &[&str; 4], i: usize| sides[i] != "none";
+        let north_south = connected(&sides, 0) || connected(&sides, 2);
+        let east_west = connected(&sides, 1) || connected(&sides, 3);
+        if !north_south && !east_west {
+            // Isolated dust is a cross
+            sides = ["side"; 4];
+        } else if north_south && !east_west {
+            sides[0] = if sides[0] == "none" { "side" } else { sides[0] };
+            sides[2] = if sides[2] == "none" { "side" } else { sides[2] };
+        } else if east_west && !north_south {
+            sides[1] = if sides[1] == "none" { "side" } else { sides[1] };
+            sides[3] = if sides[3] == "none" { "side" } else { sides[3] };
+        }
+
+        let mut state = block.with_property("power", &power.to_string())?;
+        for (dir, side) in Direction::HORIZONTAL.iter().zip(sides) {
+            state = state.with_property(dir.as_str(), side)?;
+        }
+        (state != block).then_some(state)
+    }
+
+    // -------------------------------------------------------------------------
+    // Diodes
+    // -------------------------------------------------------------------------
+
+    fn repeater_input(&self, pos: BlockPos, block: BlockStateId) -> bool {
+        facing(block).is_some_and(|facing| self.input_power(pos, facing) > 0)
+    }
+
+    /// A repeater is locked while a powered repeater or comparator points into its side.
+    fn repeater_locked(&self, pos: BlockPos, block: BlockStateId) -> bool {
+        let Some(facing) = facing(block) else {
+            return false;
+        };
+        [facing.rotate_clockwise(), facing.rotate_counter_clockwise()]
+            .into_iter()
+            .any(|side| {
+                let neighbour = pos + side;
+                matches!(
+                    component(self.block(neighbour)),
+                    Some(Component::Repeater | Component::Comparator)
+                ) && self.weak_signal(neighbour, side.opposite()) > 0
+            })
+    }
+
+    fn update_repeater(&mut self, pos: BlockPos, block: BlockStateId) {
+        let locked = self.repeater_locked(pos, block);
+        if flag(block, "locked") != locked {
+            // Locking is instant, the changed state gets evaluated again next pass
+            if let Some(updated) = block.with_property("locked", bool_str(locked)) {
+                self.set(pos, updated);
+            }
+            return;
+        }
+        if !locked && self.repeater_input(pos, block) != flag(block, "powered") {
+            self.engine.schedule(pos, repeater_delay(block));
+        }
+    }
+
+    /// The strength a comparator is currently outputting.
+    ///
+    /// Output strength isn't part of the block state and is only kept in memory, so comparators
+    /// that haven't updated since the server started fall back to full strength when powered.
+    fn comparator_output(&self, pos: BlockPos, block: BlockStateId) -> u8 {
+        self.engine
+            .comparator_outputs
+            .get(&pos)
+            .copied()
+            .unwrap_or(if flag(block, "powered") { 15 } else { 0 })
+    }
+
+    /// The strength a comparator should output given its current inputs.
+    fn comparator_target(&self, pos: BlockPos, block: BlockStateId) -> u8 {
+        let Some(facing) = facing(block) else {
+            return 0;
+        };
+        let rear = self.input_power(pos, facing);
+        let side = [facing.rotate_clockwise(), facing.rotate_counter_clockwise()]
+            .into_iter()
+            .map(|side| {
+                // Only dust, diodes and redstone blocks count as side inputs
+                let neighbour = pos + side;
+                let neighbour_block = self.block(neighbour);
+                match component(neighbour_block) {
+                    Some(Component::Dust) => int_property(neighbour_block, "power").unwrap_or(0),
+                    Some(
+                        Component::Repeater | Component::Comparator | Component::RedstoneBlock,
+                    ) => self.weak_signal(neighbour, side.opposite()),
+                    _ => 0,
+                }
+            })
+            .max()
+            .unwrap_or(0);
+
+        if block.property("mode") == Some("subtract") {
+            rear.saturating_sub(side)
+        } else if rear >= side {
+            rear
+        } else {
+            0
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // Pistons
+    // -------------------------------------------------------------------------
+
+    /// Pistons are powered from any side except their front. Unlike vanilla they aren't powered
+    /// by blocks diagonally above them (quasi-connectivity), so they always react straight away.
+    fn update_piston(&mut self, pos: BlockPos, block: BlockStateId, sticky: bool) {
+        let Some(facing) = facing(block) else {
+            return;
+        };
+        let powered = Direction::ALL
+            .iter()
+            .filter(|dir| **dir != facing)
+            .any(|dir| self.input_power(pos, *dir) > 0);
+        let extended = flag(block, "extended");
+
+        if powered && !extended {
+            self.extend(pos, block, facing, sticky);
+        } else if !powered && extended {
+            self.retract(pos, block, facing, sticky);
+        }
+    }
+
+    /// Pushes the line of blocks in front of the piston one block forward. Blocks that break when
+    /// pushed end the line and are overwritten. Returns `false` if the line can't move.
+    ///
+    /// There's no block entity for moving blocks yet, so the move happens instantly.
+    fn extend(
+        &mut self,
+        pos: BlockPos,
+        block: BlockStateId,
+        facing: Direction,
+        sticky: bool,
+    ) -> bool {
+        let mut line = Vec::new();
+        let mut cursor = pos + facing;
+        loop {
+            let Some(pushed) = self.get(cursor) else {
+                return false;
+            };
+            if is_air(pushed) || breaks_when_pushed(pushed) {
+                break;
+            }
+            if !is_movable(pushed) || line.len() == PUSH_LIMIT {
+                return false;
+            }
+            line.push((cursor, pushed));
+            cursor = cursor + facing;
+        }
+        // The free spot at the end of the line has to be loaded too
+        if self.get(cursor).is_none() {
+            return false;
+        }
+
+        for (from, pushed) in line.into_iter().rev() {
+            self.set(from + facing, pushed);
+        }
+        self.set(pos + facing, piston_head(facing, sticky));
+        if let Some(extended) = block.with_property("extended", "true") {
+            self.set(pos, extended);
+        }
+        true
+    }
+
+    fn retract(&mut self, pos: BlockPos, block: BlockStateId, facing: Direction, sticky: bool) {
+        let head = pos + facing;
+        if self.block(head).name() == Some("minecraft:piston_head") {
+            self.set(head, air());
+        }
+        if let Some(retracted) = block.with_property("extended", "false") {
+            self.set(pos, retracted);
+        }
+
+        if sticky {
+            let pulled_from = head + facing;
+            let pulled = self.block(pulled_from);
+            if !is_air(pulled) && is_movable(pulled) && !breaks_when_pushed(pulled) {
+                self.set(pulled_from, air());
+                self.set(head, pulled);
+            }
+        }
+    }
+}
+
+fn repeater_delay(block: BlockStateId) -> u64 {
+    int_property(block, "delay").unwrap_or(1) as u64
+}
+
+/// Whether dust points toward a component on its `dir` side.
+fn connects_to_dust(block: BlockStateId, dir: Direction) -> bool {
+    match component(block) {
+        Some(Component::Repeater) => {
+            facing(block).is_some_and(|facing| facing == dir || facing == dir.opposite())
+        }
+        Some(
+            Component::Comparator
+            | Component::Torch
+            | Component::Lever
+            | Component::Button { .. }
+            | Component::PressurePlate
+            | Component::RedstoneBlock,
+        ) => true,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::systems::redstone::TORCH_BURNOUT_TOGGLES;
+    use ferrumc_macros::block;
+    use ferrumc_state::{create_test_state, GlobalStateResource};
+    use ferrumc_world::pos::ChunkPos;
+    use tempfile::TempDir;
+
+    /// Runs redstone ticks against a single empty chunk, which covers x and z 0 to 15.
+    struct Harness {
+        state: GlobalStateResource,
+        _temp_dir: TempDir,
+        behaviours: BlockBehaviourRegistry,
+        engine: RedstoneEngine,
+    }
+
+    impl Harness {
+        fn new() -> Self {
+            let (state, _temp_dir) = create_test_state();
+            ferrumc_utils::world::load_or_generate_mut(
+                &state.0,
+                ChunkPos::new(0, 0),
+                DEFAULT_DIMENSION,
+            )
+            .expect("Failed to load or generate chunk")
+            .fill(block!("air"));
+            Self {
+                state,
+                _temp_dir,
+                behaviours: BlockBehaviourRegistry::new(),
+                engine: RedstoneEngine::default(),
+            }
+        }
+
+        fn set(&mut self, pos: BlockPos, block: BlockStateId) {
+            assert!(self
+                .state
+                .0
+                .world
+                .set_loaded_block(pos, DEFAULT_DIMENSION, block));
+            self.engine.mark_changed(pos);
+        }
+
+        fn get(&self, pos: BlockPos) -> BlockStateId {
+            self.state
+                .0
+                .world
+                .get_loaded_block(pos, DEFAULT_DIMENSION)
+                .expect("block should be loaded")
+        }
+
+        /// Runs a redstone tick and returns the blocks it changed.
+        fn tick(&mut self) -> Vec<(BlockPos, BlockStateId)> {
+            let mut changes = Vec::new();
+            Circuit::new(
+                &self.state.0.world,
+                &self.behaviours,
+                &mut self.engine,
+                &mut changes,
+            )
+            .tick();
+            changes
+        }
+
+        fn run(&mut self, ticks: usize) {
+            for _ in 0..ticks {
+                self.tick();
+            }
+        }
+
+        fn flag(&self, pos: BlockPos, key: &str) -> bool {
+            flag(self.get(pos), key)
+        }
+
+        fn power(&self, pos: BlockPos) -> u8 {
+            int_property(self.get(pos), "power").expect("block should have a power level")
+        }
+
+        /// Places a redstone block at `source` with `length` dust after it in the direction
+        /// `dir`.
+        fn dust_line(&mut self, source: BlockPos, dir: Direction, length: i32) {
+            self.set(source, block!("redstone_block"));
+            let mut pos = source;
+            for _ in 0..length {
+                pos = pos + dir;
+                self.set(pos, dust());
+            }
+        }
+    }
+
+    fn dust() -> BlockStateId {
+        block!("redstone_wire", { east: "none", north: "none", power: 0, south: "none", west: "none" })
+    }
+
+    fn repeater(facing: &str, delay: u8) -> BlockStateId {
+        block!("repeater", { delay: 1, facing: "north", locked: false, powered: false })
+            .with_property("facing", facing)
+            .and_then(|block| block.with_property("delay", &delay.to_string()))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_dust_decays_by_one_per_block() {
+        let mut harness = Harness::new();
+        harness.dust_line(BlockPos::of(0, 64, 8), Direction::East, 15);
+        harness.tick();
+        for x in 1..=15 {
+            assert_eq!(harness.power(BlockPos::of(x, 64, 8)), 16 - x as u8, "x {x}");
+        }
+
+        // Taking the source away turns the whole line off
+        harness.set(BlockPos::of(0, 64, 8), block!("air"));
+        harness.tick();
+        for x in 1..=15 {
+            assert_eq!(harness.power(BlockPos::of(x, 64, 8)), 0, "x {x}");
+        }
+    }
+
+    #[test]
+    fn test_repeater_delay() {
+        let pos = BlockPos::of(8, 64, 8);
+        let input = pos + Direction::West;
+        for delay in 1..=4 {
+            let mut harness = Harness::new();
+            harness.set(pos, repeater("west", delay));
+            harness.set(input, block!("redstone_block"));
+            for _ in 0..delay {
+                harness.tick();
+                assert!(!harness.flag(pos, "powered"), "delay {delay}");
+            }
+            harness.tick();
+            assert!(harness.flag(pos, "powered"), "delay {delay}");
+
+            harness.set(input, block!("air"));
+            for _ in 0..delay {
+                harness.tick();
+                assert!(harness.flag(pos, "powered"), "delay {delay}");
+            }
+            harness.tick();
+            assert!(!harness.flag(pos, "powered"), "delay {delay}");
+        }
+    }
+
+    #[test]
+    fn test_repeater_lock() {
+        let mut harness = Harness::new();
+        let pos = BlockPos::of(8, 64, 8);
+        // A powered repeater pointing into the side of the tested one
+        let side = pos + Direction::South;
+        let side_input = side + Direction::South;
+        harness.set(pos, repeater("west", 1));
+        harness.set(
+            side,
+            block!("repeater", { delay: 1, facing: "south", locked: false, powered: true }),
+        );
+        harness.set(side_input, block!("redstone_block"));
+        harness.tick();
+        assert!(harness.flag(pos, "locked"));
+
+        // Locked repeaters ignore their input
+        harness.set(pos + Direction::West, block!("redstone_block"));
+        harness.run(5);
+        assert!(harness.flag(pos, "locked"));
+        assert!(!harness.flag(pos, "powered"));
+
+        // Once unlocked it picks the input up again
+        harness.set(side_input, block!("air"));
+        harness.run(5);
+        assert!(!harness.flag(pos, "locked"));
+        assert!(harness.flag(pos, "powered"));
+    }
+
+    #[test]
+    fn test_torch_inversion() {
+        let mut harness = Harness::new();
+        let support = BlockPos::of(8, 64, 8);
+        let torch = support + Direction::Up;
+        let lever = support + Direction::West;
+        harness.set(support, block!("stone"));
+        harness.set(torch, block!("redstone_torch", { lit: true }));
+        harness.set(
+            lever,
+            block!("lever", { face: "wall", facing: "west", powered: false }),
+        );
+        harness.run(2);
+        assert!(harness.flag(torch, "lit"));
+
+        // Powering the block the torch stands on turns it off
+        harness.set(
+            lever,
+            block!("lever", { face: "wall", facing: "west", powered: true }),
+        );
+        harness.tick();
+        assert!(harness.flag(torch, "lit"));
+        harness.tick();
+        assert!(!harness.flag(torch, "lit"));
+
+        harness.set(
+            lever,
+            block!("lever", { face: "wall", facing: "west", powered: false }),
+        );
+        harness.run(2);
+        assert!(harness.flag(torch, "lit"));
+    }
+
+    #[test]
+    fn test_torch_burnout() {
+        let mut harness = Harness::new();
+        // A wall torch on the side of a block, with dust from above the torch back onto the block,
+        // which makes a clock that toggles every redstone tick
+        let support = BlockPos::of(8, 64, 8);
+        let torch = support + Direction::East;
+        harness.set(support, block!("stone"));
+        harness.set(
+            torch,
+            block!("redstone_wall_torch", { facing: "east", lit: true }),
+        );
+        harness.set(torch + Direction::Up, dust());
+        harness.set(support + Direction::Up, dust());
+
+        let turned_off = |changes: &[(BlockPos, BlockStateId)]| {
+            changes
+                .iter()
+                .filter(|(pos, block)| *pos == torch && !flag(*block, "lit"))
+                .count()
+        };
+        let mut offs = 0;
+        for _ in 0..20 {
+            offs += turned_off(&harness.tick());
+        }
+        assert_eq!(offs, TORCH_BURNOUT_TOGGLES);
+        assert!(!harness.flag(torch, "lit"));
+
+        // Burned out torches stay off for a while before lighting again
+        for _ in 0..TORCH_BURNOUT_COOLDOWN - 10 {
+            harness.tick();
+            assert!(!harness.flag(torch, "lit"));
+        }
+        assert!((0..20).any(|_| {
+            harness.tick();
+            harness.flag(torch, "lit")
+        }));
+    }
+
+    #[test]
+    fn test_comparator_modes() {
+        let pos = BlockPos::of(8, 64, 8);
+        // Mode, rear and side dust lengths, and the output they should give
+        let cases = [
+            ("compare", 1, None, 15),
+            ("compare", 1, Some(3), 15),
+            ("compare", 3, Some(3), 13),
+            ("compare", 3, Some(1), 0),
+            ("subtract", 3, None, 13),
+            ("subtract", 1, Some(3), 2),
+            ("subtract", 3, Some(1), 0),
+        ];
+        for (mode, rear, side, expected) in cases {
+            let mut harness = Harness::new();
+            let comparator =
+                block!("comparator", { facing: "west", mode: "compare", powered: false })
+                    .with_property("mode", mode)
+                    .unwrap();
+            harness.set(pos, comparator);
+            harness.dust_line(BlockPos::of(8 - rear - 1, 64, 8), Direction::East, rear);
+            if let Some(side) = side {
+                harness.dust_line(BlockPos::of(8, 64, 8 + side + 1), Direction::North, side);
+            }
+            harness.run(3);
+
+            let name = format!("{mode} {rear} {side:?}");
+            assert_eq!(
+                harness
+                    .engine
+                    .comparator_outputs
+                    .get(&pos)
+                    .copied()
+                    .unwrap_or(0),
+                expected,
+                "{name}"
+            );
+            assert_eq!(harness.flag(pos, "powered"), expected > 0, "{name}");
+        }
+    }
+
+    #[test]
+    fn test_piston_push_limit() {
+        let piston = BlockPos::of(0, 64, 8);
+        for (length, extends) in [(0, true), (PUSH_LIMIT, true), (PUSH_LIMIT + 1, false)] {
+            let mut harness = Harness::new();
+            harness.set(
+                piston,
+                block!("piston", { extended: false, facing: "east" }),
+            );
+            for x in 1..=length {
+                harness.set(BlockPos::of(x as i32, 64, 8), block!("stone"));
+            }
+            harness.set(piston + Direction::South, block!("redstone_block"));
+            harness.tick();
+
+            assert_eq!(harness.flag(piston, "extended"), extends, "{length}");
+            if extends {
+                assert_eq!(
+                    harness.get(piston + Direction::East),
+                    piston_head(Direction::East, false)
+                );
+                for x in 2..=length + 1 {
+                    assert_eq!(harness.get(BlockPos::of(x as i32, 64, 8)), block!("stone"));
+                }
+            }
+        }
+
+        // Immovable blocks stop the piston
+        let mut harness = Harness::new();
+        harness.set(
+            piston,
+            block!("piston", { extended: false, facing: "east" }),
+        );
+        harness.set(piston + Direction::East, block!("obsidian"));
+        harness.set(piston + Direction::South, block!("redstone_block"));
+        harness.tick();
+        assert!(!harness.flag(piston, "extended"));
+    }
+
+    #[test]
+    fn test_sticky_piston_pulls() {
+        let mut harness = Harness::new();
+        let piston = BlockPos::of(0, 64, 8);
+        let power = piston + Direction::South;
+        harness.set(
+            piston,
+            block!("sticky_piston", { extended: false, facing: "east" }),
+        );
+        harness.set(piston + Direction::East, block!("stone"));
+        harness.set(power, block!("redstone_block"));
+        harness.tick();
+        assert_eq!(harness.get(BlockPos::of(2, 64, 8)), block!("stone"));
+
+        harness.set(power, block!("air"));
+        harness.tick();
+        assert!(!harness.flag(piston, "extended"));
+        assert_eq!(harness.get(BlockPos::of(1, 64, 8)), block!("stone"));
+        assert_eq!(harness.get(BlockPos::of(2, 64, 8)), block!("air"));
+    }
+}
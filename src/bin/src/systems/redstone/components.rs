@@ -0,0 +1,382 @@
+//! Classifying redstone blocks and reading their state properties.
+
+use ferrumc_data::blocks::types::Block;
+use ferrumc_macros::{block, match_block};
+use ferrumc_world::block_state_id::BlockStateId;
+use ferrumc_world::pos::Direction;
+use ferrumc_world::vanilla_chunk_format::BlockData;
+use std::collections::BTreeMap;
+
+/// How many blocks a piston can push at once.
+pub(super) const PUSH_LIMIT: usize = 12;
+
+/// Blocks pistons can't move. Anything with a negative hardness (bedrock, barriers, portals) is
+/// immovable too, as are extended pistons.
+const IMMOVABLE: &[&str] = &[
+    "obsidian",
+    "crying_obsidian",
+    "respawn_anchor",
+    "reinforced_deepslate",
+    "enchanting_table",
+    "ender_chest",
+    "chest",
+    "trapped_chest",
+    "barrel",
+    "furnace",
+    "blast_furnace",
+    "smoker",
+    "hopper",
+    "dispenser",
+    "dropper",
+    "beacon",
+    "brewing_stand",
+    "lectern",
+    "jukebox",
+    "spawner",
+    "piston_head",
+    "moving_piston",
+];
+
+/// Blocks with no hardness that pistons push instead of breaking.
+const PUSHABLE_INSTANT_BREAK: &[&str] = &["tnt", "slime_block", "honey_block"];
+
+/// The blocks the redstone engine knows how to update.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Component {
+    Dust,
+    Torch,
+    Repeater,
+    Comparator,
+    Lamp,
+    Piston {
+        sticky: bool,
+    },
+    Lever,
+    /// `release_after` is how many redstone ticks the button stays pressed.
+    Button {
+        release_after: u64,
+    },
+    PressurePlate,
+    RedstoneBlock,
+    /// Lights as soon as it's powered.
+    Tnt,
+}
+
+pub(super) fn component(block: BlockStateId) -> Option<Component> {
+    let name = block.name()?.strip_prefix("minecraft:")?;
+    Some(match name {
+        "redstone_wire" => Component::Dust,
+        "redstone_torch" | "redstone_wall_torch" => Component::Torch,
+        "repeater" => Component::Repeater,
+        "comparator" => Component::Comparator,
+        "redstone_lamp" => Component::Lamp,
+        "piston" => Component::Piston { sticky: false },
+        "sticky_piston" => Component::Piston { sticky: true },
+        "lever" => Component::Lever,
+        "redstone_block" => Component::RedstoneBlock,
+        "tnt" => Component::Tnt,
+        "stone_button" | "polished_blackstone_button" => Component::Button { release_after: 10 },
+        name if name.ends_with("_button") => Component::Button { release_after: 15 },
+        name if name.ends_with("_pressure_plate") => Component::PressurePlate,
+        _ => return None,
+    })
+}
+
+pub(super) fn is_dust(block: BlockStateId) -> bool {
+    match_block!("redstone_wire", block)
+}
+
+pub(super) fn is_air(block: BlockStateId) -> bool {
+    match_block!("air", block) || match_block!("cave_air", block) || match_block!("void_air", block)
+}
+
+/// Whether a block is a full solid block that can be powered and pass power on, like stone.
+///
+/// There's no shape data to go on, so this goes by name for the common non-full blocks and treats
+/// anything that breaks instantly (plants, torches, dust) as non-conductive.
+pub(super) fn is_conductor(block: BlockStateId) -> bool {
+    if is_air(block) || component(block).is_some() {
+        return false;
+    }
+    let Some(name) = block
+        .name()
+        .and_then(|name| name.strip_prefix("minecraft:"))
+    else {
+        return false;
+    };
+    const NON_FULL_SUFFIXES: &[&str] = &[
+        "glass",
+        "glass_pane",
+        "leaves",
+        "slab",
+        "stairs",
+        "fence",
+        "fence_gate",
+        "wall",
+        "door",
+        "trapdoor",
+        "carpet",
+        "sign",
+        "banner",
+        "bed",
+        "rail",
+        "ladder",
+        "bars",
+        "chain",
+        "lantern",
+        "head",
+        "skull",
+        "candle",
+        "campfire",
+        "water",
+        "lava",
+        "glowstone",
+        "piston_head",
+        "observer",
+        "hopper",
+        "slime_block",
+        "honey_block",
+    ];
+    if NON_FULL_SUFFIXES
+        .iter()
+        .any(|suffix| name.ends_with(suffix))
+    {
+        return false;
+    }
+    Block::by_name(name).is_some_and(|b| b.hardness != 0.0)
+}
+
+fn hardness(block: BlockStateId) -> Option<f32> {
+    let name = block.name()?.strip_prefix("minecraft:")?;
+    Block::by_name(name).map(|b| b.hardness)
+}
+
+/// Whether a piston destroys this block instead of pushing it, e.g. grass or torches.
+pub(super) fn breaks_when_pushed(block: BlockStateId) -> bool {
+    if match_block!("water", block) || match_block!("lava", block) {
+        return true;
+    }
+    let Some(name) = block
+        .name()
+        .and_then(|name| name.strip_prefix("minecraft:"))
+    else {
+        return false;
+    };
+    !PUSHABLE_INSTANT_BREAK.contains(&name) && hardness(block) == Some(0.0)
+}
+
+pub(super) fn is_movable(block: BlockStateId) -> bool {
+    if matches!(component(block), Some(Component::Piston { .. }))
+        && block.property("extended") == Some("true")
+    {
+        return false;
+    }
+    let Some(name) = block
+        .name()
+        .and_then(|name| name.strip_prefix("minecraft:"))
+    else {
+        return false;
+    };
+    if IMMOVABLE.contains(&name) || name.ends_with("shulker_box") {
+        return false;
+    }
+    hardness(block).is_some_and(|hardness| hardness >= 0.0)
+}
+
+pub(super) fn piston_head(facing: Direction, sticky: bool) -> BlockStateId {
+    let kind = if sticky { "sticky" } else { "normal" };
+    BlockData {
+        name: "minecraft:piston_head".to_string(),
+        properties: Some(BTreeMap::from([
+            ("facing".to_string(), facing.as_str().to_string()),
+            ("short".to_string(), "false".to_string()),
+            ("type".to_string(), kind.to_string()),
+        ])),
+    }
+    .to_block_state_id()
+}
+
+pub(super) fn air() -> BlockStateId {
+    block!("air")
+}
+
+pub(super) fn facing(block: BlockStateId) -> Option<Direction> {
+    Direction::from_name(block.property("facing")?)
+}
+
+/// The side of a lever, button or torch that it's attached to.
+pub(super) fn attached_face(block: BlockStateId) -> Option<Direction> {
+    match block.name()? {
+        "minecraft:redstone_torch" => return Some(Direction::Down),
+        "minecraft:redstone_wall_torch" => return facing(block).map(Direction::opposite),
+        _ => {}
+    }
+    match block.property("face")? {
+        "floor" => Some(Direction::Down),
+        "ceiling" => Some(Direction::Up),
+        _ => facing(block).map(Direction::opposite),
+    }
+}
+
+pub(super) fn flag(block: BlockStateId, key: &str) -> bool {
+    block.property(key) == Some("true")
+}
+
+pub(super) fn int_property(block: BlockStateId, key: &str) -> Option<u8> {
+    block.property(key)?.parse().ok()
+}
+
+/// The signal a pressure plate in this state gives off.
+pub(super) fn plate_signal(block: BlockStateId) -> u8 {
+    int_property(block, "power").unwrap_or(if flag(block, "powered") { 15 } else { 0 })
+}
+
+/// The signal a pressure plate should give off with `entities` standing on it.
+pub(super) fn plate_target(block: BlockStateId, entities: u8) -> u8 {
+    match block.name() {
+        Some("minecraft:light_weighted_pressure_plate") => entities.min(15),
+        Some("minecraft:heavy_weighted_pressure_plate") => entities.div_ceil(10).min(15),
+        _ if entities > 0 => 15,
+        _ => 0,
+    }
+}
+
+/// The pressure plate state that gives off `signal`.
+pub(super) fn with_plate_signal(block: BlockStateId, signal: u8) -> Option<BlockStateId> {
+    if block.property("power").is_some() {
+        block.with_property("power", &signal.to_string())
+    } else {
+        block.with_property("powered", bool_str(signal > 0))
+    }
+}
+
+pub(super) const fn bool_str(value: bool) -> &'static str {
+    if value {
+        "true"
+    } else {
+        "false"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_component() {
+        let cases = [
+            (
+                block!("redstone_wire", { east: "none", north: "none", power: 0, south: "none", west: "none" }),
+                Some(Component::Dust),
+            ),
+            (
+                block!("redstone_wall_torch", { facing: "east", lit: true }),
+                Some(Component::Torch),
+            ),
+            (
+                block!("sticky_piston", { extended: false, facing: "up" }),
+                Some(Component::Piston { sticky: true }),
+            ),
+            (
+                block!("stone_button", { face: "wall", facing: "east", powered: false }),
+                Some(Component::Button { release_after: 10 }),
+            ),
+            (
+                block!("oak_button", { face: "wall", facing: "east", powered: false }),
+                Some(Component::Button { release_after: 15 }),
+            ),
+            (
+                block!("heavy_weighted_pressure_plate", { power: 0 }),
+                Some(Component::PressurePlate),
+            ),
+            (block!("stone"), None),
+        ];
+        for (block, expected) in cases {
+            assert_eq!(component(block), expected, "{:?}", block.name());
+        }
+    }
+
+    #[test]
+    fn test_pushing() {
+        // Whether a piston can move the block, and whether it breaks instead
+        let cases = [
+            (block!("stone"), true, false),
+            (block!("obsidian"), false, false),
+            (block!("bedrock"), false, false),
+            (
+                block!("piston", { extended: false, facing: "up" }),
+                true,
+                false,
+            ),
+            (
+                block!("piston", { extended: true, facing: "up" }),
+                false,
+                false,
+            ),
+            (block!("tnt", { unstable: false }), true, false),
+            (block!("short_grass"), true, true),
+        ];
+        for (block, movable, breaks) in cases {
+            assert_eq!(is_movable(block), movable, "{:?}", block.name());
+            assert_eq!(breaks_when_pushed(block), breaks, "{:?}", block.name());
+        }
+        assert!(breaks_when_pushed(block!("water", { level: 0 })));
+    }
+
+    #[test]
+    fn test_conductors() {
+        assert!(is_conductor(block!("stone")));
+        assert!(!is_conductor(block!("glass")));
+        assert!(!is_conductor(block!("redstone_block")));
+        assert!(!is_conductor(block!("short_grass")));
+        assert!(!is_conductor(block!("air")));
+    }
+
+    #[test]
+    fn test_plate_target() {
+        let light = block!("light_weighted_pressure_plate", { power: 0 });
+        let heavy = block!("heavy_weighted_pressure_plate", { power: 0 });
+        let stone = block!("stone_pressure_plate", { powered: false });
+        let cases = [
+            (light, 0, 0),
+            (light, 3, 3),
+            (light, 20, 15),
+            (heavy, 1, 1),
+            (heavy, 11, 2),
+            (heavy, 200, 15),
+            (stone, 0, 0),
+            (stone, 2, 15),
+        ];
+        for (block, entities, expected) in cases {
+            assert_eq!(plate_target(block, entities), expected, "{entities}");
+            let pressed = with_plate_signal(block, expected).unwrap();
+            assert_eq!(plate_signal(pressed), expected, "{entities}");
+        }
+    }
+
+    #[test]
+    fn test_attached_face() {
+        let cases = [
+            (block!("redstone_torch", { lit: true }), Direction::Down),
+            (
+                block!("redstone_wall_torch", { facing: "east", lit: true }),
+                Direction::West,
+            ),
+            (
+                block!("lever", { face: "floor", facing: "north", powered: false }),
+                Direction::Down,
+            ),
+            (
+                block!("stone_button", { face: "ceiling", facing: "north", powered: false }),
+                Direction::Up,
+            ),
+            (
+                block!("lever", { face: "wall", facing: "west", powered: false }),
+                Direction::East,
+            ),
+        ];
+        for (block, expected) in cases {
+            assert_eq!(attached_face(block), Some(expected), "{:?}", block.name());
+        }
+    }
+}
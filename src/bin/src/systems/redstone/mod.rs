@@ -0,0 +1,208 @@
+//! Redstone.
+//!
+//! Redstone runs on its own schedule, once per redstone tick (two game ticks). Rather than
+//! vanilla's recursive neighbour updates, every block change marks the positions around it as
+//! dirty, and each redstone tick re-evaluates the dirty components in a fixed order until nothing
+//! changes. Compared to vanilla this means:
+//! - Dust networks are recalculated as a whole instead of wire by wire, which is where most
+//!   redstone lag comes from in vanilla.
+//! - The update order only depends on positions, so circuits behave the same regardless of where
+//!   they're built or which direction they face.
+//! - Pistons have no quasi-connectivity and move their blocks instantly.
+//!
+//! Delays are counted in redstone ticks and kept in memory, so components waiting on a delay when
+//! the server stops are re-evaluated from scratch the next time something near them changes.
+
+mod circuit;
+mod components;
+
+use crate::systems::block_ticks::{broadcast_block_changes, BlockBehaviours};
+use bevy_ecs::prelude::{Entity, MessageReader, MessageWriter, Query, Res, ResMut, Resource};
+use circuit::Circuit;
+use components::Component;
+use ferrumc_core::transform::position::Position;
+use ferrumc_messages::{BlockBrokenEvent, BlockPlacedEvent, PrimeTnt};
+use ferrumc_net::connection::StreamWriter;
+use ferrumc_state::GlobalStateResource;
+use ferrumc_world::pos::{BlockPos, Direction};
+use ferrumc_world::DEFAULT_DIMENSION;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// How many times a torch can turn off within [`TORCH_BURNOUT_WINDOW`] before it burns out.
+const TORCH_BURNOUT_TOGGLES: usize = 8;
+/// How many redstone ticks a torch remembers turning off for.
+const TORCH_BURNOUT_WINDOW: u64 = 30;
+/// How many redstone ticks a burned out torch waits before trying to light again.
+const TORCH_BURNOUT_COOLDOWN: u64 = 80;
+
+/// State of the redstone engine that lives between redstone ticks.
+#[derive(Resource, Default)]
+pub struct RedstoneEngine {
+    /// Redstone ticks since the server started.
+    tick: u64,
+    /// Positions that need to be re-evaluated this redstone tick.
+    dirty: HashSet<BlockPos>,
+    /// Delayed updates, keyed by the redstone tick they're due on.
+    scheduled: BTreeMap<u64, Vec<BlockPos>>,
+    /// Positions with an update in `scheduled`, so a component is never queued twice.
+    pending: HashSet<BlockPos>,
+    /// The output strength of every comparator that has updated.
+    comparator_outputs: HashMap<BlockPos, u8>,
+    /// How many entities are standing on each occupied pressure plate.
+    plates: HashMap<BlockPos, u8>,
+    /// TNT that got powered this redstone tick, to be lit by the explosion systems.
+    lit_tnt: Vec<BlockPos>,
+    /// The redstone ticks each torch recently turned off on, to burn out torches in fast clocks.
+    torch_toggles: HashMap<BlockPos, Vec<u64>>,
+}
+
+impl RedstoneEngine {
+    /// Tells the engine the block at `pos` changed, so anything it could power or unpower gets
+    /// re-evaluated on the next redstone tick.
+    ///
+    /// Since a changed block can strongly power the conductors next to it, this marks everything
+    /// up to two blocks away along the axes and diagonals.
+    pub fn mark_changed(&mut self, pos: BlockPos) {
+        self.dirty.insert(pos);
+        for first in Direction::ALL {
+            let neighbour = pos + first;
+            self.dirty.insert(neighbour);
+            for second in Direction::ALL {
+                self.dirty.insert(neighbour + second);
+            }
+        }
+    }
+
+    /// Queues a delayed update for `pos` in `delay` redstone ticks. Does nothing if one is
+    /// already pending.
+    fn schedule(&mut self, pos: BlockPos, delay: u64) {
+        if !self.pending.insert(pos) {
+            return;
+        }
+        self.scheduled
+            .entry(self.tick + delay.max(1))
+            .or_default()
+            .push(pos);
+    }
+
+    /// Removes and returns every delayed update that's due this redstone tick.
+    fn take_due(&mut self) -> Vec<BlockPos> {
+        let mut due = Vec::new();
+        while let Some(entry) = self.scheduled.first_entry() {
+            if *entry.key() > self.tick {
+                break;
+            }
+            due.extend(entry.remove());
+        }
+        for pos in &due {
+            self.pending.remove(pos);
+        }
+        due
+    }
+
+    /// Records the torch at `pos` turning off.
+    fn torch_turned_off(&mut self, pos: BlockPos) {
+        let tick = self.tick;
+        let toggles = self.torch_toggles.entry(pos).or_default();
+        toggles.retain(|toggled| tick - toggled < TORCH_BURNOUT_WINDOW);
+        toggles.push(tick);
+    }
+
+    /// Whether the torch at `pos` turned off too often recently to light again.
+    fn torch_burned_out(&mut self, pos: BlockPos) -> bool {
+        let tick = self.tick;
+        let Some(toggles) = self.torch_toggles.get_mut(&pos) else {
+            return false;
+        };
+        toggles.retain(|toggled| tick - toggled < TORCH_BURNOUT_WINDOW);
+        if toggles.is_empty() {
+            self.torch_toggles.remove(&pos);
+            return false;
+        }
+        toggles.len() >= TORCH_BURNOUT_TOGGLES
+    }
+
+    /// Drops the toggles of torches that haven't turned off in a while.
+    fn forget_torch_toggles(&mut self) {
+        let tick = self.tick;
+        self.torch_toggles.retain(|_, toggles| {
+            toggles.retain(|toggled| tick - toggled < TORCH_BURNOUT_WINDOW);
+            !toggles.is_empty()
+        });
+    }
+
+    fn plate_entities(&self, pos: BlockPos) -> u8 {
+        self.plates.get(&pos).copied().unwrap_or(0)
+    }
+}
+
+/// Feeds blocks placed and broken by players into the engine.
+pub fn queue_block_changes(
+    mut engine: ResMut<RedstoneEngine>,
+    mut broken: MessageReader<BlockBrokenEvent>,
+    mut placed: MessageReader<BlockPlacedEvent>,
+) {
+    for event in broken.read() {
+        engine.mark_changed(event.position);
+    }
+    for event in placed.read() {
+        engine.mark_changed(event.position);
+    }
+}
+
+/// Counts the entities standing on each pressure plate and marks plates whose count changed.
+pub fn detect_pressure_plates(
+    mut engine: ResMut<RedstoneEngine>,
+    state: Res<GlobalStateResource>,
+    entities: Query<&Position>,
+) {
+    let mut plates: HashMap<BlockPos, u8> = HashMap::new();
+    for pos in entities.iter() {
+        let block_pos = BlockPos::of(
+            pos.x.floor() as i32,
+            pos.y.floor() as i32,
+            pos.z.floor() as i32,
+        );
+        let Some(block) = state.0.world.get_loaded_block(block_pos, DEFAULT_DIMENSION) else {
+            continue;
+        };
+        if components::component(block) == Some(Component::PressurePlate) {
+            let count = plates.entry(block_pos).or_default();
+            *count = count.saturating_add(1);
+        }
+    }
+
+    let changed: Vec<BlockPos> = plates
+        .iter()
+        .filter(|(pos, count)| engine.plates.get(*pos) != Some(*count))
+        .map(|(pos, _)| *pos)
+        .chain(
+            engine
+                .plates
+                .keys()
+                .filter(|pos| !plates.contains_key(*pos))
+                .copied(),
+        )
+        .collect();
+    engine.plates = plates;
+    engine.dirty.extend(changed);
+}
+
+/// Runs a single redstone tick and sends the resulting block changes to players.
+pub fn tick_redstone(
+    mut engine: ResMut<RedstoneEngine>,
+    state: Res<GlobalStateResource>,
+    behaviours: Res<BlockBehaviours>,
+    players: Query<(Entity, &StreamWriter, &Position)>,
+    mut lit_tnt: MessageWriter<PrimeTnt>,
+) {
+    let mut changes = Vec::new();
+    Circuit::new(&state.0.world, &behaviours.0, &mut engine, &mut changes).tick();
+    broadcast_block_changes(&state.0, &changes, &players);
+    for position in engine.lit_tnt.drain(..) {
+        lit_tnt.write(PrimeTnt {
+            position,
+            igniter: None,
+        });
+    }
+}
@@ -0,0 +1,173 @@
+//! Scoreboard.
+//!
+//! Objectives, scores and teams are changed by `/scoreboard`, `/team` and the criteria kept up to
+//! date here, and every change is sent to all players. The scoreboard is the same for everyone,
+//! so every player gets every objective, whether it's shown anywhere or not.
+
+use bevy_ecs::prelude::{Entity, MessageReader, Query, ResMut, With};
+use ferrumc_components::health::Health;
+use ferrumc_components::player::experience::Experience;
+use ferrumc_components::player::hunger::Hunger;
+use ferrumc_core::identity::player_identity::PlayerIdentity;
+use ferrumc_core::scoreboard::{ObjectiveCriteria, Scoreboard, ScoreboardChange};
+use ferrumc_messages::player_damage::PlayerDied;
+use ferrumc_messages::PlayerJoined;
+use ferrumc_net::broadcast::broadcast_packet_all;
+use ferrumc_net::connection::StreamWriter;
+use ferrumc_net::packets::outgoing::reset_score::ResetScorePacket;
+use ferrumc_net::packets::outgoing::set_display_objective::SetDisplayObjectivePacket;
+use ferrumc_net::packets::outgoing::set_objective::SetObjectivePacket;
+use ferrumc_net::packets::outgoing::set_player_team::SetPlayerTeamPacket;
+use ferrumc_net::packets::outgoing::set_score::SetScorePacket;
+
+/// The objectives with one of the criteria, by name.
+fn objectives_with(scoreboard: &Scoreboard, criteria: &[ObjectiveCriteria]) -> Vec<String> {
+    scoreboard
+        .objectives()
+        .filter(|(_, objective)| criteria.contains(&objective.criteria))
+        .map(|(name, _)| name.to_string())
+        .collect()
+}
+
+type StatsQuery<'a> = (&'a PlayerIdentity, &'a Health, &'a Hunger, &'a Experience);
+
+/// Keeps the scores of objectives that mirror players' stats up to date.
+pub fn track_criteria(players: Query<StatsQuery>, mut scoreboard: ResMut<Scoreboard>) {
+    let objectives: Vec<(String, ObjectiveCriteria)> = scoreboard
+        .objectives()
+        .filter(|(_, objective)| objective.criteria.read_only())
+        .map(|(name, objective)| (name.to_string(), objective.criteria))
+        .collect();
+    if objectives.is_empty() {
+        return;
+    }
+
+    for (identity, health, hunger, experience) in players.iter() {
+        for (objective, criteria) in &objectives {
+            let value = match criteria {
+                ObjectiveCriteria::Health => health.current.max(0.0).ceil() as i32,
+                ObjectiveCriteria::Food => hunger.level as i32,
+                ObjectiveCriteria::Level => experience.level as i32,
+                ObjectiveCriteria::Xp => experience.total_xp as i32,
+                ObjectiveCriteria::Dummy | ObjectiveCriteria::DeathCount => continue,
+            };
+            // Unchanged scores aren't sent again
+            scoreboard.set_score(&identity.username, objective, value);
+        }
+    }
+}
+
+/// Adds to the death count objectives of players that died.
+pub fn count_deaths(
+    mut events: MessageReader<PlayerDied>,
+    players: Query<&PlayerIdentity>,
+    mut scoreboard: ResMut<Scoreboard>,
+) {
+    let objectives = objectives_with(&scoreboard, &[ObjectiveCriteria::DeathCount]);
+    for event in events.read() {
+        let Ok(identity) = players.get(event.player) else {
+            continue;
+        };
+        for objective in &objectives {
+            scoreboard.add_score(&identity.username, objective, 1);
+        }
+    }
+}
+
+/// Sends the scoreboard's changes to every player, and the whole scoreboard to players that just
+/// joined.
+///
+/// Both happen here so players joining this tick don't get things they're about to be sent
+/// anyway, which clients don't like for objectives and teams.
+pub fn sync_scoreboard(
+    mut events: MessageReader<PlayerJoined>,
+    mut scoreboard: ResMut<Scoreboard>,
+    writers: Query<(Entity, &StreamWriter), With<PlayerIdentity>>,
+) {
+    let joined: Vec<Entity> = events.read().map(|event| event.entity).collect();
+    let others = || {
+        writers
+            .iter()
+            .filter(|(entity, _)| !joined.contains(entity))
+    };
+
+    for change in scoreboard.take_changes() {
+        match change {
+            ScoreboardChange::ObjectiveAdded(name) => {
+                if let Some(objective) = scoreboard.objective(&name) {
+                    broadcast_packet_all(&SetObjectivePacket::create(&name, objective), others());
+                }
+            }
+            ScoreboardChange::ObjectiveUpdated(name) => {
+                if let Some(objective) = scoreboard.objective(&name) {
+                    broadcast_packet_all(&SetObjectivePacket::update(&name, objective), others());
+                }
+            }
+            ScoreboardChange::ObjectiveRemoved(name) => {
+                broadcast_packet_all(&SetObjectivePacket::remove(&name), others());
+            }
+            ScoreboardChange::ScoreChanged { holder, objective } => {
+                if let Some(value) = scoreboard.score(&holder, &objective) {
+                    broadcast_packet_all(
+                        &SetScorePacket::new(&holder, &objective, value),
+                        others(),
+                    );
+                }
+            }
+            ScoreboardChange::ScoreReset { holder, objective } => {
+                broadcast_packet_all(
+                    &ResetScorePacket::new(&holder, objective.as_deref()),
+                    others(),
+                );
+            }
+            ScoreboardChange::DisplayChanged(slot) => {
+                broadcast_packet_all(
+                    &SetDisplayObjectivePacket::new(slot, scoreboard.displayed(slot)),
+                    others(),
+                );
+            }
+            ScoreboardChange::TeamAdded(name) => {
+                if let Some(team) = scoreboard.team(&name) {
+                    broadcast_packet_all(&SetPlayerTeamPacket::create(&name, team), others());
+                }
+            }
+            ScoreboardChange::TeamUpdated(name) => {
+                if let Some(team) = scoreboard.team(&name) {
+                    broadcast_packet_all(&SetPlayerTeamPacket::update(&name, team), others());
+                }
+            }
+            ScoreboardChange::TeamRemoved(name) => {
+                broadcast_packet_all(&SetPlayerTeamPacket::remove(&name), others());
+            }
+            ScoreboardChange::MembersAdded { team, members } => {
+                broadcast_packet_all(&SetPlayerTeamPacket::add_members(&team, members), others());
+            }
+            ScoreboardChange::MembersRemoved { team, members } => {
+                broadcast_packet_all(
+                    &SetPlayerTeamPacket::remove_members(&team, members),
+                    others(),
+                );
+            }
+        }
+    }
+
+    if joined.is_empty() {
+        return;
+    }
+    let joiners = || writers.iter().filter(|(entity, _)| joined.contains(entity));
+    for (name, objective) in scoreboard.objectives() {
+        broadcast_packet_all(&SetObjectivePacket::create(name, objective), joiners());
+        for (holder, value) in scoreboard.scores(name) {
+            broadcast_packet_all(&SetScorePacket::new(holder, name, value), joiners());
+        }
+    }
+    for (slot, objective) in scoreboard.display_slots() {
+        broadcast_packet_all(
+            &SetDisplayObjectivePacket::new(slot, Some(objective)),
+            joiners(),
+        );
+    }
+    for (name, team) in scoreboard.teams() {
+        broadcast_packet_all(&SetPlayerTeamPacket::create(name, team), joiners());
+    }
+}
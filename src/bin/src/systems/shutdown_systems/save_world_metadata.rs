@@ -0,0 +1,33 @@
+use crate::systems::world_sync::world_metadata;
+use bevy_ecs::prelude::Res;
+use ferrumc_core::game_rules::GameRules;
+use ferrumc_core::scoreboard::{Scoreboard, ScoreboardData};
+use ferrumc_core::time::WorldTime;
+use ferrumc_core::weather::Weather;
+use ferrumc_core::world_metadata::{WorldInfo, WorldMetadata};
+use ferrumc_state::GlobalStateResource;
+
+pub fn handle(
+    time: Res<WorldTime>,
+    weather: Res<Weather>,
+    game_rules: Res<GameRules>,
+    info: Res<WorldInfo>,
+    scoreboard: Res<Scoreboard>,
+    state: Res<GlobalStateResource>,
+) {
+    let metadata = world_metadata(&time, &weather, &game_rules, &info);
+    if let Err(e) = state
+        .0
+        .world
+        .save_world_data(WorldMetadata::SAVE_NAME, &metadata)
+    {
+        tracing::error!("Failed to save world metadata: {:?}", e);
+    }
+    if let Err(e) = state
+        .0
+        .world
+        .save_world_data(ScoreboardData::SAVE_NAME, scoreboard.data())
+    {
+        tracing::error!("Failed to save the scoreboard: {:?}", e);
+    }
+}
@@ -0,0 +1,230 @@
+//! Sleeping in beds.
+//!
+//! Right-clicking a bed always sets the player's respawn point. At night it also puts them to
+//! sleep, and once enough of the connected players have been asleep for long enough the night is
+//! skipped. How many is set by the `playersSleepingPercentage` game rule.
+
+use crate::systems::block_ticks::broadcast_block_changes;
+use bevy_ecs::prelude::{Commands, Component, Entity, MessageReader, Query, Res, ResMut};
+use ferrumc_components::player::respawn_point::RespawnPoint;
+use ferrumc_core::game_rules::{GameRule, GameRules};
+use ferrumc_core::identity::player_identity::PlayerIdentity;
+use ferrumc_core::mq;
+use ferrumc_core::time::{LastSentTimeUpdate, WorldTime};
+use ferrumc_core::transform::position::Position;
+use ferrumc_core::transform::rotation::Rotation;
+use ferrumc_core::weather::Weather;
+use ferrumc_messages::PlayerUsedBed;
+use ferrumc_net::broadcast::broadcast_packet_all;
+use ferrumc_net::connection::StreamWriter;
+use ferrumc_net::packets::outgoing::entity_metadata::{EntityMetadata, EntityMetadataPacket};
+use ferrumc_net_codec::net_types::var_int::VarInt;
+use ferrumc_state::{GlobalState, GlobalStateResource};
+use ferrumc_text::ComponentBuilder;
+use ferrumc_world::block_state_id::BlockStateId;
+use ferrumc_world::pos::BlockPos;
+use ferrumc_world::DEFAULT_DIMENSION;
+use std::ops::Range;
+use tracing::error;
+
+/// The times of day players are allowed to go to sleep.
+const SLEEP_WINDOW: Range<u16> = 12542..23460;
+
+/// How long every player needs to have been asleep before the night is skipped.
+const TICKS_TO_SKIP_NIGHT: u32 = 100;
+
+/// Marks a player that's asleep in a bed.
+#[derive(Component)]
+pub struct Sleeping {
+    pub foot: BlockPos,
+    pub head: BlockPos,
+    /// Game ticks since the player went to sleep.
+    pub ticks: u32,
+}
+
+/// Sets the respawn point of players that used a bed and puts them to sleep if it's night.
+pub fn handle_bed_use(
+    mut events: MessageReader<PlayerUsedBed>,
+    state: Res<GlobalStateResource>,
+    world_time: Res<WorldTime>,
+    mut users: Query<(&PlayerIdentity, &Rotation, &mut RespawnPoint)>,
+    sleepers: Query<&Sleeping>,
+    players: Query<(Entity, &StreamWriter, &Position)>,
+    mut commands: Commands,
+) {
+    for event in events.read() {
+        let Ok((identity, rotation, mut respawn_point)) = users.get_mut(event.player) else {
+            continue;
+        };
+        if sleepers.contains(event.player) {
+            continue;
+        }
+
+        let head = event.head;
+        respawn_point.position = Some((head.pos.x, head.pos.y, head.pos.z));
+        respawn_point.angle = rotation.yaw;
+        mq::queue(
+            ComponentBuilder::translate("block.minecraft.set_spawn", vec![]),
+            false,
+            event.player,
+        );
+
+        if !SLEEP_WINDOW.contains(&world_time.current_time()) {
+            mq::queue(
+                ComponentBuilder::translate("block.minecraft.bed.no_sleep", vec![]),
+                true,
+                event.player,
+            );
+            continue;
+        }
+
+        // The occupied flag is left behind if a sleeping player disconnects, so only trust it if
+        // someone is actually in the bed
+        let taken = sleepers.iter().any(|sleeping| sleeping.foot == event.foot);
+        if taken {
+            mq::queue(
+                ComponentBuilder::translate("block.minecraft.bed.occupied", vec![]),
+                true,
+                event.player,
+            );
+            continue;
+        }
+
+        let changes = set_occupied(&state.0, &[event.foot, head], true);
+        broadcast_block_changes(&state.0, &changes, &players);
+
+        let packet = EntityMetadataPacket::new(
+            VarInt::new(identity.short_uuid),
+            [
+                EntityMetadata::entity_sleeping_pose(),
+                EntityMetadata::living_entity_sleeping_position(Some(head.into())),
+            ],
+        );
+        broadcast_packet_all(
+            &packet,
+            players.iter().map(|(entity, conn, _)| (entity, conn)),
+        );
+
+        commands.entity(event.player).insert(Sleeping {
+            foot: event.foot,
+            head,
+            ticks: 0,
+        });
+    }
+}
+
+/// Skips the night once enough connected players have been asleep long enough, and wakes everyone
+/// up when it's no longer night. Sleeping through the night also stops any rain.
+#[expect(clippy::too_many_arguments)]
+pub fn tick_sleeping(
+    state: Res<GlobalStateResource>,
+    mut world_time: ResMut<WorldTime>,
+    mut weather: ResMut<Weather>,
+    game_rules: Res<GameRules>,
+    mut sleepers: Query<(Entity, &PlayerIdentity, &mut Sleeping)>,
+    mut last_sent_time: Query<&mut LastSentTimeUpdate>,
+    players: Query<(Entity, &StreamWriter, &Position)>,
+    mut commands: Commands,
+) {
+    if sleepers.is_empty() {
+        return;
+    }
+    for (_, _, mut sleeping) in sleepers.iter_mut() {
+        sleeping.ticks += 1;
+    }
+
+    let connected: Vec<Entity> = players
+        .iter()
+        .map(|(entity, _, _)| entity)
+        .filter(|entity| state.0.players.is_connected(*entity))
+        .collect();
+    let deep_sleepers = connected
+        .iter()
+        .filter(|entity| {
+            sleepers
+                .get(**entity)
+                .is_ok_and(|(_, _, sleeping)| sleeping.ticks >= TICKS_TO_SKIP_NIGHT)
+        })
+        .count();
+    let percentage = game_rules
+        .get_int(GameRule::PlayersSleepingPercentage)
+        .max(0) as usize;
+    let needed = (connected.len() * percentage).div_ceil(100).max(1);
+    if deep_sleepers >= needed {
+        world_time.set_time(0);
+        if game_rules.get_bool(GameRule::DoWeatherCycle) && weather.is_raining() {
+            weather.reset_cycle();
+        }
+        for mut last_sent in last_sent_time.iter_mut() {
+            last_sent.send_next_tick();
+        }
+    }
+
+    if SLEEP_WINDOW.contains(&world_time.current_time()) {
+        return;
+    }
+    for (entity, identity, sleeping) in sleepers.iter() {
+        wake_up(
+            &mut commands,
+            &state.0,
+            entity,
+            identity.short_uuid,
+            sleeping,
+            &players,
+        );
+    }
+}
+
+/// Gets a player out of bed, freeing the bed up and showing them standing to everyone.
+pub fn wake_up(
+    commands: &mut Commands,
+    state: &GlobalState,
+    entity: Entity,
+    short_uuid: i32,
+    sleeping: &Sleeping,
+    players: &Query<(Entity, &StreamWriter, &Position)>,
+) {
+    commands.entity(entity).remove::<Sleeping>();
+
+    let changes = set_occupied(state, &[sleeping.foot, sleeping.head], false);
+    broadcast_block_changes(state, &changes, players);
+
+    let packet = EntityMetadataPacket::new(
+        VarInt::new(short_uuid),
+        [
+            EntityMetadata::entity_standing(),
+            EntityMetadata::living_entity_sleeping_position(None),
+        ],
+    );
+    broadcast_packet_all(
+        &packet,
+        players.iter().map(|(entity, conn, _)| (entity, conn)),
+    );
+}
+
+/// Sets the `occupied` property on both halves of a bed, skipping any that are no longer beds.
+fn set_occupied(
+    state: &GlobalState,
+    halves: &[BlockPos],
+    occupied: bool,
+) -> Vec<(BlockPos, BlockStateId)> {
+    let value = if occupied { "true" } else { "false" };
+    let mut changes = Vec::new();
+    for pos in halves {
+        let Some(block) = state.world.get_loaded_block(*pos, DEFAULT_DIMENSION) else {
+            continue;
+        };
+        let Some(updated) = block.with_property("occupied", value) else {
+            continue;
+        };
+        if let Err(err) = state
+            .world
+            .set_block_and_fetch(*pos, DEFAULT_DIMENSION, updated)
+        {
+            error!("Failed to update bed at {}: {:?}", pos, err);
+            continue;
+        }
+        changes.push((*pos, updated));
+    }
+    changes
+}
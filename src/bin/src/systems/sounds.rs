@@ -0,0 +1,373 @@
+//! Sounds.
+//!
+//! Gameplay systems fire [`PlaySound`] messages, which get sent to every player close enough to
+//! hear them. Footsteps are played here too, since the server is the only one that knows how far
+//! other players have walked.
+
+use bevy_ecs::prelude::{
+    Commands, Component, Entity, MessageReader, MessageWriter, Query, Res, With,
+};
+use bevy_math::DVec3;
+use ferrumc_components::player::gamemode::{GameMode, GameModeComponent};
+use ferrumc_components::player::sneak::SneakState;
+use ferrumc_core::identity::entity_identity::EntityIdentity;
+use ferrumc_core::identity::player_identity::PlayerIdentity;
+use ferrumc_core::sound::SoundCategory;
+use ferrumc_core::transform::grounded::OnGround;
+use ferrumc_core::transform::position::Position;
+use ferrumc_data::generated::sounds::Sound;
+use ferrumc_messages::{PlaySound, PlayerLeveledUp, SoundSource};
+use ferrumc_net::broadcast::broadcast_packet_all;
+use ferrumc_net::connection::StreamWriter;
+use ferrumc_net::packets::outgoing::entity_sound_effect::EntitySoundEffectPacket;
+use ferrumc_net::packets::outgoing::sound_effect::{SoundEffectPacket, SoundEvent};
+use ferrumc_state::GlobalStateResource;
+use ferrumc_world::block_state_id::BlockStateId;
+use ferrumc_world::pos::BlockPos;
+use ferrumc_world::DEFAULT_DIMENSION;
+
+/// How far a player walks between footsteps, the same as vanilla.
+const STEP_LENGTH: f64 = 1.0 / 0.6;
+
+/// Footsteps are much quieter than breaking or placing blocks.
+const STEP_VOLUME: f32 = 0.15;
+
+/// Moving further than this in one tick is a teleport rather than walking.
+const MAX_STEP_DISTANCE: f64 = 8.0;
+
+/// Something that makes a block play one of its sounds.
+#[derive(Debug, Clone, Copy)]
+pub enum BlockSound {
+    Break,
+    Place,
+    Step,
+}
+
+impl BlockSound {
+    fn suffix(self) -> &'static str {
+        match self {
+            BlockSound::Break => "break",
+            BlockSound::Place => "place",
+            BlockSound::Step => "step",
+        }
+    }
+}
+
+/// The common sets of block sounds, and the words in block names that use them. Checked in order,
+/// and blocks that match none of them sound like stone.
+const SOUND_SETS: &[(&str, &[&str])] = &[
+    ("stone", &["sandstone", "ore"]),
+    ("wool", &["wool", "carpet"]),
+    ("glass", &["glass", "ice"]),
+    ("snow", &["snow"]),
+    ("sand", &["sand"]),
+    (
+        "gravel",
+        &["gravel", "dirt", "farmland", "clay", "podzol", "path"],
+    ),
+    (
+        "grass",
+        &[
+            "grass",
+            "leaves",
+            "sapling",
+            "fern",
+            "mycelium",
+            "hay",
+            "flower",
+            "tulip",
+            "poppy",
+            "dandelion",
+            "orchid",
+            "daisy",
+            "bluet",
+            "allium",
+            "sugar_cane",
+            "wheat",
+            "carrots",
+            "potatoes",
+            "beetroots",
+            "vine",
+            "sponge",
+            "bush",
+        ],
+    ),
+    (
+        "metal",
+        &[
+            "iron_block",
+            "iron_door",
+            "iron_trapdoor",
+            "iron_bars",
+            "gold_block",
+            "rail",
+            "cauldron",
+            "hopper",
+        ],
+    ),
+    (
+        "wood",
+        &[
+            "planks",
+            "log",
+            "wood",
+            "stem",
+            "hyphae",
+            "fence",
+            "door",
+            "sign",
+            "crafting_table",
+            "chest",
+            "barrel",
+            "bookshelf",
+            "lectern",
+            "composter",
+            "note_block",
+            "jukebox",
+            "loom",
+            "fletching_table",
+            "cartography_table",
+            "smithing_table",
+            "campfire",
+        ],
+    ),
+];
+
+/// The sound a block makes, or `None` for air and fluids.
+pub fn block_sound(block: BlockStateId, kind: BlockSound) -> Option<Sound> {
+    named_block_sound(block.name()?, kind)
+}
+
+/// The sound the block with this name makes. Blocks with their own sounds, like amethyst or
+/// copper, use them, and everything else falls back to the closest of the common sets.
+fn named_block_sound(name: &str, kind: BlockSound) -> Option<Sound> {
+    let name = name.trim_start_matches("minecraft:");
+    if matches!(name, "air" | "cave_air" | "void_air" | "water" | "lava") {
+        return None;
+    }
+    if let Some(sound) = Sound::from_name(&format!("block.{name}.{}", kind.suffix())) {
+        return Some(sound);
+    }
+    let set = SOUND_SETS
+        .iter()
+        .find(|(_, words)| words.iter().any(|word| name.contains(word)))
+        .map_or("stone", |(set, _)| set);
+    Sound::from_name(&format!("block.{set}.{}", kind.suffix()))
+}
+
+/// Plays a block's break or place sound at the middle of it, for everyone but `player`, whose
+/// client already plays it.
+pub fn play_block_sound(
+    sounds: &mut MessageWriter<PlaySound>,
+    pos: BlockPos,
+    block: BlockStateId,
+    kind: BlockSound,
+    player: Entity,
+) {
+    let Some(sound) = block_sound(block, kind) else {
+        return;
+    };
+    // Vanilla plays these a bit lower than the sound files
+    sounds.write(
+        PlaySound::at(
+            sound,
+            SoundCategory::Blocks,
+            pos.pos.as_dvec3() + DVec3::splat(0.5),
+        )
+        .with_pitch(0.8)
+        .except(player),
+    );
+}
+
+type SoundSourceQuery<'a> = (
+    &'a Position,
+    Option<&'a EntityIdentity>,
+    Option<&'a PlayerIdentity>,
+);
+
+/// Sends sounds to the players in range of them.
+pub fn play_sounds(
+    mut sounds: MessageReader<PlaySound>,
+    sources: Query<SoundSourceQuery>,
+    listeners: Query<(Entity, &StreamWriter, &Position), With<PlayerIdentity>>,
+) {
+    for sound in sounds.read() {
+        let event = SoundEvent::new(format!("minecraft:{}", sound.sound.to_name()));
+        // Every listener gets the same seed so they all hear the same variant of the sound
+        let seed = rand::random();
+
+        let (position, entity_id) = match sound.source {
+            SoundSource::Position(position) => (position, None),
+            SoundSource::Entity(entity) => {
+                let Ok((position, identity, player)) = sources.get(entity) else {
+                    continue;
+                };
+                let id = player
+                    .map(|player| player.short_uuid)
+                    .or(identity.map(|identity| identity.entity_id));
+                (position.coords, id)
+            }
+        };
+
+        let range = sound.range();
+        let recipients = listeners.iter().filter(|(listener, _, pos)| {
+            Some(*listener) != sound.except
+                && sound
+                    .listeners
+                    .as_ref()
+                    .is_none_or(|listeners| listeners.contains(listener))
+                && pos.coords.distance(position) <= range
+        });
+        let recipients = recipients.map(|(listener, writer, _)| (listener, writer));
+
+        match entity_id {
+            Some(entity_id) => broadcast_packet_all(
+                &EntitySoundEffectPacket::new(
+                    event,
+                    sound.category,
+                    entity_id,
+                    sound.volume,
+                    sound.pitch,
+                    seed,
+                ),
+                recipients,
+            ),
+            None => broadcast_packet_all(
+                &SoundEffectPacket::new(
+                    event,
+                    sound.category,
+                    position,
+                    sound.volume,
+                    sound.pitch,
+                    seed,
+                ),
+                recipients,
+            ),
+        }
+    }
+}
+
+/// How far a player has walked since their last footstep.
+#[derive(Component, Default)]
+pub struct Footsteps {
+    last_position: Option<DVec3>,
+    distance: f64,
+}
+
+type WalkerQuery<'a> = (
+    Entity,
+    &'a Position,
+    &'a OnGround,
+    &'a SneakState,
+    &'a GameModeComponent,
+    Option<&'a mut Footsteps>,
+);
+
+/// Plays the footsteps of walking players to the players around them. Sneaking players don't make
+/// a sound.
+pub fn play_footsteps(
+    mut walkers: Query<WalkerQuery, With<PlayerIdentity>>,
+    state: Res<GlobalStateResource>,
+    mut sounds: MessageWriter<PlaySound>,
+    mut commands: Commands,
+) {
+    for (player, pos, on_ground, sneak, gamemode, footsteps) in walkers.iter_mut() {
+        let Some(mut footsteps) = footsteps else {
+            commands.entity(player).insert(Footsteps::default());
+            continue;
+        };
+        let last = footsteps.last_position.replace(pos.coords);
+        let Some(last) = last else {
+            continue;
+        };
+        if !on_ground.0 || sneak.is_sneaking || gamemode.0 == GameMode::Spectator {
+            continue;
+        }
+        let moved = DVec3::new(pos.x - last.x, 0.0, pos.z - last.z).length();
+        if moved > MAX_STEP_DISTANCE {
+            continue;
+        }
+        footsteps.distance += moved;
+        if footsteps.distance < STEP_LENGTH {
+            continue;
+        }
+        footsteps.distance = 0.0;
+
+        // The block being stood on, which for slabs and carpets is only just below the feet
+        let below = (pos.coords - DVec3::new(0.0, 0.2, 0.0)).floor().as_ivec3();
+        let Some(block) = state
+            .0
+            .world
+            .get_loaded_block(BlockPos::of(below.x, below.y, below.z), DEFAULT_DIMENSION)
+        else {
+            continue;
+        };
+        let Some(sound) = block_sound(block, BlockSound::Step) else {
+            continue;
+        };
+        sounds.write(
+            PlaySound::from_entity(sound, SoundCategory::Players, player)
+                .with_volume(STEP_VOLUME)
+                .except(player),
+        );
+    }
+}
+
+/// Plays the level up jingle every 5 levels, like vanilla.
+pub fn play_level_up_sounds(
+    mut events: MessageReader<PlayerLeveledUp>,
+    mut sounds: MessageWriter<PlaySound>,
+) {
+    for event in events.read() {
+        if event.new_level == 0 || event.new_level % 5 != 0 {
+            continue;
+        }
+        // It gets louder up to level 30
+        let volume = (event.new_level as f32 / 30.0).min(1.0) * 0.75;
+        sounds.write(
+            PlaySound::from_entity(
+                Sound::EntityPlayerLevelup,
+                SoundCategory::Players,
+                event.player,
+            )
+            .with_volume(volume),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_named_block_sound() {
+        // Blocks with their own sounds
+        assert_eq!(
+            named_block_sound("minecraft:amethyst_block", BlockSound::Break),
+            Some(Sound::BlockAmethystBlockBreak)
+        );
+        // Blocks using one of the common sets
+        assert_eq!(
+            named_block_sound("minecraft:oak_planks", BlockSound::Place),
+            Some(Sound::BlockWoodPlace)
+        );
+        assert_eq!(
+            named_block_sound("minecraft:white_wool", BlockSound::Step),
+            Some(Sound::BlockWoolStep)
+        );
+        assert_eq!(
+            named_block_sound("minecraft:sandstone", BlockSound::Break),
+            Some(Sound::BlockStoneBreak)
+        );
+        assert_eq!(
+            named_block_sound("minecraft:red_sand", BlockSound::Break),
+            Some(Sound::BlockSandBreak)
+        );
+        assert_eq!(
+            named_block_sound("minecraft:cobblestone", BlockSound::Break),
+            Some(Sound::BlockStoneBreak)
+        );
+        assert_eq!(named_block_sound("minecraft:air", BlockSound::Step), None);
+        assert_eq!(named_block_sound("minecraft:water", BlockSound::Step), None);
+    }
+}
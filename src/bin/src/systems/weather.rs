@@ -0,0 +1,313 @@
+//! Weather.
+//!
+//! The weather cycles between clear skies, rain and thunderstorms. Clients draw rain or snow
+//! themselves from the rain level they're sent, and decide which one from the biome. During
+//! thunderstorms lightning strikes random columns in loaded chunks where it's raining, hurting
+//! players nearby and setting fire to the block it hits.
+
+use crate::systems::block_ticks::broadcast_block_changes;
+use bevy_ecs::prelude::{
+    Commands, Component, Entity, MessageReader, MessageWriter, Query, Res, ResMut, With,
+};
+use ferrumc_components::player::gamemode::{GameMode, GameModeComponent};
+use ferrumc_core::chunks::chunk_receiver::ChunkReceiver;
+use ferrumc_core::game_rules::{GameRule, GameRules};
+use ferrumc_core::identity::entity_identity::EntityIdentity;
+use ferrumc_core::identity::player_identity::PlayerIdentity;
+use ferrumc_core::transform::position::Position;
+use ferrumc_core::transform::rotation::Rotation;
+use ferrumc_core::weather::Weather;
+use ferrumc_data::biomes::Biome;
+use ferrumc_data::generated::damage_types::DamageType;
+use ferrumc_data::generated::entities::EntityType as VanillaEntityType;
+use ferrumc_macros::{block, match_block};
+use ferrumc_messages::player_damage::PlayerDamaged;
+use ferrumc_messages::PlayerJoined;
+use ferrumc_net::broadcast::broadcast_packet_all;
+use ferrumc_net::connection::StreamWriter;
+use ferrumc_net::packets::outgoing::game_event::GameEventPacket;
+use ferrumc_net::packets::outgoing::registry_data::REGISTRY_PACKETS;
+use ferrumc_net::packets::outgoing::remove_entities::RemoveEntitiesPacket;
+use ferrumc_net::packets::outgoing::spawn_entity::SpawnEntityPacket;
+use ferrumc_state::{GlobalState, GlobalStateResource};
+use ferrumc_world::block_state_id::BlockStateId;
+use ferrumc_world::chunk::Chunk;
+use ferrumc_world::pos::{BlockPos, ChunkBlockPos, ChunkPos};
+use ferrumc_world::DEFAULT_DIMENSION;
+use rand::Rng;
+use std::collections::HashSet;
+use std::sync::LazyLock;
+use tracing::error;
+
+/// Every loaded chunk has a 1 in this many chance of being struck each tick during a
+/// thunderstorm, the same as vanilla.
+const LIGHTNING_CHANCE: u32 = 100_000;
+
+/// How many ticks a lightning bolt stays around before it's removed.
+const LIGHTNING_LIFETIME: u32 = 10;
+
+/// Damage dealt to players close to a lightning strike.
+const LIGHTNING_DAMAGE: f32 = 5.0;
+
+/// How far from a strike players get hurt, horizontally and vertically.
+const LIGHTNING_REACH: (f64, f64) = (3.0, 6.0);
+
+/// Below this temperature it snows instead of rains.
+const SNOW_TEMPERATURE: f64 = 0.15;
+
+/// Above this height biomes get colder the higher up you go.
+const SNOW_LINE: i16 = 80;
+
+/// Biomes by their index in the biome registry sent to clients, which is what chunks store.
+static BIOMES: LazyLock<Vec<Option<&'static Biome>>> = LazyLock::new(|| {
+    REGISTRY_PACKETS
+        .iter()
+        .find(|packet| packet.registry_id == "minecraft:worldgen/biome")
+        .map(|packet| {
+            packet
+                .entries
+                .data
+                .iter()
+                .map(|entry| Biome::from_name(&entry.id))
+                .collect()
+        })
+        .unwrap_or_default()
+});
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Precipitation {
+    None,
+    Rain,
+    Snow,
+}
+
+/// What falls from the sky at a position in a chunk while it's raining.
+fn precipitation_at(chunk: &Chunk, pos: ChunkBlockPos) -> Precipitation {
+    let Some(biome) = BIOMES.get(chunk.get_biome(pos) as usize).copied().flatten() else {
+        return Precipitation::Rain;
+    };
+    if !biome.has_precipitation {
+        return Precipitation::None;
+    }
+
+    let height_adjustment = (pos.y() - SNOW_LINE).max(0) as f64 * 0.05 / 40.0;
+    if biome.temperature - height_adjustment < SNOW_TEMPERATURE {
+        Precipitation::Snow
+    } else {
+        Precipitation::Rain
+    }
+}
+
+/// A lightning bolt that has struck and will disappear soon.
+#[derive(Component)]
+pub struct LightningBolt {
+    /// Ticks left until the bolt is removed.
+    pub ticks: u32,
+}
+
+/// Advances the weather cycle and sends any changes to the players.
+pub fn tick_weather(
+    mut weather: ResMut<Weather>,
+    game_rules: Res<GameRules>,
+    writers: Query<(Entity, &StreamWriter), With<PlayerIdentity>>,
+) {
+    let was_raining = weather.is_raining();
+    let rain_level = weather.rain_level();
+    let thunder_level = weather.thunder_level();
+
+    weather.tick(
+        &mut rand::thread_rng(),
+        game_rules.get_bool(GameRule::DoWeatherCycle),
+    );
+
+    if was_raining != weather.is_raining() {
+        let event = if weather.is_raining() {
+            GameEventPacket::BEGIN_RAINING
+        } else {
+            GameEventPacket::END_RAINING
+        };
+        broadcast_packet_all(&GameEventPacket::new(event, 0.0), writers.iter());
+    }
+    if rain_level != weather.rain_level() {
+        let packet = GameEventPacket::new(GameEventPacket::RAIN_LEVEL_CHANGE, weather.rain_level());
+        broadcast_packet_all(&packet, writers.iter());
+    }
+    if thunder_level != weather.thunder_level() {
+        let packet = GameEventPacket::new(
+            GameEventPacket::THUNDER_LEVEL_CHANGE,
+            weather.thunder_level(),
+        );
+        broadcast_packet_all(&packet, writers.iter());
+    }
+}
+
+pub fn send_weather_to_joining_players(
+    mut events: MessageReader<PlayerJoined>,
+    weather: Res<Weather>,
+    writers: Query<&StreamWriter, With<PlayerIdentity>>,
+) {
+    if !weather.is_raining() {
+        return;
+    }
+    let packets = [
+        GameEventPacket::new(GameEventPacket::BEGIN_RAINING, 0.0),
+        GameEventPacket::new(GameEventPacket::RAIN_LEVEL_CHANGE, weather.rain_level()),
+        GameEventPacket::new(
+            GameEventPacket::THUNDER_LEVEL_CHANGE,
+            weather.thunder_level(),
+        ),
+    ];
+
+    for event in events.read() {
+        let Ok(writer) = writers.get(event.entity) else {
+            continue;
+        };
+        for packet in &packets {
+            if let Err(err) = writer.send_packet_ref(packet) {
+                error!("Failed to send weather to {:?}: {:?}", event.entity, err);
+                break;
+            }
+        }
+    }
+}
+
+/// Strikes lightning in random loaded chunks during thunderstorms.
+#[expect(clippy::too_many_arguments)]
+pub fn strike_lightning(
+    weather: Res<Weather>,
+    game_rules: Res<GameRules>,
+    state: Res<GlobalStateResource>,
+    receivers: Query<&ChunkReceiver>,
+    players: Query<(Entity, &StreamWriter, &Position)>,
+    targets: Query<(Entity, &Position, &GameModeComponent), With<PlayerIdentity>>,
+    mut damage_msgs: MessageWriter<PlayerDamaged>,
+    mut commands: Commands,
+) {
+    if !weather.is_thundering() {
+        return;
+    }
+
+    let mut rng = rand::thread_rng();
+    let loaded: HashSet<(i32, i32)> = receivers
+        .iter()
+        .flat_map(|receiver| receiver.loaded.iter().copied())
+        .collect();
+
+    for (x, z) in loaded {
+        if rng.gen_range(0..LIGHTNING_CHANCE) != 0 {
+            continue;
+        }
+        let Some((pos, struck)) = find_strike_pos(&state.0, ChunkPos::new(x, z), &mut rng) else {
+            continue;
+        };
+
+        let identity = EntityIdentity::new();
+        let position = Position::new(
+            pos.pos.x as f64 + 0.5,
+            pos.pos.y as f64,
+            pos.pos.z as f64 + 0.5,
+        );
+        let packet = SpawnEntityPacket::new(
+            identity.entity_id,
+            identity.uuid.as_u128(),
+            VanillaEntityType::LIGHTNING_BOLT.id as i32,
+            &position,
+            &Rotation::default(),
+        );
+        broadcast_packet_all(
+            &packet,
+            players.iter().map(|(entity, conn, _)| (entity, conn)),
+        );
+
+        for (player, player_pos, gamemode) in targets.iter() {
+            if matches!(gamemode.0, GameMode::Creative | GameMode::Spectator) {
+                continue;
+            }
+            if (player_pos.x - position.x).abs() <= LIGHTNING_REACH.0
+                && (player_pos.z - position.z).abs() <= LIGHTNING_REACH.0
+                && (player_pos.y - position.y).abs() <= LIGHTNING_REACH.1
+            {
+                damage_msgs.write(PlayerDamaged::new(
+                    player,
+                    LIGHTNING_DAMAGE,
+                    DamageType::LightningBolt,
+                ));
+            }
+        }
+
+        commands.spawn((
+            LightningBolt {
+                ticks: LIGHTNING_LIFETIME,
+            },
+            identity,
+            position,
+        ));
+
+        if game_rules.get_bool(GameRule::DoFireTick) {
+            ignite(&state.0, pos, struck, &players);
+        }
+    }
+}
+
+/// Picks a random column of the chunk and returns the position just above its highest block,
+/// along with the block that would be struck. Returns `None` if the chunk isn't loaded, the
+/// column is empty or it isn't raining there.
+fn find_strike_pos(
+    state: &GlobalState,
+    chunk_pos: ChunkPos,
+    rng: &mut impl Rng,
+) -> Option<(BlockPos, BlockStateId)> {
+    let chunk = state
+        .world
+        .get_cache()
+        .get(&(chunk_pos, DEFAULT_DIMENSION.to_string()))?;
+    let (x, z) = (rng.gen_range(0..16u8), rng.gen_range(0..16u8));
+    let y = chunk.highest_block_y(x, z)? + 1;
+    if precipitation_at(&chunk, ChunkBlockPos::new(x, y, z)) != Precipitation::Rain {
+        return None;
+    }
+    let struck = chunk.get_block(ChunkBlockPos::new(x, y - 1, z));
+    Some((chunk_pos.block_offset(x as i32, y as i32, z as i32), struck))
+}
+
+/// Sets fire to the top of the struck block, unless it's a liquid fire can't sit on.
+fn ignite(
+    state: &GlobalState,
+    pos: BlockPos,
+    struck: BlockStateId,
+    players: &Query<(Entity, &StreamWriter, &Position)>,
+) {
+    if match_block!("water", struck) || match_block!("lava", struck) {
+        return;
+    }
+    let fire =
+        block!("fire", {age: 0, east: false, north: false, south: false, up: false, west: false});
+    if let Err(err) = state
+        .world
+        .set_block_and_fetch(pos, DEFAULT_DIMENSION, fire)
+    {
+        error!("Failed to set fire at {}: {:?}", pos, err);
+        return;
+    }
+    broadcast_block_changes(state, &[(pos, fire)], players);
+}
+
+/// Removes lightning bolts once they've faded.
+pub fn tick_lightning(
+    mut bolts: Query<(Entity, &EntityIdentity, &mut LightningBolt)>,
+    players: Query<(Entity, &StreamWriter), With<PlayerIdentity>>,
+    mut commands: Commands,
+) {
+    let mut despawned = Vec::new();
+    for (entity, identity, mut bolt) in bolts.iter_mut() {
+        bolt.ticks = bolt.ticks.saturating_sub(1);
+        if bolt.ticks == 0 {
+            commands.entity(entity).despawn();
+            despawned.push(identity.entity_id);
+        }
+    }
+
+    if !despawned.is_empty() {
+        broadcast_packet_all(&RemoveEntitiesPacket::from_ids(despawned), players.iter());
+    }
+}
@@ -0,0 +1,80 @@
+//! Keeps players' world borders in sync with the server and hurts players outside of them.
+//!
+//! Clients stop players at the border themselves, so players only end up outside when the border
+//! shrinks past them. Keeping them from walking out otherwise is done by the movement handlers.
+
+use bevy_ecs::prelude::{Entity, Local, MessageReader, MessageWriter, Query, Res, ResMut, With};
+use ferrumc_components::player::gamemode::{GameMode, GameModeComponent};
+use ferrumc_config::server_config::get_global_config;
+use ferrumc_core::identity::player_identity::PlayerIdentity;
+use ferrumc_core::transform::position::Position;
+use ferrumc_core::world_border::WorldBorders;
+use ferrumc_data::generated::damage_types::DamageType;
+use ferrumc_messages::player_damage::PlayerDamaged;
+use ferrumc_messages::PlayerJoined;
+use ferrumc_net::broadcast::broadcast_packet_all;
+use ferrumc_net::connection::StreamWriter;
+use ferrumc_net::packets::outgoing::initialize_border::InitializeBorderPacket;
+use ferrumc_world::DEFAULT_DIMENSION;
+use tracing::error;
+
+pub fn send_border_to_joining_players(
+    mut events: MessageReader<PlayerJoined>,
+    borders: Res<WorldBorders>,
+    writers: Query<&StreamWriter, With<PlayerIdentity>>,
+) {
+    for event in events.read() {
+        let Ok(writer) = writers.get(event.entity) else {
+            continue;
+        };
+        let packet = InitializeBorderPacket::new(borders.get(DEFAULT_DIMENSION));
+        if let Err(err) = writer.send_packet_ref(&packet) {
+            error!(
+                "Failed to send world border to {:?}: {:?}",
+                event.entity, err
+            );
+        }
+    }
+}
+
+/// Sends borders changed by `/worldborder` to the players in their dimension.
+pub fn sync_world_borders(
+    mut borders: ResMut<WorldBorders>,
+    writers: Query<(Entity, &StreamWriter), With<PlayerIdentity>>,
+) {
+    for (dimension, border) in borders.take_changed() {
+        if dimension != DEFAULT_DIMENSION {
+            continue;
+        }
+        broadcast_packet_all(&InitializeBorderPacket::new(border), writers.iter());
+    }
+}
+
+/// Hurts players outside the border's safe zone once a second.
+pub fn damage_outside_border(
+    mut ticks: Local<u32>,
+    borders: Res<WorldBorders>,
+    players: Query<(Entity, &Position, &GameModeComponent), With<PlayerIdentity>>,
+    mut damage_msgs: MessageWriter<PlayerDamaged>,
+) {
+    *ticks += 1;
+    if *ticks < get_global_config().tps {
+        return;
+    }
+    *ticks = 0;
+
+    let border = borders.get(DEFAULT_DIMENSION);
+    for (player, pos, gamemode) in players.iter() {
+        if matches!(gamemode.0, GameMode::Creative | GameMode::Spectator) {
+            continue;
+        }
+        let amount = border.damage_at(pos.x, pos.z);
+        if amount > 0.0 {
+            damage_msgs.write(PlayerDamaged::new(
+                player,
+                amount,
+                DamageType::OutsideBorder,
+            ));
+        }
+    }
+}
@@ -8,12 +8,19 @@ use ferrumc_components::player::gamemode::GameModeComponent;
 use ferrumc_components::player::gameplay_state::ender_chest::EnderChest;
 use ferrumc_components::player::hunger::Hunger;
 use ferrumc_components::player::offline_player_data::OfflinePlayerData;
+use ferrumc_components::player::respawn_point::RespawnPoint;
 use ferrumc_core::chunks::world_sync_tracker::WorldSyncTracker;
+use ferrumc_core::game_rules::GameRules;
 use ferrumc_core::identity::player_identity::PlayerIdentity;
+use ferrumc_core::scoreboard::{Scoreboard, ScoreboardData};
+use ferrumc_core::time::WorldTime;
 use ferrumc_core::transform::position::Position;
 use ferrumc_core::transform::rotation::Rotation;
+use ferrumc_core::weather::Weather;
+use ferrumc_core::world_metadata::{WorldInfo, WorldMetadata};
 use ferrumc_inventories::inventory::Inventory;
 use ferrumc_state::GlobalStateResource;
+use tracing::error;
 
 pub fn sync_world(
     player_query: Query<(
@@ -28,14 +35,37 @@ pub fn sync_world(
         &Experience,
         &EnderChest,
         &ActiveEffects,
+        &RespawnPoint,
     )>,
     state: Res<GlobalStateResource>,
+    metadata: (
+        Res<WorldTime>,
+        Res<Weather>,
+        Res<GameRules>,
+        Res<WorldInfo>,
+        Res<Scoreboard>,
+    ),
     mut last_synced: ResMut<WorldSyncTracker>,
 ) {
     if state.0.shut_down.load(std::sync::atomic::Ordering::Relaxed) {
         return;
     }
 
+    let (time, weather, game_rules, info, scoreboard) = metadata;
+    if let Err(e) = state.0.world.save_world_data(
+        WorldMetadata::SAVE_NAME,
+        &world_metadata(&time, &weather, &game_rules, &info),
+    ) {
+        error!("Failed to save world metadata: {:?}", e);
+    }
+    if let Err(e) = state
+        .0
+        .world
+        .save_world_data(ScoreboardData::SAVE_NAME, scoreboard.data())
+    {
+        error!("Failed to save the scoreboard: {:?}", e);
+    }
+
     // Always schedule a sync; frequency is handled by the schedule period.
     state.0.world.sync().expect("Failed to sync world");
 
@@ -51,6 +81,7 @@ pub fn sync_world(
         experience,
         ender_chest,
         active_effects,
+        respawn_point,
     ) in player_query.iter()
     {
         let data = OfflinePlayerData {
@@ -64,6 +95,7 @@ pub fn sync_world(
             experience: *experience,
             ender_chest: ender_chest.clone(),
             active_effects: active_effects.clone(),
+            respawn_point: *respawn_point,
         };
         state
             .0
@@ -74,3 +106,20 @@ pub fn sync_world(
 
     last_synced.last_synced = std::time::Instant::now();
 }
+
+/// Gathers the world's metadata from the resources that hold it while the server runs.
+pub fn world_metadata(
+    time: &WorldTime,
+    weather: &Weather,
+    game_rules: &GameRules,
+    info: &WorldInfo,
+) -> WorldMetadata {
+    WorldMetadata {
+        world_age: time.world_age(),
+        day_time: time.current_time(),
+        spawn: info.spawn,
+        seed: info.seed,
+        weather: weather.clone(),
+        game_rules: game_rules.clone(),
+    }
+}
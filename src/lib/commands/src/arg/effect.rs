@@ -0,0 +1,30 @@
+use crate::{
+    CommandContext, Suggestion,
+    arg::{CommandArgument, ParserResult, utils::parser_error},
+};
+
+use super::PrimitiveArgument;
+use ferrumc_data::generated::effects::Effect;
+
+impl CommandArgument for Effect {
+    fn parse(ctx: &mut CommandContext) -> ParserResult<Self> {
+        let str = ctx.input.read_string();
+
+        Effect::from_name(&str.to_lowercase())
+            .ok_or_else(|| parser_error(&format!("unknown effect: {str}")))
+    }
+
+    fn primitive() -> PrimitiveArgument {
+        // Clients only accept plain words here, so effects are named without the namespace
+        PrimitiveArgument::word()
+    }
+
+    fn suggest(ctx: &mut CommandContext) -> Vec<Suggestion> {
+        ctx.input.read_string();
+
+        Effect::all()
+            .iter()
+            .map(|effect| Suggestion::of(effect.to_name()))
+            .collect()
+    }
+}
@@ -11,6 +11,8 @@ use ::uuid::Uuid;
 use bevy_ecs::prelude::Entity;
 use ferrumc_core::identity::entity_identity::EntityIdentity;
 use ferrumc_core::identity::player_identity::PlayerIdentity;
+use ferrumc_text::TextComponent;
+use std::str::FromStr;
 
 /// Represents an entity argument in a command.
 /// It can be a player name, UUID, or special selectors like @e, @p, @r, @a.
@@ -39,28 +41,37 @@ pub enum EntityArgument {
     RandomPlayer,
 }
 
-impl CommandArgument for EntityArgument {
-    fn parse(ctx: &mut CommandContext) -> ParserResult<Self> {
+/// Parses a single selector, name or UUID, for commands that take them as part of a longer
+/// string.
+impl FromStr for EntityArgument {
+    type Err = Box<TextComponent>;
+
+    fn from_str(input: &str) -> ParserResult<Self> {
         const PREFIXES: &[(&str, EntityArgument)] = &[
             ("@e", EntityArgument::AnyEntity),
             // ("@p", EntityArgument::NearestPlayer),
             ("@r", EntityArgument::RandomPlayer),
             ("@a", EntityArgument::AnyPlayer),
         ];
-        let input = ctx.input.read_string();
         for (prefix, entity_type) in PREFIXES {
             if input == *prefix {
                 return Ok(entity_type.clone());
             }
         }
         if input.len() == 36 && input.chars().all(|c| c.is_ascii_hexdigit() || c == '-') {
-            let uuid = Uuid::parse_str(&input)
+            let uuid = Uuid::parse_str(input)
                 .map_err(|_| crate::arg::utils::parser_error("invalid UUID format"))?;
             Ok(EntityArgument::Uuid(uuid))
         } else {
-            Ok(EntityArgument::PlayerName(input))
+            Ok(EntityArgument::PlayerName(input.to_string()))
         }
     }
+}
+
+impl CommandArgument for EntityArgument {
+    fn parse(ctx: &mut CommandContext) -> ParserResult<Self> {
+        ctx.input.read_string().parse()
+    }
 
     fn primitive() -> PrimitiveArgument {
         PrimitiveArgument::word()
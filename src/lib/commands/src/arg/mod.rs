@@ -6,10 +6,14 @@ use primitive::PrimitiveArgument;
 use crate::{Suggestion, ctx::CommandContext};
 
 pub mod duration;
+pub mod effect;
 pub mod entities;
 pub mod gamemode;
 pub mod position;
 pub mod primitive;
+pub mod scoreboard;
+pub mod sound;
+pub mod text;
 
 pub type ParserResult<T> = Result<T, Box<TextComponent>>;
 
@@ -127,13 +127,9 @@ impl CommandArgument for QuotableString {
 impl CommandArgument for GreedyString {
     fn parse(ctx: &mut CommandContext) -> ParserResult<Self> {
         let input = &mut ctx.input;
-        let mut result = String::new();
-
-        while input.has_remaining_input() {
-            let string = input.read_string();
-
-            result.push_str(&string);
-        }
+        input.skip_whitespace(u32::MAX, false);
+        // Keep the spaces between words
+        let result = input.read(input.remaining_length());
 
         if result.is_empty() {
             return Err(parser_error("string cannot be empty"));
@@ -146,3 +142,30 @@ impl CommandArgument for GreedyString {
         PrimitiveArgument::greedy()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Command, CommandInput, Sender};
+    use ferrumc_state::create_test_state;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_greedy_string_keeps_spaces() {
+        let mut ctx = CommandContext {
+            input: CommandInput {
+                input: "  Red  Team ".to_string(),
+                cursor: 0,
+            },
+            command: Arc::new(Command {
+                name: "",
+                args: vec![],
+            }),
+            sender: Sender::Server,
+            state: create_test_state().0.0,
+        };
+        let string = GreedyString::parse(&mut ctx).unwrap();
+        assert_eq!(string.as_str(), "Red  Team ");
+        assert!(!ctx.input.has_remaining_input());
+    }
+}
@@ -0,0 +1,62 @@
+use crate::{
+    CommandContext, Suggestion,
+    arg::{CommandArgument, ParserResult, utils::parser_error},
+};
+
+use super::PrimitiveArgument;
+use ferrumc_core::scoreboard::{
+    CollisionRule, DisplaySlot, NameTagVisibility, ObjectiveCriteria, RenderType, TeamColor,
+};
+
+/// Implements [`CommandArgument`] for a scoreboard option that is one of a few names.
+macro_rules! named_argument {
+    ($ty:ty, $what:literal, $names:expr) => {
+        impl CommandArgument for $ty {
+            fn parse(ctx: &mut CommandContext) -> ParserResult<Self> {
+                let str = ctx.input.read_string();
+
+                <$ty>::from_name(&str)
+                    .ok_or_else(|| parser_error(&format!(concat!("unknown ", $what, ": {}"), str)))
+            }
+
+            fn primitive() -> PrimitiveArgument {
+                PrimitiveArgument::word()
+            }
+
+            fn suggest(ctx: &mut CommandContext) -> Vec<Suggestion> {
+                ctx.input.read_string();
+
+                $names.into_iter().map(Suggestion::of).collect()
+            }
+        }
+    };
+}
+
+named_argument!(
+    ObjectiveCriteria,
+    "criteria",
+    ObjectiveCriteria::ALL.map(ObjectiveCriteria::name)
+);
+named_argument!(
+    RenderType,
+    "render type",
+    RenderType::ALL.map(RenderType::name)
+);
+named_argument!(TeamColor, "color", TeamColor::ALL.map(TeamColor::name));
+named_argument!(
+    NameTagVisibility,
+    "name tag visibility",
+    NameTagVisibility::ALL.map(NameTagVisibility::name)
+);
+named_argument!(
+    CollisionRule,
+    "collision rule",
+    CollisionRule::ALL.map(CollisionRule::name)
+);
+named_argument!(
+    DisplaySlot,
+    "display slot",
+    DisplaySlot::all()
+        .map(DisplaySlot::name)
+        .collect::<Vec<_>>()
+);
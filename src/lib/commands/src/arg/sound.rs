@@ -0,0 +1,53 @@
+use crate::{
+    CommandContext, Suggestion,
+    arg::{CommandArgument, ParserResult, utils::parser_error},
+};
+
+use super::PrimitiveArgument;
+use ferrumc_core::sound::SoundCategory;
+use ferrumc_data::generated::sounds::Sound;
+
+impl CommandArgument for Sound {
+    fn parse(ctx: &mut CommandContext) -> ParserResult<Self> {
+        let str = ctx.input.read_string();
+
+        Sound::from_name(&str.to_lowercase())
+            .ok_or_else(|| parser_error(&format!("unknown sound: {str}")))
+    }
+
+    fn primitive() -> PrimitiveArgument {
+        // Clients only accept plain words here, so sounds are named without the namespace
+        PrimitiveArgument::word()
+    }
+
+    fn suggest(ctx: &mut CommandContext) -> Vec<Suggestion> {
+        ctx.input.read_string();
+
+        Sound::all()
+            .iter()
+            .map(|sound| Suggestion::of(sound.to_name()))
+            .collect()
+    }
+}
+
+impl CommandArgument for SoundCategory {
+    fn parse(ctx: &mut CommandContext) -> ParserResult<Self> {
+        let str = ctx.input.read_string();
+
+        SoundCategory::from_name(&str)
+            .ok_or_else(|| parser_error(&format!("invalid sound source: {str}")))
+    }
+
+    fn primitive() -> PrimitiveArgument {
+        PrimitiveArgument::word()
+    }
+
+    fn suggest(ctx: &mut CommandContext) -> Vec<Suggestion> {
+        ctx.input.read_string();
+
+        SoundCategory::ALL
+            .into_iter()
+            .map(|category| Suggestion::of(category.name()))
+            .collect()
+    }
+}
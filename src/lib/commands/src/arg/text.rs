@@ -0,0 +1,35 @@
+use crate::{
+    CommandContext, Suggestion,
+    arg::{CommandArgument, ParserResult, utils::parser_error},
+};
+
+use super::PrimitiveArgument;
+use ferrumc_text::TextComponent;
+
+/// Text in JSON, like `{"text":"Hello","color":"gold"}`, a quoted string or a list of them. It
+/// takes the rest of the command.
+impl CommandArgument for TextComponent {
+    fn parse(ctx: &mut CommandContext) -> ParserResult<Self> {
+        let input = &mut ctx.input;
+        input.skip_whitespace(u32::MAX, false);
+        let json = input.read(input.remaining_length());
+
+        if json.is_empty() {
+            return Err(parser_error("text must not be empty"));
+        }
+
+        TextComponent::from_json(&json)
+            .map_err(|err| parser_error(&format!("invalid text component: {err}")))
+    }
+
+    fn primitive() -> PrimitiveArgument {
+        // Sent as a greedy string so clients don't check it against their own component parser
+        PrimitiveArgument::greedy()
+    }
+
+    fn suggest(ctx: &mut CommandContext) -> Vec<Suggestion> {
+        let input = &mut ctx.input;
+        input.read(input.remaining_length());
+        vec![]
+    }
+}
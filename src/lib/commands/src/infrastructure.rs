@@ -13,6 +13,13 @@ static COMMANDS: LazyLock<DashMap<&'static str, Arc<Command>>> = LazyLock::new(D
 static COMMAND_GRAPH: LazyLock<RwLock<CommandGraph>> =
     LazyLock::new(|| RwLock::new(CommandGraph::default()));
 
+/// The system set every command system runs in.
+///
+/// Systems that dispatch commands should run before it, and systems that collect the
+/// output of those commands should run after it.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CommandSystems;
+
 thread_local! {
     static SYSTEMS_TO_BE_REGISTERED: RefCell<Vec<ScheduleConfigs<ScheduleSystem>>> = RefCell::new(Vec::new());
     static EXCLUSIVE_SYSTEMS_TO_BE_REGISTERED: RefCell<Vec<ScheduleConfigs<ScheduleSystem>>> = RefCell::new(Vec::new());
@@ -32,7 +39,7 @@ pub fn register_command_systems(schedule: &mut Schedule) {
     SYSTEMS_TO_BE_REGISTERED.with(|systems| {
         let mut systems = systems.borrow_mut();
         while let Some(sys) = systems.pop() {
-            schedule.add_systems(sys);
+            schedule.add_systems(sys.in_set(CommandSystems));
         }
     });
 }
@@ -59,6 +66,13 @@ pub fn get_command_by_name(name: &str) -> Option<Arc<Command>> {
     COMMANDS.get(name).map(|cmd_ref| Arc::clone(&cmd_ref))
 }
 
+/// Gets the names of all registered commands, sorted alphabetically.
+pub fn get_command_names() -> Vec<&'static str> {
+    let mut names: Vec<_> = COMMANDS.iter().map(|cmd_ref| *cmd_ref.key()).collect();
+    names.sort_unstable();
+    names
+}
+
 /// Attempts to find a command by an `input` string.
 pub fn find_command(input: &str) -> Option<Arc<Command>> {
     let graph = get_graph();
@@ -1,9 +1,19 @@
 //! Command senders.
 
+use std::sync::LazyLock;
+
 use bevy_ecs::prelude::*;
+use dashmap::DashMap;
 use ferrumc_core::mq;
 use ferrumc_text::TextComponent;
-use tracing::info;
+
+/// Output of commands sent over RCON, by request id, until it is sent back to the client.
+/// Only requests started with [`start_rcon_output`] capture anything, so output that comes in
+/// after the response was sent is dropped.
+static RCON_OUTPUT: LazyLock<DashMap<u32, String>> = LazyLock::new(DashMap::new);
+
+/// The most output kept for a single RCON request, in bytes. Anything past it is dropped.
+const MAX_RCON_OUTPUT: usize = 64 * 1024;
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 /// A possible command sender.
@@ -13,6 +23,9 @@ pub enum Sender {
 
     /// The server console has sent a command.
     Server,
+
+    /// A remote console (RCON) client has sent a command, identified by its request id.
+    Rcon(u32),
 }
 
 impl Sender {
@@ -21,9 +34,34 @@ impl Sender {
     pub fn send_message(&self, message: TextComponent, actionbar: bool) {
         match self {
             Sender::Player(entity) => mq::queue(message, actionbar, *entity),
-            Sender::Server => {
-                info!("{message}"); // TODO: serialize into ANSI?
+            Sender::Server => ferrumc_logging::console::print_line(&message.to_ansi_string()),
+            Sender::Rcon(request) => {
+                let Some(mut output) = RCON_OUTPUT.get_mut(request) else {
+                    return;
+                };
+                let message = message.to_plain_string();
+                if output.len() + message.len() + 1 > MAX_RCON_OUTPUT {
+                    return;
+                }
+                if !output.is_empty() {
+                    output.push('\n');
+                }
+                output.push_str(&message);
             }
         }
     }
 }
+
+/// Starts capturing the output of the RCON command with the given `request` id.
+pub fn start_rcon_output(request: u32) {
+    RCON_OUTPUT.insert(request, String::new());
+}
+
+/// Takes the plain text output of the RCON command with the given `request` id, and stops
+/// capturing it.
+pub fn take_rcon_output(request: u32) -> String {
+    RCON_OUTPUT
+        .remove(&request)
+        .map(|(_, output)| output)
+        .unwrap_or_default()
+}
@@ -1,25 +1,168 @@
 use bevy_ecs::prelude::Component;
 use bitcode_derive::{Decode, Encode};
+use ferrumc_data::generated::effects::Effect;
 use std::collections::HashMap;
 
-// --- Placeholders ---
-// TODO: fill this out in some way
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Decode, Encode)]
-pub enum EffectType {
-    Speed,
-    Poison,
-    Regeneration,
-}
-
-#[derive(Debug, Clone, Copy, Decode, Encode)]
+/// The state of a single active effect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Decode, Encode)]
 pub struct EffectState {
+    /// The level of the effect, starting at 0 for level I.
     pub amplifier: u8,
-    /// Duration in server ticks
-    pub duration_ticks: u32,
+    /// Duration in server ticks, or [`EffectState::INFINITE`].
+    pub duration_ticks: i32,
+    /// Whether the effect comes from a beacon, which makes its particles fainter.
+    pub ambient: bool,
+    pub show_particles: bool,
+    pub show_icon: bool,
+}
+
+impl EffectState {
+    /// The duration of effects that never run out.
+    pub const INFINITE: i32 = -1;
+
+    pub fn new(amplifier: u8, duration_ticks: i32) -> Self {
+        Self {
+            amplifier,
+            duration_ticks,
+            ambient: false,
+            show_particles: true,
+            show_icon: true,
+        }
+    }
+
+    pub fn is_infinite(&self) -> bool {
+        self.duration_ticks == Self::INFINITE
+    }
+
+    /// Whether this state should replace `other` when the same effect is applied again. Like
+    /// vanilla, a higher level always wins and the same level only wins if it lasts longer.
+    fn overrides(&self, other: &EffectState) -> bool {
+        if self.amplifier != other.amplifier {
+            return self.amplifier > other.amplifier;
+        }
+        if other.is_infinite() {
+            return false;
+        }
+        self.is_infinite() || self.duration_ticks > other.duration_ticks
+    }
 }
 
 /// Tracks all active potion effects on the player.
-#[derive(Component, Debug, Clone, Default, Decode, Encode)]
+///
+/// Effects are stored by name so saved players keep working when effects are added to or
+/// removed from the registry.
+#[derive(Component, Debug, Clone, Default, PartialEq, Decode, Encode)]
 pub struct ActiveEffects {
-    pub effects: HashMap<EffectType, EffectState>,
+    effects: HashMap<String, EffectState>,
+}
+
+impl ActiveEffects {
+    pub fn get(&self, effect: Effect) -> Option<&EffectState> {
+        self.effects.get(effect.to_name())
+    }
+
+    pub fn has(&self, effect: Effect) -> bool {
+        self.effects.contains_key(effect.to_name())
+    }
+
+    /// The amplifier of an effect, if it is active.
+    pub fn amplifier(&self, effect: Effect) -> Option<u8> {
+        self.get(effect).map(|state| state.amplifier)
+    }
+
+    /// Adds an effect, returning whether it changed anything. An effect that's already active
+    /// is only replaced by a stronger or longer one.
+    pub fn add(&mut self, effect: Effect, state: EffectState) -> bool {
+        match self.effects.get_mut(effect.to_name()) {
+            Some(current) if !state.overrides(current) => false,
+            Some(current) => {
+                *current = state;
+                true
+            }
+            None => {
+                self.effects.insert(effect.to_name().to_string(), state);
+                true
+            }
+        }
+    }
+
+    /// Removes an effect, returning its state if it was active.
+    pub fn remove(&mut self, effect: Effect) -> Option<EffectState> {
+        self.effects.remove(effect.to_name())
+    }
+
+    /// Removes every effect, returning the ones that were active.
+    pub fn clear(&mut self) -> Vec<Effect> {
+        let removed = self.iter().map(|(effect, _)| effect).collect();
+        self.effects.clear();
+        removed
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.effects.is_empty()
+    }
+
+    /// Iterates over the active effects. Effects that no longer exist in the registry are
+    /// skipped.
+    pub fn iter(&self) -> impl Iterator<Item = (Effect, &EffectState)> {
+        self.effects
+            .iter()
+            .filter_map(|(name, state)| Some((Effect::from_name(name)?, state)))
+    }
+
+    /// Counts down every effect by one tick, removing and returning the ones that ran out.
+    pub fn tick(&mut self) -> Vec<Effect> {
+        let mut expired = Vec::new();
+        self.effects.retain(|name, state| {
+            if state.is_infinite() {
+                return true;
+            }
+            state.duration_ticks -= 1;
+            if state.duration_ticks > 0 {
+                return true;
+            }
+            expired.extend(Effect::from_name(name));
+            false
+        });
+        expired
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_replaces_weaker_effects() {
+        let mut effects = ActiveEffects::default();
+        assert!(effects.add(Effect::Speed, EffectState::new(0, 100)));
+        // Shorter effects of the same level don't replace longer ones
+        assert!(!effects.add(Effect::Speed, EffectState::new(0, 50)));
+        assert!(effects.add(Effect::Speed, EffectState::new(0, 200)));
+        assert_eq!(effects.get(Effect::Speed).unwrap().duration_ticks, 200);
+        // Higher levels always win, lower ones never do
+        assert!(effects.add(Effect::Speed, EffectState::new(1, 20)));
+        assert!(!effects.add(Effect::Speed, EffectState::new(0, 1000)));
+        assert_eq!(effects.amplifier(Effect::Speed), Some(1));
+
+        assert!(effects.add(Effect::Speed, EffectState::new(1, EffectState::INFINITE)));
+        assert!(!effects.add(Effect::Speed, EffectState::new(1, 1000)));
+    }
+
+    #[test]
+    fn test_tick_expires_effects() {
+        let mut effects = ActiveEffects::default();
+        effects.add(Effect::Poison, EffectState::new(0, 2));
+        effects.add(
+            Effect::NightVision,
+            EffectState::new(0, EffectState::INFINITE),
+        );
+
+        assert!(effects.tick().is_empty());
+        assert_eq!(effects.tick(), vec![Effect::Poison]);
+        assert!(!effects.has(Effect::Poison));
+        assert!(effects.has(Effect::NightVision));
+        assert_eq!(effects.clear(), vec![Effect::NightVision]);
+        assert!(effects.is_empty());
+    }
 }
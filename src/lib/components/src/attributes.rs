@@ -0,0 +1,314 @@
+use bevy_ecs::prelude::Component;
+use ferrumc_data::generated::attributes::Attribute;
+use ferrumc_data::generated::effects::Effect;
+use ferrumc_data::generated::items::Operation;
+use std::collections::BTreeMap;
+
+/// How a modifier changes the value of an attribute.
+///
+/// All `Add` modifiers are added to the base value first, then every `MultiplyBase` modifier
+/// adds its amount times that sum, and finally each `MultiplyTotal` modifier multiplies the
+/// result by one plus its amount.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModifierOperation {
+    Add,
+    MultiplyBase,
+    MultiplyTotal,
+}
+
+impl ModifierOperation {
+    /// The protocol ID of the operation.
+    pub fn id(self) -> u8 {
+        match self {
+            Self::Add => 0,
+            Self::MultiplyBase => 1,
+            Self::MultiplyTotal => 2,
+        }
+    }
+
+    pub fn from_id(id: i32) -> Option<Self> {
+        match id {
+            0 => Some(Self::Add),
+            1 => Some(Self::MultiplyBase),
+            2 => Some(Self::MultiplyTotal),
+            _ => None,
+        }
+    }
+}
+
+impl From<Operation> for ModifierOperation {
+    fn from(operation: Operation) -> Self {
+        match operation {
+            Operation::AddValue => Self::Add,
+            Operation::AddMultipliedBase => Self::MultiplyBase,
+            Operation::AddMultipliedTotal => Self::MultiplyTotal,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttributeModifier {
+    /// The resource location identifying the modifier, e.g. `minecraft:effect.speed`. An
+    /// attribute only has one modifier with any given ID.
+    pub id: String,
+    pub amount: f64,
+    pub operation: ModifierOperation,
+}
+
+impl AttributeModifier {
+    pub fn new(id: impl Into<String>, amount: f64, operation: ModifierOperation) -> Self {
+        Self {
+            id: id.into(),
+            amount,
+            operation,
+        }
+    }
+}
+
+/// The base value of an attribute along with its modifiers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttributeInstance {
+    pub base: f64,
+    modifiers: Vec<AttributeModifier>,
+}
+
+impl AttributeInstance {
+    fn new(base: f64) -> Self {
+        Self {
+            base,
+            modifiers: Vec::new(),
+        }
+    }
+
+    pub fn modifiers(&self) -> &[AttributeModifier] {
+        &self.modifiers
+    }
+
+    /// The value of the attribute with all the modifiers applied.
+    pub fn value(&self, attribute: &Attribute) -> f64 {
+        let amounts = |operation| {
+            self.modifiers
+                .iter()
+                .filter(move |modifier| modifier.operation == operation)
+                .map(|modifier| modifier.amount)
+        };
+
+        let base = self.base + amounts(ModifierOperation::Add).sum::<f64>();
+        let mut value = base + base * amounts(ModifierOperation::MultiplyBase).sum::<f64>();
+        for amount in amounts(ModifierOperation::MultiplyTotal) {
+            value *= 1.0 + amount;
+        }
+
+        let (min, max) = range(attribute);
+        if value.is_nan() {
+            min
+        } else {
+            value.clamp(min, max)
+        }
+    }
+}
+
+/// The attributes of an entity, such as its max health and movement speed.
+///
+/// Attributes that were never touched have their default value from the registry. Modifiers
+/// are added by whatever gives them, like effects and equipment, and removed again by ID.
+#[derive(Component, Debug, Clone, Default, PartialEq)]
+pub struct Attributes {
+    instances: BTreeMap<u16, AttributeInstance>,
+}
+
+impl Attributes {
+    /// The attributes of a player, which differ from the registry defaults for a few.
+    pub fn player() -> Self {
+        let mut attributes = Self::default();
+        attributes.set_base(&Attribute::MOVEMENT_SPEED, 0.1);
+        attributes.set_base(&Attribute::ATTACK_DAMAGE, 1.0);
+        attributes
+    }
+
+    pub fn get(&self, attribute: &Attribute) -> Option<&AttributeInstance> {
+        self.instances.get(&attribute.id)
+    }
+
+    fn get_or_insert(&mut self, attribute: &Attribute) -> &mut AttributeInstance {
+        self.instances
+            .entry(attribute.id)
+            .or_insert_with(|| AttributeInstance::new(attribute.default_value))
+    }
+
+    pub fn base(&self, attribute: &Attribute) -> f64 {
+        self.get(attribute)
+            .map_or(attribute.default_value, |instance| instance.base)
+    }
+
+    pub fn set_base(&mut self, attribute: &Attribute, base: f64) {
+        self.get_or_insert(attribute).base = base;
+    }
+
+    /// The value of an attribute with all its modifiers applied.
+    pub fn value(&self, attribute: &Attribute) -> f64 {
+        match self.get(attribute) {
+            Some(instance) => instance.value(attribute),
+            None => attribute.default_value,
+        }
+    }
+
+    pub fn modifier(&self, attribute: &Attribute, id: &str) -> Option<&AttributeModifier> {
+        self.get(attribute)?
+            .modifiers
+            .iter()
+            .find(|modifier| modifier.id == id)
+    }
+
+    /// Adds a modifier to an attribute, replacing any it already had with the same ID.
+    pub fn add_modifier(&mut self, attribute: &Attribute, modifier: AttributeModifier) {
+        let instance = self.get_or_insert(attribute);
+        match instance
+            .modifiers
+            .iter_mut()
+            .find(|existing| existing.id == modifier.id)
+        {
+            Some(existing) => *existing = modifier,
+            None => instance.modifiers.push(modifier),
+        }
+    }
+
+    /// Removes a modifier from an attribute, returning whether it had it.
+    pub fn remove_modifier(&mut self, attribute: &Attribute, id: &str) -> bool {
+        let Some(instance) = self.instances.get_mut(&attribute.id) else {
+            return false;
+        };
+        let len = instance.modifiers.len();
+        instance.modifiers.retain(|modifier| modifier.id != id);
+        instance.modifiers.len() != len
+    }
+
+    /// Adds the modifiers of an effect at the given amplifier.
+    pub fn add_effect_modifiers(&mut self, effect: Effect, amplifier: u8) {
+        for modifier in effect.attribute_modifiers() {
+            self.add_modifier(
+                modifier.attribute,
+                AttributeModifier::new(
+                    modifier.id,
+                    modifier.base_value * (amplifier as f64 + 1.0),
+                    modifier.operation.into(),
+                ),
+            );
+        }
+    }
+
+    pub fn remove_effect_modifiers(&mut self, effect: Effect) {
+        for modifier in effect.attribute_modifiers() {
+            self.remove_modifier(modifier.attribute, modifier.id);
+        }
+    }
+
+    /// Iterates over every attribute that was given a base value or modifiers, even if they
+    /// have been removed since. These are the ones clients need to be told about.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static Attribute, &AttributeInstance)> {
+        self.instances
+            .iter()
+            .filter_map(|(id, instance)| Some((Attribute::from_id(*id)?, instance)))
+    }
+}
+
+/// The attribute modifiers an entity's equipment is giving it, so they can be taken off again
+/// when the equipment changes.
+#[derive(Component, Debug, Clone, Default, PartialEq)]
+pub struct EquipmentModifiers {
+    pub applied: Vec<(&'static Attribute, AttributeModifier)>,
+}
+
+/// The lowest and highest values an attribute can have, the same as vanilla.
+fn range(attribute: &Attribute) -> (f64, f64) {
+    match attribute.name {
+        "armor" => (0.0, 30.0),
+        "armor_toughness" => (0.0, 20.0),
+        "attack_knockback" => (0.0, 5.0),
+        "block_interaction_range" | "entity_interaction_range" => (0.0, 64.0),
+        "camera_distance" => (0.0, 32.0),
+        "fall_damage_multiplier" => (0.0, 100.0),
+        "gravity" => (-1.0, 1.0),
+        "jump_strength" => (0.0, 32.0),
+        "luck" | "safe_fall_distance" => (-1024.0, 1024.0),
+        "max_health" => (1.0, 1024.0),
+        "scale" => (0.0625, 16.0),
+        "step_height" => (0.0, 10.0),
+        "submerged_mining_speed" => (0.0, 20.0),
+        "explosion_knockback_resistance"
+        | "knockback_resistance"
+        | "movement_efficiency"
+        | "sneaking_speed"
+        | "spawn_reinforcements"
+        | "sweeping_damage_ratio"
+        | "water_movement_efficiency" => (0.0, 1.0),
+        "attack_damage" | "follow_range" | "max_absorption" | "tempt_range" => (0.0, 2048.0),
+        "waypoint_transmit_range" | "waypoint_receive_range" => (0.0, 6.0e7),
+        _ => (0.0, 1024.0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_modifiers_stack() {
+        let mut attributes = Attributes::player();
+        let speed = &Attribute::MOVEMENT_SPEED;
+        assert_eq!(attributes.value(speed), 0.1);
+
+        attributes.add_modifier(
+            speed,
+            AttributeModifier::new("test:add", 0.1, ModifierOperation::Add),
+        );
+        attributes.add_modifier(
+            speed,
+            AttributeModifier::new("test:base", 0.5, ModifierOperation::MultiplyBase),
+        );
+        attributes.add_modifier(
+            speed,
+            AttributeModifier::new("test:total", 1.0, ModifierOperation::MultiplyTotal),
+        );
+        // (0.1 + 0.1) * 1.5 * 2
+        assert!((attributes.value(speed) - 0.6).abs() < 1e-9);
+
+        // Modifiers with the same ID replace each other
+        attributes.add_modifier(
+            speed,
+            AttributeModifier::new("test:total", 0.0, ModifierOperation::MultiplyTotal),
+        );
+        assert!((attributes.value(speed) - 0.3).abs() < 1e-9);
+        assert!(attributes.remove_modifier(speed, "test:base"));
+        assert!(!attributes.remove_modifier(speed, "test:base"));
+        assert!((attributes.value(speed) - 0.2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_values_are_clamped() {
+        let mut attributes = Attributes::default();
+        let health = &Attribute::MAX_HEALTH;
+        attributes.add_modifier(
+            health,
+            AttributeModifier::new("test:drain", -100.0, ModifierOperation::Add),
+        );
+        assert_eq!(attributes.value(health), 1.0);
+        assert_eq!(attributes.base(health), 20.0);
+    }
+
+    #[test]
+    fn test_effect_modifiers() {
+        let mut attributes = Attributes::player();
+        attributes.add_effect_modifiers(Effect::Strength, 1);
+        let modifier = attributes
+            .modifier(&Attribute::ATTACK_DAMAGE, "minecraft:effect.strength")
+            .unwrap();
+        assert_eq!(modifier.amount, 6.0);
+        assert_eq!(attributes.value(&Attribute::ATTACK_DAMAGE), 7.0);
+
+        attributes.remove_effect_modifiers(Effect::Strength);
+        assert_eq!(attributes.value(&Attribute::ATTACK_DAMAGE), 1.0);
+        // Attributes that were changed are still sent to clients, so they lose the modifier
+        assert_eq!(attributes.iter().count(), 2);
+    }
+}
@@ -15,3 +15,69 @@ impl Default for Health {
         }
     }
 }
+
+/// Extra health on top of `Health` that's lost first, shown as golden hearts. It can't go above
+/// the `max_absorption` attribute.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct Absorption {
+    pub amount: f32,
+}
+
+/// The short time after a player is hurt during which they only take damage from bigger hits,
+/// the same as vanilla.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct HurtCooldown {
+    /// Ticks left until the cooldown is over.
+    pub ticks: u32,
+    /// The biggest hit taken during the cooldown.
+    pub last_damage: f32,
+}
+
+impl HurtCooldown {
+    /// How long the cooldown lasts after a hit.
+    pub const TICKS: u32 = 10;
+
+    pub fn is_active(&self) -> bool {
+        self.ticks > 0
+    }
+
+    /// Takes a hit, returning how much of it gets through. During the cooldown only the part of
+    /// a hit that's bigger than the biggest one so far does.
+    pub fn hurt(&mut self, amount: f32) -> Option<f32> {
+        if !self.is_active() {
+            self.ticks = Self::TICKS;
+            self.last_damage = amount;
+            return Some(amount);
+        }
+        if amount <= self.last_damage {
+            return None;
+        }
+        let extra = amount - self.last_damage;
+        self.last_damage = amount;
+        Some(extra)
+    }
+
+    pub fn tick(&mut self) {
+        self.ticks = self.ticks.saturating_sub(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hurt_cooldown() {
+        let mut cooldown = HurtCooldown::default();
+        assert_eq!(cooldown.hurt(4.0), Some(4.0));
+        assert_eq!(cooldown.hurt(3.0), None);
+        assert_eq!(cooldown.hurt(6.0), Some(2.0));
+        assert_eq!(cooldown.hurt(6.0), None);
+
+        for _ in 0..HurtCooldown::TICKS {
+            cooldown.tick();
+        }
+        assert!(!cooldown.is_active());
+        assert_eq!(cooldown.hurt(1.0), Some(1.0));
+    }
+}
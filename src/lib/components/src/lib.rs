@@ -1,3 +1,4 @@
 pub mod active_effects;
+pub mod attributes;
 pub mod health;
 pub mod player;
@@ -10,3 +10,164 @@ pub struct Experience {
     /// The total XP the player has ever collected
     pub total_xp: u32,
 }
+
+impl Experience {
+    /// The most XP a player drops when they die.
+    pub const MAX_DEATH_DROP: u32 = 100;
+
+    /// The XP it takes to get from `level` to the next one, the same as vanilla.
+    pub fn points_for_level(level: u32) -> u32 {
+        match level {
+            30.. => 112 + (level - 30) * 9,
+            15.. => 37 + (level - 15) * 5,
+            _ => 7 + level * 2,
+        }
+    }
+
+    /// The XP it takes to get from no levels to `level`.
+    pub fn total_for_level(level: u32) -> u32 {
+        let level = level as u64;
+        let total = match level {
+            32.. => (9 * level * level + 4440 - 325 * level) / 2,
+            17.. => (5 * level * level + 720 - 81 * level) / 2,
+            _ => level * level + 6 * level,
+        };
+        total.min(u32::MAX as u64) as u32
+    }
+
+    /// The XP collected towards the next level.
+    pub fn points(&self) -> u32 {
+        (self.progress * Self::points_for_level(self.level) as f32) as u32
+    }
+
+    /// Gives (or takes, if negative) XP points, going up or down levels as needed.
+    pub fn add_points(&mut self, amount: i32) {
+        self.progress += amount as f32 / Self::points_for_level(self.level) as f32;
+        self.total_xp = self.total_xp.saturating_add_signed(amount);
+
+        while self.progress < 0.0 {
+            if self.level == 0 {
+                *self = Self::default();
+                return;
+            }
+            let points = self.progress * Self::points_for_level(self.level) as f32;
+            self.level -= 1;
+            self.progress = 1.0 + points / Self::points_for_level(self.level) as f32;
+        }
+        while self.progress >= 1.0 {
+            let points = (self.progress - 1.0) * Self::points_for_level(self.level) as f32;
+            self.add_levels(1);
+            self.progress = points / Self::points_for_level(self.level) as f32;
+        }
+    }
+
+    /// Gives (or takes, if negative) levels. Going below zero loses all of the player's XP.
+    pub fn add_levels(&mut self, levels: i32) {
+        match self.level.checked_add_signed(levels) {
+            Some(level) => self.level = level,
+            None => *self = Self::default(),
+        }
+    }
+
+    /// Sets the XP collected towards the next level, returning `false` if it's enough to level up.
+    pub fn set_points(&mut self, points: u32) -> bool {
+        let needed = Self::points_for_level(self.level);
+        if points >= needed {
+            return false;
+        }
+        self.progress = points as f32 / needed as f32;
+        true
+    }
+
+    /// How much XP the player drops when they die: 7 points per level, up to
+    /// [`Experience::MAX_DEATH_DROP`].
+    pub fn death_drop(&self) -> u32 {
+        self.level.saturating_mul(7).min(Self::MAX_DEATH_DROP)
+    }
+}
+
+/// Seeds the enchantments an enchanting table offers the player. It only changes once they
+/// enchant something, so they can't reroll the offers by reopening the table.
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct EnchantmentSeed(pub i32);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_level_curve() {
+        assert_eq!(Experience::points_for_level(0), 7);
+        assert_eq!(Experience::points_for_level(15), 37);
+        assert_eq!(Experience::points_for_level(30), 112);
+        assert_eq!(Experience::total_for_level(0), 0);
+        assert_eq!(Experience::total_for_level(16), 352);
+        assert_eq!(Experience::total_for_level(30), 1395);
+        assert_eq!(Experience::total_for_level(31), 1507);
+
+        // The totals are the sums of the levels before them
+        for level in 0..50 {
+            assert_eq!(
+                Experience::total_for_level(level) + Experience::points_for_level(level),
+                Experience::total_for_level(level + 1)
+            );
+        }
+    }
+
+    #[test]
+    fn test_add_points() {
+        let mut experience = Experience::default();
+        experience.add_points(10);
+        assert_eq!(experience.level, 1);
+        assert_eq!(experience.points(), 3);
+        assert_eq!(experience.total_xp, 10);
+
+        // Enough for several levels at once
+        experience.add_points(Experience::total_for_level(5) as i32 - 10);
+        assert_eq!(experience.level, 5);
+        assert_eq!(experience.points(), 0);
+
+        experience.add_points(-3);
+        assert_eq!(experience.level, 4);
+        assert_eq!(experience.points(), 12);
+
+        experience.add_points(-1000);
+        assert_eq!(experience.level, 0);
+        assert_eq!(experience.progress, 0.0);
+    }
+
+    #[test]
+    fn test_add_levels() {
+        let mut experience = Experience::default();
+        experience.add_points(20);
+        experience.add_levels(3);
+        assert_eq!(experience.level, 5);
+        assert_eq!(experience.total_xp, 20);
+
+        experience.add_levels(-6);
+        assert_eq!(experience.level, 0);
+        assert_eq!(experience.total_xp, 0);
+    }
+
+    #[test]
+    fn test_set_points() {
+        let mut experience = Experience {
+            level: 2,
+            ..Default::default()
+        };
+        assert!(experience.set_points(5));
+        assert_eq!(experience.points(), 5);
+        assert!(!experience.set_points(11));
+        assert_eq!(experience.points(), 5);
+    }
+
+    #[test]
+    fn test_death_drop() {
+        let mut experience = Experience::default();
+        assert_eq!(experience.death_drop(), 0);
+        experience.level = 3;
+        assert_eq!(experience.death_drop(), 21);
+        experience.level = 30;
+        assert_eq!(experience.death_drop(), Experience::MAX_DEATH_DROP);
+    }
+}
@@ -7,6 +7,7 @@ pub mod hunger;
 pub mod offline_player_data;
 pub mod pending_events;
 pub mod player_bundle;
+pub mod respawn_point;
 pub mod sneak;
 pub mod swimming;
 pub mod teleport_tracker;
@@ -1,13 +1,21 @@
-use crate::active_effects::ActiveEffects;
+use crate::active_effects::{ActiveEffects, EffectState};
 use crate::health::Health;
 use crate::player::abilities::PlayerAbilities;
 use crate::player::experience::Experience;
 use crate::player::gamemode::GameMode;
 use crate::player::gameplay_state::ender_chest::EnderChest;
 use crate::player::hunger::Hunger;
+use crate::player::respawn_point::RespawnPoint;
 use bitcode_derive::{Decode, Encode};
 use ferrumc_core::transform::rotation::Rotation;
+use ferrumc_data::generated::effects::Effect;
 use ferrumc_inventories::inventory::Inventory;
+use ferrumc_inventories::item::ItemID;
+use ferrumc_inventories::slot::InventorySlot;
+use ferrumc_net_codec::net_types::var_int::VarInt;
+use ferrumc_world::errors::WorldError;
+use ferrumc_world::player::{decode_player_data, PlayerDataFormat};
+use std::collections::HashMap;
 
 #[derive(Clone, Debug, Encode, Decode, Default)]
 pub struct OfflinePlayerData {
@@ -21,4 +29,195 @@ pub struct OfflinePlayerData {
     pub experience: Experience,
     pub ender_chest: EnderChest,
     pub active_effects: ActiveEffects,
+    pub respawn_point: RespawnPoint,
+}
+
+impl PlayerDataFormat for OfflinePlayerData {
+    const VERSION: u8 = 1;
+
+    fn decode_old(version: u8, data: &[u8]) -> Result<Self, WorldError> {
+        match version {
+            0 => decode_player_data::<OfflinePlayerDataV0>(data).map(OfflinePlayerData::from),
+            _ => Err(WorldError::BitcodeDecodeError(format!(
+                "Unknown player data format version {version}"
+            ))),
+        }
+    }
+}
+
+/// Player data from before the format was versioned, when players had no respawn point, slots
+/// only held the IDs of their components and there were only three placeholder effects.
+#[derive(Encode, Decode)]
+struct OfflinePlayerDataV0 {
+    abilities: PlayerAbilities,
+    gamemode: GameMode,
+    position: (f64, f64, f64),
+    rotation: Rotation,
+    inventory: InventoryV0,
+    health: Health,
+    hunger: Hunger,
+    experience: Experience,
+    ender_chest: InventoryV0,
+    active_effects: ActiveEffectsV0,
+}
+
+#[derive(Encode, Decode)]
+struct InventoryV0 {
+    slots: Box<[Option<InventorySlotV0>]>,
+}
+
+impl From<InventoryV0> for Inventory {
+    fn from(inventory: InventoryV0) -> Self {
+        Self {
+            slots: inventory
+                .slots
+                .into_vec()
+                .into_iter()
+                .map(|slot| slot.map(InventorySlot::from))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Encode, Decode)]
+struct InventorySlotV0 {
+    count: VarInt,
+    item_id: Option<ItemID>,
+    components_to_add_count: Option<VarInt>,
+    components_to_remove_count: Option<VarInt>,
+    components_to_add: Option<Vec<VarInt>>,
+    components_to_remove: Option<Vec<VarInt>>,
+}
+
+impl From<InventorySlotV0> for InventorySlot {
+    /// Added components were saved without their data, so they can't be kept. The item keeps
+    /// the defaults of its type instead.
+    fn from(slot: InventorySlotV0) -> Self {
+        Self {
+            count: slot.count,
+            item_id: slot.item_id,
+            components_to_add: Vec::new(),
+            components_to_remove: slot.components_to_remove.unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Encode, Decode)]
+enum EffectTypeV0 {
+    Speed,
+    Poison,
+    Regeneration,
+}
+
+#[derive(Encode, Decode)]
+struct EffectStateV0 {
+    amplifier: u8,
+    duration_ticks: u32,
+}
+
+#[derive(Encode, Decode)]
+struct ActiveEffectsV0 {
+    effects: HashMap<EffectTypeV0, EffectStateV0>,
+}
+
+impl From<ActiveEffectsV0> for ActiveEffects {
+    fn from(old: ActiveEffectsV0) -> Self {
+        let mut effects = ActiveEffects::default();
+        for (effect, state) in old.effects {
+            let effect = match effect {
+                EffectTypeV0::Speed => Effect::Speed,
+                EffectTypeV0::Poison => Effect::Poison,
+                EffectTypeV0::Regeneration => Effect::Regeneration,
+            };
+            let duration = i32::try_from(state.duration_ticks).unwrap_or(i32::MAX);
+            effects.add(effect, EffectState::new(state.amplifier, duration));
+        }
+        effects
+    }
+}
+
+impl From<OfflinePlayerDataV0> for OfflinePlayerData {
+    fn from(data: OfflinePlayerDataV0) -> Self {
+        Self {
+            abilities: data.abilities,
+            gamemode: data.gamemode,
+            position: data.position,
+            rotation: data.rotation,
+            inventory: data.inventory.into(),
+            health: data.health,
+            hunger: data.hunger,
+            experience: data.experience,
+            ender_chest: EnderChest(data.ender_chest.into()),
+            active_effects: data.active_effects.into(),
+            respawn_point: RespawnPoint::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn v0() -> OfflinePlayerDataV0 {
+        let mut slots: Vec<Option<InventorySlotV0>> = (0..46).map(|_| None).collect();
+        slots[36] = Some(InventorySlotV0 {
+            count: VarInt(12),
+            item_id: Some(ItemID(VarInt(1))),
+            components_to_add_count: Some(VarInt(1)),
+            components_to_remove_count: Some(VarInt(1)),
+            components_to_add: Some(vec![VarInt(5)]),
+            components_to_remove: Some(vec![VarInt(3)]),
+        });
+        OfflinePlayerDataV0 {
+            abilities: PlayerAbilities::default(),
+            gamemode: GameMode::Creative,
+            position: (1.0, 70.0, -3.0),
+            rotation: Rotation::default(),
+            inventory: InventoryV0 {
+                slots: slots.into_boxed_slice(),
+            },
+            health: Health::default(),
+            hunger: Hunger::default(),
+            experience: Experience::default(),
+            ender_chest: InventoryV0 {
+                slots: (0..27).map(|_| None).collect(),
+            },
+            active_effects: ActiveEffectsV0 {
+                effects: HashMap::from([(
+                    EffectTypeV0::Poison,
+                    EffectStateV0 {
+                        amplifier: 1,
+                        duration_ticks: 200,
+                    },
+                )]),
+            },
+        }
+    }
+
+    #[test]
+    fn test_unversioned_data() {
+        let data = OfflinePlayerData::decode_old(0, &bitcode::encode(&v0())).unwrap();
+        assert_eq!(data.gamemode, GameMode::Creative);
+        assert_eq!(data.position, (1.0, 70.0, -3.0));
+        assert!(data.respawn_point.position.is_none());
+
+        let slot = data.inventory.slots[36].as_ref().unwrap();
+        assert_eq!(slot.count, VarInt(12));
+        assert_eq!(slot.item_id, Some(ItemID(VarInt(1))));
+        assert!(slot.components_to_add.is_empty());
+        assert_eq!(slot.components_to_remove, vec![VarInt(3)]);
+        assert_eq!(data.inventory.slots.iter().flatten().count(), 1);
+        assert_eq!(data.ender_chest.0.slots.len(), 27);
+
+        assert_eq!(
+            data.active_effects.get(Effect::Poison),
+            Some(&EffectState::new(1, 200))
+        );
+        assert_eq!(data.active_effects.iter().count(), 1);
+    }
+
+    #[test]
+    fn test_garbage_fails() {
+        assert!(OfflinePlayerData::decode_old(0, &[0xFF; 3]).is_err());
+    }
 }
@@ -1,9 +1,15 @@
 use crate::{
     active_effects::ActiveEffects,
-    health::Health,
+    attributes::{Attributes, EquipmentModifiers},
+    health::{Absorption, Health, HurtCooldown},
     player::{
-        abilities::PlayerAbilities, experience::Experience, gamemode::GameModeComponent,
-        gameplay_state::ender_chest::EnderChest, hunger::Hunger, sneak::SneakState,
+        abilities::PlayerAbilities,
+        experience::{EnchantmentSeed, Experience},
+        gamemode::GameModeComponent,
+        gameplay_state::ender_chest::EnderChest,
+        hunger::Hunger,
+        respawn_point::RespawnPoint,
+        sneak::SneakState,
         swimming::SwimmingState,
     },
 };
@@ -13,7 +19,7 @@ use ferrumc_core::{
     identity::player_identity::PlayerIdentity,
     transform::{grounded::OnGround, position::Position, rotation::Rotation},
 };
-use ferrumc_inventories::{hotbar::Hotbar, inventory::Inventory};
+use ferrumc_inventories::{container::CarriedItem, hotbar::Hotbar, inventory::Inventory};
 /// A Bevy Bundle containing all components required for a player entity.
 /// This groups all 17+ components into a single, spawnable unit.
 #[derive(Bundle, Default)]
@@ -30,17 +36,24 @@ pub struct PlayerBundle {
     pub rotation: Rotation,
     pub on_ground: OnGround,
     pub chunk_receiver: ChunkReceiver,
+    pub respawn_point: RespawnPoint,
 
     // Inventory
     pub inventory: Inventory,
     pub hotbar: Hotbar,
     pub ender_chest: EnderChest,
+    pub carried_item: CarriedItem,
 
     // Survival Stats
     pub health: Health,
+    pub absorption: Absorption,
+    pub hurt_cooldown: HurtCooldown,
     pub hunger: Hunger,
     pub experience: Experience,
+    pub enchantment_seed: EnchantmentSeed,
     pub active_effects: ActiveEffects,
+    pub attributes: Attributes,
+    pub equipment_modifiers: EquipmentModifiers,
 
     // Movement State
     pub swimming: SwimmingState,
@@ -0,0 +1,11 @@
+use bevy_ecs::prelude::Component;
+use bitcode_derive::{Decode, Encode};
+
+/// Where a player respawns after dying. Set by sleeping in a bed.
+#[derive(Component, Debug, Clone, Copy, Default, Decode, Encode)]
+pub struct RespawnPoint {
+    /// The block position of the bed, or `None` to respawn at the world spawn
+    pub position: Option<(i32, i32, i32)>,
+    /// The yaw the player faces after respawning
+    pub angle: f32,
+}
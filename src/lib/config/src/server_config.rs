@@ -37,6 +37,11 @@ pub fn set_global_config(config: ServerConfig) {
 /// - `whitelist`: Whether the server whitelist is enabled or not.
 /// - `chunk_render_distance`: The render distance of the chunks. This is the number of chunks that will be
 ///   loaded around the player.
+/// - `random_tick_speed`: How many blocks per chunk section get a random tick each game tick in new
+///   worlds. Existing worlds use their `randomTickSpeed` game rule.
+/// - `rcon` - [RconConfig]: The configuration for the remote console.
+/// - `query` - [QueryConfig]: The configuration for the UDP query protocol.
+/// - `world_border` - [WorldBorderConfig]: The world border every dimension starts with.
 #[derive(Debug, Deserialize, Serialize, Default)]
 pub struct ServerConfig {
     pub host: String,
@@ -52,9 +57,13 @@ pub struct ServerConfig {
     pub online_mode: bool,
     pub whitelist: bool,
     pub chunk_render_distance: u32,
+    pub random_tick_speed: u32,
     pub default_gamemode: String,
     pub dashboard: DashboardConfig,
     pub performance: PerformanceConfig,
+    pub rcon: RconConfig,
+    pub query: QueryConfig,
+    pub world_border: WorldBorderConfig,
 }
 
 /// The database configuration section from [ServerConfig].
@@ -84,6 +93,51 @@ pub struct DashboardConfig {
     pub secret: String,
 }
 
+/// The remote console (RCON) configuration section from [ServerConfig].
+///
+/// Fields:
+/// - `enabled`: Whether the RCON server is started.
+/// - `port`: The port that the RCON server will bind to, on the same host as the game server. (0-65535)
+/// - `password`: The password clients must authenticate with. RCON stays disabled while this is empty.
+#[derive(Debug, Deserialize, Serialize, Default)]
+pub struct RconConfig {
+    pub enabled: bool,
+    pub port: u16,
+    pub password: String,
+}
+
+/// The UDP query protocol configuration section from [ServerConfig].
+///
+/// Fields:
+/// - `enabled`: Whether the server answers GameSpy4 query requests.
+/// - `port`: The UDP port that queries are answered on. (0-65535)
+#[derive(Debug, Deserialize, Serialize, Default)]
+pub struct QueryConfig {
+    pub enabled: bool,
+    pub port: u16,
+}
+
+/// The world border configuration section from [ServerConfig].
+///
+/// Fields:
+/// - `center_x`: The x coordinate of the centre of the border.
+/// - `center_z`: The z coordinate of the centre of the border.
+/// - `diameter`: The width of the border, in blocks.
+/// - `damage_per_block`: Damage per second for every block a player is past the safe zone.
+/// - `safe_zone`: How far past the border players can go without taking damage, in blocks.
+/// - `warning_blocks`: How close to the border players see the warning, in blocks.
+/// - `warning_time`: How many seconds before a shrinking border reaches players they see the warning.
+#[derive(Debug, Deserialize, Serialize, Default)]
+pub struct WorldBorderConfig {
+    pub center_x: f64,
+    pub center_z: f64,
+    pub diameter: f64,
+    pub damage_per_block: f64,
+    pub safe_zone: f64,
+    pub warning_blocks: i32,
+    pub warning_time: i32,
+}
+
 #[derive(Debug, Deserialize, Serialize, Default)]
 pub struct PerformanceConfig {
     pub chunks_per_tick_min: u32,
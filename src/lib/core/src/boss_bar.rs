@@ -0,0 +1,221 @@
+//! Boss bars.
+//!
+//! A boss bar is an entity with a [`BossBar`] component. Changing the component is all it takes to
+//! show, update or hide it for its viewers, and despawning the entity removes it from their
+//! screens.
+
+use bevy_ecs::prelude::{Component, Entity};
+use ferrumc_text::TextComponent;
+use std::collections::HashSet;
+use uuid::Uuid;
+
+/// The colour of a boss bar. The order matches the IDs the protocol uses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum BossBarColor {
+    Pink,
+    Blue,
+    Red,
+    Green,
+    Yellow,
+    Purple,
+    #[default]
+    White,
+}
+
+impl BossBarColor {
+    pub const ALL: [BossBarColor; 7] = [
+        BossBarColor::Pink,
+        BossBarColor::Blue,
+        BossBarColor::Red,
+        BossBarColor::Green,
+        BossBarColor::Yellow,
+        BossBarColor::Purple,
+        BossBarColor::White,
+    ];
+
+    pub const fn id(self) -> i32 {
+        self as i32
+    }
+
+    /// The name used in commands, like `/bossbar`.
+    pub const fn name(self) -> &'static str {
+        match self {
+            BossBarColor::Pink => "pink",
+            BossBarColor::Blue => "blue",
+            BossBarColor::Red => "red",
+            BossBarColor::Green => "green",
+            BossBarColor::Yellow => "yellow",
+            BossBarColor::Purple => "purple",
+            BossBarColor::White => "white",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|color| color.name().eq_ignore_ascii_case(name))
+    }
+}
+
+/// How many notches a boss bar is split into. The order matches the IDs the protocol uses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum BossBarStyle {
+    #[default]
+    Progress,
+    Notched6,
+    Notched10,
+    Notched12,
+    Notched20,
+}
+
+impl BossBarStyle {
+    pub const ALL: [BossBarStyle; 5] = [
+        BossBarStyle::Progress,
+        BossBarStyle::Notched6,
+        BossBarStyle::Notched10,
+        BossBarStyle::Notched12,
+        BossBarStyle::Notched20,
+    ];
+
+    pub const fn id(self) -> i32 {
+        self as i32
+    }
+
+    /// The name used in commands, like `/bossbar`.
+    pub const fn name(self) -> &'static str {
+        match self {
+            BossBarStyle::Progress => "progress",
+            BossBarStyle::Notched6 => "notched_6",
+            BossBarStyle::Notched10 => "notched_10",
+            BossBarStyle::Notched12 => "notched_12",
+            BossBarStyle::Notched20 => "notched_20",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|style| style.name().eq_ignore_ascii_case(name))
+    }
+}
+
+/// A bar at the top of the screen, shown to the players that are viewing it.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct BossBar {
+    /// Identifies the bar to clients, so it must stay the same while the bar exists.
+    pub uuid: Uuid,
+    pub title: TextComponent,
+    /// How full the bar is, from 0.0 to 1.0.
+    pub progress: f32,
+    pub color: BossBarColor,
+    pub style: BossBarStyle,
+    pub darken_sky: bool,
+    /// Plays the end music, like the ender dragon's bar.
+    pub play_music: bool,
+    pub create_fog: bool,
+    /// Hidden bars aren't shown to anyone, but keep their viewers.
+    pub visible: bool,
+    viewers: HashSet<Entity>,
+}
+
+impl BossBar {
+    /// A full white bar without any viewers.
+    pub fn new(title: impl Into<TextComponent>) -> Self {
+        Self {
+            uuid: Uuid::new_v4(),
+            title: title.into(),
+            progress: 1.0,
+            color: BossBarColor::default(),
+            style: BossBarStyle::default(),
+            darken_sky: false,
+            play_music: false,
+            create_fog: false,
+            visible: true,
+            viewers: HashSet::new(),
+        }
+    }
+
+    pub fn with_color(mut self, color: BossBarColor) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn with_style(mut self, style: BossBarStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the progress, clamped between 0.0 and 1.0.
+    pub fn set_progress(&mut self, progress: f32) {
+        self.progress = progress.clamp(0.0, 1.0);
+    }
+
+    /// The flags sent to clients: 0x01 darkens the sky, 0x02 plays the end music and 0x04 creates
+    /// fog.
+    pub fn flags(&self) -> u8 {
+        let mut flags = 0;
+        if self.darken_sky {
+            flags |= 0x01;
+        }
+        if self.play_music {
+            flags |= 0x02;
+        }
+        if self.create_fog {
+            flags |= 0x04;
+        }
+        flags
+    }
+
+    pub fn viewers(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.viewers.iter().copied()
+    }
+
+    pub fn is_viewer(&self, player: Entity) -> bool {
+        self.viewers.contains(&player)
+    }
+
+    /// Returns false if the player was already viewing the bar.
+    pub fn add_viewer(&mut self, player: Entity) -> bool {
+        self.viewers.insert(player)
+    }
+
+    /// Returns false if the player wasn't viewing the bar.
+    pub fn remove_viewer(&mut self, player: Entity) -> bool {
+        self.viewers.remove(&player)
+    }
+
+    pub fn set_viewers(&mut self, players: impl IntoIterator<Item = Entity>) {
+        self.viewers = players.into_iter().collect();
+    }
+
+    pub fn clear_viewers(&mut self) {
+        self.viewers.clear();
+    }
+}
+
+/// A boss bar made with `/bossbar`, which is looked up by its ID and has a value out of a maximum
+/// rather than a progress.
+#[derive(Component, Debug, Clone)]
+pub struct CustomBossBar {
+    pub id: String,
+    pub value: i32,
+    pub max: i32,
+}
+
+impl CustomBossBar {
+    pub fn new(id: String) -> Self {
+        Self {
+            id,
+            value: 0,
+            max: 100,
+        }
+    }
+
+    /// The progress of the bar shown for the value.
+    pub fn progress(&self) -> f32 {
+        if self.max <= 0 {
+            return 0.0;
+        }
+        (self.value as f32 / self.max as f32).clamp(0.0, 1.0)
+    }
+}
@@ -2,13 +2,26 @@ use bevy_ecs::prelude::Component;
 use std::collections::{HashSet, VecDeque};
 use typename::TypeName;
 
+/// The rate clients start at before they acknowledge their first batch, like vanilla.
+const START_CHUNKS_PER_TICK: f32 = 9.0;
+const MIN_CHUNKS_PER_TICK: f32 = 0.01;
+const MAX_CHUNKS_PER_TICK: f32 = 64.0;
+/// How many batches may be unacknowledged once the client acknowledged its first one.
+const MAX_UNACKNOWLEDGED_BATCHES: u32 = 10;
+
 #[derive(TypeName, Component)]
 pub struct ChunkReceiver {
     pub loading: VecDeque<(i32, i32)>,
     pub dirty: VecDeque<(i32, i32)>,
     pub loaded: HashSet<(i32, i32)>,
     pub unloading: VecDeque<(i32, i32)>,
+    /// How many chunks per tick the client last asked for.
     pub chunks_per_tick: f32,
+    /// Batches sent that the client has not acknowledged yet.
+    pub unacknowledged_batches: u32,
+    pub max_unacknowledged_batches: u32,
+    /// How many chunks can be sent in the next batch, built up by `chunks_per_tick` every tick.
+    pub batch_quota: f32,
 }
 
 impl Default for ChunkReceiver {
@@ -24,8 +37,72 @@ impl ChunkReceiver {
             loaded: HashSet::new(),
             unloading: VecDeque::new(),
             dirty: VecDeque::new(),
-            // 32.5 chunks per tick is enough to send 650 chunks per second (20 ticks per second)
-            chunks_per_tick: 32.5,
+            chunks_per_tick: START_CHUNKS_PER_TICK,
+            unacknowledged_batches: 0,
+            // Only one batch until the client reports how fast it is
+            max_unacknowledged_batches: 1,
+            batch_quota: 0.0,
+        }
+    }
+
+    /// Returns how many chunks can be sent this tick, if any. Should be called once per tick.
+    pub fn next_batch_size(&mut self) -> usize {
+        if self.unacknowledged_batches >= self.max_unacknowledged_batches {
+            return 0;
+        }
+        self.batch_quota =
+            (self.batch_quota + self.chunks_per_tick).min(self.chunks_per_tick.max(1.0));
+        self.batch_quota.floor() as usize
+    }
+
+    /// Records a batch of `size` chunks being sent.
+    pub fn batch_sent(&mut self, size: usize) {
+        self.unacknowledged_batches += 1;
+        self.batch_quota -= size as f32;
+    }
+
+    /// Records the client acknowledging a batch, and the chunks per tick it wants from now on.
+    pub fn batch_acknowledged(&mut self, chunks_per_tick: f32) {
+        self.unacknowledged_batches = self.unacknowledged_batches.saturating_sub(1);
+        self.chunks_per_tick = if chunks_per_tick.is_nan() {
+            MIN_CHUNKS_PER_TICK
+        } else {
+            chunks_per_tick.clamp(MIN_CHUNKS_PER_TICK, MAX_CHUNKS_PER_TICK)
+        };
+        if self.unacknowledged_batches == 0 {
+            self.batch_quota = 1.0;
         }
+        self.max_unacknowledged_batches = MAX_UNACKNOWLEDGED_BATCHES;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_batch_waits_for_ack() {
+        let mut receiver = ChunkReceiver::new();
+        assert_eq!(receiver.next_batch_size(), 9);
+        receiver.batch_sent(9);
+        assert_eq!(receiver.next_batch_size(), 0);
+
+        receiver.batch_acknowledged(2.5);
+        assert_eq!(receiver.next_batch_size(), 2);
+        receiver.batch_sent(2);
+        // Further batches are allowed once the client has acknowledged one
+        assert_eq!(receiver.next_batch_size(), 2);
+    }
+
+    #[test]
+    fn test_slow_client_accumulates_quota() {
+        let mut receiver = ChunkReceiver::new();
+        receiver.batch_acknowledged(0.5);
+        receiver.batch_sent(1);
+        assert_eq!(receiver.next_batch_size(), 0);
+        assert_eq!(receiver.next_batch_size(), 1);
+
+        receiver.batch_acknowledged(f32::NAN);
+        assert_eq!(receiver.chunks_per_tick, MIN_CHUNKS_PER_TICK);
     }
 }
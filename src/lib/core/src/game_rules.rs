@@ -0,0 +1,213 @@
+use bevy_ecs::prelude::Resource;
+use bitcode_derive::{Decode, Encode};
+use std::collections::HashMap;
+use std::fmt;
+
+/// The value of a game rule. Every vanilla rule is either on or off, or a whole number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
+pub enum GameRuleValue {
+    Bool(bool),
+    Int(i32),
+}
+
+impl fmt::Display for GameRuleValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bool(value) => write!(f, "{value}"),
+            Self::Int(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+/// The game rules the server knows about, named the same as in vanilla.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GameRule {
+    DoDaylightCycle,
+    DoWeatherCycle,
+    DoFireTick,
+    DoTileDrops,
+    DoMobLoot,
+    MobGriefing,
+    TntExplodes,
+    MobExplosionDropDecay,
+    TntExplosionDropDecay,
+    KeepInventory,
+    NaturalRegeneration,
+    FallDamage,
+    FireDamage,
+    DrowningDamage,
+    ShowDeathMessages,
+    DoImmediateRespawn,
+    RandomTickSpeed,
+    PlayersSleepingPercentage,
+    SpawnRadius,
+}
+
+impl GameRule {
+    pub const ALL: [GameRule; 19] = [
+        Self::DoDaylightCycle,
+        Self::DoWeatherCycle,
+        Self::DoFireTick,
+        Self::DoTileDrops,
+        Self::DoMobLoot,
+        Self::MobGriefing,
+        Self::TntExplodes,
+        Self::MobExplosionDropDecay,
+        Self::TntExplosionDropDecay,
+        Self::KeepInventory,
+        Self::NaturalRegeneration,
+        Self::FallDamage,
+        Self::FireDamage,
+        Self::DrowningDamage,
+        Self::ShowDeathMessages,
+        Self::DoImmediateRespawn,
+        Self::RandomTickSpeed,
+        Self::PlayersSleepingPercentage,
+        Self::SpawnRadius,
+    ];
+
+    /// The name of the rule, as used by `/gamerule`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::DoDaylightCycle => "doDaylightCycle",
+            Self::DoWeatherCycle => "doWeatherCycle",
+            Self::DoFireTick => "doFireTick",
+            Self::DoTileDrops => "doTileDrops",
+            Self::DoMobLoot => "doMobLoot",
+            Self::MobGriefing => "mobGriefing",
+            Self::TntExplodes => "tntExplodes",
+            Self::MobExplosionDropDecay => "mobExplosionDropDecay",
+            Self::TntExplosionDropDecay => "tntExplosionDropDecay",
+            Self::KeepInventory => "keepInventory",
+            Self::NaturalRegeneration => "naturalRegeneration",
+            Self::FallDamage => "fallDamage",
+            Self::FireDamage => "fireDamage",
+            Self::DrowningDamage => "drowningDamage",
+            Self::ShowDeathMessages => "showDeathMessages",
+            Self::DoImmediateRespawn => "doImmediateRespawn",
+            Self::RandomTickSpeed => "randomTickSpeed",
+            Self::PlayersSleepingPercentage => "playersSleepingPercentage",
+            Self::SpawnRadius => "spawnRadius",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|rule| rule.name() == name)
+    }
+
+    /// The value the rule has in a new world.
+    pub fn default_value(self) -> GameRuleValue {
+        match self {
+            Self::KeepInventory | Self::DoImmediateRespawn | Self::TntExplosionDropDecay => {
+                GameRuleValue::Bool(false)
+            }
+            Self::RandomTickSpeed => GameRuleValue::Int(3),
+            Self::PlayersSleepingPercentage => GameRuleValue::Int(100),
+            Self::SpawnRadius => GameRuleValue::Int(10),
+            _ => GameRuleValue::Bool(true),
+        }
+    }
+
+    /// Parses a value for the rule, returning `None` if it isn't the right kind of value.
+    pub fn parse(self, value: &str) -> Option<GameRuleValue> {
+        match self.default_value() {
+            GameRuleValue::Bool(_) => value.parse().ok().map(GameRuleValue::Bool),
+            GameRuleValue::Int(_) => value.parse().ok().map(GameRuleValue::Int),
+        }
+    }
+}
+
+/// The game rules of the world.
+///
+/// Only rules that were changed are stored, by name, so saved worlds keep working when rules
+/// are added or removed.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Encode, Decode)]
+pub struct GameRules {
+    values: HashMap<String, GameRuleValue>,
+}
+
+impl GameRules {
+    pub fn get(&self, rule: GameRule) -> GameRuleValue {
+        self.values
+            .get(rule.name())
+            .copied()
+            .unwrap_or_else(|| rule.default_value())
+    }
+
+    /// Gets a rule that is on or off. Rules that are numbers count as off.
+    pub fn get_bool(&self, rule: GameRule) -> bool {
+        match self.get(rule) {
+            GameRuleValue::Bool(value) => value,
+            GameRuleValue::Int(_) => false,
+        }
+    }
+
+    /// Gets a rule that is a number. Rules that are on or off count as 0.
+    pub fn get_int(&self, rule: GameRule) -> i32 {
+        match self.get(rule) {
+            GameRuleValue::Int(value) => value,
+            GameRuleValue::Bool(_) => 0,
+        }
+    }
+
+    /// Sets a rule, returning false without changing it if the value is the wrong kind for it.
+    pub fn set(&mut self, rule: GameRule, value: GameRuleValue) -> bool {
+        if std::mem::discriminant(&value) != std::mem::discriminant(&rule.default_value()) {
+            return false;
+        }
+        if value == rule.default_value() {
+            self.values.remove(rule.name());
+        } else {
+            self.values.insert(rule.name().to_string(), value);
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_defaults_and_set() {
+        let mut rules = GameRules::default();
+        assert!(rules.get_bool(GameRule::DoDaylightCycle));
+        assert!(!rules.get_bool(GameRule::KeepInventory));
+        assert_eq!(rules.get_int(GameRule::RandomTickSpeed), 3);
+
+        assert!(rules.set(GameRule::KeepInventory, GameRuleValue::Bool(true)));
+        assert!(rules.get_bool(GameRule::KeepInventory));
+        assert!(!rules.set(GameRule::RandomTickSpeed, GameRuleValue::Bool(true)));
+        assert_eq!(rules.get_int(GameRule::RandomTickSpeed), 3);
+
+        // Going back to the default doesn't keep the rule around
+        assert!(rules.set(GameRule::KeepInventory, GameRuleValue::Bool(false)));
+        assert_eq!(rules, GameRules::default());
+    }
+
+    #[test]
+    fn test_names_and_parsing() {
+        for rule in GameRule::ALL {
+            assert_eq!(GameRule::from_name(rule.name()), Some(rule));
+        }
+        assert_eq!(GameRule::from_name("doesNotExist"), None);
+        assert_eq!(
+            GameRule::RandomTickSpeed.parse("10"),
+            Some(GameRuleValue::Int(10))
+        );
+        assert_eq!(GameRule::RandomTickSpeed.parse("true"), None);
+        assert_eq!(
+            GameRule::DoFireTick.parse("false"),
+            Some(GameRuleValue::Bool(false))
+        );
+        assert_eq!(GameRule::DoFireTick.parse("1"), None);
+    }
+
+    #[test]
+    fn test_encode_roundtrip() {
+        let mut rules = GameRules::default();
+        rules.set(GameRule::PlayersSleepingPercentage, GameRuleValue::Int(50));
+        let decoded: GameRules = bitcode::decode(&bitcode::encode(&rules)).unwrap();
+        assert_eq!(decoded, rules);
+    }
+}
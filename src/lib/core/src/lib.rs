@@ -1,12 +1,20 @@
 pub mod errors;
 
 // Core structs/types. Usually used in ECS Components.
+pub mod boss_bar;
 pub mod chunks;
 pub mod collisions;
 pub mod color;
 pub mod conn;
+pub mod game_rules;
 pub mod identity;
 pub mod mq;
+pub mod scoreboard;
+pub mod sound;
 pub mod state;
+pub mod tab_list;
 pub mod time;
 pub mod transform;
+pub mod weather;
+pub mod world_border;
+pub mod world_metadata;
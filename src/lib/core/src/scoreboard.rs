@@ -0,0 +1,830 @@
+use crate::identity::entity_identity::EntityIdentity;
+use crate::identity::player_identity::PlayerIdentity;
+use bevy_ecs::prelude::Resource;
+use bitcode_derive::{Decode, Encode};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// What an objective counts. Objectives other than dummy ones are kept up to date by the server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Encode, Decode)]
+pub enum ObjectiveCriteria {
+    /// Only changed by commands.
+    Dummy,
+    /// How many times a player died.
+    DeathCount,
+    Health,
+    Food,
+    Level,
+    /// The total experience a player has collected.
+    Xp,
+}
+
+impl ObjectiveCriteria {
+    pub const ALL: [ObjectiveCriteria; 6] = [
+        ObjectiveCriteria::Dummy,
+        ObjectiveCriteria::DeathCount,
+        ObjectiveCriteria::Health,
+        ObjectiveCriteria::Food,
+        ObjectiveCriteria::Level,
+        ObjectiveCriteria::Xp,
+    ];
+
+    pub const fn name(self) -> &'static str {
+        match self {
+            ObjectiveCriteria::Dummy => "dummy",
+            ObjectiveCriteria::DeathCount => "deathCount",
+            ObjectiveCriteria::Health => "health",
+            ObjectiveCriteria::Food => "food",
+            ObjectiveCriteria::Level => "level",
+            ObjectiveCriteria::Xp => "xp",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|criteria| criteria.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Returns true if the scores mirror a player's stats, so commands can't change them.
+    pub const fn read_only(self) -> bool {
+        matches!(
+            self,
+            ObjectiveCriteria::Health
+                | ObjectiveCriteria::Food
+                | ObjectiveCriteria::Level
+                | ObjectiveCriteria::Xp
+        )
+    }
+
+    /// How scores are shown when no render type is given.
+    pub const fn default_render_type(self) -> RenderType {
+        match self {
+            ObjectiveCriteria::Health => RenderType::Hearts,
+            _ => RenderType::Integer,
+        }
+    }
+}
+
+/// How the scores of an objective are shown in the player list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Encode, Decode)]
+pub enum RenderType {
+    Integer,
+    Hearts,
+}
+
+impl RenderType {
+    pub const ALL: [RenderType; 2] = [RenderType::Integer, RenderType::Hearts];
+
+    /// The protocol ID of the render type.
+    pub const fn id(self) -> i32 {
+        self as i32
+    }
+
+    pub const fn name(self) -> &'static str {
+        match self {
+            RenderType::Integer => "integer",
+            RenderType::Hearts => "hearts",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|render| render.name() == name)
+    }
+}
+
+/// A named set of scores.
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
+pub struct Objective {
+    pub criteria: ObjectiveCriteria,
+    /// The name shown above the sidebar and in the player list, as plain text.
+    pub display_name: String,
+    pub render_type: RenderType,
+}
+
+/// The colours a team can have. The order matches the protocol's formatting codes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Encode, Decode)]
+pub enum TeamColor {
+    Black,
+    DarkBlue,
+    DarkGreen,
+    DarkAqua,
+    DarkRed,
+    DarkPurple,
+    Gold,
+    Gray,
+    DarkGray,
+    Blue,
+    Green,
+    Aqua,
+    Red,
+    LightPurple,
+    Yellow,
+    White,
+    /// No colour, which is what new teams start with.
+    Reset,
+}
+
+impl TeamColor {
+    pub const ALL: [TeamColor; 17] = [
+        TeamColor::Black,
+        TeamColor::DarkBlue,
+        TeamColor::DarkGreen,
+        TeamColor::DarkAqua,
+        TeamColor::DarkRed,
+        TeamColor::DarkPurple,
+        TeamColor::Gold,
+        TeamColor::Gray,
+        TeamColor::DarkGray,
+        TeamColor::Blue,
+        TeamColor::Green,
+        TeamColor::Aqua,
+        TeamColor::Red,
+        TeamColor::LightPurple,
+        TeamColor::Yellow,
+        TeamColor::White,
+        TeamColor::Reset,
+    ];
+
+    /// The protocol ID of the colour.
+    pub const fn id(self) -> i32 {
+        match self {
+            // Formatting codes 16 to 20 are bold, italic and so on
+            TeamColor::Reset => 21,
+            _ => self as i32,
+        }
+    }
+
+    pub const fn name(self) -> &'static str {
+        match self {
+            TeamColor::Black => "black",
+            TeamColor::DarkBlue => "dark_blue",
+            TeamColor::DarkGreen => "dark_green",
+            TeamColor::DarkAqua => "dark_aqua",
+            TeamColor::DarkRed => "dark_red",
+            TeamColor::DarkPurple => "dark_purple",
+            TeamColor::Gold => "gold",
+            TeamColor::Gray => "gray",
+            TeamColor::DarkGray => "dark_gray",
+            TeamColor::Blue => "blue",
+            TeamColor::Green => "green",
+            TeamColor::Aqua => "aqua",
+            TeamColor::Red => "red",
+            TeamColor::LightPurple => "light_purple",
+            TeamColor::Yellow => "yellow",
+            TeamColor::White => "white",
+            TeamColor::Reset => "reset",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|color| color.name() == name)
+    }
+}
+
+/// Who can see the name tags of a team's members.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Encode, Decode)]
+pub enum NameTagVisibility {
+    Always,
+    Never,
+    HideForOtherTeams,
+    HideForOwnTeam,
+}
+
+impl NameTagVisibility {
+    pub const ALL: [NameTagVisibility; 4] = [
+        NameTagVisibility::Always,
+        NameTagVisibility::Never,
+        NameTagVisibility::HideForOtherTeams,
+        NameTagVisibility::HideForOwnTeam,
+    ];
+
+    /// The protocol ID of the visibility.
+    pub const fn id(self) -> i32 {
+        self as i32
+    }
+
+    pub const fn name(self) -> &'static str {
+        match self {
+            NameTagVisibility::Always => "always",
+            NameTagVisibility::Never => "never",
+            NameTagVisibility::HideForOtherTeams => "hideForOtherTeams",
+            NameTagVisibility::HideForOwnTeam => "hideForOwnTeam",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|rule| rule.name() == name)
+    }
+}
+
+/// Which entities a team's members get pushed around by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Encode, Decode)]
+pub enum CollisionRule {
+    Always,
+    Never,
+    PushOtherTeams,
+    PushOwnTeam,
+}
+
+impl CollisionRule {
+    pub const ALL: [CollisionRule; 4] = [
+        CollisionRule::Always,
+        CollisionRule::Never,
+        CollisionRule::PushOtherTeams,
+        CollisionRule::PushOwnTeam,
+    ];
+
+    /// The protocol ID of the rule.
+    pub const fn id(self) -> i32 {
+        self as i32
+    }
+
+    pub const fn name(self) -> &'static str {
+        match self {
+            CollisionRule::Always => "always",
+            CollisionRule::Never => "never",
+            CollisionRule::PushOtherTeams => "pushOtherTeams",
+            CollisionRule::PushOwnTeam => "pushOwnTeam",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|rule| rule.name() == name)
+    }
+}
+
+/// A group of score holders that share a colour, a prefix and suffix on their names, and rules
+/// for how they interact.
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
+pub struct Team {
+    /// The team's name as shown to players, as plain text.
+    pub display_name: String,
+    /// Text shown before the names of members.
+    pub prefix: String,
+    /// Text shown after the names of members.
+    pub suffix: String,
+    pub color: TeamColor,
+    /// Whether members can hurt each other.
+    pub friendly_fire: bool,
+    /// Whether members can see each other while invisible.
+    pub see_friendly_invisibles: bool,
+    pub name_tag_visibility: NameTagVisibility,
+    pub collision_rule: CollisionRule,
+    members: BTreeSet<String>,
+}
+
+impl Team {
+    pub fn new(display_name: String) -> Self {
+        Self {
+            display_name,
+            prefix: String::new(),
+            suffix: String::new(),
+            color: TeamColor::Reset,
+            friendly_fire: true,
+            see_friendly_invisibles: true,
+            name_tag_visibility: NameTagVisibility::Always,
+            collision_rule: CollisionRule::Always,
+            members: BTreeSet::new(),
+        }
+    }
+
+    /// The score holders on the team.
+    pub fn members(&self) -> impl Iterator<Item = &str> {
+        self.members.iter().map(String::as_str)
+    }
+
+    /// The friendly fire and invisibility flags, packed the way the protocol sends them.
+    pub fn flags(&self) -> u8 {
+        self.friendly_fire as u8 | (self.see_friendly_invisibles as u8) << 1
+    }
+}
+
+/// Where an objective is shown to players.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Encode, Decode)]
+pub enum DisplaySlot {
+    /// Next to names in the player list.
+    List,
+    Sidebar,
+    /// Under the name tags of players.
+    BelowName,
+    /// The sidebar, only for players on a team with this colour.
+    TeamSidebar(TeamColor),
+}
+
+impl DisplaySlot {
+    /// The protocol ID of the slot.
+    pub const fn id(self) -> i32 {
+        match self {
+            DisplaySlot::List => 0,
+            DisplaySlot::Sidebar => 1,
+            DisplaySlot::BelowName => 2,
+            DisplaySlot::TeamSidebar(color) => 3 + color as i32,
+        }
+    }
+
+    pub fn name(self) -> String {
+        match self {
+            DisplaySlot::List => "list".to_string(),
+            DisplaySlot::Sidebar => "sidebar".to_string(),
+            DisplaySlot::BelowName => "below_name".to_string(),
+            DisplaySlot::TeamSidebar(color) => format!("sidebar.team.{}", color.name()),
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "list" => Some(DisplaySlot::List),
+            "sidebar" => Some(DisplaySlot::Sidebar),
+            "below_name" | "belowName" => Some(DisplaySlot::BelowName),
+            _ => name
+                .strip_prefix("sidebar.team.")
+                .and_then(TeamColor::from_name)
+                .filter(|color| *color != TeamColor::Reset)
+                .map(DisplaySlot::TeamSidebar),
+        }
+    }
+
+    /// Every slot an objective can be shown in.
+    pub fn all() -> impl Iterator<Item = DisplaySlot> {
+        [
+            DisplaySlot::List,
+            DisplaySlot::Sidebar,
+            DisplaySlot::BelowName,
+        ]
+        .into_iter()
+        .chain(
+            TeamColor::ALL
+                .into_iter()
+                .filter(|color| *color != TeamColor::Reset)
+                .map(DisplaySlot::TeamSidebar),
+        )
+    }
+}
+
+/// The name an entity's scores and team membership are kept under: its name for players, like
+/// vanilla, and its UUID for everything else.
+pub fn holder_name(
+    entity: Option<&EntityIdentity>,
+    player: Option<&PlayerIdentity>,
+) -> Option<String> {
+    match (player, entity) {
+        (Some(player), _) => Some(player.username.clone()),
+        (None, Some(entity)) => Some(entity.uuid.to_string()),
+        (None, None) => None,
+    }
+}
+
+/// Something about the scoreboard that changed and has to be sent to players.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScoreboardChange {
+    ObjectiveAdded(String),
+    ObjectiveUpdated(String),
+    ObjectiveRemoved(String),
+    ScoreChanged {
+        holder: String,
+        objective: String,
+    },
+    /// The holder's score in the objective was reset, or all of their scores if `None`.
+    ScoreReset {
+        holder: String,
+        objective: Option<String>,
+    },
+    DisplayChanged(DisplaySlot),
+    TeamAdded(String),
+    TeamUpdated(String),
+    TeamRemoved(String),
+    MembersAdded {
+        team: String,
+        members: Vec<String>,
+    },
+    MembersRemoved {
+        team: String,
+        members: Vec<String>,
+    },
+}
+
+/// Everything on the scoreboard, saved alongside the world.
+#[derive(Debug, Clone, Default, PartialEq, Encode, Decode)]
+pub struct ScoreboardData {
+    objectives: BTreeMap<String, Objective>,
+    /// Scores by holder, then by objective.
+    scores: BTreeMap<String, BTreeMap<String, i32>>,
+    teams: BTreeMap<String, Team>,
+    display_slots: BTreeMap<DisplaySlot, String>,
+}
+
+impl ScoreboardData {
+    /// The name the scoreboard is saved under in the world's data.
+    pub const SAVE_NAME: &'static str = "scoreboard";
+}
+
+/// The world's objectives, scores and teams.
+///
+/// Score holders are player names, or UUIDs for other entities, but can be any name so fake
+/// players can be used for things like sidebar lines. Every change is queued up until it has
+/// been sent to the players.
+#[derive(Resource, Debug, Default)]
+pub struct Scoreboard {
+    data: ScoreboardData,
+    changes: Vec<ScoreboardChange>,
+}
+
+impl Scoreboard {
+    pub fn new(data: ScoreboardData) -> Self {
+        Self {
+            data,
+            changes: Vec::new(),
+        }
+    }
+
+    /// What gets saved.
+    pub fn data(&self) -> &ScoreboardData {
+        &self.data
+    }
+
+    pub fn objective(&self, name: &str) -> Option<&Objective> {
+        self.data.objectives.get(name)
+    }
+
+    pub fn objectives(&self) -> impl Iterator<Item = (&str, &Objective)> {
+        self.data
+            .objectives
+            .iter()
+            .map(|(name, objective)| (name.as_str(), objective))
+    }
+
+    /// Adds an objective, returning false if there already is one with the name.
+    pub fn add_objective(&mut self, name: &str, objective: Objective) -> bool {
+        if self.data.objectives.contains_key(name) {
+            return false;
+        }
+        self.data.objectives.insert(name.to_string(), objective);
+        self.changes
+            .push(ScoreboardChange::ObjectiveAdded(name.to_string()));
+        true
+    }
+
+    /// Removes an objective along with its scores, and takes it off any slot it was shown in.
+    pub fn remove_objective(&mut self, name: &str) -> bool {
+        if self.data.objectives.remove(name).is_none() {
+            return false;
+        }
+        for scores in self.data.scores.values_mut() {
+            scores.remove(name);
+        }
+        self.data.scores.retain(|_, scores| !scores.is_empty());
+        self.data
+            .display_slots
+            .retain(|_, objective| objective != name);
+        self.changes
+            .push(ScoreboardChange::ObjectiveRemoved(name.to_string()));
+        true
+    }
+
+    /// Changes how an objective looks, returning false if there isn't one with the name.
+    pub fn update_objective(&mut self, name: &str, update: impl FnOnce(&mut Objective)) -> bool {
+        let Some(objective) = self.data.objectives.get_mut(name) else {
+            return false;
+        };
+        update(objective);
+        self.changes
+            .push(ScoreboardChange::ObjectiveUpdated(name.to_string()));
+        true
+    }
+
+    pub fn score(&self, holder: &str, objective: &str) -> Option<i32> {
+        self.data.scores.get(holder)?.get(objective).copied()
+    }
+
+    /// Every score in an objective, by holder.
+    pub fn scores<'a>(&'a self, objective: &'a str) -> impl Iterator<Item = (&'a str, i32)> + 'a {
+        self.data.scores.iter().filter_map(move |(holder, scores)| {
+            scores.get(objective).map(|score| (holder.as_str(), *score))
+        })
+    }
+
+    /// Every score of a holder, by objective.
+    pub fn scores_of(&self, holder: &str) -> impl Iterator<Item = (&str, i32)> {
+        self.data
+            .scores
+            .get(holder)
+            .into_iter()
+            .flatten()
+            .map(|(objective, score)| (objective.as_str(), *score))
+    }
+
+    /// Every score holder with at least one score.
+    pub fn holders(&self) -> impl Iterator<Item = &str> {
+        self.data.scores.keys().map(String::as_str)
+    }
+
+    /// Sets a score, returning false if the objective doesn't exist. Setting a score to what it
+    /// already is doesn't count as a change.
+    pub fn set_score(&mut self, holder: &str, objective: &str, value: i32) -> bool {
+        if !self.data.objectives.contains_key(objective) {
+            return false;
+        }
+        let scores = self.data.scores.entry(holder.to_string()).or_default();
+        if scores.insert(objective.to_string(), value) != Some(value) {
+            self.changes.push(ScoreboardChange::ScoreChanged {
+                holder: holder.to_string(),
+                objective: objective.to_string(),
+            });
+        }
+        true
+    }
+
+    /// Adds to a score, starting from 0 if the holder has none, and returns the new score.
+    pub fn add_score(&mut self, holder: &str, objective: &str, amount: i32) -> Option<i32> {
+        let value = self
+            .score(holder, objective)
+            .unwrap_or(0)
+            .saturating_add(amount);
+        self.set_score(holder, objective, value).then_some(value)
+    }
+
+    /// Resets a holder's score in an objective, or all of their scores. Returns false if there
+    /// was nothing to reset.
+    pub fn reset_score(&mut self, holder: &str, objective: Option<&str>) -> bool {
+        let Some(scores) = self.data.scores.get_mut(holder) else {
+            return false;
+        };
+        let removed = match objective {
+            Some(objective) => scores.remove(objective).is_some(),
+            None => {
+                scores.clear();
+                true
+            }
+        };
+        if scores.is_empty() {
+            self.data.scores.remove(holder);
+        }
+        if removed {
+            self.changes.push(ScoreboardChange::ScoreReset {
+                holder: holder.to_string(),
+                objective: objective.map(str::to_string),
+            });
+        }
+        removed
+    }
+
+    /// The objective shown in a slot.
+    pub fn displayed(&self, slot: DisplaySlot) -> Option<&str> {
+        self.data.display_slots.get(&slot).map(String::as_str)
+    }
+
+    /// Every slot that is showing an objective.
+    pub fn display_slots(&self) -> impl Iterator<Item = (DisplaySlot, &str)> {
+        self.data
+            .display_slots
+            .iter()
+            .map(|(slot, objective)| (*slot, objective.as_str()))
+    }
+
+    /// Shows an objective in a slot, or clears the slot if `None`. Returns false if the
+    /// objective doesn't exist.
+    pub fn set_display(&mut self, slot: DisplaySlot, objective: Option<&str>) -> bool {
+        match objective {
+            Some(objective) => {
+                if !self.data.objectives.contains_key(objective) {
+                    return false;
+                }
+                self.data.display_slots.insert(slot, objective.to_string());
+            }
+            None => {
+                self.data.display_slots.remove(&slot);
+            }
+        }
+        self.changes.push(ScoreboardChange::DisplayChanged(slot));
+        true
+    }
+
+    pub fn team(&self, name: &str) -> Option<&Team> {
+        self.data.teams.get(name)
+    }
+
+    pub fn teams(&self) -> impl Iterator<Item = (&str, &Team)> {
+        self.data
+            .teams
+            .iter()
+            .map(|(name, team)| (name.as_str(), team))
+    }
+
+    /// The name of the team a holder is on.
+    pub fn team_of(&self, holder: &str) -> Option<&str> {
+        self.teams()
+            .find(|(_, team)| team.members.contains(holder))
+            .map(|(name, _)| name)
+    }
+
+    /// Whether `attacker` can hurt `target`. Teammates can only hurt each other if their team
+    /// has friendly fire on.
+    pub fn can_hurt(&self, attacker: &str, target: &str) -> bool {
+        match self.team_of(attacker) {
+            Some(team) if self.team_of(target) == Some(team) => {
+                self.team(team).is_none_or(|team| team.friendly_fire)
+            }
+            _ => true,
+        }
+    }
+
+    /// Adds a team, returning false if there already is one with the name.
+    pub fn add_team(&mut self, name: &str, team: Team) -> bool {
+        if self.data.teams.contains_key(name) {
+            return false;
+        }
+        self.data.teams.insert(name.to_string(), team);
+        self.changes
+            .push(ScoreboardChange::TeamAdded(name.to_string()));
+        true
+    }
+
+    pub fn remove_team(&mut self, name: &str) -> bool {
+        if self.data.teams.remove(name).is_none() {
+            return false;
+        }
+        self.changes
+            .push(ScoreboardChange::TeamRemoved(name.to_string()));
+        true
+    }
+
+    /// Changes a team's options, returning false if there isn't one with the name.
+    pub fn update_team(&mut self, name: &str, update: impl FnOnce(&mut Team)) -> bool {
+        let Some(team) = self.data.teams.get_mut(name) else {
+            return false;
+        };
+        update(team);
+        self.changes
+            .push(ScoreboardChange::TeamUpdated(name.to_string()));
+        true
+    }
+
+    /// Puts holders on a team, taking them off the one they were on before. Returns false if the
+    /// team doesn't exist.
+    pub fn join_team(&mut self, name: &str, holders: &[String]) -> bool {
+        if !self.data.teams.contains_key(name) {
+            return false;
+        }
+        // Clients take players off their old team themselves when they join a new one
+        for team in self.data.teams.values_mut() {
+            for holder in holders {
+                team.members.remove(holder);
+            }
+        }
+        if let Some(team) = self.data.teams.get_mut(name) {
+            team.members.extend(holders.iter().cloned());
+        }
+        self.changes.push(ScoreboardChange::MembersAdded {
+            team: name.to_string(),
+            members: holders.to_vec(),
+        });
+        true
+    }
+
+    /// Takes holders off whatever team they're on, returning how many were on one.
+    pub fn leave_team(&mut self, holders: &[String]) -> usize {
+        let mut left = 0;
+        for (name, team) in self.data.teams.iter_mut() {
+            let members: Vec<String> = holders
+                .iter()
+                .filter(|holder| team.members.remove(*holder))
+                .cloned()
+                .collect();
+            if members.is_empty() {
+                continue;
+            }
+            left += members.len();
+            self.changes.push(ScoreboardChange::MembersRemoved {
+                team: name.clone(),
+                members,
+            });
+        }
+        left
+    }
+
+    /// Takes every member off a team, returning how many there were.
+    pub fn empty_team(&mut self, name: &str) -> Option<usize> {
+        let team = self.data.teams.get_mut(name)?;
+        let members: Vec<String> = std::mem::take(&mut team.members).into_iter().collect();
+        let count = members.len();
+        if count > 0 {
+            self.changes.push(ScoreboardChange::MembersRemoved {
+                team: name.to_string(),
+                members,
+            });
+        }
+        Some(count)
+    }
+
+    /// The changes since the last call, in the order they were made.
+    pub fn take_changes(&mut self) -> Vec<ScoreboardChange> {
+        std::mem::take(&mut self.changes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dummy(display_name: &str) -> Objective {
+        Objective {
+            criteria: ObjectiveCriteria::Dummy,
+            display_name: display_name.to_string(),
+            render_type: RenderType::Integer,
+        }
+    }
+
+    #[test]
+    fn test_scores() {
+        let mut scoreboard = Scoreboard::default();
+        assert!(!scoreboard.set_score("Steve", "kills", 1));
+        assert!(scoreboard.add_objective("kills", dummy("Kills")));
+        assert!(!scoreboard.add_objective("kills", dummy("Kills")));
+
+        assert!(scoreboard.set_score("Steve", "kills", 1));
+        assert_eq!(scoreboard.add_score("Steve", "kills", 2), Some(3));
+        assert_eq!(scoreboard.add_score("Alex", "kills", -1), Some(-1));
+        assert_eq!(scoreboard.score("Steve", "kills"), Some(3));
+        assert_eq!(scoreboard.scores("kills").count(), 2);
+
+        assert!(scoreboard.reset_score("Alex", None));
+        assert!(!scoreboard.reset_score("Alex", Some("kills")));
+        assert_eq!(scoreboard.holders().collect::<Vec<_>>(), ["Steve"]);
+    }
+
+    #[test]
+    fn test_removing_objective_clears_scores_and_slots() {
+        let mut scoreboard = Scoreboard::default();
+        scoreboard.add_objective("kills", dummy("Kills"));
+        scoreboard.set_score("Steve", "kills", 5);
+        assert!(scoreboard.set_display(DisplaySlot::Sidebar, Some("kills")));
+        assert!(!scoreboard.set_display(DisplaySlot::List, Some("deaths")));
+
+        assert!(scoreboard.remove_objective("kills"));
+        assert_eq!(scoreboard.score("Steve", "kills"), None);
+        assert_eq!(scoreboard.displayed(DisplaySlot::Sidebar), None);
+        assert_eq!(scoreboard.holders().count(), 0);
+    }
+
+    #[test]
+    fn test_teams() {
+        let mut scoreboard = Scoreboard::default();
+        scoreboard.add_team("red", Team::new("Red".to_string()));
+        scoreboard.add_team("blue", Team::new("Blue".to_string()));
+        let steve = vec!["Steve".to_string()];
+
+        assert!(scoreboard.join_team("red", &steve));
+        assert!(scoreboard.join_team("blue", &steve));
+        assert_eq!(scoreboard.team_of("Steve"), Some("blue"));
+        assert_eq!(scoreboard.team("red").unwrap().members().count(), 0);
+
+        assert_eq!(scoreboard.leave_team(&steve), 1);
+        assert_eq!(scoreboard.team_of("Steve"), None);
+        assert!(!scoreboard.join_team("green", &steve));
+    }
+
+    #[test]
+    fn test_friendly_fire() {
+        let mut scoreboard = Scoreboard::default();
+        scoreboard.add_team("red", Team::new("Red".to_string()));
+        scoreboard.add_team("blue", Team::new("Blue".to_string()));
+        scoreboard.join_team("red", &["Steve".to_string(), "Alex".to_string()]);
+        scoreboard.join_team("blue", &["Notch".to_string()]);
+        assert!(scoreboard.can_hurt("Steve", "Alex"));
+
+        scoreboard.update_team("red", |team| team.friendly_fire = false);
+        assert!(!scoreboard.can_hurt("Steve", "Alex"));
+        assert!(scoreboard.can_hurt("Steve", "Notch"));
+        assert!(scoreboard.can_hurt("Herobrine", "Alex"));
+    }
+
+    #[test]
+    fn test_changes_are_queued() {
+        let mut scoreboard = Scoreboard::default();
+        scoreboard.add_objective("kills", dummy("Kills"));
+        scoreboard.set_score("Steve", "kills", 1);
+        // Setting the same score again isn't a change
+        scoreboard.set_score("Steve", "kills", 1);
+        assert_eq!(
+            scoreboard.take_changes(),
+            [
+                ScoreboardChange::ObjectiveAdded("kills".to_string()),
+                ScoreboardChange::ScoreChanged {
+                    holder: "Steve".to_string(),
+                    objective: "kills".to_string()
+                },
+            ]
+        );
+        assert!(scoreboard.take_changes().is_empty());
+    }
+
+    #[test]
+    fn test_display_slot_names() {
+        for slot in DisplaySlot::all() {
+            assert_eq!(DisplaySlot::from_name(&slot.name()), Some(slot));
+        }
+        assert_eq!(DisplaySlot::TeamSidebar(TeamColor::White).id(), 18);
+        assert_eq!(DisplaySlot::from_name("sidebar.team.reset"), None);
+        assert_eq!(TeamColor::Reset.id(), 21);
+    }
+}
@@ -0,0 +1,79 @@
+/// The volume slider a sound plays under in the client's settings.
+///
+/// The order matches the IDs the protocol uses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SoundCategory {
+    Master,
+    Music,
+    Records,
+    Weather,
+    Blocks,
+    Hostile,
+    Neutral,
+    Players,
+    Ambient,
+    Voice,
+    Ui,
+}
+
+impl SoundCategory {
+    pub const ALL: [SoundCategory; 11] = [
+        SoundCategory::Master,
+        SoundCategory::Music,
+        SoundCategory::Records,
+        SoundCategory::Weather,
+        SoundCategory::Blocks,
+        SoundCategory::Hostile,
+        SoundCategory::Neutral,
+        SoundCategory::Players,
+        SoundCategory::Ambient,
+        SoundCategory::Voice,
+        SoundCategory::Ui,
+    ];
+
+    /// The protocol ID of the category.
+    pub const fn id(self) -> i32 {
+        self as i32
+    }
+
+    /// The name used in commands, like `/playsound`.
+    pub const fn name(self) -> &'static str {
+        match self {
+            SoundCategory::Master => "master",
+            SoundCategory::Music => "music",
+            SoundCategory::Records => "record",
+            SoundCategory::Weather => "weather",
+            SoundCategory::Blocks => "block",
+            SoundCategory::Hostile => "hostile",
+            SoundCategory::Neutral => "neutral",
+            SoundCategory::Players => "player",
+            SoundCategory::Ambient => "ambient",
+            SoundCategory::Voice => "voice",
+            SoundCategory::Ui => "ui",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|category| category.name().eq_ignore_ascii_case(name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_names_and_ids() {
+        for (id, category) in SoundCategory::ALL.into_iter().enumerate() {
+            assert_eq!(category.id(), id as i32);
+            assert_eq!(SoundCategory::from_name(category.name()), Some(category));
+        }
+        assert_eq!(
+            SoundCategory::from_name("BLOCK"),
+            Some(SoundCategory::Blocks)
+        );
+        assert_eq!(SoundCategory::from_name("blocks"), None);
+    }
+}
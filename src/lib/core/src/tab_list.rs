@@ -0,0 +1,20 @@
+use bevy_ecs::prelude::Component;
+use ferrumc_text::TextComponent;
+
+/// The text above and below the player list, which is sent whenever it changes.
+///
+/// Players without it have an empty header and footer.
+#[derive(Component, Debug, Clone, Default, PartialEq)]
+pub struct TabListText {
+    pub header: TextComponent,
+    pub footer: TextComponent,
+}
+
+impl TabListText {
+    pub fn new(header: impl Into<TextComponent>, footer: impl Into<TextComponent>) -> Self {
+        Self {
+            header: header.into(),
+            footer: footer.into(),
+        }
+    }
+}
@@ -4,7 +4,10 @@ use std::ops::Range;
 use std::time::Instant;
 
 #[derive(Resource, Debug, Default)]
-pub struct WorldTime(u16);
+pub struct WorldTime {
+    time: u16,
+    world_age: u64,
+}
 
 #[derive(Component)]
 pub struct LastSentTimeUpdate {
@@ -20,19 +23,36 @@ impl WorldTime {
     pub const NIGHT: Range<u16> = 13000..23000;
     pub const DAWN: Range<u16> = 23000..24000;
 
+    pub fn new(time: u16, world_age: u64) -> Self {
+        Self {
+            time: time % Self::MAX_TIME,
+            world_age,
+        }
+    }
+
+    /// Ages the world by a tick, and moves the time of day along if `daylight_cycle` is set.
     #[inline]
-    pub fn advance_tick(&mut self) {
-        self.0 = (self.0 + 1) % Self::MAX_TIME;
+    pub fn advance_tick(&mut self, daylight_cycle: bool) {
+        self.world_age += 1;
+        if daylight_cycle {
+            self.time = (self.time + 1) % Self::MAX_TIME;
+        }
     }
 
     #[inline]
     pub fn current_time(&self) -> u16 {
-        self.0
+        self.time
+    }
+
+    /// How many ticks the world has been running for, which unlike the time of day never resets.
+    #[inline]
+    pub fn world_age(&self) -> u64 {
+        self.world_age
     }
 
     #[inline]
     pub fn set_time(&mut self, time: u16) {
-        self.0 = time % Self::MAX_TIME;
+        self.time = time % Self::MAX_TIME;
     }
 
     pub fn set_time_to_start(&mut self, range: Range<u16>) {
@@ -0,0 +1,206 @@
+use bevy_ecs::prelude::Resource;
+use bitcode_derive::{Decode, Encode};
+use rand::Rng;
+use std::ops::Range;
+
+/// How long it stays clear before it starts raining, in ticks.
+pub const CLEAR_DURATION: Range<i32> = 12_000..180_000;
+/// How long it rains for, in ticks.
+pub const RAIN_DURATION: Range<i32> = 12_000..24_000;
+/// How long a thunderstorm lasts, in ticks.
+pub const THUNDER_DURATION: Range<i32> = 3_600..15_600;
+/// How long it takes before a thunderstorm can start, in ticks.
+pub const THUNDER_DELAY: Range<i32> = 12_000..180_000;
+
+/// How much the rain and thunder levels change every tick while fading in or out.
+const LEVEL_STEP: f32 = 0.01;
+
+/// The weather of the world, cycling between clear skies, rain and thunderstorms like vanilla.
+///
+/// Rain and thunder each count down to their next change. While `clear_time` is set, both are
+/// held off until it runs out, which is what `/weather clear` uses.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Encode, Decode)]
+pub struct Weather {
+    /// Ticks of guaranteed clear weather left.
+    pub clear_time: i32,
+    /// Ticks until it starts or stops raining.
+    pub rain_time: i32,
+    /// Ticks until a thunderstorm starts or stops.
+    pub thunder_time: i32,
+    pub raining: bool,
+    pub thundering: bool,
+    rain_level: f32,
+    thunder_level: f32,
+}
+
+impl Weather {
+    /// Advances the weather by one tick. Without `advance_cycle` the weather stays as it is,
+    /// though rain that was just started or stopped still fades in or out.
+    pub fn tick(&mut self, rng: &mut impl Rng, advance_cycle: bool) {
+        if advance_cycle {
+            self.advance_cycle(rng);
+        }
+        self.thunder_level = step_towards(self.thunder_level, self.thundering);
+        self.rain_level = step_towards(self.rain_level, self.raining);
+    }
+
+    fn advance_cycle(&mut self, rng: &mut impl Rng) {
+        if self.clear_time > 0 {
+            self.clear_time -= 1;
+            // Pick new durations as soon as the clear weather runs out
+            self.thunder_time = if self.thundering { 0 } else { 1 };
+            self.rain_time = if self.raining { 0 } else { 1 };
+            self.thundering = false;
+            self.raining = false;
+            return;
+        }
+
+        if self.thunder_time > 0 {
+            self.thunder_time -= 1;
+            if self.thunder_time == 0 {
+                self.thundering = !self.thundering;
+            }
+        } else if self.thundering {
+            self.thunder_time = rng.gen_range(THUNDER_DURATION);
+        } else {
+            self.thunder_time = rng.gen_range(THUNDER_DELAY);
+        }
+
+        if self.rain_time > 0 {
+            self.rain_time -= 1;
+            if self.rain_time == 0 {
+                self.raining = !self.raining;
+            }
+        } else if self.raining {
+            self.rain_time = rng.gen_range(RAIN_DURATION);
+        } else {
+            self.rain_time = rng.gen_range(CLEAR_DURATION);
+        }
+    }
+
+    /// Clears the sky for `duration` ticks.
+    pub fn set_clear(&mut self, duration: i32) {
+        self.clear_time = duration;
+        self.rain_time = 0;
+        self.thunder_time = 0;
+        self.raining = false;
+        self.thundering = false;
+    }
+
+    /// Makes it rain for `duration` ticks.
+    pub fn set_rain(&mut self, duration: i32) {
+        self.clear_time = 0;
+        self.rain_time = duration;
+        self.thunder_time = duration;
+        self.raining = true;
+        self.thundering = false;
+    }
+
+    /// Starts a thunderstorm lasting `duration` ticks.
+    pub fn set_thunder(&mut self, duration: i32) {
+        self.clear_time = 0;
+        self.rain_time = duration;
+        self.thunder_time = duration;
+        self.raining = true;
+        self.thundering = true;
+    }
+
+    /// Stops any rain and picks new durations on the next tick, used when players sleep through
+    /// the night.
+    pub fn reset_cycle(&mut self) {
+        self.rain_time = 0;
+        self.thunder_time = 0;
+        self.raining = false;
+        self.thundering = false;
+    }
+
+    /// How heavy the rain currently is, from 0 to 1. It fades in and out over a few seconds.
+    pub fn rain_level(&self) -> f32 {
+        self.rain_level
+    }
+
+    /// How strong the thunderstorm currently is, from 0 to 1.
+    pub fn thunder_level(&self) -> f32 {
+        self.thunder_level
+    }
+
+    /// Whether it's visibly raining, the same threshold clients use.
+    pub fn is_raining(&self) -> bool {
+        self.rain_level > 0.2
+    }
+
+    /// Whether there is a thunderstorm heavy enough for lightning.
+    pub fn is_thundering(&self) -> bool {
+        self.rain_level * self.thunder_level > 0.9
+    }
+}
+
+fn step_towards(level: f32, on: bool) -> f32 {
+    let step = if on { LEVEL_STEP } else { -LEVEL_STEP };
+    (level + step).clamp(0.0, 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rain_fades_in_and_out() {
+        let mut rng = rand::thread_rng();
+        let mut weather = Weather::default();
+        weather.set_rain(200);
+        for _ in 0..150 {
+            weather.tick(&mut rng, true);
+        }
+        assert!(weather.is_raining());
+        assert_eq!(weather.rain_level(), 1.0);
+        assert!(!weather.is_thundering());
+
+        weather.set_clear(1000);
+        weather.tick(&mut rng, true);
+        assert!(!weather.raining);
+        assert!(weather.is_raining());
+        for _ in 0..100 {
+            weather.tick(&mut rng, true);
+        }
+        assert!(!weather.is_raining());
+        assert_eq!(weather.clear_time, 1000 - 101);
+    }
+
+    #[test]
+    fn test_rain_stops_after_duration() {
+        let mut rng = rand::thread_rng();
+        let mut weather = Weather::default();
+        weather.set_thunder(50);
+        for _ in 0..50 {
+            weather.tick(&mut rng, true);
+        }
+        assert!(!weather.raining);
+        assert!(!weather.thundering);
+        // A new clear spell is picked on the next tick
+        weather.tick(&mut rng, true);
+        assert!(CLEAR_DURATION.contains(&weather.rain_time));
+    }
+
+    #[test]
+    fn test_frozen_cycle() {
+        let mut rng = rand::thread_rng();
+        let mut weather = Weather::default();
+        weather.set_rain(10);
+        for _ in 0..50 {
+            weather.tick(&mut rng, false);
+        }
+        assert!(weather.raining);
+        assert_eq!(weather.rain_time, 10);
+        assert!(weather.is_raining());
+    }
+
+    #[test]
+    fn test_cycle_picks_durations() {
+        let mut rng = rand::thread_rng();
+        let mut weather = Weather::default();
+        weather.tick(&mut rng, true);
+        assert!(CLEAR_DURATION.contains(&weather.rain_time));
+        assert!(THUNDER_DELAY.contains(&weather.thunder_time));
+    }
+}
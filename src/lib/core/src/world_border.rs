@@ -0,0 +1,252 @@
+use bevy_ecs::prelude::Resource;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// The largest the border can get, the same as vanilla.
+pub const MAX_DIAMETER: f64 = 59_999_968.0;
+/// How far out portals may teleport entities, which clients need to know about.
+pub const PORTAL_TELEPORT_BOUNDARY: i32 = 29_999_984;
+
+/// A square border around a dimension that players can't cross, which can grow or shrink over
+/// time.
+#[derive(Debug, Clone)]
+pub struct WorldBorder {
+    center_x: f64,
+    center_z: f64,
+    old_diameter: f64,
+    new_diameter: f64,
+    lerp_start: Instant,
+    lerp_duration: Duration,
+    /// Damage per block a player is past the safe zone, dealt every second.
+    pub damage_per_block: f64,
+    /// How far past the border players can go without taking damage, in blocks.
+    pub safe_zone: f64,
+    /// How close to the border players see the warning, in blocks.
+    pub warning_blocks: i32,
+    /// How many seconds before a shrinking border reaches players they see the warning.
+    pub warning_time: i32,
+    /// Set whenever the border changes, until it has been sent to the players.
+    dirty: bool,
+}
+
+impl WorldBorder {
+    pub fn new(center_x: f64, center_z: f64, diameter: f64) -> Self {
+        let diameter = diameter.clamp(1.0, MAX_DIAMETER);
+        Self {
+            center_x,
+            center_z,
+            old_diameter: diameter,
+            new_diameter: diameter,
+            lerp_start: Instant::now(),
+            lerp_duration: Duration::ZERO,
+            damage_per_block: 0.2,
+            safe_zone: 5.0,
+            warning_blocks: 5,
+            warning_time: 15,
+            dirty: false,
+        }
+    }
+
+    pub fn center(&self) -> (f64, f64) {
+        (self.center_x, self.center_z)
+    }
+
+    pub fn set_center(&mut self, x: f64, z: f64) {
+        self.center_x = x;
+        self.center_z = z;
+        self.dirty = true;
+    }
+
+    /// The current width of the border, part way between the old and new size while it moves.
+    pub fn diameter(&self) -> f64 {
+        let elapsed = self.lerp_start.elapsed();
+        if elapsed >= self.lerp_duration {
+            return self.new_diameter;
+        }
+        let progress = elapsed.as_secs_f64() / self.lerp_duration.as_secs_f64();
+        self.old_diameter + (self.new_diameter - self.old_diameter) * progress
+    }
+
+    /// The width the border is moving towards, or its current width if it isn't moving.
+    pub fn target_diameter(&self) -> f64 {
+        self.new_diameter
+    }
+
+    /// How long until the border reaches its target width.
+    pub fn remaining(&self) -> Duration {
+        self.lerp_duration.saturating_sub(self.lerp_start.elapsed())
+    }
+
+    pub fn is_moving(&self) -> bool {
+        !self.remaining().is_zero()
+    }
+
+    /// Resizes the border right away, stopping it if it was moving.
+    pub fn set_diameter(&mut self, diameter: f64) {
+        self.lerp_to(diameter, Duration::ZERO);
+    }
+
+    /// Grows or shrinks the border from its current width to `diameter` over `duration`.
+    pub fn lerp_to(&mut self, diameter: f64, duration: Duration) {
+        self.old_diameter = self.diameter();
+        self.new_diameter = diameter.clamp(1.0, MAX_DIAMETER);
+        self.lerp_start = Instant::now();
+        self.lerp_duration = duration;
+        self.dirty = true;
+    }
+
+    /// The lowest and highest x and z inside the border, as `(min_x, min_z, max_x, max_z)`.
+    pub fn bounds(&self) -> (f64, f64, f64, f64) {
+        let radius = self.diameter() / 2.0;
+        (
+            self.center_x - radius,
+            self.center_z - radius,
+            self.center_x + radius,
+            self.center_z + radius,
+        )
+    }
+
+    pub fn contains(&self, x: f64, z: f64) -> bool {
+        let (min_x, min_z, max_x, max_z) = self.bounds();
+        x >= min_x && x < max_x && z >= min_z && z < max_z
+    }
+
+    /// Returns true if any part of the block at the position is inside the border, the same
+    /// check vanilla uses for placing and breaking blocks.
+    pub fn contains_block(&self, x: i32, z: i32) -> bool {
+        let (min_x, min_z, max_x, max_z) = self.bounds();
+        let (x, z) = (x as f64, z as f64);
+        x + 1.0 > min_x && x < max_x && z + 1.0 > min_z && z < max_z
+    }
+
+    /// Returns true if moving between the positions crosses the border from the inside.
+    /// Players that are already outside, because the border shrank past them, may move freely
+    /// so they can get back in.
+    pub fn blocks_move(&self, from: (f64, f64), to: (f64, f64)) -> bool {
+        self.contains(from.0, from.1) && !self.contains(to.0, to.1)
+    }
+
+    /// How far outside the border the position is, 0 if it is inside.
+    pub fn distance_outside(&self, x: f64, z: f64) -> f64 {
+        let (min_x, min_z, max_x, max_z) = self.bounds();
+        let dx = (min_x - x).max(x - max_x).max(0.0);
+        let dz = (min_z - z).max(z - max_z).max(0.0);
+        dx.max(dz)
+    }
+
+    /// The damage a player at the position takes every second, 0 within the safe zone.
+    pub fn damage_at(&self, x: f64, z: f64) -> f32 {
+        let past_safe_zone = self.distance_outside(x, z) - self.safe_zone;
+        if past_safe_zone <= 0.0 || self.damage_per_block <= 0.0 {
+            return 0.0;
+        }
+        (past_safe_zone * self.damage_per_block).floor().max(1.0) as f32
+    }
+
+    /// Returns true once after every change, so the change can be sent to the players.
+    pub fn take_dirty(&mut self) -> bool {
+        std::mem::take(&mut self.dirty)
+    }
+}
+
+/// The world border of every dimension.
+///
+/// Dimensions start out with the default border, and get their own once it is changed.
+#[derive(Resource, Debug)]
+pub struct WorldBorders {
+    default: WorldBorder,
+    borders: HashMap<String, WorldBorder>,
+}
+
+impl WorldBorders {
+    pub fn new(default: WorldBorder) -> Self {
+        Self {
+            default,
+            borders: HashMap::new(),
+        }
+    }
+
+    pub fn get(&self, dimension: &str) -> &WorldBorder {
+        self.borders.get(dimension).unwrap_or(&self.default)
+    }
+
+    pub fn get_mut(&mut self, dimension: &str) -> &mut WorldBorder {
+        self.borders
+            .entry(dimension.to_string())
+            .or_insert_with(|| self.default.clone())
+    }
+
+    /// The borders that changed since the last call, by dimension.
+    pub fn take_changed(&mut self) -> impl Iterator<Item = (&str, &WorldBorder)> {
+        self.borders.iter_mut().filter_map(|(dimension, border)| {
+            border
+                .take_dirty()
+                .then_some((dimension.as_str(), &*border))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contains_and_distance() {
+        let border = WorldBorder::new(10.0, -10.0, 20.0);
+        assert!(border.contains(0.0, -20.0));
+        assert!(border.contains(19.9, -0.1));
+        assert!(!border.contains(20.0, -10.0));
+        assert!(border.contains_block(19, -20));
+        assert!(!border.contains_block(20, -10));
+        assert!(!border.contains_block(0, -21));
+        assert_eq!(border.distance_outside(10.0, -10.0), 0.0);
+        assert_eq!(border.distance_outside(25.0, -10.0), 5.0);
+        assert_eq!(border.distance_outside(-3.0, 5.0), 5.0);
+    }
+
+    #[test]
+    fn test_blocks_move() {
+        let border = WorldBorder::new(0.0, 0.0, 10.0);
+        assert!(!border.blocks_move((0.0, 0.0), (4.0, 4.0)));
+        assert!(border.blocks_move((4.0, 0.0), (6.0, 0.0)));
+        // Players outside the border can walk back in, or further out
+        assert!(!border.blocks_move((8.0, 0.0), (4.0, 0.0)));
+        assert!(!border.blocks_move((8.0, 0.0), (9.0, 0.0)));
+    }
+
+    #[test]
+    fn test_damage_outside_safe_zone() {
+        let mut border = WorldBorder::new(0.0, 0.0, 10.0);
+        border.safe_zone = 2.0;
+        border.damage_per_block = 0.5;
+        assert_eq!(border.damage_at(6.0, 0.0), 0.0);
+        // Always at least 1 damage once past the safe zone
+        assert_eq!(border.damage_at(7.5, 0.0), 1.0);
+        assert_eq!(border.damage_at(0.0, 15.0), 4.0);
+    }
+
+    #[test]
+    fn test_lerp() {
+        let mut border = WorldBorder::new(0.0, 0.0, 100.0);
+        border.lerp_to(10.0, Duration::from_secs(3600));
+        assert!(border.is_moving());
+        assert_eq!(border.target_diameter(), 10.0);
+        assert!(border.diameter() <= 100.0 && border.diameter() > 10.0);
+
+        border.set_diameter(0.0);
+        assert!(!border.is_moving());
+        assert_eq!(border.diameter(), 1.0);
+    }
+
+    #[test]
+    fn test_dimensions_are_separate() {
+        let mut borders = WorldBorders::new(WorldBorder::new(0.0, 0.0, 100.0));
+        borders.get_mut("the_nether").set_diameter(50.0);
+        assert_eq!(borders.get("overworld").diameter(), 100.0);
+        assert_eq!(borders.get("the_nether").diameter(), 50.0);
+
+        let changed: Vec<&str> = borders.take_changed().map(|(dim, _)| dim).collect();
+        assert_eq!(changed, ["the_nether"]);
+        assert_eq!(borders.take_changed().count(), 0);
+    }
+}
@@ -0,0 +1,53 @@
+use crate::game_rules::GameRules;
+use crate::transform::position::Position;
+use crate::weather::Weather;
+use bevy_ecs::prelude::Resource;
+use bitcode_derive::{Decode, Encode};
+
+/// Everything about a world that isn't kept in its chunks or player data, saved alongside them
+/// in the world's storage.
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
+pub struct WorldMetadata {
+    /// How many ticks the world has been running for.
+    pub world_age: u64,
+    /// The time of day, in ticks.
+    pub day_time: u16,
+    /// Where players spawn when they first join.
+    pub spawn: (i32, i32, i32),
+    /// The seed the world is generated from.
+    pub seed: u64,
+    pub weather: Weather,
+    pub game_rules: GameRules,
+}
+
+impl WorldMetadata {
+    /// The name the metadata is saved under in the world's data.
+    pub const SAVE_NAME: &'static str = "metadata";
+
+    /// The metadata of a brand new world.
+    pub fn new(seed: u64, spawn: (i32, i32, i32)) -> Self {
+        Self {
+            world_age: 0,
+            day_time: 0,
+            spawn,
+            seed,
+            weather: Weather::default(),
+            game_rules: GameRules::default(),
+        }
+    }
+}
+
+/// The parts of the world's metadata that don't change while the server runs.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct WorldInfo {
+    pub seed: u64,
+    pub spawn: (i32, i32, i32),
+}
+
+impl WorldInfo {
+    /// Where players stand at the spawn, in the middle of the spawn block.
+    pub fn spawn_position(&self) -> Position {
+        let (x, y, z) = self.spawn;
+        Position::new(x as f64 + 0.5, y as f64, z as f64 + 0.5)
+    }
+}
@@ -2,6 +2,7 @@ pub mod player_list;
 
 use crate::player_list::PlayerList;
 use bevy_ecs::prelude::Resource;
+use ferrumc_core::world_metadata::WorldInfo;
 use ferrumc_threadpool::ThreadPool;
 use ferrumc_world::World;
 use ferrumc_world_gen::WorldGenerator;
@@ -17,6 +18,8 @@ pub struct ServerState {
     pub players: PlayerList, // (UUID, Username)
     pub thread_pool: ThreadPool,
     pub start_time: Instant,
+    /// The world's seed and spawn, which don't change while the server runs.
+    pub info: WorldInfo,
 }
 
 pub type GlobalState = Arc<ServerState>;
@@ -36,6 +39,10 @@ pub fn create_test_state() -> (GlobalStateResource, TempDir) {
         players: PlayerList::default(),
         thread_pool: ThreadPool::new(),
         start_time: Instant::now(),
+        info: WorldInfo {
+            seed: 0,
+            spawn: (0, 64, 0),
+        },
     };
 
     let global_state = Arc::new(server_state);
@@ -29,10 +29,8 @@ pub(crate) fn build() -> TokenStream {
         let const_ident = format_ident!("{}", name.to_shouty_snake_case());
         const_idents.push(const_ident.clone());
         let id_lit = syn::LitInt::new(&attribute.id.to_string(), Span::call_site());
-        let default_value_lit = LitFloat::new(
-            &format!("{:.1}", attribute.default_value),
-            Span::call_site(),
-        );
+        let default_value_lit =
+            LitFloat::new(&format!("{:?}", attribute.default_value), Span::call_site());
 
         constants.extend(quote! {
             pub const #const_ident: Attribute = Attribute {
@@ -23,6 +23,8 @@ pub struct Block {
     properties: Vec<Property>,
     default_state_id: u32,
     states: Vec<State>,
+    #[serde(default)]
+    loot_table: Option<serde_json::Value>,
 }
 
 // Properties are actually integers in the JSON format
@@ -90,6 +92,9 @@ pub fn build() -> Result<(), Box<dyn std::error::Error>> {
     types_content.push_str("    pub jump_velocity_multiplier: f32,\n");
     types_content.push_str("    pub luminance: u32,\n");
     types_content.push_str("    pub item_id: u32,\n");
+    types_content.push_str(
+        "    pub loot_table: Option<&'static crate::generated::loot_tables::LootTable>,\n",
+    );
     types_content.push_str("}\n\n");
 
     types_content.push_str("#[derive(Debug, Clone, Copy)]\n");
@@ -132,7 +137,11 @@ pub fn build() -> Result<(), Box<dyn std::error::Error>> {
         let file_name = format!("{}.rs", sanitized_name);
 
         let mut content = String::new();
-        content.push_str("use super::types::{Block, BlockState};\n\n");
+        content.push_str("use super::types::{Block, BlockState};\n");
+        if block.loot_table.is_some() {
+            content.push_str("use crate::generated::loot_tables::*;\n");
+        }
+        content.push('\n');
 
         // Block constant
         content.push_str(&format!(
@@ -170,6 +179,16 @@ pub fn build() -> Result<(), Box<dyn std::error::Error>> {
         let first_state = &block.states[0];
         content.push_str(&format!("    luminance: {},\n", first_state.luminance));
         content.push_str(&format!("    item_id: {},\n", block.item_id));
+        match &block.loot_table {
+            Some(loot_table) => {
+                let name = format!("minecraft:blocks/{}", sanitized_name);
+                content.push_str(&format!(
+                    "    loot_table: Some(&{}),\n",
+                    crate::loot_tables::table(&name, loot_table)
+                ));
+            }
+            None => content.push_str("    loot_table: None,\n"),
+        }
         content.push_str("};\n\n");
 
         // States
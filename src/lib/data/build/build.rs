@@ -11,6 +11,7 @@ mod enchantments;
 mod entities;
 mod fluids;
 mod items;
+mod loot_tables;
 mod particles;
 mod potions;
 mod recipes;
@@ -49,6 +50,7 @@ pub fn main() {
         (items::build, "items.rs"),
         (recipes::build, "recipes.rs"),
         (tags::build, "tags.rs"),
+        (loot_tables::build, "loot_tables.rs"),
     ];
 
     // Build other files normally
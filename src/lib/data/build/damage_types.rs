@@ -1,7 +1,9 @@
 use heck::ToPascalCase;
+use proc_macro2::Span;
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 use std::{collections::BTreeMap, fs};
+use syn::{LitFloat, LitInt};
 
 pub(crate) fn build() -> TokenStream {
     println!("cargo:rerun-if-changed=../../../assets/extracted/damage_type.json");
@@ -11,6 +13,13 @@ pub(crate) fn build() -> TokenStream {
     )
     .expect("Failed to parse damage_type.json");
 
+    println!("cargo:rerun-if-changed=../../../assets/extracted/tags.json");
+
+    let tags: BTreeMap<String, BTreeMap<String, Vec<String>>> =
+        serde_json::from_str(&fs::read_to_string("../../../assets/extracted/tags.json").unwrap())
+            .expect("Failed to parse tags.json");
+    let damage_type_tags = tags.get("damage_type").cloned().unwrap_or_default();
+
     let damage_type_names: Vec<String> = damage_types.keys().cloned().collect();
 
     let variants = crate::array_to_tokenstream(&damage_type_names);
@@ -39,6 +48,66 @@ pub(crate) fn build() -> TokenStream {
         })
         .collect::<TokenStream>();
 
+    let type_to_id = &damage_types
+        .iter()
+        .map(|(damage_type, data)| {
+            let name = format_ident!("{}", damage_type.to_pascal_case());
+            let id = LitInt::new(
+                &data["id"]
+                    .as_u64()
+                    .expect("Damage type without an ID")
+                    .to_string(),
+                Span::call_site(),
+            );
+
+            quote! {
+                Self::#name => #id,
+            }
+        })
+        .collect::<TokenStream>();
+
+    let type_to_exhaustion = &damage_types
+        .iter()
+        .map(|(damage_type, data)| {
+            let name = format_ident!("{}", damage_type.to_pascal_case());
+            let exhaustion = data["components"]["exhaustion"].as_f64().unwrap_or(0.0) as f32;
+            let exhaustion = LitFloat::new(&format!("{exhaustion:?}"), Span::call_site());
+
+            quote! {
+                Self::#name => #exhaustion,
+            }
+        })
+        .collect::<TokenStream>();
+
+    let type_to_message_id = &damage_types
+        .iter()
+        .map(|(damage_type, data)| {
+            let name = format_ident!("{}", damage_type.to_pascal_case());
+            let message_id = data["components"]["message_id"]
+                .as_str()
+                .expect("Damage type without a message ID");
+
+            quote! {
+                Self::#name => #message_id,
+            }
+        })
+        .collect::<TokenStream>();
+
+    let type_to_tags = &damage_type_names
+        .iter()
+        .map(|damage_type| {
+            let name = format_ident!("{}", damage_type.to_pascal_case());
+            let tags = damage_type_tags
+                .iter()
+                .filter(|(_, values)| values.contains(damage_type))
+                .map(|(tag, _)| tag.strip_prefix("minecraft:").unwrap_or(tag));
+
+            quote! {
+                Self::#name => &[#(#tags),*],
+            }
+        })
+        .collect::<TokenStream>();
+
     quote! {
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
         pub enum DamageType {
@@ -60,6 +129,40 @@ pub(crate) fn build() -> TokenStream {
                     #type_to_name
                 }
             }
+
+            #[doc = r" The protocol ID of the damage type."]
+            pub const fn id(&self) -> u16 {
+                match self {
+                    #type_to_id
+                }
+            }
+
+            #[doc = r" How much exhaustion a player gets from taking this type of damage."]
+            pub const fn exhaustion(&self) -> f32 {
+                match self {
+                    #type_to_exhaustion
+                }
+            }
+
+            #[doc = r" The ID of the death messages for this type, as in `death.attack.<id>`."]
+            pub const fn message_id(&self) -> &'static str {
+                match self {
+                    #type_to_message_id
+                }
+            }
+
+            #[doc = r" The damage type tags this type is in, like `bypasses_armor`."]
+            pub const fn tags(&self) -> &'static [&'static str] {
+                match self {
+                    #type_to_tags
+                }
+            }
+
+            #[doc = r" Whether this type is in a damage type tag."]
+            pub fn has_tag(&self, tag: &str) -> bool {
+                let tag = tag.strip_prefix("minecraft:").unwrap_or(tag);
+                self.tags().contains(&tag)
+            }
         }
     }
 }
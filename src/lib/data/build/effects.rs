@@ -1,12 +1,31 @@
-use heck::ToPascalCase;
-use proc_macro2::TokenStream;
+use heck::{ToPascalCase, ToShoutySnakeCase};
+use proc_macro2::{Span, TokenStream};
 use quote::{format_ident, quote};
+use serde::Deserialize;
 use std::{collections::BTreeMap, fs};
+use syn::{LitFloat, LitInt};
+
+#[derive(Deserialize, Clone, Debug)]
+struct Effect {
+    id: u8,
+    category: String,
+    color: i32,
+    attribute_modifiers: Vec<Modifier>,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+struct Modifier {
+    attribute: String,
+    operation: String,
+    id: String,
+    #[serde(rename = "baseValue")]
+    base_value: f64,
+}
 
 pub(crate) fn build() -> TokenStream {
     println!("cargo:rerun-if-changed=../../../assets/extracted/effect.json");
 
-    let effects: BTreeMap<String, serde_json::Value> =
+    let effects: BTreeMap<String, Effect> =
         serde_json::from_str(&fs::read_to_string("../../../assets/extracted/effect.json").unwrap())
             .expect("Failed to parse effect.json");
 
@@ -38,12 +57,74 @@ pub(crate) fn build() -> TokenStream {
         })
         .collect::<TokenStream>();
 
+    let mut type_to_id = TokenStream::new();
+    let mut type_from_id = TokenStream::new();
+    let mut type_to_category = TokenStream::new();
+    let mut type_to_color = TokenStream::new();
+    let mut type_to_modifiers = TokenStream::new();
+    let mut all = TokenStream::new();
+
+    for (name, effect) in &effects {
+        let variant = format_ident!("{}", name.to_pascal_case());
+        let id = LitInt::new(&effect.id.to_string(), Span::call_site());
+        let category = format_ident!("{}", effect.category.to_pascal_case());
+        let color = LitInt::new(&effect.color.to_string(), Span::call_site());
+
+        let modifiers = effect
+            .attribute_modifiers
+            .iter()
+            .map(|modifier| {
+                let attribute = format_ident!("{}", modifier.attribute.to_shouty_snake_case());
+                let operation = format_ident!("{}", modifier.operation.to_pascal_case());
+                let modifier_id = &modifier.id;
+                let base_value =
+                    LitFloat::new(&format!("{:?}", modifier.base_value), Span::call_site());
+                quote! {
+                    EffectModifier {
+                        attribute: &Attribute::#attribute,
+                        id: #modifier_id,
+                        base_value: #base_value,
+                        operation: Operation::#operation,
+                    },
+                }
+            })
+            .collect::<TokenStream>();
+
+        type_to_id.extend(quote! { Self::#variant => #id, });
+        type_from_id.extend(quote! { #id => Some(Self::#variant), });
+        type_to_category.extend(quote! { Self::#variant => EffectCategory::#category, });
+        type_to_color.extend(quote! { Self::#variant => #color, });
+        type_to_modifiers.extend(quote! { Self::#variant => &[#modifiers], });
+        all.extend(quote! { Self::#variant, });
+    }
+
     quote! {
+        use crate::attributes::Attribute;
+        use crate::items::Operation;
+
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
         pub enum Effect {
             #variants
         }
 
+        /// Whether an effect is good or bad for whoever has it.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum EffectCategory {
+            Beneficial,
+            Harmful,
+            Neutral,
+        }
+
+        /// An attribute modifier applied while an effect is active. The amount is `base_value`
+        /// for every level of the effect.
+        #[derive(Debug, Clone)]
+        pub struct EffectModifier {
+            pub attribute: &'static Attribute,
+            pub id: &'static str,
+            pub base_value: f64,
+            pub operation: Operation,
+        }
+
         impl Effect {
             #[doc = r" Try to parse an `Effect` from a resource location string."]
             pub fn from_name(name: &str) -> Option<Self> {
@@ -59,6 +140,46 @@ pub(crate) fn build() -> TokenStream {
                     #type_to_name
                 }
             }
+
+            #[doc = r" The protocol ID of the effect."]
+            pub const fn id(&self) -> u8 {
+                match self {
+                    #type_to_id
+                }
+            }
+
+            #[doc = r" Try to get an `Effect` from its protocol ID."]
+            pub const fn from_id(id: u8) -> Option<Self> {
+                match id {
+                    #type_from_id
+                    _ => None
+                }
+            }
+
+            pub const fn category(&self) -> EffectCategory {
+                match self {
+                    #type_to_category
+                }
+            }
+
+            #[doc = r" The colour of the effect's particles, as RGB."]
+            pub const fn color(&self) -> i32 {
+                match self {
+                    #type_to_color
+                }
+            }
+
+            #[doc = r" The attribute modifiers applied while the effect is active."]
+            pub const fn attribute_modifiers(&self) -> &'static [EffectModifier] {
+                match self {
+                    #type_to_modifiers
+                }
+            }
+
+            #[doc = r" Get all effects as a slice."]
+            pub fn all() -> &'static [Self] {
+                &[#all]
+            }
         }
     }
 }
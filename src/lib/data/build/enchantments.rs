@@ -1,4 +1,4 @@
-use heck::ToShoutySnakeCase;
+use heck::{ToPascalCase, ToShoutySnakeCase};
 use proc_macro2::{Span, TokenStream};
 use quote::{format_ident, quote};
 use serde::Deserialize;
@@ -16,6 +16,8 @@ pub struct Enchantment {
     pub anvil_cost: u8,
     pub slots: Vec<String>,
     pub supported_items: String,
+    #[serde(default)]
+    pub primary_items: Option<String>,
     pub weight: u8,
     pub max_level: u8,
     #[serde(default)]
@@ -35,6 +37,181 @@ pub struct Cost {
     pub per_level_above_first: f32,
 }
 
+#[derive(Deserialize, Clone, Debug)]
+pub struct AttributeEffect {
+    pub id: String,
+    pub attribute: String,
+    pub amount: LevelBasedValue,
+    pub operation: String,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(tag = "type")]
+pub enum LevelBasedValue {
+    #[serde(rename = "minecraft:linear")]
+    Linear {
+        base: f32,
+        per_level_above_first: f32,
+    },
+    #[serde(rename = "minecraft:levels_squared")]
+    LevelsSquared { added: f32 },
+    #[serde(rename = "minecraft:fraction")]
+    Fraction {
+        numerator: Box<LevelBasedValue>,
+        denominator: Box<LevelBasedValue>,
+    },
+}
+
+impl LevelBasedValue {
+    fn to_tokens(&self) -> TokenStream {
+        match self {
+            Self::Linear {
+                base,
+                per_level_above_first,
+            } => {
+                let base = LitFloat::new(&format!("{base:?}"), Span::call_site());
+                let per_level =
+                    LitFloat::new(&format!("{per_level_above_first:?}"), Span::call_site());
+                quote! {
+                    LevelBasedValue::Linear {
+                        base: #base,
+                        per_level_above_first: #per_level,
+                    }
+                }
+            }
+            Self::LevelsSquared { added } => {
+                let added = LitFloat::new(&format!("{added:?}"), Span::call_site());
+                quote! { LevelBasedValue::LevelsSquared { added: #added } }
+            }
+            Self::Fraction {
+                numerator,
+                denominator,
+            } => {
+                let numerator = numerator.to_tokens();
+                let denominator = denominator.to_tokens();
+                quote! {
+                    LevelBasedValue::Fraction {
+                        numerator: &#numerator,
+                        denominator: &#denominator,
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The attribute modifiers an enchantment gives while its item is equipped.
+fn attribute_effects(enchantment: &Enchantment) -> Vec<TokenStream> {
+    let Some(effects) = enchantment.effects.get("minecraft:attributes") else {
+        return Vec::new();
+    };
+    let effects: Vec<AttributeEffect> =
+        serde_json::from_value(effects.clone()).expect("Failed to parse attribute effects");
+
+    effects
+        .iter()
+        .map(|effect| {
+            let id = &effect.id;
+            let attribute = format_ident!(
+                "{}",
+                effect
+                    .attribute
+                    .strip_prefix("minecraft:")
+                    .unwrap_or(&effect.attribute)
+                    .to_shouty_snake_case()
+            );
+            let amount = effect.amount.to_tokens();
+            let operation = format_ident!("{}", effect.operation.to_pascal_case());
+            quote! {
+                AttributeEffect {
+                    id: #id,
+                    attribute: &Attribute::#attribute,
+                    amount: #amount,
+                    operation: Operation::#operation,
+                }
+            }
+        })
+        .collect()
+}
+
+/// The protection an enchantment gives, and the damage type tags the damage has to be in (or
+/// not be in) for it to apply.
+fn damage_protection(enchantment: &Enchantment) -> Vec<TokenStream> {
+    let Some(effects) = enchantment.effects.get("minecraft:damage_protection") else {
+        return Vec::new();
+    };
+
+    effects
+        .as_array()
+        .expect("Failed to parse damage protection effects")
+        .iter()
+        .map(|effect| {
+            let amount: LevelBasedValue = serde_json::from_value(effect["effect"]["value"].clone())
+                .expect("Failed to parse damage protection amount");
+            let amount = amount.to_tokens();
+
+            // The requirements are either a single predicate or all of a list of them
+            let requirements = &effect["requirements"];
+            let predicates = match requirements["terms"].as_array() {
+                Some(terms) => terms.iter().collect(),
+                None => vec![requirements],
+            };
+            let tags = predicates
+                .iter()
+                .filter_map(|predicate| predicate["predicate"]["tags"].as_array())
+                .flatten()
+                .map(|tag| {
+                    let id = tag["id"].as_str().expect("Damage tag without an ID");
+                    let id = id.strip_prefix("minecraft:").unwrap_or(id);
+                    let expected = tag["expected"].as_bool().unwrap_or(true);
+                    quote! { (#id, #expected) }
+                });
+
+            quote! {
+                DamageProtection {
+                    amount: #amount,
+                    tags: &[#(#tags),*],
+                }
+            }
+        })
+        .collect()
+}
+
+/// The extra attack damage an enchantment gives, and the entity type tag the target (or the
+/// entity that directly dealt the damage) has to be in for it to apply.
+fn damage(enchantment: &Enchantment) -> Vec<TokenStream> {
+    let Some(effects) = enchantment.effects.get("minecraft:damage") else {
+        return Vec::new();
+    };
+
+    effects
+        .as_array()
+        .expect("Failed to parse damage effects")
+        .iter()
+        .map(|effect| {
+            let amount: LevelBasedValue = serde_json::from_value(effect["effect"]["value"].clone())
+                .expect("Failed to parse damage amount");
+            let amount = amount.to_tokens();
+
+            let requirements = &effect["requirements"];
+            let requirement = match requirements["predicate"]["type"].as_str() {
+                Some(tag) => {
+                    let entity = requirements["entity"].as_str().unwrap_or("this");
+                    quote! { Some((#entity, #tag)) }
+                }
+                None => quote! { None },
+            };
+
+            quote! {
+                DamageBonus {
+                    amount: #amount,
+                    requirement: #requirement,
+                }
+            }
+        })
+        .collect()
+}
+
 pub(crate) fn build() -> TokenStream {
     println!("cargo:rerun-if-changed=../../../assets/extracted/enchantments.json");
 
@@ -78,6 +255,10 @@ pub(crate) fn build() -> TokenStream {
         let max_level = LitInt::new(&enchantment.max_level.to_string(), Span::call_site());
 
         let supported_items = &enchantment.supported_items;
+        let primary_items = match &enchantment.primary_items {
+            Some(items) => quote! { Some(#items) },
+            None => quote! { None },
+        };
 
         let slots = enchantment
             .slots
@@ -88,6 +269,10 @@ pub(crate) fn build() -> TokenStream {
             })
             .collect::<Vec<_>>();
 
+        let attribute_effects = attribute_effects(enchantment);
+        let damage_protection = damage_protection(enchantment);
+        let damage = damage(enchantment);
+
         let exclusive_set = match &enchantment.exclusive_set {
             Some(set) => {
                 quote! { Some(#set) }
@@ -111,9 +296,13 @@ pub(crate) fn build() -> TokenStream {
                 anvil_cost: #anvil_cost,
                 slots: &[#(#slots),*],
                 supported_items: #supported_items,
+                primary_items: #primary_items,
                 weight: #weight,
                 max_level: #max_level,
                 exclusive_set: #exclusive_set,
+                attribute_effects: &[#(#attribute_effects),*],
+                damage_protection: &[#(#damage_protection),*],
+                damage: &[#(#damage),*],
             };
         });
 
@@ -127,6 +316,9 @@ pub(crate) fn build() -> TokenStream {
     }
 
     quote! {
+        use crate::attributes::Attribute;
+        use crate::items::Operation;
+
         #[derive(Debug, Clone, Copy, PartialEq)]
         pub struct Enchantment {
             pub id: u16,
@@ -137,9 +329,15 @@ pub(crate) fn build() -> TokenStream {
             pub anvil_cost: u8,
             pub slots: &'static [EnchantmentSlot],
             pub supported_items: &'static str,
+            /// The items the enchantment is offered for by enchanting tables, if they're fewer
+            /// than the supported items.
+            pub primary_items: Option<&'static str>,
             pub weight: u8,
             pub max_level: u8,
             pub exclusive_set: Option<&'static str>,
+            pub attribute_effects: &'static [AttributeEffect],
+            pub damage_protection: &'static [DamageProtection],
+            pub damage: &'static [DamageBonus],
         }
 
         #[derive(Debug, Clone, Copy, PartialEq)]
@@ -148,6 +346,67 @@ pub(crate) fn build() -> TokenStream {
             pub per_level_above_first: f32,
         }
 
+        /// An attribute modifier given by an enchantment, with an amount depending on its level.
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub struct AttributeEffect {
+            pub id: &'static str,
+            pub attribute: &'static Attribute,
+            pub amount: LevelBasedValue,
+            pub operation: Operation,
+        }
+
+        /// Protection given by an enchantment against damage, with an amount depending on its
+        /// level. It only applies to damage types that are in (or not in) each of the tags.
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub struct DamageProtection {
+            pub amount: LevelBasedValue,
+            pub tags: &'static [(&'static str, bool)],
+        }
+
+        /// Extra attack damage given by an enchantment, with an amount depending on its level. If
+        /// there's a requirement, it's the entity (`this` for the target, or `direct_attacker`)
+        /// and the entity type tag it has to be in.
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub struct DamageBonus {
+            pub amount: LevelBasedValue,
+            pub requirement: Option<(&'static str, &'static str)>,
+        }
+
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub enum LevelBasedValue {
+            Linear {
+                base: f32,
+                per_level_above_first: f32,
+            },
+            LevelsSquared {
+                added: f32,
+            },
+            Fraction {
+                numerator: &'static LevelBasedValue,
+                denominator: &'static LevelBasedValue,
+            },
+        }
+
+        impl LevelBasedValue {
+            #[doc = r" Calculate the value for the given enchantment level."]
+            pub fn calculate(&self, level: u8) -> f32 {
+                match self {
+                    Self::Linear { base, per_level_above_first } => {
+                        base + per_level_above_first * (level as f32 - 1.0)
+                    }
+                    Self::LevelsSquared { added } => (level as f32).powi(2) + added,
+                    Self::Fraction { numerator, denominator } => {
+                        let denominator = denominator.calculate(level);
+                        if denominator == 0.0 {
+                            0.0
+                        } else {
+                            numerator.calculate(level) / denominator
+                        }
+                    }
+                }
+            }
+        }
+
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
         pub enum EnchantmentSlot {
             MAINHAND,
@@ -167,7 +426,7 @@ pub(crate) fn build() -> TokenStream {
             #[doc = r" Try to parse an `Enchantment` from a resource location string."]
             pub fn from_name(name: &str) -> Option<&'static Self> {
                 let name = name.strip_prefix("minecraft:").unwrap_or(name);
-                match name {
+                match format!("minecraft:{name}").as_str() {
                     #type_from_name
                     _ => None
                 }
@@ -1,4 +1,4 @@
-use heck::ToShoutySnakeCase;
+use heck::{ToPascalCase, ToShoutySnakeCase};
 use proc_macro2::{Span, TokenStream};
 use quote::{format_ident, quote, ToTokens};
 use serde::Deserialize;
@@ -41,6 +41,10 @@ pub struct ItemComponents {
     pub blocks_attacks: Option<BlocksAttacks>,
     #[serde(rename = "minecraft:death_protection")]
     pub death_protection: Option<DeathProtection>,
+    #[serde(rename = "minecraft:enchantable")]
+    pub enchantable: Option<Enchantable>,
+    #[serde(rename = "minecraft:repairable")]
+    pub repairable: Option<Repairable>,
 }
 
 #[derive(Deserialize, Clone)]
@@ -184,6 +188,20 @@ impl ToTokens for ItemComponents {
             tokens.extend(quote! { (DataComponent::DeathProtection, &DeathProtectionImpl), });
         };
 
+        if let Some(enchantable) = &self.enchantable {
+            let value = LitInt::new(&enchantable.value.to_string(), Span::call_site());
+            tokens.extend(quote! { (DataComponent::Enchantable, &EnchantableImpl {
+                value: #value,
+            }), });
+        };
+
+        if let Some(repairable) = &self.repairable {
+            let items = LitStr::new(&repairable.items, Span::call_site());
+            tokens.extend(quote! { (DataComponent::Repairable, &RepairableImpl {
+                items: #items,
+            }), });
+        };
+
         if let Some(equippable) = &self.equippable {
             let slot = match equippable.slot.as_str() {
                 "mainhand" => quote! { &EquipmentSlot::MAIN_HAND },
@@ -345,6 +363,16 @@ pub struct BlocksAttacks {
     // TODO
 }
 
+#[derive(Deserialize, Clone, Debug)]
+pub struct Enchantable {
+    pub value: u8,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct Repairable {
+    pub items: String,
+}
+
 #[derive(Deserialize, Clone, Debug)]
 pub struct EquippableComponent {
     pub slot: String,
@@ -405,6 +433,39 @@ pub(crate) fn build() -> TokenStream {
         serde_json::from_str(&fs::read_to_string("../../../assets/extracted/items.json").unwrap())
             .expect("Failed to parse items.json");
 
+    println!("cargo:rerun-if-changed=../../../assets/extracted/data_component.json");
+
+    let data_components: BTreeMap<String, i32> = serde_json::from_str(
+        &fs::read_to_string("../../../assets/extracted/data_component.json").unwrap(),
+    )
+    .expect("Failed to parse data_component.json");
+    let mut data_components: Vec<(String, i32)> = data_components.into_iter().collect();
+    data_components.sort_by_key(|(_, id)| *id);
+
+    let mut component_variants = TokenStream::new();
+    let mut component_from_id_arms = TokenStream::new();
+    let mut component_to_name_arms = TokenStream::new();
+
+    for (name, id) in &data_components {
+        let variant = format_ident!(
+            "{}",
+            name.strip_prefix("minecraft:")
+                .unwrap_or(name)
+                .to_pascal_case()
+        );
+        let id_lit = LitInt::new(&id.to_string(), Span::call_site());
+
+        component_variants.extend(quote! {
+            #variant = #id_lit,
+        });
+        component_from_id_arms.extend(quote! {
+            #id_lit => Some(Self::#variant),
+        });
+        component_to_name_arms.extend(quote! {
+            Self::#variant => #name,
+        });
+    }
+
     let mut type_from_raw_id_arms = TokenStream::new();
     let mut type_from_name = TokenStream::new();
 
@@ -477,6 +538,74 @@ pub(crate) fn build() -> TokenStream {
                     .to_string()
             }
 
+            #[doc = "How many of this item fit in a single stack."]
+            pub fn max_stack_size(&self) -> u8 {
+                self.components
+                    .iter()
+                    .find_map(|(id, data)| if id == &DataComponent::MaxStackSize {
+                        Some(data.as_any().downcast_ref::<MaxStackSizeImpl>().unwrap().size)
+                    } else {
+                        None
+                    })
+                    .unwrap_or(64)
+            }
+
+            #[doc = "How much damage this item can take before it breaks, if it can be damaged."]
+            pub fn max_damage(&self) -> Option<u16> {
+                self.components
+                    .iter()
+                    .find_map(|(id, data)| if id == &DataComponent::MaxDamage {
+                        Some(data.as_any().downcast_ref::<MaxDamageImpl>().unwrap().max_damage)
+                    } else {
+                        None
+                    })
+            }
+
+            #[doc = "The attribute modifiers the item gives while it's equipped."]
+            pub fn attribute_modifiers(&self) -> &'static [Modifier] {
+                self.components
+                    .iter()
+                    .find_map(|(id, data)| if id == &DataComponent::AttributeModifiers {
+                        Some(data.as_any().downcast_ref::<AttributeModifiersImpl>().unwrap().attribute_modifiers)
+                    } else {
+                        None
+                    })
+                    .unwrap_or(&[])
+            }
+
+            #[doc = "The mining rules of the item, if it's a tool."]
+            pub fn tool(&self) -> Option<&'static ToolImpl> {
+                self.components
+                    .iter()
+                    .find_map(|(id, data)| if id == &DataComponent::Tool {
+                        data.as_any().downcast_ref::<ToolImpl>()
+                    } else {
+                        None
+                    })
+            }
+
+            #[doc = "How good the enchantments the item gets from an enchanting table are, if it can be enchanted there."]
+            pub fn enchantability(&self) -> Option<u8> {
+                self.components
+                    .iter()
+                    .find_map(|(id, data)| if id == &DataComponent::Enchantable {
+                        Some(data.as_any().downcast_ref::<EnchantableImpl>().unwrap().value)
+                    } else {
+                        None
+                    })
+            }
+
+            #[doc = "The item or item tag that repairs the item in an anvil, if it can be repaired."]
+            pub fn repair_items(&self) -> Option<&'static str> {
+                self.components
+                    .iter()
+                    .find_map(|(id, data)| if id == &DataComponent::Repairable {
+                        Some(data.as_any().downcast_ref::<RepairableImpl>().unwrap().items)
+                    } else {
+                        None
+                    })
+            }
+
             #[doc = "Try to parse an item from a resource location string."]
             pub fn from_registry_key(name: &str) -> Option<&'static Self> {
                 let name = name.strip_prefix("minecraft:").unwrap_or(name);
@@ -496,20 +625,32 @@ pub(crate) fn build() -> TokenStream {
         }
 
         // Data component system
+        #[doc = "The data components from the `minecraft:data_component_type` registry, with their protocol IDs."]
         #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
         pub enum DataComponent {
-            MaxStackSize,
-            JukeboxPlayable,
-            ItemName,
-            Damage,
-            MaxDamage,
-            AttributeModifiers,
-            Tool,
-            Food,
-            Equippable,
-            Consumable,
-            BlocksAttacks,
-            DeathProtection,
+            #component_variants
+        }
+
+        impl DataComponent {
+            #[doc = "The protocol ID of the component."]
+            pub const fn id(self) -> i32 {
+                self as i32
+            }
+
+            #[doc = "Try to get a component from its protocol ID."]
+            pub const fn from_id(id: i32) -> Option<Self> {
+                match id {
+                    #component_from_id_arms
+                    _ => None
+                }
+            }
+
+            #[doc = "The resource location of the component, e.g. `minecraft:damage`."]
+            pub const fn name(self) -> &'static str {
+                match self {
+                    #component_to_name_arms
+                }
+            }
         }
 
         pub trait DataComponentImpl {
@@ -659,6 +800,28 @@ pub(crate) fn build() -> TokenStream {
             }
         }
 
+        #[derive(Clone, Debug)]
+        pub struct EnchantableImpl {
+            pub value: u8,
+        }
+
+        impl DataComponentImpl for EnchantableImpl {
+            fn as_any(&self) -> &dyn std::any::Any {
+                self
+            }
+        }
+
+        #[derive(Clone, Debug)]
+        pub struct RepairableImpl {
+            pub items: &'static str,
+        }
+
+        impl DataComponentImpl for RepairableImpl {
+            fn as_any(&self) -> &dyn std::any::Any {
+                self
+            }
+        }
+
         #[derive(Clone, Debug)]
         pub struct Modifier {
             pub r#type: &'static crate::attributes::Attribute,
@@ -675,7 +838,40 @@ pub(crate) fn build() -> TokenStream {
             pub correct_for_drops: Option<bool>,
         }
 
-        #[derive(Clone, Debug)]
+        impl ToolRule {
+            #[doc = "Whether the rule applies to a block, by its registry name. The blocks are a block, a block tag or a JSON list of blocks."]
+            pub fn applies_to(&self, block: &str) -> bool {
+                self.blocks
+                    .trim_matches(|c| c == '[' || c == ']')
+                    .split(',')
+                    .map(|entry| entry.trim_matches('"'))
+                    .any(|entry| match entry.strip_prefix('#') {
+                        Some(tag) => crate::tags::TagData::get_block_tag(tag)
+                            .is_some_and(|tag| tag.values.contains(&block)),
+                        None => entry == block,
+                    })
+            }
+        }
+
+        impl ToolImpl {
+            #[doc = "How fast the tool mines a block, from the first rule for it with a speed."]
+            pub fn mining_speed(&self, block: &str) -> f32 {
+                self.rules
+                    .iter()
+                    .find_map(|rule| rule.speed.filter(|_| rule.applies_to(block)))
+                    .unwrap_or(self.default_mining_speed)
+            }
+
+            #[doc = "Whether the tool is the right one to get a block's drops, from the first rule for it that says."]
+            pub fn is_correct_for_drops(&self, block: &str) -> bool {
+                self.rules
+                    .iter()
+                    .find_map(|rule| rule.correct_for_drops.filter(|_| rule.applies_to(block)))
+                    .unwrap_or(false)
+            }
+        }
+
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
         pub enum Operation {
             AddValue,
             AddMultipliedBase,
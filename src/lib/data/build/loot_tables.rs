@@ -0,0 +1,709 @@
+use heck::ToShoutySnakeCase;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fs;
+
+fn as_f32(value: &Value) -> Option<f32> {
+    match value {
+        Value::Number(number) => number.as_f64().map(|n| n as f32),
+        Value::Object(object)
+            if object.get("type").and_then(Value::as_str) == Some("minecraft:constant") =>
+        {
+            object.get("value").and_then(as_f32)
+        }
+        _ => None,
+    }
+}
+
+fn as_i32(value: &Value) -> Option<i32> {
+    as_f32(value).map(|n| n as i32)
+}
+
+fn string_field<'a>(value: &'a Value, key: &str) -> &'a str {
+    value.get(key).and_then(Value::as_str).unwrap_or_default()
+}
+
+/// Number providers are either a plain number or an object with a `type`.
+fn number_provider(value: &Value) -> TokenStream {
+    if let Some(constant) = as_f32(value) {
+        return quote! { NumberProvider::Constant(#constant) };
+    }
+    match string_field(value, "type") {
+        "minecraft:uniform" => {
+            let min = value.get("min").and_then(as_f32).unwrap_or(0.0);
+            let max = value.get("max").and_then(as_f32).unwrap_or(min);
+            quote! { NumberProvider::Uniform { min: #min, max: #max } }
+        }
+        "minecraft:binomial" => {
+            let n = value.get("n").and_then(as_i32).unwrap_or(0);
+            let p = value.get("p").and_then(as_f32).unwrap_or(0.0);
+            quote! { NumberProvider::Binomial { n: #n, p: #p } }
+        }
+        other => panic!("Unsupported number provider in loot table: {other}"),
+    }
+}
+
+fn conditions(value: &Value) -> TokenStream {
+    let conditions = value
+        .get("conditions")
+        .and_then(Value::as_array)
+        .map(|conditions| conditions.iter().map(condition).collect::<Vec<_>>())
+        .unwrap_or_default();
+    quote! { &[#(#conditions),*] }
+}
+
+fn condition(value: &Value) -> TokenStream {
+    let kind = string_field(value, "condition");
+    let unsupported = || quote! { LootCondition::Unsupported(#kind) };
+    match kind {
+        "minecraft:survives_explosion" => quote! { LootCondition::SurvivesExplosion },
+        "minecraft:killed_by_player" => quote! { LootCondition::KilledByPlayer },
+        "minecraft:random_chance" => match value.get("chance").and_then(as_f32) {
+            Some(chance) => quote! { LootCondition::RandomChance(#chance) },
+            None => unsupported(),
+        },
+        "minecraft:random_chance_with_enchanted_bonus" => {
+            let enchantment = string_field(value, "enchantment");
+            let unenchanted_chance = value
+                .get("unenchanted_chance")
+                .and_then(as_f32)
+                .unwrap_or(0.0);
+            let enchanted = value.get("enchanted_chance").unwrap_or(&Value::Null);
+            let (base, per_level_above_first) = match as_f32(enchanted) {
+                Some(chance) => (chance, 0.0),
+                None => (
+                    enchanted.get("base").and_then(as_f32).unwrap_or(0.0),
+                    enchanted
+                        .get("per_level_above_first")
+                        .and_then(as_f32)
+                        .unwrap_or(0.0),
+                ),
+            };
+            quote! {
+                LootCondition::RandomChanceWithEnchantedBonus {
+                    enchantment: #enchantment,
+                    unenchanted_chance: #unenchanted_chance,
+                    base: #base,
+                    per_level_above_first: #per_level_above_first,
+                }
+            }
+        }
+        "minecraft:table_bonus" => {
+            let enchantment = string_field(value, "enchantment");
+            let chances = value
+                .get("chances")
+                .and_then(Value::as_array)
+                .map(|chances| chances.iter().filter_map(as_f32).collect::<Vec<_>>())
+                .unwrap_or_default();
+            quote! { LootCondition::TableBonus { enchantment: #enchantment, chances: &[#(#chances),*] } }
+        }
+        "minecraft:match_tool" => match tool_predicate(value.get("predicate")) {
+            Some(predicate) => quote! { LootCondition::MatchTool(#predicate) },
+            None => unsupported(),
+        },
+        "minecraft:block_state_property" => {
+            let block = string_field(value, "block");
+            let mut properties = Vec::new();
+            for (name, expected) in value
+                .get("properties")
+                .and_then(Value::as_object)
+                .into_iter()
+                .flatten()
+            {
+                let expected = match expected {
+                    Value::String(expected) => expected.clone(),
+                    Value::Bool(_) | Value::Number(_) => expected.to_string(),
+                    _ => return unsupported(),
+                };
+                properties.push(quote! { (#name, #expected) });
+            }
+            quote! { LootCondition::BlockStateProperty { block: #block, properties: &[#(#properties),*] } }
+        }
+        "minecraft:entity_properties" => {
+            let entity = match string_field(value, "entity") {
+                "this" => quote! { LootEntityTarget::This },
+                "attacker" => quote! { LootEntityTarget::Attacker },
+                "direct_attacker" => quote! { LootEntityTarget::DirectAttacker },
+                _ => return unsupported(),
+            };
+            match entity_predicate(value.get("predicate")) {
+                Some(predicate) => {
+                    quote! { LootCondition::EntityProperties { entity: #entity, predicate: #predicate } }
+                }
+                None => unsupported(),
+            }
+        }
+        "minecraft:damage_source_properties" => {
+            match damage_source_predicate(value.get("predicate")) {
+                Some(predicate) => quote! { LootCondition::DamageSourceProperties(#predicate) },
+                None => unsupported(),
+            }
+        }
+        "minecraft:inverted" => {
+            let term = condition(value.get("term").unwrap_or(&Value::Null));
+            quote! { LootCondition::Inverted(&#term) }
+        }
+        "minecraft:any_of" | "minecraft:all_of" => {
+            let terms = value
+                .get("terms")
+                .and_then(Value::as_array)
+                .map(|terms| terms.iter().map(condition).collect::<Vec<_>>())
+                .unwrap_or_default();
+            if kind == "minecraft:any_of" {
+                quote! { LootCondition::AnyOf(&[#(#terms),*]) }
+            } else {
+                quote! { LootCondition::AllOf(&[#(#terms),*]) }
+            }
+        }
+        _ => unsupported(),
+    }
+}
+
+/// Only item lists and enchantment levels are understood. Anything else in the predicate makes
+/// the whole condition unsupported, rather than silently matching more tools than it should.
+fn tool_predicate(value: Option<&Value>) -> Option<TokenStream> {
+    let predicate = value?.as_object()?;
+    let mut items = Vec::new();
+    let mut enchantments = Vec::new();
+    for (key, value) in predicate {
+        match key.as_str() {
+            "items" => match value {
+                Value::String(item) => items.push(item.clone()),
+                Value::Array(list) => {
+                    items.extend(list.iter().filter_map(Value::as_str).map(str::to_string))
+                }
+                _ => return None,
+            },
+            "predicates" => {
+                for (component, value) in value.as_object()? {
+                    if component != "minecraft:enchantments" {
+                        return None;
+                    }
+                    for enchantment in value.as_array()? {
+                        let name = enchantment.get("enchantments")?.as_str()?;
+                        let min_level = enchantment
+                            .get("levels")
+                            .and_then(|levels| {
+                                levels.get("min").and_then(as_i32).or(as_i32(levels))
+                            })
+                            .unwrap_or(1) as u32;
+                        enchantments.push(quote! { (#name, #min_level) });
+                    }
+                }
+            }
+            _ => return None,
+        }
+    }
+    Some(quote! {
+        ToolPredicate {
+            items: &[#(#items),*],
+            enchantments: &[#(#enchantments),*],
+        }
+    })
+}
+
+/// Only entity types and the on fire and baby flags are understood. Like tool predicates, anything
+/// else makes the whole condition unsupported.
+fn entity_predicate(value: Option<&Value>) -> Option<TokenStream> {
+    let predicate = value?.as_object()?;
+    let mut types = Vec::new();
+    let mut on_fire = quote! { None };
+    let mut baby = quote! { None };
+    for (key, value) in predicate {
+        match key.as_str() {
+            "type" => match value {
+                Value::String(kind) => types.push(kind.clone()),
+                Value::Array(list) => {
+                    types.extend(list.iter().filter_map(Value::as_str).map(str::to_string))
+                }
+                _ => return None,
+            },
+            "flags" => {
+                for (flag, expected) in value.as_object()? {
+                    let expected = expected.as_bool()?;
+                    match flag.as_str() {
+                        "is_on_fire" => on_fire = quote! { Some(#expected) },
+                        "is_baby" => baby = quote! { Some(#expected) },
+                        _ => return None,
+                    }
+                }
+            }
+            _ => return None,
+        }
+    }
+    Some(quote! {
+        EntityPredicate {
+            types: &[#(#types),*],
+            on_fire: #on_fire,
+            baby: #baby,
+        }
+    })
+}
+
+/// Damage type tags and the entities behind the damage, which are entity predicates.
+fn damage_source_predicate(value: Option<&Value>) -> Option<TokenStream> {
+    let predicate = value?.as_object()?;
+    let mut tags = Vec::new();
+    let mut direct_entity = quote! { None };
+    let mut source_entity = quote! { None };
+    for (key, value) in predicate {
+        match key.as_str() {
+            "tags" => {
+                for tag in value.as_array()? {
+                    let id = tag.get("id")?.as_str()?;
+                    let expected = tag.get("expected")?.as_bool()?;
+                    tags.push(quote! { (#id, #expected) });
+                }
+            }
+            "direct_entity" => {
+                let entity = entity_predicate(Some(value))?;
+                direct_entity = quote! { Some(&#entity) };
+            }
+            "source_entity" => {
+                let entity = entity_predicate(Some(value))?;
+                source_entity = quote! { Some(&#entity) };
+            }
+            _ => return None,
+        }
+    }
+    Some(quote! {
+        DamageSourcePredicate {
+            tags: &[#(#tags),*],
+            direct_entity: #direct_entity,
+            source_entity: #source_entity,
+        }
+    })
+}
+
+fn functions(value: &Value) -> TokenStream {
+    let functions = value
+        .get("functions")
+        .and_then(Value::as_array)
+        .map(|functions| functions.iter().map(function).collect::<Vec<_>>())
+        .unwrap_or_default();
+    quote! { &[#(#functions),*] }
+}
+
+fn function(value: &Value) -> TokenStream {
+    let name = string_field(value, "function");
+    let kind = match name {
+        "minecraft:set_count" => {
+            let count = number_provider(value.get("count").unwrap_or(&Value::Null));
+            let add = value.get("add").and_then(Value::as_bool).unwrap_or(false);
+            quote! { LootFunctionKind::SetCount { count: #count, add: #add } }
+        }
+        "minecraft:apply_bonus" => {
+            let enchantment = string_field(value, "enchantment");
+            let parameters = value.get("parameters").unwrap_or(&Value::Null);
+            let formula = match string_field(value, "formula") {
+                "minecraft:ore_drops" => quote! { BonusFormula::OreDrops },
+                "minecraft:uniform_bonus_count" => {
+                    let bonus_multiplier = parameters
+                        .get("bonusMultiplier")
+                        .and_then(as_i32)
+                        .unwrap_or(1);
+                    quote! { BonusFormula::UniformBonusCount { bonus_multiplier: #bonus_multiplier } }
+                }
+                "minecraft:binomial_with_bonus_count" => {
+                    let extra = parameters.get("extra").and_then(as_i32).unwrap_or(0);
+                    let probability = parameters
+                        .get("probability")
+                        .and_then(as_f32)
+                        .unwrap_or(0.0);
+                    quote! { BonusFormula::BinomialWithBonusCount { extra: #extra, probability: #probability } }
+                }
+                other => panic!("Unsupported apply_bonus formula in loot table: {other}"),
+            };
+            quote! { LootFunctionKind::ApplyBonus { enchantment: #enchantment, formula: #formula } }
+        }
+        "minecraft:explosion_decay" => quote! { LootFunctionKind::ExplosionDecay },
+        "minecraft:enchanted_count_increase" => {
+            let enchantment = string_field(value, "enchantment");
+            let count = number_provider(value.get("count").unwrap_or(&Value::Null));
+            let limit = value.get("limit").and_then(as_i32).unwrap_or(0);
+            quote! {
+                LootFunctionKind::EnchantedCountIncrease {
+                    enchantment: #enchantment,
+                    count: #count,
+                    limit: #limit,
+                }
+            }
+        }
+        "minecraft:limit_count" => {
+            let limit = value.get("limit").unwrap_or(&Value::Null);
+            let optional = |key: &str| match limit.get(key).and_then(as_i32) {
+                Some(n) => quote! { Some(#n) },
+                None => quote! { None },
+            };
+            let (min, max) = (optional("min"), optional("max"));
+            quote! { LootFunctionKind::LimitCount { min: #min, max: #max } }
+        }
+        _ => quote! { LootFunctionKind::Unsupported(#name) },
+    };
+    let conditions = conditions(value);
+    quote! {
+        LootFunction {
+            kind: #kind,
+            conditions: #conditions,
+        }
+    }
+}
+
+fn entry(value: &Value) -> TokenStream {
+    let children = || {
+        let children = value
+            .get("children")
+            .and_then(Value::as_array)
+            .map(|children| children.iter().map(entry).collect::<Vec<_>>())
+            .unwrap_or_default();
+        quote! { &[#(#children),*] }
+    };
+    let kind = match string_field(value, "type") {
+        "minecraft:item" => {
+            let name = string_field(value, "name");
+            quote! { LootEntryKind::Item(#name) }
+        }
+        "minecraft:tag" => {
+            let name = string_field(value, "name");
+            let expand = value
+                .get("expand")
+                .and_then(Value::as_bool)
+                .unwrap_or(false);
+            quote! { LootEntryKind::Tag { name: #name, expand: #expand } }
+        }
+        "minecraft:loot_table" => match value.get("value") {
+            Some(Value::String(name)) => quote! { LootEntryKind::LootTable(#name) },
+            // Inline tables are never used by vanilla
+            _ => quote! { LootEntryKind::Empty },
+        },
+        "minecraft:dynamic" => {
+            let name = string_field(value, "name");
+            quote! { LootEntryKind::Dynamic(#name) }
+        }
+        "minecraft:alternatives" => {
+            let children = children();
+            quote! { LootEntryKind::Alternatives(#children) }
+        }
+        "minecraft:group" => {
+            let children = children();
+            quote! { LootEntryKind::Group(#children) }
+        }
+        "minecraft:sequence" => {
+            let children = children();
+            quote! { LootEntryKind::Sequence(#children) }
+        }
+        _ => quote! { LootEntryKind::Empty },
+    };
+    let weight = value.get("weight").and_then(as_i32).unwrap_or(1).max(0) as u32;
+    let quality = value.get("quality").and_then(as_i32).unwrap_or(0);
+    let conditions = conditions(value);
+    let functions = functions(value);
+    quote! {
+        LootEntry {
+            kind: #kind,
+            weight: #weight,
+            quality: #quality,
+            conditions: #conditions,
+            functions: #functions,
+        }
+    }
+}
+
+fn pool(value: &Value) -> TokenStream {
+    let rolls = number_provider(value.get("rolls").unwrap_or(&Value::Null));
+    let bonus_rolls = number_provider(value.get("bonus_rolls").unwrap_or(&Value::from(0.0)));
+    let entries = value
+        .get("entries")
+        .and_then(Value::as_array)
+        .map(|entries| entries.iter().map(entry).collect::<Vec<_>>())
+        .unwrap_or_default();
+    let conditions = conditions(value);
+    let functions = functions(value);
+    quote! {
+        LootPool {
+            rolls: #rolls,
+            bonus_rolls: #bonus_rolls,
+            entries: &[#(#entries),*],
+            conditions: #conditions,
+            functions: #functions,
+        }
+    }
+}
+
+/// Generates a `LootTable` expression for a loot table in vanilla's JSON format.
+///
+/// `name` is used when the table doesn't carry its own `random_sequence`.
+pub fn table(name: &str, value: &Value) -> TokenStream {
+    let name = value
+        .get("random_sequence")
+        .and_then(Value::as_str)
+        .unwrap_or(name);
+    let kind = match string_field(value, "type") {
+        "minecraft:block" => quote! { LootTableKind::Block },
+        "minecraft:entity" => quote! { LootTableKind::Entity },
+        "minecraft:chest" => quote! { LootTableKind::Chest },
+        "minecraft:fishing" => quote! { LootTableKind::Fishing },
+        "minecraft:archaeology" => quote! { LootTableKind::Archaeology },
+        _ => quote! { LootTableKind::Other },
+    };
+    let pools = value
+        .get("pools")
+        .and_then(Value::as_array)
+        .map(|pools| pools.iter().map(pool).collect::<Vec<_>>())
+        .unwrap_or_default();
+    let functions = functions(value);
+    quote! {
+        LootTable {
+            name: #name,
+            kind: #kind,
+            pools: &[#(#pools),*],
+            functions: #functions,
+        }
+    }
+}
+
+pub(crate) fn build() -> TokenStream {
+    println!("cargo:rerun-if-changed=../../../assets/extracted/entities.json");
+
+    let entities: BTreeMap<String, Value> = serde_json::from_str(
+        &fs::read_to_string("../../../assets/extracted/entities.json").unwrap(),
+    )
+    .expect("Failed to parse entities.json");
+
+    // Every other table, like chests and fishing, by name. Only some extractors dump these, so a
+    // missing file leaves them out instead of failing the build.
+    println!("cargo:rerun-if-changed=../../../assets/extracted/loot_tables.json");
+    let other_tables: BTreeMap<String, Value> =
+        match fs::read_to_string("../../../assets/extracted/loot_tables.json") {
+            Ok(json) => serde_json::from_str(&json).expect("Failed to parse loot_tables.json"),
+            Err(_) => {
+                println!("cargo:warning=No loot_tables.json, chest loot tables won't be generated");
+                BTreeMap::new()
+            }
+        };
+
+    // Block loot tables are generated with the blocks, see `Block::loot_table`
+    let entity_tables = entities.iter().filter_map(|(entity, data)| {
+        let loot_table = data.get("loot_table")?;
+        Some((format!("minecraft:entities/{entity}"), loot_table))
+    });
+    let other_tables = other_tables
+        .iter()
+        .filter(|(name, _)| !name.starts_with("minecraft:blocks/"))
+        .map(|(name, loot_table)| (name.clone(), loot_table));
+
+    let mut constants = TokenStream::new();
+    let mut lookups = TokenStream::new();
+    for (name, loot_table) in entity_tables.chain(other_tables) {
+        let name = loot_table
+            .get("random_sequence")
+            .and_then(Value::as_str)
+            .unwrap_or(&name)
+            .to_string();
+        let ident = format_ident!(
+            "{}",
+            name.trim_start_matches("minecraft:")
+                .replace('/', "_")
+                .to_shouty_snake_case()
+        );
+        let table = table(&name, loot_table);
+        constants.extend(quote! {
+            pub const #ident: LootTable = #table;
+        });
+        lookups.extend(quote! {
+            #name => Some(&Self::#ident),
+        });
+    }
+
+    quote! {
+        /// A vanilla loot table.
+        #[derive(Debug, Clone, Copy)]
+        pub struct LootTable {
+            pub name: &'static str,
+            pub kind: LootTableKind,
+            pub pools: &'static [LootPool],
+            /// Functions applied to everything the table generates.
+            pub functions: &'static [LootFunction],
+        }
+
+        /// What a loot table is used for, which decides what's in its loot context.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub enum LootTableKind {
+            Block,
+            Entity,
+            Chest,
+            Fishing,
+            Archaeology,
+            Other,
+        }
+
+        /// A pool rolls its entries a random number of times, if its conditions pass.
+        #[derive(Debug, Clone, Copy)]
+        pub struct LootPool {
+            pub rolls: NumberProvider,
+            /// Extra rolls per point of luck.
+            pub bonus_rolls: NumberProvider,
+            pub entries: &'static [LootEntry],
+            pub conditions: &'static [LootCondition],
+            pub functions: &'static [LootFunction],
+        }
+
+        #[derive(Debug, Clone, Copy)]
+        pub struct LootEntry {
+            pub kind: LootEntryKind,
+            /// Chance of this entry being picked relative to the other entries in the pool.
+            pub weight: u32,
+            /// How much luck changes the weight.
+            pub quality: i32,
+            pub conditions: &'static [LootCondition],
+            pub functions: &'static [LootFunction],
+        }
+
+        #[derive(Debug, Clone, Copy)]
+        pub enum LootEntryKind {
+            /// A single item, by registry name.
+            Item(&'static str),
+            /// Every item in an item tag, or one of them at random if `expand` is set.
+            Tag { name: &'static str, expand: bool },
+            /// Everything another loot table generates, by name.
+            LootTable(&'static str),
+            /// Contents that depend on the block entity, like a shulker box.
+            Dynamic(&'static str),
+            Empty,
+            /// The first child whose conditions pass.
+            Alternatives(&'static [LootEntry]),
+            /// Every child whose conditions pass.
+            Group(&'static [LootEntry]),
+            /// Children in order until one's conditions fail.
+            Sequence(&'static [LootEntry]),
+        }
+
+        #[derive(Debug, Clone, Copy)]
+        pub enum NumberProvider {
+            Constant(f32),
+            Uniform { min: f32, max: f32 },
+            Binomial { n: i32, p: f32 },
+        }
+
+        #[derive(Debug, Clone, Copy)]
+        pub enum LootCondition {
+            SurvivesExplosion,
+            KilledByPlayer,
+            RandomChance(f32),
+            RandomChanceWithEnchantedBonus {
+                enchantment: &'static str,
+                unenchanted_chance: f32,
+                base: f32,
+                per_level_above_first: f32,
+            },
+            /// Passes with the chance at the index of the tool's enchantment level.
+            TableBonus {
+                enchantment: &'static str,
+                chances: &'static [f32],
+            },
+            MatchTool(ToolPredicate),
+            /// Checks the killed entity or its killer.
+            EntityProperties {
+                entity: LootEntityTarget,
+                predicate: EntityPredicate,
+            },
+            /// Checks the damage that killed the entity.
+            DamageSourceProperties(DamageSourcePredicate),
+            BlockStateProperty {
+                block: &'static str,
+                properties: &'static [(&'static str, &'static str)],
+            },
+            Inverted(&'static LootCondition),
+            AnyOf(&'static [LootCondition]),
+            AllOf(&'static [LootCondition]),
+            /// A condition, or a part of one, that can't be evaluated yet, by type. Never passes.
+            Unsupported(&'static str),
+        }
+
+        /// Which entity an `entity_properties` condition checks.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub enum LootEntityTarget {
+            /// The entity the loot is for.
+            This,
+            /// The entity responsible for the kill, like the skeleton that shot the arrow.
+            Attacker,
+            /// The entity that dealt the damage, like the arrow.
+            DirectAttacker,
+        }
+
+        /// Matches an entity. Unset fields match anything.
+        #[derive(Debug, Clone, Copy)]
+        pub struct EntityPredicate {
+            /// Entity types or `#` prefixed entity type tags, any of which match.
+            pub types: &'static [&'static str],
+            pub on_fire: Option<bool>,
+            pub baby: Option<bool>,
+        }
+
+        /// Matches the damage that killed an entity.
+        #[derive(Debug, Clone, Copy)]
+        pub struct DamageSourcePredicate {
+            /// Damage type tags, and whether the damage has to be in them or not.
+            pub tags: &'static [(&'static str, bool)],
+            pub direct_entity: Option<&'static EntityPredicate>,
+            pub source_entity: Option<&'static EntityPredicate>,
+        }
+
+        /// Matches the tool used to break a block or kill an entity.
+        #[derive(Debug, Clone, Copy)]
+        pub struct ToolPredicate {
+            /// Item names or `#` prefixed item tags, any of which match. Empty matches any item.
+            pub items: &'static [&'static str],
+            /// Enchantments the tool needs, with their minimum level.
+            pub enchantments: &'static [(&'static str, u32)],
+        }
+
+        #[derive(Debug, Clone, Copy)]
+        pub struct LootFunction {
+            pub kind: LootFunctionKind,
+            pub conditions: &'static [LootCondition],
+        }
+
+        #[derive(Debug, Clone, Copy)]
+        pub enum LootFunctionKind {
+            SetCount { count: NumberProvider, add: bool },
+            ApplyBonus { enchantment: &'static str, formula: BonusFormula },
+            ExplosionDecay,
+            /// Looting.
+            EnchantedCountIncrease {
+                enchantment: &'static str,
+                count: NumberProvider,
+                limit: i32,
+            },
+            LimitCount { min: Option<i32>, max: Option<i32> },
+            /// A function that isn't implemented yet, by type. Leaves the stack as it is.
+            Unsupported(&'static str),
+        }
+
+        /// How `apply_bonus` scales the count with the enchantment level.
+        #[derive(Debug, Clone, Copy)]
+        pub enum BonusFormula {
+            OreDrops,
+            UniformBonusCount { bonus_multiplier: i32 },
+            BinomialWithBonusCount { extra: i32, probability: f32 },
+        }
+
+        impl LootTable {
+            #constants
+
+            /// Looks up a loot table by name, e.g. `minecraft:entities/zombie` or
+            /// `minecraft:blocks/stone`.
+            pub fn by_name(name: &str) -> Option<&'static LootTable> {
+                if let Some(block) = name.strip_prefix("minecraft:blocks/") {
+                    return crate::blocks::Block::by_name(block).and_then(|block| block.loot_table);
+                }
+                match name {
+                    #lookups
+                    _ => None,
+                }
+            }
+        }
+    }
+}
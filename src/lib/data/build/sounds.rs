@@ -38,6 +38,15 @@ pub(crate) fn build() -> TokenStream {
         })
         .collect::<TokenStream>();
 
+    let all = &sounds
+        .iter()
+        .map(|sound| {
+            let name = format_ident!("{}", sound.to_pascal_case());
+
+            quote! { Self::#name, }
+        })
+        .collect::<TokenStream>();
+
     quote! {
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
         pub enum Sound {
@@ -59,6 +68,11 @@ pub(crate) fn build() -> TokenStream {
                     #type_to_name
                 }
             }
+
+            #[doc = r" Get all sounds as a slice."]
+            pub fn all() -> &'static [Self] {
+                &[#all]
+            }
         }
     }
 }
@@ -78,12 +78,12 @@ impl Attribute {
     pub const GRAVITY: Attribute = Attribute {
         id: 14,
         name: "gravity",
-        default_value: 0.1,
+        default_value: 0.08,
     };
     pub const JUMP_STRENGTH: Attribute = Attribute {
         id: 15,
         name: "jump_strength",
-        default_value: 0.4,
+        default_value: 0.41999998688697815,
     };
     pub const KNOCKBACK_RESISTANCE: Attribute = Attribute {
         id: 16,
@@ -160,4 +160,406 @@ impl DamageType {
             Self::WitherSkull => "wither_skull",
         }
     }
+    #[doc = r" The protocol ID of the damage type."]
+    pub const fn id(&self) -> u16 {
+        match self {
+            Self::Arrow => 0,
+            Self::BadRespawnPoint => 1,
+            Self::Cactus => 2,
+            Self::Campfire => 3,
+            Self::Cramming => 4,
+            Self::DragonBreath => 5,
+            Self::Drown => 6,
+            Self::DryOut => 7,
+            Self::EnderPearl => 8,
+            Self::Explosion => 9,
+            Self::Fall => 10,
+            Self::FallingAnvil => 11,
+            Self::FallingBlock => 12,
+            Self::FallingStalactite => 13,
+            Self::Fireball => 14,
+            Self::Fireworks => 15,
+            Self::FlyIntoWall => 16,
+            Self::Freeze => 17,
+            Self::Generic => 18,
+            Self::GenericKill => 19,
+            Self::HotFloor => 20,
+            Self::InFire => 21,
+            Self::InWall => 22,
+            Self::IndirectMagic => 23,
+            Self::Lava => 24,
+            Self::LightningBolt => 25,
+            Self::MaceSmash => 26,
+            Self::Magic => 27,
+            Self::MobAttack => 28,
+            Self::MobAttackNoAggro => 29,
+            Self::MobProjectile => 30,
+            Self::OnFire => 31,
+            Self::OutOfWorld => 32,
+            Self::OutsideBorder => 33,
+            Self::PlayerAttack => 34,
+            Self::PlayerExplosion => 35,
+            Self::SonicBoom => 36,
+            Self::Spit => 37,
+            Self::Stalagmite => 38,
+            Self::Starve => 39,
+            Self::Sting => 40,
+            Self::SweetBerryBush => 41,
+            Self::Thorns => 42,
+            Self::Thrown => 43,
+            Self::Trident => 44,
+            Self::UnattributedFireball => 45,
+            Self::WindCharge => 46,
+            Self::Wither => 47,
+            Self::WitherSkull => 48,
+        }
+    }
+    #[doc = r" How much exhaustion a player gets from taking this type of damage."]
+    pub const fn exhaustion(&self) -> f32 {
+        match self {
+            Self::Arrow => 0.1,
+            Self::BadRespawnPoint => 0.1,
+            Self::Cactus => 0.1,
+            Self::Campfire => 0.1,
+            Self::Cramming => 0.0,
+            Self::DragonBreath => 0.0,
+            Self::Drown => 0.0,
+            Self::DryOut => 0.1,
+            Self::EnderPearl => 0.0,
+            Self::Explosion => 0.1,
+            Self::Fall => 0.0,
+            Self::FallingAnvil => 0.1,
+            Self::FallingBlock => 0.1,
+            Self::FallingStalactite => 0.1,
+            Self::Fireball => 0.1,
+            Self::Fireworks => 0.1,
+            Self::FlyIntoWall => 0.0,
+            Self::Freeze => 0.0,
+            Self::Generic => 0.0,
+            Self::GenericKill => 0.0,
+            Self::HotFloor => 0.1,
+            Self::InFire => 0.1,
+            Self::InWall => 0.0,
+            Self::IndirectMagic => 0.0,
+            Self::Lava => 0.1,
+            Self::LightningBolt => 0.1,
+            Self::MaceSmash => 0.1,
+            Self::Magic => 0.0,
+            Self::MobAttack => 0.1,
+            Self::MobAttackNoAggro => 0.1,
+            Self::MobProjectile => 0.1,
+            Self::OnFire => 0.0,
+            Self::OutOfWorld => 0.0,
+            Self::OutsideBorder => 0.0,
+            Self::PlayerAttack => 0.1,
+            Self::PlayerExplosion => 0.1,
+            Self::SonicBoom => 0.0,
+            Self::Spit => 0.1,
+            Self::Stalagmite => 0.0,
+            Self::Starve => 0.0,
+            Self::Sting => 0.1,
+            Self::SweetBerryBush => 0.1,
+            Self::Thorns => 0.1,
+            Self::Thrown => 0.1,
+            Self::Trident => 0.1,
+            Self::UnattributedFireball => 0.1,
+            Self::WindCharge => 0.1,
+            Self::Wither => 0.0,
+            Self::WitherSkull => 0.1,
+        }
+    }
+    #[doc = r" The ID of the death messages for this type, as in `death.attack.<id>`."]
+    pub const fn message_id(&self) -> &'static str {
+        match self {
+            Self::Arrow => "arrow",
+            Self::BadRespawnPoint => "badRespawnPoint",
+            Self::Cactus => "cactus",
+            Self::Campfire => "inFire",
+            Self::Cramming => "cramming",
+            Self::DragonBreath => "dragonBreath",
+            Self::Drown => "drown",
+            Self::DryOut => "dryout",
+            Self::EnderPearl => "fall",
+            Self::Explosion => "explosion",
+            Self::Fall => "fall",
+            Self::FallingAnvil => "anvil",
+            Self::FallingBlock => "fallingBlock",
+            Self::FallingStalactite => "fallingStalactite",
+            Self::Fireball => "fireball",
+            Self::Fireworks => "fireworks",
+            Self::FlyIntoWall => "flyIntoWall",
+            Self::Freeze => "freeze",
+            Self::Generic => "generic",
+            Self::GenericKill => "genericKill",
+            Self::HotFloor => "hotFloor",
+            Self::InFire => "inFire",
+            Self::InWall => "inWall",
+            Self::IndirectMagic => "indirectMagic",
+            Self::Lava => "lava",
+            Self::LightningBolt => "lightningBolt",
+            Self::MaceSmash => "mace_smash",
+            Self::Magic => "magic",
+            Self::MobAttack => "mob",
+            Self::MobAttackNoAggro => "mob",
+            Self::MobProjectile => "mob",
+            Self::OnFire => "onFire",
+            Self::OutOfWorld => "outOfWorld",
+            Self::OutsideBorder => "outsideBorder",
+            Self::PlayerAttack => "player",
+            Self::PlayerExplosion => "explosion.player",
+            Self::SonicBoom => "sonic_boom",
+            Self::Spit => "mob",
+            Self::Stalagmite => "stalagmite",
+            Self::Starve => "starve",
+            Self::Sting => "sting",
+            Self::SweetBerryBush => "sweetBerryBush",
+            Self::Thorns => "thorns",
+            Self::Thrown => "thrown",
+            Self::Trident => "trident",
+            Self::UnattributedFireball => "onFire",
+            Self::WindCharge => "mob",
+            Self::Wither => "wither",
+            Self::WitherSkull => "witherSkull",
+        }
+    }
+    #[doc = r" The damage type tags this type is in, like `bypasses_armor`."]
+    pub const fn tags(&self) -> &'static [&'static str] {
+        match self {
+            Self::Arrow => &["always_kills_armor_stands", "is_projectile", "panic_causes"],
+            Self::BadRespawnPoint => &[
+                "always_hurts_ender_dragons",
+                "avoids_guardian_thorns",
+                "is_explosion",
+                "no_knockback",
+            ],
+            Self::Cactus => &[
+                "bypasses_shield",
+                "no_knockback",
+                "panic_causes",
+                "panic_environmental_causes",
+            ],
+            Self::Campfire => &[
+                "burn_from_stepping",
+                "bypasses_shield",
+                "ignites_armor_stands",
+                "is_fire",
+                "no_knockback",
+            ],
+            Self::Cramming => &[
+                "bypasses_armor",
+                "bypasses_shield",
+                "bypasses_wolf_armor",
+                "no_knockback",
+            ],
+            Self::DragonBreath => &[
+                "bypasses_armor",
+                "bypasses_shield",
+                "no_knockback",
+                "panic_causes",
+            ],
+            Self::Drown => &[
+                "bypasses_armor",
+                "bypasses_shield",
+                "bypasses_wolf_armor",
+                "is_drowning",
+                "no_impact",
+                "no_knockback",
+                "wither_immune_to",
+            ],
+            Self::DryOut => &["bypasses_shield", "bypasses_wolf_armor", "no_knockback"],
+            Self::EnderPearl => &[
+                "bypasses_armor",
+                "bypasses_shield",
+                "is_fall",
+                "no_knockback",
+            ],
+            Self::Explosion => &[
+                "always_hurts_ender_dragons",
+                "avoids_guardian_thorns",
+                "is_explosion",
+                "no_knockback",
+                "panic_causes",
+            ],
+            Self::Fall => &[
+                "bypasses_armor",
+                "bypasses_shield",
+                "is_fall",
+                "no_knockback",
+            ],
+            Self::FallingAnvil => &["bypasses_shield", "damages_helmet"],
+            Self::FallingBlock => &["damages_helmet"],
+            Self::FallingStalactite => &["bypasses_shield", "damages_helmet"],
+            Self::Fireball => &[
+                "always_kills_armor_stands",
+                "is_fire",
+                "is_projectile",
+                "panic_causes",
+            ],
+            Self::Fireworks => &[
+                "always_hurts_ender_dragons",
+                "avoids_guardian_thorns",
+                "is_explosion",
+                "panic_causes",
+            ],
+            Self::FlyIntoWall => &["bypasses_armor", "bypasses_shield", "no_knockback"],
+            Self::Freeze => &[
+                "bypasses_armor",
+                "bypasses_shield",
+                "bypasses_wolf_armor",
+                "is_freezing",
+                "no_knockback",
+                "panic_causes",
+                "panic_environmental_causes",
+            ],
+            Self::Generic => &["bypasses_armor", "bypasses_shield", "no_knockback"],
+            Self::GenericKill => &[
+                "bypasses_armor",
+                "bypasses_invulnerability",
+                "bypasses_resistance",
+                "bypasses_shield",
+                "bypasses_wolf_armor",
+                "no_knockback",
+            ],
+            Self::HotFloor => &[
+                "burn_from_stepping",
+                "bypasses_shield",
+                "is_fire",
+                "no_knockback",
+                "panic_causes",
+                "panic_environmental_causes",
+            ],
+            Self::InFire => &[
+                "bypasses_shield",
+                "ignites_armor_stands",
+                "is_fire",
+                "no_knockback",
+                "panic_causes",
+                "panic_environmental_causes",
+            ],
+            Self::InWall => &[
+                "bypasses_armor",
+                "bypasses_shield",
+                "bypasses_wolf_armor",
+                "no_knockback",
+            ],
+            Self::IndirectMagic => &[
+                "bypasses_armor",
+                "bypasses_shield",
+                "bypasses_wolf_armor",
+                "panic_causes",
+                "witch_resistant_to",
+            ],
+            Self::Lava => &[
+                "bypasses_shield",
+                "is_fire",
+                "no_knockback",
+                "panic_causes",
+                "panic_environmental_causes",
+            ],
+            Self::LightningBolt => &[
+                "bypasses_shield",
+                "is_lightning",
+                "no_knockback",
+                "panic_causes",
+                "panic_environmental_causes",
+            ],
+            Self::MaceSmash => &[
+                "can_break_armor_stand",
+                "is_player_attack",
+                "mace_smash",
+                "panic_causes",
+            ],
+            Self::Magic => &[
+                "always_triggers_silverfish",
+                "avoids_guardian_thorns",
+                "bypasses_armor",
+                "bypasses_shield",
+                "bypasses_wolf_armor",
+                "no_knockback",
+                "panic_causes",
+                "witch_resistant_to",
+            ],
+            Self::MobAttack => &["panic_causes"],
+            Self::MobAttackNoAggro => &["no_anger"],
+            Self::MobProjectile => &["is_projectile", "panic_causes"],
+            Self::OnFire => &[
+                "burns_armor_stands",
+                "bypasses_armor",
+                "bypasses_shield",
+                "is_fire",
+                "no_knockback",
+                "panic_causes",
+                "panic_environmental_causes",
+            ],
+            Self::OutOfWorld => &[
+                "always_most_significant_fall",
+                "bypasses_armor",
+                "bypasses_invulnerability",
+                "bypasses_resistance",
+                "bypasses_shield",
+                "bypasses_wolf_armor",
+                "no_knockback",
+            ],
+            Self::OutsideBorder => &[
+                "bypasses_armor",
+                "bypasses_shield",
+                "bypasses_wolf_armor",
+                "no_knockback",
+            ],
+            Self::PlayerAttack => &["can_break_armor_stand", "is_player_attack", "panic_causes"],
+            Self::PlayerExplosion => &[
+                "always_hurts_ender_dragons",
+                "avoids_guardian_thorns",
+                "can_break_armor_stand",
+                "is_explosion",
+                "no_knockback",
+                "panic_causes",
+            ],
+            Self::SonicBoom => &[
+                "bypasses_armor",
+                "bypasses_enchantments",
+                "bypasses_shield",
+                "panic_causes",
+                "witch_resistant_to",
+            ],
+            Self::Spit => &[],
+            Self::Stalagmite => &[
+                "bypasses_armor",
+                "bypasses_shield",
+                "is_fall",
+                "no_knockback",
+            ],
+            Self::Starve => &[
+                "bypasses_armor",
+                "bypasses_effects",
+                "bypasses_shield",
+                "bypasses_wolf_armor",
+                "no_knockback",
+            ],
+            Self::Sting => &["panic_causes"],
+            Self::SweetBerryBush => &["bypasses_shield", "no_knockback"],
+            Self::Thorns => &[
+                "avoids_guardian_thorns",
+                "bypasses_wolf_armor",
+                "witch_resistant_to",
+            ],
+            Self::Thrown => &["is_projectile", "panic_causes"],
+            Self::Trident => &["always_kills_armor_stands", "is_projectile", "panic_causes"],
+            Self::UnattributedFireball => &["is_fire", "is_projectile", "panic_causes"],
+            Self::WindCharge => &["always_kills_armor_stands", "is_projectile", "panic_causes"],
+            Self::Wither => &[
+                "bypasses_armor",
+                "bypasses_shield",
+                "bypasses_wolf_armor",
+                "no_knockback",
+                "panic_causes",
+            ],
+            Self::WitherSkull => &["always_kills_armor_stands", "is_projectile", "panic_causes"],
+        }
+    }
+    #[doc = r" Whether this type is in a damage type tag."]
+    pub fn has_tag(&self, tag: &str) -> bool {
+        let tag = tag.strip_prefix("minecraft:").unwrap_or(tag);
+        self.tags().contains(&tag)
+    }
 }
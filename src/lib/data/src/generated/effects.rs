@@ -1,3 +1,5 @@
+use crate::attributes::Attribute;
+use crate::items::Operation;
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Effect {
     Absorption,
@@ -40,6 +42,22 @@ pub enum Effect {
     WindCharged,
     Wither,
 }
+#[doc = r" Whether an effect is good or bad for whoever has it."]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EffectCategory {
+    Beneficial,
+    Harmful,
+    Neutral,
+}
+#[doc = r" An attribute modifier applied while an effect is active. The amount is `base_value`"]
+#[doc = r" for every level of the effect."]
+#[derive(Debug, Clone)]
+pub struct EffectModifier {
+    pub attribute: &'static Attribute,
+    pub id: &'static str,
+    pub base_value: f64,
+    pub operation: Operation,
+}
 impl Effect {
     #[doc = r" Try to parse an `Effect` from a resource location string."]
     pub fn from_name(name: &str) -> Option<Self> {
@@ -130,4 +148,328 @@ impl Effect {
             Self::Wither => "wither",
         }
     }
+    #[doc = r" The protocol ID of the effect."]
+    pub const fn id(&self) -> u8 {
+        match self {
+            Self::Absorption => 21,
+            Self::BadOmen => 30,
+            Self::Blindness => 14,
+            Self::ConduitPower => 28,
+            Self::Darkness => 32,
+            Self::DolphinsGrace => 29,
+            Self::FireResistance => 11,
+            Self::Glowing => 23,
+            Self::Haste => 2,
+            Self::HealthBoost => 20,
+            Self::HeroOfTheVillage => 31,
+            Self::Hunger => 16,
+            Self::Infested => 38,
+            Self::InstantDamage => 6,
+            Self::InstantHealth => 5,
+            Self::Invisibility => 13,
+            Self::JumpBoost => 7,
+            Self::Levitation => 24,
+            Self::Luck => 25,
+            Self::MiningFatigue => 3,
+            Self::Nausea => 8,
+            Self::NightVision => 15,
+            Self::Oozing => 37,
+            Self::Poison => 18,
+            Self::RaidOmen => 34,
+            Self::Regeneration => 9,
+            Self::Resistance => 10,
+            Self::Saturation => 22,
+            Self::SlowFalling => 27,
+            Self::Slowness => 1,
+            Self::Speed => 0,
+            Self::Strength => 4,
+            Self::TrialOmen => 33,
+            Self::Unluck => 26,
+            Self::WaterBreathing => 12,
+            Self::Weakness => 17,
+            Self::Weaving => 36,
+            Self::WindCharged => 35,
+            Self::Wither => 19,
+        }
+    }
+    #[doc = r" Try to get an `Effect` from its protocol ID."]
+    pub const fn from_id(id: u8) -> Option<Self> {
+        match id {
+            21 => Some(Self::Absorption),
+            30 => Some(Self::BadOmen),
+            14 => Some(Self::Blindness),
+            28 => Some(Self::ConduitPower),
+            32 => Some(Self::Darkness),
+            29 => Some(Self::DolphinsGrace),
+            11 => Some(Self::FireResistance),
+            23 => Some(Self::Glowing),
+            2 => Some(Self::Haste),
+            20 => Some(Self::HealthBoost),
+            31 => Some(Self::HeroOfTheVillage),
+            16 => Some(Self::Hunger),
+            38 => Some(Self::Infested),
+            6 => Some(Self::InstantDamage),
+            5 => Some(Self::InstantHealth),
+            13 => Some(Self::Invisibility),
+            7 => Some(Self::JumpBoost),
+            24 => Some(Self::Levitation),
+            25 => Some(Self::Luck),
+            3 => Some(Self::MiningFatigue),
+            8 => Some(Self::Nausea),
+            15 => Some(Self::NightVision),
+            37 => Some(Self::Oozing),
+            18 => Some(Self::Poison),
+            34 => Some(Self::RaidOmen),
+            9 => Some(Self::Regeneration),
+            10 => Some(Self::Resistance),
+            22 => Some(Self::Saturation),
+            27 => Some(Self::SlowFalling),
+            1 => Some(Self::Slowness),
+            0 => Some(Self::Speed),
+            4 => Some(Self::Strength),
+            33 => Some(Self::TrialOmen),
+            26 => Some(Self::Unluck),
+            12 => Some(Self::WaterBreathing),
+            17 => Some(Self::Weakness),
+            36 => Some(Self::Weaving),
+            35 => Some(Self::WindCharged),
+            19 => Some(Self::Wither),
+            _ => None,
+        }
+    }
+    pub const fn category(&self) -> EffectCategory {
+        match self {
+            Self::Absorption => EffectCategory::Beneficial,
+            Self::BadOmen => EffectCategory::Neutral,
+            Self::Blindness => EffectCategory::Harmful,
+            Self::ConduitPower => EffectCategory::Beneficial,
+            Self::Darkness => EffectCategory::Harmful,
+            Self::DolphinsGrace => EffectCategory::Beneficial,
+            Self::FireResistance => EffectCategory::Beneficial,
+            Self::Glowing => EffectCategory::Neutral,
+            Self::Haste => EffectCategory::Beneficial,
+            Self::HealthBoost => EffectCategory::Beneficial,
+            Self::HeroOfTheVillage => EffectCategory::Beneficial,
+            Self::Hunger => EffectCategory::Harmful,
+            Self::Infested => EffectCategory::Harmful,
+            Self::InstantDamage => EffectCategory::Harmful,
+            Self::InstantHealth => EffectCategory::Beneficial,
+            Self::Invisibility => EffectCategory::Beneficial,
+            Self::JumpBoost => EffectCategory::Beneficial,
+            Self::Levitation => EffectCategory::Harmful,
+            Self::Luck => EffectCategory::Beneficial,
+            Self::MiningFatigue => EffectCategory::Harmful,
+            Self::Nausea => EffectCategory::Harmful,
+            Self::NightVision => EffectCategory::Beneficial,
+            Self::Oozing => EffectCategory::Harmful,
+            Self::Poison => EffectCategory::Harmful,
+            Self::RaidOmen => EffectCategory::Neutral,
+            Self::Regeneration => EffectCategory::Beneficial,
+            Self::Resistance => EffectCategory::Beneficial,
+            Self::Saturation => EffectCategory::Beneficial,
+            Self::SlowFalling => EffectCategory::Beneficial,
+            Self::Slowness => EffectCategory::Harmful,
+            Self::Speed => EffectCategory::Beneficial,
+            Self::Strength => EffectCategory::Beneficial,
+            Self::TrialOmen => EffectCategory::Neutral,
+            Self::Unluck => EffectCategory::Harmful,
+            Self::WaterBreathing => EffectCategory::Beneficial,
+            Self::Weakness => EffectCategory::Harmful,
+            Self::Weaving => EffectCategory::Harmful,
+            Self::WindCharged => EffectCategory::Harmful,
+            Self::Wither => EffectCategory::Harmful,
+        }
+    }
+    #[doc = r" The colour of the effect's particles, as RGB."]
+    pub const fn color(&self) -> i32 {
+        match self {
+            Self::Absorption => 2445989,
+            Self::BadOmen => 745784,
+            Self::Blindness => 2039587,
+            Self::ConduitPower => 1950417,
+            Self::Darkness => 2696993,
+            Self::DolphinsGrace => 8954814,
+            Self::FireResistance => 16750848,
+            Self::Glowing => 9740385,
+            Self::Haste => 14270531,
+            Self::HealthBoost => 16284963,
+            Self::HeroOfTheVillage => 4521796,
+            Self::Hunger => 5797459,
+            Self::Infested => 9214860,
+            Self::InstantDamage => 11101546,
+            Self::InstantHealth => 16262179,
+            Self::Invisibility => 16185078,
+            Self::JumpBoost => 16646020,
+            Self::Levitation => 13565951,
+            Self::Luck => 5882118,
+            Self::MiningFatigue => 4866583,
+            Self::Nausea => 5578058,
+            Self::NightVision => 12779366,
+            Self::Oozing => 10092451,
+            Self::Poison => 8889187,
+            Self::RaidOmen => 14565464,
+            Self::Regeneration => 13458603,
+            Self::Resistance => 9520880,
+            Self::Saturation => 16262179,
+            Self::SlowFalling => 15978425,
+            Self::Slowness => 9154528,
+            Self::Speed => 3402751,
+            Self::Strength => 16762624,
+            Self::TrialOmen => 1484454,
+            Self::Unluck => 12624973,
+            Self::WaterBreathing => 10017472,
+            Self::Weakness => 4738376,
+            Self::Weaving => 7891290,
+            Self::WindCharged => 12438015,
+            Self::Wither => 7561558,
+        }
+    }
+    #[doc = r" The attribute modifiers applied while the effect is active."]
+    pub const fn attribute_modifiers(&self) -> &'static [EffectModifier] {
+        match self {
+            Self::Absorption => &[EffectModifier {
+                attribute: &Attribute::MAX_ABSORPTION,
+                id: "minecraft:effect.absorption",
+                base_value: 4.0,
+                operation: Operation::AddValue,
+            }],
+            Self::BadOmen => &[],
+            Self::Blindness => &[],
+            Self::ConduitPower => &[],
+            Self::Darkness => &[],
+            Self::DolphinsGrace => &[],
+            Self::FireResistance => &[],
+            Self::Glowing => &[],
+            Self::Haste => &[EffectModifier {
+                attribute: &Attribute::ATTACK_SPEED,
+                id: "minecraft:effect.haste",
+                base_value: 0.10000000149011612,
+                operation: Operation::AddMultipliedTotal,
+            }],
+            Self::HealthBoost => &[EffectModifier {
+                attribute: &Attribute::MAX_HEALTH,
+                id: "minecraft:effect.health_boost",
+                base_value: 4.0,
+                operation: Operation::AddValue,
+            }],
+            Self::HeroOfTheVillage => &[],
+            Self::Hunger => &[],
+            Self::Infested => &[],
+            Self::InstantDamage => &[],
+            Self::InstantHealth => &[],
+            Self::Invisibility => &[EffectModifier {
+                attribute: &Attribute::WAYPOINT_TRANSMIT_RANGE,
+                id: "minecraft:effect.waypoint_transmit_range_hide",
+                base_value: -1.0,
+                operation: Operation::AddMultipliedTotal,
+            }],
+            Self::JumpBoost => &[EffectModifier {
+                attribute: &Attribute::SAFE_FALL_DISTANCE,
+                id: "minecraft:effect.jump_boost",
+                base_value: 1.0,
+                operation: Operation::AddValue,
+            }],
+            Self::Levitation => &[],
+            Self::Luck => &[EffectModifier {
+                attribute: &Attribute::LUCK,
+                id: "minecraft:effect.luck",
+                base_value: 1.0,
+                operation: Operation::AddValue,
+            }],
+            Self::MiningFatigue => &[EffectModifier {
+                attribute: &Attribute::ATTACK_SPEED,
+                id: "minecraft:effect.mining_fatigue",
+                base_value: -0.10000000149011612,
+                operation: Operation::AddMultipliedTotal,
+            }],
+            Self::Nausea => &[],
+            Self::NightVision => &[],
+            Self::Oozing => &[],
+            Self::Poison => &[],
+            Self::RaidOmen => &[],
+            Self::Regeneration => &[],
+            Self::Resistance => &[],
+            Self::Saturation => &[],
+            Self::SlowFalling => &[],
+            Self::Slowness => &[EffectModifier {
+                attribute: &Attribute::MOVEMENT_SPEED,
+                id: "minecraft:effect.slowness",
+                base_value: -0.15000000596046448,
+                operation: Operation::AddMultipliedTotal,
+            }],
+            Self::Speed => &[EffectModifier {
+                attribute: &Attribute::MOVEMENT_SPEED,
+                id: "minecraft:effect.speed",
+                base_value: 0.20000000298023224,
+                operation: Operation::AddMultipliedTotal,
+            }],
+            Self::Strength => &[EffectModifier {
+                attribute: &Attribute::ATTACK_DAMAGE,
+                id: "minecraft:effect.strength",
+                base_value: 3.0,
+                operation: Operation::AddValue,
+            }],
+            Self::TrialOmen => &[],
+            Self::Unluck => &[EffectModifier {
+                attribute: &Attribute::LUCK,
+                id: "minecraft:effect.unluck",
+                base_value: -1.0,
+                operation: Operation::AddValue,
+            }],
+            Self::WaterBreathing => &[],
+            Self::Weakness => &[EffectModifier {
+                attribute: &Attribute::ATTACK_DAMAGE,
+                id: "minecraft:effect.weakness",
+                base_value: -4.0,
+                operation: Operation::AddValue,
+            }],
+            Self::Weaving => &[],
+            Self::WindCharged => &[],
+            Self::Wither => &[],
+        }
+    }
+    #[doc = r" Get all effects as a slice."]
+    pub fn all() -> &'static [Self] {
+        &[
+            Self::Absorption,
+            Self::BadOmen,
+            Self::Blindness,
+            Self::ConduitPower,
+            Self::Darkness,
+            Self::DolphinsGrace,
+            Self::FireResistance,
+            Self::Glowing,
+            Self::Haste,
+            Self::HealthBoost,
+            Self::HeroOfTheVillage,
+            Self::Hunger,
+            Self::Infested,
+            Self::InstantDamage,
+            Self::InstantHealth,
+            Self::Invisibility,
+            Self::JumpBoost,
+            Self::Levitation,
+            Self::Luck,
+            Self::MiningFatigue,
+            Self::Nausea,
+            Self::NightVision,
+            Self::Oozing,
+            Self::Poison,
+            Self::RaidOmen,
+            Self::Regeneration,
+            Self::Resistance,
+            Self::Saturation,
+            Self::SlowFalling,
+            Self::Slowness,
+            Self::Speed,
+            Self::Strength,
+            Self::TrialOmen,
+            Self::Unluck,
+            Self::WaterBreathing,
+            Self::Weakness,
+            Self::Weaving,
+            Self::WindCharged,
+            Self::Wither,
+        ]
+    }
 }
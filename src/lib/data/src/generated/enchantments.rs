@@ -1,3 +1,5 @@
+use crate::attributes::Attribute;
+use crate::items::Operation;
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Enchantment {
     pub id: u16,
@@ -8,15 +10,81 @@ pub struct Enchantment {
     pub anvil_cost: u8,
     pub slots: &'static [EnchantmentSlot],
     pub supported_items: &'static str,
+    #[doc = r" The items the enchantment is offered for by enchanting tables, if they're fewer"]
+    #[doc = r" than the supported items."]
+    pub primary_items: Option<&'static str>,
     pub weight: u8,
     pub max_level: u8,
     pub exclusive_set: Option<&'static str>,
+    pub attribute_effects: &'static [AttributeEffect],
+    pub damage_protection: &'static [DamageProtection],
+    pub damage: &'static [DamageBonus],
 }
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Cost {
     pub base: f32,
     pub per_level_above_first: f32,
 }
+#[doc = r" An attribute modifier given by an enchantment, with an amount depending on its level."]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AttributeEffect {
+    pub id: &'static str,
+    pub attribute: &'static Attribute,
+    pub amount: LevelBasedValue,
+    pub operation: Operation,
+}
+#[doc = r" Protection given by an enchantment against damage, with an amount depending on its"]
+#[doc = r" level. It only applies to damage types that are in (or not in) each of the tags."]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DamageProtection {
+    pub amount: LevelBasedValue,
+    pub tags: &'static [(&'static str, bool)],
+}
+#[doc = r" Extra attack damage given by an enchantment, with an amount depending on its level. If"]
+#[doc = r" there's a requirement, it's the entity (`this` for the target, or `direct_attacker`)"]
+#[doc = r" and the entity type tag it has to be in."]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DamageBonus {
+    pub amount: LevelBasedValue,
+    pub requirement: Option<(&'static str, &'static str)>,
+}
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LevelBasedValue {
+    Linear {
+        base: f32,
+        per_level_above_first: f32,
+    },
+    LevelsSquared {
+        added: f32,
+    },
+    Fraction {
+        numerator: &'static LevelBasedValue,
+        denominator: &'static LevelBasedValue,
+    },
+}
+impl LevelBasedValue {
+    #[doc = r" Calculate the value for the given enchantment level."]
+    pub fn calculate(&self, level: u8) -> f32 {
+        match self {
+            Self::Linear {
+                base,
+                per_level_above_first,
+            } => base + per_level_above_first * (level as f32 - 1.0),
+            Self::LevelsSquared { added } => (level as f32).powi(2) + added,
+            Self::Fraction {
+                numerator,
+                denominator,
+            } => {
+                let denominator = denominator.calculate(level);
+                if denominator == 0.0 {
+                    0.0
+                } else {
+                    numerator.calculate(level) / denominator
+                }
+            }
+        }
+    }
+}
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum EnchantmentSlot {
     MAINHAND,
@@ -45,9 +113,21 @@ impl Enchantment {
         anvil_cost: 4,
         slots: &[EnchantmentSlot::HEAD],
         supported_items: "#minecraft:enchantable/head_armor",
+        primary_items: None,
         weight: 2,
         max_level: 1,
         exclusive_set: None,
+        attribute_effects: &[AttributeEffect {
+            id: "minecraft:enchantment.aqua_affinity",
+            attribute: &Attribute::SUBMERGED_MINING_SPEED,
+            amount: LevelBasedValue::Linear {
+                base: 4.0,
+                per_level_above_first: 4.0,
+            },
+            operation: Operation::AddMultipliedTotal,
+        }],
+        damage_protection: &[],
+        damage: &[],
     };
     pub const BANE_OF_ARTHROPODS: Enchantment = Enchantment {
         id: 1,
@@ -64,9 +144,19 @@ impl Enchantment {
         anvil_cost: 2,
         slots: &[EnchantmentSlot::MAINHAND],
         supported_items: "#minecraft:enchantable/weapon",
+        primary_items: Some("#minecraft:enchantable/sword"),
         weight: 5,
         max_level: 5,
         exclusive_set: Some("#minecraft:exclusive_set/damage"),
+        attribute_effects: &[],
+        damage_protection: &[],
+        damage: &[DamageBonus {
+            amount: LevelBasedValue::Linear {
+                base: 2.5,
+                per_level_above_first: 2.5,
+            },
+            requirement: Some(("this", "#minecraft:sensitive_to_bane_of_arthropods")),
+        }],
     };
     pub const BINDING_CURSE: Enchantment = Enchantment {
         id: 2,
@@ -83,9 +173,13 @@ impl Enchantment {
         anvil_cost: 8,
         slots: &[EnchantmentSlot::ARMOR],
         supported_items: "#minecraft:enchantable/equippable",
+        primary_items: None,
         weight: 1,
         max_level: 1,
         exclusive_set: None,
+        attribute_effects: &[],
+        damage_protection: &[],
+        damage: &[],
     };
     pub const BLAST_PROTECTION: Enchantment = Enchantment {
         id: 3,
@@ -102,9 +196,27 @@ impl Enchantment {
         anvil_cost: 4,
         slots: &[EnchantmentSlot::ARMOR],
         supported_items: "#minecraft:enchantable/armor",
+        primary_items: None,
         weight: 2,
         max_level: 4,
         exclusive_set: Some("#minecraft:exclusive_set/armor"),
+        attribute_effects: &[AttributeEffect {
+            id: "minecraft:enchantment.blast_protection",
+            attribute: &Attribute::EXPLOSION_KNOCKBACK_RESISTANCE,
+            amount: LevelBasedValue::Linear {
+                base: 0.15,
+                per_level_above_first: 0.15,
+            },
+            operation: Operation::AddValue,
+        }],
+        damage_protection: &[DamageProtection {
+            amount: LevelBasedValue::Linear {
+                base: 2.0,
+                per_level_above_first: 2.0,
+            },
+            tags: &[("is_explosion", true), ("bypasses_invulnerability", false)],
+        }],
+        damage: &[],
     };
     pub const BREACH: Enchantment = Enchantment {
         id: 4,
@@ -121,9 +233,13 @@ impl Enchantment {
         anvil_cost: 4,
         slots: &[EnchantmentSlot::MAINHAND],
         supported_items: "#minecraft:enchantable/mace",
+        primary_items: None,
         weight: 2,
         max_level: 4,
         exclusive_set: Some("#minecraft:exclusive_set/damage"),
+        attribute_effects: &[],
+        damage_protection: &[],
+        damage: &[],
     };
     pub const CHANNELING: Enchantment = Enchantment {
         id: 5,
@@ -140,9 +256,13 @@ impl Enchantment {
         anvil_cost: 8,
         slots: &[EnchantmentSlot::MAINHAND],
         supported_items: "#minecraft:enchantable/trident",
+        primary_items: None,
         weight: 1,
         max_level: 1,
         exclusive_set: None,
+        attribute_effects: &[],
+        damage_protection: &[],
+        damage: &[],
     };
     pub const DENSITY: Enchantment = Enchantment {
         id: 6,
@@ -159,9 +279,13 @@ impl Enchantment {
         anvil_cost: 2,
         slots: &[EnchantmentSlot::MAINHAND],
         supported_items: "#minecraft:enchantable/mace",
+        primary_items: None,
         weight: 5,
         max_level: 5,
         exclusive_set: Some("#minecraft:exclusive_set/damage"),
+        attribute_effects: &[],
+        damage_protection: &[],
+        damage: &[],
     };
     pub const DEPTH_STRIDER: Enchantment = Enchantment {
         id: 7,
@@ -178,9 +302,21 @@ impl Enchantment {
         anvil_cost: 4,
         slots: &[EnchantmentSlot::FEET],
         supported_items: "#minecraft:enchantable/foot_armor",
+        primary_items: None,
         weight: 2,
         max_level: 3,
         exclusive_set: Some("#minecraft:exclusive_set/boots"),
+        attribute_effects: &[AttributeEffect {
+            id: "minecraft:enchantment.depth_strider",
+            attribute: &Attribute::WATER_MOVEMENT_EFFICIENCY,
+            amount: LevelBasedValue::Linear {
+                base: 0.33333334,
+                per_level_above_first: 0.33333334,
+            },
+            operation: Operation::AddValue,
+        }],
+        damage_protection: &[],
+        damage: &[],
     };
     pub const EFFICIENCY: Enchantment = Enchantment {
         id: 8,
@@ -197,9 +333,18 @@ impl Enchantment {
         anvil_cost: 1,
         slots: &[EnchantmentSlot::MAINHAND],
         supported_items: "#minecraft:enchantable/mining",
+        primary_items: None,
         weight: 10,
         max_level: 5,
         exclusive_set: None,
+        attribute_effects: &[AttributeEffect {
+            id: "minecraft:enchantment.efficiency",
+            attribute: &Attribute::MINING_EFFICIENCY,
+            amount: LevelBasedValue::LevelsSquared { added: 1.0 },
+            operation: Operation::AddValue,
+        }],
+        damage_protection: &[],
+        damage: &[],
     };
     pub const FEATHER_FALLING: Enchantment = Enchantment {
         id: 9,
@@ -216,9 +361,19 @@ impl Enchantment {
         anvil_cost: 2,
         slots: &[EnchantmentSlot::ARMOR],
         supported_items: "#minecraft:enchantable/foot_armor",
+        primary_items: None,
         weight: 5,
         max_level: 4,
         exclusive_set: None,
+        attribute_effects: &[],
+        damage_protection: &[DamageProtection {
+            amount: LevelBasedValue::Linear {
+                base: 3.0,
+                per_level_above_first: 3.0,
+            },
+            tags: &[("is_fall", true), ("bypasses_invulnerability", false)],
+        }],
+        damage: &[],
     };
     pub const FIRE_ASPECT: Enchantment = Enchantment {
         id: 10,
@@ -235,9 +390,13 @@ impl Enchantment {
         anvil_cost: 4,
         slots: &[EnchantmentSlot::MAINHAND],
         supported_items: "#minecraft:enchantable/fire_aspect",
+        primary_items: Some("#minecraft:enchantable/sword"),
         weight: 2,
         max_level: 2,
         exclusive_set: None,
+        attribute_effects: &[],
+        damage_protection: &[],
+        damage: &[],
     };
     pub const FIRE_PROTECTION: Enchantment = Enchantment {
         id: 11,
@@ -254,9 +413,27 @@ impl Enchantment {
         anvil_cost: 2,
         slots: &[EnchantmentSlot::ARMOR],
         supported_items: "#minecraft:enchantable/armor",
+        primary_items: None,
         weight: 5,
         max_level: 4,
         exclusive_set: Some("#minecraft:exclusive_set/armor"),
+        attribute_effects: &[AttributeEffect {
+            id: "minecraft:enchantment.fire_protection",
+            attribute: &Attribute::BURNING_TIME,
+            amount: LevelBasedValue::Linear {
+                base: -0.15,
+                per_level_above_first: -0.15,
+            },
+            operation: Operation::AddMultipliedBase,
+        }],
+        damage_protection: &[DamageProtection {
+            amount: LevelBasedValue::Linear {
+                base: 2.0,
+                per_level_above_first: 2.0,
+            },
+            tags: &[("is_fire", true), ("bypasses_invulnerability", false)],
+        }],
+        damage: &[],
     };
     pub const FLAME: Enchantment = Enchantment {
         id: 12,
@@ -273,9 +450,13 @@ impl Enchantment {
         anvil_cost: 4,
         slots: &[EnchantmentSlot::MAINHAND],
         supported_items: "#minecraft:enchantable/bow",
+        primary_items: None,
         weight: 2,
         max_level: 1,
         exclusive_set: None,
+        attribute_effects: &[],
+        damage_protection: &[],
+        damage: &[],
     };
     pub const FORTUNE: Enchantment = Enchantment {
         id: 13,
@@ -292,9 +473,13 @@ impl Enchantment {
         anvil_cost: 4,
         slots: &[EnchantmentSlot::MAINHAND],
         supported_items: "#minecraft:enchantable/mining_loot",
+        primary_items: None,
         weight: 2,
         max_level: 3,
         exclusive_set: Some("#minecraft:exclusive_set/mining"),
+        attribute_effects: &[],
+        damage_protection: &[],
+        damage: &[],
     };
     pub const FROST_WALKER: Enchantment = Enchantment {
         id: 14,
@@ -311,9 +496,13 @@ impl Enchantment {
         anvil_cost: 4,
         slots: &[EnchantmentSlot::FEET],
         supported_items: "#minecraft:enchantable/foot_armor",
+        primary_items: None,
         weight: 2,
         max_level: 2,
         exclusive_set: Some("#minecraft:exclusive_set/boots"),
+        attribute_effects: &[],
+        damage_protection: &[],
+        damage: &[],
     };
     pub const IMPALING: Enchantment = Enchantment {
         id: 15,
@@ -330,9 +519,19 @@ impl Enchantment {
         anvil_cost: 4,
         slots: &[EnchantmentSlot::MAINHAND],
         supported_items: "#minecraft:enchantable/trident",
+        primary_items: None,
         weight: 2,
         max_level: 5,
         exclusive_set: Some("#minecraft:exclusive_set/damage"),
+        attribute_effects: &[],
+        damage_protection: &[],
+        damage: &[DamageBonus {
+            amount: LevelBasedValue::Linear {
+                base: 2.5,
+                per_level_above_first: 2.5,
+            },
+            requirement: Some(("this", "#minecraft:sensitive_to_impaling")),
+        }],
     };
     pub const INFINITY: Enchantment = Enchantment {
         id: 16,
@@ -349,9 +548,13 @@ impl Enchantment {
         anvil_cost: 8,
         slots: &[EnchantmentSlot::MAINHAND],
         supported_items: "#minecraft:enchantable/bow",
+        primary_items: None,
         weight: 1,
         max_level: 1,
         exclusive_set: Some("#minecraft:exclusive_set/bow"),
+        attribute_effects: &[],
+        damage_protection: &[],
+        damage: &[],
     };
     pub const KNOCKBACK: Enchantment = Enchantment {
         id: 17,
@@ -368,9 +571,13 @@ impl Enchantment {
         anvil_cost: 2,
         slots: &[EnchantmentSlot::MAINHAND],
         supported_items: "#minecraft:enchantable/sword",
+        primary_items: None,
         weight: 5,
         max_level: 2,
         exclusive_set: None,
+        attribute_effects: &[],
+        damage_protection: &[],
+        damage: &[],
     };
     pub const LOOTING: Enchantment = Enchantment {
         id: 18,
@@ -387,9 +594,13 @@ impl Enchantment {
         anvil_cost: 4,
         slots: &[EnchantmentSlot::MAINHAND],
         supported_items: "#minecraft:enchantable/sword",
+        primary_items: None,
         weight: 2,
         max_level: 3,
         exclusive_set: None,
+        attribute_effects: &[],
+        damage_protection: &[],
+        damage: &[],
     };
     pub const LOYALTY: Enchantment = Enchantment {
         id: 19,
@@ -406,9 +617,13 @@ impl Enchantment {
         anvil_cost: 2,
         slots: &[EnchantmentSlot::MAINHAND],
         supported_items: "#minecraft:enchantable/trident",
+        primary_items: None,
         weight: 5,
         max_level: 3,
         exclusive_set: None,
+        attribute_effects: &[],
+        damage_protection: &[],
+        damage: &[],
     };
     pub const LUCK_OF_THE_SEA: Enchantment = Enchantment {
         id: 20,
@@ -425,9 +640,13 @@ impl Enchantment {
         anvil_cost: 4,
         slots: &[EnchantmentSlot::MAINHAND],
         supported_items: "#minecraft:enchantable/fishing",
+        primary_items: None,
         weight: 2,
         max_level: 3,
         exclusive_set: None,
+        attribute_effects: &[],
+        damage_protection: &[],
+        damage: &[],
     };
     pub const LURE: Enchantment = Enchantment {
         id: 21,
@@ -444,9 +663,13 @@ impl Enchantment {
         anvil_cost: 4,
         slots: &[EnchantmentSlot::MAINHAND],
         supported_items: "#minecraft:enchantable/fishing",
+        primary_items: None,
         weight: 2,
         max_level: 3,
         exclusive_set: None,
+        attribute_effects: &[],
+        damage_protection: &[],
+        damage: &[],
     };
     pub const MENDING: Enchantment = Enchantment {
         id: 22,
@@ -463,9 +686,13 @@ impl Enchantment {
         anvil_cost: 4,
         slots: &[EnchantmentSlot::ANY],
         supported_items: "#minecraft:enchantable/durability",
+        primary_items: None,
         weight: 2,
         max_level: 1,
         exclusive_set: None,
+        attribute_effects: &[],
+        damage_protection: &[],
+        damage: &[],
     };
     pub const MULTISHOT: Enchantment = Enchantment {
         id: 23,
@@ -482,9 +709,13 @@ impl Enchantment {
         anvil_cost: 4,
         slots: &[EnchantmentSlot::MAINHAND],
         supported_items: "#minecraft:enchantable/crossbow",
+        primary_items: None,
         weight: 2,
         max_level: 1,
         exclusive_set: Some("#minecraft:exclusive_set/crossbow"),
+        attribute_effects: &[],
+        damage_protection: &[],
+        damage: &[],
     };
     pub const PIERCING: Enchantment = Enchantment {
         id: 24,
@@ -501,9 +732,13 @@ impl Enchantment {
         anvil_cost: 1,
         slots: &[EnchantmentSlot::MAINHAND],
         supported_items: "#minecraft:enchantable/crossbow",
+        primary_items: None,
         weight: 10,
         max_level: 4,
         exclusive_set: Some("#minecraft:exclusive_set/crossbow"),
+        attribute_effects: &[],
+        damage_protection: &[],
+        damage: &[],
     };
     pub const POWER: Enchantment = Enchantment {
         id: 25,
@@ -520,9 +755,19 @@ impl Enchantment {
         anvil_cost: 1,
         slots: &[EnchantmentSlot::MAINHAND],
         supported_items: "#minecraft:enchantable/bow",
+        primary_items: None,
         weight: 10,
         max_level: 5,
         exclusive_set: None,
+        attribute_effects: &[],
+        damage_protection: &[],
+        damage: &[DamageBonus {
+            amount: LevelBasedValue::Linear {
+                base: 1.0,
+                per_level_above_first: 0.5,
+            },
+            requirement: Some(("direct_attacker", "#minecraft:arrows")),
+        }],
     };
     pub const PROJECTILE_PROTECTION: Enchantment = Enchantment {
         id: 26,
@@ -539,9 +784,19 @@ impl Enchantment {
         anvil_cost: 2,
         slots: &[EnchantmentSlot::ARMOR],
         supported_items: "#minecraft:enchantable/armor",
+        primary_items: None,
         weight: 5,
         max_level: 4,
         exclusive_set: Some("#minecraft:exclusive_set/armor"),
+        attribute_effects: &[],
+        damage_protection: &[DamageProtection {
+            amount: LevelBasedValue::Linear {
+                base: 2.0,
+                per_level_above_first: 2.0,
+            },
+            tags: &[("is_projectile", true), ("bypasses_invulnerability", false)],
+        }],
+        damage: &[],
     };
     pub const PROTECTION: Enchantment = Enchantment {
         id: 27,
@@ -558,9 +813,19 @@ impl Enchantment {
         anvil_cost: 1,
         slots: &[EnchantmentSlot::ARMOR],
         supported_items: "#minecraft:enchantable/armor",
+        primary_items: None,
         weight: 10,
         max_level: 4,
         exclusive_set: Some("#minecraft:exclusive_set/armor"),
+        attribute_effects: &[],
+        damage_protection: &[DamageProtection {
+            amount: LevelBasedValue::Linear {
+                base: 1.0,
+                per_level_above_first: 1.0,
+            },
+            tags: &[("bypasses_invulnerability", false)],
+        }],
+        damage: &[],
     };
     pub const PUNCH: Enchantment = Enchantment {
         id: 28,
@@ -577,9 +842,13 @@ impl Enchantment {
         anvil_cost: 4,
         slots: &[EnchantmentSlot::MAINHAND],
         supported_items: "#minecraft:enchantable/bow",
+        primary_items: None,
         weight: 2,
         max_level: 2,
         exclusive_set: None,
+        attribute_effects: &[],
+        damage_protection: &[],
+        damage: &[],
     };
     pub const QUICK_CHARGE: Enchantment = Enchantment {
         id: 29,
@@ -596,9 +865,13 @@ impl Enchantment {
         anvil_cost: 2,
         slots: &[EnchantmentSlot::MAINHAND, EnchantmentSlot::OFFHAND],
         supported_items: "#minecraft:enchantable/crossbow",
+        primary_items: None,
         weight: 5,
         max_level: 3,
         exclusive_set: None,
+        attribute_effects: &[],
+        damage_protection: &[],
+        damage: &[],
     };
     pub const RESPIRATION: Enchantment = Enchantment {
         id: 30,
@@ -615,9 +888,21 @@ impl Enchantment {
         anvil_cost: 4,
         slots: &[EnchantmentSlot::HEAD],
         supported_items: "#minecraft:enchantable/head_armor",
+        primary_items: None,
         weight: 2,
         max_level: 3,
         exclusive_set: None,
+        attribute_effects: &[AttributeEffect {
+            id: "minecraft:enchantment.respiration",
+            attribute: &Attribute::OXYGEN_BONUS,
+            amount: LevelBasedValue::Linear {
+                base: 1.0,
+                per_level_above_first: 1.0,
+            },
+            operation: Operation::AddValue,
+        }],
+        damage_protection: &[],
+        damage: &[],
     };
     pub const RIPTIDE: Enchantment = Enchantment {
         id: 31,
@@ -634,9 +919,13 @@ impl Enchantment {
         anvil_cost: 4,
         slots: &[EnchantmentSlot::HAND],
         supported_items: "#minecraft:enchantable/trident",
+        primary_items: None,
         weight: 2,
         max_level: 3,
         exclusive_set: Some("#minecraft:exclusive_set/riptide"),
+        attribute_effects: &[],
+        damage_protection: &[],
+        damage: &[],
     };
     pub const SHARPNESS: Enchantment = Enchantment {
         id: 32,
@@ -653,9 +942,19 @@ impl Enchantment {
         anvil_cost: 1,
         slots: &[EnchantmentSlot::MAINHAND],
         supported_items: "#minecraft:enchantable/sharp_weapon",
+        primary_items: Some("#minecraft:enchantable/sword"),
         weight: 10,
         max_level: 5,
         exclusive_set: Some("#minecraft:exclusive_set/damage"),
+        attribute_effects: &[],
+        damage_protection: &[],
+        damage: &[DamageBonus {
+            amount: LevelBasedValue::Linear {
+                base: 1.0,
+                per_level_above_first: 0.5,
+            },
+            requirement: None,
+        }],
     };
     pub const SILK_TOUCH: Enchantment = Enchantment {
         id: 33,
@@ -672,9 +971,13 @@ impl Enchantment {
         anvil_cost: 8,
         slots: &[EnchantmentSlot::MAINHAND],
         supported_items: "#minecraft:enchantable/mining_loot",
+        primary_items: None,
         weight: 1,
         max_level: 1,
         exclusive_set: Some("#minecraft:exclusive_set/mining"),
+        attribute_effects: &[],
+        damage_protection: &[],
+        damage: &[],
     };
     pub const SMITE: Enchantment = Enchantment {
         id: 34,
@@ -691,9 +994,19 @@ impl Enchantment {
         anvil_cost: 2,
         slots: &[EnchantmentSlot::MAINHAND],
         supported_items: "#minecraft:enchantable/weapon",
+        primary_items: Some("#minecraft:enchantable/sword"),
         weight: 5,
         max_level: 5,
         exclusive_set: Some("#minecraft:exclusive_set/damage"),
+        attribute_effects: &[],
+        damage_protection: &[],
+        damage: &[DamageBonus {
+            amount: LevelBasedValue::Linear {
+                base: 2.5,
+                per_level_above_first: 2.5,
+            },
+            requirement: Some(("this", "#minecraft:sensitive_to_smite")),
+        }],
     };
     pub const SOUL_SPEED: Enchantment = Enchantment {
         id: 35,
@@ -710,9 +1023,13 @@ impl Enchantment {
         anvil_cost: 8,
         slots: &[EnchantmentSlot::FEET],
         supported_items: "#minecraft:enchantable/foot_armor",
+        primary_items: None,
         weight: 1,
         max_level: 3,
         exclusive_set: None,
+        attribute_effects: &[],
+        damage_protection: &[],
+        damage: &[],
     };
     pub const SWEEPING_EDGE: Enchantment = Enchantment {
         id: 36,
@@ -729,9 +1046,27 @@ impl Enchantment {
         anvil_cost: 4,
         slots: &[EnchantmentSlot::MAINHAND],
         supported_items: "#minecraft:enchantable/sword",
+        primary_items: None,
         weight: 2,
         max_level: 3,
         exclusive_set: None,
+        attribute_effects: &[AttributeEffect {
+            id: "minecraft:enchantment.sweeping_edge",
+            attribute: &Attribute::SWEEPING_DAMAGE_RATIO,
+            amount: LevelBasedValue::Fraction {
+                numerator: &LevelBasedValue::Linear {
+                    base: 1.0,
+                    per_level_above_first: 1.0,
+                },
+                denominator: &LevelBasedValue::Linear {
+                    base: 2.0,
+                    per_level_above_first: 1.0,
+                },
+            },
+            operation: Operation::AddValue,
+        }],
+        damage_protection: &[],
+        damage: &[],
     };
     pub const SWIFT_SNEAK: Enchantment = Enchantment {
         id: 37,
@@ -748,9 +1083,21 @@ impl Enchantment {
         anvil_cost: 8,
         slots: &[EnchantmentSlot::LEGS],
         supported_items: "#minecraft:enchantable/leg_armor",
+        primary_items: None,
         weight: 1,
         max_level: 3,
         exclusive_set: None,
+        attribute_effects: &[AttributeEffect {
+            id: "minecraft:enchantment.swift_sneak",
+            attribute: &Attribute::SNEAKING_SPEED,
+            amount: LevelBasedValue::Linear {
+                base: 0.15,
+                per_level_above_first: 0.15,
+            },
+            operation: Operation::AddValue,
+        }],
+        damage_protection: &[],
+        damage: &[],
     };
     pub const THORNS: Enchantment = Enchantment {
         id: 38,
@@ -767,9 +1114,13 @@ impl Enchantment {
         anvil_cost: 8,
         slots: &[EnchantmentSlot::ANY],
         supported_items: "#minecraft:enchantable/armor",
+        primary_items: Some("#minecraft:enchantable/chest_armor"),
         weight: 1,
         max_level: 3,
         exclusive_set: None,
+        attribute_effects: &[],
+        damage_protection: &[],
+        damage: &[],
     };
     pub const UNBREAKING: Enchantment = Enchantment {
         id: 39,
@@ -786,9 +1137,13 @@ impl Enchantment {
         anvil_cost: 2,
         slots: &[EnchantmentSlot::ANY],
         supported_items: "#minecraft:enchantable/durability",
+        primary_items: None,
         weight: 5,
         max_level: 3,
         exclusive_set: None,
+        attribute_effects: &[],
+        damage_protection: &[],
+        damage: &[],
     };
     pub const VANISHING_CURSE: Enchantment = Enchantment {
         id: 40,
@@ -805,9 +1160,13 @@ impl Enchantment {
         anvil_cost: 8,
         slots: &[EnchantmentSlot::ANY],
         supported_items: "#minecraft:enchantable/vanishing",
+        primary_items: None,
         weight: 1,
         max_level: 1,
         exclusive_set: None,
+        attribute_effects: &[],
+        damage_protection: &[],
+        damage: &[],
     };
     pub const WIND_BURST: Enchantment = Enchantment {
         id: 41,
@@ -824,14 +1183,18 @@ impl Enchantment {
         anvil_cost: 4,
         slots: &[EnchantmentSlot::MAINHAND],
         supported_items: "#minecraft:enchantable/mace",
+        primary_items: None,
         weight: 2,
         max_level: 3,
         exclusive_set: None,
+        attribute_effects: &[],
+        damage_protection: &[],
+        damage: &[],
     };
     #[doc = r" Try to parse an `Enchantment` from a resource location string."]
     pub fn from_name(name: &str) -> Option<&'static Self> {
         let name = name.strip_prefix("minecraft:").unwrap_or(name);
-        match name {
+        match format!("minecraft:{name}").as_str() {
             "minecraft:aqua_affinity" => Some(&Self::AQUA_AFFINITY),
             "minecraft:bane_of_arthropods" => Some(&Self::BANE_OF_ARTHROPODS),
             "minecraft:binding_curse" => Some(&Self::BINDING_CURSE),
@@ -2844,6 +2844,7 @@ impl Item {
                     attribute_modifiers: &[],
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 1 }),
         ],
     };
     pub const BOOKSHELF: Item = Item {
@@ -2903,6 +2904,7 @@ impl Item {
                     attribute_modifiers: &[],
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 1 }),
         ],
     };
     pub const BOWL: Item = Item {
@@ -4124,6 +4126,13 @@ impl Item {
                     ],
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 12 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:repairs_chain_armor",
+                },
+            ),
             (
                 DataComponent::Equippable,
                 &EquippableImpl {
@@ -4175,6 +4184,13 @@ impl Item {
                     ],
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 12 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:repairs_chain_armor",
+                },
+            ),
             (
                 DataComponent::Equippable,
                 &EquippableImpl {
@@ -4226,6 +4242,13 @@ impl Item {
                     ],
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 12 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:repairs_chain_armor",
+                },
+            ),
             (
                 DataComponent::Equippable,
                 &EquippableImpl {
@@ -4277,6 +4300,13 @@ impl Item {
                     ],
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 12 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:repairs_chain_armor",
+                },
+            ),
             (
                 DataComponent::Equippable,
                 &EquippableImpl {
@@ -6597,6 +6627,7 @@ impl Item {
                     attribute_modifiers: &[],
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 1 }),
         ],
     };
     pub const CRYING_OBSIDIAN: Item = Item {
@@ -8282,6 +8313,13 @@ impl Item {
                     can_destroy_blocks_in_creative: true,
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 10 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:diamond_tool_materials",
+                },
+            ),
         ],
     };
     pub const DIAMOND_BLOCK: Item = Item {
@@ -8337,6 +8375,13 @@ impl Item {
                     ],
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 10 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:repairs_diamond_armor",
+                },
+            ),
             (
                 DataComponent::Equippable,
                 &EquippableImpl {
@@ -8388,6 +8433,13 @@ impl Item {
                     ],
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 10 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:repairs_diamond_armor",
+                },
+            ),
             (
                 DataComponent::Equippable,
                 &EquippableImpl {
@@ -8439,6 +8491,13 @@ impl Item {
                     ],
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 10 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:repairs_diamond_armor",
+                },
+            ),
             (
                 DataComponent::Equippable,
                 &EquippableImpl {
@@ -8513,6 +8572,13 @@ impl Item {
                     can_destroy_blocks_in_creative: true,
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 10 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:diamond_tool_materials",
+                },
+            ),
         ],
     };
     pub const DIAMOND_HORSE_ARMOR: Item = Item {
@@ -8598,6 +8664,13 @@ impl Item {
                     ],
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 10 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:repairs_diamond_armor",
+                },
+            ),
             (
                 DataComponent::Equippable,
                 &EquippableImpl {
@@ -8691,6 +8764,13 @@ impl Item {
                     can_destroy_blocks_in_creative: true,
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 10 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:diamond_tool_materials",
+                },
+            ),
         ],
     };
     pub const DIAMOND_SHOVEL: Item = Item {
@@ -8750,6 +8830,13 @@ impl Item {
                     can_destroy_blocks_in_creative: true,
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 10 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:diamond_tool_materials",
+                },
+            ),
         ],
     };
     pub const DIAMOND_SWORD: Item = Item {
@@ -8814,6 +8901,13 @@ impl Item {
                     can_destroy_blocks_in_creative: false,
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 10 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:diamond_tool_materials",
+                },
+            ),
         ],
     };
     pub const DIORITE: Item = Item {
@@ -9307,6 +9401,12 @@ impl Item {
                     attribute_modifiers: &[],
                 },
             ),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "minecraft:phantom_membrane",
+                },
+            ),
             (
                 DataComponent::Equippable,
                 &EquippableImpl {
@@ -10231,6 +10331,7 @@ impl Item {
                     attribute_modifiers: &[],
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 1 }),
         ],
     };
     pub const FLETCHING_TABLE: Item = Item {
@@ -11039,6 +11140,13 @@ impl Item {
                     can_destroy_blocks_in_creative: true,
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 22 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:gold_tool_materials",
+                },
+            ),
         ],
     };
     pub const GOLDEN_BOOTS: Item = Item {
@@ -11075,6 +11183,13 @@ impl Item {
                     ],
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 25 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:repairs_gold_armor",
+                },
+            ),
             (
                 DataComponent::Equippable,
                 &EquippableImpl {
@@ -11159,6 +11274,13 @@ impl Item {
                     ],
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 25 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:repairs_gold_armor",
+                },
+            ),
             (
                 DataComponent::Equippable,
                 &EquippableImpl {
@@ -11210,6 +11332,13 @@ impl Item {
                     ],
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 25 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:repairs_gold_armor",
+                },
+            ),
             (
                 DataComponent::Equippable,
                 &EquippableImpl {
@@ -11281,6 +11410,13 @@ impl Item {
                     can_destroy_blocks_in_creative: true,
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 22 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:gold_tool_materials",
+                },
+            ),
         ],
     };
     pub const GOLDEN_HORSE_ARMOR: Item = Item {
@@ -11366,6 +11502,13 @@ impl Item {
                     ],
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 25 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:repairs_gold_armor",
+                },
+            ),
             (
                 DataComponent::Equippable,
                 &EquippableImpl {
@@ -11437,6 +11580,13 @@ impl Item {
                     can_destroy_blocks_in_creative: true,
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 22 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:gold_tool_materials",
+                },
+            ),
         ],
     };
     pub const GOLDEN_SHOVEL: Item = Item {
@@ -11493,6 +11643,13 @@ impl Item {
                     can_destroy_blocks_in_creative: true,
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 22 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:gold_tool_materials",
+                },
+            ),
         ],
     };
     pub const GOLDEN_SWORD: Item = Item {
@@ -11554,6 +11711,13 @@ impl Item {
                     can_destroy_blocks_in_creative: false,
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 22 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:gold_tool_materials",
+                },
+            ),
         ],
     };
     pub const GRANITE: Item = Item {
@@ -13052,6 +13216,13 @@ impl Item {
                     can_destroy_blocks_in_creative: true,
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 14 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:iron_tool_materials",
+                },
+            ),
         ],
     };
     pub const IRON_BARS: Item = Item {
@@ -13126,6 +13297,13 @@ impl Item {
                     ],
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 9 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:repairs_iron_armor",
+                },
+            ),
             (
                 DataComponent::Equippable,
                 &EquippableImpl {
@@ -13177,6 +13355,13 @@ impl Item {
                     ],
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 9 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:repairs_iron_armor",
+                },
+            ),
             (
                 DataComponent::Equippable,
                 &EquippableImpl {
@@ -13266,6 +13451,13 @@ impl Item {
                     ],
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 9 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:repairs_iron_armor",
+                },
+            ),
             (
                 DataComponent::Equippable,
                 &EquippableImpl {
@@ -13337,6 +13529,13 @@ impl Item {
                     can_destroy_blocks_in_creative: true,
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 14 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:iron_tool_materials",
+                },
+            ),
         ],
     };
     pub const IRON_HORSE_ARMOR: Item = Item {
@@ -13441,6 +13640,13 @@ impl Item {
                     ],
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 9 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:repairs_iron_armor",
+                },
+            ),
             (
                 DataComponent::Equippable,
                 &EquippableImpl {
@@ -13550,6 +13756,13 @@ impl Item {
                     can_destroy_blocks_in_creative: true,
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 14 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:iron_tool_materials",
+                },
+            ),
         ],
     };
     pub const IRON_SHOVEL: Item = Item {
@@ -13606,6 +13819,13 @@ impl Item {
                     can_destroy_blocks_in_creative: true,
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 14 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:iron_tool_materials",
+                },
+            ),
         ],
     };
     pub const IRON_SWORD: Item = Item {
@@ -13667,6 +13887,13 @@ impl Item {
                     can_destroy_blocks_in_creative: false,
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 14 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:iron_tool_materials",
+                },
+            ),
         ],
     };
     pub const IRON_TRAPDOOR: Item = Item {
@@ -14368,6 +14595,13 @@ impl Item {
                     ],
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 15 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:repairs_leather_armor",
+                },
+            ),
             (
                 DataComponent::Equippable,
                 &EquippableImpl {
@@ -14419,6 +14653,13 @@ impl Item {
                     ],
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 15 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:repairs_leather_armor",
+                },
+            ),
             (
                 DataComponent::Equippable,
                 &EquippableImpl {
@@ -14470,6 +14711,13 @@ impl Item {
                     ],
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 15 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:repairs_leather_armor",
+                },
+            ),
             (
                 DataComponent::Equippable,
                 &EquippableImpl {
@@ -14570,6 +14818,13 @@ impl Item {
                     ],
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 15 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:repairs_leather_armor",
+                },
+            ),
             (
                 DataComponent::Equippable,
                 &EquippableImpl {
@@ -15803,6 +16058,13 @@ impl Item {
                     can_destroy_blocks_in_creative: false,
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 15 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "minecraft:breeze_rod",
+                },
+            ),
         ],
     };
     pub const MAGENTA_BANNER: Item = Item {
@@ -18023,6 +18285,13 @@ impl Item {
                     can_destroy_blocks_in_creative: true,
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 15 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:netherite_tool_materials",
+                },
+            ),
         ],
     };
     pub const NETHERITE_BLOCK: Item = Item {
@@ -18085,6 +18354,13 @@ impl Item {
                     ],
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 15 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:repairs_netherite_armor",
+                },
+            ),
             (
                 DataComponent::Equippable,
                 &EquippableImpl {
@@ -18143,6 +18419,13 @@ impl Item {
                     ],
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 15 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:repairs_netherite_armor",
+                },
+            ),
             (
                 DataComponent::Equippable,
                 &EquippableImpl {
@@ -18201,6 +18484,13 @@ impl Item {
                     ],
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 15 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:repairs_netherite_armor",
+                },
+            ),
             (
                 DataComponent::Equippable,
                 &EquippableImpl {
@@ -18275,6 +18565,13 @@ impl Item {
                     can_destroy_blocks_in_creative: true,
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 15 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:netherite_tool_materials",
+                },
+            ),
         ],
     };
     pub const NETHERITE_INGOT: Item = Item {
@@ -18337,6 +18634,13 @@ impl Item {
                     ],
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 15 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:repairs_netherite_armor",
+                },
+            ),
             (
                 DataComponent::Equippable,
                 &EquippableImpl {
@@ -18411,6 +18715,13 @@ impl Item {
                     can_destroy_blocks_in_creative: true,
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 15 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:netherite_tool_materials",
+                },
+            ),
         ],
     };
     pub const NETHERITE_SCRAP: Item = Item {
@@ -18489,6 +18800,13 @@ impl Item {
                     can_destroy_blocks_in_creative: true,
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 15 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:netherite_tool_materials",
+                },
+            ),
         ],
     };
     pub const NETHERITE_SWORD: Item = Item {
@@ -18553,6 +18871,13 @@ impl Item {
                     can_destroy_blocks_in_creative: false,
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 15 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:netherite_tool_materials",
+                },
+            ),
         ],
     };
     pub const NETHERITE_UPGRADE_SMITHING_TEMPLATE: Item = Item {
@@ -24223,6 +24548,12 @@ impl Item {
                 },
             ),
             (DataComponent::BlocksAttacks, &BlocksAttacksImpl),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:wooden_tool_materials",
+                },
+            ),
             (
                 DataComponent::Equippable,
                 &EquippableImpl {
@@ -25754,6 +26085,13 @@ impl Item {
                     can_destroy_blocks_in_creative: true,
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 5 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:stone_tool_materials",
+                },
+            ),
         ],
     };
     pub const STONE_BRICK_SLAB: Item = Item {
@@ -25905,6 +26243,13 @@ impl Item {
                     can_destroy_blocks_in_creative: true,
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 5 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:stone_tool_materials",
+                },
+            ),
         ],
     };
     pub const STONE_PICKAXE: Item = Item {
@@ -25961,6 +26306,13 @@ impl Item {
                     can_destroy_blocks_in_creative: true,
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 5 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:stone_tool_materials",
+                },
+            ),
         ],
     };
     pub const STONE_PRESSURE_PLATE: Item = Item {
@@ -26036,6 +26388,13 @@ impl Item {
                     can_destroy_blocks_in_creative: true,
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 5 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:stone_tool_materials",
+                },
+            ),
         ],
     };
     pub const STONE_SLAB: Item = Item {
@@ -26135,6 +26494,13 @@ impl Item {
                     can_destroy_blocks_in_creative: false,
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 5 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:stone_tool_materials",
+                },
+            ),
         ],
     };
     pub const STONECUTTER: Item = Item {
@@ -27292,6 +27658,7 @@ impl Item {
                     can_destroy_blocks_in_creative: false,
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 1 }),
         ],
     };
     pub const TRIPWIRE_HOOK: Item = Item {
@@ -27646,6 +28013,13 @@ impl Item {
                     ],
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 9 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:repairs_turtle_helmet",
+                },
+            ),
             (
                 DataComponent::Equippable,
                 &EquippableImpl {
@@ -29764,6 +30138,12 @@ impl Item {
                     ],
                 },
             ),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:repairs_wolf_armor",
+                },
+            ),
             (
                 DataComponent::Equippable,
                 &EquippableImpl {
@@ -29854,6 +30234,13 @@ impl Item {
                     can_destroy_blocks_in_creative: true,
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 15 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:wooden_tool_materials",
+                },
+            ),
         ],
     };
     pub const WOODEN_HOE: Item = Item {
@@ -29910,6 +30297,13 @@ impl Item {
                     can_destroy_blocks_in_creative: true,
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 15 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:wooden_tool_materials",
+                },
+            ),
         ],
     };
     pub const WOODEN_PICKAXE: Item = Item {
@@ -29966,6 +30360,13 @@ impl Item {
                     can_destroy_blocks_in_creative: true,
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 15 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:wooden_tool_materials",
+                },
+            ),
         ],
     };
     pub const WOODEN_SHOVEL: Item = Item {
@@ -30022,6 +30423,13 @@ impl Item {
                     can_destroy_blocks_in_creative: true,
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 15 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:wooden_tool_materials",
+                },
+            ),
         ],
     };
     pub const WOODEN_SWORD: Item = Item {
@@ -30083,6 +30491,13 @@ impl Item {
                     can_destroy_blocks_in_creative: false,
                 },
             ),
+            (DataComponent::Enchantable, &EnchantableImpl { value: 15 }),
+            (
+                DataComponent::Repairable,
+                &RepairableImpl {
+                    items: "#minecraft:wooden_tool_materials",
+                },
+            ),
         ],
     };
     pub const WRITABLE_BOOK: Item = Item {
@@ -30586,6 +31001,97 @@ impl Item {
             .unwrap_or("Unknown")
             .to_string()
     }
+    #[doc = "How many of this item fit in a single stack."]
+    pub fn max_stack_size(&self) -> u8 {
+        self.components
+            .iter()
+            .find_map(|(id, data)| {
+                if id == &DataComponent::MaxStackSize {
+                    Some(
+                        data.as_any()
+                            .downcast_ref::<MaxStackSizeImpl>()
+                            .unwrap()
+                            .size,
+                    )
+                } else {
+                    None
+                }
+            })
+            .unwrap_or(64)
+    }
+    #[doc = "How much damage this item can take before it breaks, if it can be damaged."]
+    pub fn max_damage(&self) -> Option<u16> {
+        self.components.iter().find_map(|(id, data)| {
+            if id == &DataComponent::MaxDamage {
+                Some(
+                    data.as_any()
+                        .downcast_ref::<MaxDamageImpl>()
+                        .unwrap()
+                        .max_damage,
+                )
+            } else {
+                None
+            }
+        })
+    }
+    #[doc = "The attribute modifiers the item gives while it's equipped."]
+    pub fn attribute_modifiers(&self) -> &'static [Modifier] {
+        self.components
+            .iter()
+            .find_map(|(id, data)| {
+                if id == &DataComponent::AttributeModifiers {
+                    Some(
+                        data.as_any()
+                            .downcast_ref::<AttributeModifiersImpl>()
+                            .unwrap()
+                            .attribute_modifiers,
+                    )
+                } else {
+                    None
+                }
+            })
+            .unwrap_or(&[])
+    }
+    #[doc = "The mining rules of the item, if it's a tool."]
+    pub fn tool(&self) -> Option<&'static ToolImpl> {
+        self.components.iter().find_map(|(id, data)| {
+            if id == &DataComponent::Tool {
+                data.as_any().downcast_ref::<ToolImpl>()
+            } else {
+                None
+            }
+        })
+    }
+    #[doc = "How good the enchantments the item gets from an enchanting table are, if it can be enchanted there."]
+    pub fn enchantability(&self) -> Option<u8> {
+        self.components.iter().find_map(|(id, data)| {
+            if id == &DataComponent::Enchantable {
+                Some(
+                    data.as_any()
+                        .downcast_ref::<EnchantableImpl>()
+                        .unwrap()
+                        .value,
+                )
+            } else {
+                None
+            }
+        })
+    }
+    #[doc = "The item or item tag that repairs the item in an anvil, if it can be repaired."]
+    pub fn repair_items(&self) -> Option<&'static str> {
+        self.components.iter().find_map(|(id, data)| {
+            if id == &DataComponent::Repairable {
+                Some(
+                    data.as_any()
+                        .downcast_ref::<RepairableImpl>()
+                        .unwrap()
+                        .items,
+                )
+            } else {
+                None
+            }
+        })
+    }
     #[doc = "Try to parse an item from a resource location string."]
     pub fn from_registry_key(name: &str) -> Option<&'static Self> {
         let name = name.strip_prefix("minecraft:").unwrap_or(name);
@@ -33502,20 +34008,314 @@ impl Item {
         }
     }
 }
+#[doc = "The data components from the `minecraft:data_component_type` registry, with their protocol IDs."]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum DataComponent {
-    MaxStackSize,
-    JukeboxPlayable,
-    ItemName,
-    Damage,
-    MaxDamage,
-    AttributeModifiers,
-    Tool,
-    Food,
-    Equippable,
-    Consumable,
-    BlocksAttacks,
-    DeathProtection,
+    CustomData = 0,
+    MaxStackSize = 1,
+    MaxDamage = 2,
+    Damage = 3,
+    Unbreakable = 4,
+    CustomName = 5,
+    ItemName = 6,
+    ItemModel = 7,
+    Lore = 8,
+    Rarity = 9,
+    Enchantments = 10,
+    CanPlaceOn = 11,
+    CanBreak = 12,
+    AttributeModifiers = 13,
+    CustomModelData = 14,
+    TooltipDisplay = 15,
+    RepairCost = 16,
+    CreativeSlotLock = 17,
+    EnchantmentGlintOverride = 18,
+    IntangibleProjectile = 19,
+    Food = 20,
+    Consumable = 21,
+    UseRemainder = 22,
+    UseCooldown = 23,
+    DamageResistant = 24,
+    Tool = 25,
+    Weapon = 26,
+    Enchantable = 27,
+    Equippable = 28,
+    Repairable = 29,
+    Glider = 30,
+    TooltipStyle = 31,
+    DeathProtection = 32,
+    BlocksAttacks = 33,
+    StoredEnchantments = 34,
+    DyedColor = 35,
+    MapColor = 36,
+    MapId = 37,
+    MapDecorations = 38,
+    MapPostProcessing = 39,
+    ChargedProjectiles = 40,
+    BundleContents = 41,
+    PotionContents = 42,
+    PotionDurationScale = 43,
+    SuspiciousStewEffects = 44,
+    WritableBookContent = 45,
+    WrittenBookContent = 46,
+    Trim = 47,
+    DebugStickState = 48,
+    EntityData = 49,
+    BucketEntityData = 50,
+    BlockEntityData = 51,
+    Instrument = 52,
+    ProvidesTrimMaterial = 53,
+    OminousBottleAmplifier = 54,
+    JukeboxPlayable = 55,
+    ProvidesBannerPatterns = 56,
+    Recipes = 57,
+    LodestoneTracker = 58,
+    FireworkExplosion = 59,
+    Fireworks = 60,
+    Profile = 61,
+    NoteBlockSound = 62,
+    BannerPatterns = 63,
+    BaseColor = 64,
+    PotDecorations = 65,
+    Container = 66,
+    BlockState = 67,
+    Bees = 68,
+    Lock = 69,
+    ContainerLoot = 70,
+    BreakSound = 71,
+    VillagerVariant = 72,
+    WolfVariant = 73,
+    WolfSoundVariant = 74,
+    WolfCollar = 75,
+    FoxVariant = 76,
+    SalmonSize = 77,
+    ParrotVariant = 78,
+    TropicalFishPattern = 79,
+    TropicalFishBaseColor = 80,
+    TropicalFishPatternColor = 81,
+    MooshroomVariant = 82,
+    RabbitVariant = 83,
+    PigVariant = 84,
+    CowVariant = 85,
+    ChickenVariant = 86,
+    FrogVariant = 87,
+    HorseVariant = 88,
+    PaintingVariant = 89,
+    LlamaVariant = 90,
+    AxolotlVariant = 91,
+    CatVariant = 92,
+    CatCollar = 93,
+    SheepColor = 94,
+    ShulkerColor = 95,
+}
+impl DataComponent {
+    #[doc = "The protocol ID of the component."]
+    pub const fn id(self) -> i32 {
+        self as i32
+    }
+    #[doc = "Try to get a component from its protocol ID."]
+    pub const fn from_id(id: i32) -> Option<Self> {
+        match id {
+            0 => Some(Self::CustomData),
+            1 => Some(Self::MaxStackSize),
+            2 => Some(Self::MaxDamage),
+            3 => Some(Self::Damage),
+            4 => Some(Self::Unbreakable),
+            5 => Some(Self::CustomName),
+            6 => Some(Self::ItemName),
+            7 => Some(Self::ItemModel),
+            8 => Some(Self::Lore),
+            9 => Some(Self::Rarity),
+            10 => Some(Self::Enchantments),
+            11 => Some(Self::CanPlaceOn),
+            12 => Some(Self::CanBreak),
+            13 => Some(Self::AttributeModifiers),
+            14 => Some(Self::CustomModelData),
+            15 => Some(Self::TooltipDisplay),
+            16 => Some(Self::RepairCost),
+            17 => Some(Self::CreativeSlotLock),
+            18 => Some(Self::EnchantmentGlintOverride),
+            19 => Some(Self::IntangibleProjectile),
+            20 => Some(Self::Food),
+            21 => Some(Self::Consumable),
+            22 => Some(Self::UseRemainder),
+            23 => Some(Self::UseCooldown),
+            24 => Some(Self::DamageResistant),
+            25 => Some(Self::Tool),
+            26 => Some(Self::Weapon),
+            27 => Some(Self::Enchantable),
+            28 => Some(Self::Equippable),
+            29 => Some(Self::Repairable),
+            30 => Some(Self::Glider),
+            31 => Some(Self::TooltipStyle),
+            32 => Some(Self::DeathProtection),
+            33 => Some(Self::BlocksAttacks),
+            34 => Some(Self::StoredEnchantments),
+            35 => Some(Self::DyedColor),
+            36 => Some(Self::MapColor),
+            37 => Some(Self::MapId),
+            38 => Some(Self::MapDecorations),
+            39 => Some(Self::MapPostProcessing),
+            40 => Some(Self::ChargedProjectiles),
+            41 => Some(Self::BundleContents),
+            42 => Some(Self::PotionContents),
+            43 => Some(Self::PotionDurationScale),
+            44 => Some(Self::SuspiciousStewEffects),
+            45 => Some(Self::WritableBookContent),
+            46 => Some(Self::WrittenBookContent),
+            47 => Some(Self::Trim),
+            48 => Some(Self::DebugStickState),
+            49 => Some(Self::EntityData),
+            50 => Some(Self::BucketEntityData),
+            51 => Some(Self::BlockEntityData),
+            52 => Some(Self::Instrument),
+            53 => Some(Self::ProvidesTrimMaterial),
+            54 => Some(Self::OminousBottleAmplifier),
+            55 => Some(Self::JukeboxPlayable),
+            56 => Some(Self::ProvidesBannerPatterns),
+            57 => Some(Self::Recipes),
+            58 => Some(Self::LodestoneTracker),
+            59 => Some(Self::FireworkExplosion),
+            60 => Some(Self::Fireworks),
+            61 => Some(Self::Profile),
+            62 => Some(Self::NoteBlockSound),
+            63 => Some(Self::BannerPatterns),
+            64 => Some(Self::BaseColor),
+            65 => Some(Self::PotDecorations),
+            66 => Some(Self::Container),
+            67 => Some(Self::BlockState),
+            68 => Some(Self::Bees),
+            69 => Some(Self::Lock),
+            70 => Some(Self::ContainerLoot),
+            71 => Some(Self::BreakSound),
+            72 => Some(Self::VillagerVariant),
+            73 => Some(Self::WolfVariant),
+            74 => Some(Self::WolfSoundVariant),
+            75 => Some(Self::WolfCollar),
+            76 => Some(Self::FoxVariant),
+            77 => Some(Self::SalmonSize),
+            78 => Some(Self::ParrotVariant),
+            79 => Some(Self::TropicalFishPattern),
+            80 => Some(Self::TropicalFishBaseColor),
+            81 => Some(Self::TropicalFishPatternColor),
+            82 => Some(Self::MooshroomVariant),
+            83 => Some(Self::RabbitVariant),
+            84 => Some(Self::PigVariant),
+            85 => Some(Self::CowVariant),
+            86 => Some(Self::ChickenVariant),
+            87 => Some(Self::FrogVariant),
+            88 => Some(Self::HorseVariant),
+            89 => Some(Self::PaintingVariant),
+            90 => Some(Self::LlamaVariant),
+            91 => Some(Self::AxolotlVariant),
+            92 => Some(Self::CatVariant),
+            93 => Some(Self::CatCollar),
+            94 => Some(Self::SheepColor),
+            95 => Some(Self::ShulkerColor),
+            _ => None,
+        }
+    }
+    #[doc = "The resource location of the component, e.g. `minecraft:damage`."]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::CustomData => "minecraft:custom_data",
+            Self::MaxStackSize => "minecraft:max_stack_size",
+            Self::MaxDamage => "minecraft:max_damage",
+            Self::Damage => "minecraft:damage",
+            Self::Unbreakable => "minecraft:unbreakable",
+            Self::CustomName => "minecraft:custom_name",
+            Self::ItemName => "minecraft:item_name",
+            Self::ItemModel => "minecraft:item_model",
+            Self::Lore => "minecraft:lore",
+            Self::Rarity => "minecraft:rarity",
+            Self::Enchantments => "minecraft:enchantments",
+            Self::CanPlaceOn => "minecraft:can_place_on",
+            Self::CanBreak => "minecraft:can_break",
+            Self::AttributeModifiers => "minecraft:attribute_modifiers",
+            Self::CustomModelData => "minecraft:custom_model_data",
+            Self::TooltipDisplay => "minecraft:tooltip_display",
+            Self::RepairCost => "minecraft:repair_cost",
+            Self::CreativeSlotLock => "minecraft:creative_slot_lock",
+            Self::EnchantmentGlintOverride => "minecraft:enchantment_glint_override",
+            Self::IntangibleProjectile => "minecraft:intangible_projectile",
+            Self::Food => "minecraft:food",
+            Self::Consumable => "minecraft:consumable",
+            Self::UseRemainder => "minecraft:use_remainder",
+            Self::UseCooldown => "minecraft:use_cooldown",
+            Self::DamageResistant => "minecraft:damage_resistant",
+            Self::Tool => "minecraft:tool",
+            Self::Weapon => "minecraft:weapon",
+            Self::Enchantable => "minecraft:enchantable",
+            Self::Equippable => "minecraft:equippable",
+            Self::Repairable => "minecraft:repairable",
+            Self::Glider => "minecraft:glider",
+            Self::TooltipStyle => "minecraft:tooltip_style",
+            Self::DeathProtection => "minecraft:death_protection",
+            Self::BlocksAttacks => "minecraft:blocks_attacks",
+            Self::StoredEnchantments => "minecraft:stored_enchantments",
+            Self::DyedColor => "minecraft:dyed_color",
+            Self::MapColor => "minecraft:map_color",
+            Self::MapId => "minecraft:map_id",
+            Self::MapDecorations => "minecraft:map_decorations",
+            Self::MapPostProcessing => "minecraft:map_post_processing",
+            Self::ChargedProjectiles => "minecraft:charged_projectiles",
+            Self::BundleContents => "minecraft:bundle_contents",
+            Self::PotionContents => "minecraft:potion_contents",
+            Self::PotionDurationScale => "minecraft:potion_duration_scale",
+            Self::SuspiciousStewEffects => "minecraft:suspicious_stew_effects",
+            Self::WritableBookContent => "minecraft:writable_book_content",
+            Self::WrittenBookContent => "minecraft:written_book_content",
+            Self::Trim => "minecraft:trim",
+            Self::DebugStickState => "minecraft:debug_stick_state",
+            Self::EntityData => "minecraft:entity_data",
+            Self::BucketEntityData => "minecraft:bucket_entity_data",
+            Self::BlockEntityData => "minecraft:block_entity_data",
+            Self::Instrument => "minecraft:instrument",
+            Self::ProvidesTrimMaterial => "minecraft:provides_trim_material",
+            Self::OminousBottleAmplifier => "minecraft:ominous_bottle_amplifier",
+            Self::JukeboxPlayable => "minecraft:jukebox_playable",
+            Self::ProvidesBannerPatterns => "minecraft:provides_banner_patterns",
+            Self::Recipes => "minecraft:recipes",
+            Self::LodestoneTracker => "minecraft:lodestone_tracker",
+            Self::FireworkExplosion => "minecraft:firework_explosion",
+            Self::Fireworks => "minecraft:fireworks",
+            Self::Profile => "minecraft:profile",
+            Self::NoteBlockSound => "minecraft:note_block_sound",
+            Self::BannerPatterns => "minecraft:banner_patterns",
+            Self::BaseColor => "minecraft:base_color",
+            Self::PotDecorations => "minecraft:pot_decorations",
+            Self::Container => "minecraft:container",
+            Self::BlockState => "minecraft:block_state",
+            Self::Bees => "minecraft:bees",
+            Self::Lock => "minecraft:lock",
+            Self::ContainerLoot => "minecraft:container_loot",
+            Self::BreakSound => "minecraft:break_sound",
+            Self::VillagerVariant => "minecraft:villager/variant",
+            Self::WolfVariant => "minecraft:wolf/variant",
+            Self::WolfSoundVariant => "minecraft:wolf/sound_variant",
+            Self::WolfCollar => "minecraft:wolf/collar",
+            Self::FoxVariant => "minecraft:fox/variant",
+            Self::SalmonSize => "minecraft:salmon/size",
+            Self::ParrotVariant => "minecraft:parrot/variant",
+            Self::TropicalFishPattern => "minecraft:tropical_fish/pattern",
+            Self::TropicalFishBaseColor => "minecraft:tropical_fish/base_color",
+            Self::TropicalFishPatternColor => "minecraft:tropical_fish/pattern_color",
+            Self::MooshroomVariant => "minecraft:mooshroom/variant",
+            Self::RabbitVariant => "minecraft:rabbit/variant",
+            Self::PigVariant => "minecraft:pig/variant",
+            Self::CowVariant => "minecraft:cow/variant",
+            Self::ChickenVariant => "minecraft:chicken/variant",
+            Self::FrogVariant => "minecraft:frog/variant",
+            Self::HorseVariant => "minecraft:horse/variant",
+            Self::PaintingVariant => "minecraft:painting/variant",
+            Self::LlamaVariant => "minecraft:llama/variant",
+            Self::AxolotlVariant => "minecraft:axolotl/variant",
+            Self::CatVariant => "minecraft:cat/variant",
+            Self::CatCollar => "minecraft:cat/collar",
+            Self::SheepColor => "minecraft:sheep/color",
+            Self::ShulkerColor => "minecraft:shulker/color",
+        }
+    }
 }
 pub trait DataComponentImpl {
     fn as_any(&self) -> &dyn std::any::Any;
@@ -33639,6 +34439,24 @@ impl DataComponentImpl for DeathProtectionImpl {
     }
 }
 #[derive(Clone, Debug)]
+pub struct EnchantableImpl {
+    pub value: u8,
+}
+impl DataComponentImpl for EnchantableImpl {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+#[derive(Clone, Debug)]
+pub struct RepairableImpl {
+    pub items: &'static str,
+}
+impl DataComponentImpl for RepairableImpl {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+#[derive(Clone, Debug)]
 pub struct Modifier {
     pub r#type: &'static crate::attributes::Attribute,
     pub id: &'static str,
@@ -33652,7 +34470,37 @@ pub struct ToolRule {
     pub speed: Option<f32>,
     pub correct_for_drops: Option<bool>,
 }
-#[derive(Clone, Debug)]
+impl ToolRule {
+    #[doc = "Whether the rule applies to a block, by its registry name. The blocks are a block, a block tag or a JSON list of blocks."]
+    pub fn applies_to(&self, block: &str) -> bool {
+        self.blocks
+            .trim_matches(|c| c == '[' || c == ']')
+            .split(',')
+            .map(|entry| entry.trim_matches('"'))
+            .any(|entry| match entry.strip_prefix('#') {
+                Some(tag) => crate::tags::TagData::get_block_tag(tag)
+                    .is_some_and(|tag| tag.values.contains(&block)),
+                None => entry == block,
+            })
+    }
+}
+impl ToolImpl {
+    #[doc = "How fast the tool mines a block, from the first rule for it with a speed."]
+    pub fn mining_speed(&self, block: &str) -> f32 {
+        self.rules
+            .iter()
+            .find_map(|rule| rule.speed.filter(|_| rule.applies_to(block)))
+            .unwrap_or(self.default_mining_speed)
+    }
+    #[doc = "Whether the tool is the right one to get a block's drops, from the first rule for it that says."]
+    pub fn is_correct_for_drops(&self, block: &str) -> bool {
+        self.rules
+            .iter()
+            .find_map(|rule| rule.correct_for_drops.filter(|_| rule.applies_to(block)))
+            .unwrap_or(false)
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Operation {
     AddValue,
     AddMultipliedBase,
@@ -0,0 +1,4417 @@
+#[doc = r" A vanilla loot table."]
+#[derive(Debug, Clone, Copy)]
+pub struct LootTable {
+    pub name: &'static str,
+    pub kind: LootTableKind,
+    pub pools: &'static [LootPool],
+    #[doc = r" Functions applied to everything the table generates."]
+    pub functions: &'static [LootFunction],
+}
+#[doc = r" What a loot table is used for, which decides what's in its loot context."]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LootTableKind {
+    Block,
+    Entity,
+    Chest,
+    Fishing,
+    Archaeology,
+    Other,
+}
+#[doc = r" A pool rolls its entries a random number of times, if its conditions pass."]
+#[derive(Debug, Clone, Copy)]
+pub struct LootPool {
+    pub rolls: NumberProvider,
+    #[doc = r" Extra rolls per point of luck."]
+    pub bonus_rolls: NumberProvider,
+    pub entries: &'static [LootEntry],
+    pub conditions: &'static [LootCondition],
+    pub functions: &'static [LootFunction],
+}
+#[derive(Debug, Clone, Copy)]
+pub struct LootEntry {
+    pub kind: LootEntryKind,
+    #[doc = r" Chance of this entry being picked relative to the other entries in the pool."]
+    pub weight: u32,
+    #[doc = r" How much luck changes the weight."]
+    pub quality: i32,
+    pub conditions: &'static [LootCondition],
+    pub functions: &'static [LootFunction],
+}
+#[derive(Debug, Clone, Copy)]
+pub enum LootEntryKind {
+    #[doc = r" A single item, by registry name."]
+    Item(&'static str),
+    #[doc = r" Every item in an item tag, or one of them at random if `expand` is set."]
+    Tag {
+        name: &'static str,
+        expand: bool,
+    },
+    #[doc = r" Everything another loot table generates, by name."]
+    LootTable(&'static str),
+    #[doc = r" Contents that depend on the block entity, like a shulker box."]
+    Dynamic(&'static str),
+    Empty,
+    #[doc = r" The first child whose conditions pass."]
+    Alternatives(&'static [LootEntry]),
+    #[doc = r" Every child whose conditions pass."]
+    Group(&'static [LootEntry]),
+    #[doc = r" Children in order until one's conditions fail."]
+    Sequence(&'static [LootEntry]),
+}
+#[derive(Debug, Clone, Copy)]
+pub enum NumberProvider {
+    Constant(f32),
+    Uniform { min: f32, max: f32 },
+    Binomial { n: i32, p: f32 },
+}
+#[derive(Debug, Clone, Copy)]
+pub enum LootCondition {
+    SurvivesExplosion,
+    KilledByPlayer,
+    RandomChance(f32),
+    RandomChanceWithEnchantedBonus {
+        enchantment: &'static str,
+        unenchanted_chance: f32,
+        base: f32,
+        per_level_above_first: f32,
+    },
+    #[doc = r" Passes with the chance at the index of the tool's enchantment level."]
+    TableBonus {
+        enchantment: &'static str,
+        chances: &'static [f32],
+    },
+    MatchTool(ToolPredicate),
+    #[doc = r" Checks the killed entity or its killer."]
+    EntityProperties {
+        entity: LootEntityTarget,
+        predicate: EntityPredicate,
+    },
+    #[doc = r" Checks the damage that killed the entity."]
+    DamageSourceProperties(DamageSourcePredicate),
+    BlockStateProperty {
+        block: &'static str,
+        properties: &'static [(&'static str, &'static str)],
+    },
+    Inverted(&'static LootCondition),
+    AnyOf(&'static [LootCondition]),
+    AllOf(&'static [LootCondition]),
+    #[doc = r" A condition, or a part of one, that can't be evaluated yet, by type. Never passes."]
+    Unsupported(&'static str),
+}
+#[doc = r" Which entity an `entity_properties` condition checks."]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LootEntityTarget {
+    #[doc = r" The entity the loot is for."]
+    This,
+    #[doc = r" The entity responsible for the kill, like the skeleton that shot the arrow."]
+    Attacker,
+    #[doc = r" The entity that dealt the damage, like the arrow."]
+    DirectAttacker,
+}
+#[doc = r" Matches an entity. Unset fields match anything."]
+#[derive(Debug, Clone, Copy)]
+pub struct EntityPredicate {
+    #[doc = r" Entity types or `#` prefixed entity type tags, any of which match."]
+    pub types: &'static [&'static str],
+    pub on_fire: Option<bool>,
+    pub baby: Option<bool>,
+}
+#[doc = r" Matches the damage that killed an entity."]
+#[derive(Debug, Clone, Copy)]
+pub struct DamageSourcePredicate {
+    #[doc = r" Damage type tags, and whether the damage has to be in them or not."]
+    pub tags: &'static [(&'static str, bool)],
+    pub direct_entity: Option<&'static EntityPredicate>,
+    pub source_entity: Option<&'static EntityPredicate>,
+}
+#[doc = r" Matches the tool used to break a block or kill an entity."]
+#[derive(Debug, Clone, Copy)]
+pub struct ToolPredicate {
+    #[doc = r" Item names or `#` prefixed item tags, any of which match. Empty matches any item."]
+    pub items: &'static [&'static str],
+    #[doc = r" Enchantments the tool needs, with their minimum level."]
+    pub enchantments: &'static [(&'static str, u32)],
+}
+#[derive(Debug, Clone, Copy)]
+pub struct LootFunction {
+    pub kind: LootFunctionKind,
+    pub conditions: &'static [LootCondition],
+}
+#[derive(Debug, Clone, Copy)]
+pub enum LootFunctionKind {
+    SetCount {
+        count: NumberProvider,
+        add: bool,
+    },
+    ApplyBonus {
+        enchantment: &'static str,
+        formula: BonusFormula,
+    },
+    ExplosionDecay,
+    #[doc = r" Looting."]
+    EnchantedCountIncrease {
+        enchantment: &'static str,
+        count: NumberProvider,
+        limit: i32,
+    },
+    LimitCount {
+        min: Option<i32>,
+        max: Option<i32>,
+    },
+    #[doc = r" A function that isn't implemented yet, by type. Leaves the stack as it is."]
+    Unsupported(&'static str),
+}
+#[doc = r" How `apply_bonus` scales the count with the enchantment level."]
+#[derive(Debug, Clone, Copy)]
+pub enum BonusFormula {
+    OreDrops,
+    UniformBonusCount { bonus_multiplier: i32 },
+    BinomialWithBonusCount { extra: i32, probability: f32 },
+}
+impl LootTable {
+    pub const ENTITIES_ALLAY: LootTable = LootTable {
+        name: "minecraft:entities/allay",
+        kind: LootTableKind::Entity,
+        pools: &[],
+        functions: &[],
+    };
+    pub const ENTITIES_ARMADILLO: LootTable = LootTable {
+        name: "minecraft:entities/armadillo",
+        kind: LootTableKind::Entity,
+        pools: &[],
+        functions: &[],
+    };
+    pub const ENTITIES_ARMOR_STAND: LootTable = LootTable {
+        name: "minecraft:entities/armor_stand",
+        kind: LootTableKind::Entity,
+        pools: &[],
+        functions: &[],
+    };
+    pub const ENTITIES_AXOLOTL: LootTable = LootTable {
+        name: "minecraft:entities/axolotl",
+        kind: LootTableKind::Entity,
+        pools: &[],
+        functions: &[],
+    };
+    pub const ENTITIES_BAT: LootTable = LootTable {
+        name: "minecraft:entities/bat",
+        kind: LootTableKind::Entity,
+        pools: &[],
+        functions: &[],
+    };
+    pub const ENTITIES_BEE: LootTable = LootTable {
+        name: "minecraft:entities/bee",
+        kind: LootTableKind::Entity,
+        pools: &[],
+        functions: &[],
+    };
+    pub const ENTITIES_BLAZE: LootTable = LootTable {
+        name: "minecraft:entities/blaze",
+        kind: LootTableKind::Entity,
+        pools: &[LootPool {
+            rolls: NumberProvider::Constant(1f32),
+            bonus_rolls: NumberProvider::Constant(0f32),
+            entries: &[LootEntry {
+                kind: LootEntryKind::Item("minecraft:blaze_rod"),
+                weight: 1u32,
+                quality: 0i32,
+                conditions: &[],
+                functions: &[
+                    LootFunction {
+                        kind: LootFunctionKind::SetCount {
+                            count: NumberProvider::Uniform {
+                                min: 0f32,
+                                max: 1f32,
+                            },
+                            add: false,
+                        },
+                        conditions: &[],
+                    },
+                    LootFunction {
+                        kind: LootFunctionKind::EnchantedCountIncrease {
+                            enchantment: "minecraft:looting",
+                            count: NumberProvider::Uniform {
+                                min: 0f32,
+                                max: 1f32,
+                            },
+                            limit: 0i32,
+                        },
+                        conditions: &[],
+                    },
+                ],
+            }],
+            conditions: &[LootCondition::KilledByPlayer],
+            functions: &[],
+        }],
+        functions: &[],
+    };
+    pub const ENTITIES_BOGGED: LootTable = LootTable {
+        name: "minecraft:entities/bogged",
+        kind: LootTableKind::Entity,
+        pools: &[
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:arrow"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[
+                        LootFunction {
+                            kind: LootFunctionKind::SetCount {
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 2f32,
+                                },
+                                add: false,
+                            },
+                            conditions: &[],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::EnchantedCountIncrease {
+                                enchantment: "minecraft:looting",
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: 0i32,
+                            },
+                            conditions: &[],
+                        },
+                    ],
+                }],
+                conditions: &[],
+                functions: &[],
+            },
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:bone"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[
+                        LootFunction {
+                            kind: LootFunctionKind::SetCount {
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 2f32,
+                                },
+                                add: false,
+                            },
+                            conditions: &[],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::EnchantedCountIncrease {
+                                enchantment: "minecraft:looting",
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: 0i32,
+                            },
+                            conditions: &[],
+                        },
+                    ],
+                }],
+                conditions: &[],
+                functions: &[],
+            },
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:tipped_arrow"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[
+                        LootFunction {
+                            kind: LootFunctionKind::SetCount {
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                add: false,
+                            },
+                            conditions: &[],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::EnchantedCountIncrease {
+                                enchantment: "minecraft:looting",
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: 1i32,
+                            },
+                            conditions: &[],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::Unsupported("minecraft:set_potion"),
+                            conditions: &[],
+                        },
+                    ],
+                }],
+                conditions: &[LootCondition::KilledByPlayer],
+                functions: &[],
+            },
+        ],
+        functions: &[],
+    };
+    pub const ENTITIES_BREEZE: LootTable = LootTable {
+        name: "minecraft:entities/breeze",
+        kind: LootTableKind::Entity,
+        pools: &[LootPool {
+            rolls: NumberProvider::Constant(1f32),
+            bonus_rolls: NumberProvider::Constant(0f32),
+            entries: &[LootEntry {
+                kind: LootEntryKind::Item("minecraft:breeze_rod"),
+                weight: 1u32,
+                quality: 0i32,
+                conditions: &[],
+                functions: &[
+                    LootFunction {
+                        kind: LootFunctionKind::SetCount {
+                            count: NumberProvider::Uniform {
+                                min: 1f32,
+                                max: 2f32,
+                            },
+                            add: false,
+                        },
+                        conditions: &[],
+                    },
+                    LootFunction {
+                        kind: LootFunctionKind::EnchantedCountIncrease {
+                            enchantment: "minecraft:looting",
+                            count: NumberProvider::Uniform {
+                                min: 1f32,
+                                max: 2f32,
+                            },
+                            limit: 0i32,
+                        },
+                        conditions: &[],
+                    },
+                ],
+            }],
+            conditions: &[LootCondition::KilledByPlayer],
+            functions: &[],
+        }],
+        functions: &[],
+    };
+    pub const ENTITIES_CAMEL: LootTable = LootTable {
+        name: "minecraft:entities/camel",
+        kind: LootTableKind::Entity,
+        pools: &[],
+        functions: &[],
+    };
+    pub const ENTITIES_CAT: LootTable = LootTable {
+        name: "minecraft:entities/cat",
+        kind: LootTableKind::Entity,
+        pools: &[LootPool {
+            rolls: NumberProvider::Constant(1f32),
+            bonus_rolls: NumberProvider::Constant(0f32),
+            entries: &[LootEntry {
+                kind: LootEntryKind::Item("minecraft:string"),
+                weight: 1u32,
+                quality: 0i32,
+                conditions: &[],
+                functions: &[LootFunction {
+                    kind: LootFunctionKind::SetCount {
+                        count: NumberProvider::Uniform {
+                            min: 0f32,
+                            max: 2f32,
+                        },
+                        add: false,
+                    },
+                    conditions: &[],
+                }],
+            }],
+            conditions: &[],
+            functions: &[],
+        }],
+        functions: &[],
+    };
+    pub const ENTITIES_CAVE_SPIDER: LootTable = LootTable {
+        name: "minecraft:entities/cave_spider",
+        kind: LootTableKind::Entity,
+        pools: &[
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:string"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[
+                        LootFunction {
+                            kind: LootFunctionKind::SetCount {
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 2f32,
+                                },
+                                add: false,
+                            },
+                            conditions: &[],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::EnchantedCountIncrease {
+                                enchantment: "minecraft:looting",
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: 0i32,
+                            },
+                            conditions: &[],
+                        },
+                    ],
+                }],
+                conditions: &[],
+                functions: &[],
+            },
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:spider_eye"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[
+                        LootFunction {
+                            kind: LootFunctionKind::SetCount {
+                                count: NumberProvider::Uniform {
+                                    min: -1f32,
+                                    max: 1f32,
+                                },
+                                add: false,
+                            },
+                            conditions: &[],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::EnchantedCountIncrease {
+                                enchantment: "minecraft:looting",
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: 0i32,
+                            },
+                            conditions: &[],
+                        },
+                    ],
+                }],
+                conditions: &[LootCondition::KilledByPlayer],
+                functions: &[],
+            },
+        ],
+        functions: &[],
+    };
+    pub const ENTITIES_CHICKEN: LootTable = LootTable {
+        name: "minecraft:entities/chicken",
+        kind: LootTableKind::Entity,
+        pools: &[
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:feather"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[
+                        LootFunction {
+                            kind: LootFunctionKind::SetCount {
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 2f32,
+                                },
+                                add: false,
+                            },
+                            conditions: &[],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::EnchantedCountIncrease {
+                                enchantment: "minecraft:looting",
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: 0i32,
+                            },
+                            conditions: &[],
+                        },
+                    ],
+                }],
+                conditions: &[],
+                functions: &[],
+            },
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:chicken"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[
+                        LootFunction {
+                            kind: LootFunctionKind::Unsupported("minecraft:furnace_smelt"),
+                            conditions: &[LootCondition::AnyOf(&[
+                                LootCondition::EntityProperties {
+                                    entity: LootEntityTarget::This,
+                                    predicate: EntityPredicate {
+                                        types: &[],
+                                        on_fire: Some(true),
+                                        baby: None,
+                                    },
+                                },
+                                LootCondition::Unsupported("minecraft:entity_properties"),
+                            ])],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::EnchantedCountIncrease {
+                                enchantment: "minecraft:looting",
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: 0i32,
+                            },
+                            conditions: &[],
+                        },
+                    ],
+                }],
+                conditions: &[],
+                functions: &[],
+            },
+        ],
+        functions: &[],
+    };
+    pub const ENTITIES_COD: LootTable = LootTable {
+        name: "minecraft:entities/cod",
+        kind: LootTableKind::Entity,
+        pools: &[
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:cod"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[LootFunction {
+                        kind: LootFunctionKind::Unsupported("minecraft:furnace_smelt"),
+                        conditions: &[LootCondition::AnyOf(&[
+                            LootCondition::EntityProperties {
+                                entity: LootEntityTarget::This,
+                                predicate: EntityPredicate {
+                                    types: &[],
+                                    on_fire: Some(true),
+                                    baby: None,
+                                },
+                            },
+                            LootCondition::Unsupported("minecraft:entity_properties"),
+                        ])],
+                    }],
+                }],
+                conditions: &[],
+                functions: &[],
+            },
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:bone_meal"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[],
+                }],
+                conditions: &[LootCondition::RandomChance(0.05f32)],
+                functions: &[],
+            },
+        ],
+        functions: &[],
+    };
+    pub const ENTITIES_COW: LootTable = LootTable {
+        name: "minecraft:entities/cow",
+        kind: LootTableKind::Entity,
+        pools: &[
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:leather"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[
+                        LootFunction {
+                            kind: LootFunctionKind::SetCount {
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 2f32,
+                                },
+                                add: false,
+                            },
+                            conditions: &[],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::EnchantedCountIncrease {
+                                enchantment: "minecraft:looting",
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: 0i32,
+                            },
+                            conditions: &[],
+                        },
+                    ],
+                }],
+                conditions: &[],
+                functions: &[],
+            },
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:beef"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[
+                        LootFunction {
+                            kind: LootFunctionKind::SetCount {
+                                count: NumberProvider::Uniform {
+                                    min: 1f32,
+                                    max: 3f32,
+                                },
+                                add: false,
+                            },
+                            conditions: &[],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::Unsupported("minecraft:furnace_smelt"),
+                            conditions: &[LootCondition::AnyOf(&[
+                                LootCondition::EntityProperties {
+                                    entity: LootEntityTarget::This,
+                                    predicate: EntityPredicate {
+                                        types: &[],
+                                        on_fire: Some(true),
+                                        baby: None,
+                                    },
+                                },
+                                LootCondition::Unsupported("minecraft:entity_properties"),
+                            ])],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::EnchantedCountIncrease {
+                                enchantment: "minecraft:looting",
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: 0i32,
+                            },
+                            conditions: &[],
+                        },
+                    ],
+                }],
+                conditions: &[],
+                functions: &[],
+            },
+        ],
+        functions: &[],
+    };
+    pub const ENTITIES_CREAKING: LootTable = LootTable {
+        name: "minecraft:entities/creaking",
+        kind: LootTableKind::Entity,
+        pools: &[],
+        functions: &[],
+    };
+    pub const ENTITIES_CREEPER: LootTable = LootTable {
+        name: "minecraft:entities/creeper",
+        kind: LootTableKind::Entity,
+        pools: &[
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:gunpowder"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[
+                        LootFunction {
+                            kind: LootFunctionKind::SetCount {
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 2f32,
+                                },
+                                add: false,
+                            },
+                            conditions: &[],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::EnchantedCountIncrease {
+                                enchantment: "minecraft:looting",
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: 0i32,
+                            },
+                            conditions: &[],
+                        },
+                    ],
+                }],
+                conditions: &[],
+                functions: &[],
+            },
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Tag {
+                        name: "minecraft:creeper_drop_music_discs",
+                        expand: true,
+                    },
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[],
+                }],
+                conditions: &[LootCondition::EntityProperties {
+                    entity: LootEntityTarget::Attacker,
+                    predicate: EntityPredicate {
+                        types: &["#minecraft:skeletons"],
+                        on_fire: None,
+                        baby: None,
+                    },
+                }],
+                functions: &[],
+            },
+        ],
+        functions: &[],
+    };
+    pub const ENTITIES_DOLPHIN: LootTable = LootTable {
+        name: "minecraft:entities/dolphin",
+        kind: LootTableKind::Entity,
+        pools: &[LootPool {
+            rolls: NumberProvider::Constant(1f32),
+            bonus_rolls: NumberProvider::Constant(0f32),
+            entries: &[LootEntry {
+                kind: LootEntryKind::Item("minecraft:cod"),
+                weight: 1u32,
+                quality: 0i32,
+                conditions: &[],
+                functions: &[
+                    LootFunction {
+                        kind: LootFunctionKind::SetCount {
+                            count: NumberProvider::Uniform {
+                                min: 0f32,
+                                max: 1f32,
+                            },
+                            add: false,
+                        },
+                        conditions: &[],
+                    },
+                    LootFunction {
+                        kind: LootFunctionKind::EnchantedCountIncrease {
+                            enchantment: "minecraft:looting",
+                            count: NumberProvider::Uniform {
+                                min: 0f32,
+                                max: 1f32,
+                            },
+                            limit: 0i32,
+                        },
+                        conditions: &[],
+                    },
+                    LootFunction {
+                        kind: LootFunctionKind::Unsupported("minecraft:furnace_smelt"),
+                        conditions: &[LootCondition::AnyOf(&[
+                            LootCondition::EntityProperties {
+                                entity: LootEntityTarget::This,
+                                predicate: EntityPredicate {
+                                    types: &[],
+                                    on_fire: Some(true),
+                                    baby: None,
+                                },
+                            },
+                            LootCondition::Unsupported("minecraft:entity_properties"),
+                        ])],
+                    },
+                ],
+            }],
+            conditions: &[],
+            functions: &[],
+        }],
+        functions: &[],
+    };
+    pub const ENTITIES_DONKEY: LootTable = LootTable {
+        name: "minecraft:entities/donkey",
+        kind: LootTableKind::Entity,
+        pools: &[LootPool {
+            rolls: NumberProvider::Constant(1f32),
+            bonus_rolls: NumberProvider::Constant(0f32),
+            entries: &[LootEntry {
+                kind: LootEntryKind::Item("minecraft:leather"),
+                weight: 1u32,
+                quality: 0i32,
+                conditions: &[],
+                functions: &[
+                    LootFunction {
+                        kind: LootFunctionKind::SetCount {
+                            count: NumberProvider::Uniform {
+                                min: 0f32,
+                                max: 2f32,
+                            },
+                            add: false,
+                        },
+                        conditions: &[],
+                    },
+                    LootFunction {
+                        kind: LootFunctionKind::EnchantedCountIncrease {
+                            enchantment: "minecraft:looting",
+                            count: NumberProvider::Uniform {
+                                min: 0f32,
+                                max: 1f32,
+                            },
+                            limit: 0i32,
+                        },
+                        conditions: &[],
+                    },
+                ],
+            }],
+            conditions: &[],
+            functions: &[],
+        }],
+        functions: &[],
+    };
+    pub const ENTITIES_DROWNED: LootTable = LootTable {
+        name: "minecraft:entities/drowned",
+        kind: LootTableKind::Entity,
+        pools: &[
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:rotten_flesh"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[
+                        LootFunction {
+                            kind: LootFunctionKind::SetCount {
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 2f32,
+                                },
+                                add: false,
+                            },
+                            conditions: &[],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::EnchantedCountIncrease {
+                                enchantment: "minecraft:looting",
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: 0i32,
+                            },
+                            conditions: &[],
+                        },
+                    ],
+                }],
+                conditions: &[],
+                functions: &[],
+            },
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:copper_ingot"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[],
+                }],
+                conditions: &[
+                    LootCondition::KilledByPlayer,
+                    LootCondition::RandomChanceWithEnchantedBonus {
+                        enchantment: "minecraft:looting",
+                        unenchanted_chance: 0.11f32,
+                        base: 0.13f32,
+                        per_level_above_first: 0.02f32,
+                    },
+                ],
+                functions: &[],
+            },
+        ],
+        functions: &[],
+    };
+    pub const ENTITIES_ELDER_GUARDIAN: LootTable = LootTable {
+        name: "minecraft:entities/elder_guardian",
+        kind: LootTableKind::Entity,
+        pools: &[
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:prismarine_shard"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[
+                        LootFunction {
+                            kind: LootFunctionKind::SetCount {
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 2f32,
+                                },
+                                add: false,
+                            },
+                            conditions: &[],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::EnchantedCountIncrease {
+                                enchantment: "minecraft:looting",
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: 0i32,
+                            },
+                            conditions: &[],
+                        },
+                    ],
+                }],
+                conditions: &[],
+                functions: &[],
+            },
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[
+                    LootEntry {
+                        kind: LootEntryKind::Item("minecraft:cod"),
+                        weight: 3u32,
+                        quality: 0i32,
+                        conditions: &[],
+                        functions: &[
+                            LootFunction {
+                                kind: LootFunctionKind::EnchantedCountIncrease {
+                                    enchantment: "minecraft:looting",
+                                    count: NumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: 0i32,
+                                },
+                                conditions: &[],
+                            },
+                            LootFunction {
+                                kind: LootFunctionKind::Unsupported("minecraft:furnace_smelt"),
+                                conditions: &[LootCondition::AnyOf(&[
+                                    LootCondition::EntityProperties {
+                                        entity: LootEntityTarget::This,
+                                        predicate: EntityPredicate {
+                                            types: &[],
+                                            on_fire: Some(true),
+                                            baby: None,
+                                        },
+                                    },
+                                    LootCondition::Unsupported("minecraft:entity_properties"),
+                                ])],
+                            },
+                        ],
+                    },
+                    LootEntry {
+                        kind: LootEntryKind::Item("minecraft:prismarine_crystals"),
+                        weight: 2u32,
+                        quality: 0i32,
+                        conditions: &[],
+                        functions: &[LootFunction {
+                            kind: LootFunctionKind::EnchantedCountIncrease {
+                                enchantment: "minecraft:looting",
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: 0i32,
+                            },
+                            conditions: &[],
+                        }],
+                    },
+                    LootEntry {
+                        kind: LootEntryKind::Empty,
+                        weight: 1u32,
+                        quality: 0i32,
+                        conditions: &[],
+                        functions: &[],
+                    },
+                ],
+                conditions: &[],
+                functions: &[],
+            },
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:wet_sponge"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[],
+                }],
+                conditions: &[LootCondition::KilledByPlayer],
+                functions: &[],
+            },
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::LootTable("minecraft:gameplay/fishing/fish"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[LootFunction {
+                        kind: LootFunctionKind::Unsupported("minecraft:furnace_smelt"),
+                        conditions: &[LootCondition::AnyOf(&[
+                            LootCondition::EntityProperties {
+                                entity: LootEntityTarget::This,
+                                predicate: EntityPredicate {
+                                    types: &[],
+                                    on_fire: Some(true),
+                                    baby: None,
+                                },
+                            },
+                            LootCondition::Unsupported("minecraft:entity_properties"),
+                        ])],
+                    }],
+                }],
+                conditions: &[
+                    LootCondition::KilledByPlayer,
+                    LootCondition::RandomChanceWithEnchantedBonus {
+                        enchantment: "minecraft:looting",
+                        unenchanted_chance: 0.025f32,
+                        base: 0.035f32,
+                        per_level_above_first: 0.01f32,
+                    },
+                ],
+                functions: &[],
+            },
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[
+                    LootEntry {
+                        kind: LootEntryKind::Empty,
+                        weight: 4u32,
+                        quality: 0i32,
+                        conditions: &[],
+                        functions: &[],
+                    },
+                    LootEntry {
+                        kind: LootEntryKind::Item("minecraft:tide_armor_trim_smithing_template"),
+                        weight: 1u32,
+                        quality: 0i32,
+                        conditions: &[],
+                        functions: &[],
+                    },
+                ],
+                conditions: &[],
+                functions: &[],
+            },
+        ],
+        functions: &[],
+    };
+    pub const ENTITIES_ENDER_DRAGON: LootTable = LootTable {
+        name: "minecraft:entities/ender_dragon",
+        kind: LootTableKind::Entity,
+        pools: &[],
+        functions: &[],
+    };
+    pub const ENTITIES_ENDERMAN: LootTable = LootTable {
+        name: "minecraft:entities/enderman",
+        kind: LootTableKind::Entity,
+        pools: &[LootPool {
+            rolls: NumberProvider::Constant(1f32),
+            bonus_rolls: NumberProvider::Constant(0f32),
+            entries: &[LootEntry {
+                kind: LootEntryKind::Item("minecraft:ender_pearl"),
+                weight: 1u32,
+                quality: 0i32,
+                conditions: &[],
+                functions: &[
+                    LootFunction {
+                        kind: LootFunctionKind::SetCount {
+                            count: NumberProvider::Uniform {
+                                min: 0f32,
+                                max: 1f32,
+                            },
+                            add: false,
+                        },
+                        conditions: &[],
+                    },
+                    LootFunction {
+                        kind: LootFunctionKind::EnchantedCountIncrease {
+                            enchantment: "minecraft:looting",
+                            count: NumberProvider::Uniform {
+                                min: 0f32,
+                                max: 1f32,
+                            },
+                            limit: 0i32,
+                        },
+                        conditions: &[],
+                    },
+                ],
+            }],
+            conditions: &[],
+            functions: &[],
+        }],
+        functions: &[],
+    };
+    pub const ENTITIES_ENDERMITE: LootTable = LootTable {
+        name: "minecraft:entities/endermite",
+        kind: LootTableKind::Entity,
+        pools: &[],
+        functions: &[],
+    };
+    pub const ENTITIES_EVOKER: LootTable = LootTable {
+        name: "minecraft:entities/evoker",
+        kind: LootTableKind::Entity,
+        pools: &[
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:totem_of_undying"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[],
+                }],
+                conditions: &[],
+                functions: &[],
+            },
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:emerald"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[
+                        LootFunction {
+                            kind: LootFunctionKind::SetCount {
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                add: false,
+                            },
+                            conditions: &[],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::EnchantedCountIncrease {
+                                enchantment: "minecraft:looting",
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: 0i32,
+                            },
+                            conditions: &[],
+                        },
+                    ],
+                }],
+                conditions: &[LootCondition::KilledByPlayer],
+                functions: &[],
+            },
+        ],
+        functions: &[],
+    };
+    pub const ENTITIES_FOX: LootTable = LootTable {
+        name: "minecraft:entities/fox",
+        kind: LootTableKind::Entity,
+        pools: &[],
+        functions: &[],
+    };
+    pub const ENTITIES_FROG: LootTable = LootTable {
+        name: "minecraft:entities/frog",
+        kind: LootTableKind::Entity,
+        pools: &[],
+        functions: &[],
+    };
+    pub const ENTITIES_GHAST: LootTable = LootTable {
+        name: "minecraft:entities/ghast",
+        kind: LootTableKind::Entity,
+        pools: &[
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:ghast_tear"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[
+                        LootFunction {
+                            kind: LootFunctionKind::SetCount {
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                add: false,
+                            },
+                            conditions: &[],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::EnchantedCountIncrease {
+                                enchantment: "minecraft:looting",
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: 0i32,
+                            },
+                            conditions: &[],
+                        },
+                    ],
+                }],
+                conditions: &[],
+                functions: &[],
+            },
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:gunpowder"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[
+                        LootFunction {
+                            kind: LootFunctionKind::SetCount {
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 2f32,
+                                },
+                                add: false,
+                            },
+                            conditions: &[],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::EnchantedCountIncrease {
+                                enchantment: "minecraft:looting",
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: 0i32,
+                            },
+                            conditions: &[],
+                        },
+                    ],
+                }],
+                conditions: &[],
+                functions: &[],
+            },
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:music_disc_tears"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[],
+                }],
+                conditions: &[
+                    LootCondition::DamageSourceProperties(DamageSourcePredicate {
+                        tags: &[("minecraft:is_projectile", true)],
+                        direct_entity: Some(&EntityPredicate {
+                            types: &["minecraft:fireball"],
+                            on_fire: None,
+                            baby: None,
+                        }),
+                        source_entity: None,
+                    }),
+                    LootCondition::KilledByPlayer,
+                ],
+                functions: &[LootFunction {
+                    kind: LootFunctionKind::SetCount {
+                        count: NumberProvider::Constant(1f32),
+                        add: false,
+                    },
+                    conditions: &[],
+                }],
+            },
+        ],
+        functions: &[],
+    };
+    pub const ENTITIES_GIANT: LootTable = LootTable {
+        name: "minecraft:entities/giant",
+        kind: LootTableKind::Entity,
+        pools: &[],
+        functions: &[],
+    };
+    pub const ENTITIES_GLOW_SQUID: LootTable = LootTable {
+        name: "minecraft:entities/glow_squid",
+        kind: LootTableKind::Entity,
+        pools: &[LootPool {
+            rolls: NumberProvider::Constant(1f32),
+            bonus_rolls: NumberProvider::Constant(0f32),
+            entries: &[LootEntry {
+                kind: LootEntryKind::Item("minecraft:glow_ink_sac"),
+                weight: 1u32,
+                quality: 0i32,
+                conditions: &[],
+                functions: &[
+                    LootFunction {
+                        kind: LootFunctionKind::SetCount {
+                            count: NumberProvider::Uniform {
+                                min: 1f32,
+                                max: 3f32,
+                            },
+                            add: false,
+                        },
+                        conditions: &[],
+                    },
+                    LootFunction {
+                        kind: LootFunctionKind::EnchantedCountIncrease {
+                            enchantment: "minecraft:looting",
+                            count: NumberProvider::Uniform {
+                                min: 0f32,
+                                max: 1f32,
+                            },
+                            limit: 0i32,
+                        },
+                        conditions: &[],
+                    },
+                ],
+            }],
+            conditions: &[],
+            functions: &[],
+        }],
+        functions: &[],
+    };
+    pub const ENTITIES_GOAT: LootTable = LootTable {
+        name: "minecraft:entities/goat",
+        kind: LootTableKind::Entity,
+        pools: &[],
+        functions: &[],
+    };
+    pub const ENTITIES_GUARDIAN: LootTable = LootTable {
+        name: "minecraft:entities/guardian",
+        kind: LootTableKind::Entity,
+        pools: &[
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:prismarine_shard"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[
+                        LootFunction {
+                            kind: LootFunctionKind::SetCount {
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 2f32,
+                                },
+                                add: false,
+                            },
+                            conditions: &[],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::EnchantedCountIncrease {
+                                enchantment: "minecraft:looting",
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: 0i32,
+                            },
+                            conditions: &[],
+                        },
+                    ],
+                }],
+                conditions: &[],
+                functions: &[],
+            },
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[
+                    LootEntry {
+                        kind: LootEntryKind::Item("minecraft:cod"),
+                        weight: 2u32,
+                        quality: 0i32,
+                        conditions: &[],
+                        functions: &[
+                            LootFunction {
+                                kind: LootFunctionKind::EnchantedCountIncrease {
+                                    enchantment: "minecraft:looting",
+                                    count: NumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: 0i32,
+                                },
+                                conditions: &[],
+                            },
+                            LootFunction {
+                                kind: LootFunctionKind::Unsupported("minecraft:furnace_smelt"),
+                                conditions: &[LootCondition::AnyOf(&[
+                                    LootCondition::EntityProperties {
+                                        entity: LootEntityTarget::This,
+                                        predicate: EntityPredicate {
+                                            types: &[],
+                                            on_fire: Some(true),
+                                            baby: None,
+                                        },
+                                    },
+                                    LootCondition::Unsupported("minecraft:entity_properties"),
+                                ])],
+                            },
+                        ],
+                    },
+                    LootEntry {
+                        kind: LootEntryKind::Item("minecraft:prismarine_crystals"),
+                        weight: 2u32,
+                        quality: 0i32,
+                        conditions: &[],
+                        functions: &[LootFunction {
+                            kind: LootFunctionKind::EnchantedCountIncrease {
+                                enchantment: "minecraft:looting",
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: 0i32,
+                            },
+                            conditions: &[],
+                        }],
+                    },
+                    LootEntry {
+                        kind: LootEntryKind::Empty,
+                        weight: 1u32,
+                        quality: 0i32,
+                        conditions: &[],
+                        functions: &[],
+                    },
+                ],
+                conditions: &[],
+                functions: &[],
+            },
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::LootTable("minecraft:gameplay/fishing/fish"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[LootFunction {
+                        kind: LootFunctionKind::Unsupported("minecraft:furnace_smelt"),
+                        conditions: &[LootCondition::AnyOf(&[
+                            LootCondition::EntityProperties {
+                                entity: LootEntityTarget::This,
+                                predicate: EntityPredicate {
+                                    types: &[],
+                                    on_fire: Some(true),
+                                    baby: None,
+                                },
+                            },
+                            LootCondition::Unsupported("minecraft:entity_properties"),
+                        ])],
+                    }],
+                }],
+                conditions: &[
+                    LootCondition::KilledByPlayer,
+                    LootCondition::RandomChanceWithEnchantedBonus {
+                        enchantment: "minecraft:looting",
+                        unenchanted_chance: 0.025f32,
+                        base: 0.035f32,
+                        per_level_above_first: 0.01f32,
+                    },
+                ],
+                functions: &[],
+            },
+        ],
+        functions: &[],
+    };
+    pub const ENTITIES_HAPPY_GHAST: LootTable = LootTable {
+        name: "minecraft:entities/happy_ghast",
+        kind: LootTableKind::Entity,
+        pools: &[],
+        functions: &[],
+    };
+    pub const ENTITIES_HOGLIN: LootTable = LootTable {
+        name: "minecraft:entities/hoglin",
+        kind: LootTableKind::Entity,
+        pools: &[
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:porkchop"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[
+                        LootFunction {
+                            kind: LootFunctionKind::SetCount {
+                                count: NumberProvider::Uniform {
+                                    min: 2f32,
+                                    max: 4f32,
+                                },
+                                add: false,
+                            },
+                            conditions: &[],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::Unsupported("minecraft:furnace_smelt"),
+                            conditions: &[LootCondition::AnyOf(&[
+                                LootCondition::EntityProperties {
+                                    entity: LootEntityTarget::This,
+                                    predicate: EntityPredicate {
+                                        types: &[],
+                                        on_fire: Some(true),
+                                        baby: None,
+                                    },
+                                },
+                                LootCondition::Unsupported("minecraft:entity_properties"),
+                            ])],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::EnchantedCountIncrease {
+                                enchantment: "minecraft:looting",
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: 0i32,
+                            },
+                            conditions: &[],
+                        },
+                    ],
+                }],
+                conditions: &[],
+                functions: &[],
+            },
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:leather"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[
+                        LootFunction {
+                            kind: LootFunctionKind::SetCount {
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                add: false,
+                            },
+                            conditions: &[],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::EnchantedCountIncrease {
+                                enchantment: "minecraft:looting",
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: 0i32,
+                            },
+                            conditions: &[],
+                        },
+                    ],
+                }],
+                conditions: &[],
+                functions: &[],
+            },
+        ],
+        functions: &[],
+    };
+    pub const ENTITIES_HORSE: LootTable = LootTable {
+        name: "minecraft:entities/horse",
+        kind: LootTableKind::Entity,
+        pools: &[LootPool {
+            rolls: NumberProvider::Constant(1f32),
+            bonus_rolls: NumberProvider::Constant(0f32),
+            entries: &[LootEntry {
+                kind: LootEntryKind::Item("minecraft:leather"),
+                weight: 1u32,
+                quality: 0i32,
+                conditions: &[],
+                functions: &[
+                    LootFunction {
+                        kind: LootFunctionKind::SetCount {
+                            count: NumberProvider::Uniform {
+                                min: 0f32,
+                                max: 2f32,
+                            },
+                            add: false,
+                        },
+                        conditions: &[],
+                    },
+                    LootFunction {
+                        kind: LootFunctionKind::EnchantedCountIncrease {
+                            enchantment: "minecraft:looting",
+                            count: NumberProvider::Uniform {
+                                min: 0f32,
+                                max: 1f32,
+                            },
+                            limit: 0i32,
+                        },
+                        conditions: &[],
+                    },
+                ],
+            }],
+            conditions: &[],
+            functions: &[],
+        }],
+        functions: &[],
+    };
+    pub const ENTITIES_HUSK: LootTable = LootTable {
+        name: "minecraft:entities/husk",
+        kind: LootTableKind::Entity,
+        pools: &[
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:rotten_flesh"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[
+                        LootFunction {
+                            kind: LootFunctionKind::SetCount {
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 2f32,
+                                },
+                                add: false,
+                            },
+                            conditions: &[],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::EnchantedCountIncrease {
+                                enchantment: "minecraft:looting",
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: 0i32,
+                            },
+                            conditions: &[],
+                        },
+                    ],
+                }],
+                conditions: &[],
+                functions: &[],
+            },
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[
+                    LootEntry {
+                        kind: LootEntryKind::Item("minecraft:iron_ingot"),
+                        weight: 1u32,
+                        quality: 0i32,
+                        conditions: &[],
+                        functions: &[],
+                    },
+                    LootEntry {
+                        kind: LootEntryKind::Item("minecraft:carrot"),
+                        weight: 1u32,
+                        quality: 0i32,
+                        conditions: &[],
+                        functions: &[],
+                    },
+                    LootEntry {
+                        kind: LootEntryKind::Item("minecraft:potato"),
+                        weight: 1u32,
+                        quality: 0i32,
+                        conditions: &[],
+                        functions: &[LootFunction {
+                            kind: LootFunctionKind::Unsupported("minecraft:furnace_smelt"),
+                            conditions: &[LootCondition::AnyOf(&[
+                                LootCondition::EntityProperties {
+                                    entity: LootEntityTarget::This,
+                                    predicate: EntityPredicate {
+                                        types: &[],
+                                        on_fire: Some(true),
+                                        baby: None,
+                                    },
+                                },
+                                LootCondition::Unsupported("minecraft:entity_properties"),
+                            ])],
+                        }],
+                    },
+                ],
+                conditions: &[
+                    LootCondition::KilledByPlayer,
+                    LootCondition::RandomChanceWithEnchantedBonus {
+                        enchantment: "minecraft:looting",
+                        unenchanted_chance: 0.025f32,
+                        base: 0.035f32,
+                        per_level_above_first: 0.01f32,
+                    },
+                ],
+                functions: &[],
+            },
+        ],
+        functions: &[],
+    };
+    pub const ENTITIES_ILLUSIONER: LootTable = LootTable {
+        name: "minecraft:entities/illusioner",
+        kind: LootTableKind::Entity,
+        pools: &[],
+        functions: &[],
+    };
+    pub const ENTITIES_IRON_GOLEM: LootTable = LootTable {
+        name: "minecraft:entities/iron_golem",
+        kind: LootTableKind::Entity,
+        pools: &[
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:poppy"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[LootFunction {
+                        kind: LootFunctionKind::SetCount {
+                            count: NumberProvider::Uniform {
+                                min: 0f32,
+                                max: 2f32,
+                            },
+                            add: false,
+                        },
+                        conditions: &[],
+                    }],
+                }],
+                conditions: &[],
+                functions: &[],
+            },
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:iron_ingot"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[LootFunction {
+                        kind: LootFunctionKind::SetCount {
+                            count: NumberProvider::Uniform {
+                                min: 3f32,
+                                max: 5f32,
+                            },
+                            add: false,
+                        },
+                        conditions: &[],
+                    }],
+                }],
+                conditions: &[],
+                functions: &[],
+            },
+        ],
+        functions: &[],
+    };
+    pub const ENTITIES_LLAMA: LootTable = LootTable {
+        name: "minecraft:entities/llama",
+        kind: LootTableKind::Entity,
+        pools: &[LootPool {
+            rolls: NumberProvider::Constant(1f32),
+            bonus_rolls: NumberProvider::Constant(0f32),
+            entries: &[LootEntry {
+                kind: LootEntryKind::Item("minecraft:leather"),
+                weight: 1u32,
+                quality: 0i32,
+                conditions: &[],
+                functions: &[
+                    LootFunction {
+                        kind: LootFunctionKind::SetCount {
+                            count: NumberProvider::Uniform {
+                                min: 0f32,
+                                max: 2f32,
+                            },
+                            add: false,
+                        },
+                        conditions: &[],
+                    },
+                    LootFunction {
+                        kind: LootFunctionKind::EnchantedCountIncrease {
+                            enchantment: "minecraft:looting",
+                            count: NumberProvider::Uniform {
+                                min: 0f32,
+                                max: 1f32,
+                            },
+                            limit: 0i32,
+                        },
+                        conditions: &[],
+                    },
+                ],
+            }],
+            conditions: &[],
+            functions: &[],
+        }],
+        functions: &[],
+    };
+    pub const ENTITIES_MAGMA_CUBE: LootTable = LootTable {
+        name: "minecraft:entities/magma_cube",
+        kind: LootTableKind::Entity,
+        pools: &[LootPool {
+            rolls: NumberProvider::Constant(1f32),
+            bonus_rolls: NumberProvider::Constant(0f32),
+            entries: &[
+                LootEntry {
+                    kind: LootEntryKind::Item("minecraft:magma_cream"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[
+                        LootCondition::Inverted(&LootCondition::DamageSourceProperties(
+                            DamageSourcePredicate {
+                                tags: &[],
+                                direct_entity: None,
+                                source_entity: Some(&EntityPredicate {
+                                    types: &["minecraft:frog"],
+                                    on_fire: None,
+                                    baby: None,
+                                }),
+                            },
+                        )),
+                        LootCondition::Unsupported("minecraft:entity_properties"),
+                    ],
+                    functions: &[
+                        LootFunction {
+                            kind: LootFunctionKind::SetCount {
+                                count: NumberProvider::Uniform {
+                                    min: -2f32,
+                                    max: 1f32,
+                                },
+                                add: false,
+                            },
+                            conditions: &[],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::EnchantedCountIncrease {
+                                enchantment: "minecraft:looting",
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: 0i32,
+                            },
+                            conditions: &[],
+                        },
+                    ],
+                },
+                LootEntry {
+                    kind: LootEntryKind::Item("minecraft:pearlescent_froglight"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[LootCondition::Unsupported(
+                        "minecraft:damage_source_properties",
+                    )],
+                    functions: &[LootFunction {
+                        kind: LootFunctionKind::SetCount {
+                            count: NumberProvider::Constant(1f32),
+                            add: false,
+                        },
+                        conditions: &[],
+                    }],
+                },
+                LootEntry {
+                    kind: LootEntryKind::Item("minecraft:verdant_froglight"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[LootCondition::Unsupported(
+                        "minecraft:damage_source_properties",
+                    )],
+                    functions: &[LootFunction {
+                        kind: LootFunctionKind::SetCount {
+                            count: NumberProvider::Constant(1f32),
+                            add: false,
+                        },
+                        conditions: &[],
+                    }],
+                },
+                LootEntry {
+                    kind: LootEntryKind::Item("minecraft:ochre_froglight"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[LootCondition::Unsupported(
+                        "minecraft:damage_source_properties",
+                    )],
+                    functions: &[LootFunction {
+                        kind: LootFunctionKind::SetCount {
+                            count: NumberProvider::Constant(1f32),
+                            add: false,
+                        },
+                        conditions: &[],
+                    }],
+                },
+            ],
+            conditions: &[],
+            functions: &[],
+        }],
+        functions: &[],
+    };
+    pub const ENTITIES_MOOSHROOM: LootTable = LootTable {
+        name: "minecraft:entities/mooshroom",
+        kind: LootTableKind::Entity,
+        pools: &[
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:leather"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[
+                        LootFunction {
+                            kind: LootFunctionKind::SetCount {
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 2f32,
+                                },
+                                add: false,
+                            },
+                            conditions: &[],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::EnchantedCountIncrease {
+                                enchantment: "minecraft:looting",
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: 0i32,
+                            },
+                            conditions: &[],
+                        },
+                    ],
+                }],
+                conditions: &[],
+                functions: &[],
+            },
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:beef"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[
+                        LootFunction {
+                            kind: LootFunctionKind::SetCount {
+                                count: NumberProvider::Uniform {
+                                    min: 1f32,
+                                    max: 3f32,
+                                },
+                                add: false,
+                            },
+                            conditions: &[],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::Unsupported("minecraft:furnace_smelt"),
+                            conditions: &[LootCondition::AnyOf(&[
+                                LootCondition::EntityProperties {
+                                    entity: LootEntityTarget::This,
+                                    predicate: EntityPredicate {
+                                        types: &[],
+                                        on_fire: Some(true),
+                                        baby: None,
+                                    },
+                                },
+                                LootCondition::Unsupported("minecraft:entity_properties"),
+                            ])],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::EnchantedCountIncrease {
+                                enchantment: "minecraft:looting",
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: 0i32,
+                            },
+                            conditions: &[],
+                        },
+                    ],
+                }],
+                conditions: &[],
+                functions: &[],
+            },
+        ],
+        functions: &[],
+    };
+    pub const ENTITIES_MULE: LootTable = LootTable {
+        name: "minecraft:entities/mule",
+        kind: LootTableKind::Entity,
+        pools: &[LootPool {
+            rolls: NumberProvider::Constant(1f32),
+            bonus_rolls: NumberProvider::Constant(0f32),
+            entries: &[LootEntry {
+                kind: LootEntryKind::Item("minecraft:leather"),
+                weight: 1u32,
+                quality: 0i32,
+                conditions: &[],
+                functions: &[
+                    LootFunction {
+                        kind: LootFunctionKind::SetCount {
+                            count: NumberProvider::Uniform {
+                                min: 0f32,
+                                max: 2f32,
+                            },
+                            add: false,
+                        },
+                        conditions: &[],
+                    },
+                    LootFunction {
+                        kind: LootFunctionKind::EnchantedCountIncrease {
+                            enchantment: "minecraft:looting",
+                            count: NumberProvider::Uniform {
+                                min: 0f32,
+                                max: 1f32,
+                            },
+                            limit: 0i32,
+                        },
+                        conditions: &[],
+                    },
+                ],
+            }],
+            conditions: &[],
+            functions: &[],
+        }],
+        functions: &[],
+    };
+    pub const ENTITIES_OCELOT: LootTable = LootTable {
+        name: "minecraft:entities/ocelot",
+        kind: LootTableKind::Entity,
+        pools: &[],
+        functions: &[],
+    };
+    pub const ENTITIES_PANDA: LootTable = LootTable {
+        name: "minecraft:entities/panda",
+        kind: LootTableKind::Entity,
+        pools: &[LootPool {
+            rolls: NumberProvider::Constant(1f32),
+            bonus_rolls: NumberProvider::Constant(0f32),
+            entries: &[LootEntry {
+                kind: LootEntryKind::Item("minecraft:bamboo"),
+                weight: 1u32,
+                quality: 0i32,
+                conditions: &[],
+                functions: &[LootFunction {
+                    kind: LootFunctionKind::SetCount {
+                        count: NumberProvider::Constant(1f32),
+                        add: false,
+                    },
+                    conditions: &[],
+                }],
+            }],
+            conditions: &[],
+            functions: &[],
+        }],
+        functions: &[],
+    };
+    pub const ENTITIES_PARROT: LootTable = LootTable {
+        name: "minecraft:entities/parrot",
+        kind: LootTableKind::Entity,
+        pools: &[LootPool {
+            rolls: NumberProvider::Constant(1f32),
+            bonus_rolls: NumberProvider::Constant(0f32),
+            entries: &[LootEntry {
+                kind: LootEntryKind::Item("minecraft:feather"),
+                weight: 1u32,
+                quality: 0i32,
+                conditions: &[],
+                functions: &[
+                    LootFunction {
+                        kind: LootFunctionKind::SetCount {
+                            count: NumberProvider::Uniform {
+                                min: 1f32,
+                                max: 2f32,
+                            },
+                            add: false,
+                        },
+                        conditions: &[],
+                    },
+                    LootFunction {
+                        kind: LootFunctionKind::EnchantedCountIncrease {
+                            enchantment: "minecraft:looting",
+                            count: NumberProvider::Uniform {
+                                min: 0f32,
+                                max: 1f32,
+                            },
+                            limit: 0i32,
+                        },
+                        conditions: &[],
+                    },
+                ],
+            }],
+            conditions: &[],
+            functions: &[],
+        }],
+        functions: &[],
+    };
+    pub const ENTITIES_PHANTOM: LootTable = LootTable {
+        name: "minecraft:entities/phantom",
+        kind: LootTableKind::Entity,
+        pools: &[LootPool {
+            rolls: NumberProvider::Constant(1f32),
+            bonus_rolls: NumberProvider::Constant(0f32),
+            entries: &[LootEntry {
+                kind: LootEntryKind::Item("minecraft:phantom_membrane"),
+                weight: 1u32,
+                quality: 0i32,
+                conditions: &[],
+                functions: &[
+                    LootFunction {
+                        kind: LootFunctionKind::SetCount {
+                            count: NumberProvider::Uniform {
+                                min: 0f32,
+                                max: 1f32,
+                            },
+                            add: false,
+                        },
+                        conditions: &[],
+                    },
+                    LootFunction {
+                        kind: LootFunctionKind::EnchantedCountIncrease {
+                            enchantment: "minecraft:looting",
+                            count: NumberProvider::Uniform {
+                                min: 0f32,
+                                max: 1f32,
+                            },
+                            limit: 0i32,
+                        },
+                        conditions: &[],
+                    },
+                ],
+            }],
+            conditions: &[LootCondition::KilledByPlayer],
+            functions: &[],
+        }],
+        functions: &[],
+    };
+    pub const ENTITIES_PIG: LootTable = LootTable {
+        name: "minecraft:entities/pig",
+        kind: LootTableKind::Entity,
+        pools: &[LootPool {
+            rolls: NumberProvider::Constant(1f32),
+            bonus_rolls: NumberProvider::Constant(0f32),
+            entries: &[LootEntry {
+                kind: LootEntryKind::Item("minecraft:porkchop"),
+                weight: 1u32,
+                quality: 0i32,
+                conditions: &[],
+                functions: &[
+                    LootFunction {
+                        kind: LootFunctionKind::SetCount {
+                            count: NumberProvider::Uniform {
+                                min: 1f32,
+                                max: 3f32,
+                            },
+                            add: false,
+                        },
+                        conditions: &[],
+                    },
+                    LootFunction {
+                        kind: LootFunctionKind::Unsupported("minecraft:furnace_smelt"),
+                        conditions: &[LootCondition::AnyOf(&[
+                            LootCondition::EntityProperties {
+                                entity: LootEntityTarget::This,
+                                predicate: EntityPredicate {
+                                    types: &[],
+                                    on_fire: Some(true),
+                                    baby: None,
+                                },
+                            },
+                            LootCondition::Unsupported("minecraft:entity_properties"),
+                        ])],
+                    },
+                    LootFunction {
+                        kind: LootFunctionKind::EnchantedCountIncrease {
+                            enchantment: "minecraft:looting",
+                            count: NumberProvider::Uniform {
+                                min: 0f32,
+                                max: 1f32,
+                            },
+                            limit: 0i32,
+                        },
+                        conditions: &[],
+                    },
+                ],
+            }],
+            conditions: &[],
+            functions: &[],
+        }],
+        functions: &[],
+    };
+    pub const ENTITIES_PIGLIN: LootTable = LootTable {
+        name: "minecraft:entities/piglin",
+        kind: LootTableKind::Entity,
+        pools: &[],
+        functions: &[],
+    };
+    pub const ENTITIES_PIGLIN_BRUTE: LootTable = LootTable {
+        name: "minecraft:entities/piglin_brute",
+        kind: LootTableKind::Entity,
+        pools: &[],
+        functions: &[],
+    };
+    pub const ENTITIES_PILLAGER: LootTable = LootTable {
+        name: "minecraft:entities/pillager",
+        kind: LootTableKind::Entity,
+        pools: &[LootPool {
+            rolls: NumberProvider::Constant(1f32),
+            bonus_rolls: NumberProvider::Constant(0f32),
+            entries: &[LootEntry {
+                kind: LootEntryKind::Item("minecraft:ominous_bottle"),
+                weight: 1u32,
+                quality: 0i32,
+                conditions: &[],
+                functions: &[
+                    LootFunction {
+                        kind: LootFunctionKind::SetCount {
+                            count: NumberProvider::Constant(1f32),
+                            add: false,
+                        },
+                        conditions: &[],
+                    },
+                    LootFunction {
+                        kind: LootFunctionKind::Unsupported(
+                            "minecraft:set_ominous_bottle_amplifier",
+                        ),
+                        conditions: &[],
+                    },
+                ],
+            }],
+            conditions: &[LootCondition::Unsupported("minecraft:entity_properties")],
+            functions: &[],
+        }],
+        functions: &[],
+    };
+    pub const ENTITIES_PLAYER: LootTable = LootTable {
+        name: "minecraft:entities/player",
+        kind: LootTableKind::Entity,
+        pools: &[],
+        functions: &[],
+    };
+    pub const ENTITIES_POLAR_BEAR: LootTable = LootTable {
+        name: "minecraft:entities/polar_bear",
+        kind: LootTableKind::Entity,
+        pools: &[LootPool {
+            rolls: NumberProvider::Constant(1f32),
+            bonus_rolls: NumberProvider::Constant(0f32),
+            entries: &[
+                LootEntry {
+                    kind: LootEntryKind::Item("minecraft:cod"),
+                    weight: 3u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[
+                        LootFunction {
+                            kind: LootFunctionKind::Unsupported("minecraft:furnace_smelt"),
+                            conditions: &[LootCondition::AnyOf(&[
+                                LootCondition::EntityProperties {
+                                    entity: LootEntityTarget::This,
+                                    predicate: EntityPredicate {
+                                        types: &[],
+                                        on_fire: Some(true),
+                                        baby: None,
+                                    },
+                                },
+                                LootCondition::Unsupported("minecraft:entity_properties"),
+                            ])],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::SetCount {
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 2f32,
+                                },
+                                add: false,
+                            },
+                            conditions: &[],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::EnchantedCountIncrease {
+                                enchantment: "minecraft:looting",
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: 0i32,
+                            },
+                            conditions: &[],
+                        },
+                    ],
+                },
+                LootEntry {
+                    kind: LootEntryKind::Item("minecraft:salmon"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[
+                        LootFunction {
+                            kind: LootFunctionKind::Unsupported("minecraft:furnace_smelt"),
+                            conditions: &[LootCondition::AnyOf(&[
+                                LootCondition::EntityProperties {
+                                    entity: LootEntityTarget::This,
+                                    predicate: EntityPredicate {
+                                        types: &[],
+                                        on_fire: Some(true),
+                                        baby: None,
+                                    },
+                                },
+                                LootCondition::Unsupported("minecraft:entity_properties"),
+                            ])],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::SetCount {
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 2f32,
+                                },
+                                add: false,
+                            },
+                            conditions: &[],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::EnchantedCountIncrease {
+                                enchantment: "minecraft:looting",
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: 0i32,
+                            },
+                            conditions: &[],
+                        },
+                    ],
+                },
+            ],
+            conditions: &[],
+            functions: &[],
+        }],
+        functions: &[],
+    };
+    pub const ENTITIES_PUFFERFISH: LootTable = LootTable {
+        name: "minecraft:entities/pufferfish",
+        kind: LootTableKind::Entity,
+        pools: &[
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:pufferfish"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[LootFunction {
+                        kind: LootFunctionKind::SetCount {
+                            count: NumberProvider::Constant(1f32),
+                            add: false,
+                        },
+                        conditions: &[],
+                    }],
+                }],
+                conditions: &[],
+                functions: &[],
+            },
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:bone_meal"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[],
+                }],
+                conditions: &[LootCondition::RandomChance(0.05f32)],
+                functions: &[],
+            },
+        ],
+        functions: &[],
+    };
+    pub const ENTITIES_RABBIT: LootTable = LootTable {
+        name: "minecraft:entities/rabbit",
+        kind: LootTableKind::Entity,
+        pools: &[
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:rabbit_hide"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[
+                        LootFunction {
+                            kind: LootFunctionKind::SetCount {
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                add: false,
+                            },
+                            conditions: &[],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::EnchantedCountIncrease {
+                                enchantment: "minecraft:looting",
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: 0i32,
+                            },
+                            conditions: &[],
+                        },
+                    ],
+                }],
+                conditions: &[],
+                functions: &[],
+            },
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:rabbit"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[
+                        LootFunction {
+                            kind: LootFunctionKind::SetCount {
+                                count: NumberProvider::Constant(1f32),
+                                add: false,
+                            },
+                            conditions: &[],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::Unsupported("minecraft:furnace_smelt"),
+                            conditions: &[LootCondition::AnyOf(&[
+                                LootCondition::EntityProperties {
+                                    entity: LootEntityTarget::This,
+                                    predicate: EntityPredicate {
+                                        types: &[],
+                                        on_fire: Some(true),
+                                        baby: None,
+                                    },
+                                },
+                                LootCondition::Unsupported("minecraft:entity_properties"),
+                            ])],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::EnchantedCountIncrease {
+                                enchantment: "minecraft:looting",
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: 0i32,
+                            },
+                            conditions: &[],
+                        },
+                    ],
+                }],
+                conditions: &[],
+                functions: &[],
+            },
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:rabbit_foot"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[],
+                }],
+                conditions: &[
+                    LootCondition::KilledByPlayer,
+                    LootCondition::RandomChanceWithEnchantedBonus {
+                        enchantment: "minecraft:looting",
+                        unenchanted_chance: 0.1f32,
+                        base: 0.13f32,
+                        per_level_above_first: 0.03f32,
+                    },
+                ],
+                functions: &[],
+            },
+        ],
+        functions: &[],
+    };
+    pub const ENTITIES_RAVAGER: LootTable = LootTable {
+        name: "minecraft:entities/ravager",
+        kind: LootTableKind::Entity,
+        pools: &[LootPool {
+            rolls: NumberProvider::Constant(1f32),
+            bonus_rolls: NumberProvider::Constant(0f32),
+            entries: &[LootEntry {
+                kind: LootEntryKind::Item("minecraft:saddle"),
+                weight: 1u32,
+                quality: 0i32,
+                conditions: &[],
+                functions: &[LootFunction {
+                    kind: LootFunctionKind::SetCount {
+                        count: NumberProvider::Constant(1f32),
+                        add: false,
+                    },
+                    conditions: &[],
+                }],
+            }],
+            conditions: &[],
+            functions: &[],
+        }],
+        functions: &[],
+    };
+    pub const ENTITIES_SALMON: LootTable = LootTable {
+        name: "minecraft:entities/salmon",
+        kind: LootTableKind::Entity,
+        pools: &[
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:salmon"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[LootFunction {
+                        kind: LootFunctionKind::Unsupported("minecraft:furnace_smelt"),
+                        conditions: &[LootCondition::AnyOf(&[
+                            LootCondition::EntityProperties {
+                                entity: LootEntityTarget::This,
+                                predicate: EntityPredicate {
+                                    types: &[],
+                                    on_fire: Some(true),
+                                    baby: None,
+                                },
+                            },
+                            LootCondition::Unsupported("minecraft:entity_properties"),
+                        ])],
+                    }],
+                }],
+                conditions: &[],
+                functions: &[],
+            },
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:bone_meal"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[],
+                }],
+                conditions: &[LootCondition::RandomChance(0.05f32)],
+                functions: &[],
+            },
+        ],
+        functions: &[],
+    };
+    pub const ENTITIES_SHEEP: LootTable = LootTable {
+        name: "minecraft:entities/sheep",
+        kind: LootTableKind::Entity,
+        pools: &[
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:mutton"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[
+                        LootFunction {
+                            kind: LootFunctionKind::SetCount {
+                                count: NumberProvider::Uniform {
+                                    min: 1f32,
+                                    max: 2f32,
+                                },
+                                add: false,
+                            },
+                            conditions: &[],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::Unsupported("minecraft:furnace_smelt"),
+                            conditions: &[LootCondition::AnyOf(&[
+                                LootCondition::EntityProperties {
+                                    entity: LootEntityTarget::This,
+                                    predicate: EntityPredicate {
+                                        types: &[],
+                                        on_fire: Some(true),
+                                        baby: None,
+                                    },
+                                },
+                                LootCondition::Unsupported("minecraft:entity_properties"),
+                            ])],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::EnchantedCountIncrease {
+                                enchantment: "minecraft:looting",
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: 0i32,
+                            },
+                            conditions: &[],
+                        },
+                    ],
+                }],
+                conditions: &[],
+                functions: &[],
+            },
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Alternatives(&[
+                        LootEntry {
+                            kind: LootEntryKind::LootTable("minecraft:entities/sheep/white"),
+                            weight: 1u32,
+                            quality: 0i32,
+                            conditions: &[LootCondition::Unsupported(
+                                "minecraft:entity_properties",
+                            )],
+                            functions: &[],
+                        },
+                        LootEntry {
+                            kind: LootEntryKind::LootTable("minecraft:entities/sheep/orange"),
+                            weight: 1u32,
+                            quality: 0i32,
+                            conditions: &[LootCondition::Unsupported(
+                                "minecraft:entity_properties",
+                            )],
+                            functions: &[],
+                        },
+                        LootEntry {
+                            kind: LootEntryKind::LootTable("minecraft:entities/sheep/magenta"),
+                            weight: 1u32,
+                            quality: 0i32,
+                            conditions: &[LootCondition::Unsupported(
+                                "minecraft:entity_properties",
+                            )],
+                            functions: &[],
+                        },
+                        LootEntry {
+                            kind: LootEntryKind::LootTable("minecraft:entities/sheep/light_blue"),
+                            weight: 1u32,
+                            quality: 0i32,
+                            conditions: &[LootCondition::Unsupported(
+                                "minecraft:entity_properties",
+                            )],
+                            functions: &[],
+                        },
+                        LootEntry {
+                            kind: LootEntryKind::LootTable("minecraft:entities/sheep/yellow"),
+                            weight: 1u32,
+                            quality: 0i32,
+                            conditions: &[LootCondition::Unsupported(
+                                "minecraft:entity_properties",
+                            )],
+                            functions: &[],
+                        },
+                        LootEntry {
+                            kind: LootEntryKind::LootTable("minecraft:entities/sheep/lime"),
+                            weight: 1u32,
+                            quality: 0i32,
+                            conditions: &[LootCondition::Unsupported(
+                                "minecraft:entity_properties",
+                            )],
+                            functions: &[],
+                        },
+                        LootEntry {
+                            kind: LootEntryKind::LootTable("minecraft:entities/sheep/pink"),
+                            weight: 1u32,
+                            quality: 0i32,
+                            conditions: &[LootCondition::Unsupported(
+                                "minecraft:entity_properties",
+                            )],
+                            functions: &[],
+                        },
+                        LootEntry {
+                            kind: LootEntryKind::LootTable("minecraft:entities/sheep/gray"),
+                            weight: 1u32,
+                            quality: 0i32,
+                            conditions: &[LootCondition::Unsupported(
+                                "minecraft:entity_properties",
+                            )],
+                            functions: &[],
+                        },
+                        LootEntry {
+                            kind: LootEntryKind::LootTable("minecraft:entities/sheep/light_gray"),
+                            weight: 1u32,
+                            quality: 0i32,
+                            conditions: &[LootCondition::Unsupported(
+                                "minecraft:entity_properties",
+                            )],
+                            functions: &[],
+                        },
+                        LootEntry {
+                            kind: LootEntryKind::LootTable("minecraft:entities/sheep/cyan"),
+                            weight: 1u32,
+                            quality: 0i32,
+                            conditions: &[LootCondition::Unsupported(
+                                "minecraft:entity_properties",
+                            )],
+                            functions: &[],
+                        },
+                        LootEntry {
+                            kind: LootEntryKind::LootTable("minecraft:entities/sheep/purple"),
+                            weight: 1u32,
+                            quality: 0i32,
+                            conditions: &[LootCondition::Unsupported(
+                                "minecraft:entity_properties",
+                            )],
+                            functions: &[],
+                        },
+                        LootEntry {
+                            kind: LootEntryKind::LootTable("minecraft:entities/sheep/blue"),
+                            weight: 1u32,
+                            quality: 0i32,
+                            conditions: &[LootCondition::Unsupported(
+                                "minecraft:entity_properties",
+                            )],
+                            functions: &[],
+                        },
+                        LootEntry {
+                            kind: LootEntryKind::LootTable("minecraft:entities/sheep/brown"),
+                            weight: 1u32,
+                            quality: 0i32,
+                            conditions: &[LootCondition::Unsupported(
+                                "minecraft:entity_properties",
+                            )],
+                            functions: &[],
+                        },
+                        LootEntry {
+                            kind: LootEntryKind::LootTable("minecraft:entities/sheep/green"),
+                            weight: 1u32,
+                            quality: 0i32,
+                            conditions: &[LootCondition::Unsupported(
+                                "minecraft:entity_properties",
+                            )],
+                            functions: &[],
+                        },
+                        LootEntry {
+                            kind: LootEntryKind::LootTable("minecraft:entities/sheep/red"),
+                            weight: 1u32,
+                            quality: 0i32,
+                            conditions: &[LootCondition::Unsupported(
+                                "minecraft:entity_properties",
+                            )],
+                            functions: &[],
+                        },
+                        LootEntry {
+                            kind: LootEntryKind::LootTable("minecraft:entities/sheep/black"),
+                            weight: 1u32,
+                            quality: 0i32,
+                            conditions: &[LootCondition::Unsupported(
+                                "minecraft:entity_properties",
+                            )],
+                            functions: &[],
+                        },
+                    ]),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[],
+                }],
+                conditions: &[],
+                functions: &[],
+            },
+        ],
+        functions: &[],
+    };
+    pub const ENTITIES_SHULKER: LootTable = LootTable {
+        name: "minecraft:entities/shulker",
+        kind: LootTableKind::Entity,
+        pools: &[LootPool {
+            rolls: NumberProvider::Constant(1f32),
+            bonus_rolls: NumberProvider::Constant(0f32),
+            entries: &[LootEntry {
+                kind: LootEntryKind::Item("minecraft:shulker_shell"),
+                weight: 1u32,
+                quality: 0i32,
+                conditions: &[],
+                functions: &[],
+            }],
+            conditions: &[LootCondition::RandomChanceWithEnchantedBonus {
+                enchantment: "minecraft:looting",
+                unenchanted_chance: 0.5f32,
+                base: 0.5625f32,
+                per_level_above_first: 0.0625f32,
+            }],
+            functions: &[],
+        }],
+        functions: &[],
+    };
+    pub const ENTITIES_SILVERFISH: LootTable = LootTable {
+        name: "minecraft:entities/silverfish",
+        kind: LootTableKind::Entity,
+        pools: &[],
+        functions: &[],
+    };
+    pub const ENTITIES_SKELETON: LootTable = LootTable {
+        name: "minecraft:entities/skeleton",
+        kind: LootTableKind::Entity,
+        pools: &[
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:arrow"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[
+                        LootFunction {
+                            kind: LootFunctionKind::SetCount {
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 2f32,
+                                },
+                                add: false,
+                            },
+                            conditions: &[],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::EnchantedCountIncrease {
+                                enchantment: "minecraft:looting",
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: 0i32,
+                            },
+                            conditions: &[],
+                        },
+                    ],
+                }],
+                conditions: &[],
+                functions: &[],
+            },
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:bone"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[
+                        LootFunction {
+                            kind: LootFunctionKind::SetCount {
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 2f32,
+                                },
+                                add: false,
+                            },
+                            conditions: &[],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::EnchantedCountIncrease {
+                                enchantment: "minecraft:looting",
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: 0i32,
+                            },
+                            conditions: &[],
+                        },
+                    ],
+                }],
+                conditions: &[],
+                functions: &[],
+            },
+        ],
+        functions: &[],
+    };
+    pub const ENTITIES_SKELETON_HORSE: LootTable = LootTable {
+        name: "minecraft:entities/skeleton_horse",
+        kind: LootTableKind::Entity,
+        pools: &[LootPool {
+            rolls: NumberProvider::Constant(1f32),
+            bonus_rolls: NumberProvider::Constant(0f32),
+            entries: &[LootEntry {
+                kind: LootEntryKind::Item("minecraft:bone"),
+                weight: 1u32,
+                quality: 0i32,
+                conditions: &[],
+                functions: &[
+                    LootFunction {
+                        kind: LootFunctionKind::SetCount {
+                            count: NumberProvider::Uniform {
+                                min: 0f32,
+                                max: 2f32,
+                            },
+                            add: false,
+                        },
+                        conditions: &[],
+                    },
+                    LootFunction {
+                        kind: LootFunctionKind::EnchantedCountIncrease {
+                            enchantment: "minecraft:looting",
+                            count: NumberProvider::Uniform {
+                                min: 0f32,
+                                max: 1f32,
+                            },
+                            limit: 0i32,
+                        },
+                        conditions: &[],
+                    },
+                ],
+            }],
+            conditions: &[],
+            functions: &[],
+        }],
+        functions: &[],
+    };
+    pub const ENTITIES_SLIME: LootTable = LootTable {
+        name: "minecraft:entities/slime",
+        kind: LootTableKind::Entity,
+        pools: &[LootPool {
+            rolls: NumberProvider::Constant(1f32),
+            bonus_rolls: NumberProvider::Constant(0f32),
+            entries: &[
+                LootEntry {
+                    kind: LootEntryKind::Item("minecraft:slime_ball"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[LootCondition::Inverted(
+                        &LootCondition::DamageSourceProperties(DamageSourcePredicate {
+                            tags: &[],
+                            direct_entity: None,
+                            source_entity: Some(&EntityPredicate {
+                                types: &["minecraft:frog"],
+                                on_fire: None,
+                                baby: None,
+                            }),
+                        }),
+                    )],
+                    functions: &[
+                        LootFunction {
+                            kind: LootFunctionKind::SetCount {
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 2f32,
+                                },
+                                add: false,
+                            },
+                            conditions: &[],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::EnchantedCountIncrease {
+                                enchantment: "minecraft:looting",
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: 0i32,
+                            },
+                            conditions: &[],
+                        },
+                    ],
+                },
+                LootEntry {
+                    kind: LootEntryKind::Item("minecraft:slime_ball"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[LootCondition::DamageSourceProperties(
+                        DamageSourcePredicate {
+                            tags: &[],
+                            direct_entity: None,
+                            source_entity: Some(&EntityPredicate {
+                                types: &["minecraft:frog"],
+                                on_fire: None,
+                                baby: None,
+                            }),
+                        },
+                    )],
+                    functions: &[LootFunction {
+                        kind: LootFunctionKind::SetCount {
+                            count: NumberProvider::Constant(1f32),
+                            add: false,
+                        },
+                        conditions: &[],
+                    }],
+                },
+            ],
+            conditions: &[LootCondition::Unsupported("minecraft:entity_properties")],
+            functions: &[],
+        }],
+        functions: &[],
+    };
+    pub const ENTITIES_SNIFFER: LootTable = LootTable {
+        name: "minecraft:entities/sniffer",
+        kind: LootTableKind::Entity,
+        pools: &[],
+        functions: &[],
+    };
+    pub const ENTITIES_SNOW_GOLEM: LootTable = LootTable {
+        name: "minecraft:entities/snow_golem",
+        kind: LootTableKind::Entity,
+        pools: &[LootPool {
+            rolls: NumberProvider::Constant(1f32),
+            bonus_rolls: NumberProvider::Constant(0f32),
+            entries: &[LootEntry {
+                kind: LootEntryKind::Item("minecraft:snowball"),
+                weight: 1u32,
+                quality: 0i32,
+                conditions: &[],
+                functions: &[LootFunction {
+                    kind: LootFunctionKind::SetCount {
+                        count: NumberProvider::Uniform {
+                            min: 0f32,
+                            max: 15f32,
+                        },
+                        add: false,
+                    },
+                    conditions: &[],
+                }],
+            }],
+            conditions: &[],
+            functions: &[],
+        }],
+        functions: &[],
+    };
+    pub const ENTITIES_SPIDER: LootTable = LootTable {
+        name: "minecraft:entities/spider",
+        kind: LootTableKind::Entity,
+        pools: &[
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:string"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[
+                        LootFunction {
+                            kind: LootFunctionKind::SetCount {
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 2f32,
+                                },
+                                add: false,
+                            },
+                            conditions: &[],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::EnchantedCountIncrease {
+                                enchantment: "minecraft:looting",
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: 0i32,
+                            },
+                            conditions: &[],
+                        },
+                    ],
+                }],
+                conditions: &[],
+                functions: &[],
+            },
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:spider_eye"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[
+                        LootFunction {
+                            kind: LootFunctionKind::SetCount {
+                                count: NumberProvider::Uniform {
+                                    min: -1f32,
+                                    max: 1f32,
+                                },
+                                add: false,
+                            },
+                            conditions: &[],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::EnchantedCountIncrease {
+                                enchantment: "minecraft:looting",
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: 0i32,
+                            },
+                            conditions: &[],
+                        },
+                    ],
+                }],
+                conditions: &[LootCondition::KilledByPlayer],
+                functions: &[],
+            },
+        ],
+        functions: &[],
+    };
+    pub const ENTITIES_SQUID: LootTable = LootTable {
+        name: "minecraft:entities/squid",
+        kind: LootTableKind::Entity,
+        pools: &[LootPool {
+            rolls: NumberProvider::Constant(1f32),
+            bonus_rolls: NumberProvider::Constant(0f32),
+            entries: &[LootEntry {
+                kind: LootEntryKind::Item("minecraft:ink_sac"),
+                weight: 1u32,
+                quality: 0i32,
+                conditions: &[],
+                functions: &[
+                    LootFunction {
+                        kind: LootFunctionKind::SetCount {
+                            count: NumberProvider::Uniform {
+                                min: 1f32,
+                                max: 3f32,
+                            },
+                            add: false,
+                        },
+                        conditions: &[],
+                    },
+                    LootFunction {
+                        kind: LootFunctionKind::EnchantedCountIncrease {
+                            enchantment: "minecraft:looting",
+                            count: NumberProvider::Uniform {
+                                min: 0f32,
+                                max: 1f32,
+                            },
+                            limit: 0i32,
+                        },
+                        conditions: &[],
+                    },
+                ],
+            }],
+            conditions: &[],
+            functions: &[],
+        }],
+        functions: &[],
+    };
+    pub const ENTITIES_STRAY: LootTable = LootTable {
+        name: "minecraft:entities/stray",
+        kind: LootTableKind::Entity,
+        pools: &[
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:arrow"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[
+                        LootFunction {
+                            kind: LootFunctionKind::SetCount {
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 2f32,
+                                },
+                                add: false,
+                            },
+                            conditions: &[],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::EnchantedCountIncrease {
+                                enchantment: "minecraft:looting",
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: 0i32,
+                            },
+                            conditions: &[],
+                        },
+                    ],
+                }],
+                conditions: &[],
+                functions: &[],
+            },
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:bone"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[
+                        LootFunction {
+                            kind: LootFunctionKind::SetCount {
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 2f32,
+                                },
+                                add: false,
+                            },
+                            conditions: &[],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::EnchantedCountIncrease {
+                                enchantment: "minecraft:looting",
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: 0i32,
+                            },
+                            conditions: &[],
+                        },
+                    ],
+                }],
+                conditions: &[],
+                functions: &[],
+            },
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:tipped_arrow"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[
+                        LootFunction {
+                            kind: LootFunctionKind::SetCount {
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                add: false,
+                            },
+                            conditions: &[],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::EnchantedCountIncrease {
+                                enchantment: "minecraft:looting",
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: 1i32,
+                            },
+                            conditions: &[],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::Unsupported("minecraft:set_potion"),
+                            conditions: &[],
+                        },
+                    ],
+                }],
+                conditions: &[LootCondition::KilledByPlayer],
+                functions: &[],
+            },
+        ],
+        functions: &[],
+    };
+    pub const ENTITIES_STRIDER: LootTable = LootTable {
+        name: "minecraft:entities/strider",
+        kind: LootTableKind::Entity,
+        pools: &[LootPool {
+            rolls: NumberProvider::Constant(1f32),
+            bonus_rolls: NumberProvider::Constant(0f32),
+            entries: &[LootEntry {
+                kind: LootEntryKind::Item("minecraft:string"),
+                weight: 1u32,
+                quality: 0i32,
+                conditions: &[],
+                functions: &[
+                    LootFunction {
+                        kind: LootFunctionKind::SetCount {
+                            count: NumberProvider::Uniform {
+                                min: 2f32,
+                                max: 5f32,
+                            },
+                            add: false,
+                        },
+                        conditions: &[],
+                    },
+                    LootFunction {
+                        kind: LootFunctionKind::EnchantedCountIncrease {
+                            enchantment: "minecraft:looting",
+                            count: NumberProvider::Uniform {
+                                min: 0f32,
+                                max: 1f32,
+                            },
+                            limit: 0i32,
+                        },
+                        conditions: &[],
+                    },
+                ],
+            }],
+            conditions: &[],
+            functions: &[],
+        }],
+        functions: &[],
+    };
+    pub const ENTITIES_TADPOLE: LootTable = LootTable {
+        name: "minecraft:entities/tadpole",
+        kind: LootTableKind::Entity,
+        pools: &[],
+        functions: &[],
+    };
+    pub const ENTITIES_TRADER_LLAMA: LootTable = LootTable {
+        name: "minecraft:entities/trader_llama",
+        kind: LootTableKind::Entity,
+        pools: &[LootPool {
+            rolls: NumberProvider::Constant(1f32),
+            bonus_rolls: NumberProvider::Constant(0f32),
+            entries: &[LootEntry {
+                kind: LootEntryKind::Item("minecraft:leather"),
+                weight: 1u32,
+                quality: 0i32,
+                conditions: &[],
+                functions: &[
+                    LootFunction {
+                        kind: LootFunctionKind::SetCount {
+                            count: NumberProvider::Uniform {
+                                min: 0f32,
+                                max: 2f32,
+                            },
+                            add: false,
+                        },
+                        conditions: &[],
+                    },
+                    LootFunction {
+                        kind: LootFunctionKind::EnchantedCountIncrease {
+                            enchantment: "minecraft:looting",
+                            count: NumberProvider::Uniform {
+                                min: 0f32,
+                                max: 1f32,
+                            },
+                            limit: 0i32,
+                        },
+                        conditions: &[],
+                    },
+                ],
+            }],
+            conditions: &[],
+            functions: &[],
+        }],
+        functions: &[],
+    };
+    pub const ENTITIES_TROPICAL_FISH: LootTable = LootTable {
+        name: "minecraft:entities/tropical_fish",
+        kind: LootTableKind::Entity,
+        pools: &[
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:tropical_fish"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[LootFunction {
+                        kind: LootFunctionKind::SetCount {
+                            count: NumberProvider::Constant(1f32),
+                            add: false,
+                        },
+                        conditions: &[],
+                    }],
+                }],
+                conditions: &[],
+                functions: &[],
+            },
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:bone_meal"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[],
+                }],
+                conditions: &[LootCondition::RandomChance(0.05f32)],
+                functions: &[],
+            },
+        ],
+        functions: &[],
+    };
+    pub const ENTITIES_TURTLE: LootTable = LootTable {
+        name: "minecraft:entities/turtle",
+        kind: LootTableKind::Entity,
+        pools: &[
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:seagrass"),
+                    weight: 3u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[
+                        LootFunction {
+                            kind: LootFunctionKind::SetCount {
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 2f32,
+                                },
+                                add: false,
+                            },
+                            conditions: &[],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::EnchantedCountIncrease {
+                                enchantment: "minecraft:looting",
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: 0i32,
+                            },
+                            conditions: &[],
+                        },
+                    ],
+                }],
+                conditions: &[],
+                functions: &[],
+            },
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:bowl"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[],
+                }],
+                conditions: &[LootCondition::DamageSourceProperties(
+                    DamageSourcePredicate {
+                        tags: &[("minecraft:is_lightning", true)],
+                        direct_entity: None,
+                        source_entity: None,
+                    },
+                )],
+                functions: &[],
+            },
+        ],
+        functions: &[],
+    };
+    pub const ENTITIES_VEX: LootTable = LootTable {
+        name: "minecraft:entities/vex",
+        kind: LootTableKind::Entity,
+        pools: &[],
+        functions: &[],
+    };
+    pub const ENTITIES_VILLAGER: LootTable = LootTable {
+        name: "minecraft:entities/villager",
+        kind: LootTableKind::Entity,
+        pools: &[],
+        functions: &[],
+    };
+    pub const ENTITIES_VINDICATOR: LootTable = LootTable {
+        name: "minecraft:entities/vindicator",
+        kind: LootTableKind::Entity,
+        pools: &[LootPool {
+            rolls: NumberProvider::Constant(1f32),
+            bonus_rolls: NumberProvider::Constant(0f32),
+            entries: &[LootEntry {
+                kind: LootEntryKind::Item("minecraft:emerald"),
+                weight: 1u32,
+                quality: 0i32,
+                conditions: &[],
+                functions: &[
+                    LootFunction {
+                        kind: LootFunctionKind::SetCount {
+                            count: NumberProvider::Uniform {
+                                min: 0f32,
+                                max: 1f32,
+                            },
+                            add: false,
+                        },
+                        conditions: &[],
+                    },
+                    LootFunction {
+                        kind: LootFunctionKind::EnchantedCountIncrease {
+                            enchantment: "minecraft:looting",
+                            count: NumberProvider::Uniform {
+                                min: 0f32,
+                                max: 1f32,
+                            },
+                            limit: 0i32,
+                        },
+                        conditions: &[],
+                    },
+                ],
+            }],
+            conditions: &[LootCondition::KilledByPlayer],
+            functions: &[],
+        }],
+        functions: &[],
+    };
+    pub const ENTITIES_WANDERING_TRADER: LootTable = LootTable {
+        name: "minecraft:entities/wandering_trader",
+        kind: LootTableKind::Entity,
+        pools: &[],
+        functions: &[],
+    };
+    pub const ENTITIES_WARDEN: LootTable = LootTable {
+        name: "minecraft:entities/warden",
+        kind: LootTableKind::Entity,
+        pools: &[LootPool {
+            rolls: NumberProvider::Constant(1f32),
+            bonus_rolls: NumberProvider::Constant(0f32),
+            entries: &[LootEntry {
+                kind: LootEntryKind::Item("minecraft:sculk_catalyst"),
+                weight: 1u32,
+                quality: 0i32,
+                conditions: &[],
+                functions: &[],
+            }],
+            conditions: &[],
+            functions: &[],
+        }],
+        functions: &[],
+    };
+    pub const ENTITIES_WITCH: LootTable = LootTable {
+        name: "minecraft:entities/witch",
+        kind: LootTableKind::Entity,
+        pools: &[
+            LootPool {
+                rolls: NumberProvider::Uniform {
+                    min: 1f32,
+                    max: 3f32,
+                },
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[
+                    LootEntry {
+                        kind: LootEntryKind::Item("minecraft:glowstone_dust"),
+                        weight: 1u32,
+                        quality: 0i32,
+                        conditions: &[],
+                        functions: &[
+                            LootFunction {
+                                kind: LootFunctionKind::SetCount {
+                                    count: NumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 2f32,
+                                    },
+                                    add: false,
+                                },
+                                conditions: &[],
+                            },
+                            LootFunction {
+                                kind: LootFunctionKind::EnchantedCountIncrease {
+                                    enchantment: "minecraft:looting",
+                                    count: NumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: 0i32,
+                                },
+                                conditions: &[],
+                            },
+                        ],
+                    },
+                    LootEntry {
+                        kind: LootEntryKind::Item("minecraft:sugar"),
+                        weight: 1u32,
+                        quality: 0i32,
+                        conditions: &[],
+                        functions: &[
+                            LootFunction {
+                                kind: LootFunctionKind::SetCount {
+                                    count: NumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 2f32,
+                                    },
+                                    add: false,
+                                },
+                                conditions: &[],
+                            },
+                            LootFunction {
+                                kind: LootFunctionKind::EnchantedCountIncrease {
+                                    enchantment: "minecraft:looting",
+                                    count: NumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: 0i32,
+                                },
+                                conditions: &[],
+                            },
+                        ],
+                    },
+                    LootEntry {
+                        kind: LootEntryKind::Item("minecraft:spider_eye"),
+                        weight: 1u32,
+                        quality: 0i32,
+                        conditions: &[],
+                        functions: &[
+                            LootFunction {
+                                kind: LootFunctionKind::SetCount {
+                                    count: NumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 2f32,
+                                    },
+                                    add: false,
+                                },
+                                conditions: &[],
+                            },
+                            LootFunction {
+                                kind: LootFunctionKind::EnchantedCountIncrease {
+                                    enchantment: "minecraft:looting",
+                                    count: NumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: 0i32,
+                                },
+                                conditions: &[],
+                            },
+                        ],
+                    },
+                    LootEntry {
+                        kind: LootEntryKind::Item("minecraft:glass_bottle"),
+                        weight: 1u32,
+                        quality: 0i32,
+                        conditions: &[],
+                        functions: &[
+                            LootFunction {
+                                kind: LootFunctionKind::SetCount {
+                                    count: NumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 2f32,
+                                    },
+                                    add: false,
+                                },
+                                conditions: &[],
+                            },
+                            LootFunction {
+                                kind: LootFunctionKind::EnchantedCountIncrease {
+                                    enchantment: "minecraft:looting",
+                                    count: NumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: 0i32,
+                                },
+                                conditions: &[],
+                            },
+                        ],
+                    },
+                    LootEntry {
+                        kind: LootEntryKind::Item("minecraft:gunpowder"),
+                        weight: 1u32,
+                        quality: 0i32,
+                        conditions: &[],
+                        functions: &[
+                            LootFunction {
+                                kind: LootFunctionKind::SetCount {
+                                    count: NumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 2f32,
+                                    },
+                                    add: false,
+                                },
+                                conditions: &[],
+                            },
+                            LootFunction {
+                                kind: LootFunctionKind::EnchantedCountIncrease {
+                                    enchantment: "minecraft:looting",
+                                    count: NumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: 0i32,
+                                },
+                                conditions: &[],
+                            },
+                        ],
+                    },
+                    LootEntry {
+                        kind: LootEntryKind::Item("minecraft:stick"),
+                        weight: 2u32,
+                        quality: 0i32,
+                        conditions: &[],
+                        functions: &[
+                            LootFunction {
+                                kind: LootFunctionKind::SetCount {
+                                    count: NumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 2f32,
+                                    },
+                                    add: false,
+                                },
+                                conditions: &[],
+                            },
+                            LootFunction {
+                                kind: LootFunctionKind::EnchantedCountIncrease {
+                                    enchantment: "minecraft:looting",
+                                    count: NumberProvider::Uniform {
+                                        min: 0f32,
+                                        max: 1f32,
+                                    },
+                                    limit: 0i32,
+                                },
+                                conditions: &[],
+                            },
+                        ],
+                    },
+                ],
+                conditions: &[],
+                functions: &[],
+            },
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:redstone"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[
+                        LootFunction {
+                            kind: LootFunctionKind::SetCount {
+                                count: NumberProvider::Uniform {
+                                    min: 4f32,
+                                    max: 8f32,
+                                },
+                                add: false,
+                            },
+                            conditions: &[],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::EnchantedCountIncrease {
+                                enchantment: "minecraft:looting",
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: 0i32,
+                            },
+                            conditions: &[],
+                        },
+                    ],
+                }],
+                conditions: &[],
+                functions: &[],
+            },
+        ],
+        functions: &[],
+    };
+    pub const ENTITIES_WITHER: LootTable = LootTable {
+        name: "minecraft:entities/wither",
+        kind: LootTableKind::Entity,
+        pools: &[],
+        functions: &[],
+    };
+    pub const ENTITIES_WITHER_SKELETON: LootTable = LootTable {
+        name: "minecraft:entities/wither_skeleton",
+        kind: LootTableKind::Entity,
+        pools: &[
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:coal"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[
+                        LootFunction {
+                            kind: LootFunctionKind::SetCount {
+                                count: NumberProvider::Uniform {
+                                    min: -1f32,
+                                    max: 1f32,
+                                },
+                                add: false,
+                            },
+                            conditions: &[],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::EnchantedCountIncrease {
+                                enchantment: "minecraft:looting",
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: 0i32,
+                            },
+                            conditions: &[],
+                        },
+                    ],
+                }],
+                conditions: &[],
+                functions: &[],
+            },
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:bone"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[
+                        LootFunction {
+                            kind: LootFunctionKind::SetCount {
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 2f32,
+                                },
+                                add: false,
+                            },
+                            conditions: &[],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::EnchantedCountIncrease {
+                                enchantment: "minecraft:looting",
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: 0i32,
+                            },
+                            conditions: &[],
+                        },
+                    ],
+                }],
+                conditions: &[],
+                functions: &[],
+            },
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:wither_skeleton_skull"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[],
+                }],
+                conditions: &[
+                    LootCondition::KilledByPlayer,
+                    LootCondition::RandomChanceWithEnchantedBonus {
+                        enchantment: "minecraft:looting",
+                        unenchanted_chance: 0.025f32,
+                        base: 0.035f32,
+                        per_level_above_first: 0.01f32,
+                    },
+                ],
+                functions: &[],
+            },
+        ],
+        functions: &[],
+    };
+    pub const ENTITIES_WOLF: LootTable = LootTable {
+        name: "minecraft:entities/wolf",
+        kind: LootTableKind::Entity,
+        pools: &[],
+        functions: &[],
+    };
+    pub const ENTITIES_ZOGLIN: LootTable = LootTable {
+        name: "minecraft:entities/zoglin",
+        kind: LootTableKind::Entity,
+        pools: &[LootPool {
+            rolls: NumberProvider::Constant(1f32),
+            bonus_rolls: NumberProvider::Constant(0f32),
+            entries: &[LootEntry {
+                kind: LootEntryKind::Item("minecraft:rotten_flesh"),
+                weight: 1u32,
+                quality: 0i32,
+                conditions: &[],
+                functions: &[
+                    LootFunction {
+                        kind: LootFunctionKind::SetCount {
+                            count: NumberProvider::Uniform {
+                                min: 1f32,
+                                max: 3f32,
+                            },
+                            add: false,
+                        },
+                        conditions: &[],
+                    },
+                    LootFunction {
+                        kind: LootFunctionKind::EnchantedCountIncrease {
+                            enchantment: "minecraft:looting",
+                            count: NumberProvider::Uniform {
+                                min: 0f32,
+                                max: 1f32,
+                            },
+                            limit: 0i32,
+                        },
+                        conditions: &[],
+                    },
+                ],
+            }],
+            conditions: &[],
+            functions: &[],
+        }],
+        functions: &[],
+    };
+    pub const ENTITIES_ZOMBIE: LootTable = LootTable {
+        name: "minecraft:entities/zombie",
+        kind: LootTableKind::Entity,
+        pools: &[
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:rotten_flesh"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[
+                        LootFunction {
+                            kind: LootFunctionKind::SetCount {
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 2f32,
+                                },
+                                add: false,
+                            },
+                            conditions: &[],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::EnchantedCountIncrease {
+                                enchantment: "minecraft:looting",
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: 0i32,
+                            },
+                            conditions: &[],
+                        },
+                    ],
+                }],
+                conditions: &[],
+                functions: &[],
+            },
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[
+                    LootEntry {
+                        kind: LootEntryKind::Item("minecraft:iron_ingot"),
+                        weight: 1u32,
+                        quality: 0i32,
+                        conditions: &[],
+                        functions: &[],
+                    },
+                    LootEntry {
+                        kind: LootEntryKind::Item("minecraft:carrot"),
+                        weight: 1u32,
+                        quality: 0i32,
+                        conditions: &[],
+                        functions: &[],
+                    },
+                    LootEntry {
+                        kind: LootEntryKind::Item("minecraft:potato"),
+                        weight: 1u32,
+                        quality: 0i32,
+                        conditions: &[],
+                        functions: &[LootFunction {
+                            kind: LootFunctionKind::Unsupported("minecraft:furnace_smelt"),
+                            conditions: &[LootCondition::AnyOf(&[
+                                LootCondition::EntityProperties {
+                                    entity: LootEntityTarget::This,
+                                    predicate: EntityPredicate {
+                                        types: &[],
+                                        on_fire: Some(true),
+                                        baby: None,
+                                    },
+                                },
+                                LootCondition::Unsupported("minecraft:entity_properties"),
+                            ])],
+                        }],
+                    },
+                ],
+                conditions: &[
+                    LootCondition::KilledByPlayer,
+                    LootCondition::RandomChanceWithEnchantedBonus {
+                        enchantment: "minecraft:looting",
+                        unenchanted_chance: 0.025f32,
+                        base: 0.035f32,
+                        per_level_above_first: 0.01f32,
+                    },
+                ],
+                functions: &[],
+            },
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:music_disc_lava_chicken"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[],
+                }],
+                conditions: &[
+                    LootCondition::KilledByPlayer,
+                    LootCondition::Unsupported("minecraft:entity_properties"),
+                ],
+                functions: &[],
+            },
+        ],
+        functions: &[],
+    };
+    pub const ENTITIES_ZOMBIE_HORSE: LootTable = LootTable {
+        name: "minecraft:entities/zombie_horse",
+        kind: LootTableKind::Entity,
+        pools: &[LootPool {
+            rolls: NumberProvider::Constant(1f32),
+            bonus_rolls: NumberProvider::Constant(0f32),
+            entries: &[LootEntry {
+                kind: LootEntryKind::Item("minecraft:rotten_flesh"),
+                weight: 1u32,
+                quality: 0i32,
+                conditions: &[],
+                functions: &[
+                    LootFunction {
+                        kind: LootFunctionKind::SetCount {
+                            count: NumberProvider::Uniform {
+                                min: 0f32,
+                                max: 2f32,
+                            },
+                            add: false,
+                        },
+                        conditions: &[],
+                    },
+                    LootFunction {
+                        kind: LootFunctionKind::EnchantedCountIncrease {
+                            enchantment: "minecraft:looting",
+                            count: NumberProvider::Uniform {
+                                min: 0f32,
+                                max: 1f32,
+                            },
+                            limit: 0i32,
+                        },
+                        conditions: &[],
+                    },
+                ],
+            }],
+            conditions: &[],
+            functions: &[],
+        }],
+        functions: &[],
+    };
+    pub const ENTITIES_ZOMBIE_VILLAGER: LootTable = LootTable {
+        name: "minecraft:entities/zombie_villager",
+        kind: LootTableKind::Entity,
+        pools: &[
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:rotten_flesh"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[
+                        LootFunction {
+                            kind: LootFunctionKind::SetCount {
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 2f32,
+                                },
+                                add: false,
+                            },
+                            conditions: &[],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::EnchantedCountIncrease {
+                                enchantment: "minecraft:looting",
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: 0i32,
+                            },
+                            conditions: &[],
+                        },
+                    ],
+                }],
+                conditions: &[],
+                functions: &[],
+            },
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[
+                    LootEntry {
+                        kind: LootEntryKind::Item("minecraft:iron_ingot"),
+                        weight: 1u32,
+                        quality: 0i32,
+                        conditions: &[],
+                        functions: &[],
+                    },
+                    LootEntry {
+                        kind: LootEntryKind::Item("minecraft:carrot"),
+                        weight: 1u32,
+                        quality: 0i32,
+                        conditions: &[],
+                        functions: &[],
+                    },
+                    LootEntry {
+                        kind: LootEntryKind::Item("minecraft:potato"),
+                        weight: 1u32,
+                        quality: 0i32,
+                        conditions: &[],
+                        functions: &[LootFunction {
+                            kind: LootFunctionKind::Unsupported("minecraft:furnace_smelt"),
+                            conditions: &[LootCondition::AnyOf(&[
+                                LootCondition::EntityProperties {
+                                    entity: LootEntityTarget::This,
+                                    predicate: EntityPredicate {
+                                        types: &[],
+                                        on_fire: Some(true),
+                                        baby: None,
+                                    },
+                                },
+                                LootCondition::Unsupported("minecraft:entity_properties"),
+                            ])],
+                        }],
+                    },
+                ],
+                conditions: &[
+                    LootCondition::KilledByPlayer,
+                    LootCondition::RandomChanceWithEnchantedBonus {
+                        enchantment: "minecraft:looting",
+                        unenchanted_chance: 0.025f32,
+                        base: 0.035f32,
+                        per_level_above_first: 0.01f32,
+                    },
+                ],
+                functions: &[],
+            },
+        ],
+        functions: &[],
+    };
+    pub const ENTITIES_ZOMBIFIED_PIGLIN: LootTable = LootTable {
+        name: "minecraft:entities/zombified_piglin",
+        kind: LootTableKind::Entity,
+        pools: &[
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:rotten_flesh"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[
+                        LootFunction {
+                            kind: LootFunctionKind::SetCount {
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                add: false,
+                            },
+                            conditions: &[],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::EnchantedCountIncrease {
+                                enchantment: "minecraft:looting",
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: 0i32,
+                            },
+                            conditions: &[],
+                        },
+                    ],
+                }],
+                conditions: &[],
+                functions: &[],
+            },
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:gold_nugget"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[
+                        LootFunction {
+                            kind: LootFunctionKind::SetCount {
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                add: false,
+                            },
+                            conditions: &[],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::EnchantedCountIncrease {
+                                enchantment: "minecraft:looting",
+                                count: NumberProvider::Uniform {
+                                    min: 0f32,
+                                    max: 1f32,
+                                },
+                                limit: 0i32,
+                            },
+                            conditions: &[],
+                        },
+                    ],
+                }],
+                conditions: &[],
+                functions: &[],
+            },
+            LootPool {
+                rolls: NumberProvider::Constant(1f32),
+                bonus_rolls: NumberProvider::Constant(0f32),
+                entries: &[LootEntry {
+                    kind: LootEntryKind::Item("minecraft:gold_ingot"),
+                    weight: 1u32,
+                    quality: 0i32,
+                    conditions: &[],
+                    functions: &[],
+                }],
+                conditions: &[
+                    LootCondition::KilledByPlayer,
+                    LootCondition::RandomChanceWithEnchantedBonus {
+                        enchantment: "minecraft:looting",
+                        unenchanted_chance: 0.025f32,
+                        base: 0.035f32,
+                        per_level_above_first: 0.01f32,
+                    },
+                ],
+                functions: &[],
+            },
+        ],
+        functions: &[],
+    };
+    #[doc = r" Looks up a loot table by name, e.g. `minecraft:entities/zombie` or"]
+    #[doc = r" `minecraft:blocks/stone`."]
+    pub fn by_name(name: &str) -> Option<&'static LootTable> {
+        if let Some(block) = name.strip_prefix("minecraft:blocks/") {
+            return crate::blocks::Block::by_name(block).and_then(|block| block.loot_table);
+        }
+        match name {
+            "minecraft:entities/allay" => Some(&Self::ENTITIES_ALLAY),
+            "minecraft:entities/armadillo" => Some(&Self::ENTITIES_ARMADILLO),
+            "minecraft:entities/armor_stand" => Some(&Self::ENTITIES_ARMOR_STAND),
+            "minecraft:entities/axolotl" => Some(&Self::ENTITIES_AXOLOTL),
+            "minecraft:entities/bat" => Some(&Self::ENTITIES_BAT),
+            "minecraft:entities/bee" => Some(&Self::ENTITIES_BEE),
+            "minecraft:entities/blaze" => Some(&Self::ENTITIES_BLAZE),
+            "minecraft:entities/bogged" => Some(&Self::ENTITIES_BOGGED),
+            "minecraft:entities/breeze" => Some(&Self::ENTITIES_BREEZE),
+            "minecraft:entities/camel" => Some(&Self::ENTITIES_CAMEL),
+            "minecraft:entities/cat" => Some(&Self::ENTITIES_CAT),
+            "minecraft:entities/cave_spider" => Some(&Self::ENTITIES_CAVE_SPIDER),
+            "minecraft:entities/chicken" => Some(&Self::ENTITIES_CHICKEN),
+            "minecraft:entities/cod" => Some(&Self::ENTITIES_COD),
+            "minecraft:entities/cow" => Some(&Self::ENTITIES_COW),
+            "minecraft:entities/creaking" => Some(&Self::ENTITIES_CREAKING),
+            "minecraft:entities/creeper" => Some(&Self::ENTITIES_CREEPER),
+            "minecraft:entities/dolphin" => Some(&Self::ENTITIES_DOLPHIN),
+            "minecraft:entities/donkey" => Some(&Self::ENTITIES_DONKEY),
+            "minecraft:entities/drowned" => Some(&Self::ENTITIES_DROWNED),
+            "minecraft:entities/elder_guardian" => Some(&Self::ENTITIES_ELDER_GUARDIAN),
+            "minecraft:entities/ender_dragon" => Some(&Self::ENTITIES_ENDER_DRAGON),
+            "minecraft:entities/enderman" => Some(&Self::ENTITIES_ENDERMAN),
+            "minecraft:entities/endermite" => Some(&Self::ENTITIES_ENDERMITE),
+            "minecraft:entities/evoker" => Some(&Self::ENTITIES_EVOKER),
+            "minecraft:entities/fox" => Some(&Self::ENTITIES_FOX),
+            "minecraft:entities/frog" => Some(&Self::ENTITIES_FROG),
+            "minecraft:entities/ghast" => Some(&Self::ENTITIES_GHAST),
+            "minecraft:entities/giant" => Some(&Self::ENTITIES_GIANT),
+            "minecraft:entities/glow_squid" => Some(&Self::ENTITIES_GLOW_SQUID),
+            "minecraft:entities/goat" => Some(&Self::ENTITIES_GOAT),
+            "minecraft:entities/guardian" => Some(&Self::ENTITIES_GUARDIAN),
+            "minecraft:entities/happy_ghast" => Some(&Self::ENTITIES_HAPPY_GHAST),
+            "minecraft:entities/hoglin" => Some(&Self::ENTITIES_HOGLIN),
+            "minecraft:entities/horse" => Some(&Self::ENTITIES_HORSE),
+            "minecraft:entities/husk" => Some(&Self::ENTITIES_HUSK),
+            "minecraft:entities/illusioner" => Some(&Self::ENTITIES_ILLUSIONER),
+            "minecraft:entities/iron_golem" => Some(&Self::ENTITIES_IRON_GOLEM),
+            "minecraft:entities/llama" => Some(&Self::ENTITIES_LLAMA),
+            "minecraft:entities/magma_cube" => Some(&Self::ENTITIES_MAGMA_CUBE),
+            "minecraft:entities/mooshroom" => Some(&Self::ENTITIES_MOOSHROOM),
+            "minecraft:entities/mule" => Some(&Self::ENTITIES_MULE),
+            "minecraft:entities/ocelot" => Some(&Self::ENTITIES_OCELOT),
+            "minecraft:entities/panda" => Some(&Self::ENTITIES_PANDA),
+            "minecraft:entities/parrot" => Some(&Self::ENTITIES_PARROT),
+            "minecraft:entities/phantom" => Some(&Self::ENTITIES_PHANTOM),
+            "minecraft:entities/pig" => Some(&Self::ENTITIES_PIG),
+            "minecraft:entities/piglin" => Some(&Self::ENTITIES_PIGLIN),
+            "minecraft:entities/piglin_brute" => Some(&Self::ENTITIES_PIGLIN_BRUTE),
+            "minecraft:entities/pillager" => Some(&Self::ENTITIES_PILLAGER),
+            "minecraft:entities/player" => Some(&Self::ENTITIES_PLAYER),
+            "minecraft:entities/polar_bear" => Some(&Self::ENTITIES_POLAR_BEAR),
+            "minecraft:entities/pufferfish" => Some(&Self::ENTITIES_PUFFERFISH),
+            "minecraft:entities/rabbit" => Some(&Self::ENTITIES_RABBIT),
+            "minecraft:entities/ravager" => Some(&Self::ENTITIES_RAVAGER),
+            "minecraft:entities/salmon" => Some(&Self::ENTITIES_SALMON),
+            "minecraft:entities/sheep" => Some(&Self::ENTITIES_SHEEP),
+            "minecraft:entities/shulker" => Some(&Self::ENTITIES_SHULKER),
+            "minecraft:entities/silverfish" => Some(&Self::ENTITIES_SILVERFISH),
+            "minecraft:entities/skeleton" => Some(&Self::ENTITIES_SKELETON),
+            "minecraft:entities/skeleton_horse" => Some(&Self::ENTITIES_SKELETON_HORSE),
+            "minecraft:entities/slime" => Some(&Self::ENTITIES_SLIME),
+            "minecraft:entities/sniffer" => Some(&Self::ENTITIES_SNIFFER),
+            "minecraft:entities/snow_golem" => Some(&Self::ENTITIES_SNOW_GOLEM),
+            "minecraft:entities/spider" => Some(&Self::ENTITIES_SPIDER),
+            "minecraft:entities/squid" => Some(&Self::ENTITIES_SQUID),
+            "minecraft:entities/stray" => Some(&Self::ENTITIES_STRAY),
+            "minecraft:entities/strider" => Some(&Self::ENTITIES_STRIDER),
+            "minecraft:entities/tadpole" => Some(&Self::ENTITIES_TADPOLE),
+            "minecraft:entities/trader_llama" => Some(&Self::ENTITIES_TRADER_LLAMA),
+            "minecraft:entities/tropical_fish" => Some(&Self::ENTITIES_TROPICAL_FISH),
+            "minecraft:entities/turtle" => Some(&Self::ENTITIES_TURTLE),
+            "minecraft:entities/vex" => Some(&Self::ENTITIES_VEX),
+            "minecraft:entities/villager" => Some(&Self::ENTITIES_VILLAGER),
+            "minecraft:entities/vindicator" => Some(&Self::ENTITIES_VINDICATOR),
+            "minecraft:entities/wandering_trader" => Some(&Self::ENTITIES_WANDERING_TRADER),
+            "minecraft:entities/warden" => Some(&Self::ENTITIES_WARDEN),
+            "minecraft:entities/witch" => Some(&Self::ENTITIES_WITCH),
+            "minecraft:entities/wither" => Some(&Self::ENTITIES_WITHER),
+            "minecraft:entities/wither_skeleton" => Some(&Self::ENTITIES_WITHER_SKELETON),
+            "minecraft:entities/wolf" => Some(&Self::ENTITIES_WOLF),
+            "minecraft:entities/zoglin" => Some(&Self::ENTITIES_ZOGLIN),
+            "minecraft:entities/zombie" => Some(&Self::ENTITIES_ZOMBIE),
+            "minecraft:entities/zombie_horse" => Some(&Self::ENTITIES_ZOMBIE_HORSE),
+            "minecraft:entities/zombie_villager" => Some(&Self::ENTITIES_ZOMBIE_VILLAGER),
+            "minecraft:entities/zombified_piglin" => Some(&Self::ENTITIES_ZOMBIFIED_PIGLIN),
+            _ => None,
+        }
+    }
+}
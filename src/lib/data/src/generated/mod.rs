@@ -9,6 +9,7 @@ pub mod enchantments;
 pub mod entities;
 pub mod fluids;
 pub mod items;
+pub mod loot_tables;
 pub mod particles;
 pub mod potions;
 pub mod recipes;
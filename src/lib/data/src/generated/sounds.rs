@@ -5263,4 +5263,1737 @@ impl Sound {
             Self::ItemSaddleUnequip => "item.saddle.unequip",
         }
     }
+    #[doc = r" Get all sounds as a slice."]
+    pub fn all() -> &'static [Self] {
+        &[
+            Self::EntityAllayAmbientWithItem,
+            Self::EntityAllayAmbientWithoutItem,
+            Self::EntityAllayDeath,
+            Self::EntityAllayHurt,
+            Self::EntityAllayItemGiven,
+            Self::EntityAllayItemTaken,
+            Self::EntityAllayItemThrown,
+            Self::AmbientCave,
+            Self::AmbientBasaltDeltasAdditions,
+            Self::AmbientBasaltDeltasLoop,
+            Self::AmbientBasaltDeltasMood,
+            Self::AmbientCrimsonForestAdditions,
+            Self::AmbientCrimsonForestLoop,
+            Self::AmbientCrimsonForestMood,
+            Self::AmbientNetherWastesAdditions,
+            Self::AmbientNetherWastesLoop,
+            Self::AmbientNetherWastesMood,
+            Self::AmbientSoulSandValleyAdditions,
+            Self::AmbientSoulSandValleyLoop,
+            Self::AmbientSoulSandValleyMood,
+            Self::AmbientWarpedForestAdditions,
+            Self::AmbientWarpedForestLoop,
+            Self::AmbientWarpedForestMood,
+            Self::AmbientUnderwaterEnter,
+            Self::AmbientUnderwaterExit,
+            Self::AmbientUnderwaterLoop,
+            Self::AmbientUnderwaterLoopAdditions,
+            Self::AmbientUnderwaterLoopAdditionsRare,
+            Self::AmbientUnderwaterLoopAdditionsUltraRare,
+            Self::BlockAmethystBlockBreak,
+            Self::BlockAmethystBlockChime,
+            Self::BlockAmethystBlockFall,
+            Self::BlockAmethystBlockHit,
+            Self::BlockAmethystBlockPlace,
+            Self::BlockAmethystBlockResonate,
+            Self::BlockAmethystBlockStep,
+            Self::BlockAmethystClusterBreak,
+            Self::BlockAmethystClusterFall,
+            Self::BlockAmethystClusterHit,
+            Self::BlockAmethystClusterPlace,
+            Self::BlockAmethystClusterStep,
+            Self::BlockAncientDebrisBreak,
+            Self::BlockAncientDebrisStep,
+            Self::BlockAncientDebrisPlace,
+            Self::BlockAncientDebrisHit,
+            Self::BlockAncientDebrisFall,
+            Self::BlockAnvilBreak,
+            Self::BlockAnvilDestroy,
+            Self::BlockAnvilFall,
+            Self::BlockAnvilHit,
+            Self::BlockAnvilLand,
+            Self::BlockAnvilPlace,
+            Self::BlockAnvilStep,
+            Self::BlockAnvilUse,
+            Self::EntityArmadilloEat,
+            Self::EntityArmadilloHurt,
+            Self::EntityArmadilloHurtReduced,
+            Self::EntityArmadilloAmbient,
+            Self::EntityArmadilloStep,
+            Self::EntityArmadilloDeath,
+            Self::EntityArmadilloRoll,
+            Self::EntityArmadilloLand,
+            Self::EntityArmadilloScuteDrop,
+            Self::EntityArmadilloUnrollFinish,
+            Self::EntityArmadilloPeek,
+            Self::EntityArmadilloUnrollStart,
+            Self::EntityArmadilloBrush,
+            Self::ItemArmorEquipChain,
+            Self::ItemArmorEquipDiamond,
+            Self::ItemArmorEquipElytra,
+            Self::ItemArmorEquipGeneric,
+            Self::ItemArmorEquipGold,
+            Self::ItemArmorEquipIron,
+            Self::ItemArmorEquipLeather,
+            Self::ItemArmorEquipNetherite,
+            Self::ItemArmorEquipTurtle,
+            Self::ItemArmorEquipWolf,
+            Self::ItemArmorUnequipWolf,
+            Self::EntityArmorStandBreak,
+            Self::EntityArmorStandFall,
+            Self::EntityArmorStandHit,
+            Self::EntityArmorStandPlace,
+            Self::EntityArrowHit,
+            Self::EntityArrowHitPlayer,
+            Self::EntityArrowShoot,
+            Self::ItemAxeStrip,
+            Self::ItemAxeScrape,
+            Self::ItemAxeWaxOff,
+            Self::EntityAxolotlAttack,
+            Self::EntityAxolotlDeath,
+            Self::EntityAxolotlHurt,
+            Self::EntityAxolotlIdleAir,
+            Self::EntityAxolotlIdleWater,
+            Self::EntityAxolotlSplash,
+            Self::EntityAxolotlSwim,
+            Self::BlockAzaleaBreak,
+            Self::BlockAzaleaFall,
+            Self::BlockAzaleaHit,
+            Self::BlockAzaleaPlace,
+            Self::BlockAzaleaStep,
+            Self::BlockAzaleaLeavesBreak,
+            Self::BlockAzaleaLeavesFall,
+            Self::BlockAzaleaLeavesHit,
+            Self::BlockAzaleaLeavesPlace,
+            Self::BlockAzaleaLeavesStep,
+            Self::BlockBambooBreak,
+            Self::BlockBambooFall,
+            Self::BlockBambooHit,
+            Self::BlockBambooPlace,
+            Self::BlockBambooStep,
+            Self::BlockBambooSaplingBreak,
+            Self::BlockBambooSaplingHit,
+            Self::BlockBambooSaplingPlace,
+            Self::BlockBambooWoodBreak,
+            Self::BlockBambooWoodFall,
+            Self::BlockBambooWoodHit,
+            Self::BlockBambooWoodPlace,
+            Self::BlockBambooWoodStep,
+            Self::BlockBambooWoodDoorClose,
+            Self::BlockBambooWoodDoorOpen,
+            Self::BlockBambooWoodTrapdoorClose,
+            Self::BlockBambooWoodTrapdoorOpen,
+            Self::BlockBambooWoodButtonClickOff,
+            Self::BlockBambooWoodButtonClickOn,
+            Self::BlockBambooWoodPressurePlateClickOff,
+            Self::BlockBambooWoodPressurePlateClickOn,
+            Self::BlockBambooWoodFenceGateClose,
+            Self::BlockBambooWoodFenceGateOpen,
+            Self::BlockBarrelClose,
+            Self::BlockBarrelOpen,
+            Self::BlockBasaltBreak,
+            Self::BlockBasaltStep,
+            Self::BlockBasaltPlace,
+            Self::BlockBasaltHit,
+            Self::BlockBasaltFall,
+            Self::EntityBatAmbient,
+            Self::EntityBatDeath,
+            Self::EntityBatHurt,
+            Self::EntityBatLoop,
+            Self::EntityBatTakeoff,
+            Self::BlockBeaconActivate,
+            Self::BlockBeaconAmbient,
+            Self::BlockBeaconDeactivate,
+            Self::BlockBeaconPowerSelect,
+            Self::EntityBeeDeath,
+            Self::EntityBeeHurt,
+            Self::EntityBeeLoopAggressive,
+            Self::EntityBeeLoop,
+            Self::EntityBeeSting,
+            Self::EntityBeePollinate,
+            Self::BlockBeehiveDrip,
+            Self::BlockBeehiveEnter,
+            Self::BlockBeehiveExit,
+            Self::BlockBeehiveShear,
+            Self::BlockBeehiveWork,
+            Self::BlockBellUse,
+            Self::BlockBellResonate,
+            Self::BlockBigDripleafBreak,
+            Self::BlockBigDripleafFall,
+            Self::BlockBigDripleafHit,
+            Self::BlockBigDripleafPlace,
+            Self::BlockBigDripleafStep,
+            Self::EntityBlazeAmbient,
+            Self::EntityBlazeBurn,
+            Self::EntityBlazeDeath,
+            Self::EntityBlazeHurt,
+            Self::EntityBlazeShoot,
+            Self::EntityBoatPaddleLand,
+            Self::EntityBoatPaddleWater,
+            Self::EntityBoggedAmbient,
+            Self::EntityBoggedDeath,
+            Self::EntityBoggedHurt,
+            Self::EntityBoggedShear,
+            Self::EntityBoggedStep,
+            Self::BlockBoneBlockBreak,
+            Self::BlockBoneBlockFall,
+            Self::BlockBoneBlockHit,
+            Self::BlockBoneBlockPlace,
+            Self::BlockBoneBlockStep,
+            Self::ItemBoneMealUse,
+            Self::ItemBookPageTurn,
+            Self::ItemBookPut,
+            Self::BlockBlastfurnaceFireCrackle,
+            Self::ItemBottleEmpty,
+            Self::ItemBottleFill,
+            Self::ItemBottleFillDragonbreath,
+            Self::EntityBreezeCharge,
+            Self::EntityBreezeDeflect,
+            Self::EntityBreezeInhale,
+            Self::EntityBreezeIdleGround,
+            Self::EntityBreezeIdleAir,
+            Self::EntityBreezeShoot,
+            Self::EntityBreezeJump,
+            Self::EntityBreezeLand,
+            Self::EntityBreezeSlide,
+            Self::EntityBreezeDeath,
+            Self::EntityBreezeHurt,
+            Self::EntityBreezeWhirl,
+            Self::EntityBreezeWindBurst,
+            Self::BlockBrewingStandBrew,
+            Self::ItemBrushBrushingGeneric,
+            Self::ItemBrushBrushingSand,
+            Self::ItemBrushBrushingGravel,
+            Self::ItemBrushBrushingSandComplete,
+            Self::ItemBrushBrushingGravelComplete,
+            Self::BlockBubbleColumnBubblePop,
+            Self::BlockBubbleColumnUpwardsAmbient,
+            Self::BlockBubbleColumnUpwardsInside,
+            Self::BlockBubbleColumnWhirlpoolAmbient,
+            Self::BlockBubbleColumnWhirlpoolInside,
+            Self::UiHudBubblePop,
+            Self::ItemBucketEmpty,
+            Self::ItemBucketEmptyAxolotl,
+            Self::ItemBucketEmptyFish,
+            Self::ItemBucketEmptyLava,
+            Self::ItemBucketEmptyPowderSnow,
+            Self::ItemBucketEmptyTadpole,
+            Self::ItemBucketFill,
+            Self::ItemBucketFillAxolotl,
+            Self::ItemBucketFillFish,
+            Self::ItemBucketFillLava,
+            Self::ItemBucketFillPowderSnow,
+            Self::ItemBucketFillTadpole,
+            Self::ItemBundleDropContents,
+            Self::ItemBundleInsert,
+            Self::ItemBundleInsertFail,
+            Self::ItemBundleRemoveOne,
+            Self::BlockCactusFlowerBreak,
+            Self::BlockCactusFlowerPlace,
+            Self::BlockCakeAddCandle,
+            Self::BlockCalciteBreak,
+            Self::BlockCalciteStep,
+            Self::BlockCalcitePlace,
+            Self::BlockCalciteHit,
+            Self::BlockCalciteFall,
+            Self::EntityCamelAmbient,
+            Self::EntityCamelDash,
+            Self::EntityCamelDashReady,
+            Self::EntityCamelDeath,
+            Self::EntityCamelEat,
+            Self::EntityCamelHurt,
+            Self::EntityCamelSaddle,
+            Self::EntityCamelSit,
+            Self::EntityCamelStand,
+            Self::EntityCamelStep,
+            Self::EntityCamelStepSand,
+            Self::BlockCampfireCrackle,
+            Self::BlockCandleAmbient,
+            Self::BlockCandleBreak,
+            Self::BlockCandleExtinguish,
+            Self::BlockCandleFall,
+            Self::BlockCandleHit,
+            Self::BlockCandlePlace,
+            Self::BlockCandleStep,
+            Self::EntityCatAmbient,
+            Self::EntityCatStrayAmbient,
+            Self::EntityCatDeath,
+            Self::EntityCatEat,
+            Self::EntityCatHiss,
+            Self::EntityCatBegForFood,
+            Self::EntityCatHurt,
+            Self::EntityCatPurr,
+            Self::EntityCatPurreow,
+            Self::BlockCaveVinesBreak,
+            Self::BlockCaveVinesFall,
+            Self::BlockCaveVinesHit,
+            Self::BlockCaveVinesPlace,
+            Self::BlockCaveVinesStep,
+            Self::BlockCaveVinesPickBerries,
+            Self::BlockChainBreak,
+            Self::BlockChainFall,
+            Self::BlockChainHit,
+            Self::BlockChainPlace,
+            Self::BlockChainStep,
+            Self::BlockCherryWoodBreak,
+            Self::BlockCherryWoodFall,
+            Self::BlockCherryWoodHit,
+            Self::BlockCherryWoodPlace,
+            Self::BlockCherryWoodStep,
+            Self::BlockCherrySaplingBreak,
+            Self::BlockCherrySaplingFall,
+            Self::BlockCherrySaplingHit,
+            Self::BlockCherrySaplingPlace,
+            Self::BlockCherrySaplingStep,
+            Self::BlockCherryLeavesBreak,
+            Self::BlockCherryLeavesFall,
+            Self::BlockCherryLeavesHit,
+            Self::BlockCherryLeavesPlace,
+            Self::BlockCherryLeavesStep,
+            Self::BlockCherryWoodHangingSignStep,
+            Self::BlockCherryWoodHangingSignBreak,
+            Self::BlockCherryWoodHangingSignFall,
+            Self::BlockCherryWoodHangingSignHit,
+            Self::BlockCherryWoodHangingSignPlace,
+            Self::BlockCherryWoodDoorClose,
+            Self::BlockCherryWoodDoorOpen,
+            Self::BlockCherryWoodTrapdoorClose,
+            Self::BlockCherryWoodTrapdoorOpen,
+            Self::BlockCherryWoodButtonClickOff,
+            Self::BlockCherryWoodButtonClickOn,
+            Self::BlockCherryWoodPressurePlateClickOff,
+            Self::BlockCherryWoodPressurePlateClickOn,
+            Self::BlockCherryWoodFenceGateClose,
+            Self::BlockCherryWoodFenceGateOpen,
+            Self::BlockChestClose,
+            Self::BlockChestLocked,
+            Self::BlockChestOpen,
+            Self::EntityChickenAmbient,
+            Self::EntityChickenDeath,
+            Self::EntityChickenEgg,
+            Self::EntityChickenHurt,
+            Self::EntityChickenStep,
+            Self::BlockChiseledBookshelfBreak,
+            Self::BlockChiseledBookshelfFall,
+            Self::BlockChiseledBookshelfHit,
+            Self::BlockChiseledBookshelfInsert,
+            Self::BlockChiseledBookshelfInsertEnchanted,
+            Self::BlockChiseledBookshelfStep,
+            Self::BlockChiseledBookshelfPickup,
+            Self::BlockChiseledBookshelfPickupEnchanted,
+            Self::BlockChiseledBookshelfPlace,
+            Self::BlockChorusFlowerDeath,
+            Self::BlockChorusFlowerGrow,
+            Self::ItemChorusFruitTeleport,
+            Self::BlockCobwebBreak,
+            Self::BlockCobwebStep,
+            Self::BlockCobwebPlace,
+            Self::BlockCobwebHit,
+            Self::BlockCobwebFall,
+            Self::EntityCodAmbient,
+            Self::EntityCodDeath,
+            Self::EntityCodFlop,
+            Self::EntityCodHurt,
+            Self::BlockComparatorClick,
+            Self::BlockComposterEmpty,
+            Self::BlockComposterFill,
+            Self::BlockComposterFillSuccess,
+            Self::BlockComposterReady,
+            Self::BlockConduitActivate,
+            Self::BlockConduitAmbient,
+            Self::BlockConduitAmbientShort,
+            Self::BlockConduitAttackTarget,
+            Self::BlockConduitDeactivate,
+            Self::BlockCopperBulbBreak,
+            Self::BlockCopperBulbStep,
+            Self::BlockCopperBulbPlace,
+            Self::BlockCopperBulbHit,
+            Self::BlockCopperBulbFall,
+            Self::BlockCopperBulbTurnOn,
+            Self::BlockCopperBulbTurnOff,
+            Self::BlockCopperBreak,
+            Self::BlockCopperStep,
+            Self::BlockCopperPlace,
+            Self::BlockCopperHit,
+            Self::BlockCopperFall,
+            Self::BlockCopperDoorClose,
+            Self::BlockCopperDoorOpen,
+            Self::BlockCopperGrateBreak,
+            Self::BlockCopperGrateStep,
+            Self::BlockCopperGratePlace,
+            Self::BlockCopperGrateHit,
+            Self::BlockCopperGrateFall,
+            Self::BlockCopperTrapdoorClose,
+            Self::BlockCopperTrapdoorOpen,
+            Self::BlockCoralBlockBreak,
+            Self::BlockCoralBlockFall,
+            Self::BlockCoralBlockHit,
+            Self::BlockCoralBlockPlace,
+            Self::BlockCoralBlockStep,
+            Self::EntityCowAmbient,
+            Self::EntityCowDeath,
+            Self::EntityCowHurt,
+            Self::EntityCowMilk,
+            Self::EntityCowStep,
+            Self::BlockCrafterCraft,
+            Self::BlockCrafterFail,
+            Self::EntityCreakingAmbient,
+            Self::EntityCreakingActivate,
+            Self::EntityCreakingDeactivate,
+            Self::EntityCreakingAttack,
+            Self::EntityCreakingDeath,
+            Self::EntityCreakingStep,
+            Self::EntityCreakingFreeze,
+            Self::EntityCreakingUnfreeze,
+            Self::EntityCreakingSpawn,
+            Self::EntityCreakingSway,
+            Self::EntityCreakingTwitch,
+            Self::BlockCreakingHeartBreak,
+            Self::BlockCreakingHeartFall,
+            Self::BlockCreakingHeartHit,
+            Self::BlockCreakingHeartHurt,
+            Self::BlockCreakingHeartPlace,
+            Self::BlockCreakingHeartStep,
+            Self::BlockCreakingHeartIdle,
+            Self::BlockCreakingHeartSpawn,
+            Self::EntityCreeperDeath,
+            Self::EntityCreeperHurt,
+            Self::EntityCreeperPrimed,
+            Self::BlockCropBreak,
+            Self::ItemCropPlant,
+            Self::ItemCrossbowHit,
+            Self::ItemCrossbowLoadingEnd,
+            Self::ItemCrossbowLoadingMiddle,
+            Self::ItemCrossbowLoadingStart,
+            Self::ItemCrossbowQuickCharge1,
+            Self::ItemCrossbowQuickCharge2,
+            Self::ItemCrossbowQuickCharge3,
+            Self::ItemCrossbowShoot,
+            Self::BlockDeadbushIdle,
+            Self::BlockDecoratedPotBreak,
+            Self::BlockDecoratedPotFall,
+            Self::BlockDecoratedPotHit,
+            Self::BlockDecoratedPotInsert,
+            Self::BlockDecoratedPotInsertFail,
+            Self::BlockDecoratedPotStep,
+            Self::BlockDecoratedPotPlace,
+            Self::BlockDecoratedPotShatter,
+            Self::BlockDeepslateBricksBreak,
+            Self::BlockDeepslateBricksFall,
+            Self::BlockDeepslateBricksHit,
+            Self::BlockDeepslateBricksPlace,
+            Self::BlockDeepslateBricksStep,
+            Self::BlockDeepslateBreak,
+            Self::BlockDeepslateFall,
+            Self::BlockDeepslateHit,
+            Self::BlockDeepslatePlace,
+            Self::BlockDeepslateStep,
+            Self::BlockDeepslateTilesBreak,
+            Self::BlockDeepslateTilesFall,
+            Self::BlockDeepslateTilesHit,
+            Self::BlockDeepslateTilesPlace,
+            Self::BlockDeepslateTilesStep,
+            Self::BlockDispenserDispense,
+            Self::BlockDispenserFail,
+            Self::BlockDispenserLaunch,
+            Self::EntityDolphinAmbient,
+            Self::EntityDolphinAmbientWater,
+            Self::EntityDolphinAttack,
+            Self::EntityDolphinDeath,
+            Self::EntityDolphinEat,
+            Self::EntityDolphinHurt,
+            Self::EntityDolphinJump,
+            Self::EntityDolphinPlay,
+            Self::EntityDolphinSplash,
+            Self::EntityDolphinSwim,
+            Self::EntityDonkeyAmbient,
+            Self::EntityDonkeyAngry,
+            Self::EntityDonkeyChest,
+            Self::EntityDonkeyDeath,
+            Self::EntityDonkeyEat,
+            Self::EntityDonkeyHurt,
+            Self::EntityDonkeyJump,
+            Self::BlockDriedGhastBreak,
+            Self::BlockDriedGhastStep,
+            Self::BlockDriedGhastFall,
+            Self::BlockDriedGhastAmbient,
+            Self::BlockDriedGhastAmbientWater,
+            Self::BlockDriedGhastPlace,
+            Self::BlockDriedGhastPlaceInWater,
+            Self::BlockDriedGhastTransition,
+            Self::BlockDripstoneBlockBreak,
+            Self::BlockDripstoneBlockStep,
+            Self::BlockDripstoneBlockPlace,
+            Self::BlockDripstoneBlockHit,
+            Self::BlockDripstoneBlockFall,
+            Self::BlockDryGrassAmbient,
+            Self::BlockPointedDripstoneBreak,
+            Self::BlockPointedDripstoneStep,
+            Self::BlockPointedDripstonePlace,
+            Self::BlockPointedDripstoneHit,
+            Self::BlockPointedDripstoneFall,
+            Self::BlockPointedDripstoneLand,
+            Self::BlockPointedDripstoneDripLava,
+            Self::BlockPointedDripstoneDripWater,
+            Self::BlockPointedDripstoneDripLavaIntoCauldron,
+            Self::BlockPointedDripstoneDripWaterIntoCauldron,
+            Self::BlockBigDripleafTiltDown,
+            Self::BlockBigDripleafTiltUp,
+            Self::EntityDrownedAmbient,
+            Self::EntityDrownedAmbientWater,
+            Self::EntityDrownedDeath,
+            Self::EntityDrownedDeathWater,
+            Self::EntityDrownedHurt,
+            Self::EntityDrownedHurtWater,
+            Self::EntityDrownedShoot,
+            Self::EntityDrownedStep,
+            Self::EntityDrownedSwim,
+            Self::ItemDyeUse,
+            Self::EntityEggThrow,
+            Self::EntityElderGuardianAmbient,
+            Self::EntityElderGuardianAmbientLand,
+            Self::EntityElderGuardianCurse,
+            Self::EntityElderGuardianDeath,
+            Self::EntityElderGuardianDeathLand,
+            Self::EntityElderGuardianFlop,
+            Self::EntityElderGuardianHurt,
+            Self::EntityElderGuardianHurtLand,
+            Self::ItemElytraFlying,
+            Self::BlockEnchantmentTableUse,
+            Self::BlockEnderChestClose,
+            Self::BlockEnderChestOpen,
+            Self::EntityEnderDragonAmbient,
+            Self::EntityEnderDragonDeath,
+            Self::EntityDragonFireballExplode,
+            Self::EntityEnderDragonFlap,
+            Self::EntityEnderDragonGrowl,
+            Self::EntityEnderDragonHurt,
+            Self::EntityEnderDragonShoot,
+            Self::EntityEnderEyeDeath,
+            Self::EntityEnderEyeLaunch,
+            Self::EntityEndermanAmbient,
+            Self::EntityEndermanDeath,
+            Self::EntityEndermanHurt,
+            Self::EntityEndermanScream,
+            Self::EntityEndermanStare,
+            Self::EntityEndermanTeleport,
+            Self::EntityEndermiteAmbient,
+            Self::EntityEndermiteDeath,
+            Self::EntityEndermiteHurt,
+            Self::EntityEndermiteStep,
+            Self::EntityEnderPearlThrow,
+            Self::BlockEndGatewaySpawn,
+            Self::BlockEndPortalFrameFill,
+            Self::BlockEndPortalSpawn,
+            Self::EntityEvokerAmbient,
+            Self::EntityEvokerCastSpell,
+            Self::EntityEvokerCelebrate,
+            Self::EntityEvokerDeath,
+            Self::EntityEvokerFangsAttack,
+            Self::EntityEvokerHurt,
+            Self::EntityEvokerPrepareAttack,
+            Self::EntityEvokerPrepareSummon,
+            Self::EntityEvokerPrepareWololo,
+            Self::EntityExperienceBottleThrow,
+            Self::EntityExperienceOrbPickup,
+            Self::BlockEyeblossomOpenLong,
+            Self::BlockEyeblossomOpen,
+            Self::BlockEyeblossomCloseLong,
+            Self::BlockEyeblossomClose,
+            Self::BlockEyeblossomIdle,
+            Self::BlockFenceGateClose,
+            Self::BlockFenceGateOpen,
+            Self::ItemFirechargeUse,
+            Self::BlockFireflyBushIdle,
+            Self::EntityFireworkRocketBlast,
+            Self::EntityFireworkRocketBlastFar,
+            Self::EntityFireworkRocketLargeBlast,
+            Self::EntityFireworkRocketLargeBlastFar,
+            Self::EntityFireworkRocketLaunch,
+            Self::EntityFireworkRocketShoot,
+            Self::EntityFireworkRocketTwinkle,
+            Self::EntityFireworkRocketTwinkleFar,
+            Self::BlockFireAmbient,
+            Self::BlockFireExtinguish,
+            Self::EntityFishSwim,
+            Self::EntityFishingBobberRetrieve,
+            Self::EntityFishingBobberSplash,
+            Self::EntityFishingBobberThrow,
+            Self::ItemFlintandsteelUse,
+            Self::BlockFloweringAzaleaBreak,
+            Self::BlockFloweringAzaleaFall,
+            Self::BlockFloweringAzaleaHit,
+            Self::BlockFloweringAzaleaPlace,
+            Self::BlockFloweringAzaleaStep,
+            Self::EntityFoxAggro,
+            Self::EntityFoxAmbient,
+            Self::EntityFoxBite,
+            Self::EntityFoxDeath,
+            Self::EntityFoxEat,
+            Self::EntityFoxHurt,
+            Self::EntityFoxScreech,
+            Self::EntityFoxSleep,
+            Self::EntityFoxSniff,
+            Self::EntityFoxSpit,
+            Self::EntityFoxTeleport,
+            Self::BlockSuspiciousSandBreak,
+            Self::BlockSuspiciousSandStep,
+            Self::BlockSuspiciousSandPlace,
+            Self::BlockSuspiciousSandHit,
+            Self::BlockSuspiciousSandFall,
+            Self::BlockSuspiciousGravelBreak,
+            Self::BlockSuspiciousGravelStep,
+            Self::BlockSuspiciousGravelPlace,
+            Self::BlockSuspiciousGravelHit,
+            Self::BlockSuspiciousGravelFall,
+            Self::BlockFroglightBreak,
+            Self::BlockFroglightFall,
+            Self::BlockFroglightHit,
+            Self::BlockFroglightPlace,
+            Self::BlockFroglightStep,
+            Self::BlockFrogspawnStep,
+            Self::BlockFrogspawnBreak,
+            Self::BlockFrogspawnFall,
+            Self::BlockFrogspawnHatch,
+            Self::BlockFrogspawnHit,
+            Self::BlockFrogspawnPlace,
+            Self::EntityFrogAmbient,
+            Self::EntityFrogDeath,
+            Self::EntityFrogEat,
+            Self::EntityFrogHurt,
+            Self::EntityFrogLaySpawn,
+            Self::EntityFrogLongJump,
+            Self::EntityFrogStep,
+            Self::EntityFrogTongue,
+            Self::BlockRootsBreak,
+            Self::BlockRootsStep,
+            Self::BlockRootsPlace,
+            Self::BlockRootsHit,
+            Self::BlockRootsFall,
+            Self::BlockFurnaceFireCrackle,
+            Self::EntityGenericBigFall,
+            Self::EntityGenericBurn,
+            Self::EntityGenericDeath,
+            Self::EntityGenericDrink,
+            Self::EntityGenericEat,
+            Self::EntityGenericExplode,
+            Self::EntityGenericExtinguishFire,
+            Self::EntityGenericHurt,
+            Self::EntityGenericSmallFall,
+            Self::EntityGenericSplash,
+            Self::EntityGenericSwim,
+            Self::EntityGhastAmbient,
+            Self::EntityGhastDeath,
+            Self::EntityGhastHurt,
+            Self::EntityGhastScream,
+            Self::EntityGhastShoot,
+            Self::EntityGhastWarn,
+            Self::EntityGhastlingAmbient,
+            Self::EntityGhastlingDeath,
+            Self::EntityGhastlingHurt,
+            Self::EntityGhastlingSpawn,
+            Self::BlockGildedBlackstoneBreak,
+            Self::BlockGildedBlackstoneFall,
+            Self::BlockGildedBlackstoneHit,
+            Self::BlockGildedBlackstonePlace,
+            Self::BlockGildedBlackstoneStep,
+            Self::BlockGlassBreak,
+            Self::BlockGlassFall,
+            Self::BlockGlassHit,
+            Self::BlockGlassPlace,
+            Self::BlockGlassStep,
+            Self::ItemGlowInkSacUse,
+            Self::EntityGlowItemFrameAddItem,
+            Self::EntityGlowItemFrameBreak,
+            Self::EntityGlowItemFramePlace,
+            Self::EntityGlowItemFrameRemoveItem,
+            Self::EntityGlowItemFrameRotateItem,
+            Self::EntityGlowSquidAmbient,
+            Self::EntityGlowSquidDeath,
+            Self::EntityGlowSquidHurt,
+            Self::EntityGlowSquidSquirt,
+            Self::EntityGoatAmbient,
+            Self::EntityGoatDeath,
+            Self::EntityGoatEat,
+            Self::EntityGoatHurt,
+            Self::EntityGoatLongJump,
+            Self::EntityGoatMilk,
+            Self::EntityGoatPrepareRam,
+            Self::EntityGoatRamImpact,
+            Self::EntityGoatHornBreak,
+            Self::EntityGoatScreamingAmbient,
+            Self::EntityGoatScreamingDeath,
+            Self::EntityGoatScreamingEat,
+            Self::EntityGoatScreamingHurt,
+            Self::EntityGoatScreamingLongJump,
+            Self::EntityGoatScreamingMilk,
+            Self::EntityGoatScreamingPrepareRam,
+            Self::EntityGoatScreamingRamImpact,
+            Self::EntityGoatStep,
+            Self::BlockGrassBreak,
+            Self::BlockGrassFall,
+            Self::BlockGrassHit,
+            Self::BlockGrassPlace,
+            Self::BlockGrassStep,
+            Self::BlockGravelBreak,
+            Self::BlockGravelFall,
+            Self::BlockGravelHit,
+            Self::BlockGravelPlace,
+            Self::BlockGravelStep,
+            Self::BlockGrindstoneUse,
+            Self::BlockGrowingPlantCrop,
+            Self::EntityGuardianAmbient,
+            Self::EntityGuardianAmbientLand,
+            Self::EntityGuardianAttack,
+            Self::EntityGuardianDeath,
+            Self::EntityGuardianDeathLand,
+            Self::EntityGuardianFlop,
+            Self::EntityGuardianHurt,
+            Self::EntityGuardianHurtLand,
+            Self::BlockHangingRootsBreak,
+            Self::BlockHangingRootsFall,
+            Self::BlockHangingRootsHit,
+            Self::BlockHangingRootsPlace,
+            Self::BlockHangingRootsStep,
+            Self::BlockHangingSignStep,
+            Self::BlockHangingSignBreak,
+            Self::BlockHangingSignFall,
+            Self::BlockHangingSignHit,
+            Self::BlockHangingSignPlace,
+            Self::EntityHappyGhastAmbient,
+            Self::EntityHappyGhastDeath,
+            Self::EntityHappyGhastHurt,
+            Self::EntityHappyGhastRiding,
+            Self::BlockHeavyCoreBreak,
+            Self::BlockHeavyCoreFall,
+            Self::BlockHeavyCoreHit,
+            Self::BlockHeavyCorePlace,
+            Self::BlockHeavyCoreStep,
+            Self::BlockNetherWoodHangingSignStep,
+            Self::BlockNetherWoodHangingSignBreak,
+            Self::BlockNetherWoodHangingSignFall,
+            Self::BlockNetherWoodHangingSignHit,
+            Self::BlockNetherWoodHangingSignPlace,
+            Self::BlockBambooWoodHangingSignStep,
+            Self::BlockBambooWoodHangingSignBreak,
+            Self::BlockBambooWoodHangingSignFall,
+            Self::BlockBambooWoodHangingSignHit,
+            Self::BlockBambooWoodHangingSignPlace,
+            Self::BlockTrialSpawnerBreak,
+            Self::BlockTrialSpawnerStep,
+            Self::BlockTrialSpawnerPlace,
+            Self::BlockTrialSpawnerHit,
+            Self::BlockTrialSpawnerFall,
+            Self::BlockTrialSpawnerSpawnMob,
+            Self::BlockTrialSpawnerAboutToSpawnItem,
+            Self::BlockTrialSpawnerSpawnItem,
+            Self::BlockTrialSpawnerSpawnItemBegin,
+            Self::BlockTrialSpawnerDetectPlayer,
+            Self::BlockTrialSpawnerOminousActivate,
+            Self::BlockTrialSpawnerAmbient,
+            Self::BlockTrialSpawnerAmbientOminous,
+            Self::BlockTrialSpawnerOpenShutter,
+            Self::BlockTrialSpawnerCloseShutter,
+            Self::BlockTrialSpawnerEjectItem,
+            Self::EntityHappyGhastEquip,
+            Self::EntityHappyGhastUnequip,
+            Self::EntityHappyGhastHarnessGogglesUp,
+            Self::EntityHappyGhastHarnessGogglesDown,
+            Self::ItemHoeTill,
+            Self::EntityHoglinAmbient,
+            Self::EntityHoglinAngry,
+            Self::EntityHoglinAttack,
+            Self::EntityHoglinConvertedToZombified,
+            Self::EntityHoglinDeath,
+            Self::EntityHoglinHurt,
+            Self::EntityHoglinRetreat,
+            Self::EntityHoglinStep,
+            Self::BlockHoneyBlockBreak,
+            Self::BlockHoneyBlockFall,
+            Self::BlockHoneyBlockHit,
+            Self::BlockHoneyBlockPlace,
+            Self::BlockHoneyBlockSlide,
+            Self::BlockHoneyBlockStep,
+            Self::ItemHoneycombWaxOn,
+            Self::ItemHoneyBottleDrink,
+            Self::ItemGoatHornSound0,
+            Self::ItemGoatHornSound1,
+            Self::ItemGoatHornSound2,
+            Self::ItemGoatHornSound3,
+            Self::ItemGoatHornSound4,
+            Self::ItemGoatHornSound5,
+            Self::ItemGoatHornSound6,
+            Self::ItemGoatHornSound7,
+            Self::EntityHorseAmbient,
+            Self::EntityHorseAngry,
+            Self::EntityHorseArmor,
+            Self::ItemHorseArmorUnequip,
+            Self::EntityHorseBreathe,
+            Self::EntityHorseDeath,
+            Self::EntityHorseEat,
+            Self::EntityHorseGallop,
+            Self::EntityHorseHurt,
+            Self::EntityHorseJump,
+            Self::EntityHorseLand,
+            Self::EntityHorseSaddle,
+            Self::EntityHorseStep,
+            Self::EntityHorseStepWood,
+            Self::EntityHostileBigFall,
+            Self::EntityHostileDeath,
+            Self::EntityHostileHurt,
+            Self::EntityHostileSmallFall,
+            Self::EntityHostileSplash,
+            Self::EntityHostileSwim,
+            Self::EntityHuskAmbient,
+            Self::EntityHuskConvertedToZombie,
+            Self::EntityHuskDeath,
+            Self::EntityHuskHurt,
+            Self::EntityHuskStep,
+            Self::EntityIllusionerAmbient,
+            Self::EntityIllusionerCastSpell,
+            Self::EntityIllusionerDeath,
+            Self::EntityIllusionerHurt,
+            Self::EntityIllusionerMirrorMove,
+            Self::EntityIllusionerPrepareBlindness,
+            Self::EntityIllusionerPrepareMirror,
+            Self::ItemInkSacUse,
+            Self::BlockIronBreak,
+            Self::BlockIronStep,
+            Self::BlockIronPlace,
+            Self::BlockIronHit,
+            Self::BlockIronFall,
+            Self::BlockIronDoorClose,
+            Self::BlockIronDoorOpen,
+            Self::EntityIronGolemAttack,
+            Self::EntityIronGolemDamage,
+            Self::EntityIronGolemDeath,
+            Self::EntityIronGolemHurt,
+            Self::EntityIronGolemRepair,
+            Self::EntityIronGolemStep,
+            Self::BlockIronTrapdoorClose,
+            Self::BlockIronTrapdoorOpen,
+            Self::EntityItemFrameAddItem,
+            Self::EntityItemFrameBreak,
+            Self::EntityItemFramePlace,
+            Self::EntityItemFrameRemoveItem,
+            Self::EntityItemFrameRotateItem,
+            Self::EntityItemBreak,
+            Self::EntityItemPickup,
+            Self::BlockLadderBreak,
+            Self::BlockLadderFall,
+            Self::BlockLadderHit,
+            Self::BlockLadderPlace,
+            Self::BlockLadderStep,
+            Self::BlockLanternBreak,
+            Self::BlockLanternFall,
+            Self::BlockLanternHit,
+            Self::BlockLanternPlace,
+            Self::BlockLanternStep,
+            Self::BlockLargeAmethystBudBreak,
+            Self::BlockLargeAmethystBudPlace,
+            Self::BlockLavaAmbient,
+            Self::BlockLavaExtinguish,
+            Self::BlockLavaPop,
+            Self::BlockLeafLitterBreak,
+            Self::BlockLeafLitterStep,
+            Self::BlockLeafLitterPlace,
+            Self::BlockLeafLitterHit,
+            Self::BlockLeafLitterFall,
+            Self::ItemLeadUntied,
+            Self::ItemLeadTied,
+            Self::ItemLeadBreak,
+            Self::BlockLeverClick,
+            Self::EntityLightningBoltImpact,
+            Self::EntityLightningBoltThunder,
+            Self::EntityLingeringPotionThrow,
+            Self::EntityLlamaAmbient,
+            Self::EntityLlamaAngry,
+            Self::EntityLlamaChest,
+            Self::EntityLlamaDeath,
+            Self::EntityLlamaEat,
+            Self::EntityLlamaHurt,
+            Self::EntityLlamaSpit,
+            Self::EntityLlamaStep,
+            Self::EntityLlamaSwag,
+            Self::ItemLlamaCarpetUnequip,
+            Self::EntityMagmaCubeDeathSmall,
+            Self::BlockLodestoneBreak,
+            Self::BlockLodestoneStep,
+            Self::BlockLodestonePlace,
+            Self::BlockLodestoneHit,
+            Self::BlockLodestoneFall,
+            Self::ItemLodestoneCompassLock,
+            Self::ItemMaceSmashAir,
+            Self::ItemMaceSmashGround,
+            Self::ItemMaceSmashGroundHeavy,
+            Self::EntityMagmaCubeDeath,
+            Self::EntityMagmaCubeHurt,
+            Self::EntityMagmaCubeHurtSmall,
+            Self::EntityMagmaCubeJump,
+            Self::EntityMagmaCubeSquish,
+            Self::EntityMagmaCubeSquishSmall,
+            Self::BlockMangroveRootsBreak,
+            Self::BlockMangroveRootsFall,
+            Self::BlockMangroveRootsHit,
+            Self::BlockMangroveRootsPlace,
+            Self::BlockMangroveRootsStep,
+            Self::BlockMediumAmethystBudBreak,
+            Self::BlockMediumAmethystBudPlace,
+            Self::BlockMetalBreak,
+            Self::BlockMetalFall,
+            Self::BlockMetalHit,
+            Self::BlockMetalPlace,
+            Self::BlockMetalPressurePlateClickOff,
+            Self::BlockMetalPressurePlateClickOn,
+            Self::BlockMetalStep,
+            Self::EntityMinecartInsideUnderwater,
+            Self::EntityMinecartInside,
+            Self::EntityMinecartRiding,
+            Self::EntityMooshroomConvert,
+            Self::EntityMooshroomEat,
+            Self::EntityMooshroomMilk,
+            Self::EntityMooshroomSuspiciousMilk,
+            Self::EntityMooshroomShear,
+            Self::BlockMossCarpetBreak,
+            Self::BlockMossCarpetFall,
+            Self::BlockMossCarpetHit,
+            Self::BlockMossCarpetPlace,
+            Self::BlockMossCarpetStep,
+            Self::BlockPinkPetalsBreak,
+            Self::BlockPinkPetalsFall,
+            Self::BlockPinkPetalsHit,
+            Self::BlockPinkPetalsPlace,
+            Self::BlockPinkPetalsStep,
+            Self::BlockMossBreak,
+            Self::BlockMossFall,
+            Self::BlockMossHit,
+            Self::BlockMossPlace,
+            Self::BlockMossStep,
+            Self::BlockMudBreak,
+            Self::BlockMudFall,
+            Self::BlockMudHit,
+            Self::BlockMudPlace,
+            Self::BlockMudStep,
+            Self::BlockMudBricksBreak,
+            Self::BlockMudBricksFall,
+            Self::BlockMudBricksHit,
+            Self::BlockMudBricksPlace,
+            Self::BlockMudBricksStep,
+            Self::BlockMuddyMangroveRootsBreak,
+            Self::BlockMuddyMangroveRootsFall,
+            Self::BlockMuddyMangroveRootsHit,
+            Self::BlockMuddyMangroveRootsPlace,
+            Self::BlockMuddyMangroveRootsStep,
+            Self::EntityMuleAmbient,
+            Self::EntityMuleAngry,
+            Self::EntityMuleChest,
+            Self::EntityMuleDeath,
+            Self::EntityMuleEat,
+            Self::EntityMuleHurt,
+            Self::EntityMuleJump,
+            Self::MusicCreative,
+            Self::MusicCredits,
+            Self::MusicDisc5,
+            Self::MusicDisc11,
+            Self::MusicDisc13,
+            Self::MusicDiscBlocks,
+            Self::MusicDiscCat,
+            Self::MusicDiscChirp,
+            Self::MusicDiscFar,
+            Self::MusicDiscLavaChicken,
+            Self::MusicDiscMall,
+            Self::MusicDiscMellohi,
+            Self::MusicDiscPigstep,
+            Self::MusicDiscStal,
+            Self::MusicDiscStrad,
+            Self::MusicDiscWait,
+            Self::MusicDiscWard,
+            Self::MusicDiscOtherside,
+            Self::MusicDiscRelic,
+            Self::MusicDiscCreator,
+            Self::MusicDiscCreatorMusicBox,
+            Self::MusicDiscPrecipice,
+            Self::MusicDiscTears,
+            Self::MusicDragon,
+            Self::MusicEnd,
+            Self::MusicGame,
+            Self::MusicMenu,
+            Self::MusicNetherBasaltDeltas,
+            Self::MusicNetherCrimsonForest,
+            Self::MusicOverworldDeepDark,
+            Self::MusicOverworldDripstoneCaves,
+            Self::MusicOverworldGrove,
+            Self::MusicOverworldJaggedPeaks,
+            Self::MusicOverworldLushCaves,
+            Self::MusicOverworldSwamp,
+            Self::MusicOverworldForest,
+            Self::MusicOverworldOldGrowthTaiga,
+            Self::MusicOverworldMeadow,
+            Self::MusicOverworldCherryGrove,
+            Self::MusicNetherNetherWastes,
+            Self::MusicOverworldFrozenPeaks,
+            Self::MusicOverworldSnowySlopes,
+            Self::MusicNetherSoulSandValley,
+            Self::MusicOverworldStonyPeaks,
+            Self::MusicNetherWarpedForest,
+            Self::MusicOverworldFlowerForest,
+            Self::MusicOverworldDesert,
+            Self::MusicOverworldBadlands,
+            Self::MusicOverworldJungle,
+            Self::MusicOverworldSparseJungle,
+            Self::MusicOverworldBambooJungle,
+            Self::MusicUnderWater,
+            Self::BlockNetherBricksBreak,
+            Self::BlockNetherBricksStep,
+            Self::BlockNetherBricksPlace,
+            Self::BlockNetherBricksHit,
+            Self::BlockNetherBricksFall,
+            Self::BlockNetherWartBreak,
+            Self::ItemNetherWartPlant,
+            Self::BlockNetherWoodBreak,
+            Self::BlockNetherWoodFall,
+            Self::BlockNetherWoodHit,
+            Self::BlockNetherWoodPlace,
+            Self::BlockNetherWoodStep,
+            Self::BlockNetherWoodDoorClose,
+            Self::BlockNetherWoodDoorOpen,
+            Self::BlockNetherWoodTrapdoorClose,
+            Self::BlockNetherWoodTrapdoorOpen,
+            Self::BlockNetherWoodButtonClickOff,
+            Self::BlockNetherWoodButtonClickOn,
+            Self::BlockNetherWoodPressurePlateClickOff,
+            Self::BlockNetherWoodPressurePlateClickOn,
+            Self::BlockNetherWoodFenceGateClose,
+            Self::BlockNetherWoodFenceGateOpen,
+            Self::IntentionallyEmpty,
+            Self::BlockPackedMudBreak,
+            Self::BlockPackedMudFall,
+            Self::BlockPackedMudHit,
+            Self::BlockPackedMudPlace,
+            Self::BlockPackedMudStep,
+            Self::BlockStemBreak,
+            Self::BlockStemStep,
+            Self::BlockStemPlace,
+            Self::BlockStemHit,
+            Self::BlockStemFall,
+            Self::BlockNyliumBreak,
+            Self::BlockNyliumStep,
+            Self::BlockNyliumPlace,
+            Self::BlockNyliumHit,
+            Self::BlockNyliumFall,
+            Self::BlockNetherSproutsBreak,
+            Self::BlockNetherSproutsStep,
+            Self::BlockNetherSproutsPlace,
+            Self::BlockNetherSproutsHit,
+            Self::BlockNetherSproutsFall,
+            Self::BlockFungusBreak,
+            Self::BlockFungusStep,
+            Self::BlockFungusPlace,
+            Self::BlockFungusHit,
+            Self::BlockFungusFall,
+            Self::BlockWeepingVinesBreak,
+            Self::BlockWeepingVinesStep,
+            Self::BlockWeepingVinesPlace,
+            Self::BlockWeepingVinesHit,
+            Self::BlockWeepingVinesFall,
+            Self::BlockWartBlockBreak,
+            Self::BlockWartBlockStep,
+            Self::BlockWartBlockPlace,
+            Self::BlockWartBlockHit,
+            Self::BlockWartBlockFall,
+            Self::BlockNetheriteBlockBreak,
+            Self::BlockNetheriteBlockStep,
+            Self::BlockNetheriteBlockPlace,
+            Self::BlockNetheriteBlockHit,
+            Self::BlockNetheriteBlockFall,
+            Self::BlockNetherrackBreak,
+            Self::BlockNetherrackStep,
+            Self::BlockNetherrackPlace,
+            Self::BlockNetherrackHit,
+            Self::BlockNetherrackFall,
+            Self::BlockNoteBlockBasedrum,
+            Self::BlockNoteBlockBass,
+            Self::BlockNoteBlockBell,
+            Self::BlockNoteBlockChime,
+            Self::BlockNoteBlockFlute,
+            Self::BlockNoteBlockGuitar,
+            Self::BlockNoteBlockHarp,
+            Self::BlockNoteBlockHat,
+            Self::BlockNoteBlockPling,
+            Self::BlockNoteBlockSnare,
+            Self::BlockNoteBlockXylophone,
+            Self::BlockNoteBlockIronXylophone,
+            Self::BlockNoteBlockCowBell,
+            Self::BlockNoteBlockDidgeridoo,
+            Self::BlockNoteBlockBit,
+            Self::BlockNoteBlockBanjo,
+            Self::BlockNoteBlockImitateZombie,
+            Self::BlockNoteBlockImitateSkeleton,
+            Self::BlockNoteBlockImitateCreeper,
+            Self::BlockNoteBlockImitateEnderDragon,
+            Self::BlockNoteBlockImitateWitherSkeleton,
+            Self::BlockNoteBlockImitatePiglin,
+            Self::EntityOcelotHurt,
+            Self::EntityOcelotAmbient,
+            Self::EntityOcelotDeath,
+            Self::ItemOminousBottleDispose,
+            Self::EntityPaintingBreak,
+            Self::EntityPaintingPlace,
+            Self::BlockPaleHangingMossIdle,
+            Self::EntityPandaPreSneeze,
+            Self::EntityPandaSneeze,
+            Self::EntityPandaAmbient,
+            Self::EntityPandaDeath,
+            Self::EntityPandaEat,
+            Self::EntityPandaStep,
+            Self::EntityPandaCantBreed,
+            Self::EntityPandaAggressiveAmbient,
+            Self::EntityPandaWorriedAmbient,
+            Self::EntityPandaHurt,
+            Self::EntityPandaBite,
+            Self::EntityParrotAmbient,
+            Self::EntityParrotDeath,
+            Self::EntityParrotEat,
+            Self::EntityParrotFly,
+            Self::EntityParrotHurt,
+            Self::EntityParrotImitateBlaze,
+            Self::EntityParrotImitateBogged,
+            Self::EntityParrotImitateBreeze,
+            Self::EntityParrotImitateCreaking,
+            Self::EntityParrotImitateCreeper,
+            Self::EntityParrotImitateDrowned,
+            Self::EntityParrotImitateElderGuardian,
+            Self::EntityParrotImitateEnderDragon,
+            Self::EntityParrotImitateEndermite,
+            Self::EntityParrotImitateEvoker,
+            Self::EntityParrotImitateGhast,
+            Self::EntityParrotImitateGuardian,
+            Self::EntityParrotImitateHoglin,
+            Self::EntityParrotImitateHusk,
+            Self::EntityParrotImitateIllusioner,
+            Self::EntityParrotImitateMagmaCube,
+            Self::EntityParrotImitatePhantom,
+            Self::EntityParrotImitatePiglin,
+            Self::EntityParrotImitatePiglinBrute,
+            Self::EntityParrotImitatePillager,
+            Self::EntityParrotImitateRavager,
+            Self::EntityParrotImitateShulker,
+            Self::EntityParrotImitateSilverfish,
+            Self::EntityParrotImitateSkeleton,
+            Self::EntityParrotImitateSlime,
+            Self::EntityParrotImitateSpider,
+            Self::EntityParrotImitateStray,
+            Self::EntityParrotImitateVex,
+            Self::EntityParrotImitateVindicator,
+            Self::EntityParrotImitateWarden,
+            Self::EntityParrotImitateWitch,
+            Self::EntityParrotImitateWither,
+            Self::EntityParrotImitateWitherSkeleton,
+            Self::EntityParrotImitateZoglin,
+            Self::EntityParrotImitateZombie,
+            Self::EntityParrotImitateZombieVillager,
+            Self::EntityParrotStep,
+            Self::EntityPhantomAmbient,
+            Self::EntityPhantomBite,
+            Self::EntityPhantomDeath,
+            Self::EntityPhantomFlap,
+            Self::EntityPhantomHurt,
+            Self::EntityPhantomSwoop,
+            Self::EntityPigAmbient,
+            Self::EntityPigDeath,
+            Self::EntityPigHurt,
+            Self::EntityPigSaddle,
+            Self::EntityPigStep,
+            Self::EntityPiglinAdmiringItem,
+            Self::EntityPiglinAmbient,
+            Self::EntityPiglinAngry,
+            Self::EntityPiglinCelebrate,
+            Self::EntityPiglinDeath,
+            Self::EntityPiglinJealous,
+            Self::EntityPiglinHurt,
+            Self::EntityPiglinRetreat,
+            Self::EntityPiglinStep,
+            Self::EntityPiglinConvertedToZombified,
+            Self::EntityPiglinBruteAmbient,
+            Self::EntityPiglinBruteAngry,
+            Self::EntityPiglinBruteDeath,
+            Self::EntityPiglinBruteHurt,
+            Self::EntityPiglinBruteStep,
+            Self::EntityPiglinBruteConvertedToZombified,
+            Self::EntityPillagerAmbient,
+            Self::EntityPillagerCelebrate,
+            Self::EntityPillagerDeath,
+            Self::EntityPillagerHurt,
+            Self::BlockPistonContract,
+            Self::BlockPistonExtend,
+            Self::EntityPlayerAttackCrit,
+            Self::EntityPlayerAttackKnockback,
+            Self::EntityPlayerAttackNodamage,
+            Self::EntityPlayerAttackStrong,
+            Self::EntityPlayerAttackSweep,
+            Self::EntityPlayerAttackWeak,
+            Self::EntityPlayerBigFall,
+            Self::EntityPlayerBreath,
+            Self::EntityPlayerBurp,
+            Self::EntityPlayerDeath,
+            Self::EntityPlayerHurt,
+            Self::EntityPlayerHurtDrown,
+            Self::EntityPlayerHurtFreeze,
+            Self::EntityPlayerHurtOnFire,
+            Self::EntityPlayerHurtSweetBerryBush,
+            Self::EntityPlayerLevelup,
+            Self::EntityPlayerSmallFall,
+            Self::EntityPlayerSplash,
+            Self::EntityPlayerSplashHighSpeed,
+            Self::EntityPlayerSwim,
+            Self::EntityPlayerTeleport,
+            Self::EntityPolarBearAmbient,
+            Self::EntityPolarBearAmbientBaby,
+            Self::EntityPolarBearDeath,
+            Self::EntityPolarBearHurt,
+            Self::EntityPolarBearStep,
+            Self::EntityPolarBearWarning,
+            Self::BlockPolishedDeepslateBreak,
+            Self::BlockPolishedDeepslateFall,
+            Self::BlockPolishedDeepslateHit,
+            Self::BlockPolishedDeepslatePlace,
+            Self::BlockPolishedDeepslateStep,
+            Self::BlockPortalAmbient,
+            Self::BlockPortalTravel,
+            Self::BlockPortalTrigger,
+            Self::BlockPowderSnowBreak,
+            Self::BlockPowderSnowFall,
+            Self::BlockPowderSnowHit,
+            Self::BlockPowderSnowPlace,
+            Self::BlockPowderSnowStep,
+            Self::EntityPufferFishBlowOut,
+            Self::EntityPufferFishBlowUp,
+            Self::EntityPufferFishDeath,
+            Self::EntityPufferFishFlop,
+            Self::EntityPufferFishHurt,
+            Self::EntityPufferFishSting,
+            Self::BlockPumpkinCarve,
+            Self::EntityRabbitAmbient,
+            Self::EntityRabbitAttack,
+            Self::EntityRabbitDeath,
+            Self::EntityRabbitHurt,
+            Self::EntityRabbitJump,
+            Self::EventRaidHorn,
+            Self::EntityRavagerAmbient,
+            Self::EntityRavagerAttack,
+            Self::EntityRavagerCelebrate,
+            Self::EntityRavagerDeath,
+            Self::EntityRavagerHurt,
+            Self::EntityRavagerStep,
+            Self::EntityRavagerStunned,
+            Self::EntityRavagerRoar,
+            Self::BlockNetherGoldOreBreak,
+            Self::BlockNetherGoldOreFall,
+            Self::BlockNetherGoldOreHit,
+            Self::BlockNetherGoldOrePlace,
+            Self::BlockNetherGoldOreStep,
+            Self::BlockNetherOreBreak,
+            Self::BlockNetherOreFall,
+            Self::BlockNetherOreHit,
+            Self::BlockNetherOrePlace,
+            Self::BlockNetherOreStep,
+            Self::BlockRedstoneTorchBurnout,
+            Self::BlockRespawnAnchorAmbient,
+            Self::BlockRespawnAnchorCharge,
+            Self::BlockRespawnAnchorDeplete,
+            Self::BlockRespawnAnchorSetSpawn,
+            Self::BlockRootedDirtBreak,
+            Self::BlockRootedDirtFall,
+            Self::BlockRootedDirtHit,
+            Self::BlockRootedDirtPlace,
+            Self::BlockRootedDirtStep,
+            Self::EntitySalmonAmbient,
+            Self::EntitySalmonDeath,
+            Self::EntitySalmonFlop,
+            Self::EntitySalmonHurt,
+            Self::BlockSandBreak,
+            Self::BlockSandFall,
+            Self::BlockSandHit,
+            Self::BlockSandPlace,
+            Self::BlockSandStep,
+            Self::BlockSandIdle,
+            Self::BlockScaffoldingBreak,
+            Self::BlockScaffoldingFall,
+            Self::BlockScaffoldingHit,
+            Self::BlockScaffoldingPlace,
+            Self::BlockScaffoldingStep,
+            Self::BlockSculkSpread,
+            Self::BlockSculkCharge,
+            Self::BlockSculkBreak,
+            Self::BlockSculkFall,
+            Self::BlockSculkHit,
+            Self::BlockSculkPlace,
+            Self::BlockSculkStep,
+            Self::BlockSculkCatalystBloom,
+            Self::BlockSculkCatalystBreak,
+            Self::BlockSculkCatalystFall,
+            Self::BlockSculkCatalystHit,
+            Self::BlockSculkCatalystPlace,
+            Self::BlockSculkCatalystStep,
+            Self::BlockSculkSensorClicking,
+            Self::BlockSculkSensorClickingStop,
+            Self::BlockSculkSensorBreak,
+            Self::BlockSculkSensorFall,
+            Self::BlockSculkSensorHit,
+            Self::BlockSculkSensorPlace,
+            Self::BlockSculkSensorStep,
+            Self::BlockSculkShriekerBreak,
+            Self::BlockSculkShriekerFall,
+            Self::BlockSculkShriekerHit,
+            Self::BlockSculkShriekerPlace,
+            Self::BlockSculkShriekerShriek,
+            Self::BlockSculkShriekerStep,
+            Self::BlockSculkVeinBreak,
+            Self::BlockSculkVeinFall,
+            Self::BlockSculkVeinHit,
+            Self::BlockSculkVeinPlace,
+            Self::BlockSculkVeinStep,
+            Self::EntitySheepAmbient,
+            Self::EntitySheepDeath,
+            Self::EntitySheepHurt,
+            Self::EntitySheepShear,
+            Self::EntitySheepStep,
+            Self::ItemShearsSnip,
+            Self::ItemShieldBlock,
+            Self::ItemShieldBreak,
+            Self::BlockShroomlightBreak,
+            Self::BlockShroomlightStep,
+            Self::BlockShroomlightPlace,
+            Self::BlockShroomlightHit,
+            Self::BlockShroomlightFall,
+            Self::ItemShovelFlatten,
+            Self::EntityShulkerAmbient,
+            Self::BlockShulkerBoxClose,
+            Self::BlockShulkerBoxOpen,
+            Self::EntityShulkerBulletHit,
+            Self::EntityShulkerBulletHurt,
+            Self::EntityShulkerClose,
+            Self::EntityShulkerDeath,
+            Self::EntityShulkerHurt,
+            Self::EntityShulkerHurtClosed,
+            Self::EntityShulkerOpen,
+            Self::EntityShulkerShoot,
+            Self::EntityShulkerTeleport,
+            Self::EntitySilverfishAmbient,
+            Self::EntitySilverfishDeath,
+            Self::EntitySilverfishHurt,
+            Self::EntitySilverfishStep,
+            Self::EntitySkeletonAmbient,
+            Self::EntitySkeletonConvertedToStray,
+            Self::EntitySkeletonDeath,
+            Self::EntitySkeletonHorseAmbient,
+            Self::EntitySkeletonHorseDeath,
+            Self::EntitySkeletonHorseHurt,
+            Self::EntitySkeletonHorseSwim,
+            Self::EntitySkeletonHorseAmbientWater,
+            Self::EntitySkeletonHorseGallopWater,
+            Self::EntitySkeletonHorseJumpWater,
+            Self::EntitySkeletonHorseStepWater,
+            Self::EntitySkeletonHurt,
+            Self::EntitySkeletonShoot,
+            Self::EntitySkeletonStep,
+            Self::EntitySlimeAttack,
+            Self::EntitySlimeDeath,
+            Self::EntitySlimeHurt,
+            Self::EntitySlimeJump,
+            Self::EntitySlimeSquish,
+            Self::BlockSlimeBlockBreak,
+            Self::BlockSlimeBlockFall,
+            Self::BlockSlimeBlockHit,
+            Self::BlockSlimeBlockPlace,
+            Self::BlockSlimeBlockStep,
+            Self::BlockSmallAmethystBudBreak,
+            Self::BlockSmallAmethystBudPlace,
+            Self::BlockSmallDripleafBreak,
+            Self::BlockSmallDripleafFall,
+            Self::BlockSmallDripleafHit,
+            Self::BlockSmallDripleafPlace,
+            Self::BlockSmallDripleafStep,
+            Self::BlockSoulSandBreak,
+            Self::BlockSoulSandStep,
+            Self::BlockSoulSandPlace,
+            Self::BlockSoulSandHit,
+            Self::BlockSoulSandFall,
+            Self::BlockSoulSoilBreak,
+            Self::BlockSoulSoilStep,
+            Self::BlockSoulSoilPlace,
+            Self::BlockSoulSoilHit,
+            Self::BlockSoulSoilFall,
+            Self::ParticleSoulEscape,
+            Self::BlockSpawnerBreak,
+            Self::BlockSpawnerFall,
+            Self::BlockSpawnerHit,
+            Self::BlockSpawnerPlace,
+            Self::BlockSpawnerStep,
+            Self::BlockResinBreak,
+            Self::BlockResinFall,
+            Self::BlockResinPlace,
+            Self::BlockResinStep,
+            Self::BlockResinBricksBreak,
+            Self::BlockResinBricksFall,
+            Self::BlockResinBricksHit,
+            Self::BlockResinBricksPlace,
+            Self::BlockResinBricksStep,
+            Self::BlockSporeBlossomBreak,
+            Self::BlockSporeBlossomFall,
+            Self::BlockSporeBlossomHit,
+            Self::BlockSporeBlossomPlace,
+            Self::BlockSporeBlossomStep,
+            Self::EntityStriderAmbient,
+            Self::EntityStriderHappy,
+            Self::EntityStriderRetreat,
+            Self::EntityStriderDeath,
+            Self::EntityStriderHurt,
+            Self::EntityStriderStep,
+            Self::EntityStriderStepLava,
+            Self::EntityStriderEat,
+            Self::EntityStriderSaddle,
+            Self::EntitySlimeDeathSmall,
+            Self::EntitySlimeHurtSmall,
+            Self::EntitySlimeJumpSmall,
+            Self::EntitySlimeSquishSmall,
+            Self::BlockSmithingTableUse,
+            Self::BlockSmokerSmoke,
+            Self::EntitySnifferStep,
+            Self::EntitySnifferEat,
+            Self::EntitySnifferIdle,
+            Self::EntitySnifferHurt,
+            Self::EntitySnifferDeath,
+            Self::EntitySnifferDropSeed,
+            Self::EntitySnifferScenting,
+            Self::EntitySnifferSniffing,
+            Self::EntitySnifferSearching,
+            Self::EntitySnifferDigging,
+            Self::EntitySnifferDiggingStop,
+            Self::EntitySnifferHappy,
+            Self::BlockSnifferEggPlop,
+            Self::BlockSnifferEggCrack,
+            Self::BlockSnifferEggHatch,
+            Self::EntitySnowballThrow,
+            Self::BlockSnowBreak,
+            Self::BlockSnowFall,
+            Self::EntitySnowGolemAmbient,
+            Self::EntitySnowGolemDeath,
+            Self::EntitySnowGolemHurt,
+            Self::EntitySnowGolemShoot,
+            Self::EntitySnowGolemShear,
+            Self::BlockSnowHit,
+            Self::BlockSnowPlace,
+            Self::BlockSnowStep,
+            Self::EntitySpiderAmbient,
+            Self::EntitySpiderDeath,
+            Self::EntitySpiderHurt,
+            Self::EntitySpiderStep,
+            Self::EntitySplashPotionBreak,
+            Self::EntitySplashPotionThrow,
+            Self::BlockSpongeBreak,
+            Self::BlockSpongeFall,
+            Self::BlockSpongeHit,
+            Self::BlockSpongePlace,
+            Self::BlockSpongeStep,
+            Self::BlockSpongeAbsorb,
+            Self::ItemSpyglassUse,
+            Self::ItemSpyglassStopUsing,
+            Self::EntitySquidAmbient,
+            Self::EntitySquidDeath,
+            Self::EntitySquidHurt,
+            Self::EntitySquidSquirt,
+            Self::BlockStoneBreak,
+            Self::BlockStoneButtonClickOff,
+            Self::BlockStoneButtonClickOn,
+            Self::BlockStoneFall,
+            Self::BlockStoneHit,
+            Self::BlockStonePlace,
+            Self::BlockStonePressurePlateClickOff,
+            Self::BlockStonePressurePlateClickOn,
+            Self::BlockStoneStep,
+            Self::EntityStrayAmbient,
+            Self::EntityStrayDeath,
+            Self::EntityStrayHurt,
+            Self::EntityStrayStep,
+            Self::BlockSweetBerryBushBreak,
+            Self::BlockSweetBerryBushPlace,
+            Self::BlockSweetBerryBushPickBerries,
+            Self::EntityTadpoleDeath,
+            Self::EntityTadpoleFlop,
+            Self::EntityTadpoleGrowUp,
+            Self::EntityTadpoleHurt,
+            Self::EnchantThornsHit,
+            Self::EntityTntPrimed,
+            Self::ItemTotemUse,
+            Self::ItemTridentHit,
+            Self::ItemTridentHitGround,
+            Self::ItemTridentReturn,
+            Self::ItemTridentRiptide1,
+            Self::ItemTridentRiptide2,
+            Self::ItemTridentRiptide3,
+            Self::ItemTridentThrow,
+            Self::ItemTridentThunder,
+            Self::BlockTripwireAttach,
+            Self::BlockTripwireClickOff,
+            Self::BlockTripwireClickOn,
+            Self::BlockTripwireDetach,
+            Self::EntityTropicalFishAmbient,
+            Self::EntityTropicalFishDeath,
+            Self::EntityTropicalFishFlop,
+            Self::EntityTropicalFishHurt,
+            Self::BlockTuffBreak,
+            Self::BlockTuffStep,
+            Self::BlockTuffPlace,
+            Self::BlockTuffHit,
+            Self::BlockTuffFall,
+            Self::BlockTuffBricksBreak,
+            Self::BlockTuffBricksFall,
+            Self::BlockTuffBricksHit,
+            Self::BlockTuffBricksPlace,
+            Self::BlockTuffBricksStep,
+            Self::BlockPolishedTuffBreak,
+            Self::BlockPolishedTuffFall,
+            Self::BlockPolishedTuffHit,
+            Self::BlockPolishedTuffPlace,
+            Self::BlockPolishedTuffStep,
+            Self::EntityTurtleAmbientLand,
+            Self::EntityTurtleDeath,
+            Self::EntityTurtleDeathBaby,
+            Self::EntityTurtleEggBreak,
+            Self::EntityTurtleEggCrack,
+            Self::EntityTurtleEggHatch,
+            Self::EntityTurtleHurt,
+            Self::EntityTurtleHurtBaby,
+            Self::EntityTurtleLayEgg,
+            Self::EntityTurtleShamble,
+            Self::EntityTurtleShambleBaby,
+            Self::EntityTurtleSwim,
+            Self::UiButtonClick,
+            Self::UiLoomSelectPattern,
+            Self::UiLoomTakeResult,
+            Self::UiCartographyTableTakeResult,
+            Self::UiStonecutterTakeResult,
+            Self::UiStonecutterSelectRecipe,
+            Self::UiToastChallengeComplete,
+            Self::UiToastIn,
+            Self::UiToastOut,
+            Self::BlockVaultActivate,
+            Self::BlockVaultAmbient,
+            Self::BlockVaultBreak,
+            Self::BlockVaultCloseShutter,
+            Self::BlockVaultDeactivate,
+            Self::BlockVaultEjectItem,
+            Self::BlockVaultRejectRewardedPlayer,
+            Self::BlockVaultFall,
+            Self::BlockVaultHit,
+            Self::BlockVaultInsertItem,
+            Self::BlockVaultInsertItemFail,
+            Self::BlockVaultOpenShutter,
+            Self::BlockVaultPlace,
+            Self::BlockVaultStep,
+            Self::EntityVexAmbient,
+            Self::EntityVexCharge,
+            Self::EntityVexDeath,
+            Self::EntityVexHurt,
+            Self::EntityVillagerAmbient,
+            Self::EntityVillagerCelebrate,
+            Self::EntityVillagerDeath,
+            Self::EntityVillagerHurt,
+            Self::EntityVillagerNo,
+            Self::EntityVillagerTrade,
+            Self::EntityVillagerYes,
+            Self::EntityVillagerWorkArmorer,
+            Self::EntityVillagerWorkButcher,
+            Self::EntityVillagerWorkCartographer,
+            Self::EntityVillagerWorkCleric,
+            Self::EntityVillagerWorkFarmer,
+            Self::EntityVillagerWorkFisherman,
+            Self::EntityVillagerWorkFletcher,
+            Self::EntityVillagerWorkLeatherworker,
+            Self::EntityVillagerWorkLibrarian,
+            Self::EntityVillagerWorkMason,
+            Self::EntityVillagerWorkShepherd,
+            Self::EntityVillagerWorkToolsmith,
+            Self::EntityVillagerWorkWeaponsmith,
+            Self::EntityVindicatorAmbient,
+            Self::EntityVindicatorCelebrate,
+            Self::EntityVindicatorDeath,
+            Self::EntityVindicatorHurt,
+            Self::BlockVineBreak,
+            Self::BlockVineFall,
+            Self::BlockVineHit,
+            Self::BlockVinePlace,
+            Self::BlockVineStep,
+            Self::BlockLilyPadPlace,
+            Self::EntityWanderingTraderAmbient,
+            Self::EntityWanderingTraderDeath,
+            Self::EntityWanderingTraderDisappeared,
+            Self::EntityWanderingTraderDrinkMilk,
+            Self::EntityWanderingTraderDrinkPotion,
+            Self::EntityWanderingTraderHurt,
+            Self::EntityWanderingTraderNo,
+            Self::EntityWanderingTraderReappeared,
+            Self::EntityWanderingTraderTrade,
+            Self::EntityWanderingTraderYes,
+            Self::EntityWardenAgitated,
+            Self::EntityWardenAmbient,
+            Self::EntityWardenAngry,
+            Self::EntityWardenAttackImpact,
+            Self::EntityWardenDeath,
+            Self::EntityWardenDig,
+            Self::EntityWardenEmerge,
+            Self::EntityWardenHeartbeat,
+            Self::EntityWardenHurt,
+            Self::EntityWardenListening,
+            Self::EntityWardenListeningAngry,
+            Self::EntityWardenNearbyClose,
+            Self::EntityWardenNearbyCloser,
+            Self::EntityWardenNearbyClosest,
+            Self::EntityWardenRoar,
+            Self::EntityWardenSniff,
+            Self::EntityWardenSonicBoom,
+            Self::EntityWardenSonicCharge,
+            Self::EntityWardenStep,
+            Self::EntityWardenTendrilClicks,
+            Self::BlockHangingSignWaxedInteractFail,
+            Self::BlockSignWaxedInteractFail,
+            Self::BlockWaterAmbient,
+            Self::WeatherRain,
+            Self::WeatherRainAbove,
+            Self::BlockWetGrassBreak,
+            Self::BlockWetGrassFall,
+            Self::BlockWetGrassHit,
+            Self::BlockWetGrassPlace,
+            Self::BlockWetGrassStep,
+            Self::BlockWetSpongeBreak,
+            Self::BlockWetSpongeDries,
+            Self::BlockWetSpongeFall,
+            Self::BlockWetSpongeHit,
+            Self::BlockWetSpongePlace,
+            Self::BlockWetSpongeStep,
+            Self::EntityWindChargeWindBurst,
+            Self::EntityWindChargeThrow,
+            Self::EntityWitchAmbient,
+            Self::EntityWitchCelebrate,
+            Self::EntityWitchDeath,
+            Self::EntityWitchDrink,
+            Self::EntityWitchHurt,
+            Self::EntityWitchThrow,
+            Self::EntityWitherAmbient,
+            Self::EntityWitherBreakBlock,
+            Self::EntityWitherDeath,
+            Self::EntityWitherHurt,
+            Self::EntityWitherShoot,
+            Self::EntityWitherSkeletonAmbient,
+            Self::EntityWitherSkeletonDeath,
+            Self::EntityWitherSkeletonHurt,
+            Self::EntityWitherSkeletonStep,
+            Self::EntityWitherSpawn,
+            Self::ItemWolfArmorBreak,
+            Self::ItemWolfArmorCrack,
+            Self::ItemWolfArmorDamage,
+            Self::ItemWolfArmorRepair,
+            Self::EntityWolfShake,
+            Self::EntityWolfStep,
+            Self::EntityWolfAmbient,
+            Self::EntityWolfDeath,
+            Self::EntityWolfGrowl,
+            Self::EntityWolfHurt,
+            Self::EntityWolfPant,
+            Self::EntityWolfWhine,
+            Self::EntityWolfPuglinAmbient,
+            Self::EntityWolfPuglinDeath,
+            Self::EntityWolfPuglinGrowl,
+            Self::EntityWolfPuglinHurt,
+            Self::EntityWolfPuglinPant,
+            Self::EntityWolfPuglinWhine,
+            Self::EntityWolfSadAmbient,
+            Self::EntityWolfSadDeath,
+            Self::EntityWolfSadGrowl,
+            Self::EntityWolfSadHurt,
+            Self::EntityWolfSadPant,
+            Self::EntityWolfSadWhine,
+            Self::EntityWolfAngryAmbient,
+            Self::EntityWolfAngryDeath,
+            Self::EntityWolfAngryGrowl,
+            Self::EntityWolfAngryHurt,
+            Self::EntityWolfAngryPant,
+            Self::EntityWolfAngryWhine,
+            Self::EntityWolfGrumpyAmbient,
+            Self::EntityWolfGrumpyDeath,
+            Self::EntityWolfGrumpyGrowl,
+            Self::EntityWolfGrumpyHurt,
+            Self::EntityWolfGrumpyPant,
+            Self::EntityWolfGrumpyWhine,
+            Self::EntityWolfBigAmbient,
+            Self::EntityWolfBigDeath,
+            Self::EntityWolfBigGrowl,
+            Self::EntityWolfBigHurt,
+            Self::EntityWolfBigPant,
+            Self::EntityWolfBigWhine,
+            Self::EntityWolfCuteAmbient,
+            Self::EntityWolfCuteDeath,
+            Self::EntityWolfCuteGrowl,
+            Self::EntityWolfCuteHurt,
+            Self::EntityWolfCutePant,
+            Self::EntityWolfCuteWhine,
+            Self::BlockWoodenDoorClose,
+            Self::BlockWoodenDoorOpen,
+            Self::BlockWoodenTrapdoorClose,
+            Self::BlockWoodenTrapdoorOpen,
+            Self::BlockWoodenButtonClickOff,
+            Self::BlockWoodenButtonClickOn,
+            Self::BlockWoodenPressurePlateClickOff,
+            Self::BlockWoodenPressurePlateClickOn,
+            Self::BlockWoodBreak,
+            Self::BlockWoodFall,
+            Self::BlockWoodHit,
+            Self::BlockWoodPlace,
+            Self::BlockWoodStep,
+            Self::BlockWoolBreak,
+            Self::BlockWoolFall,
+            Self::BlockWoolHit,
+            Self::BlockWoolPlace,
+            Self::BlockWoolStep,
+            Self::EntityZoglinAmbient,
+            Self::EntityZoglinAngry,
+            Self::EntityZoglinAttack,
+            Self::EntityZoglinDeath,
+            Self::EntityZoglinHurt,
+            Self::EntityZoglinStep,
+            Self::EntityZombieAmbient,
+            Self::EntityZombieAttackWoodenDoor,
+            Self::EntityZombieAttackIronDoor,
+            Self::EntityZombieBreakWoodenDoor,
+            Self::EntityZombieConvertedToDrowned,
+            Self::EntityZombieDeath,
+            Self::EntityZombieDestroyEgg,
+            Self::EntityZombieHorseAmbient,
+            Self::EntityZombieHorseDeath,
+            Self::EntityZombieHorseHurt,
+            Self::EntityZombieHurt,
+            Self::EntityZombieInfect,
+            Self::EntityZombifiedPiglinAmbient,
+            Self::EntityZombifiedPiglinAngry,
+            Self::EntityZombifiedPiglinDeath,
+            Self::EntityZombifiedPiglinHurt,
+            Self::EntityZombieStep,
+            Self::EntityZombieVillagerAmbient,
+            Self::EntityZombieVillagerConverted,
+            Self::EntityZombieVillagerCure,
+            Self::EntityZombieVillagerDeath,
+            Self::EntityZombieVillagerHurt,
+            Self::EntityZombieVillagerStep,
+            Self::EventMobEffectBadOmen,
+            Self::EventMobEffectTrialOmen,
+            Self::EventMobEffectRaidOmen,
+            Self::ItemSaddleUnequip,
+        ]
+    }
 }
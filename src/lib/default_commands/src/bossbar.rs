@@ -0,0 +1,242 @@
+use crate::title::{players, PlayerQuery};
+use bevy_ecs::prelude::{Commands, Entity, Query};
+use ferrumc_commands::arg::entities::EntityArgument;
+use ferrumc_commands::arg::primitive::string::{GreedyString, SingleWord};
+use ferrumc_commands::Sender;
+use ferrumc_core::boss_bar::{BossBar, BossBarColor, BossBarStyle, CustomBossBar};
+use ferrumc_macros::command;
+use ferrumc_text::TextComponent;
+
+type BarQuery<'a> = (Entity, &'a mut BossBar, &'a mut CustomBossBar);
+
+fn reply(sender: &Sender, message: String) {
+    sender.send_message(TextComponent::from(message), false);
+}
+
+/// How a bar is named in replies.
+fn describe(bar: &BossBar) -> String {
+    format!("[{}]", bar.title.to_plain_string())
+}
+
+/// Usage: /bossbar list
+#[command("bossbar list")]
+fn bossbar_list(#[sender] sender: Sender, bars: Query<BarQuery>) {
+    let names: Vec<String> = bars.iter().map(|(_, bar, _)| describe(&bar)).collect();
+    if names.is_empty() {
+        reply(&sender, "There are no custom bossbars active".to_string());
+    } else {
+        reply(
+            &sender,
+            format!(
+                "There are {} custom bossbar(s) active: {}",
+                names.len(),
+                names.join(", ")
+            ),
+        );
+    }
+}
+
+/// Usage: /bossbar add <id> <name>
+///
+/// The name is given as JSON, like `{"text":"Boss","color":"red"}`.
+#[command("bossbar add")]
+fn bossbar_add(
+    #[sender] sender: Sender,
+    #[arg] id: SingleWord,
+    #[arg] name: TextComponent,
+    args: (Query<BarQuery>, Commands),
+) {
+    let (bars, mut commands) = args;
+    if bars.iter().any(|(_, _, custom)| custom.id == *id) {
+        reply(
+            &sender,
+            format!("A bossbar already exists with the ID '{}'", id.as_str()),
+        );
+        return;
+    }
+
+    let bar = BossBar::new(name);
+    reply(
+        &sender,
+        format!("Created custom bossbar {}", describe(&bar)),
+    );
+    commands.spawn((bar, CustomBossBar::new(id.to_string())));
+}
+
+/// Usage: /bossbar remove <id>
+#[command("bossbar remove")]
+fn bossbar_remove(
+    #[sender] sender: Sender,
+    #[arg] id: SingleWord,
+    args: (Query<BarQuery>, Commands),
+) {
+    let (bars, mut commands) = args;
+    let Some((entity, bar, _)) = bars.iter().find(|(_, _, custom)| custom.id == *id) else {
+        reply(
+            &sender,
+            format!("No bossbar exists with the ID '{}'", id.as_str()),
+        );
+        return;
+    };
+    reply(
+        &sender,
+        format!("Removed custom bossbar {}", describe(&bar)),
+    );
+    commands.entity(entity).despawn();
+}
+
+/// Usage: /bossbar get <id> max|value|visible|players
+#[command("bossbar get")]
+fn bossbar_get(
+    #[sender] sender: Sender,
+    #[arg] id: SingleWord,
+    #[arg] property: SingleWord,
+    bars: Query<BarQuery>,
+) {
+    let Some((_, bar, custom)) = bars.iter().find(|(_, _, custom)| custom.id == *id) else {
+        reply(
+            &sender,
+            format!("No bossbar exists with the ID '{}'", id.as_str()),
+        );
+        return;
+    };
+    let name = describe(&bar);
+    let message = match property.as_str() {
+        "max" => format!("Custom bossbar {name} has a maximum of {}", custom.max),
+        "value" => format!("Custom bossbar {name} has a value of {}", custom.value),
+        "visible" if bar.visible => format!("Custom bossbar {name} is currently shown"),
+        "visible" => format!("Custom bossbar {name} is currently hidden"),
+        "players" => format!(
+            "Custom bossbar {name} has {} player(s) watching",
+            bar.viewers().count()
+        ),
+        other => {
+            format!("Unknown bossbar property '{other}', expected max, value, visible or players")
+        }
+    };
+    reply(&sender, message);
+}
+
+/// Usage: /bossbar set <id> <property> [value]
+///
+/// The properties are name, color, style, value, max, visible and players. The name is given as
+/// JSON, and players takes any number of selectors or names, or none to clear them.
+#[command("bossbar set")]
+fn bossbar_set(
+    #[sender] sender: Sender,
+    #[arg] id: SingleWord,
+    #[arg] property: SingleWord,
+    #[arg] value: Option<GreedyString>,
+    args: (Query<BarQuery>, Query<PlayerQuery>),
+) {
+    let (mut bars, query) = args;
+    let Some((_, mut bar, mut custom)) = bars.iter_mut().find(|(_, _, custom)| custom.id == *id)
+    else {
+        reply(
+            &sender,
+            format!("No bossbar exists with the ID '{}'", id.as_str()),
+        );
+        return;
+    };
+    let value = value
+        .as_deref()
+        .map(|value| value.trim())
+        .unwrap_or_default();
+    if value.is_empty() && property.as_str() != "players" {
+        reply(
+            &sender,
+            format!("Missing a value for {}", property.as_str()),
+        );
+        return;
+    }
+
+    let name = describe(&bar);
+    let message = match property.as_str() {
+        "name" => match TextComponent::from_json(value) {
+            Ok(title) => {
+                bar.title = title;
+                format!(
+                    "Custom bossbar {name} has been renamed to {}",
+                    describe(&bar)
+                )
+            }
+            Err(err) => format!("Invalid text component: {err}"),
+        },
+        "color" => match BossBarColor::from_name(value) {
+            Some(color) => {
+                bar.color = color;
+                format!(
+                    "Custom bossbar {name} has changed color to {}",
+                    color.name()
+                )
+            }
+            None => format!(
+                "Unknown color '{value}', expected pink, blue, red, green, yellow, purple or \
+                 white"
+            ),
+        },
+        "style" => match BossBarStyle::from_name(value) {
+            Some(style) => {
+                bar.style = style;
+                format!(
+                    "Custom bossbar {name} has changed style to {}",
+                    style.name()
+                )
+            }
+            None => format!(
+                "Unknown style '{value}', expected progress, notched_6, notched_10, notched_12 \
+                 or notched_20"
+            ),
+        },
+        "value" => match value.parse::<i32>() {
+            Ok(amount) if amount >= 0 => {
+                custom.value = amount;
+                bar.set_progress(custom.progress());
+                format!("Custom bossbar {name} has changed value to {amount}")
+            }
+            _ => format!("Invalid value '{value}', expected a whole number of at least 0"),
+        },
+        "max" => match value.parse::<i32>() {
+            Ok(amount) if amount >= 1 => {
+                custom.max = amount;
+                bar.set_progress(custom.progress());
+                format!("Custom bossbar {name} has changed maximum to {amount}")
+            }
+            _ => format!("Invalid maximum '{value}', expected a whole number of at least 1"),
+        },
+        "visible" => match value {
+            "true" | "false" => {
+                bar.visible = value == "true";
+                if bar.visible {
+                    format!("Custom bossbar {name} is now visible")
+                } else {
+                    format!("Custom bossbar {name} is now hidden")
+                }
+            }
+            _ => format!("Invalid value '{value}', expected true or false"),
+        },
+        "players" => {
+            let targets: Result<Vec<EntityArgument>, _> =
+                value.split_whitespace().map(str::parse).collect();
+            match targets {
+                Ok(targets) => {
+                    let viewers: Vec<Entity> = targets
+                        .iter()
+                        .flat_map(|target| players(target, &query))
+                        .collect();
+                    bar.set_viewers(viewers);
+                    format!(
+                        "Custom bossbar {name} now has {} player(s)",
+                        bar.viewers().count()
+                    )
+                }
+                Err(err) => return sender.send_message(*err, false),
+            }
+        }
+        other => format!(
+            "Unknown bossbar property '{other}', expected name, color, style, value, max, visible \
+             or players"
+        ),
+    };
+    reply(&sender, message);
+}
@@ -12,6 +12,7 @@ fn test_command(
 ) {
     let username = match sender {
         Sender::Server => "Server".to_string(),
+        Sender::Rcon(_) => "Rcon".to_string(),
         Sender::Player(entity) => query
             .get(entity)
             .expect("sender does not exist")
@@ -0,0 +1,106 @@
+use bevy_ecs::prelude::{Entity, MessageWriter, Query};
+use ferrumc_commands::arg::entities::EntityArgument;
+use ferrumc_commands::arg::primitive::int::Integer;
+use ferrumc_commands::Sender;
+use ferrumc_components::active_effects::EffectState;
+use ferrumc_core::identity::entity_identity::EntityIdentity;
+use ferrumc_core::identity::player_identity::PlayerIdentity;
+use ferrumc_data::generated::effects::Effect;
+use ferrumc_macros::command;
+use ferrumc_messages::{AddEffect, RemoveEffect};
+use ferrumc_text::TextComponent;
+
+type EffectSeconds = Integer<1, 1_000_000>;
+type EffectAmplifier = Integer<0, 255>;
+
+type TargetQuery<'a> = (
+    Entity,
+    Option<&'a EntityIdentity>,
+    Option<&'a PlayerIdentity>,
+);
+
+/// How long effects last when no duration is given, in seconds.
+const DEFAULT_SECONDS: i32 = 30;
+
+/// Effect durations are counted in game ticks, like vanilla.
+const TICKS_PER_SECOND: i32 = 20;
+
+/// Only players can have effects for now.
+fn players(targets: EntityArgument, query: &Query<TargetQuery>) -> Vec<Entity> {
+    targets
+        .resolve(query.iter())
+        .into_iter()
+        .filter(|entity| matches!(query.get(*entity), Ok((_, _, Some(_)))))
+        .collect()
+}
+
+/// Usage: /effect give <targets> <effect> [seconds] [amplifier] [hideParticles]
+#[command("effect give")]
+fn effect_give(
+    #[sender] sender: Sender,
+    #[arg] targets: EntityArgument,
+    #[arg] effect: Effect,
+    #[arg] seconds: Option<EffectSeconds>,
+    #[arg] amplifier: Option<EffectAmplifier>,
+    #[arg] hide_particles: Option<bool>,
+    args: (Query<TargetQuery>, MessageWriter<AddEffect>),
+) {
+    let (query, mut effect_msgs) = args;
+    let targets = players(targets, &query);
+    if targets.is_empty() {
+        sender.send_message(TextComponent::from("No player was found"), false);
+        return;
+    }
+
+    let seconds = seconds.map(|seconds| *seconds).unwrap_or(DEFAULT_SECONDS);
+    let mut state = EffectState::new(
+        amplifier.map(|amplifier| *amplifier as u8).unwrap_or(0),
+        seconds * TICKS_PER_SECOND,
+    );
+    state.show_particles = !hide_particles.unwrap_or(false);
+
+    for &entity in &targets {
+        effect_msgs.write(AddEffect {
+            entity,
+            effect,
+            state,
+        });
+    }
+    sender.send_message(
+        TextComponent::from(format!(
+            "Applied effect {} to {} target(s)",
+            effect.to_name(),
+            targets.len()
+        )),
+        false,
+    );
+}
+
+/// Usage: /effect clear <targets> [effect]
+#[command("effect clear")]
+fn effect_clear(
+    #[sender] sender: Sender,
+    #[arg] targets: EntityArgument,
+    #[arg] effect: Option<Effect>,
+    args: (Query<TargetQuery>, MessageWriter<RemoveEffect>),
+) {
+    let (query, mut effect_msgs) = args;
+    let targets = players(targets, &query);
+    if targets.is_empty() {
+        sender.send_message(TextComponent::from("No player was found"), false);
+        return;
+    }
+
+    for &entity in &targets {
+        effect_msgs.write(RemoveEffect { entity, effect });
+    }
+    let message = match effect {
+        Some(effect) => format!(
+            "Removed effect {} from {} target(s)",
+            effect.to_name(),
+            targets.len()
+        ),
+        None => format!("Removed every effect from {} target(s)", targets.len()),
+    };
+    sender.send_message(TextComponent::from(message), false);
+}
@@ -16,7 +16,7 @@ fn fly_command(
 ) {
     // 1. Ensure the sender is a player
     let player_entity = match sender {
-        Sender::Server => {
+        Sender::Server | Sender::Rcon(_) => {
             sender.send_message("Error: The server can't fly.".into(), false);
             return;
         }
@@ -14,7 +14,7 @@ fn gamemode_command(
 ) {
     // 1. Ensure the sender is a player
     let player_entity = match sender {
-        Sender::Server => {
+        Sender::Server | Sender::Rcon(_) => {
             sender.send_message("Error: The server can't change gamemode.".into(), false);
             return;
         }
@@ -0,0 +1,70 @@
+use bevy_ecs::prelude::{Query, ResMut};
+use ferrumc_commands::arg::primitive::string::SingleWord;
+use ferrumc_commands::Sender;
+use ferrumc_core::game_rules::{GameRule, GameRuleValue, GameRules};
+use ferrumc_core::time::LastSentTimeUpdate;
+use ferrumc_macros::command;
+use ferrumc_text::TextComponent;
+
+fn reply(sender: &Sender, message: String) {
+    sender.send_message(TextComponent::from(message), false);
+}
+
+/// Shows a game rule, or changes it if a value is given.
+///
+/// Usage: /gamerule <rule> [value]
+#[command("gamerule")]
+fn gamerule(
+    #[sender] sender: Sender,
+    #[arg] rule: SingleWord,
+    #[arg] value: Option<SingleWord>,
+    args: (ResMut<GameRules>, Query<&mut LastSentTimeUpdate>),
+) {
+    let (mut game_rules, mut last_sent_time) = args;
+
+    let Some(rule) = GameRule::from_name(&rule) else {
+        reply(&sender, format!("Unknown game rule '{}'", rule.as_str()));
+        return;
+    };
+
+    let Some(value) = value else {
+        reply(
+            &sender,
+            format!(
+                "Gamerule {} is currently set to: {}",
+                rule.name(),
+                game_rules.get(rule)
+            ),
+        );
+        return;
+    };
+
+    let Some(parsed) = rule.parse(&value) else {
+        reply(
+            &sender,
+            format!(
+                "Invalid value '{}' for {}, expected a {}",
+                value.as_str(),
+                rule.name(),
+                match rule.default_value() {
+                    GameRuleValue::Bool(_) => "true or false",
+                    GameRuleValue::Int(_) => "whole number",
+                }
+            ),
+        );
+        return;
+    };
+    game_rules.set(rule, parsed);
+
+    // Clients only stop the clock once they're told to
+    if rule == GameRule::DoDaylightCycle {
+        for mut last_sent in last_sent_time.iter_mut() {
+            last_sent.send_next_tick();
+        }
+    }
+
+    reply(
+        &sender,
+        format!("Gamerule {} is now set to: {}", rule.name(), parsed),
+    );
+}
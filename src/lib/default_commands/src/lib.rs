@@ -1,12 +1,24 @@
+mod bossbar;
 pub mod echo;
+mod effect;
 pub mod fly;
 pub mod gamemode;
+mod gamerule;
 mod kill;
 pub mod nested;
+mod pregen;
+mod scoreboard;
+mod sound;
 pub mod spawn;
+mod team;
+mod tellraw;
 pub mod time;
+mod title;
 mod tp;
 pub mod tps;
+mod weather;
+mod worldborder;
+mod xp;
 
 /// Static library initialisation shenanigans.
 pub fn init() {}
@@ -0,0 +1,96 @@
+use bevy_ecs::prelude::{MessageWriter, Query};
+use ferrumc_commands::arg::primitive::int::Integer;
+use ferrumc_commands::Sender;
+use ferrumc_core::transform::position::Position;
+use ferrumc_macros::command;
+use ferrumc_messages::pregen::{PregenAction, PregenCommand};
+
+type PregenRadius = Integer<1, 2000>;
+
+/// The chunk the sender is standing in, or the origin for the console.
+fn center(sender: Sender, query: &Query<&Position>) -> (i32, i32) {
+    match sender {
+        Sender::Player(entity) => query
+            .get(entity)
+            .map(|pos| {
+                (
+                    pos.coords.x.floor() as i32 >> 4,
+                    pos.coords.z.floor() as i32 >> 4,
+                )
+            })
+            .unwrap_or_default(),
+        Sender::Server | Sender::Rcon(_) => (0, 0),
+    }
+}
+
+/// Pregenerates a square of chunks around the sender.
+///
+/// Usage: /pregen square <radius>
+#[command("pregen square")]
+fn pregen_square(
+    #[sender] sender: Sender,
+    #[arg] radius: PregenRadius,
+    args: (Query<&Position>, MessageWriter<PregenCommand>),
+) {
+    let (query, mut pregen_msgs) = args;
+    pregen_msgs.write(PregenCommand {
+        action: PregenAction::Start {
+            center: center(sender, &query),
+            radius: *radius as u32,
+            circle: false,
+        },
+        sender,
+    });
+}
+
+/// Pregenerates a circle of chunks around the sender.
+///
+/// Usage: /pregen circle <radius>
+#[command("pregen circle")]
+fn pregen_circle(
+    #[sender] sender: Sender,
+    #[arg] radius: PregenRadius,
+    args: (Query<&Position>, MessageWriter<PregenCommand>),
+) {
+    let (query, mut pregen_msgs) = args;
+    pregen_msgs.write(PregenCommand {
+        action: PregenAction::Start {
+            center: center(sender, &query),
+            radius: *radius as u32,
+            circle: true,
+        },
+        sender,
+    });
+}
+
+#[command("pregen pause")]
+fn pregen_pause(#[sender] sender: Sender, mut pregen_msgs: MessageWriter<PregenCommand>) {
+    pregen_msgs.write(PregenCommand {
+        action: PregenAction::Pause,
+        sender,
+    });
+}
+
+#[command("pregen resume")]
+fn pregen_resume(#[sender] sender: Sender, mut pregen_msgs: MessageWriter<PregenCommand>) {
+    pregen_msgs.write(PregenCommand {
+        action: PregenAction::Resume,
+        sender,
+    });
+}
+
+#[command("pregen cancel")]
+fn pregen_cancel(#[sender] sender: Sender, mut pregen_msgs: MessageWriter<PregenCommand>) {
+    pregen_msgs.write(PregenCommand {
+        action: PregenAction::Cancel,
+        sender,
+    });
+}
+
+#[command("pregen status")]
+fn pregen_status(#[sender] sender: Sender, mut pregen_msgs: MessageWriter<PregenCommand>) {
+    pregen_msgs.write(PregenCommand {
+        action: PregenAction::Status,
+        sender,
+    });
+}
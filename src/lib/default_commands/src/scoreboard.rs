@@ -0,0 +1,457 @@
+use bevy_ecs::prelude::{Entity, Query, Res, ResMut};
+use ferrumc_commands::arg::entities::EntityArgument;
+use ferrumc_commands::arg::primitive::int::Integer;
+use ferrumc_commands::arg::primitive::string::{GreedyString, SingleWord};
+use ferrumc_commands::Sender;
+use ferrumc_core::identity::entity_identity::EntityIdentity;
+use ferrumc_core::identity::player_identity::PlayerIdentity;
+use ferrumc_core::scoreboard::{
+    holder_name, DisplaySlot, Objective, ObjectiveCriteria, RenderType, Scoreboard,
+};
+use ferrumc_macros::command;
+use ferrumc_text::TextComponent;
+
+pub(crate) type HolderQuery<'a> = (
+    Entity,
+    Option<&'a EntityIdentity>,
+    Option<&'a PlayerIdentity>,
+);
+
+fn reply(sender: &Sender, message: String) {
+    sender.send_message(TextComponent::from(message), false);
+}
+
+/// The score holders `targets` stands for, with `*` standing for every holder on the
+/// scoreboard. Names don't have to belong to a player that's online, so scores can be kept for
+/// made up names too, like the lines of a sidebar.
+pub(crate) fn score_holders(
+    targets: &EntityArgument,
+    query: &Query<HolderQuery>,
+    scoreboard: &Scoreboard,
+) -> Vec<String> {
+    let holders: Vec<String> = targets
+        .resolve(query.iter())
+        .into_iter()
+        .filter_map(|entity| query.get(entity).ok())
+        .filter_map(|(_, identity, player)| holder_name(identity, player))
+        .collect();
+    match targets {
+        EntityArgument::PlayerName(name) if holders.is_empty() => {
+            if name == "*" {
+                scoreboard.holders().map(str::to_string).collect()
+            } else {
+                vec![name.clone()]
+            }
+        }
+        _ => holders,
+    }
+}
+
+/// Describes who a command was run on, by name if it was only one of them.
+pub(crate) fn describe(holders: &[String]) -> String {
+    match holders {
+        [holder] => holder.clone(),
+        _ => format!("{} entities", holders.len()),
+    }
+}
+
+/// Looks up an objective by name, telling the sender if it doesn't exist.
+fn objective<'a>(sender: &Sender, scoreboard: &'a Scoreboard, name: &str) -> Option<&'a Objective> {
+    let objective = scoreboard.objective(name);
+    if objective.is_none() {
+        reply(sender, format!("Unknown scoreboard objective '{name}'"));
+    }
+    objective
+}
+
+/// Usage: /scoreboard objectives list
+#[command("scoreboard objectives list")]
+fn objectives_list(#[sender] sender: Sender, scoreboard: Res<Scoreboard>) {
+    let names: Vec<String> = scoreboard
+        .objectives()
+        .map(|(_, objective)| format!("[{}]", objective.display_name))
+        .collect();
+    if names.is_empty() {
+        reply(&sender, "There are no objectives".to_string());
+    } else {
+        reply(
+            &sender,
+            format!(
+                "There are {} objective(s): {}",
+                names.len(),
+                names.join(", ")
+            ),
+        );
+    }
+}
+
+/// Usage: /scoreboard objectives add <objective> <criteria> [displayName]
+#[command("scoreboard objectives add")]
+fn objectives_add(
+    #[sender] sender: Sender,
+    #[arg] name: SingleWord,
+    #[arg] criteria: ObjectiveCriteria,
+    #[arg] display_name: Option<GreedyString>,
+    mut scoreboard: ResMut<Scoreboard>,
+) {
+    let display_name = display_name
+        .map(|display_name| display_name.to_string())
+        .unwrap_or_else(|| name.to_string());
+    let added = scoreboard.add_objective(
+        &name,
+        Objective {
+            criteria,
+            display_name: display_name.clone(),
+            render_type: criteria.default_render_type(),
+        },
+    );
+    if added {
+        reply(&sender, format!("Created new objective [{display_name}]"));
+    } else {
+        reply(
+            &sender,
+            "An objective already exists by that name".to_string(),
+        );
+    }
+}
+
+/// Usage: /scoreboard objectives remove <objective>
+#[command("scoreboard objectives remove")]
+fn objectives_remove(
+    #[sender] sender: Sender,
+    #[arg] name: SingleWord,
+    mut scoreboard: ResMut<Scoreboard>,
+) {
+    let Some(display_name) =
+        objective(&sender, &scoreboard, &name).map(|objective| objective.display_name.clone())
+    else {
+        return;
+    };
+    scoreboard.remove_objective(&name);
+    reply(&sender, format!("Removed objective [{display_name}]"));
+}
+
+/// Shows an objective in a display slot, or clears the slot if no objective is given.
+///
+/// Usage: /scoreboard objectives setdisplay <slot> [objective]
+#[command("scoreboard objectives setdisplay")]
+fn objectives_setdisplay(
+    #[sender] sender: Sender,
+    #[arg] slot: DisplaySlot,
+    #[arg] name: Option<SingleWord>,
+    mut scoreboard: ResMut<Scoreboard>,
+) {
+    let Some(name) = name else {
+        scoreboard.set_display(slot, None);
+        reply(
+            &sender,
+            format!("Cleared objective display slot {}", slot.name()),
+        );
+        return;
+    };
+    if objective(&sender, &scoreboard, &name).is_none() {
+        return;
+    }
+    scoreboard.set_display(slot, Some(&name));
+    reply(
+        &sender,
+        format!(
+            "Set display slot {} to show objective {}",
+            slot.name(),
+            name.as_str()
+        ),
+    );
+}
+
+/// Usage: /scoreboard objectives modify <objective> (displayname|rendertype) <value>
+#[command("scoreboard objectives modify")]
+fn objectives_modify(
+    #[sender] sender: Sender,
+    #[arg] name: SingleWord,
+    #[arg] option: SingleWord,
+    #[arg] value: GreedyString,
+    mut scoreboard: ResMut<Scoreboard>,
+) {
+    if objective(&sender, &scoreboard, &name).is_none() {
+        return;
+    }
+    match option.as_str() {
+        "displayname" => {
+            scoreboard.update_objective(&name, |objective| {
+                objective.display_name = value.to_string();
+            });
+            reply(
+                &sender,
+                format!(
+                    "Changed the display name of {} to [{}]",
+                    name.as_str(),
+                    value.as_str()
+                ),
+            );
+        }
+        "rendertype" => {
+            let Some(render_type) = RenderType::from_name(value.trim()) else {
+                reply(
+                    &sender,
+                    format!(
+                        "Unknown render type '{}', expected integer or hearts",
+                        value.trim()
+                    ),
+                );
+                return;
+            };
+            scoreboard.update_objective(&name, |objective| {
+                objective.render_type = render_type;
+            });
+            reply(
+                &sender,
+                format!(
+                    "Changed the render type of objective {} to {}",
+                    name.as_str(),
+                    render_type.name()
+                ),
+            );
+        }
+        other => reply(
+            &sender,
+            format!("Unknown option '{other}', expected displayname or rendertype"),
+        ),
+    }
+}
+
+/// Lists every score holder, or the scores of one.
+///
+/// Usage: /scoreboard players list [target]
+#[command("scoreboard players list")]
+fn players_list(
+    #[sender] sender: Sender,
+    #[arg] target: Option<EntityArgument>,
+    args: (Query<HolderQuery>, Res<Scoreboard>),
+) {
+    let (query, scoreboard) = args;
+    let Some(target) = target else {
+        let holders: Vec<&str> = scoreboard.holders().collect();
+        if holders.is_empty() {
+            reply(&sender, "There are no tracked entities".to_string());
+        } else {
+            reply(
+                &sender,
+                format!(
+                    "There are {} tracked entities: {}",
+                    holders.len(),
+                    holders.join(", ")
+                ),
+            );
+        }
+        return;
+    };
+
+    for holder in score_holders(&target, &query, &scoreboard) {
+        let scores: Vec<String> = scoreboard
+            .scores_of(&holder)
+            .filter_map(|(name, score)| {
+                let objective = scoreboard.objective(name)?;
+                Some(format!("[{}]: {score}", objective.display_name))
+            })
+            .collect();
+        if scores.is_empty() {
+            reply(&sender, format!("{holder} has no scores to show"));
+        } else {
+            reply(
+                &sender,
+                format!(
+                    "{holder} has {} score(s): {}",
+                    scores.len(),
+                    scores.join(", ")
+                ),
+            );
+        }
+    }
+}
+
+/// Usage: /scoreboard players get <target> <objective>
+#[command("scoreboard players get")]
+fn players_get(
+    #[sender] sender: Sender,
+    #[arg] target: EntityArgument,
+    #[arg] name: SingleWord,
+    args: (Query<HolderQuery>, Res<Scoreboard>),
+) {
+    let (query, scoreboard) = args;
+    let Some(objective) = objective(&sender, &scoreboard, &name) else {
+        return;
+    };
+    let holders = score_holders(&target, &query, &scoreboard);
+    let [holder] = holders.as_slice() else {
+        reply(&sender, "Only one score holder is allowed".to_string());
+        return;
+    };
+    match scoreboard.score(holder, &name) {
+        Some(score) => reply(
+            &sender,
+            format!("{holder} has {score} [{}]", objective.display_name),
+        ),
+        None => reply(
+            &sender,
+            format!(
+                "Can't get value of {} for {holder}; none is set",
+                name.as_str()
+            ),
+        ),
+    }
+}
+
+/// How a score command changes the scores.
+#[derive(Clone, Copy)]
+enum Operation {
+    Set,
+    Add,
+}
+
+/// Sets or adds to the scores of `targets`, for `/scoreboard players set`, `add` and `remove`.
+fn change_scores(
+    sender: &Sender,
+    targets: &EntityArgument,
+    name: &str,
+    operation: Operation,
+    amount: i32,
+    query: &Query<HolderQuery>,
+    scoreboard: &mut Scoreboard,
+) {
+    let Some(objective) = objective(sender, scoreboard, name) else {
+        return;
+    };
+    if objective.criteria.read_only() {
+        reply(
+            sender,
+            format!("Objective {name} is read-only and can't be changed"),
+        );
+        return;
+    }
+    let display_name = objective.display_name.clone();
+    let holders = score_holders(targets, query, scoreboard);
+    if holders.is_empty() {
+        reply(sender, "No score holder was found".to_string());
+        return;
+    }
+
+    let mut last = amount;
+    for holder in &holders {
+        match operation {
+            Operation::Set => {
+                scoreboard.set_score(holder, name, amount);
+            }
+            Operation::Add => {
+                last = scoreboard.add_score(holder, name, amount).unwrap_or(last);
+            }
+        }
+    }
+    let message = match (operation, holders.as_slice()) {
+        (Operation::Add, [holder]) => {
+            format!("Changed [{display_name}] for {holder}, which is now {last}")
+        }
+        (Operation::Add, _) => format!("Changed [{display_name}] for {}", describe(&holders)),
+        (Operation::Set, _) => format!(
+            "Set [{display_name}] for {} to {amount}",
+            describe(&holders)
+        ),
+    };
+    reply(sender, message);
+}
+
+/// Usage: /scoreboard players set <targets> <objective> <score>
+#[command("scoreboard players set")]
+fn players_set(
+    #[sender] sender: Sender,
+    #[arg] targets: EntityArgument,
+    #[arg] name: SingleWord,
+    #[arg] score: Integer,
+    args: (Query<HolderQuery>, ResMut<Scoreboard>),
+) {
+    let (query, mut scoreboard) = args;
+    change_scores(
+        &sender,
+        &targets,
+        &name,
+        Operation::Set,
+        *score,
+        &query,
+        &mut scoreboard,
+    );
+}
+
+/// Usage: /scoreboard players add <targets> <objective> <score>
+#[command("scoreboard players add")]
+fn players_add(
+    #[sender] sender: Sender,
+    #[arg] targets: EntityArgument,
+    #[arg] name: SingleWord,
+    #[arg] score: Integer<0>,
+    args: (Query<HolderQuery>, ResMut<Scoreboard>),
+) {
+    let (query, mut scoreboard) = args;
+    change_scores(
+        &sender,
+        &targets,
+        &name,
+        Operation::Add,
+        *score,
+        &query,
+        &mut scoreboard,
+    );
+}
+
+/// Usage: /scoreboard players remove <targets> <objective> <score>
+#[command("scoreboard players remove")]
+fn players_remove(
+    #[sender] sender: Sender,
+    #[arg] targets: EntityArgument,
+    #[arg] name: SingleWord,
+    #[arg] score: Integer<0>,
+    args: (Query<HolderQuery>, ResMut<Scoreboard>),
+) {
+    let (query, mut scoreboard) = args;
+    change_scores(
+        &sender,
+        &targets,
+        &name,
+        Operation::Add,
+        -*score,
+        &query,
+        &mut scoreboard,
+    );
+}
+
+/// Resets the scores of `targets` in an objective, or all of their scores.
+///
+/// Usage: /scoreboard players reset <targets> [objective]
+#[command("scoreboard players reset")]
+fn players_reset(
+    #[sender] sender: Sender,
+    #[arg] targets: EntityArgument,
+    #[arg] name: Option<SingleWord>,
+    args: (Query<HolderQuery>, ResMut<Scoreboard>),
+) {
+    let (query, mut scoreboard) = args;
+    let display_name = match &name {
+        Some(name) => match objective(&sender, &scoreboard, name) {
+            Some(objective) => Some(objective.display_name.clone()),
+            None => return,
+        },
+        None => None,
+    };
+    let holders = score_holders(&targets, &query, &scoreboard);
+    if holders.is_empty() {
+        reply(&sender, "No score holder was found".to_string());
+        return;
+    }
+
+    for holder in &holders {
+        scoreboard.reset_score(holder, name.as_deref().map(String::as_str));
+    }
+    let message = match display_name {
+        Some(display_name) => format!("Reset [{display_name}] for {}", describe(&holders)),
+        None => format!("Reset all scores for {}", describe(&holders)),
+    };
+    reply(&sender, message);
+}
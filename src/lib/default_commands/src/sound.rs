@@ -0,0 +1,145 @@
+use bevy_ecs::prelude::{Entity, MessageWriter, Query};
+use ferrumc_commands::arg::entities::EntityArgument;
+use ferrumc_commands::arg::primitive::float::Float;
+use ferrumc_commands::Sender;
+use ferrumc_core::identity::entity_identity::EntityIdentity;
+use ferrumc_core::identity::player_identity::PlayerIdentity;
+use ferrumc_core::sound::SoundCategory;
+use ferrumc_core::transform::position::Position;
+use ferrumc_data::generated::sounds::Sound;
+use ferrumc_macros::command;
+use ferrumc_messages::PlaySound;
+use ferrumc_net::connection::StreamWriter;
+use ferrumc_net::packets::outgoing::stop_sound::StopSoundPacket;
+use ferrumc_text::TextComponent;
+use tracing::error;
+
+type TargetQuery<'a> = (
+    Entity,
+    Option<&'a EntityIdentity>,
+    Option<&'a PlayerIdentity>,
+    &'a Position,
+);
+
+/// Only players can hear sounds.
+fn players(targets: EntityArgument, query: &Query<TargetQuery>) -> Vec<Entity> {
+    targets
+        .resolve(
+            query
+                .iter()
+                .map(|(entity, identity, player, _)| (entity, identity, player)),
+        )
+        .into_iter()
+        .filter(|entity| matches!(query.get(*entity), Ok((_, _, Some(_), _))))
+        .collect()
+}
+
+/// Usage: /playsound <sound> <source> <targets> [volume] [pitch]
+///
+/// Players hear the sound from where the sender is standing, or from where they are themselves
+/// when it's run from the console.
+#[command("playsound")]
+fn playsound(
+    #[sender] sender: Sender,
+    #[arg] sound: Sound,
+    #[arg] source: SoundCategory,
+    #[arg] targets: EntityArgument,
+    #[arg] volume: Option<Float>,
+    #[arg] pitch: Option<Float>,
+    args: (Query<TargetQuery>, MessageWriter<PlaySound>),
+) {
+    let (query, mut sounds) = args;
+    let targets = players(targets, &query);
+    if targets.is_empty() {
+        sender.send_message(TextComponent::from("No player was found"), false);
+        return;
+    }
+
+    let volume = volume.map(|volume| *volume).unwrap_or(1.0);
+    let pitch = pitch.map(|pitch| *pitch).unwrap_or(1.0);
+    if volume < 0.0 {
+        sender.send_message(TextComponent::from("Volume can't be negative"), false);
+        return;
+    }
+    if !(0.0..=2.0).contains(&pitch) {
+        sender.send_message(TextComponent::from("Pitch must be between 0 and 2"), false);
+        return;
+    }
+
+    let origin = match sender {
+        Sender::Player(entity) => query.get(entity).ok().map(|(.., pos)| pos.coords),
+        Sender::Server | Sender::Rcon(_) => None,
+    };
+    let play = |position, listeners| {
+        PlaySound::at(sound, source, position)
+            .with_volume(volume)
+            .with_pitch(pitch)
+            .only(listeners)
+    };
+    match origin {
+        Some(origin) => {
+            sounds.write(play(origin, targets.clone()));
+        }
+        None => {
+            for &target in &targets {
+                if let Ok((.., pos)) = query.get(target) {
+                    sounds.write(play(pos.coords, vec![target]));
+                }
+            }
+        }
+    }
+
+    sender.send_message(
+        TextComponent::from(format!(
+            "Played sound {} to {} target(s)",
+            sound.to_name(),
+            targets.len()
+        )),
+        false,
+    );
+}
+
+/// Usage: /stopsound <targets> [source] [sound]
+#[command("stopsound")]
+fn stopsound(
+    #[sender] sender: Sender,
+    #[arg] targets: EntityArgument,
+    #[arg] source: Option<SoundCategory>,
+    #[arg] sound: Option<Sound>,
+    args: (Query<TargetQuery>, Query<(&PlayerIdentity, &StreamWriter)>),
+) {
+    let (query, writers) = args;
+    let targets = players(targets, &query);
+    if targets.is_empty() {
+        sender.send_message(TextComponent::from("No player was found"), false);
+        return;
+    }
+
+    let packet = StopSoundPacket::new(
+        source,
+        sound.map(|sound| format!("minecraft:{}", sound.to_name())),
+    );
+    for &target in &targets {
+        let Ok((identity, writer)) = writers.get(target) else {
+            continue;
+        };
+        if let Err(err) = writer.send_packet_ref(&packet) {
+            error!("Failed to stop sounds for {}: {:?}", identity.username, err);
+        }
+    }
+
+    let message = match (sound, source) {
+        (Some(sound), _) => format!(
+            "Stopped sound {} for {} target(s)",
+            sound.to_name(),
+            targets.len()
+        ),
+        (None, Some(source)) => format!(
+            "Stopped every {} sound for {} target(s)",
+            source.name(),
+            targets.len()
+        ),
+        (None, None) => format!("Stopped every sound for {} target(s)", targets.len()),
+    };
+    sender.send_message(TextComponent::from(message), false);
+}
@@ -163,7 +163,7 @@ fn spawn_command(
                 false,
             );
         }
-        Sender::Server => {
+        Sender::Server | Sender::Rcon(_) => {
             sender.send_message(
                 TextComponent::from("Only players can use this command"),
                 false,
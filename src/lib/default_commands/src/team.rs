@@ -0,0 +1,271 @@
+use crate::scoreboard::{describe, score_holders, HolderQuery};
+use bevy_ecs::prelude::{Query, Res, ResMut};
+use ferrumc_commands::arg::entities::EntityArgument;
+use ferrumc_commands::arg::primitive::string::{GreedyString, SingleWord};
+use ferrumc_commands::Sender;
+use ferrumc_core::scoreboard::{
+    holder_name, CollisionRule, NameTagVisibility, Scoreboard, Team, TeamColor,
+};
+use ferrumc_macros::command;
+use ferrumc_text::TextComponent;
+
+fn reply(sender: &Sender, message: String) {
+    sender.send_message(TextComponent::from(message), false);
+}
+
+/// Tells the sender if there's no team with the name.
+fn team_exists(sender: &Sender, scoreboard: &Scoreboard, name: &str) -> bool {
+    let exists = scoreboard.team(name).is_some();
+    if !exists {
+        reply(sender, format!("Unknown team '{name}'"));
+    }
+    exists
+}
+
+/// Lists every team, or the members of one.
+///
+/// Usage: /team list [team]
+#[command("team list")]
+fn team_list(
+    #[sender] sender: Sender,
+    #[arg] name: Option<SingleWord>,
+    scoreboard: Res<Scoreboard>,
+) {
+    let Some(name) = name else {
+        let teams: Vec<String> = scoreboard
+            .teams()
+            .map(|(_, team)| format!("[{}]", team.display_name))
+            .collect();
+        if teams.is_empty() {
+            reply(&sender, "There are no teams".to_string());
+        } else {
+            reply(
+                &sender,
+                format!("There are {} team(s): {}", teams.len(), teams.join(", ")),
+            );
+        }
+        return;
+    };
+
+    if !team_exists(&sender, &scoreboard, &name) {
+        return;
+    }
+    let team = scoreboard.team(&name).expect("checked above");
+    let members: Vec<&str> = team.members().collect();
+    if members.is_empty() {
+        reply(
+            &sender,
+            format!("There are no members on team [{}]", team.display_name),
+        );
+    } else {
+        reply(
+            &sender,
+            format!(
+                "Team [{}] has {} member(s): {}",
+                team.display_name,
+                members.len(),
+                members.join(", ")
+            ),
+        );
+    }
+}
+
+/// Usage: /team add <team> [displayName]
+#[command("team add")]
+fn team_add(
+    #[sender] sender: Sender,
+    #[arg] name: SingleWord,
+    #[arg] display_name: Option<GreedyString>,
+    mut scoreboard: ResMut<Scoreboard>,
+) {
+    let display_name = display_name
+        .map(|display_name| display_name.to_string())
+        .unwrap_or_else(|| name.to_string());
+    if scoreboard.add_team(&name, Team::new(display_name.clone())) {
+        reply(&sender, format!("Created team [{display_name}]"));
+    } else {
+        reply(&sender, "A team already exists by that name".to_string());
+    }
+}
+
+/// Usage: /team remove <team>
+#[command("team remove")]
+fn team_remove(
+    #[sender] sender: Sender,
+    #[arg] name: SingleWord,
+    mut scoreboard: ResMut<Scoreboard>,
+) {
+    if !team_exists(&sender, &scoreboard, &name) {
+        return;
+    }
+    let display_name = scoreboard
+        .team(&name)
+        .map(|team| team.display_name.clone())
+        .unwrap_or_default();
+    scoreboard.remove_team(&name);
+    reply(&sender, format!("Removed team [{display_name}]"));
+}
+
+/// Takes everyone off a team.
+///
+/// Usage: /team empty <team>
+#[command("team empty")]
+fn team_empty(
+    #[sender] sender: Sender,
+    #[arg] name: SingleWord,
+    mut scoreboard: ResMut<Scoreboard>,
+) {
+    match scoreboard.empty_team(&name) {
+        Some(0) => reply(
+            &sender,
+            "Nothing changed. That team is already empty".to_string(),
+        ),
+        Some(count) => reply(
+            &sender,
+            format!("Removed {count} member(s) from team {}", name.as_str()),
+        ),
+        None => reply(&sender, format!("Unknown team '{}'", name.as_str())),
+    }
+}
+
+/// Puts score holders on a team, or the sender if none are given.
+///
+/// Usage: /team join <team> [members]
+#[command("team join")]
+fn team_join(
+    #[sender] sender: Sender,
+    #[arg] name: SingleWord,
+    #[arg] members: Option<EntityArgument>,
+    args: (Query<HolderQuery>, ResMut<Scoreboard>),
+) {
+    let (query, mut scoreboard) = args;
+    if !team_exists(&sender, &scoreboard, &name) {
+        return;
+    }
+    let holders = match (members, &sender) {
+        (Some(members), _) => score_holders(&members, &query, &scoreboard),
+        (None, Sender::Player(entity)) => query
+            .get(*entity)
+            .ok()
+            .and_then(|(_, identity, player)| holder_name(identity, player))
+            .into_iter()
+            .collect(),
+        (None, Sender::Server | Sender::Rcon(_)) => {
+            reply(
+                &sender,
+                "Only players can join a team without naming who joins".to_string(),
+            );
+            return;
+        }
+    };
+    if holders.is_empty() {
+        reply(&sender, "No score holder was found".to_string());
+        return;
+    }
+
+    scoreboard.join_team(&name, &holders);
+    reply(
+        &sender,
+        format!("Added {} to team {}", describe(&holders), name.as_str()),
+    );
+}
+
+/// Usage: /team leave <members>
+#[command("team leave")]
+fn team_leave(
+    #[sender] sender: Sender,
+    #[arg] members: EntityArgument,
+    args: (Query<HolderQuery>, ResMut<Scoreboard>),
+) {
+    let (query, mut scoreboard) = args;
+    let holders = score_holders(&members, &query, &scoreboard);
+    if scoreboard.leave_team(&holders) == 0 {
+        reply(&sender, "None of them are on a team".to_string());
+        return;
+    }
+    reply(
+        &sender,
+        format!("Removed {} from any team", describe(&holders)),
+    );
+}
+
+/// Parses an on or off option value.
+fn parse_bool(value: &str) -> Option<bool> {
+    match value {
+        "true" => Some(true),
+        "false" => Some(false),
+        _ => None,
+    }
+}
+
+/// Usage: /team modify <team> <option> <value>
+///
+/// The options are displayName, prefix, suffix, color, friendlyFire, seeFriendlyInvisibles,
+/// nametagVisibility and collisionRule.
+#[command("team modify")]
+fn team_modify(
+    #[sender] sender: Sender,
+    #[arg] name: SingleWord,
+    #[arg] option: SingleWord,
+    #[arg] value: GreedyString,
+    mut scoreboard: ResMut<Scoreboard>,
+) {
+    if !team_exists(&sender, &scoreboard, &name) {
+        return;
+    }
+
+    let text = value.to_string();
+    let word = value.trim();
+    let invalid = |expected: &str| {
+        reply(
+            &sender,
+            format!(
+                "Invalid value '{word}' for {}, expected {expected}",
+                option.as_str()
+            ),
+        );
+    };
+    match option.as_str() {
+        "displayName" => scoreboard.update_team(&name, |team| team.display_name = text),
+        "prefix" => scoreboard.update_team(&name, |team| team.prefix = text),
+        "suffix" => scoreboard.update_team(&name, |team| team.suffix = text),
+        "color" => {
+            let Some(color) = TeamColor::from_name(word) else {
+                return invalid("a colour like red or reset");
+            };
+            scoreboard.update_team(&name, |team| team.color = color)
+        }
+        "friendlyFire" => {
+            let Some(on) = parse_bool(word) else {
+                return invalid("true or false");
+            };
+            scoreboard.update_team(&name, |team| team.friendly_fire = on)
+        }
+        "seeFriendlyInvisibles" => {
+            let Some(on) = parse_bool(word) else {
+                return invalid("true or false");
+            };
+            scoreboard.update_team(&name, |team| team.see_friendly_invisibles = on)
+        }
+        "nametagVisibility" => {
+            let Some(visibility) = NameTagVisibility::from_name(word) else {
+                return invalid("always, never, hideForOtherTeams or hideForOwnTeam");
+            };
+            scoreboard.update_team(&name, |team| team.name_tag_visibility = visibility)
+        }
+        "collisionRule" => {
+            let Some(rule) = CollisionRule::from_name(word) else {
+                return invalid("always, never, pushOtherTeams or pushOwnTeam");
+            };
+            scoreboard.update_team(&name, |team| team.collision_rule = rule)
+        }
+        other => {
+            reply(&sender, format!("Unknown team option '{other}'"));
+            return;
+        }
+    };
+    reply(
+        &sender,
+        format!("Updated {} of team {}", option.as_str(), name.as_str()),
+    );
+}
@@ -0,0 +1,27 @@
+use crate::title::{players, PlayerQuery};
+use bevy_ecs::prelude::Query;
+use ferrumc_commands::arg::entities::EntityArgument;
+use ferrumc_commands::Sender;
+use ferrumc_core::mq;
+use ferrumc_macros::command;
+use ferrumc_text::TextComponent;
+
+/// Sends a chat message to players, given as JSON like `{"text":"Hello","color":"gold"}`.
+///
+/// Usage: /tellraw <targets> <message>
+#[command("tellraw")]
+fn tellraw(
+    #[sender] sender: Sender,
+    #[arg] targets: EntityArgument,
+    #[arg] message: TextComponent,
+    query: Query<PlayerQuery>,
+) {
+    let players = players(&targets, &query);
+    if players.is_empty() {
+        sender.send_message(TextComponent::from("No player was found"), false);
+        return;
+    }
+    for player in players {
+        mq::queue(message.clone(), false, player);
+    }
+}
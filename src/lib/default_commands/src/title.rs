@@ -0,0 +1,117 @@
+use bevy_ecs::prelude::{Entity, MessageWriter, Query};
+use ferrumc_commands::arg::entities::EntityArgument;
+use ferrumc_commands::arg::primitive::string::{GreedyString, SingleWord};
+use ferrumc_commands::Sender;
+use ferrumc_core::identity::entity_identity::EntityIdentity;
+use ferrumc_core::identity::player_identity::PlayerIdentity;
+use ferrumc_macros::command;
+use ferrumc_messages::{SendTitle, TitleAction, TitleTimes};
+use ferrumc_text::TextComponent;
+
+pub(crate) type PlayerQuery<'a> = (
+    Entity,
+    Option<&'a EntityIdentity>,
+    Option<&'a PlayerIdentity>,
+);
+
+fn reply(sender: &Sender, message: String) {
+    sender.send_message(TextComponent::from(message), false);
+}
+
+/// Only players can see titles, boss bars and chat.
+pub(crate) fn players(targets: &EntityArgument, query: &Query<PlayerQuery>) -> Vec<Entity> {
+    targets
+        .resolve(query.iter())
+        .into_iter()
+        .filter(|entity| matches!(query.get(*entity), Ok((_, _, Some(_)))))
+        .collect()
+}
+
+/// Parses a time in ticks, or in seconds or days with an `s` or `d` after it.
+fn parse_ticks(value: &str) -> Option<i32> {
+    let (number, scale) = match value.char_indices().last()? {
+        (at, 's') => (&value[..at], 20.0),
+        (at, 'd') => (&value[..at], 24000.0),
+        (at, 't') => (&value[..at], 1.0),
+        _ => (value, 1.0),
+    };
+    let ticks = (number.parse::<f32>().ok()? * scale).round();
+    (0.0..=i32::MAX as f32)
+        .contains(&ticks)
+        .then_some(ticks as i32)
+}
+
+/// Works out what to do from the action and what comes after it.
+fn parse_action(action: &str, value: Option<&str>) -> Result<TitleAction, String> {
+    let text = |value: Option<&str>| -> Result<TextComponent, String> {
+        let value = value.ok_or_else(|| format!("Missing the text to show for {action}"))?;
+        TextComponent::from_json(value).map_err(|err| format!("Invalid text component: {err}"))
+    };
+    match (action, value) {
+        ("clear", None) => Ok(TitleAction::Clear),
+        ("reset", None) => Ok(TitleAction::Reset),
+        ("clear" | "reset", Some(_)) => Err(format!("{action} doesn't take a value")),
+        ("title", value) => text(value).map(TitleAction::Title),
+        ("subtitle", value) => text(value).map(TitleAction::Subtitle),
+        ("actionbar", value) => text(value).map(TitleAction::ActionBar),
+        ("times", value) => {
+            let times: Vec<i32> = value
+                .unwrap_or_default()
+                .split_whitespace()
+                .map(parse_ticks)
+                .collect::<Option<_>>()
+                .ok_or_else(|| {
+                    "Times must be a number of ticks, like 20, 1s or 0.5d".to_string()
+                })?;
+            let [fade_in, stay, fade_out] = times[..] else {
+                return Err("Expected three times: fade in, stay and fade out".to_string());
+            };
+            Ok(TitleAction::Times(TitleTimes {
+                fade_in,
+                stay,
+                fade_out,
+            }))
+        }
+        (other, _) => Err(format!(
+            "Unknown title action '{other}', expected clear, reset, title, subtitle, actionbar or \
+             times"
+        )),
+    }
+}
+
+/// Usage: /title <targets> clear|reset
+///        /title <targets> title|subtitle|actionbar <text>
+///        /title <targets> times <fadeIn> <stay> <fadeOut>
+///
+/// Text is given as JSON, like `{"text":"Hello","color":"gold"}`.
+#[command("title")]
+fn title(
+    #[sender] sender: Sender,
+    #[arg] targets: EntityArgument,
+    #[arg] action: SingleWord,
+    #[arg] value: Option<GreedyString>,
+    args: (Query<PlayerQuery>, MessageWriter<SendTitle>),
+) {
+    let (query, mut titles) = args;
+    let action = match parse_action(&action, value.as_deref().map(String::as_str)) {
+        Ok(action) => action,
+        Err(message) => return reply(&sender, message),
+    };
+    let players = players(&targets, &query);
+    if players.is_empty() {
+        reply(&sender, "No player was found".to_string());
+        return;
+    }
+
+    let count = players.len();
+    let message = match &action {
+        TitleAction::Title(_) => format!("Showing new title for {count} player(s)"),
+        TitleAction::Subtitle(_) => format!("Changing subtitle for {count} player(s)"),
+        TitleAction::ActionBar(_) => format!("Showing new actionbar title for {count} player(s)"),
+        TitleAction::Times(_) => format!("Changing title times for {count} player(s)"),
+        TitleAction::Clear => format!("Cleared titles for {count} player(s)"),
+        TitleAction::Reset => format!("Reset title options for {count} player(s)"),
+    };
+    titles.write(SendTitle::new(players, action));
+    reply(&sender, message);
+}
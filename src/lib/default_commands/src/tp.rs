@@ -65,7 +65,7 @@ fn tp_to_command(
             false,
         );
         return;
-    } else if matches!(sender, Sender::Server) {
+    } else if !matches!(sender, Sender::Player(_)) {
         sender.send_message("This command can only be used by players.".into(), false);
         return;
     }
@@ -0,0 +1,56 @@
+use std::ops::Range;
+use std::time::Duration;
+
+use bevy_ecs::prelude::ResMut;
+use ferrumc_commands::Sender;
+use ferrumc_core::weather::{Weather, CLEAR_DURATION, RAIN_DURATION, THUNDER_DURATION};
+use ferrumc_macros::command;
+use ferrumc_text::TextComponent;
+use rand::Rng;
+
+/// Weather durations are counted in game ticks, like vanilla.
+const TICKS_PER_SECOND: u64 = 20;
+
+/// The duration in ticks, or a random one like vanilla picks if none was given.
+fn ticks(duration: Option<Duration>, default: Range<i32>) -> i32 {
+    match duration {
+        Some(duration) => (duration.as_secs() * TICKS_PER_SECOND).min(i32::MAX as u64) as i32,
+        None => rand::thread_rng().gen_range(default),
+    }
+}
+
+/// Usage: /weather clear [duration]
+#[command("weather clear")]
+fn weather_clear(
+    #[sender] sender: Sender,
+    #[arg] duration: Option<Duration>,
+    mut weather: ResMut<Weather>,
+) {
+    weather.set_clear(ticks(duration, CLEAR_DURATION));
+    sender.send_message(TextComponent::from("Set the weather to clear"), false);
+}
+
+/// Usage: /weather rain [duration]
+#[command("weather rain")]
+fn weather_rain(
+    #[sender] sender: Sender,
+    #[arg] duration: Option<Duration>,
+    mut weather: ResMut<Weather>,
+) {
+    weather.set_rain(ticks(duration, RAIN_DURATION));
+    sender.send_message(TextComponent::from("Set the weather to rain"), false);
+}
+
+/// Usage: /weather thunder [duration]
+#[command("weather thunder")]
+fn weather_thunder(
+    #[sender] sender: Sender,
+    #[arg] duration: Option<Duration>,
+    mut weather: ResMut<Weather>,
+) {
+    weather.set_thunder(ticks(duration, THUNDER_DURATION));
+    sender.send_message(
+        TextComponent::from("Set the weather to rain & thunder"),
+        false,
+    );
+}
@@ -0,0 +1,154 @@
+use std::time::Duration;
+
+use bevy_ecs::prelude::{Res, ResMut};
+use ferrumc_commands::arg::primitive::float::Float;
+use ferrumc_commands::Sender;
+use ferrumc_core::world_border::{WorldBorder, WorldBorders, MAX_DIAMETER};
+use ferrumc_macros::command;
+use ferrumc_text::TextComponent;
+use ferrumc_world::DEFAULT_DIMENSION;
+
+fn reply(sender: &Sender, message: String) {
+    sender.send_message(TextComponent::from(message), false);
+}
+
+/// Resizes the border, over `time` if given, and tells the sender about it.
+fn resize(sender: &Sender, border: &mut WorldBorder, diameter: f64, time: Option<Duration>) {
+    if !(1.0..=MAX_DIAMETER).contains(&diameter) {
+        reply(
+            sender,
+            format!("The world border must be between 1 and {MAX_DIAMETER} blocks wide"),
+        );
+        return;
+    }
+
+    match time {
+        Some(time) => {
+            border.lerp_to(diameter, time);
+            reply(
+                sender,
+                format!(
+                    "Resizing the world border to {diameter:.1} blocks wide over {} seconds",
+                    time.as_secs()
+                ),
+            );
+        }
+        None => {
+            border.set_diameter(diameter);
+            reply(
+                sender,
+                format!("Set the world border to {diameter:.1} blocks wide"),
+            );
+        }
+    }
+}
+
+/// Usage: /worldborder set <diameter> [time]
+#[command("worldborder set")]
+fn worldborder_set(
+    #[sender] sender: Sender,
+    #[arg] diameter: Float,
+    #[arg] time: Option<Duration>,
+    mut borders: ResMut<WorldBorders>,
+) {
+    resize(
+        &sender,
+        borders.get_mut(DEFAULT_DIMENSION),
+        *diameter as f64,
+        time,
+    );
+}
+
+/// Grows the border by `amount` blocks, or shrinks it if negative.
+///
+/// Usage: /worldborder add <amount> [time]
+#[command("worldborder add")]
+fn worldborder_add(
+    #[sender] sender: Sender,
+    #[arg] amount: Float,
+    #[arg] time: Option<Duration>,
+    mut borders: ResMut<WorldBorders>,
+) {
+    let border = borders.get_mut(DEFAULT_DIMENSION);
+    let diameter = border.target_diameter() + *amount as f64;
+    resize(&sender, border, diameter, time);
+}
+
+/// Usage: /worldborder center <x> <z>
+#[command("worldborder center")]
+fn worldborder_center(
+    #[sender] sender: Sender,
+    #[arg] x: Float,
+    #[arg] z: Float,
+    mut borders: ResMut<WorldBorders>,
+) {
+    borders
+        .get_mut(DEFAULT_DIMENSION)
+        .set_center(*x as f64, *z as f64);
+    reply(
+        &sender,
+        format!("Set the center of the world border to {:.1}, {:.1}", *x, *z),
+    );
+}
+
+/// Usage: /worldborder damage amount <damage per block>
+#[command("worldborder damage amount")]
+fn worldborder_damage_amount(
+    #[sender] sender: Sender,
+    #[arg] damage: Float,
+    mut borders: ResMut<WorldBorders>,
+) {
+    if *damage < 0.0 {
+        reply(&sender, "The damage can't be negative".to_string());
+        return;
+    }
+    borders.get_mut(DEFAULT_DIMENSION).damage_per_block = *damage as f64;
+    reply(
+        &sender,
+        format!(
+            "Set the world border damage to {:.2} per block each second",
+            *damage
+        ),
+    );
+}
+
+/// Usage: /worldborder damage buffer <distance>
+#[command("worldborder damage buffer")]
+fn worldborder_damage_buffer(
+    #[sender] sender: Sender,
+    #[arg] distance: Float,
+    mut borders: ResMut<WorldBorders>,
+) {
+    if *distance < 0.0 {
+        reply(&sender, "The buffer can't be negative".to_string());
+        return;
+    }
+    borders.get_mut(DEFAULT_DIMENSION).safe_zone = *distance as f64;
+    reply(
+        &sender,
+        format!(
+            "Set the world border damage buffer to {:.1} blocks",
+            *distance
+        ),
+    );
+}
+
+#[command("worldborder get")]
+fn worldborder_get(#[sender] sender: Sender, borders: Res<WorldBorders>) {
+    let border = borders.get(DEFAULT_DIMENSION);
+    let (x, z) = border.center();
+    let message = if border.is_moving() {
+        format!(
+            "The world border is {:.1} blocks wide around {x:.1}, {z:.1}, moving to {:.1} blocks over the next {} seconds",
+            border.diameter(),
+            border.target_diameter(),
+            border.remaining().as_secs()
+        )
+    } else {
+        format!(
+            "The world border is {:.1} blocks wide around {x:.1}, {z:.1}",
+            border.diameter()
+        )
+    };
+    reply(&sender, message);
+}
@@ -0,0 +1,199 @@
+use bevy_ecs::prelude::{Entity, Query};
+use ferrumc_commands::arg::entities::EntityArgument;
+use ferrumc_commands::arg::primitive::int::Integer;
+use ferrumc_commands::arg::primitive::string::SingleWord;
+use ferrumc_commands::Sender;
+use ferrumc_components::player::experience::Experience;
+use ferrumc_core::identity::entity_identity::EntityIdentity;
+use ferrumc_core::identity::player_identity::PlayerIdentity;
+use ferrumc_macros::command;
+use ferrumc_text::TextComponent;
+
+type TargetQuery<'a> = (
+    Entity,
+    Option<&'a EntityIdentity>,
+    Option<&'a PlayerIdentity>,
+);
+
+type SetAmount = Integer<0>;
+
+/// Whether an amount of XP is in points or levels.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Unit {
+    Points,
+    Levels,
+}
+
+impl Unit {
+    /// Parses the unit argument, which defaults to points like vanilla.
+    fn parse(sender: &Sender, unit: Option<SingleWord>) -> Option<Self> {
+        match unit.as_deref().map(String::as_str) {
+            None | Some("points") => Some(Self::Points),
+            Some("levels") => Some(Self::Levels),
+            Some(other) => {
+                reply(
+                    sender,
+                    format!("Unknown unit '{other}', expected points or levels"),
+                );
+                None
+            }
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Points => "points",
+            Self::Levels => "levels",
+        }
+    }
+}
+
+fn reply(sender: &Sender, message: String) {
+    sender.send_message(TextComponent::from(message), false);
+}
+
+/// Only players have XP. Returns them with their names.
+fn players(targets: EntityArgument, query: &Query<TargetQuery>) -> Vec<(Entity, String)> {
+    targets
+        .resolve(query.iter())
+        .into_iter()
+        .filter_map(|entity| match query.get(entity) {
+            Ok((_, _, Some(identity))) => Some((entity, identity.username.clone())),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Names the players a command changed: their name if there's only one.
+fn describe(targets: &[(Entity, String)]) -> String {
+    match targets {
+        [(_, name)] => name.clone(),
+        _ => format!("{} players", targets.len()),
+    }
+}
+
+/// Usage: /xp add <targets> <amount> [points|levels]
+#[command("xp add")]
+fn xp_add(
+    #[sender] sender: Sender,
+    #[arg] targets: EntityArgument,
+    #[arg] amount: Integer,
+    #[arg] unit: Option<SingleWord>,
+    args: (Query<TargetQuery>, Query<&mut Experience>),
+) {
+    let (query, mut experiences) = args;
+    let Some(unit) = Unit::parse(&sender, unit) else {
+        return;
+    };
+    let targets = players(targets, &query);
+    if targets.is_empty() {
+        reply(&sender, "No player was found".to_string());
+        return;
+    }
+
+    for (entity, _) in &targets {
+        let Ok(mut experience) = experiences.get_mut(*entity) else {
+            continue;
+        };
+        match unit {
+            Unit::Points => experience.add_points(*amount),
+            Unit::Levels => experience.add_levels(*amount),
+        }
+    }
+    reply(
+        &sender,
+        format!(
+            "Gave {} experience {} to {}",
+            *amount,
+            unit.name(),
+            describe(&targets)
+        ),
+    );
+}
+
+/// Usage: /xp set <targets> <amount> [points|levels]
+#[command("xp set")]
+fn xp_set(
+    #[sender] sender: Sender,
+    #[arg] targets: EntityArgument,
+    #[arg] amount: SetAmount,
+    #[arg] unit: Option<SingleWord>,
+    args: (Query<TargetQuery>, Query<&mut Experience>),
+) {
+    let (query, mut experiences) = args;
+    let Some(unit) = Unit::parse(&sender, unit) else {
+        return;
+    };
+    let targets = players(targets, &query);
+    if targets.is_empty() {
+        reply(&sender, "No player was found".to_string());
+        return;
+    }
+
+    let amount = *amount as u32;
+    let mut changed = Vec::new();
+    for (entity, name) in &targets {
+        let Ok(mut experience) = experiences.get_mut(*entity) else {
+            continue;
+        };
+        let set = match unit {
+            Unit::Points => experience.set_points(amount),
+            Unit::Levels => {
+                experience.level = amount;
+                true
+            }
+        };
+        if set {
+            changed.push((*entity, name.clone()));
+        }
+    }
+
+    if changed.is_empty() {
+        reply(
+            &sender,
+            "Can't set experience points above the maximum points for the player's current level"
+                .to_string(),
+        );
+        return;
+    }
+    reply(
+        &sender,
+        format!(
+            "Set {} experience {} on {}",
+            amount,
+            unit.name(),
+            describe(&changed)
+        ),
+    );
+}
+
+/// Usage: /xp query <target> [points|levels]
+#[command("xp query")]
+fn xp_query(
+    #[sender] sender: Sender,
+    #[arg] target: EntityArgument,
+    #[arg] unit: Option<SingleWord>,
+    args: (Query<TargetQuery>, Query<&Experience>),
+) {
+    let (query, experiences) = args;
+    let Some(unit) = Unit::parse(&sender, unit) else {
+        return;
+    };
+    let targets = players(target, &query);
+    let [(entity, name)] = targets.as_slice() else {
+        reply(&sender, "Expected a single player".to_string());
+        return;
+    };
+    let Ok(experience) = experiences.get(*entity) else {
+        return;
+    };
+
+    let amount = match unit {
+        Unit::Points => experience.points(),
+        Unit::Levels => experience.level,
+    };
+    reply(
+        &sender,
+        format!("{name} has {amount} experience {}", unit.name()),
+    );
+}
@@ -0,0 +1,38 @@
+use bevy_ecs::prelude::Bundle;
+use ferrumc_core::identity::entity_identity::EntityIdentity;
+use ferrumc_core::transform::{
+    grounded::OnGround, position::Position, rotation::Rotation, velocity::Velocity,
+};
+use ferrumc_data::generated::entities::EntityType as VanillaEntityType;
+
+use crate::components::{EntityMetadata, ExperienceOrb, LastSyncedPosition};
+
+/// Bundle for an experience orb.
+///
+/// Like items, orbs aren't mobs, so this has no combat or spawn properties.
+#[derive(Bundle)]
+pub struct ExperienceOrbBundle {
+    pub identity: EntityIdentity,
+    pub metadata: EntityMetadata,
+    pub orb: ExperienceOrb,
+    pub position: Position,
+    pub rotation: Rotation,
+    pub velocity: Velocity,
+    pub on_ground: OnGround,
+    pub last_synced_position: LastSyncedPosition,
+}
+
+impl ExperienceOrbBundle {
+    pub fn new(position: Position, velocity: Velocity, value: u32) -> Self {
+        Self {
+            identity: EntityIdentity::new(),
+            metadata: EntityMetadata::from_vanilla(&VanillaEntityType::EXPERIENCE_ORB),
+            orb: ExperienceOrb::new(value),
+            rotation: Rotation::default(),
+            velocity,
+            on_ground: OnGround(false),
+            last_synced_position: LastSyncedPosition::from_position(&position),
+            position,
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use bevy_ecs::prelude::Bundle;
+use ferrumc_core::identity::entity_identity::EntityIdentity;
+use ferrumc_core::transform::{
+    grounded::OnGround, position::Position, rotation::Rotation, velocity::Velocity,
+};
+use ferrumc_data::generated::entities::EntityType as VanillaEntityType;
+use ferrumc_inventories::slot::InventorySlot;
+
+use crate::components::{EntityMetadata, ItemEntity, LastSyncedPosition};
+
+/// Bundle for an item entity.
+///
+/// Items aren't mobs, so unlike the bundles made with `define_entity_bundle!` this has no
+/// combat or spawn properties.
+#[derive(Bundle)]
+pub struct ItemBundle {
+    pub identity: EntityIdentity,
+    pub metadata: EntityMetadata,
+    pub item: ItemEntity,
+    pub position: Position,
+    pub rotation: Rotation,
+    pub velocity: Velocity,
+    pub on_ground: OnGround,
+    pub last_synced_position: LastSyncedPosition,
+}
+
+impl ItemBundle {
+    pub fn new(
+        position: Position,
+        velocity: Velocity,
+        stack: InventorySlot,
+        pickup_delay: u32,
+    ) -> Self {
+        Self {
+            identity: EntityIdentity::new(),
+            metadata: EntityMetadata::from_vanilla(&VanillaEntityType::ITEM),
+            item: ItemEntity::new(stack, pickup_delay),
+            rotation: Rotation::default(),
+            velocity,
+            on_ground: OnGround(false),
+            last_synced_position: LastSyncedPosition::from_position(&position),
+            position,
+        }
+    }
+}
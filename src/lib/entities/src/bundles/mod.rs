@@ -1,14 +1,22 @@
 // Entity bundles for spawning in Bevy ECS
 // Organized by behavior category
 
+pub mod experience_orb;
 pub mod hostile;
+pub mod item;
 pub mod neutral;
 pub mod passive;
+pub mod primed_tnt;
+pub mod projectile;
 
 // Re-export all bundles for convenience
+pub use experience_orb::ExperienceOrbBundle;
 pub use hostile::*;
+pub use item::ItemBundle;
 pub use neutral::*;
 pub use passive::*;
+pub use primed_tnt::PrimedTntBundle;
+pub use projectile::ProjectileBundle;
 
 /// Macro to define an entity bundle with all standard components.
 ///
@@ -0,0 +1,36 @@
+use bevy_ecs::prelude::Bundle;
+use ferrumc_core::identity::entity_identity::EntityIdentity;
+use ferrumc_core::transform::{
+    grounded::OnGround, position::Position, rotation::Rotation, velocity::Velocity,
+};
+use ferrumc_data::generated::entities::EntityType as VanillaEntityType;
+
+use crate::components::{EntityMetadata, LastSyncedPosition, PrimedTnt};
+
+/// Bundle for a lit block of TNT.
+#[derive(Bundle)]
+pub struct PrimedTntBundle {
+    pub identity: EntityIdentity,
+    pub metadata: EntityMetadata,
+    pub tnt: PrimedTnt,
+    pub position: Position,
+    pub rotation: Rotation,
+    pub velocity: Velocity,
+    pub on_ground: OnGround,
+    pub last_synced_position: LastSyncedPosition,
+}
+
+impl PrimedTntBundle {
+    pub fn new(tnt: PrimedTnt, position: Position, velocity: Velocity) -> Self {
+        Self {
+            identity: EntityIdentity::new(),
+            metadata: EntityMetadata::from_vanilla(&VanillaEntityType::TNT),
+            tnt,
+            rotation: Rotation::default(),
+            velocity,
+            on_ground: OnGround(false),
+            last_synced_position: LastSyncedPosition::from_position(&position),
+            position,
+        }
+    }
+}
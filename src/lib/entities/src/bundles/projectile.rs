@@ -0,0 +1,43 @@
+use bevy_ecs::prelude::Bundle;
+use ferrumc_core::identity::entity_identity::EntityIdentity;
+use ferrumc_core::transform::{
+    grounded::OnGround, position::Position, rotation::Rotation, velocity::Velocity,
+};
+
+use crate::components::{EntityMetadata, LastSyncedPosition, Projectile};
+
+/// Bundle for an arrow, thrown item or trident.
+///
+/// Projectiles move and collide on their own, so they don't get the physics markers mobs and
+/// items have.
+#[derive(Bundle)]
+pub struct ProjectileBundle {
+    pub identity: EntityIdentity,
+    pub metadata: EntityMetadata,
+    pub projectile: Projectile,
+    pub position: Position,
+    pub rotation: Rotation,
+    pub velocity: Velocity,
+    pub on_ground: OnGround,
+    pub last_synced_position: LastSyncedPosition,
+}
+
+impl ProjectileBundle {
+    pub fn new(
+        projectile: Projectile,
+        position: Position,
+        rotation: Rotation,
+        velocity: Velocity,
+    ) -> Self {
+        Self {
+            identity: EntityIdentity::new(),
+            metadata: EntityMetadata::from_vanilla(projectile.kind.entity_type()),
+            projectile,
+            rotation,
+            velocity,
+            on_ground: OnGround(false),
+            last_synced_position: LastSyncedPosition::from_position(&position),
+            position,
+        }
+    }
+}
@@ -1 +1,130 @@
+//! Ray casts for fast-moving entities like projectiles, which can travel further than a block
+//! each tick and so have to check everything between where they are and where they're going.
 
+use bevy_math::{DVec3, IVec3};
+
+/// How far along the segment from `from` to `to` it first enters the box, from 0 at `from` to 1
+/// at `to`. Returns 0 if the segment starts inside the box and `None` if it misses it.
+pub fn segment_box_intersection(from: DVec3, to: DVec3, min: DVec3, max: DVec3) -> Option<f64> {
+    let direction = to - from;
+    let mut enter = 0.0f64;
+    let mut exit = 1.0f64;
+    for axis in 0..3 {
+        if direction[axis] == 0.0 {
+            if from[axis] < min[axis] || from[axis] > max[axis] {
+                return None;
+            }
+            continue;
+        }
+        let a = (min[axis] - from[axis]) / direction[axis];
+        let b = (max[axis] - from[axis]) / direction[axis];
+        enter = enter.max(a.min(b));
+        exit = exit.min(a.max(b));
+        if enter > exit {
+            return None;
+        }
+    }
+    Some(enter)
+}
+
+/// Finds the first block the segment from `from` to `to` hits, walking through the blocks it
+/// crosses in order. Blocks are treated as full cubes.
+///
+/// Returns the block and the point the segment enters it.
+pub fn raycast_blocks(
+    from: DVec3,
+    to: DVec3,
+    mut is_solid: impl FnMut(IVec3) -> bool,
+) -> Option<(IVec3, DVec3)> {
+    let direction = to - from;
+    let mut block = from.floor().as_ivec3();
+    let end = to.floor().as_ivec3();
+
+    let mut step = IVec3::ZERO;
+    let mut next = DVec3::INFINITY;
+    let mut delta = DVec3::INFINITY;
+    for axis in 0..3 {
+        if direction[axis] > 0.0 {
+            step[axis] = 1;
+            next[axis] = (block[axis] as f64 + 1.0 - from[axis]) / direction[axis];
+            delta[axis] = 1.0 / direction[axis];
+        } else if direction[axis] < 0.0 {
+            step[axis] = -1;
+            next[axis] = (from[axis] - block[axis] as f64) / -direction[axis];
+            delta[axis] = -1.0 / direction[axis];
+        }
+    }
+
+    // The segment can't cross more blocks than this, which also guards against NaNs
+    let crossings = (end - block).abs().element_sum();
+    for _ in 0..=crossings {
+        if is_solid(block) {
+            let min = block.as_dvec3();
+            let t = segment_box_intersection(from, to, min, min + DVec3::ONE).unwrap_or(0.0);
+            return Some((block, from + direction * t));
+        }
+        if block == end {
+            break;
+        }
+        let axis = if next.x < next.y && next.x < next.z {
+            0
+        } else if next.y < next.z {
+            1
+        } else {
+            2
+        };
+        block[axis] += step[axis];
+        next[axis] += delta[axis];
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_segment_box_intersection() {
+        let (min, max) = (DVec3::ZERO, DVec3::ONE);
+        let hit = segment_box_intersection(
+            DVec3::new(-1.0, 0.5, 0.5),
+            DVec3::new(1.0, 0.5, 0.5),
+            min,
+            max,
+        );
+        assert_eq!(hit, Some(0.5));
+        let inside = segment_box_intersection(DVec3::splat(0.5), DVec3::splat(2.0), min, max);
+        assert_eq!(inside, Some(0.0));
+        let short = segment_box_intersection(
+            DVec3::new(-2.0, 0.5, 0.5),
+            DVec3::new(-1.0, 0.5, 0.5),
+            min,
+            max,
+        );
+        assert_eq!(short, None);
+        let beside = segment_box_intersection(
+            DVec3::new(-1.0, 2.0, 0.5),
+            DVec3::new(1.0, 2.0, 0.5),
+            min,
+            max,
+        );
+        assert_eq!(beside, None);
+    }
+
+    #[test]
+    fn test_raycast_blocks() {
+        let wall = |block: IVec3| block.x == 3;
+        let (block, point) =
+            raycast_blocks(DVec3::new(0.5, 0.5, 0.5), DVec3::new(5.5, 1.0, 0.5), wall).unwrap();
+        assert_eq!(block, IVec3::new(3, 0, 0));
+        assert!((point.x - 3.0).abs() < 1e-9);
+        assert!((point.y - 0.75).abs() < 1e-9);
+
+        let short = raycast_blocks(DVec3::new(0.5, 0.5, 0.5), DVec3::new(2.5, 0.5, 0.5), wall);
+        assert_eq!(short, None);
+
+        let backwards = raycast_blocks(DVec3::new(5.5, 0.5, 0.5), DVec3::new(-1.0, 0.5, 0.5), wall);
+        assert_eq!(backwards.map(|(block, _)| block), Some(IVec3::new(3, 0, 0)));
+        assert!((backwards.unwrap().1.x - 4.0).abs() < 1e-9);
+    }
+}
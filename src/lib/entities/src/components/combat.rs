@@ -38,6 +38,10 @@ pub struct CombatProperties {
     /// This count is decremented every tick and the entity can't be damaged
     /// while (count > 0)
     pub invulnerability_ticks: u32,
+
+    /// Health left, starting at the entity's vanilla max health. The entity dies once it reaches
+    /// zero.
+    pub health: f32,
 }
 
 impl CombatProperties {
@@ -59,6 +63,7 @@ impl CombatProperties {
         Self {
             attackable: metadata.vanilla_data().attackable.unwrap_or(false),
             invulnerability_ticks: 0,
+            health: metadata.vanilla_data().max_health.unwrap_or(1.0),
         }
     }
 
@@ -67,12 +72,33 @@ impl CombatProperties {
         Self {
             attackable: data.attackable.unwrap_or(false),
             invulnerability_ticks: 0,
+            health: data.max_health.unwrap_or(1.0),
         }
     }
 
-    /// Return true if the entity can't be damaged.
+    /// Return true if the entity can be damaged.
     pub const fn can_be_damaged(&self) -> bool {
-        self.attackable && self.invulnerability_ticks == 0
+        self.attackable && self.invulnerability_ticks == 0 && !self.is_dead()
+    }
+
+    /// Return true once the entity has run out of health.
+    pub const fn is_dead(&self) -> bool {
+        self.health <= 0.0
+    }
+
+    /// Takes health from the entity, unless it can't be damaged, and makes it invulnerable for a
+    /// short time.
+    ///
+    /// # Return
+    ///
+    /// True if the entity took the damage.
+    pub fn hurt(&mut self, amount: f32) -> bool {
+        if !self.can_be_damaged() || amount <= 0.0 {
+            return false;
+        }
+        self.health = (self.health - amount).max(0.0);
+        self.set_default_invulnerability();
+        true
     }
 
     /// Activate invulnerability for a certain amount of ticks.
@@ -118,6 +144,7 @@ impl std::fmt::Debug for CombatProperties {
         f.debug_struct("CombatProperties")
             .field("attackable", &self.attackable)
             .field("invulnerability_ticks", &self.invulnerability_ticks)
+            .field("health", &self.health)
             .field("can_be_damaged", &self.can_be_damaged())
             .finish()
     }
@@ -0,0 +1,59 @@
+use bevy_ecs::prelude::Component;
+
+/// An experience orb lying in the world.
+///
+/// Orbs of the same value that touch merge into one, counting how many they stand for. Each time
+/// a player picks one up they get `value` XP and the count goes down by one.
+#[derive(Component, Debug, Clone)]
+pub struct ExperienceOrb {
+    /// The XP a player gets from each of the orbs.
+    pub value: u32,
+    /// How many orbs this entity stands for. Never zero.
+    pub count: u32,
+    /// Ticks since the orb was spawned.
+    pub age: u32,
+}
+
+impl ExperienceOrb {
+    /// Five minutes, the same as vanilla.
+    pub const DESPAWN_AGE: u32 = 6000;
+
+    /// The values orbs come in, biggest first. Larger amounts of XP are split into several orbs
+    /// of these values.
+    const SIZES: [u32; 11] = [2477, 1237, 617, 307, 149, 73, 37, 17, 7, 3, 1];
+
+    pub fn new(value: u32) -> Self {
+        Self {
+            value,
+            count: 1,
+            age: 0,
+        }
+    }
+
+    /// Splits an amount of XP into the values of the orbs that drop for it, like vanilla.
+    pub fn split(mut amount: u32) -> Vec<u32> {
+        let mut values = Vec::new();
+        while amount > 0 {
+            let value = Self::SIZES
+                .into_iter()
+                .find(|size| *size <= amount)
+                .unwrap_or(1);
+            values.push(value);
+            amount -= value;
+        }
+        values
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split() {
+        assert!(ExperienceOrb::split(0).is_empty());
+        assert_eq!(ExperienceOrb::split(5), vec![3, 1, 1]);
+        assert_eq!(ExperienceOrb::split(100), vec![73, 17, 7, 3]);
+        assert_eq!(ExperienceOrb::split(3000).iter().sum::<u32>(), 3000);
+    }
+}
@@ -0,0 +1,74 @@
+use bevy_ecs::prelude::{Component, Entity};
+
+/// A lit block of TNT, counting down to its explosion.
+#[derive(Component, Debug, Clone)]
+pub struct PrimedTnt {
+    /// Ticks left until it explodes.
+    pub fuse: u32,
+    /// The player who lit it, who gets the blame for whatever it blows up.
+    pub igniter: Option<Entity>,
+}
+
+impl PrimedTnt {
+    /// TNT lit by a player or redstone explodes after 4 seconds.
+    pub const DEFAULT_FUSE: u32 = 80;
+    pub const EXPLOSION_RADIUS: f32 = 4.0;
+
+    pub fn new(fuse: u32, igniter: Option<Entity>) -> Self {
+        Self { fuse, igniter }
+    }
+}
+
+/// How close a creeper is to exploding.
+///
+/// Creepers swell up while a player is close and calm down again once they get away, exploding
+/// if they swell for long enough.
+#[derive(Component, Debug, Clone, Default)]
+pub struct CreeperFuse {
+    /// Ticks spent swelling, minus the ticks spent calming down since.
+    pub swell: u32,
+    /// Whether it's swelling up right now, which the client animates.
+    pub swelling: bool,
+}
+
+impl CreeperFuse {
+    /// Ticks a creeper swells for before it explodes.
+    pub const MAX_SWELL: u32 = 30;
+    pub const EXPLOSION_RADIUS: f32 = 3.0;
+
+    /// Swells or calms down for one tick. Returns true once the creeper should explode.
+    pub fn tick(&mut self) -> bool {
+        if self.swelling {
+            self.swell += 1;
+        } else {
+            self.swell = self.swell.saturating_sub(1);
+        }
+        self.swell >= Self::MAX_SWELL
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_creeper_swell() {
+        let mut fuse = CreeperFuse {
+            swelling: true,
+            ..Default::default()
+        };
+        for _ in 0..20 {
+            assert!(!fuse.tick());
+        }
+        // Calming down takes back the ticks spent swelling
+        fuse.swelling = false;
+        for _ in 0..25 {
+            assert!(!fuse.tick());
+        }
+        assert_eq!(fuse.swell, 0);
+
+        fuse.swelling = true;
+        let ticks = (0..100).take_while(|_| !fuse.tick()).count();
+        assert_eq!(ticks as u32 + 1, CreeperFuse::MAX_SWELL);
+    }
+}
@@ -0,0 +1,40 @@
+use bevy_ecs::prelude::Component;
+use ferrumc_inventories::slot::InventorySlot;
+
+/// An item stack lying in the world.
+///
+/// Item entities are picked up by players who walk into them, merge with nearby stacks of the
+/// same item, and despawn once they've existed for `ItemEntity::DESPAWN_AGE` ticks.
+#[derive(Component, Debug, Clone)]
+pub struct ItemEntity {
+    /// The stack this entity represents. Never empty.
+    pub stack: InventorySlot,
+    /// Ticks since the item was dropped.
+    pub age: u32,
+    /// Ticks left until the item can be picked up.
+    pub pickup_delay: u32,
+}
+
+impl ItemEntity {
+    /// Five minutes, the same as vanilla.
+    pub const DESPAWN_AGE: u32 = 6000;
+
+    /// Pickup delay for items thrown by a player, so they don't pick them straight back up.
+    pub const THROWN_PICKUP_DELAY: u32 = 40;
+
+    /// Pickup delay for items dropped by broken blocks.
+    pub const BLOCK_PICKUP_DELAY: u32 = 10;
+
+    pub fn new(stack: InventorySlot, pickup_delay: u32) -> Self {
+        Self {
+            stack,
+            age: 0,
+            pickup_delay,
+        }
+    }
+
+    /// How many items are in the stack.
+    pub fn count(&self) -> i32 {
+        self.stack.count.0
+    }
+}
@@ -1,17 +1,25 @@
 // Core entity components based on ferrumc-data
 pub mod combat;
+pub mod experience_orb;
+pub mod explosive;
+pub mod item;
 pub mod last_synced_position;
 pub mod metadata;
 pub mod physical;
 pub mod physical_registry;
+pub mod projectile;
 pub mod spawn;
 
 // Re-exports
 pub use combat::CombatProperties;
+pub use experience_orb::ExperienceOrb;
+pub use explosive::{CreeperFuse, PrimedTnt};
+pub use item::ItemEntity;
 pub use last_synced_position::LastSyncedPosition;
 pub use metadata::EntityMetadata;
 pub use physical::{BoundingBox, PhysicalProperties};
 pub use physical_registry::PhysicalRegistry;
+pub use projectile::{Pickup, Projectile, ProjectileKind};
 pub use spawn::SpawnProperties;
 
 // Marker component for baby entities
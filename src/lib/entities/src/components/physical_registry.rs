@@ -117,6 +117,11 @@ impl PhysicalRegistry {
             &VanillaEntityType::ZOGLIN,
             &VanillaEntityType::ZOMBIE,
             &VanillaEntityType::ZOMBIE_VILLAGER,
+            // Misc
+            &VanillaEntityType::ITEM,
+            &VanillaEntityType::EXPERIENCE_ORB,
+            &VanillaEntityType::PLAYER,
+            &VanillaEntityType::TNT,
         ];
 
         for vanilla in entity_types {
@@ -0,0 +1,143 @@
+use bevy_ecs::prelude::{Component, Entity};
+use bevy_math::IVec3;
+use ferrumc_data::generated::entities::EntityType as VanillaEntityType;
+use ferrumc_inventories::slot::InventorySlot;
+
+/// The projectiles players can shoot or throw.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProjectileKind {
+    Arrow,
+    Snowball,
+    Egg,
+    EnderPearl,
+    Trident,
+}
+
+impl ProjectileKind {
+    pub fn entity_type(self) -> &'static VanillaEntityType {
+        match self {
+            Self::Arrow => &VanillaEntityType::ARROW,
+            Self::Snowball => &VanillaEntityType::SNOWBALL,
+            Self::Egg => &VanillaEntityType::EGG,
+            Self::EnderPearl => &VanillaEntityType::ENDER_PEARL,
+            Self::Trident => &VanillaEntityType::TRIDENT,
+        }
+    }
+
+    /// How much the projectile speeds up downwards every tick.
+    pub fn gravity(self) -> f32 {
+        match self {
+            Self::Arrow | Self::Trident => 0.05,
+            Self::Snowball | Self::Egg | Self::EnderPearl => 0.03,
+        }
+    }
+
+    /// What the projectile's velocity is multiplied by every tick. Arrows slow down a lot more in
+    /// water than thrown items, while tridents aren't slowed down by it at all.
+    pub fn drag(self, in_water: bool) -> f32 {
+        match (self, in_water) {
+            (_, false) | (Self::Trident, true) => 0.99,
+            (Self::Arrow, true) => 0.6,
+            (_, true) => 0.8,
+        }
+    }
+
+    /// Whether the projectile sticks in the blocks it hits, rather than breaking on them.
+    pub fn sticks(self) -> bool {
+        matches!(self, Self::Arrow | Self::Trident)
+    }
+}
+
+/// Who can pick up an arrow or trident once it's landed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pickup {
+    Disallowed,
+    Allowed,
+    /// Arrows shot by creative players or with infinity can still be picked up by creative
+    /// players, who don't get anything for it.
+    CreativeOnly,
+}
+
+/// A projectile flying through the world, or stuck in a block.
+#[derive(Component, Debug, Clone)]
+pub struct Projectile {
+    pub kind: ProjectileKind,
+    /// The entity that shot or threw it.
+    pub owner: Option<Entity>,
+    /// The damage it deals per block per tick it's flying, for arrows and tridents.
+    pub base_damage: f32,
+    /// Arrows shot from a fully drawn bow deal a bit of extra damage.
+    pub critical: bool,
+    pub pickup: Pickup,
+    /// The item players get back when they pick it up.
+    pub stack: Option<InventorySlot>,
+    /// The loyalty level of a trident, which makes it fly back to its owner.
+    pub loyalty: i32,
+    /// Ticks since it was shot.
+    pub age: u32,
+    /// The block it's stuck in, if it has landed.
+    pub stuck_in: Option<IVec3>,
+    /// Ticks since it got stuck.
+    pub ground_ticks: u32,
+    /// Set once a trident has hit an entity, after which it only falls to the ground.
+    pub dealt_damage: bool,
+    /// Whether a trident with loyalty is on its way back to its owner.
+    pub returning: bool,
+}
+
+impl Projectile {
+    /// Arrows stuck in a block despawn after a minute, the same as vanilla.
+    pub const DESPAWN_TICKS: u32 = 1200;
+
+    pub fn new(kind: ProjectileKind, owner: Option<Entity>) -> Self {
+        Self {
+            kind,
+            owner,
+            base_damage: match kind {
+                ProjectileKind::Arrow => 2.0,
+                ProjectileKind::Trident => 8.0,
+                _ => 0.0,
+            },
+            critical: false,
+            pickup: Pickup::Disallowed,
+            stack: None,
+            loyalty: 0,
+            age: 0,
+            stuck_in: None,
+            ground_ticks: 0,
+            dealt_damage: false,
+            returning: false,
+        }
+    }
+
+    /// The damage an arrow deals when it hits something at the given speed, before the random
+    /// bonus of critical arrows. Tridents always deal their base damage.
+    pub fn damage(&self, speed: f32) -> f32 {
+        match self.kind {
+            ProjectileKind::Arrow => (speed * self.base_damage).ceil(),
+            _ => self.base_damage,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_damage() {
+        let mut arrow = Projectile::new(ProjectileKind::Arrow, None);
+        // A fully drawn bow shoots arrows at 3 blocks per tick
+        assert_eq!(arrow.damage(3.0), 6.0);
+        assert_eq!(arrow.damage(1.2), 3.0);
+        arrow.base_damage += 2.5;
+        assert_eq!(arrow.damage(3.0), 14.0);
+
+        let trident = Projectile::new(ProjectileKind::Trident, None);
+        assert_eq!(trident.damage(2.5), 8.0);
+        assert_eq!(
+            Projectile::new(ProjectileKind::Snowball, None).damage(1.5),
+            0.0
+        );
+    }
+}
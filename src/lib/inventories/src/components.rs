@@ -0,0 +1,1256 @@
+//! Item data components.
+//!
+//! Items carry data components on top of the defaults their item type has, such as a custom
+//! name, enchantments or how damaged they are. Slots send them as a list of component IDs each
+//! followed by its data, and since the data isn't length prefixed, every component the client can
+//! send has to be read to get to the rest of the slot. The ones the server doesn't use are kept
+//! as [`ItemComponent::Other`], holding the bytes they were sent as.
+//!
+//! https://minecraft.wiki/w/Java_Edition_protocol/Slot_data#Structured_components
+
+use crate::errors::ItemComponentError;
+use crate::slot::InventorySlot;
+use bitcode_derive::{Decode, Encode};
+use ferrumc_data::items::DataComponent;
+use ferrumc_net_codec::decode::errors::NetDecodeError;
+use ferrumc_net_codec::decode::{NetDecode, NetDecodeOpts};
+use ferrumc_net_codec::encode::errors::NetEncodeError;
+use ferrumc_net_codec::encode::{NetEncode, NetEncodeOpts};
+use ferrumc_net_codec::net_types::var_int::VarInt;
+use std::io::{self, Read, Write};
+use tokio::io::{AsyncRead, AsyncWrite, AsyncWriteExt};
+
+/// The longest list a component may hold. Vanilla's own limits are all well below this.
+const MAX_LIST_LENGTH: i32 = 1024;
+
+/// The largest NBT value a component may hold, in bytes. The same as vanilla's limit for
+/// network NBT.
+const MAX_NBT_SIZE: usize = 2 * 1024 * 1024;
+
+/// How deeply lists and compounds may be nested in NBT values.
+const MAX_NBT_DEPTH: usize = 512;
+
+/// How deeply items may hold other items, e.g. a shulker box in a bundle in a shulker box.
+const MAX_ITEM_DEPTH: usize = 16;
+
+/// A data component with its data.
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
+pub enum ItemComponent {
+    CustomData(RawNbt),
+    MaxStackSize(VarInt),
+    MaxDamage(VarInt),
+    Damage(VarInt),
+    Unbreakable,
+    /// The name given to the item, e.g. with an anvil. A text component.
+    CustomName(RawNbt),
+    /// The default name of the item, shown when it has no custom name. A text component.
+    ItemName(RawNbt),
+    ItemModel(String),
+    /// Lines of text shown under the name. Text components.
+    Lore(Vec<RawNbt>),
+    Rarity(Rarity),
+    Enchantments(Vec<Enchantment>),
+    AttributeModifiers(Vec<AttributeModifier>),
+    CustomModelData(CustomModelData),
+    TooltipDisplay {
+        hide_tooltip: bool,
+        hidden_components: Vec<VarInt>,
+    },
+    RepairCost(VarInt),
+    CreativeSlotLock,
+    EnchantmentGlintOverride(bool),
+    IntangibleProjectile(RawNbt),
+    Food {
+        nutrition: VarInt,
+        saturation: f32,
+        can_always_eat: bool,
+    },
+    UseCooldown {
+        seconds: f32,
+        cooldown_group: Option<String>,
+    },
+    /// The damage type tag the item is immune to, e.g. fire for netherite.
+    DamageResistant(String),
+    Weapon {
+        damage_per_attack: VarInt,
+        disable_blocking_for_seconds: f32,
+    },
+    Enchantable(VarInt),
+    Glider,
+    TooltipStyle(String),
+    /// Enchantments held by an enchanted book, rather than ones applied to the item.
+    StoredEnchantments(Vec<Enchantment>),
+    DyedColor(i32),
+    MapColor(i32),
+    MapId(VarInt),
+    MapDecorations(RawNbt),
+    MapPostProcessing(VarInt),
+    PotionContents(PotionContents),
+    PotionDurationScale(f32),
+    DebugStickState(RawNbt),
+    EntityData(RawNbt),
+    BucketEntityData(RawNbt),
+    BlockEntityData(RawNbt),
+    OminousBottleAmplifier(VarInt),
+    Recipes(RawNbt),
+    BaseColor(VarInt),
+    ContainerLoot(RawNbt),
+    /// Any other component, e.g. the items in a shulker box or the owner of a player head. The
+    /// data is kept exactly as it was received so the item can be sent back unchanged.
+    Other {
+        id: i32,
+        data: Vec<u8>,
+    },
+}
+
+/// Network NBT kept exactly as it was received: the tag type followed by its unnamed payload.
+///
+/// Text components are sent this way too, so `TextComponent::serialize_nbt` can be used to build
+/// one.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Encode, Decode)]
+pub struct RawNbt(pub Vec<u8>);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
+pub enum Rarity {
+    Common,
+    Uncommon,
+    Rare,
+    Epic,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
+pub struct Enchantment {
+    /// The protocol ID of the enchantment.
+    pub id: VarInt,
+    pub level: VarInt,
+}
+
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
+pub struct AttributeModifier {
+    /// The protocol ID of the attribute.
+    pub attribute: VarInt,
+    /// The identifier of the modifier, e.g. `minecraft:base_attack_damage`.
+    pub id: String,
+    pub amount: f64,
+    pub operation: VarInt,
+    /// The equipment slot group the item has to be in for the modifier to apply.
+    pub slot: VarInt,
+    pub display: ModifierDisplay,
+}
+
+/// How an attribute modifier is shown in the tooltip.
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
+pub enum ModifierDisplay {
+    Default,
+    Hidden,
+    /// Shown as the given text component instead.
+    Override(RawNbt),
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Encode, Decode)]
+pub struct CustomModelData {
+    pub floats: Vec<f32>,
+    pub flags: Vec<bool>,
+    pub strings: Vec<String>,
+    pub colors: Vec<i32>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Encode, Decode)]
+pub struct PotionContents {
+    /// The protocol ID of the potion, e.g. swiftness.
+    pub potion: Option<VarInt>,
+    pub custom_color: Option<i32>,
+    pub custom_effects: Vec<PotionEffect>,
+    pub custom_name: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
+pub struct PotionEffect {
+    /// The protocol ID of the effect.
+    pub effect: VarInt,
+    /// The effect itself, followed by the weaker or longer effects hidden behind it, each one
+    /// hidden behind the one before. They are kept in a list rather than nested so they can be
+    /// stored with bitcode.
+    pub details: Vec<EffectDetails>,
+}
+
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
+pub struct EffectDetails {
+    pub amplifier: VarInt,
+    /// In ticks, or -1 for an infinite effect.
+    pub duration: VarInt,
+    pub ambient: bool,
+    pub show_particles: bool,
+    pub show_icon: bool,
+}
+
+impl ItemComponent {
+    /// The protocol ID of the component.
+    pub fn id(&self) -> i32 {
+        let component = match self {
+            Self::CustomData(_) => DataComponent::CustomData,
+            Self::MaxStackSize(_) => DataComponent::MaxStackSize,
+            Self::MaxDamage(_) => DataComponent::MaxDamage,
+            Self::Damage(_) => DataComponent::Damage,
+            Self::Unbreakable => DataComponent::Unbreakable,
+            Self::CustomName(_) => DataComponent::CustomName,
+            Self::ItemName(_) => DataComponent::ItemName,
+            Self::ItemModel(_) => DataComponent::ItemModel,
+            Self::Lore(_) => DataComponent::Lore,
+            Self::Rarity(_) => DataComponent::Rarity,
+            Self::Enchantments(_) => DataComponent::Enchantments,
+            Self::AttributeModifiers(_) => DataComponent::AttributeModifiers,
+            Self::CustomModelData(_) => DataComponent::CustomModelData,
+            Self::TooltipDisplay { .. } => DataComponent::TooltipDisplay,
+            Self::RepairCost(_) => DataComponent::RepairCost,
+            Self::CreativeSlotLock => DataComponent::CreativeSlotLock,
+            Self::EnchantmentGlintOverride(_) => DataComponent::EnchantmentGlintOverride,
+            Self::IntangibleProjectile(_) => DataComponent::IntangibleProjectile,
+            Self::Food { .. } => DataComponent::Food,
+            Self::UseCooldown { .. } => DataComponent::UseCooldown,
+            Self::DamageResistant(_) => DataComponent::DamageResistant,
+            Self::Weapon { .. } => DataComponent::Weapon,
+            Self::Enchantable(_) => DataComponent::Enchantable,
+            Self::Glider => DataComponent::Glider,
+            Self::TooltipStyle(_) => DataComponent::TooltipStyle,
+            Self::StoredEnchantments(_) => DataComponent::StoredEnchantments,
+            Self::DyedColor(_) => DataComponent::DyedColor,
+            Self::MapColor(_) => DataComponent::MapColor,
+            Self::MapId(_) => DataComponent::MapId,
+            Self::MapDecorations(_) => DataComponent::MapDecorations,
+            Self::MapPostProcessing(_) => DataComponent::MapPostProcessing,
+            Self::PotionContents(_) => DataComponent::PotionContents,
+            Self::PotionDurationScale(_) => DataComponent::PotionDurationScale,
+            Self::DebugStickState(_) => DataComponent::DebugStickState,
+            Self::EntityData(_) => DataComponent::EntityData,
+            Self::BucketEntityData(_) => DataComponent::BucketEntityData,
+            Self::BlockEntityData(_) => DataComponent::BlockEntityData,
+            Self::OminousBottleAmplifier(_) => DataComponent::OminousBottleAmplifier,
+            Self::Recipes(_) => DataComponent::Recipes,
+            Self::BaseColor(_) => DataComponent::BaseColor,
+            Self::ContainerLoot(_) => DataComponent::ContainerLoot,
+            Self::Other { id, .. } => return *id,
+        };
+        component.id()
+    }
+
+    /// Reads a component, which may hold items nested `depth` deep in other items.
+    pub(crate) fn read<R: Read>(reader: &mut R, depth: usize) -> Result<Self, NetDecodeError> {
+        let id = read::<VarInt, _>(reader)?.0;
+        Self::read_data(id, reader, depth)
+    }
+
+    /// Reads the data of the component with the given ID.
+    fn read_data<R: Read>(id: i32, reader: &mut R, depth: usize) -> Result<Self, NetDecodeError> {
+        let Some(component) = DataComponent::from_id(id) else {
+            return Err(ItemComponentError::Unknown(id).into());
+        };
+        Ok(match component {
+            DataComponent::CustomData => Self::CustomData(RawNbt::read(reader)?),
+            DataComponent::MaxStackSize => Self::MaxStackSize(read(reader)?),
+            DataComponent::MaxDamage => Self::MaxDamage(read(reader)?),
+            DataComponent::Damage => Self::Damage(read(reader)?),
+            DataComponent::Unbreakable => Self::Unbreakable,
+            DataComponent::CustomName => Self::CustomName(RawNbt::read(reader)?),
+            DataComponent::ItemName => Self::ItemName(RawNbt::read(reader)?),
+            DataComponent::ItemModel => Self::ItemModel(read(reader)?),
+            DataComponent::Lore => Self::Lore(read_list(reader, RawNbt::read)?),
+            DataComponent::Rarity => Self::Rarity(Rarity::read(reader)?),
+            DataComponent::Enchantments => {
+                Self::Enchantments(read_list(reader, Enchantment::read)?)
+            }
+            DataComponent::AttributeModifiers => {
+                Self::AttributeModifiers(read_list(reader, AttributeModifier::read)?)
+            }
+            DataComponent::CustomModelData => Self::CustomModelData(CustomModelData::read(reader)?),
+            DataComponent::TooltipDisplay => Self::TooltipDisplay {
+                hide_tooltip: read(reader)?,
+                hidden_components: read_list(reader, read)?,
+            },
+            DataComponent::RepairCost => Self::RepairCost(read(reader)?),
+            DataComponent::CreativeSlotLock => Self::CreativeSlotLock,
+            DataComponent::EnchantmentGlintOverride => {
+                Self::EnchantmentGlintOverride(read(reader)?)
+            }
+            DataComponent::IntangibleProjectile => {
+                Self::IntangibleProjectile(RawNbt::read(reader)?)
+            }
+            DataComponent::Food => Self::Food {
+                nutrition: read(reader)?,
+                saturation: read(reader)?,
+                can_always_eat: read(reader)?,
+            },
+            DataComponent::UseCooldown => Self::UseCooldown {
+                seconds: read(reader)?,
+                cooldown_group: read_optional(reader, read)?,
+            },
+            DataComponent::DamageResistant => Self::DamageResistant(read(reader)?),
+            DataComponent::Weapon => Self::Weapon {
+                damage_per_attack: read(reader)?,
+                disable_blocking_for_seconds: read(reader)?,
+            },
+            DataComponent::Enchantable => Self::Enchantable(read(reader)?),
+            DataComponent::Glider => Self::Glider,
+            DataComponent::TooltipStyle => Self::TooltipStyle(read(reader)?),
+            DataComponent::StoredEnchantments => {
+                Self::StoredEnchantments(read_list(reader, Enchantment::read)?)
+            }
+            DataComponent::DyedColor => Self::DyedColor(read(reader)?),
+            DataComponent::MapColor => Self::MapColor(read(reader)?),
+            DataComponent::MapId => Self::MapId(read(reader)?),
+            DataComponent::MapDecorations => Self::MapDecorations(RawNbt::read(reader)?),
+            DataComponent::MapPostProcessing => Self::MapPostProcessing(read(reader)?),
+            DataComponent::PotionContents => Self::PotionContents(PotionContents::read(reader)?),
+            DataComponent::PotionDurationScale => Self::PotionDurationScale(read(reader)?),
+            DataComponent::DebugStickState => Self::DebugStickState(RawNbt::read(reader)?),
+            DataComponent::EntityData => Self::EntityData(RawNbt::read(reader)?),
+            DataComponent::BucketEntityData => Self::BucketEntityData(RawNbt::read(reader)?),
+            DataComponent::BlockEntityData => Self::BlockEntityData(RawNbt::read(reader)?),
+            DataComponent::OminousBottleAmplifier => Self::OminousBottleAmplifier(read(reader)?),
+            DataComponent::Recipes => Self::Recipes(RawNbt::read(reader)?),
+            DataComponent::BaseColor => Self::BaseColor(read(reader)?),
+            DataComponent::ContainerLoot => Self::ContainerLoot(RawNbt::read(reader)?),
+            _ => Self::Other {
+                id,
+                data: read_other(component, reader, depth)?,
+            },
+        })
+    }
+
+    /// Writes the data of the component, without its ID.
+    fn write_data<W: Write>(&self, writer: &mut W) -> Result<(), NetEncodeError> {
+        match self {
+            Self::Unbreakable | Self::CreativeSlotLock | Self::Glider => Ok(()),
+            Self::CustomData(nbt)
+            | Self::CustomName(nbt)
+            | Self::ItemName(nbt)
+            | Self::IntangibleProjectile(nbt)
+            | Self::MapDecorations(nbt)
+            | Self::DebugStickState(nbt)
+            | Self::EntityData(nbt)
+            | Self::BucketEntityData(nbt)
+            | Self::BlockEntityData(nbt)
+            | Self::Recipes(nbt)
+            | Self::ContainerLoot(nbt) => nbt.write(writer),
+            Self::MaxStackSize(value)
+            | Self::MaxDamage(value)
+            | Self::Damage(value)
+            | Self::RepairCost(value)
+            | Self::Enchantable(value)
+            | Self::MapId(value)
+            | Self::MapPostProcessing(value)
+            | Self::OminousBottleAmplifier(value)
+            | Self::BaseColor(value) => write(value, writer),
+            Self::ItemModel(value) | Self::DamageResistant(value) | Self::TooltipStyle(value) => {
+                write(value, writer)
+            }
+            Self::DyedColor(value) | Self::MapColor(value) => write(value, writer),
+            Self::Lore(lines) => write_list(lines, writer, RawNbt::write),
+            Self::Rarity(rarity) => rarity.write(writer),
+            Self::Enchantments(enchantments) | Self::StoredEnchantments(enchantments) => {
+                write_list(enchantments, writer, Enchantment::write)
+            }
+            Self::AttributeModifiers(modifiers) => {
+                write_list(modifiers, writer, AttributeModifier::write)
+            }
+            Self::CustomModelData(data) => data.write(writer),
+            Self::TooltipDisplay {
+                hide_tooltip,
+                hidden_components,
+            } => {
+                write(hide_tooltip, writer)?;
+                write_list(hidden_components, writer, write)
+            }
+            Self::EnchantmentGlintOverride(value) => write(value, writer),
+            Self::Food {
+                nutrition,
+                saturation,
+                can_always_eat,
+            } => {
+                write(nutrition, writer)?;
+                write(saturation, writer)?;
+                write(can_always_eat, writer)
+            }
+            Self::UseCooldown {
+                seconds,
+                cooldown_group,
+            } => {
+                write(seconds, writer)?;
+                write_optional(cooldown_group, writer, write)
+            }
+            Self::Weapon {
+                damage_per_attack,
+                disable_blocking_for_seconds,
+            } => {
+                write(damage_per_attack, writer)?;
+                write(disable_blocking_for_seconds, writer)
+            }
+            Self::PotionContents(contents) => contents.write(writer),
+            Self::PotionDurationScale(scale) => write(scale, writer),
+            Self::Other { data, .. } => {
+                writer.write_all(data)?;
+                Ok(())
+            }
+        }
+    }
+}
+
+impl NetDecode for ItemComponent {
+    fn decode<R: Read>(reader: &mut R, _opts: &NetDecodeOpts) -> Result<Self, NetDecodeError> {
+        Self::read(reader, 0)
+    }
+
+    /// Components aren't length prefixed, so there's no way to know how much to read ahead of
+    /// time. Decode them from a buffer holding the whole packet instead.
+    async fn decode_async<R: AsyncRead + Unpin>(
+        _reader: &mut R,
+        _opts: &NetDecodeOpts,
+    ) -> Result<Self, NetDecodeError> {
+        Err(ItemComponentError::AsyncDecode.into())
+    }
+}
+
+impl NetEncode for ItemComponent {
+    fn encode<W: Write>(&self, writer: &mut W, opts: &NetEncodeOpts) -> Result<(), NetEncodeError> {
+        VarInt::new(self.id()).encode(writer, opts)?;
+        self.write_data(writer)
+    }
+
+    async fn encode_async<W: AsyncWrite + Unpin>(
+        &self,
+        writer: &mut W,
+        opts: &NetEncodeOpts,
+    ) -> Result<(), NetEncodeError> {
+        let mut buffer = Vec::new();
+        self.encode(&mut buffer, opts)?;
+        writer.write_all(&buffer).await?;
+        Ok(())
+    }
+}
+
+impl RawNbt {
+    /// Reads a network NBT value without parsing it, keeping the bytes it was made of.
+    pub fn read<R: Read>(reader: &mut R) -> Result<Self, NetDecodeError> {
+        let mut bytes = Vec::new();
+        let [tag] = copy_bytes(reader, &mut bytes)?;
+        copy_nbt_payload(reader, tag, &mut bytes, 0)?;
+        Ok(Self(bytes))
+    }
+
+    pub fn write<W: Write>(&self, writer: &mut W) -> Result<(), NetEncodeError> {
+        writer.write_all(&self.0)?;
+        Ok(())
+    }
+}
+
+impl Rarity {
+    fn read<R: Read>(reader: &mut R) -> Result<Self, NetDecodeError> {
+        match read::<VarInt, _>(reader)?.0 {
+            0 => Ok(Self::Common),
+            1 => Ok(Self::Uncommon),
+            2 => Ok(Self::Rare),
+            3 => Ok(Self::Epic),
+            _ => Err(NetDecodeError::InvalidEnumVariant),
+        }
+    }
+
+    fn write<W: Write>(&self, writer: &mut W) -> Result<(), NetEncodeError> {
+        write(&VarInt::new(*self as i32), writer)
+    }
+}
+
+impl Enchantment {
+    fn read<R: Read>(reader: &mut R) -> Result<Self, NetDecodeError> {
+        Ok(Self {
+            id: read(reader)?,
+            level: read(reader)?,
+        })
+    }
+
+    fn write<W: Write>(&self, writer: &mut W) -> Result<(), NetEncodeError> {
+        write(&self.id, writer)?;
+        write(&self.level, writer)
+    }
+}
+
+impl AttributeModifier {
+    fn read<R: Read>(reader: &mut R) -> Result<Self, NetDecodeError> {
+        Ok(Self {
+            attribute: read(reader)?,
+            id: read(reader)?,
+            amount: read(reader)?,
+            operation: read(reader)?,
+            slot: read(reader)?,
+            display: match read::<VarInt, _>(reader)?.0 {
+                0 => ModifierDisplay::Default,
+                1 => ModifierDisplay::Hidden,
+                2 => ModifierDisplay::Override(RawNbt::read(reader)?),
+                _ => return Err(NetDecodeError::InvalidEnumVariant),
+            },
+        })
+    }
+
+    fn write<W: Write>(&self, writer: &mut W) -> Result<(), NetEncodeError> {
+        write(&self.attribute, writer)?;
+        write(&self.id, writer)?;
+        write(&self.amount, writer)?;
+        write(&self.operation, writer)?;
+        write(&self.slot, writer)?;
+        match &self.display {
+            ModifierDisplay::Default => write(&VarInt::new(0), writer),
+            ModifierDisplay::Hidden => write(&VarInt::new(1), writer),
+            ModifierDisplay::Override(text) => {
+                write(&VarInt::new(2), writer)?;
+                text.write(writer)
+            }
+        }
+    }
+}
+
+impl CustomModelData {
+    fn read<R: Read>(reader: &mut R) -> Result<Self, NetDecodeError> {
+        Ok(Self {
+            floats: read_list(reader, read)?,
+            flags: read_list(reader, read)?,
+            strings: read_list(reader, read)?,
+            colors: read_list(reader, read)?,
+        })
+    }
+
+    fn write<W: Write>(&self, writer: &mut W) -> Result<(), NetEncodeError> {
+        write_list(&self.floats, writer, write)?;
+        write_list(&self.flags, writer, write)?;
+        write_list(&self.strings, writer, write)?;
+        write_list(&self.colors, writer, write)
+    }
+}
+
+impl PotionContents {
+    fn read<R: Read>(reader: &mut R) -> Result<Self, NetDecodeError> {
+        Ok(Self {
+            potion: read_optional(reader, read)?,
+            custom_color: read_optional(reader, read)?,
+            custom_effects: read_list(reader, PotionEffect::read)?,
+            custom_name: read_optional(reader, read)?,
+        })
+    }
+
+    fn write<W: Write>(&self, writer: &mut W) -> Result<(), NetEncodeError> {
+        write_optional(&self.potion, writer, write)?;
+        write_optional(&self.custom_color, writer, write)?;
+        write_list(&self.custom_effects, writer, PotionEffect::write)?;
+        write_optional(&self.custom_name, writer, write)
+    }
+}
+
+impl PotionEffect {
+    fn read<R: Read>(reader: &mut R) -> Result<Self, NetDecodeError> {
+        let effect = read(reader)?;
+        let mut details = Vec::new();
+        loop {
+            if details.len() > MAX_LIST_LENGTH as usize {
+                return Err(ItemComponentError::TooLong.into());
+            }
+            details.push(EffectDetails {
+                amplifier: read(reader)?,
+                duration: read(reader)?,
+                ambient: read(reader)?,
+                show_particles: read(reader)?,
+                show_icon: read(reader)?,
+            });
+            // Whether another effect is hidden behind this one
+            if !read::<bool, _>(reader)? {
+                break;
+            }
+        }
+        Ok(Self { effect, details })
+    }
+
+    fn write<W: Write>(&self, writer: &mut W) -> Result<(), NetEncodeError> {
+        write(&self.effect, writer)?;
+        for (index, details) in self.details.iter().enumerate() {
+            write(&details.amplifier, writer)?;
+            write(&details.duration, writer)?;
+            write(&details.ambient, writer)?;
+            write(&details.show_particles, writer)?;
+            write(&details.show_icon, writer)?;
+            write(&(index + 1 < self.details.len()), writer)?;
+        }
+        Ok(())
+    }
+}
+
+/// Reads through to another reader, keeping a copy of everything that was read.
+struct Recorder<'a> {
+    reader: &'a mut dyn Read,
+    bytes: Vec<u8>,
+}
+
+impl Read for Recorder<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.reader.read(buf)?;
+        if self.bytes.len() + read > MAX_NBT_SIZE {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "Item component is too large",
+            ));
+        }
+        self.bytes.extend_from_slice(&buf[..read]);
+        Ok(read)
+    }
+}
+
+/// Reads the data of a component that isn't modelled, returning the bytes it was made of.
+fn read_other<R: Read>(
+    component: DataComponent,
+    reader: &mut R,
+    depth: usize,
+) -> Result<Vec<u8>, NetDecodeError> {
+    let mut recorder = Recorder {
+        reader,
+        bytes: Vec::new(),
+    };
+    skip_data(component, &mut recorder, depth)?;
+    Ok(recorder.bytes)
+}
+
+/// Reads past the data of a component.
+///
+/// https://minecraft.wiki/w/Java_Edition_protocol/Slot_data#Structured_components
+fn skip_data(
+    component: DataComponent,
+    reader: &mut Recorder,
+    depth: usize,
+) -> Result<(), NetDecodeError> {
+    match component {
+        DataComponent::CanPlaceOn | DataComponent::CanBreak => {
+            skip_list(reader, |reader| skip_block_predicate(reader, depth))
+        }
+        DataComponent::Consumable => {
+            skip::<f32, _>(reader)?;
+            // The animation
+            skip::<VarInt, _>(reader)?;
+            skip_sound_event(reader)?;
+            // Whether it has particles
+            skip::<bool, _>(reader)?;
+            skip_list(reader, skip_consume_effect)
+        }
+        DataComponent::UseRemainder => skip_slot(reader, depth),
+        DataComponent::Tool => {
+            skip_list(reader, |reader| {
+                skip_id_set(reader)?;
+                skip_optional(reader, skip::<f32, _>)?;
+                skip_optional(reader, skip::<bool, _>)
+            })?;
+            skip::<f32, _>(reader)?;
+            skip::<VarInt, _>(reader)?;
+            skip::<bool, _>(reader)
+        }
+        DataComponent::Equippable => {
+            // The slot
+            skip::<VarInt, _>(reader)?;
+            skip_sound_event(reader)?;
+            // The model and camera overlay
+            skip_optional(reader, skip::<String, _>)?;
+            skip_optional(reader, skip::<String, _>)?;
+            // The entities that can wear it
+            skip_optional(reader, skip_id_set)?;
+            // Dispensable, swappable, damaged on hurt, equipped on interact and can be sheared
+            for _ in 0..5 {
+                skip::<bool, _>(reader)?;
+            }
+            skip_sound_event(reader)
+        }
+        DataComponent::Repairable => skip_id_set(reader),
+        DataComponent::DeathProtection => skip_list(reader, skip_consume_effect),
+        DataComponent::BlocksAttacks => {
+            // The block delay and disable cooldown scale
+            skip::<f32, _>(reader)?;
+            skip::<f32, _>(reader)?;
+            skip_list(reader, |reader| {
+                skip::<f32, _>(reader)?;
+                skip_optional(reader, skip_id_set)?;
+                skip::<f32, _>(reader)?;
+                skip::<f32, _>(reader)
+            })?;
+            // The item damage threshold, base and factor
+            for _ in 0..3 {
+                skip::<f32, _>(reader)?;
+            }
+            skip_optional(reader, skip::<String, _>)?;
+            skip_optional(reader, skip_sound_event)?;
+            skip_optional(reader, skip_sound_event)
+        }
+        DataComponent::ChargedProjectiles
+        | DataComponent::BundleContents
+        | DataComponent::Container => skip_list(reader, |reader| skip_slot(reader, depth)),
+        DataComponent::SuspiciousStewEffects => skip_list(reader, |reader| {
+            skip::<VarInt, _>(reader)?;
+            skip::<VarInt, _>(reader)
+        }),
+        DataComponent::WritableBookContent => skip_list(reader, |reader| {
+            skip::<String, _>(reader)?;
+            skip_optional(reader, skip::<String, _>)
+        }),
+        DataComponent::WrittenBookContent => {
+            // The title, the author and the generation
+            skip::<String, _>(reader)?;
+            skip_optional(reader, skip::<String, _>)?;
+            skip::<String, _>(reader)?;
+            skip::<VarInt, _>(reader)?;
+            skip_list(reader, |reader| {
+                skip_nbt(reader)?;
+                skip_optional(reader, skip_nbt)
+            })?;
+            // Whether it's resolved
+            skip::<bool, _>(reader)
+        }
+        DataComponent::Trim => {
+            skip_holder(reader, skip_trim_material)?;
+            skip_holder(reader, |reader| {
+                skip::<String, _>(reader)?;
+                skip_nbt(reader)?;
+                // Whether it's a decal
+                skip::<bool, _>(reader)
+            })
+        }
+        DataComponent::Instrument => skip_either_holder(reader, |reader| {
+            skip_holder(reader, |reader| {
+                skip_sound_event(reader)?;
+                // The use duration and range
+                skip::<f32, _>(reader)?;
+                skip::<f32, _>(reader)?;
+                skip_nbt(reader)
+            })
+        }),
+        DataComponent::ProvidesTrimMaterial => {
+            skip_either_holder(reader, |reader| skip_holder(reader, skip_trim_material))
+        }
+        DataComponent::JukeboxPlayable => skip_either_holder(reader, |reader| {
+            skip_holder(reader, |reader| {
+                skip_sound_event(reader)?;
+                skip_nbt(reader)?;
+                // The length and comparator output
+                skip::<f32, _>(reader)?;
+                skip::<VarInt, _>(reader)
+            })
+        }),
+        DataComponent::ProvidesBannerPatterns | DataComponent::NoteBlockSound => {
+            skip::<String, _>(reader)
+        }
+        DataComponent::LodestoneTracker => {
+            // The dimension and position of the lodestone
+            skip_optional(reader, |reader| {
+                skip::<String, _>(reader)?;
+                skip::<i64, _>(reader)
+            })?;
+            // Whether it's tracked
+            skip::<bool, _>(reader)
+        }
+        DataComponent::FireworkExplosion => skip_firework_explosion(reader),
+        DataComponent::Fireworks => {
+            // The flight duration
+            skip::<VarInt, _>(reader)?;
+            skip_list(reader, skip_firework_explosion)
+        }
+        DataComponent::Profile => {
+            // The name, UUID and properties
+            skip_optional(reader, skip::<String, _>)?;
+            skip_optional(reader, skip::<u128, _>)?;
+            skip_list(reader, |reader| {
+                skip::<String, _>(reader)?;
+                skip::<String, _>(reader)?;
+                skip_optional(reader, skip::<String, _>)
+            })
+        }
+        DataComponent::BannerPatterns => skip_list(reader, |reader| {
+            skip_holder(reader, |reader| {
+                skip::<String, _>(reader)?;
+                skip::<String, _>(reader)
+            })?;
+            // The colour
+            skip::<VarInt, _>(reader)
+        }),
+        DataComponent::PotDecorations => skip_list(reader, skip::<VarInt, _>),
+        DataComponent::BlockState => skip_list(reader, |reader| {
+            skip::<String, _>(reader)?;
+            skip::<String, _>(reader)
+        }),
+        DataComponent::Bees => skip_list(reader, |reader| {
+            skip_nbt(reader)?;
+            // The ticks spent in the hive, and the least it has to spend there
+            skip::<VarInt, _>(reader)?;
+            skip::<VarInt, _>(reader)
+        }),
+        DataComponent::Lock => skip_nbt(reader),
+        DataComponent::BreakSound => skip_sound_event(reader),
+        DataComponent::ChickenVariant => skip_either_holder(reader, skip::<VarInt, _>),
+        DataComponent::PaintingVariant => skip_holder(reader, |reader| {
+            // The width, height and asset
+            skip::<VarInt, _>(reader)?;
+            skip::<VarInt, _>(reader)?;
+            skip::<String, _>(reader)?;
+            // The title and author
+            skip_optional(reader, skip_nbt)?;
+            skip_optional(reader, skip_nbt)
+        }),
+        // Registry IDs, enum values and dye colours
+        DataComponent::VillagerVariant
+        | DataComponent::WolfVariant
+        | DataComponent::WolfSoundVariant
+        | DataComponent::WolfCollar
+        | DataComponent::FoxVariant
+        | DataComponent::SalmonSize
+        | DataComponent::ParrotVariant
+        | DataComponent::TropicalFishPattern
+        | DataComponent::TropicalFishBaseColor
+        | DataComponent::TropicalFishPatternColor
+        | DataComponent::MooshroomVariant
+        | DataComponent::RabbitVariant
+        | DataComponent::PigVariant
+        | DataComponent::CowVariant
+        | DataComponent::FrogVariant
+        | DataComponent::HorseVariant
+        | DataComponent::LlamaVariant
+        | DataComponent::AxolotlVariant
+        | DataComponent::CatVariant
+        | DataComponent::CatCollar
+        | DataComponent::SheepColor
+        | DataComponent::ShulkerColor => skip::<VarInt, _>(reader),
+        // The rest are modelled by `ItemComponent`
+        _ => Err(ItemComponentError::Unknown(component.id()).into()),
+    }
+}
+
+fn skip_slot(reader: &mut Recorder, depth: usize) -> Result<(), NetDecodeError> {
+    if depth >= MAX_ITEM_DEPTH {
+        return Err(ItemComponentError::TooDeep.into());
+    }
+    InventorySlot::read(reader, depth + 1).map(drop)
+}
+
+fn skip_block_predicate(reader: &mut Recorder, depth: usize) -> Result<(), NetDecodeError> {
+    skip_optional(reader, skip_id_set)?;
+    // The block state properties, each matching a value or a range of them
+    skip_optional(reader, |reader| {
+        skip_list(reader, |reader| {
+            skip::<String, _>(reader)?;
+            if read::<bool, _>(reader)? {
+                skip::<String, _>(reader)
+            } else {
+                skip_optional(reader, skip::<String, _>)?;
+                skip_optional(reader, skip::<String, _>)
+            }
+        })
+    })?;
+    skip_optional(reader, skip_nbt)?;
+    // Components the block entity has to have exactly, then ones it has to partially match
+    skip_list(reader, |reader| {
+        if depth >= MAX_ITEM_DEPTH {
+            return Err(ItemComponentError::TooDeep.into());
+        }
+        ItemComponent::read(reader, depth + 1).map(drop)
+    })?;
+    skip_list(reader, |reader| {
+        skip::<VarInt, _>(reader)?;
+        skip_nbt(reader)
+    })
+}
+
+fn skip_consume_effect(reader: &mut Recorder) -> Result<(), NetDecodeError> {
+    match read::<VarInt, _>(reader)?.0 {
+        // Apply effects, with a probability
+        0 => {
+            skip_list(reader, |reader| PotionEffect::read(reader).map(drop))?;
+            skip::<f32, _>(reader)
+        }
+        // Remove effects
+        1 => skip_id_set(reader),
+        // Clear all effects
+        2 => Ok(()),
+        // Teleport randomly, within a diameter
+        3 => skip::<f32, _>(reader),
+        // Play a sound
+        4 => skip_sound_event(reader),
+        _ => Err(NetDecodeError::InvalidEnumVariant),
+    }
+}
+
+fn skip_trim_material(reader: &mut Recorder) -> Result<(), NetDecodeError> {
+    // The asset suffix, and the ones used for particular armor materials instead
+    skip::<String, _>(reader)?;
+    skip_list(reader, |reader| {
+        skip::<String, _>(reader)?;
+        skip::<String, _>(reader)
+    })?;
+    skip_nbt(reader)
+}
+
+fn skip_firework_explosion(reader: &mut Recorder) -> Result<(), NetDecodeError> {
+    // The shape, colours and fade colours
+    skip::<VarInt, _>(reader)?;
+    skip_list(reader, skip::<i32, _>)?;
+    skip_list(reader, skip::<i32, _>)?;
+    // Whether it has a trail and twinkles
+    skip::<bool, _>(reader)?;
+    skip::<bool, _>(reader)
+}
+
+/// Skips a sound event, which is either a registry ID or a sound given by name.
+fn skip_sound_event(reader: &mut Recorder) -> Result<(), NetDecodeError> {
+    skip_holder(reader, |reader| {
+        skip::<String, _>(reader)?;
+        // The fixed range
+        skip_optional(reader, skip::<f32, _>)
+    })
+}
+
+/// Skips a registry entry, which is either its ID plus one, or 0 followed by the entry itself.
+fn skip_holder<R: Read>(
+    reader: &mut R,
+    skip_inline: impl Fn(&mut R) -> Result<(), NetDecodeError>,
+) -> Result<(), NetDecodeError> {
+    if read::<VarInt, _>(reader)?.0 == 0 {
+        skip_inline(reader)
+    } else {
+        Ok(())
+    }
+}
+
+/// Skips a registry entry that's either given directly, or by the name of its registry key.
+fn skip_either_holder<R: Read>(
+    reader: &mut R,
+    skip_direct: impl Fn(&mut R) -> Result<(), NetDecodeError>,
+) -> Result<(), NetDecodeError> {
+    if read::<bool, _>(reader)? {
+        skip_direct(reader)
+    } else {
+        skip::<String, _>(reader)
+    }
+}
+
+/// Skips a set of registry IDs, which is either a tag name or the IDs themselves.
+fn skip_id_set(reader: &mut Recorder) -> Result<(), NetDecodeError> {
+    match read::<VarInt, _>(reader)?.0 {
+        0 => skip::<String, _>(reader),
+        // The number of IDs plus one
+        length if (1..=MAX_LIST_LENGTH + 1).contains(&length) => {
+            (1..length).try_for_each(|_| skip::<VarInt, _>(reader))
+        }
+        _ => Err(ItemComponentError::TooLong.into()),
+    }
+}
+
+fn skip_nbt(reader: &mut Recorder) -> Result<(), NetDecodeError> {
+    RawNbt::read(reader).map(drop)
+}
+
+fn skip<T: NetDecode, R: Read>(reader: &mut R) -> Result<(), NetDecodeError> {
+    read::<T, R>(reader).map(drop)
+}
+
+fn skip_list<R: Read>(
+    reader: &mut R,
+    skip_item: impl Fn(&mut R) -> Result<(), NetDecodeError>,
+) -> Result<(), NetDecodeError> {
+    read_list(reader, skip_item).map(drop)
+}
+
+fn skip_optional<R: Read>(
+    reader: &mut R,
+    skip_value: impl Fn(&mut R) -> Result<(), NetDecodeError>,
+) -> Result<(), NetDecodeError> {
+    read_optional(reader, skip_value).map(drop)
+}
+
+fn read<T: NetDecode, R: Read>(reader: &mut R) -> Result<T, NetDecodeError> {
+    T::decode(reader, &NetDecodeOpts::None)
+}
+
+fn write<T: NetEncode, W: Write>(value: &T, writer: &mut W) -> Result<(), NetEncodeError> {
+    value.encode(writer, &NetEncodeOpts::None)
+}
+
+/// Reads a list prefixed with its length.
+fn read_list<T, R: Read>(
+    reader: &mut R,
+    read_item: impl Fn(&mut R) -> Result<T, NetDecodeError>,
+) -> Result<Vec<T>, NetDecodeError> {
+    let length = read::<VarInt, _>(reader)?.0;
+    if !(0..=MAX_LIST_LENGTH).contains(&length) {
+        return Err(ItemComponentError::TooLong.into());
+    }
+    (0..length).map(|_| read_item(reader)).collect()
+}
+
+fn write_list<T, W: Write>(
+    list: &[T],
+    writer: &mut W,
+    write_item: impl Fn(&T, &mut W) -> Result<(), NetEncodeError>,
+) -> Result<(), NetEncodeError> {
+    write(&VarInt::new(list.len() as i32), writer)?;
+    list.iter().try_for_each(|item| write_item(item, writer))
+}
+
+/// Reads a value prefixed with whether it's there.
+fn read_optional<T, R: Read>(
+    reader: &mut R,
+    read_value: impl Fn(&mut R) -> Result<T, NetDecodeError>,
+) -> Result<Option<T>, NetDecodeError> {
+    if read::<bool, _>(reader)? {
+        read_value(reader).map(Some)
+    } else {
+        Ok(None)
+    }
+}
+
+fn write_optional<T, W: Write>(
+    value: &Option<T>,
+    writer: &mut W,
+    write_value: impl Fn(&T, &mut W) -> Result<(), NetEncodeError>,
+) -> Result<(), NetEncodeError> {
+    write(&value.is_some(), writer)?;
+    match value {
+        Some(value) => write_value(value, writer),
+        None => Ok(()),
+    }
+}
+
+/// Reads `N` bytes, appending them to `out` as well as returning them.
+fn copy_bytes<const N: usize, R: Read>(
+    reader: &mut R,
+    out: &mut Vec<u8>,
+) -> Result<[u8; N], NetDecodeError> {
+    let mut bytes = [0; N];
+    reader.read_exact(&mut bytes)?;
+    out.extend_from_slice(&bytes);
+    Ok(bytes)
+}
+
+/// Reads `length` bytes and appends them to `out`.
+fn copy_exact<R: Read>(
+    reader: &mut R,
+    length: usize,
+    out: &mut Vec<u8>,
+) -> Result<(), NetDecodeError> {
+    if out.len() + length > MAX_NBT_SIZE {
+        return Err(ItemComponentError::NbtTooLarge.into());
+    }
+    let start = out.len();
+    out.resize(start + length, 0);
+    reader.read_exact(&mut out[start..])?;
+    Ok(())
+}
+
+/// Reads an array length, which has to fit in what's left of the size limit.
+fn copy_length<R: Read>(reader: &mut R, out: &mut Vec<u8>) -> Result<usize, NetDecodeError> {
+    let length = i32::from_be_bytes(copy_bytes(reader, out)?);
+    usize::try_from(length)
+        .ok()
+        .filter(|length| *length <= MAX_NBT_SIZE)
+        .ok_or_else(|| ItemComponentError::NbtTooLarge.into())
+}
+
+/// Copies the payload of an NBT tag of the given type.
+fn copy_nbt_payload<R: Read>(
+    reader: &mut R,
+    tag: u8,
+    out: &mut Vec<u8>,
+    depth: usize,
+) -> Result<(), NetDecodeError> {
+    if depth > MAX_NBT_DEPTH {
+        return Err(ItemComponentError::NbtTooLarge.into());
+    }
+    match tag {
+        // End, the whole value when there is none
+        0 => {}
+        // Byte, short, int, long, float and double
+        1 => copy_exact(reader, 1, out)?,
+        2 => copy_exact(reader, 2, out)?,
+        3 | 5 => copy_exact(reader, 4, out)?,
+        4 | 6 => copy_exact(reader, 8, out)?,
+        // Byte array
+        7 => {
+            let length = copy_length(reader, out)?;
+            copy_exact(reader, length, out)?;
+        }
+        // String
+        8 => {
+            let length = u16::from_be_bytes(copy_bytes(reader, out)?);
+            copy_exact(reader, length as usize, out)?;
+        }
+        // List
+        9 => {
+            let [item_tag] = copy_bytes(reader, out)?;
+            let length = copy_length(reader, out)?;
+            if item_tag == 0 && length > 0 {
+                return Err(ItemComponentError::InvalidNbtTag(item_tag).into());
+            }
+            for _ in 0..length {
+                copy_nbt_payload(reader, item_tag, out, depth + 1)?;
+            }
+        }
+        // Compound
+        10 => loop {
+            let [entry_tag] = copy_bytes(reader, out)?;
+            if entry_tag == 0 {
+                break;
+            }
+            let name_length = u16::from_be_bytes(copy_bytes(reader, out)?);
+            copy_exact(reader, name_length as usize, out)?;
+            copy_nbt_payload(reader, entry_tag, out, depth + 1)?;
+        },
+        // Int and long arrays
+        11 => {
+            let length = copy_length(reader, out)?;
+            copy_exact(reader, length * 4, out)?;
+        }
+        12 => {
+            let length = copy_length(reader, out)?;
+            copy_exact(reader, length * 8, out)?;
+        }
+        _ => return Err(ItemComponentError::InvalidNbtTag(tag).into()),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn roundtrip(component: &ItemComponent) -> ItemComponent {
+        let mut buffer = Vec::new();
+        component
+            .encode(&mut buffer, &NetEncodeOpts::None)
+            .expect("Encode failed");
+        let mut reader = Cursor::new(&buffer);
+        let decoded =
+            ItemComponent::decode(&mut reader, &NetDecodeOpts::None).expect("Decode failed");
+        assert_eq!(reader.position() as usize, buffer.len());
+        decoded
+    }
+
+    #[test]
+    fn test_component_roundtrip() {
+        // A compound holding a string, a list of ints and a nested compound
+        let nbt = RawNbt(vec![
+            10, 8, 0, 1, b'a', 0, 2, b'h', b'i', 9, 0, 1, b'b', 3, 0, 0, 0, 2, 0, 0, 0, 1, 0, 0, 0,
+            2, 10, 0, 1, b'c', 1, 0, 1, b'd', 5, 0, 0,
+        ]);
+        let components = [
+            ItemComponent::CustomData(nbt.clone()),
+            ItemComponent::Damage(VarInt::new(12)),
+            ItemComponent::Unbreakable,
+            ItemComponent::Lore(vec![nbt, RawNbt(vec![8, 0, 2, b'h', b'i'])]),
+            ItemComponent::Enchantments(vec![Enchantment {
+                id: VarInt::new(5),
+                level: VarInt::new(3),
+            }]),
+            ItemComponent::AttributeModifiers(vec![AttributeModifier {
+                attribute: VarInt::new(1),
+                id: "minecraft:base_attack_damage".to_string(),
+                amount: 6.0,
+                operation: VarInt::new(0),
+                slot: VarInt::new(1),
+                display: ModifierDisplay::Hidden,
+            }]),
+            ItemComponent::DyedColor(0xFF0000),
+            ItemComponent::UseCooldown {
+                seconds: 1.5,
+                cooldown_group: None,
+            },
+            ItemComponent::PotionContents(PotionContents {
+                potion: Some(VarInt::new(3)),
+                custom_color: None,
+                custom_effects: vec![PotionEffect {
+                    effect: VarInt::new(1),
+                    details: vec![
+                        EffectDetails {
+                            amplifier: VarInt::new(1),
+                            duration: VarInt::new(200),
+                            ambient: false,
+                            show_particles: true,
+                            show_icon: true,
+                        },
+                        EffectDetails {
+                            amplifier: VarInt::new(0),
+                            duration: VarInt::new(600),
+                            ambient: false,
+                            show_particles: true,
+                            show_icon: true,
+                        },
+                    ],
+                }],
+                custom_name: Some("test".to_string()),
+            }),
+        ];
+        for component in &components {
+            assert_eq!(&roundtrip(component), component);
+        }
+
+        let decoded: Vec<ItemComponent> =
+            bitcode::decode(&bitcode::encode(&components.to_vec())).unwrap();
+        assert_eq!(decoded, components);
+    }
+
+    #[test]
+    fn test_other_components_roundtrip() {
+        let profile: &[u8] = &[61, 1, 5, b'S', b't', b'e', b'v', b'e', 0, 0];
+        let components: [&[u8]; 4] = [
+            profile,
+            // block_state with facing=east
+            &[
+                67, 1, 6, b'f', b'a', b'c', b'i', b'n', b'g', 4, b'e', b'a', b's', b't',
+            ],
+            // can_place_on with a single block
+            &[11, 1, 1, 2, 1, 0, 0, 0, 0],
+            // container holding a player head
+            &[&[66, 1, 1, 5, 1, 0], profile].concat(),
+        ];
+        for bytes in components {
+            let mut reader = Cursor::new(bytes);
+            let component =
+                ItemComponent::decode(&mut reader, &NetDecodeOpts::None).expect("Decode failed");
+            assert_eq!(reader.position() as usize, bytes.len());
+            assert!(matches!(component, ItemComponent::Other { id, .. } if id == bytes[0] as i32));
+
+            let mut buffer = Vec::new();
+            component
+                .encode(&mut buffer, &NetEncodeOpts::None)
+                .expect("Encode failed");
+            assert_eq!(buffer, bytes);
+        }
+    }
+
+    #[test]
+    fn test_nesting_limit() {
+        // A container holding a container, and so on, with an empty one at the bottom
+        let nested = |depth: usize| {
+            let mut bytes = vec![66, 0];
+            for _ in 0..depth {
+                bytes = [&[66, 1, 1, 5, 1, 0], bytes.as_slice()].concat();
+            }
+            bytes
+        };
+        let decode =
+            |bytes: Vec<u8>| ItemComponent::decode(&mut Cursor::new(bytes), &NetDecodeOpts::None);
+        assert!(decode(nested(MAX_ITEM_DEPTH)).is_ok());
+        assert!(decode(nested(MAX_ITEM_DEPTH + 1)).is_err());
+    }
+
+    #[test]
+    fn test_unknown_and_invalid() {
+        // There's no component with this ID
+        let mut reader = Cursor::new([96u8, 0]);
+        assert!(ItemComponent::decode(&mut reader, &NetDecodeOpts::None).is_err());
+
+        // Unknown tag type
+        let mut reader = Cursor::new([0u8, 13]);
+        assert!(ItemComponent::decode(&mut reader, &NetDecodeOpts::None).is_err());
+
+        // Truncated string
+        let mut reader = Cursor::new([0u8, 8, 0, 5, b'a']);
+        assert!(ItemComponent::decode(&mut reader, &NetDecodeOpts::None).is_err());
+    }
+}
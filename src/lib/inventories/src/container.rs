@@ -0,0 +1,140 @@
+//! Windows players open by using blocks, like enchanting tables.
+//!
+//! A player has at most one of these open at a time, as an [`OpenContainer`]. The window's own
+//! slots come first, followed by the player's main inventory and then their hotbar.
+
+use crate::enchanting::{AnvilResult, EnchantingOffer};
+use crate::slot::InventorySlot;
+use bevy_ecs::prelude::Component;
+use ferrumc_world::pos::BlockPos;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// Like vanilla, window IDs go from 1 up to this and then start over, so packets for a window that
+/// was just closed aren't mistaken for ones for the next.
+const MAX_WINDOW_ID: u8 = 100;
+
+static NEXT_WINDOW_ID: AtomicU8 = AtomicU8::new(1);
+
+/// How many slots of the player's inventory are shown under a window: the main inventory and the
+/// hotbar.
+const PLAYER_SLOTS: usize = 36;
+
+/// The first slot of the main inventory in the player's own inventory window.
+const FIRST_MAIN_SLOT: usize = 9;
+
+/// The item a player is holding with their cursor while they have a window open.
+#[derive(Component, Debug, Clone, Default)]
+pub struct CarriedItem(pub InventorySlot);
+
+/// What a window does, along with the state it needs.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Menu {
+    EnchantingTable {
+        /// Bookshelves around the table, up to the 15 that count.
+        bookshelves: u32,
+        offers: [EnchantingOffer; 3],
+    },
+    Anvil {
+        /// The name typed in, or `None` if the field is empty.
+        name: Option<String>,
+        result: Option<AnvilResult>,
+    },
+    Grindstone,
+}
+
+impl Menu {
+    /// The menu a block opens, by its registry name.
+    pub fn for_block(name: &str) -> Option<Self> {
+        match name.strip_prefix("minecraft:").unwrap_or(name) {
+            "enchanting_table" => Some(Self::EnchantingTable {
+                bookshelves: 0,
+                offers: Default::default(),
+            }),
+            "anvil" | "chipped_anvil" | "damaged_anvil" => Some(Self::Anvil {
+                name: None,
+                result: None,
+            }),
+            "grindstone" => Some(Self::Grindstone),
+            _ => None,
+        }
+    }
+
+    /// The protocol ID of the menu type, from the `minecraft:menu` registry.
+    pub fn menu_type(&self) -> i32 {
+        match self {
+            Self::Anvil { .. } => 8,
+            Self::EnchantingTable { .. } => 13,
+            Self::Grindstone => 15,
+        }
+    }
+
+    /// The translation key of the window's title.
+    pub fn title(&self) -> &'static str {
+        match self {
+            Self::EnchantingTable { .. } => "container.enchant",
+            Self::Anvil { .. } => "container.repair",
+            Self::Grindstone => "container.grindstone_title",
+        }
+    }
+
+    /// How many slots the window has of its own.
+    pub fn slot_count(&self) -> usize {
+        match self {
+            Self::EnchantingTable { .. } => 2,
+            Self::Anvil { .. } | Self::Grindstone => 3,
+        }
+    }
+
+    /// The slot the result is taken out of, if the window makes something.
+    pub fn output_slot(&self) -> Option<usize> {
+        match self {
+            Self::EnchantingTable { .. } => None,
+            Self::Anvil { .. } | Self::Grindstone => Some(2),
+        }
+    }
+}
+
+/// The window a player has open.
+#[derive(Component, Debug, Clone)]
+pub struct OpenContainer {
+    pub window_id: u8,
+    /// The block the window belongs to.
+    pub position: BlockPos,
+    pub menu: Menu,
+    /// The window's own slots. Items left in them go back to the player when it's closed.
+    pub slots: Vec<InventorySlot>,
+}
+
+impl OpenContainer {
+    pub fn new(position: BlockPos, menu: Menu) -> Self {
+        let window_id = NEXT_WINDOW_ID
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |id| {
+                Some(id % MAX_WINDOW_ID + 1)
+            })
+            .unwrap_or(1);
+        Self {
+            window_id,
+            position,
+            slots: vec![InventorySlot::empty(); menu.slot_count()],
+            menu,
+        }
+    }
+
+    /// The slot of the player's inventory a window slot shows, or `None` if it's one of the
+    /// window's own.
+    pub fn player_slot(&self, window_slot: usize) -> Option<usize> {
+        let index = window_slot.checked_sub(self.slots.len())?;
+        (index < PLAYER_SLOTS).then_some(index + FIRST_MAIN_SLOT)
+    }
+
+    /// The window slot showing a slot of the player's inventory, if it's shown.
+    pub fn window_slot(&self, player_slot: usize) -> Option<usize> {
+        let index = player_slot.checked_sub(FIRST_MAIN_SLOT)?;
+        (index < PLAYER_SLOTS).then_some(index + self.slots.len())
+    }
+
+    /// How many slots the window has, including the player's.
+    pub fn total_slots(&self) -> usize {
+        self.slots.len() + PLAYER_SLOTS
+    }
+}
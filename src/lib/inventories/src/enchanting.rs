@@ -0,0 +1,852 @@
+//! Enchanting items.
+//!
+//! Enchanting tables offer three sets of random enchantments. They're picked with a seed each
+//! player has, so the offers for an item stay the same until the player enchants something.
+//! Anvils combine items with enchanted books or with each other, repair them with their repair
+//! material and rename them. Grindstones take off every enchantment but curses. Costs and chances
+//! follow vanilla.
+
+use crate::components::{self, RawNbt};
+use crate::item::ItemID;
+use crate::slot::InventorySlot;
+use ferrumc_data::enchantments::Enchantment;
+use ferrumc_data::items::{DataComponent, Item};
+use ferrumc_data::tags::TagData;
+use ferrumc_net_codec::net_types::var_int::VarInt;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// The most bookshelves that make an enchanting table's offers better.
+pub const MAX_BOOKSHELVES: u32 = 15;
+
+/// The cost at which anvils refuse to work, outside of creative mode.
+pub const TOO_EXPENSIVE: i32 = 40;
+
+/// The longest name an item can be given in an anvil.
+pub const MAX_NAME_LENGTH: usize = 50;
+
+/// An enchantment with its level.
+pub type EnchantmentLevel = (&'static Enchantment, i32);
+
+/// Whether an item is in an item tag like `#minecraft:enchantable/sword`, or is the given item.
+pub fn item_matches(items: &str, item: &Item) -> bool {
+    match items.strip_prefix('#') {
+        Some(tag) => {
+            TagData::get_item_tag(tag).is_some_and(|tag| tag.values.contains(&item.registry_key))
+        }
+        None => items == item.registry_key,
+    }
+}
+
+fn enchantment_in_tag(tag: &str, enchantment: &Enchantment) -> bool {
+    let tag = tag.strip_prefix('#').unwrap_or(tag);
+    TagData::get_enchantment_tag(tag).is_some_and(|tag| tag.values.contains(&enchantment.name))
+}
+
+/// Whether an enchantment can be put on an item.
+pub fn can_enchant(enchantment: &Enchantment, item: &Item) -> bool {
+    item_matches(enchantment.supported_items, item)
+}
+
+/// Whether two different enchantments can be on the same item, i.e. neither is in the other's
+/// exclusive set.
+pub fn are_compatible(a: &Enchantment, b: &Enchantment) -> bool {
+    a.id != b.id
+        && !a
+            .exclusive_set
+            .is_some_and(|set| enchantment_in_tag(set, b))
+        && !b
+            .exclusive_set
+            .is_some_and(|set| enchantment_in_tag(set, a))
+}
+
+pub fn is_curse(enchantment: &Enchantment) -> bool {
+    enchantment_in_tag("minecraft:curse", enchantment)
+}
+
+/// Looks up the enchantments on an item, skipping any with an unknown ID.
+pub fn resolve(enchantments: &[components::Enchantment]) -> Vec<EnchantmentLevel> {
+    enchantments
+        .iter()
+        .filter_map(|applied| {
+            let enchantment = u16::try_from(applied.id.0)
+                .ok()
+                .and_then(Enchantment::from_id)?;
+            Some((enchantment, applied.level.0))
+        })
+        .collect()
+}
+
+fn to_components(enchantments: &[EnchantmentLevel]) -> Vec<components::Enchantment> {
+    enchantments
+        .iter()
+        .map(|(enchantment, level)| components::Enchantment {
+            id: VarInt::new(enchantment.id as i32),
+            level: VarInt::new(*level),
+        })
+        .collect()
+}
+
+fn is_item(stack: &InventorySlot, item: &Item) -> bool {
+    stack.item().is_some_and(|stack_item| stack_item == item)
+}
+
+/// The enchantments anvils and grindstones work with: the stored ones of enchanted books, and
+/// the applied ones of everything else.
+fn enchantments_of(stack: &InventorySlot) -> Vec<EnchantmentLevel> {
+    if is_item(stack, &Item::ENCHANTED_BOOK) {
+        resolve(stack.stored_enchantments())
+    } else {
+        resolve(stack.enchantments())
+    }
+}
+
+fn set_enchantments_of(stack: &mut InventorySlot, enchantments: &[EnchantmentLevel]) {
+    if is_item(stack, &Item::ENCHANTED_BOOK) {
+        stack.set_stored_enchantments(to_components(enchantments));
+    } else {
+        stack.set_enchantments(to_components(enchantments));
+    }
+}
+
+fn is_damageable(stack: &InventorySlot) -> bool {
+    stack.max_damage().is_some() && !stack.is_unbreakable()
+}
+
+/// The repair cost an item gets after being worked on again.
+fn increased_repair_cost(cost: i32) -> i32 {
+    cost.saturating_mul(2).saturating_add(1)
+}
+
+/// One of the three options an enchanting table offers.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct EnchantingOffer {
+    /// The level the player needs, or 0 if the option isn't available.
+    pub cost: u32,
+    /// One of the enchantments the option gives, shown to the player as a hint.
+    pub hint: Option<EnchantmentLevel>,
+}
+
+/// Whether an enchanting table can enchant the item.
+pub fn can_enchant_at_table(stack: &InventorySlot) -> bool {
+    stack.item().is_some() && stack.enchantability() > 0 && stack.enchantments().is_empty()
+}
+
+/// The options an enchanting table with the given number of bookshelves around it offers for an
+/// item.
+pub fn enchanting_offers(
+    stack: &InventorySlot,
+    bookshelves: u32,
+    seed: i32,
+) -> [EnchantingOffer; 3] {
+    let mut offers = [EnchantingOffer::default(); 3];
+    if !can_enchant_at_table(stack) {
+        return offers;
+    }
+
+    let bookshelves = bookshelves.min(MAX_BOOKSHELVES);
+    let mut rng = StdRng::seed_from_u64(seed as u32 as u64);
+    for (option, offer) in offers.iter_mut().enumerate() {
+        let roll = rng.gen_range(1..=8) + (bookshelves >> 1) + rng.gen_range(0..=bookshelves);
+        let cost = match option {
+            0 => (roll / 3).max(1),
+            1 => roll * 2 / 3 + 1,
+            _ => roll.max(bookshelves * 2),
+        };
+        // The first option needs at least 1 level, the second 2 and the third 3
+        offer.cost = if cost < option as u32 + 1 { 0 } else { cost };
+    }
+
+    for (option, offer) in offers.iter_mut().enumerate() {
+        if offer.cost == 0 {
+            continue;
+        }
+        let enchantments = table_enchantments(stack, seed, option, offer.cost);
+        if !enchantments.is_empty() {
+            offer.hint = Some(enchantments[rng.gen_range(0..enchantments.len())]);
+        }
+    }
+    offers
+}
+
+/// The enchantments an option of an enchanting table gives. The same arguments always give the
+/// same enchantments.
+pub fn table_enchantments(
+    stack: &InventorySlot,
+    seed: i32,
+    option: usize,
+    cost: u32,
+) -> Vec<EnchantmentLevel> {
+    let Some(item) = stack.item() else {
+        return Vec::new();
+    };
+    let mut rng = StdRng::seed_from_u64((seed as u32 as u64) << 2 | option as u64);
+    let mut enchantments = select_enchantments(item, stack.enchantability(), cost, &mut rng);
+
+    // Books only get one enchantment less, since they can be combined onto anything
+    if item == &Item::BOOK && enchantments.len() > 1 {
+        enchantments.remove(rng.gen_range(0..enchantments.len()));
+    }
+    enchantments
+}
+
+fn select_enchantments(
+    item: &Item,
+    enchantability: i32,
+    cost: u32,
+    rng: &mut impl Rng,
+) -> Vec<EnchantmentLevel> {
+    if enchantability <= 0 {
+        return Vec::new();
+    }
+
+    // The cost is randomly raised depending on how enchantable the item is
+    let quarter = enchantability / 4;
+    let level = cost as i32 + 1 + rng.gen_range(0..=quarter) + rng.gen_range(0..=quarter);
+    let bonus = (rng.r#gen::<f32>() + rng.r#gen::<f32>() - 1.0) * 0.15;
+    let mut level = ((level as f32 + level as f32 * bonus).round() as i32).max(1);
+
+    let mut available = available_enchantments(item, level);
+    let mut selected = Vec::new();
+    let Some(first) = pick_weighted(&available, rng) else {
+        return selected;
+    };
+    selected.push(first);
+
+    // Every extra enchantment gets less likely
+    while rng.gen_range(0..50) <= level {
+        let (last, _) = selected[selected.len() - 1];
+        available.retain(|(enchantment, _)| are_compatible(enchantment, last));
+        let Some(next) = pick_weighted(&available, rng) else {
+            break;
+        };
+        selected.push(next);
+        level /= 2;
+    }
+    selected
+}
+
+/// The enchantments an enchanting table can give an item at a level, each with the highest level
+/// of it that can be given.
+fn available_enchantments(item: &Item, level: i32) -> Vec<EnchantmentLevel> {
+    let is_book = item == &Item::BOOK;
+    let Some(table) = TagData::get_enchantment_tag("minecraft:in_enchanting_table") else {
+        return Vec::new();
+    };
+
+    table
+        .values
+        .iter()
+        .filter_map(|name| Enchantment::from_name(name))
+        .filter(|enchantment| {
+            is_book
+                || item_matches(
+                    enchantment
+                        .primary_items
+                        .unwrap_or(enchantment.supported_items),
+                    item,
+                )
+        })
+        .filter_map(|enchantment| {
+            (1..=enchantment.max_level)
+                .rev()
+                .find(|&enchantment_level| {
+                    let min = enchantment.min_cost(enchantment_level) as i32;
+                    let max = enchantment.max_cost(enchantment_level) as i32;
+                    (min..=max).contains(&level)
+                })
+                .map(|enchantment_level| (enchantment, enchantment_level as i32))
+        })
+        .collect()
+}
+
+fn pick_weighted(
+    enchantments: &[EnchantmentLevel],
+    rng: &mut impl Rng,
+) -> Option<EnchantmentLevel> {
+    let total: u32 = enchantments
+        .iter()
+        .map(|(enchantment, _)| enchantment.weight as u32)
+        .sum();
+    if total == 0 {
+        return None;
+    }
+    let mut roll = rng.gen_range(0..total);
+    for &(enchantment, level) in enchantments {
+        if roll < enchantment.weight as u32 {
+            return Some((enchantment, level));
+        }
+        roll -= enchantment.weight as u32;
+    }
+    None
+}
+
+/// Puts enchantments from an enchanting table on an item. Books turn into enchanted books.
+pub fn apply_table_enchantments(
+    stack: &InventorySlot,
+    enchantments: &[EnchantmentLevel],
+) -> InventorySlot {
+    let mut enchanted = stack.clone();
+    if is_item(stack, &Item::BOOK) {
+        enchanted.item_id = Some(ItemID::new(Item::ENCHANTED_BOOK.id as i32));
+    }
+    set_enchantments_of(&mut enchanted, enchantments);
+    enchanted
+}
+
+/// What an anvil makes out of two items.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnvilResult {
+    /// The item the player can take. Empty if it's too expensive.
+    pub output: InventorySlot,
+    /// How many levels it costs.
+    pub cost: i32,
+    /// How much of the right item is used up, if it's being used to repair the left one.
+    /// Otherwise all of it is.
+    pub material_used: Option<i32>,
+}
+
+/// Works out what an anvil makes out of the two items and what it costs, or `None` if it can't
+/// do anything with them.
+///
+/// `name` is the name typed into the anvil serialized as network NBT, or `None` if the field is
+/// empty, which removes the custom name of the item. Players in creative mode can put any
+/// enchantment on anything, and nothing is too expensive for them.
+pub fn anvil(
+    left: &InventorySlot,
+    right: &InventorySlot,
+    name: Option<&RawNbt>,
+    creative: bool,
+) -> Option<AnvilResult> {
+    let left_item = left.item()?;
+    let mut output = left.clone();
+    let mut enchantments = enchantments_of(left);
+    let mut cost = 0;
+    let mut base_cost = left.repair_cost();
+    let mut material_used = None;
+
+    if let Some(right_item) = right.item() {
+        base_cost = base_cost.saturating_add(right.repair_cost());
+        let is_book =
+            right_item == &Item::ENCHANTED_BOOK && !right.stored_enchantments().is_empty();
+        let max_damage = left.max_damage().unwrap_or(0);
+
+        let repairs = left_item
+            .repair_items()
+            .is_some_and(|items| item_matches(items, right_item));
+        if is_damageable(left) && repairs {
+            // Each of the material repairs a quarter of the durability
+            let mut repair = output.damage().min(max_damage / 4);
+            if repair <= 0 {
+                return None;
+            }
+            let mut used = 0;
+            while repair > 0 && used < right.count.0 {
+                output.set_damage(output.damage() - repair);
+                cost += 1;
+                repair = output.damage().min(max_damage / 4);
+                used += 1;
+            }
+            material_used = Some(used);
+        } else {
+            if !is_book && (left.item_id != right.item_id || !is_damageable(left)) {
+                return None;
+            }
+
+            // Two of the same item add up their durability, plus a bonus
+            if is_damageable(left) && !is_book {
+                let left_durability = max_damage - left.damage();
+                let right_durability = right.max_damage().unwrap_or(0) - right.damage();
+                let durability = left_durability + right_durability + max_damage * 12 / 100;
+                let damage = (max_damage - durability).max(0);
+                if damage < output.damage() {
+                    output.set_damage(damage);
+                    cost += 2;
+                }
+            }
+
+            let mut any_applied = false;
+            let mut any_rejected = false;
+            for (enchantment, level) in enchantments_of(right) {
+                let current = enchantments
+                    .iter()
+                    .find(|(existing, _)| existing.id == enchantment.id)
+                    .map_or(0, |(_, level)| *level);
+                // Two of the same level make the next one up
+                let level = if current == level {
+                    level + 1
+                } else {
+                    level.max(current)
+                };
+
+                let mut applies = creative
+                    || left_item == &Item::ENCHANTED_BOOK
+                    || can_enchant(enchantment, left_item);
+                for (existing, _) in &enchantments {
+                    if existing.id != enchantment.id && !are_compatible(enchantment, existing) {
+                        applies = false;
+                        cost += 1;
+                    }
+                }
+                if !applies {
+                    any_rejected = true;
+                    continue;
+                }
+                any_applied = true;
+
+                let level = level.min(enchantment.max_level as i32);
+                match enchantments
+                    .iter_mut()
+                    .find(|(existing, _)| existing.id == enchantment.id)
+                {
+                    Some((_, existing_level)) => *existing_level = level,
+                    None => enchantments.push((enchantment, level)),
+                }
+
+                // Books are cheaper, since they can't be used again
+                let mut per_level = enchantment.anvil_cost as i32;
+                if is_book {
+                    per_level = (per_level / 2).max(1);
+                }
+                cost += per_level * level;
+                if left.count.0 > 1 {
+                    cost = TOO_EXPENSIVE;
+                }
+            }
+            if any_rejected && !any_applied {
+                return None;
+            }
+        }
+    }
+
+    let mut rename_cost = 0;
+    match name {
+        None if left.custom_name().is_some() => {
+            rename_cost = 1;
+            output.reset_component(DataComponent::CustomName);
+        }
+        Some(name) if left.custom_name() != Some(name) => {
+            rename_cost = 1;
+            output.set_custom_name(name.clone());
+        }
+        _ => {}
+    }
+    cost += rename_cost;
+    if cost <= 0 {
+        return None;
+    }
+
+    let mut total = base_cost.saturating_add(cost);
+    // Just renaming is never too expensive
+    if rename_cost == cost && total >= TOO_EXPENSIVE {
+        total = TOO_EXPENSIVE - 1;
+    }
+    if total >= TOO_EXPENSIVE && !creative {
+        return Some(AnvilResult {
+            output: InventorySlot::empty(),
+            cost: total,
+            material_used,
+        });
+    }
+
+    let mut repair_cost = output.repair_cost().max(right.repair_cost());
+    if rename_cost != cost {
+        repair_cost = increased_repair_cost(repair_cost);
+    }
+    output.set_repair_cost(repair_cost);
+    set_enchantments_of(&mut output, &enchantments);
+
+    Some(AnvilResult {
+        output,
+        cost: total,
+        material_used,
+    })
+}
+
+/// Works out what a grindstone makes out of the two items, or `None` if it can't do anything with
+/// them. Two of the same item are combined, and every enchantment but curses is taken off.
+pub fn grindstone(top: &InventorySlot, bottom: &InventorySlot) -> Option<InventorySlot> {
+    if top.count.0 > 1 || bottom.count.0 > 1 {
+        return None;
+    }
+    match (top.item(), bottom.item()) {
+        (Some(top_item), Some(bottom_item)) => {
+            if top_item != bottom_item {
+                return None;
+            }
+            let mut output = top.clone();
+            if is_damageable(top) {
+                let max_damage = top.max_damage().max(bottom.max_damage()).unwrap_or(0);
+                let durability = (max_damage - top.damage())
+                    + (max_damage - bottom.damage())
+                    + max_damage * 5 / 100;
+                output.set_damage((max_damage - durability).max(0));
+            } else {
+                if top_item.max_stack_size() < 2 || !top.stacks_with(bottom) {
+                    return None;
+                }
+                output.count = VarInt::new(2);
+            }
+
+            let mut enchantments = enchantments_of(top);
+            for (enchantment, level) in enchantments_of(bottom) {
+                match enchantments
+                    .iter_mut()
+                    .find(|(existing, _)| existing.id == enchantment.id)
+                {
+                    Some((_, existing)) if !is_curse(enchantment) => {
+                        *existing = level.max(*existing)
+                    }
+                    Some(_) => {}
+                    None => enchantments.push((enchantment, level)),
+                }
+            }
+            set_enchantments_of(&mut output, &enchantments);
+            Some(remove_non_curses(output))
+        }
+        (Some(_), None) | (None, Some(_)) => {
+            let stack = if top.item().is_some() { top } else { bottom };
+            if stack.enchantments().is_empty() && stack.stored_enchantments().is_empty() {
+                return None;
+            }
+            Some(remove_non_curses(stack.clone()))
+        }
+        (None, None) => None,
+    }
+}
+
+fn remove_non_curses(mut stack: InventorySlot) -> InventorySlot {
+    let curses = |enchantments: &[components::Enchantment]| {
+        let curses: Vec<_> = resolve(enchantments)
+            .into_iter()
+            .filter(|(enchantment, _)| is_curse(enchantment))
+            .collect();
+        to_components(&curses)
+    };
+    let applied = curses(stack.enchantments());
+    let stored = curses(stack.stored_enchantments());
+    let remaining = applied.len().max(stored.len());
+    stack.set_enchantments(applied);
+    stack.set_stored_enchantments(stored);
+
+    if is_item(&stack, &Item::ENCHANTED_BOOK) && stack.stored_enchantments().is_empty() {
+        stack.item_id = Some(ItemID::new(Item::BOOK.id as i32));
+    }
+
+    // The repair cost only counts the curses left on the item
+    let mut repair_cost = 0;
+    for _ in 0..remaining {
+        repair_cost = increased_repair_cost(repair_cost);
+    }
+    stack.set_repair_cost(repair_cost);
+    stack
+}
+
+/// The experience a grindstone gives back for the enchantments it takes off the items.
+pub fn grindstone_experience(
+    top: &InventorySlot,
+    bottom: &InventorySlot,
+    rng: &mut impl Rng,
+) -> i32 {
+    let total: i32 = [top, bottom]
+        .into_iter()
+        .flat_map(enchantments_of)
+        .filter(|(enchantment, _)| !is_curse(enchantment))
+        .map(|(enchantment, level)| {
+            enchantment.min_cost(level.clamp(1, u8::MAX as i32) as u8) as i32
+        })
+        .sum();
+    if total <= 0 {
+        return 0;
+    }
+    let half = (total + 1) / 2;
+    half + rng.gen_range(0..half)
+}
+
+/// Damages an item by `amount`, if it can be damaged. Unbreaking gives each point a chance to be
+/// ignored, which is lower for armor.
+///
+/// Returns whether the item broke, in which case the caller should remove it.
+pub fn hurt_item(stack: &mut InventorySlot, amount: i32, armor: bool, rng: &mut impl Rng) -> bool {
+    let Some(max_damage) = stack.max_damage() else {
+        return false;
+    };
+    if stack.is_unbreakable() || amount <= 0 {
+        return false;
+    }
+
+    let unbreaking = stack
+        .enchantment_level(Enchantment::UNBREAKING.id as i32)
+        .max(0) as f64;
+    let ignore_chance = if armor {
+        0.4 * unbreaking / (unbreaking + 1.0)
+    } else {
+        unbreaking / (unbreaking + 1.0)
+    };
+    let lost = (0..amount).filter(|_| !rng.gen_bool(ignore_chance)).count() as i32;
+    if lost == 0 {
+        return false;
+    }
+
+    let damage = stack.damage() + lost;
+    if damage >= max_damage {
+        return true;
+    }
+    stack.set_damage(damage);
+    false
+}
+
+/// Whether an item can be repaired with XP: it has mending and is damaged.
+pub fn can_mend(stack: &InventorySlot) -> bool {
+    stack.damage() > 0 && stack.enchantment_level(Enchantment::MENDING.id as i32) > 0
+}
+
+/// Repairs an item with mending using some XP, two durability for each point like vanilla.
+///
+/// Returns the XP that's left over.
+pub fn mend_item(stack: &mut InventorySlot, xp: u32) -> u32 {
+    if !can_mend(stack) {
+        return xp;
+    }
+    let repaired = (xp as i32).saturating_mul(2).min(stack.damage());
+    stack.set_damage(stack.damage() - repaired);
+    xp - repaired as u32 / 2
+}
+
+/// The extra attack damage from the enchantments on a weapon, which only counts the ones without
+/// a requirement on what's being hit.
+pub fn attack_damage_bonus(weapon: &InventorySlot) -> f32 {
+    resolve(weapon.enchantments())
+        .into_iter()
+        .flat_map(|(enchantment, level)| {
+            let level = level.clamp(1, u8::MAX as i32) as u8;
+            enchantment
+                .damage
+                .iter()
+                .filter(|bonus| bonus.requirement.is_none())
+                .map(move |bonus| bonus.amount.calculate(level))
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stack(item: &Item) -> InventorySlot {
+        InventorySlot {
+            count: VarInt::new(1),
+            item_id: Some(ItemID::new(item.id as i32)),
+            ..Default::default()
+        }
+    }
+
+    fn enchanted(item: &Item, enchantments: &[EnchantmentLevel]) -> InventorySlot {
+        let mut stack = stack(item);
+        set_enchantments_of(&mut stack, enchantments);
+        stack
+    }
+
+    #[test]
+    fn test_compatibility() {
+        assert!(!are_compatible(
+            &Enchantment::SHARPNESS,
+            &Enchantment::SMITE
+        ));
+        assert!(!are_compatible(
+            &Enchantment::SHARPNESS,
+            &Enchantment::SHARPNESS
+        ));
+        assert!(are_compatible(
+            &Enchantment::SHARPNESS,
+            &Enchantment::UNBREAKING
+        ));
+        assert!(can_enchant(&Enchantment::SHARPNESS, &Item::DIAMOND_AXE));
+        assert!(!can_enchant(
+            &Enchantment::SHARPNESS,
+            &Item::DIAMOND_PICKAXE
+        ));
+        assert!(is_curse(&Enchantment::VANISHING_CURSE));
+    }
+
+    #[test]
+    fn test_table_offers_are_stable() {
+        let sword = stack(&Item::DIAMOND_SWORD);
+        for seed in 0..50 {
+            let offers = enchanting_offers(&sword, 15, seed);
+            assert_eq!(offers, enchanting_offers(&sword, 15, seed));
+            assert!(offers[2].cost >= 30);
+            for (option, offer) in offers.iter().enumerate() {
+                let enchantments = table_enchantments(&sword, seed, option, offer.cost);
+                assert!(!enchantments.is_empty());
+                let (hint, _) = offer.hint.unwrap();
+                assert!(
+                    enchantments
+                        .iter()
+                        .any(|(enchantment, _)| enchantment.id == hint.id)
+                );
+                for (enchantment, _) in &enchantments {
+                    assert!(can_enchant(enchantment, &Item::DIAMOND_SWORD));
+                }
+            }
+        }
+        // Enchanted items and dirt can't be enchanted
+        let sharp = enchanted(&Item::DIAMOND_SWORD, &[(&Enchantment::SHARPNESS, 1)]);
+        assert_eq!(enchanting_offers(&sharp, 15, 0)[0].cost, 0);
+        assert_eq!(enchanting_offers(&stack(&Item::DIRT), 15, 0)[0].cost, 0);
+    }
+
+    #[test]
+    fn test_books_become_enchanted_books() {
+        let book = stack(&Item::BOOK);
+        let offers = enchanting_offers(&book, 15, 7);
+        let enchantments = table_enchantments(&book, 7, 2, offers[2].cost);
+        let enchanted = apply_table_enchantments(&book, &enchantments);
+        assert!(is_item(&enchanted, &Item::ENCHANTED_BOOK));
+        assert_eq!(enchanted.stored_enchantments().len(), enchantments.len());
+        assert!(enchanted.enchantments().is_empty());
+    }
+
+    #[test]
+    fn test_anvil_combines_levels() {
+        let left = enchanted(&Item::DIAMOND_SWORD, &[(&Enchantment::SHARPNESS, 3)]);
+        let book = enchanted(&Item::ENCHANTED_BOOK, &[(&Enchantment::SHARPNESS, 3)]);
+        let result = anvil(&left, &book, None, false).unwrap();
+        assert_eq!(
+            result
+                .output
+                .enchantment_level(Enchantment::SHARPNESS.id as i32),
+            4
+        );
+        // Half of sharpness' anvil cost of 1, but at least 1, per level
+        assert_eq!(result.cost, 4);
+        assert_eq!(result.output.repair_cost(), 1);
+
+        // Smite can't go on a sword with sharpness
+        let smite = enchanted(&Item::ENCHANTED_BOOK, &[(&Enchantment::SMITE, 1)]);
+        assert!(anvil(&left, &smite, None, false).is_none());
+        // But the incompatible one still costs a level when there's something else on the book
+        let mixed = enchanted(
+            &Item::ENCHANTED_BOOK,
+            &[(&Enchantment::SMITE, 1), (&Enchantment::UNBREAKING, 1)],
+        );
+        let result = anvil(&left, &mixed, None, false).unwrap();
+        assert_eq!(
+            result
+                .output
+                .enchantment_level(Enchantment::SMITE.id as i32),
+            0
+        );
+        assert_eq!(
+            result
+                .output
+                .enchantment_level(Enchantment::UNBREAKING.id as i32),
+            1
+        );
+        assert_eq!(result.cost, 1 + 1);
+    }
+
+    #[test]
+    fn test_anvil_repairs_and_renames() {
+        let mut left = stack(&Item::DIAMOND_SWORD);
+        left.set_damage(1500);
+        let diamonds = InventorySlot {
+            count: VarInt::new(64),
+            ..stack(&Item::DIAMOND)
+        };
+        let result = anvil(&left, &diamonds, None, false).unwrap();
+        // Each diamond repairs a quarter of 1561
+        assert_eq!(result.material_used, Some(4));
+        assert_eq!(result.output.damage(), 0);
+        assert_eq!(result.cost, 4);
+        assert!(anvil(&stack(&Item::DIAMOND_SWORD), &diamonds, None, false).is_none());
+
+        let name = RawNbt(vec![8, 0, 4, b'n', b'a', b'm', b'e']);
+        let renamed = anvil(&left, &InventorySlot::empty(), Some(&name), false).unwrap();
+        assert_eq!(renamed.cost, 1);
+        assert_eq!(renamed.output.custom_name(), Some(&name));
+        // Renaming doesn't raise the repair cost
+        assert_eq!(renamed.output.repair_cost(), 0);
+        assert!(anvil(&renamed.output, &InventorySlot::empty(), Some(&name), false).is_none());
+        let unnamed = anvil(&renamed.output, &InventorySlot::empty(), None, false).unwrap();
+        assert_eq!(unnamed.output.custom_name(), None);
+    }
+
+    #[test]
+    fn test_anvil_too_expensive() {
+        let mut left = enchanted(&Item::DIAMOND_SWORD, &[(&Enchantment::SHARPNESS, 1)]);
+        left.set_repair_cost(39);
+        let book = enchanted(&Item::ENCHANTED_BOOK, &[(&Enchantment::UNBREAKING, 1)]);
+        let result = anvil(&left, &book, None, false).unwrap();
+        assert_eq!(result.cost, 40);
+        assert_eq!(result.output, InventorySlot::empty());
+        assert_ne!(
+            anvil(&left, &book, None, true).unwrap().output,
+            InventorySlot::empty()
+        );
+    }
+
+    #[test]
+    fn test_grindstone_keeps_curses() {
+        let sword = enchanted(
+            &Item::DIAMOND_SWORD,
+            &[
+                (&Enchantment::SHARPNESS, 5),
+                (&Enchantment::VANISHING_CURSE, 1),
+            ],
+        );
+        let output = grindstone(&sword, &InventorySlot::empty()).unwrap();
+        assert_eq!(output.enchantments().len(), 1);
+        assert_eq!(
+            output.enchantment_level(Enchantment::VANISHING_CURSE.id as i32),
+            1
+        );
+        assert_eq!(output.repair_cost(), 1);
+        assert!(grindstone(&stack(&Item::DIAMOND_SWORD), &InventorySlot::empty()).is_none());
+
+        let book = enchanted(&Item::ENCHANTED_BOOK, &[(&Enchantment::MENDING, 1)]);
+        let output = grindstone(&InventorySlot::empty(), &book).unwrap();
+        assert!(is_item(&output, &Item::BOOK));
+
+        let mut rng = StdRng::seed_from_u64(0);
+        // Sharpness V has a minimum cost of 45
+        let experience = grindstone_experience(&sword, &InventorySlot::empty(), &mut rng);
+        assert!((23..46).contains(&experience));
+    }
+
+    #[test]
+    fn test_grindstone_combines_durability() {
+        let mut top = stack(&Item::DIAMOND_SWORD);
+        top.set_damage(1000);
+        let mut bottom = stack(&Item::DIAMOND_SWORD);
+        bottom.set_damage(1000);
+        let output = grindstone(&top, &bottom).unwrap();
+        // 561 + 561 + 5% of 1561
+        assert_eq!(output.damage(), 1561 - 561 - 561 - 78);
+        assert!(grindstone(&top, &stack(&Item::IRON_SWORD)).is_none());
+    }
+
+    #[test]
+    fn test_sharpness_bonus() {
+        let sword = enchanted(&Item::DIAMOND_SWORD, &[(&Enchantment::SHARPNESS, 5)]);
+        assert_eq!(attack_damage_bonus(&sword), 3.0);
+        let smite = enchanted(&Item::DIAMOND_SWORD, &[(&Enchantment::SMITE, 5)]);
+        assert_eq!(attack_damage_bonus(&smite), 0.0);
+    }
+
+    #[test]
+    fn test_mending() {
+        let mut plain = stack(&Item::DIAMOND_PICKAXE);
+        plain.set_damage(10);
+        assert_eq!(mend_item(&mut plain, 3), 3);
+
+        let mut pickaxe = enchanted(&Item::DIAMOND_PICKAXE, &[(&Enchantment::MENDING, 1)]);
+        pickaxe.set_damage(10);
+        assert_eq!(mend_item(&mut pickaxe, 3), 0);
+        assert_eq!(pickaxe.damage(), 4);
+        // Only the XP needed for the rest of the damage is used up
+        assert_eq!(mend_item(&mut pickaxe, 7), 5);
+        assert_eq!(pickaxe.damage(), 0);
+        assert!(!can_mend(&pickaxe));
+    }
+}
@@ -1,3 +1,4 @@
+use ferrumc_net_codec::decode::errors::NetDecodeError;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -11,3 +12,25 @@ pub enum InventoryError {
     #[error("Outside maximum slot range of {0}: {1}")]
     OutsideMaxSlotRange(usize, usize),
 }
+
+#[derive(Error, Debug)]
+pub enum ItemComponentError {
+    #[error("Unknown item component: {0}")]
+    Unknown(i32),
+    #[error("Invalid NBT tag type: {0}")]
+    InvalidNbtTag(u8),
+    #[error("NBT data is too large or nested too deeply")]
+    NbtTooLarge,
+    #[error("Item component list is too long")]
+    TooLong,
+    #[error("Items are nested too deeply")]
+    TooDeep,
+    #[error("Item components can't be decoded asynchronously")]
+    AsyncDecode,
+}
+
+impl From<ItemComponentError> for NetDecodeError {
+    fn from(err: ItemComponentError) -> Self {
+        NetDecodeError::ExternalError(Box::new(err))
+    }
+}
@@ -77,6 +77,85 @@ impl Inventory {
         Err(InventoryError::InventoryFull)
     }
 
+    /// Puts as much of `item` as fits into the given slots, in order. Stacks of the same item are
+    /// topped up to `max_stack` before any empty slot is used, and updates are sent for every
+    /// changed slot.
+    ///
+    /// Returns how many items didn't fit.
+    pub fn insert_stack_with_update(
+        &mut self,
+        item: &InventorySlot,
+        max_stack: i32,
+        slots: impl Iterator<Item = usize> + Clone,
+        entity: Entity,
+    ) -> i32 {
+        let (remaining, changed) = self.insert_stack_into(item, max_stack, slots);
+        for index in changed {
+            INVENTORY_UPDATES_QUEUE.push(InventoryUpdate {
+                slot_index: index as u8,
+                slot: self.slots[index].clone().unwrap_or_default(),
+                entity,
+            });
+        }
+        remaining
+    }
+
+    /// Like [`Self::insert_stack_with_update`], but without sending updates, e.g. for players
+    /// that are leaving.
+    pub fn insert_stack(
+        &mut self,
+        item: &InventorySlot,
+        max_stack: i32,
+        slots: impl Iterator<Item = usize> + Clone,
+    ) -> i32 {
+        self.insert_stack_into(item, max_stack, slots).0
+    }
+
+    /// Returns how many items didn't fit and the slots that changed.
+    fn insert_stack_into(
+        &mut self,
+        item: &InventorySlot,
+        max_stack: i32,
+        slots: impl Iterator<Item = usize> + Clone,
+    ) -> (i32, Vec<usize>) {
+        let mut remaining = item.count.0;
+        let mut changed = Vec::new();
+
+        // Top up existing stacks first
+        for index in slots.clone() {
+            if remaining <= 0 {
+                break;
+            }
+            let Some(Some(existing)) = self.slots.get_mut(index) else {
+                continue;
+            };
+            if !existing.stacks_with(item) || existing.count.0 >= max_stack {
+                continue;
+            }
+            let moved = remaining.min(max_stack - existing.count.0);
+            existing.count.0 += moved;
+            remaining -= moved;
+            changed.push(index);
+        }
+
+        // Then fill empty slots
+        for index in slots {
+            if remaining <= 0 {
+                break;
+            }
+            let Some(slot @ None) = self.slots.get_mut(index) else {
+                continue;
+            };
+            let moved = remaining.min(max_stack);
+            let mut stack = item.clone();
+            stack.count.0 = moved;
+            *slot = Some(stack);
+            remaining -= moved;
+            changed.push(index);
+        }
+        (remaining, changed)
+    }
+
     pub fn set_item(&mut self, index: usize, item: InventorySlot) -> Result<(), InventoryError> {
         if index >= self.slots.len() {
             return Err(InventoryError::InvalidSlotIndex(index));
@@ -305,4 +384,34 @@ mod tests {
             Err(InventoryError::InvalidSlotIndex(2))
         ));
     }
+
+    #[test]
+    fn test_insert_stack_tops_up_before_filling() {
+        let mut inv = Inventory::new(3);
+        let mut partial = make_slot_with_id(5);
+        partial.count = VarInt::new(60);
+        inv.set_item(2, partial).unwrap();
+
+        let mut incoming = make_slot_with_id(5);
+        incoming.count = VarInt::new(10);
+        let left = inv.insert_stack_with_update(&incoming, 64, 0..3, Entity::PLACEHOLDER);
+
+        assert_eq!(left, 0);
+        assert_eq!(inv.get_item(2).unwrap().unwrap().count.0, 64);
+        assert_eq!(inv.get_item(0).unwrap().unwrap().count.0, 6);
+        assert!(inv.get_item(1).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_insert_stack_returns_leftover() {
+        let mut inv = Inventory::new(2);
+        inv.set_item(0, make_slot_with_id(1)).unwrap();
+
+        let mut incoming = make_slot_with_id(5);
+        incoming.count = VarInt::new(20);
+        let left = inv.insert_stack_with_update(&incoming, 16, 0..2, Entity::PLACEHOLDER);
+
+        assert_eq!(left, 4);
+        assert_eq!(inv.get_item(1).unwrap().unwrap().count.0, 16);
+    }
 }
@@ -1,5 +1,8 @@
+pub mod components;
+pub mod container;
 pub mod crafting;
 pub mod defined_slots;
+pub mod enchanting;
 pub mod errors;
 pub mod hotbar;
 pub mod inventory;
@@ -1,5 +1,8 @@
+use crate::components::{Enchantment, ItemComponent, RawNbt};
+use crate::errors::ItemComponentError;
 use crate::item::ItemID;
 use bitcode_derive::{Decode, Encode};
+use ferrumc_data::items::{DataComponent, Item};
 use ferrumc_net_codec::decode::errors::NetDecodeError;
 use ferrumc_net_codec::decode::{NetDecode, NetDecodeOpts};
 use ferrumc_net_codec::encode::errors::NetEncodeError;
@@ -9,14 +12,17 @@ use std::fmt::Display;
 use std::io::{Read, Write};
 use tokio::io::{AsyncRead, AsyncWrite};
 
-#[derive(Debug, Clone, Hash, Default, PartialEq, Decode, Encode)]
+/// The most components a slot may add or remove. There are fewer component types than this.
+const MAX_COMPONENTS: i32 = 256;
+
+#[derive(Debug, Clone, Default, PartialEq, Decode, Encode)]
 pub struct InventorySlot {
     pub count: VarInt,
     pub item_id: Option<ItemID>,
-    pub components_to_add_count: Option<VarInt>,
-    pub components_to_remove_count: Option<VarInt>,
-    pub components_to_add: Option<Vec<VarInt>>,
-    pub components_to_remove: Option<Vec<VarInt>>,
+    /// Components the item has on top of the defaults of its item type, or instead of them.
+    pub components_to_add: Vec<ItemComponent>,
+    /// Protocol IDs of default components the item doesn't have.
+    pub components_to_remove: Vec<VarInt>,
     // https://minecraft.wiki/w/Java_Edition_protocol/Slot_data
 }
 
@@ -25,64 +31,260 @@ impl InventorySlot {
         Self {
             count: VarInt(0),
             item_id: None,
-            components_to_add_count: None,
-            components_to_add: None,
-            components_to_remove: None,
-            components_to_remove_count: None,
+            components_to_add: Vec::new(),
+            components_to_remove: Vec::new(),
+        }
+    }
+
+    /// Whether this slot and `other` hold the same item with the same components, so they can be
+    /// combined into one stack. Counts are ignored.
+    pub fn stacks_with(&self, other: &InventorySlot) -> bool {
+        let same_items = |a: &[ItemComponent], b: &[ItemComponent]| {
+            a.len() == b.len() && a.iter().all(|component| b.contains(component))
+        };
+        let same_ids =
+            |a: &[VarInt], b: &[VarInt]| a.len() == b.len() && a.iter().all(|id| b.contains(id));
+        self.item_id.is_some()
+            && self.item_id == other.item_id
+            && same_items(&self.components_to_add, &other.components_to_add)
+            && same_ids(&self.components_to_remove, &other.components_to_remove)
+    }
+
+    /// The vanilla definition of the item, which holds its default components.
+    pub fn item(&self) -> Option<&'static Item> {
+        Item::from_id(self.item_id?.as_u32() as u16)
+    }
+
+    /// Gets a component added to the item.
+    pub fn component(&self, component: DataComponent) -> Option<&ItemComponent> {
+        self.components_to_add
+            .iter()
+            .find(|added| added.id() == component.id())
+    }
+
+    /// Adds a component to the item, replacing any it already had with the same ID.
+    pub fn set_component(&mut self, component: ItemComponent) {
+        let id = component.id();
+        self.components_to_remove.retain(|removed| removed.0 != id);
+        match self
+            .components_to_add
+            .iter_mut()
+            .find(|existing| existing.id() == id)
+        {
+            Some(existing) => *existing = component,
+            None => self.components_to_add.push(component),
+        }
+    }
+
+    /// Removes a component from the item, including the default one its item type has.
+    pub fn remove_component(&mut self, component: DataComponent) {
+        let id = component.id();
+        self.components_to_add
+            .retain(|component| component.id() != id);
+        if !self
+            .components_to_remove
+            .iter()
+            .any(|removed| removed.0 == id)
+        {
+            self.components_to_remove.push(VarInt::new(id));
+        }
+    }
+
+    /// Goes back to the default component of the item type, if it has one.
+    pub fn reset_component(&mut self, component: DataComponent) {
+        let id = component.id();
+        self.components_to_add
+            .retain(|component| component.id() != id);
+        self.components_to_remove.retain(|removed| removed.0 != id);
+    }
+
+    fn is_removed(&self, component: DataComponent) -> bool {
+        self.components_to_remove
+            .iter()
+            .any(|removed| removed.0 == component.id())
+    }
+
+    /// How many of this item fit in a single stack.
+    pub fn max_stack_size(&self) -> i32 {
+        match self.component(DataComponent::MaxStackSize) {
+            Some(ItemComponent::MaxStackSize(size)) => size.0,
+            _ if self.is_removed(DataComponent::MaxStackSize) => 1,
+            _ => self.item().map_or(64, |item| item.max_stack_size() as i32),
+        }
+    }
+
+    /// How much damage the item can take before it breaks, or `None` if it can't be damaged.
+    pub fn max_damage(&self) -> Option<i32> {
+        match self.component(DataComponent::MaxDamage) {
+            Some(ItemComponent::MaxDamage(max_damage)) => Some(max_damage.0),
+            _ if self.is_removed(DataComponent::MaxDamage) => None,
+            _ => self.item()?.max_damage().map(i32::from),
+        }
+    }
+
+    /// How much damage the item has taken.
+    pub fn damage(&self) -> i32 {
+        match self.component(DataComponent::Damage) {
+            Some(ItemComponent::Damage(damage)) => damage.0,
+            _ => 0,
+        }
+    }
+
+    pub fn set_damage(&mut self, damage: i32) {
+        if damage == 0 {
+            self.reset_component(DataComponent::Damage);
+        } else {
+            self.set_component(ItemComponent::Damage(VarInt::new(damage)));
+        }
+    }
+
+    pub fn is_unbreakable(&self) -> bool {
+        self.component(DataComponent::Unbreakable).is_some()
+    }
+
+    /// The enchantments applied to the item.
+    pub fn enchantments(&self) -> &[Enchantment] {
+        match self.component(DataComponent::Enchantments) {
+            Some(ItemComponent::Enchantments(enchantments)) => enchantments,
+            _ => &[],
+        }
+    }
+
+    /// The level of an enchantment on the item, or 0 if it doesn't have it.
+    pub fn enchantment_level(&self, enchantment: i32) -> i32 {
+        self.enchantments()
+            .iter()
+            .find(|applied| applied.id.0 == enchantment)
+            .map_or(0, |applied| applied.level.0)
+    }
+
+    /// Replaces the enchantments applied to the item.
+    pub fn set_enchantments(&mut self, enchantments: Vec<Enchantment>) {
+        if enchantments.is_empty() {
+            self.reset_component(DataComponent::Enchantments);
+        } else {
+            self.set_component(ItemComponent::Enchantments(enchantments));
+        }
+    }
+
+    /// The enchantments stored in the item, which enchanted books have instead of applied ones.
+    pub fn stored_enchantments(&self) -> &[Enchantment] {
+        match self.component(DataComponent::StoredEnchantments) {
+            Some(ItemComponent::StoredEnchantments(enchantments)) => enchantments,
+            _ => &[],
+        }
+    }
+
+    pub fn set_stored_enchantments(&mut self, enchantments: Vec<Enchantment>) {
+        if enchantments.is_empty() {
+            self.reset_component(DataComponent::StoredEnchantments);
+        } else {
+            self.set_component(ItemComponent::StoredEnchantments(enchantments));
         }
     }
+
+    /// How good the enchantments from an enchanting table are, or 0 if the item can't be
+    /// enchanted there.
+    pub fn enchantability(&self) -> i32 {
+        match self.component(DataComponent::Enchantable) {
+            Some(ItemComponent::Enchantable(value)) => value.0,
+            _ if self.is_removed(DataComponent::Enchantable) => 0,
+            _ => self
+                .item()
+                .and_then(|item| item.enchantability())
+                .map_or(0, i32::from),
+        }
+    }
+
+    /// The extra levels it costs to work on the item in an anvil, which go up each time it's
+    /// worked on.
+    pub fn repair_cost(&self) -> i32 {
+        match self.component(DataComponent::RepairCost) {
+            Some(ItemComponent::RepairCost(cost)) => cost.0,
+            _ => 0,
+        }
+    }
+
+    pub fn set_repair_cost(&mut self, cost: i32) {
+        if cost == 0 {
+            self.reset_component(DataComponent::RepairCost);
+        } else {
+            self.set_component(ItemComponent::RepairCost(VarInt::new(cost)));
+        }
+    }
+
+    /// The name the item was given, as network NBT.
+    pub fn custom_name(&self) -> Option<&RawNbt> {
+        match self.component(DataComponent::CustomName) {
+            Some(ItemComponent::CustomName(name)) => Some(name),
+            _ => None,
+        }
+    }
+
+    /// Sets the name of the item to a text component serialized as network NBT, e.g. with
+    /// `TextComponent::serialize_nbt`.
+    pub fn set_custom_name(&mut self, name: RawNbt) {
+        self.set_component(ItemComponent::CustomName(name));
+    }
 }
 
 impl Display for InventorySlot {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "InventorySlot {{ count: {}, item_id: {:?}, components_to_add_count: {:?}, components_to_remove_count: {:?} }}",
+            "InventorySlot {{ count: {}, item_id: {:?}, components_to_add: {:?}, components_to_remove: {:?} }}",
             self.count.0,
             self.item_id,
-            self.components_to_add_count,
-            self.components_to_remove_count
+            self.components_to_add
+                .iter()
+                .map(ItemComponent::id)
+                .collect::<Vec<_>>(),
+            self.components_to_remove
+                .iter()
+                .map(|id| id.0)
+                .collect::<Vec<_>>()
         )
     }
 }
 
-impl NetDecode for InventorySlot {
-    fn decode<R: Read>(reader: &mut R, opts: &NetDecodeOpts) -> Result<Self, NetDecodeError> {
+impl InventorySlot {
+    /// Reads a slot, which may be nested `depth` deep in other items, e.g. in a shulker box.
+    pub(crate) fn read<R: Read>(reader: &mut R, depth: usize) -> Result<Self, NetDecodeError> {
+        let opts = &NetDecodeOpts::None;
         let count = VarInt::decode(reader, opts)?;
-        if count.0 == 0 {
-            Ok(Self {
-                count,
-                ..Default::default()
-            })
-        } else {
-            let item_id = VarInt::decode(reader, opts)?;
-            let components_to_add_count = VarInt::decode(reader, opts)?;
-            let components_to_remove_count = VarInt::decode(reader, opts)?;
-
-            let components_to_add = {
-                let mut components = Vec::with_capacity(components_to_add_count.0 as usize);
-                for _ in 0..components_to_add_count.0 {
-                    components.push(VarInt::decode(reader, opts)?);
-                }
-                Some(components)
-            };
-            let components_to_remove = {
-                let mut components = Vec::with_capacity(components_to_remove_count.0 as usize);
-                for _ in 0..components_to_remove_count.0 {
-                    components.push(VarInt::decode(reader, opts)?);
-                }
-                Some(components)
-            };
-
-            Ok(Self {
-                count,
-                item_id: Some(ItemID(item_id)),
-                components_to_add_count: Some(components_to_add_count),
-                components_to_remove_count: Some(components_to_remove_count),
-                components_to_add,
-                components_to_remove,
-            })
+        if count.0 <= 0 {
+            return Ok(Self::empty());
+        }
+
+        let item_id = VarInt::decode(reader, opts)?;
+        let components_to_add_count = VarInt::decode(reader, opts)?;
+        let components_to_remove_count = VarInt::decode(reader, opts)?;
+        if !(0..=MAX_COMPONENTS).contains(&components_to_add_count.0)
+            || !(0..=MAX_COMPONENTS).contains(&components_to_remove_count.0)
+        {
+            return Err(ItemComponentError::TooLong.into());
         }
+
+        // Each added component is its ID followed by its data
+        let components_to_add = (0..components_to_add_count.0)
+            .map(|_| ItemComponent::read(reader, depth))
+            .collect::<Result<_, _>>()?;
+        let components_to_remove = (0..components_to_remove_count.0)
+            .map(|_| VarInt::decode(reader, opts))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            count,
+            item_id: Some(ItemID(item_id)),
+            components_to_add,
+            components_to_remove,
+        })
+    }
+}
+
+impl NetDecode for InventorySlot {
+    fn decode<R: Read>(reader: &mut R, _opts: &NetDecodeOpts) -> Result<Self, NetDecodeError> {
+        Self::read(reader, 0)
     }
 
     async fn decode_async<R: AsyncRead + Unpin>(
@@ -103,46 +305,22 @@ impl NetEncode for InventorySlot {
             return Ok(());
         }
 
-        let zero_varint = VarInt::new(0);
-
         // 2. Encode ItemID
         match &self.item_id {
             Some(item_id) => item_id.0.encode(writer, opts)?,
-            None => zero_varint.encode(writer, opts)?,
+            None => VarInt::new(0).encode(writer, opts)?,
         }
 
-        // 3. Get add_count and remove_count
-        let add_count = self
-            .components_to_add_count
-            .as_ref()
-            .unwrap_or(&zero_varint);
-        let remove_count = self
-            .components_to_remove_count
-            .as_ref()
-            .unwrap_or(&zero_varint);
-
-        // 4. Encode components_to_add_count
-        add_count.encode(writer, opts)?;
+        // 3. Encode how many components are added and removed
+        VarInt::new(self.components_to_add.len() as i32).encode(writer, opts)?;
+        VarInt::new(self.components_to_remove.len() as i32).encode(writer, opts)?;
 
-        // 5. Encode components_to_remove_count
-        remove_count.encode(writer, opts)?;
-
-        // 6. Encode components_to_add list (if any)
-        if add_count.0 > 0
-            && let Some(components) = &self.components_to_add
-        {
-            for component in components {
-                component.encode(writer, opts)?;
-            }
+        // 4. Encode the added components with their data, then the IDs of the removed ones
+        for component in &self.components_to_add {
+            component.encode(writer, opts)?;
         }
-
-        // 7. Encode components_to_remove list (if any)
-        if remove_count.0 > 0
-            && let Some(components) = &self.components_to_remove
-        {
-            for component in components {
-                component.encode(writer, opts)?;
-            }
+        for component in &self.components_to_remove {
+            component.encode(writer, opts)?;
         }
 
         Ok(())
@@ -193,15 +371,12 @@ mod tests {
 
     #[test]
     fn test_slot_encode_decode_roundtrip() {
-        // --- Test Case 1: The Empty Slot ---
+        // --- Test Case 1: The Simple Slot ---
 
         let simple_slot = InventorySlot {
             count: VarInt::new(10),
             item_id: Some(ItemID::new(1)),
-            components_to_add_count: Some(VarInt::new(0)),
-            components_to_remove_count: Some(VarInt::new(0)),
-            components_to_add: Some(vec![]),
-            components_to_remove: Some(vec![]),
+            ..Default::default()
         };
 
         let decoded_simple = run_roundtrip_test(&simple_slot);
@@ -211,15 +386,77 @@ mod tests {
         let complex_slot = InventorySlot {
             count: VarInt::new(1),
             item_id: Some(ItemID::new(872)),
-            components_to_add_count: Some(VarInt::new(2)),
-            components_to_remove_count: Some(VarInt::new(1)),
-            components_to_add: Some(vec![VarInt::new(10), VarInt::new(11)]),
-            components_to_remove: Some(vec![VarInt::new(20)]),
+            components_to_add: vec![
+                ItemComponent::Damage(VarInt::new(10)),
+                ItemComponent::CustomName(RawNbt(vec![8, 0, 4, b'n', b'a', b'm', b'e'])),
+                ItemComponent::Enchantments(vec![Enchantment {
+                    id: VarInt::new(1),
+                    level: VarInt::new(2),
+                }]),
+            ],
+            components_to_remove: vec![VarInt::new(20)],
         };
         let decoded_complex = run_roundtrip_test(&complex_slot);
         assert_eq!(
             complex_slot, decoded_complex,
             "Complex slot roundtrip failed"
         );
+
+        let stored: InventorySlot = bitcode::decode(&bitcode::encode(&complex_slot)).unwrap();
+        assert_eq!(
+            complex_slot, stored,
+            "Complex slot bitcode roundtrip failed"
+        );
+    }
+
+    #[test]
+    fn test_slot_with_other_components() {
+        // A shulker box holding a player head, with some damage and a removed component
+        let bytes: &[u8] = &[
+            1, 5, 2, 1, 66, 1, 1, 5, 1, 0, 61, 1, 5, b'S', b't', b'e', b'v', b'e', 0, 0, 3, 4, 20,
+        ];
+        let mut reader = Cursor::new(bytes);
+        let slot = InventorySlot::decode(&mut reader, &NetDecodeOpts::None).expect("Decode failed");
+        assert_eq!(reader.position() as usize, bytes.len());
+        assert!(matches!(
+            slot.components_to_add[0],
+            ItemComponent::Other { id: 66, .. }
+        ));
+        assert_eq!(slot.damage(), 4);
+        assert_eq!(slot.components_to_remove, vec![VarInt::new(20)]);
+
+        let mut buffer = Vec::new();
+        slot.encode(&mut buffer, &NetEncodeOpts::None)
+            .expect("Encode failed");
+        assert_eq!(buffer, bytes);
+    }
+
+    #[test]
+    fn test_components() {
+        let mut slot = InventorySlot {
+            count: VarInt::new(1),
+            item_id: ItemID::from_name("minecraft:diamond_sword"),
+            ..Default::default()
+        };
+        assert_eq!(slot.max_stack_size(), 1);
+        assert_eq!(slot.max_damage(), Some(1561));
+
+        slot.set_damage(5);
+        slot.set_damage(7);
+        assert_eq!(slot.damage(), 7);
+        assert_eq!(slot.components_to_add.len(), 1);
+        slot.set_damage(0);
+        assert!(slot.components_to_add.is_empty());
+
+        slot.remove_component(DataComponent::MaxDamage);
+        assert_eq!(slot.max_damage(), None);
+        slot.set_component(ItemComponent::MaxDamage(VarInt::new(10)));
+        assert_eq!(slot.max_damage(), Some(10));
+        assert!(slot.components_to_remove.is_empty());
+
+        let mut other = slot.clone();
+        assert!(slot.stacks_with(&other));
+        other.set_component(ItemComponent::Unbreakable);
+        assert!(!slot.stacks_with(&other));
     }
 }
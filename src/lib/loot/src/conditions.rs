@@ -0,0 +1,115 @@
+use crate::context::{LootContext, LootEntity};
+use ferrumc_data::loot_tables::{
+    DamageSourcePredicate, EntityPredicate, LootCondition, ToolPredicate,
+};
+use ferrumc_data::tags::TagData;
+use rand::Rng;
+
+/// Whether every condition passes. An empty list always passes.
+pub(crate) fn all(conditions: &[LootCondition], ctx: &LootContext, rng: &mut impl Rng) -> bool {
+    conditions.iter().all(|condition| test(condition, ctx, rng))
+}
+
+pub(crate) fn test(condition: &LootCondition, ctx: &LootContext, rng: &mut impl Rng) -> bool {
+    match condition {
+        LootCondition::SurvivesExplosion => match ctx.explosion_radius {
+            Some(radius) if radius > 0.0 => rng.gen::<f32>() <= 1.0 / radius,
+            _ => true,
+        },
+        LootCondition::KilledByPlayer => ctx.killed_by_player,
+        LootCondition::RandomChance(chance) => rng.gen::<f32>() < *chance,
+        LootCondition::RandomChanceWithEnchantedBonus {
+            enchantment,
+            unenchanted_chance,
+            base,
+            per_level_above_first,
+        } => {
+            let level = ctx.enchantment_level(enchantment);
+            let chance = if level > 0 {
+                base + per_level_above_first * (level - 1) as f32
+            } else {
+                *unenchanted_chance
+            };
+            rng.gen::<f32>() < chance
+        }
+        LootCondition::TableBonus {
+            enchantment,
+            chances,
+        } => {
+            let level = ctx.enchantment_level(enchantment) as usize;
+            let Some(chance) = chances.get(level).or(chances.last()) else {
+                return false;
+            };
+            rng.gen::<f32>() < *chance
+        }
+        LootCondition::MatchTool(predicate) => matches_tool(predicate, ctx),
+        LootCondition::EntityProperties { entity, predicate } => ctx
+            .target(*entity)
+            .is_some_and(|entity| matches_entity(predicate, entity)),
+        LootCondition::DamageSourceProperties(predicate) => matches_damage_source(predicate, ctx),
+        LootCondition::BlockStateProperty { block, properties } => {
+            let Some(state) = ctx.block else {
+                return false;
+            };
+            state.name() == Some(*block)
+                && properties
+                    .iter()
+                    .all(|(key, expected)| state.property(key) == Some(*expected))
+        }
+        LootCondition::Inverted(term) => !test(term, ctx, rng),
+        LootCondition::AnyOf(terms) => terms.iter().any(|term| test(term, ctx, rng)),
+        LootCondition::AllOf(terms) => all(terms, ctx, rng),
+        LootCondition::Unsupported(_) => false,
+    }
+}
+
+fn matches_tool(predicate: &ToolPredicate, ctx: &LootContext) -> bool {
+    let Some(tool) = ctx.tool else {
+        return false;
+    };
+    let item_matches = predicate.items.is_empty()
+        || predicate
+            .items
+            .iter()
+            .any(|item| match item.strip_prefix('#') {
+                Some(tag) => TagData::get_item_tag(tag)
+                    .is_some_and(|tag| tag.values.iter().any(|value| *value == tool)),
+                None => *item == tool,
+            });
+    item_matches
+        && predicate
+            .enchantments
+            .iter()
+            .all(|(enchantment, min_level)| ctx.enchantment_level(enchantment) >= *min_level)
+}
+
+fn matches_entity(predicate: &EntityPredicate, entity: &LootEntity) -> bool {
+    let type_matches = predicate.types.is_empty()
+        || predicate
+            .types
+            .iter()
+            .any(|kind| match kind.strip_prefix('#') {
+                Some(tag) => TagData::get_entity_type_tag(tag)
+                    .is_some_and(|tag| tag.values.contains(&entity.kind)),
+                None => *kind == entity.kind,
+            });
+    type_matches
+        && predicate
+            .on_fire
+            .is_none_or(|on_fire| on_fire == entity.on_fire)
+        && predicate.baby.is_none_or(|baby| baby == entity.baby)
+}
+
+fn matches_damage_source(predicate: &DamageSourcePredicate, ctx: &LootContext) -> bool {
+    let tags_match = predicate.tags.iter().all(|(tag, expected)| {
+        ctx.damage_type
+            .is_some_and(|damage_type| damage_type.has_tag(tag) == *expected)
+    });
+    let entity_matches = |predicate: Option<&EntityPredicate>, entity: Option<&LootEntity>| {
+        predicate
+            .is_none_or(|predicate| entity.is_some_and(|entity| matches_entity(predicate, entity)))
+    };
+    tags_match
+        && entity_matches(predicate.direct_entity, ctx.direct_attacker.as_ref())
+        && entity_matches(predicate.source_entity, ctx.attacker.as_ref())
+}
@@ -0,0 +1,108 @@
+use crate::context::LootContext;
+use crate::{generate_into, into_slot, split_into_stacks, LootStack};
+use ferrumc_data::loot_tables::LootTable;
+use ferrumc_inventories::slot::InventorySlot;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use tracing::warn;
+
+/// Rolls a loot table into a container with `size` slots, such as a generated chest.
+///
+/// Like vanilla, the loot is scattered across random slots and some stacks are split up to fill
+/// empty space, so chests don't look like the items were placed in order.
+pub fn fill_container(
+    table: &LootTable,
+    ctx: &LootContext,
+    rng: &mut impl Rng,
+    size: usize,
+) -> Vec<Option<InventorySlot>> {
+    let mut stacks = Vec::new();
+    generate_into(table, ctx, rng, &mut stacks, 0);
+    let stacks = shuffle_and_split(split_into_stacks(stacks), size, rng);
+
+    let mut slots: Vec<usize> = (0..size).collect();
+    slots.shuffle(rng);
+
+    let mut container = vec![None; size];
+    if stacks.len() > size {
+        warn!(
+            "Loot table {} generated more stacks than fit in the container",
+            table.name
+        );
+    }
+    for (stack, slot) in stacks.into_iter().zip(slots) {
+        container[slot] = into_slot(stack);
+    }
+    container
+}
+
+/// Splits random stacks in two while there are more free slots than stacks, then shuffles them.
+fn shuffle_and_split(stacks: Vec<LootStack>, size: usize, rng: &mut impl Rng) -> Vec<LootStack> {
+    let (mut splittable, mut result): (Vec<_>, Vec<_>) =
+        stacks.into_iter().partition(|stack| stack.count > 1);
+
+    while !splittable.is_empty() && result.len() + splittable.len() < size {
+        let mut stack = splittable.swap_remove(rng.gen_range(0..splittable.len()));
+        let taken = rng.gen_range(1..=stack.count / 2);
+        stack.count -= taken;
+        let part = LootStack {
+            item: stack.item,
+            count: taken,
+        };
+        for half in [stack, part] {
+            if half.count > 1 && rng.gen() {
+                splittable.push(half);
+            } else {
+                result.push(half);
+            }
+        }
+    }
+
+    result.append(&mut splittable);
+    result.shuffle(rng);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ferrumc_data::loot_tables::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    const CHEST: LootTable = LootTable {
+        name: "test_chest",
+        kind: LootTableKind::Chest,
+        pools: &[LootPool {
+            rolls: NumberProvider::Constant(3.0),
+            bonus_rolls: NumberProvider::Constant(0.0),
+            entries: &[LootEntry {
+                kind: LootEntryKind::Item("minecraft:bread"),
+                weight: 1,
+                quality: 0,
+                conditions: &[],
+                functions: &[LootFunction {
+                    kind: LootFunctionKind::SetCount {
+                        count: NumberProvider::Constant(4.0),
+                        add: false,
+                    },
+                    conditions: &[],
+                }],
+            }],
+            conditions: &[],
+            functions: &[],
+        }],
+        functions: &[],
+    };
+
+    #[test]
+    fn test_fill_container_keeps_every_item() {
+        for seed in 0..20 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let container = fill_container(&CHEST, &LootContext::default(), &mut rng, 27);
+            assert_eq!(container.len(), 27);
+            let total: i32 = container.iter().flatten().map(|slot| slot.count.0).sum();
+            assert_eq!(total, 12);
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use ferrumc_data::damage_types::DamageType;
+use ferrumc_data::loot_tables::LootEntityTarget;
+use ferrumc_world::block_state_id::BlockStateId;
+
+/// Everything a loot table can look at while generating loot.
+///
+/// Fields default to "unknown", which makes conditions that depend on them fail, except for
+/// `survives_explosion` which always passes when nothing exploded.
+#[derive(Debug, Clone, Default)]
+pub struct LootContext<'a> {
+    /// Registry name of the tool used to break the block or kill the entity. `None` for an empty
+    /// hand.
+    pub tool: Option<&'a str>,
+    /// Enchantments on the tool, as registry name and level.
+    pub enchantments: &'a [(&'a str, u32)],
+    /// The block state being broken, for block loot tables.
+    pub block: Option<BlockStateId>,
+    /// Radius of the explosion that destroyed the block, if one did.
+    pub explosion_radius: Option<f32>,
+    /// Whether the entity was killed by a player.
+    pub killed_by_player: bool,
+    /// Luck of the player the loot is for. Adds bonus rolls to pools and changes entry weights.
+    pub luck: f32,
+    /// The entity the loot is for, for entity loot tables.
+    pub this: Option<LootEntity<'a>>,
+    /// The entity responsible for the kill, like the skeleton that shot the arrow.
+    pub attacker: Option<LootEntity<'a>>,
+    /// The entity that dealt the killing blow, like the arrow.
+    pub direct_attacker: Option<LootEntity<'a>>,
+    /// The type of the damage that killed the entity.
+    pub damage_type: Option<DamageType>,
+}
+
+/// What an `entity_properties` condition can check about an entity.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LootEntity<'a> {
+    /// Registry name of the entity type, e.g. `minecraft:skeleton`.
+    pub kind: &'a str,
+    pub on_fire: bool,
+    pub baby: bool,
+}
+
+impl<'a> LootContext<'a> {
+    /// Context for breaking `block` with `tool`.
+    pub fn block(
+        block: BlockStateId,
+        tool: Option<&'a str>,
+        enchantments: &'a [(&'a str, u32)],
+    ) -> Self {
+        Self {
+            tool,
+            enchantments,
+            block: Some(block),
+            ..Default::default()
+        }
+    }
+
+    /// Context for an entity killed with `tool`.
+    pub fn entity(
+        killed_by_player: bool,
+        tool: Option<&'a str>,
+        enchantments: &'a [(&'a str, u32)],
+    ) -> Self {
+        Self {
+            tool,
+            enchantments,
+            killed_by_player,
+            ..Default::default()
+        }
+    }
+
+    /// The entity an `entity_properties` condition checks, if it's known.
+    pub fn target(&self, target: LootEntityTarget) -> Option<&LootEntity<'a>> {
+        match target {
+            LootEntityTarget::This => self.this.as_ref(),
+            LootEntityTarget::Attacker => self.attacker.as_ref(),
+            LootEntityTarget::DirectAttacker => self.direct_attacker.as_ref(),
+        }
+    }
+
+    /// Level of an enchantment on the tool, or 0 if it doesn't have it.
+    pub fn enchantment_level(&self, enchantment: &str) -> u32 {
+        self.enchantments
+            .iter()
+            .find(|(name, _)| *name == enchantment)
+            .map_or(0, |(_, level)| *level)
+    }
+}
@@ -0,0 +1,82 @@
+use crate::context::LootContext;
+use crate::{conditions, numbers, LootStack};
+use ferrumc_data::loot_tables::{BonusFormula, LootFunction, LootFunctionKind};
+use rand::Rng;
+
+/// Applies each function whose conditions pass to the stack, in order.
+pub(crate) fn apply_all(
+    functions: &[LootFunction],
+    stack: &mut LootStack,
+    ctx: &LootContext,
+    rng: &mut impl Rng,
+) {
+    for function in functions {
+        if conditions::all(function.conditions, ctx, rng) {
+            apply(&function.kind, stack, ctx, rng);
+        }
+    }
+}
+
+fn apply(kind: &LootFunctionKind, stack: &mut LootStack, ctx: &LootContext, rng: &mut impl Rng) {
+    match kind {
+        LootFunctionKind::SetCount { count, add } => {
+            let count = numbers::sample_int(count, rng);
+            stack.count = if *add { stack.count + count } else { count };
+        }
+        LootFunctionKind::ApplyBonus {
+            enchantment,
+            formula,
+        } => {
+            let level = ctx.enchantment_level(enchantment) as i32;
+            stack.count = match *formula {
+                // Fortune on ores multiplies the drops by a random amount
+                BonusFormula::OreDrops if level > 0 => {
+                    let bonus = (rng.gen_range(0..level + 2) - 1).max(0);
+                    stack.count * (bonus + 1)
+                }
+                BonusFormula::OreDrops => stack.count,
+                BonusFormula::UniformBonusCount { bonus_multiplier } => {
+                    stack.count + rng.gen_range(0..=bonus_multiplier * level)
+                }
+                BonusFormula::BinomialWithBonusCount { extra, probability } => {
+                    let successes = (0..level + extra)
+                        .filter(|_| rng.gen::<f32>() < probability)
+                        .count();
+                    stack.count + successes as i32
+                }
+            };
+        }
+        LootFunctionKind::ExplosionDecay => {
+            if let Some(radius) = ctx.explosion_radius.filter(|radius| *radius > 0.0) {
+                let survives = 1.0 / radius;
+                stack.count = (0..stack.count)
+                    .filter(|_| rng.gen::<f32>() <= survives)
+                    .count() as i32;
+            }
+        }
+        LootFunctionKind::EnchantedCountIncrease {
+            enchantment,
+            count,
+            limit,
+        } => {
+            let level = ctx.enchantment_level(enchantment);
+            if level == 0 {
+                return;
+            }
+            let bonus = (numbers::sample(count, rng) * level as f32).round() as i32;
+            stack.count += bonus;
+            if *limit > 0 {
+                stack.count = stack.count.min(*limit);
+            }
+        }
+        LootFunctionKind::LimitCount { min, max } => {
+            if let Some(min) = min {
+                stack.count = stack.count.max(*min);
+            }
+            if let Some(max) = max {
+                stack.count = stack.count.min(*max);
+            }
+        }
+        LootFunctionKind::Unsupported(_) => {}
+    }
+}
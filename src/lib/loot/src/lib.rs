@@ -0,0 +1,474 @@
+//! Loot table evaluation.
+//!
+//! The tables themselves are generated from vanilla data in `ferrumc_data::loot_tables`, with
+//! block tables on `Block::loot_table`. This crate rolls them: it picks entries from each pool,
+//! checks conditions against a [`LootContext`] and runs the item functions, producing item stacks
+//! for block drops, mob drops or chest contents.
+
+mod conditions;
+mod container;
+mod context;
+mod functions;
+mod numbers;
+
+pub use container::fill_container;
+pub use context::{LootContext, LootEntity};
+
+use ferrumc_data::items::Item;
+use ferrumc_data::loot_tables::{LootEntry, LootEntryKind, LootPool, LootTable};
+use ferrumc_data::tags::TagData;
+use ferrumc_inventories::item::ItemID;
+use ferrumc_inventories::slot::InventorySlot;
+use ferrumc_net_codec::net_types::var_int::VarInt;
+use rand::Rng;
+use tracing::warn;
+
+/// Loot tables can pull in other tables, this stops a table that includes itself from recursing
+/// forever.
+const MAX_TABLE_DEPTH: usize = 8;
+
+/// An item and how many of it, before it's split into stacks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct LootStack {
+    pub item: &'static str,
+    pub count: i32,
+}
+
+/// Rolls a loot table and returns the items it generated, split into stacks no bigger than each
+/// item's max stack size.
+pub fn generate(table: &LootTable, ctx: &LootContext, rng: &mut impl Rng) -> Vec<InventorySlot> {
+    let mut stacks = Vec::new();
+    generate_into(table, ctx, rng, &mut stacks, 0);
+    split_into_stacks(stacks)
+        .into_iter()
+        .filter_map(into_slot)
+        .collect()
+}
+
+fn generate_into(
+    table: &LootTable,
+    ctx: &LootContext,
+    rng: &mut impl Rng,
+    out: &mut Vec<LootStack>,
+    depth: usize,
+) {
+    if depth > MAX_TABLE_DEPTH {
+        warn!("Loot table {} nests too deep, skipping", table.name);
+        return;
+    }
+    for pool in table.pools {
+        let mut generated = Vec::new();
+        roll_pool(pool, ctx, rng, &mut generated, depth);
+        for mut stack in generated {
+            functions::apply_all(table.functions, &mut stack, ctx, rng);
+            if stack.count > 0 {
+                out.push(stack);
+            }
+        }
+    }
+}
+
+fn roll_pool(
+    pool: &LootPool,
+    ctx: &LootContext,
+    rng: &mut impl Rng,
+    out: &mut Vec<LootStack>,
+    depth: usize,
+) {
+    if !conditions::all(pool.conditions, ctx, rng) {
+        return;
+    }
+    let bonus = (numbers::sample(&pool.bonus_rolls, rng) * ctx.luck).floor() as i32;
+    let rolls = numbers::sample_int(&pool.rolls, rng) + bonus;
+
+    for _ in 0..rolls {
+        let mut choices = Vec::new();
+        for entry in pool.entries {
+            expand(entry, ctx, rng, &mut choices);
+        }
+        let Some(choice) = pick(&choices, ctx.luck, rng) else {
+            continue;
+        };
+
+        let mut generated = Vec::new();
+        choice.generate(ctx, rng, &mut generated, depth);
+        for mut stack in generated {
+            functions::apply_all(pool.functions, &mut stack, ctx, rng);
+            out.push(stack);
+        }
+    }
+}
+
+/// A leaf entry that can be picked in a roll.
+#[derive(Clone, Copy)]
+struct Choice {
+    entry: &'static LootEntry,
+    /// Set for one item of a tag entry with `expand`.
+    tag_item: Option<&'static str>,
+}
+
+impl Choice {
+    fn weight(&self, luck: f32) -> u32 {
+        (self.entry.weight as f32 + self.entry.quality as f32 * luck)
+            .floor()
+            .max(0.0) as u32
+    }
+
+    fn generate(
+        &self,
+        ctx: &LootContext,
+        rng: &mut impl Rng,
+        out: &mut Vec<LootStack>,
+        depth: usize,
+    ) {
+        let mut generated = Vec::new();
+        match (self.tag_item, self.entry.kind) {
+            (Some(item), _) | (None, LootEntryKind::Item(item)) => {
+                generated.push(LootStack { item, count: 1 })
+            }
+            (None, LootEntryKind::Tag { name, .. }) => {
+                let items = TagData::get_item_tag(name).map_or(&[][..], |tag| tag.values);
+                generated.extend(items.iter().map(|&item| LootStack { item, count: 1 }));
+            }
+            (None, LootEntryKind::LootTable(name)) => match LootTable::by_name(name) {
+                Some(table) => generate_into(table, ctx, rng, &mut generated, depth + 1),
+                None => warn!("Unknown loot table {}", name),
+            },
+            // Dynamic contents come from the block entity, which the caller adds itself
+            _ => {}
+        }
+        for mut stack in generated {
+            functions::apply_all(self.entry.functions, &mut stack, ctx, rng);
+            out.push(stack);
+        }
+    }
+}
+
+/// Adds the leaf entries `entry` can produce to `choices`. Returns whether the entry's conditions
+/// passed, which is what `alternatives` and `sequence` entries branch on.
+fn expand(
+    entry: &'static LootEntry,
+    ctx: &LootContext,
+    rng: &mut impl Rng,
+    choices: &mut Vec<Choice>,
+) -> bool {
+    if !conditions::all(entry.conditions, ctx, rng) {
+        return false;
+    }
+    match entry.kind {
+        LootEntryKind::Alternatives(children) => {
+            for child in children {
+                if expand(child, ctx, rng, choices) {
+                    break;
+                }
+            }
+        }
+        LootEntryKind::Group(children) => {
+            for child in children {
+                expand(child, ctx, rng, choices);
+            }
+        }
+        LootEntryKind::Sequence(children) => {
+            for child in children {
+                if !expand(child, ctx, rng, choices) {
+                    break;
+                }
+            }
+        }
+        LootEntryKind::Tag { name, expand: true } => {
+            let items = TagData::get_item_tag(name).map_or(&[][..], |tag| tag.values);
+            choices.extend(items.iter().map(|&item| Choice {
+                entry,
+                tag_item: Some(item),
+            }));
+        }
+        _ => choices.push(Choice {
+            entry,
+            tag_item: None,
+        }),
+    }
+    true
+}
+
+/// Picks a choice at random, weighted by each choice's weight.
+fn pick(choices: &[Choice], luck: f32, rng: &mut impl Rng) -> Option<Choice> {
+    match choices {
+        [] => None,
+        [only] => Some(*only),
+        _ => {
+            let total: u32 = choices.iter().map(|choice| choice.weight(luck)).sum();
+            if total == 0 {
+                return None;
+            }
+            let mut target = rng.gen_range(0..total);
+            for choice in choices {
+                let weight = choice.weight(luck);
+                if target < weight {
+                    return Some(*choice);
+                }
+                target -= weight;
+            }
+            None
+        }
+    }
+}
+
+fn max_stack_size(item: &str) -> i32 {
+    Item::from_registry_key(item).map_or(64, |item| item.max_stack_size() as i32)
+}
+
+/// Splits stacks that are bigger than their item's max stack size.
+pub(crate) fn split_into_stacks(stacks: Vec<LootStack>) -> Vec<LootStack> {
+    let mut split = Vec::with_capacity(stacks.len());
+    for mut stack in stacks {
+        let max = max_stack_size(stack.item).max(1);
+        while stack.count > max {
+            split.push(LootStack {
+                item: stack.item,
+                count: max,
+            });
+            stack.count -= max;
+        }
+        if stack.count > 0 {
+            split.push(stack);
+        }
+    }
+    split
+}
+
+pub(crate) fn into_slot(stack: LootStack) -> Option<InventorySlot> {
+    let Some(item_id) = ItemID::from_name(stack.item) else {
+        warn!("Loot table produced unknown item {}", stack.item);
+        return None;
+    };
+    Some(InventorySlot {
+        count: VarInt::new(stack.count),
+        item_id: Some(item_id),
+        ..Default::default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ferrumc_data::damage_types::DamageType;
+    use ferrumc_data::loot_tables::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    const fn item(name: &'static str, functions: &'static [LootFunction]) -> LootEntry {
+        LootEntry {
+            kind: LootEntryKind::Item(name),
+            weight: 1,
+            quality: 0,
+            conditions: &[],
+            functions,
+        }
+    }
+
+    const fn table(pools: &'static [LootPool]) -> LootTable {
+        LootTable {
+            name: "test",
+            kind: LootTableKind::Block,
+            pools,
+            functions: &[],
+        }
+    }
+
+    const fn pool(entries: &'static [LootEntry], conditions: &'static [LootCondition]) -> LootPool {
+        LootPool {
+            rolls: NumberProvider::Constant(1.0),
+            bonus_rolls: NumberProvider::Constant(0.0),
+            entries,
+            conditions,
+            functions: &[],
+        }
+    }
+
+    /// The shape of vanilla's ore tables: silk touch drops the ore, otherwise fortune applies to
+    /// the raw item.
+    const ORE: LootTable = table(&[pool(
+        &[LootEntry {
+            kind: LootEntryKind::Alternatives(&[
+                LootEntry {
+                    conditions: &[LootCondition::MatchTool(ToolPredicate {
+                        items: &[],
+                        enchantments: &[("minecraft:silk_touch", 1)],
+                    })],
+                    ..item("minecraft:iron_ore", &[])
+                },
+                item(
+                    "minecraft:raw_iron",
+                    &[
+                        LootFunction {
+                            kind: LootFunctionKind::ApplyBonus {
+                                enchantment: "minecraft:fortune",
+                                formula: BonusFormula::OreDrops,
+                            },
+                            conditions: &[],
+                        },
+                        LootFunction {
+                            kind: LootFunctionKind::ExplosionDecay,
+                            conditions: &[],
+                        },
+                    ],
+                ),
+            ]),
+            weight: 1,
+            quality: 0,
+            conditions: &[],
+            functions: &[],
+        }],
+        &[],
+    )]);
+
+    fn counts(table: &LootTable, ctx: &LootContext, seed: u64) -> Vec<(Option<ItemID>, i32)> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        generate(table, ctx, &mut rng)
+            .into_iter()
+            .map(|slot| (slot.item_id, slot.count.0))
+            .collect()
+    }
+
+    #[test]
+    fn test_silk_touch_drops_the_ore() {
+        let enchantments = [("minecraft:silk_touch", 1)];
+        let ctx = LootContext {
+            tool: Some("minecraft:iron_pickaxe"),
+            enchantments: &enchantments,
+            ..Default::default()
+        };
+        assert_eq!(
+            counts(&ORE, &ctx, 1),
+            vec![(ItemID::from_name("minecraft:iron_ore"), 1)]
+        );
+    }
+
+    #[test]
+    fn test_fortune_never_lowers_ore_drops() {
+        let enchantments = [("minecraft:fortune", 3)];
+        let ctx = LootContext {
+            tool: Some("minecraft:iron_pickaxe"),
+            enchantments: &enchantments,
+            ..Default::default()
+        };
+        for seed in 0..50 {
+            let drops = counts(&ORE, &ctx, seed);
+            assert_eq!(drops.len(), 1);
+            assert_eq!(drops[0].0, ItemID::from_name("minecraft:raw_iron"));
+            assert!((1..=4).contains(&drops[0].1));
+        }
+    }
+
+    #[test]
+    fn test_explosions_can_destroy_drops() {
+        const SURVIVES: LootTable = table(&[pool(
+            &[item("minecraft:cobblestone", &[])],
+            &[LootCondition::SurvivesExplosion],
+        )]);
+        let ctx = LootContext {
+            explosion_radius: Some(1000.0),
+            ..Default::default()
+        };
+        let survived = (0..50)
+            .filter(|seed| !counts(&SURVIVES, &ctx, *seed).is_empty())
+            .count();
+        assert!(survived < 5);
+        assert_eq!(counts(&SURVIVES, &LootContext::default(), 0).len(), 1);
+    }
+
+    #[test]
+    fn test_killed_by_player_pool() {
+        const RARE: LootTable = table(&[pool(
+            &[item("minecraft:iron_ingot", &[])],
+            &[LootCondition::KilledByPlayer],
+        )]);
+        assert!(counts(&RARE, &LootContext::default(), 0).is_empty());
+        let ctx = LootContext::entity(true, None, &[]);
+        assert_eq!(counts(&RARE, &ctx, 0).len(), 1);
+    }
+
+    #[test]
+    fn test_entity_properties_condition() {
+        const COOKED: LootTable = table(&[pool(
+            &[item("minecraft:cooked_beef", &[])],
+            &[LootCondition::EntityProperties {
+                entity: LootEntityTarget::This,
+                predicate: EntityPredicate {
+                    types: &[],
+                    on_fire: Some(true),
+                    baby: None,
+                },
+            }],
+        )]);
+        assert!(counts(&COOKED, &LootContext::default(), 0).is_empty());
+        let ctx = LootContext {
+            this: Some(LootEntity {
+                kind: "minecraft:cow",
+                on_fire: true,
+                baby: false,
+            }),
+            ..Default::default()
+        };
+        assert_eq!(counts(&COOKED, &ctx, 0).len(), 1);
+    }
+
+    #[test]
+    fn test_damage_source_condition() {
+        const STRUCK: LootTable = table(&[pool(
+            &[item("minecraft:bowl", &[])],
+            &[LootCondition::DamageSourceProperties(
+                DamageSourcePredicate {
+                    tags: &[("minecraft:is_lightning", true)],
+                    direct_entity: None,
+                    source_entity: None,
+                },
+            )],
+        )]);
+        let struck_by = |damage_type| LootContext {
+            damage_type: Some(damage_type),
+            ..Default::default()
+        };
+        assert!(counts(&STRUCK, &LootContext::default(), 0).is_empty());
+        assert!(counts(&STRUCK, &struck_by(DamageType::Generic), 0).is_empty());
+        assert_eq!(
+            counts(&STRUCK, &struck_by(DamageType::LightningBolt), 0).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_set_count_and_stack_splitting() {
+        const MANY: LootTable = table(&[pool(
+            &[item(
+                "minecraft:ender_pearl",
+                &[LootFunction {
+                    kind: LootFunctionKind::SetCount {
+                        count: NumberProvider::Constant(40.0),
+                        add: false,
+                    },
+                    conditions: &[],
+                }],
+            )],
+            &[],
+        )]);
+        let drops = counts(&MANY, &LootContext::default(), 0);
+        // Ender pearls stack to 16
+        assert_eq!(
+            drops.iter().map(|(_, count)| *count).collect::<Vec<_>>(),
+            vec![16, 16, 8]
+        );
+    }
+
+    #[test]
+    fn test_generated_entity_table() {
+        let table = LootTable::by_name("minecraft:entities/zombie").unwrap();
+        let ctx = LootContext::entity(false, None, &[]);
+        for seed in 0..20 {
+            for (item, count) in counts(table, &ctx, seed) {
+                assert_eq!(item, ItemID::from_name("minecraft:rotten_flesh"));
+                assert!((1..=2).contains(&count));
+            }
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use ferrumc_data::loot_tables::NumberProvider;
+use rand::Rng;
+
+pub(crate) fn sample(provider: &NumberProvider, rng: &mut impl Rng) -> f32 {
+    match *provider {
+        NumberProvider::Constant(value) => value,
+        NumberProvider::Uniform { min, max } => {
+            if max <= min {
+                min
+            } else {
+                rng.gen_range(min..max)
+            }
+        }
+        NumberProvider::Binomial { n, p } => (0..n).filter(|_| rng.gen::<f32>() < p).count() as f32,
+    }
+}
+
+/// Samples a whole number. Uniform ranges include both ends, like vanilla.
+pub(crate) fn sample_int(provider: &NumberProvider, rng: &mut impl Rng) -> i32 {
+    match *provider {
+        NumberProvider::Uniform { min, max } => {
+            let (min, max) = (min.round() as i32, max.round() as i32);
+            if max <= min {
+                min
+            } else {
+                rng.gen_range(min..=max)
+            }
+        }
+        _ => sample(provider, rng).round() as i32,
+    }
+}
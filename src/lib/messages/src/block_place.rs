@@ -0,0 +1,11 @@
+use bevy_ecs::prelude::{Entity, Message};
+use ferrumc_world::block_state_id::BlockStateId;
+use ferrumc_world::pos::BlockPos;
+
+/// Message sent when a player places a block in the world
+#[derive(Message)]
+pub struct BlockPlacedEvent {
+    pub player: Entity,
+    pub position: BlockPos,
+    pub block: BlockStateId,
+}
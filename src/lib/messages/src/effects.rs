@@ -0,0 +1,25 @@
+use bevy_ecs::prelude::{Entity, Message};
+use ferrumc_components::active_effects::EffectState;
+use ferrumc_data::generated::effects::Effect;
+
+/// Fired when an entity should be given a status effect.
+///
+/// * Fired by: `/effect give`, potions, food.
+/// * Listened for by: `effects::apply_effects`, which updates the `ActiveEffects` component.
+#[derive(Message)]
+pub struct AddEffect {
+    pub entity: Entity,
+    pub effect: Effect,
+    pub state: EffectState,
+}
+
+/// Fired when an entity should lose a status effect.
+///
+/// * Fired by: `/effect clear`, milk.
+/// * Listened for by: `effects::apply_effects`.
+#[derive(Message)]
+pub struct RemoveEffect {
+    pub entity: Entity,
+    /// The effect to remove, or `None` to remove all of them.
+    pub effect: Option<Effect>,
+}
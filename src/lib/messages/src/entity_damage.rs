@@ -0,0 +1,19 @@
+use bevy_ecs::prelude::{Entity, Message};
+use ferrumc_data::generated::damage_types::DamageType;
+
+/// Fired when a mob or other non-player entity should take damage.
+///
+/// Players take damage through [`crate::PlayerDamaged`] instead, since armor and effects only
+/// apply to them for now.
+///
+/// * Fired by: Players attacking entities.
+/// * Listened for by: `entity_damage`, which decreases the entity's health and kills it once it
+///   runs out.
+#[derive(Message, Debug, Clone)]
+pub struct EntityDamaged {
+    pub entity: Entity,
+    pub amount: f32,
+    pub damage_type: DamageType,
+    /// The entity responsible for the damage, if there is one.
+    pub attacker: Option<Entity>,
+}
@@ -0,0 +1,42 @@
+use bevy_ecs::prelude::{Entity, Message};
+use bevy_math::DVec3;
+use ferrumc_world::pos::BlockPos;
+
+/// What an explosion does to the blocks it reaches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlockInteraction {
+    /// Blocks are left alone, like creeper explosions while `mobGriefing` is off.
+    Keep,
+    /// Blocks are destroyed and all of them drop.
+    Destroy,
+    /// Blocks are destroyed, and the bigger the explosion the fewer of them drop.
+    DestroyWithDecay,
+}
+
+/// Fired when something should explode.
+///
+/// * Fired by: Primed TNT and creepers.
+/// * Listened for by: `explosions::explode`, which destroys blocks, hurts and pushes entities and
+///   sends the explosion to players.
+#[derive(Message, Debug, Clone)]
+pub struct Explosion {
+    pub position: DVec3,
+    pub radius: f32,
+    /// The entity that exploded, which the explosion doesn't hurt or push.
+    pub source: Option<Entity>,
+    /// The entity responsible for the explosion, like the player that lit the TNT.
+    pub igniter: Option<Entity>,
+    pub block_interaction: BlockInteraction,
+}
+
+/// Fired when a TNT block should be lit.
+///
+/// * Fired by: Players using flint and steel or a fire charge on TNT, redstone.
+/// * Listened for by: `explosions::prime_tnt_blocks`, which replaces the block with a primed TNT
+///   entity.
+#[derive(Message, Debug, Clone)]
+pub struct PrimeTnt {
+    pub position: BlockPos,
+    /// The player who lit it, if any.
+    pub igniter: Option<Entity>,
+}
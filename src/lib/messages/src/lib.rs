@@ -1,6 +1,9 @@
 pub mod player_damage;
 pub use player_damage::*;
 
+pub mod player_container;
+pub use player_container::*;
+
 pub mod player_digging;
 pub use player_digging::*;
 
@@ -16,18 +19,37 @@ pub use player_join::*;
 pub mod player_leave;
 pub use player_leave::*;
 
+pub mod player_sleep;
+pub use player_sleep::*;
+
+pub mod player_use_item;
+pub use player_use_item::*;
+
 pub mod change_gamemode;
 pub mod chunk_calc;
 
 pub use change_gamemode::*;
 
+pub mod effects;
+pub mod entity_damage;
 pub mod entity_spawn;
 pub mod entity_update;
+pub mod explosion;
 pub mod particle;
+pub mod pregen;
+pub mod sound;
+pub mod title;
 
+pub use effects::{AddEffect, RemoveEffect};
+pub use entity_damage::EntityDamaged;
 pub use entity_spawn::{EntityType, SpawnEntityCommand, SpawnEntityEvent};
+pub use explosion::{BlockInteraction, Explosion, PrimeTnt};
+pub use sound::{PlaySound, SoundSource};
+pub use title::{SendTitle, TitleAction, TitleTimes};
 
 pub mod block_break;
+pub mod block_place;
 pub mod teleport_player;
 
 pub use block_break::BlockBrokenEvent;
+pub use block_place::BlockPlacedEvent;
@@ -0,0 +1,11 @@
+use bevy_ecs::prelude::{Entity, Message};
+use ferrumc_inventories::container::Menu;
+use ferrumc_world::pos::BlockPos;
+
+/// Message sent when a player right-clicks a block with a window, like an enchanting table
+#[derive(Message)]
+pub struct PlayerUsedContainer {
+    pub player: Entity,
+    pub position: BlockPos,
+    pub menu: Menu,
+}
@@ -1,23 +1,58 @@
-use bevy_ecs::prelude::{Entity, Message};
+use bevy_ecs::prelude::{Entity, Message, SystemSet};
+use ferrumc_data::generated::damage_types::DamageType;
 
 /// Fired when a player should take damage.
 ///
-/// * Fired by: Physics (fall damage), Hunger System (starvation), Combat.
-/// * Listened for by: A `health_system` that will decrease the `Health` component.
-#[derive(Message)]
-#[allow(unused)]
+/// The amount is before armor, resistance, enchantments and absorption are taken into account,
+/// which depends on the damage type. Systems that want to cancel or change the damage read it
+/// with a `MessageMutator` and run before [`DamageSystems`].
+///
+/// * Fired by: Effects (poison, wither and instant damage), lightning, the world border,
+///   attacks, projectiles and explosions.
+/// * Listened for by: `player_damage`, which decreases the `Health` component.
+#[derive(Message, Debug, Clone)]
 pub struct PlayerDamaged {
     pub player: Entity,
     pub amount: f32,
-    // TODO: add a `DamageSource` enum here later
+    pub damage_type: DamageType,
+    /// The entity responsible for the damage, if there is one.
+    pub attacker: Option<Entity>,
+    /// Set to stop the damage from being dealt.
+    pub cancelled: bool,
 }
 
-/// Fired by the `health_system` when a player's health reaches <= 0.
+impl PlayerDamaged {
+    pub fn new(player: Entity, amount: f32, damage_type: DamageType) -> Self {
+        Self {
+            player,
+            amount,
+            damage_type,
+            attacker: None,
+            cancelled: false,
+        }
+    }
+
+    /// Sets the entity responsible for the damage.
+    pub fn with_attacker(mut self, attacker: Option<Entity>) -> Self {
+        self.attacker = attacker;
+        self
+    }
+}
+
+/// The systems that apply [`PlayerDamaged`] to players.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DamageSystems;
+
+/// Fired by `player_damage` when a player's health reaches <= 0.
 ///
-/// * Fired by: `health_system`.
-/// * Listened for by: `respawn_system`, `player_leave_system` (to broadcast death msg).
+/// * Fired by: `player_damage`.
+/// * Listened for by: `effects`, `player_experience`, `player_death` and `scoreboard`.
 #[derive(Message)]
 #[allow(unused)]
 pub struct PlayerDied {
     pub player: Entity,
+    /// The type of the damage that killed the player.
+    pub damage_type: DamageType,
+    /// The entity responsible for the killing blow, if there is one.
+    pub attacker: Option<Entity>,
 }
@@ -0,0 +1,10 @@
+use bevy_ecs::prelude::{Entity, Message};
+use ferrumc_world::pos::BlockPos;
+
+/// Message sent when a player right-clicks a bed
+#[derive(Message)]
+pub struct PlayerUsedBed {
+    pub player: Entity,
+    pub foot: BlockPos,
+    pub head: BlockPos,
+}
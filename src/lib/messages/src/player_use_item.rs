@@ -0,0 +1,10 @@
+use bevy_ecs::prelude::{Entity, Message};
+
+/// Fired when a player lets go of the item they were using, like a drawn bow or a trident.
+///
+/// Fired by: `player_action` packet handler (status 5).
+/// Listened for by: `projectiles`, which shoots the arrow or throws the trident.
+#[derive(Message)]
+pub struct PlayerReleasedItem {
+    pub player: Entity,
+}
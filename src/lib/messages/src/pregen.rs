@@ -0,0 +1,27 @@
+use bevy_ecs::prelude::Message;
+use ferrumc_commands::Sender;
+
+/// What the /pregen command asked for.
+#[derive(Debug, Clone, Copy)]
+pub enum PregenAction {
+    /// Starts generating every chunk within `radius` chunks of the `center` chunk, replacing any
+    /// paused pregeneration.
+    Start {
+        center: (i32, i32),
+        radius: u32,
+        circle: bool,
+    },
+    Pause,
+    /// Resumes the last paused pregeneration, which may be from before a restart.
+    Resume,
+    Cancel,
+    Status,
+}
+
+/// Written by the /pregen command and handled by the pregeneration system, which reports back to
+/// `sender`.
+#[derive(Message)]
+pub struct PregenCommand {
+    pub action: PregenAction,
+    pub sender: Sender,
+}
@@ -0,0 +1,84 @@
+use bevy_ecs::prelude::{Entity, Message};
+use bevy_math::DVec3;
+use ferrumc_core::sound::SoundCategory;
+use ferrumc_data::generated::sounds::Sound;
+
+/// Where a sound plays from.
+#[derive(Debug, Clone, Copy)]
+pub enum SoundSource {
+    /// A fixed point in the world.
+    Position(DVec3),
+    /// An entity, which the sound follows while it plays.
+    Entity(Entity),
+}
+
+/// Fired when a sound should play in the world.
+///
+/// * Fired by: Gameplay systems like breaking and placing blocks, footsteps and doors, and
+///   `/playsound`.
+/// * Listened for by: `sounds::play_sounds`, which sends it to every player close enough to hear
+///   it.
+#[derive(Message, Debug, Clone)]
+pub struct PlaySound {
+    pub sound: Sound,
+    pub category: SoundCategory,
+    pub source: SoundSource,
+    /// Louder sounds can be heard further away, 16 blocks for every 1.0 of volume.
+    pub volume: f32,
+    pub pitch: f32,
+    /// A player that shouldn't hear it, usually because their client already plays it.
+    pub except: Option<Entity>,
+    /// Only these players hear it, if set.
+    pub listeners: Option<Vec<Entity>>,
+}
+
+impl PlaySound {
+    /// A sound at a fixed point with normal volume and pitch.
+    pub fn at(sound: Sound, category: SoundCategory, position: DVec3) -> Self {
+        Self::new(sound, category, SoundSource::Position(position))
+    }
+
+    /// A sound following an entity with normal volume and pitch.
+    pub fn from_entity(sound: Sound, category: SoundCategory, entity: Entity) -> Self {
+        Self::new(sound, category, SoundSource::Entity(entity))
+    }
+
+    fn new(sound: Sound, category: SoundCategory, source: SoundSource) -> Self {
+        Self {
+            sound,
+            category,
+            source,
+            volume: 1.0,
+            pitch: 1.0,
+            except: None,
+            listeners: None,
+        }
+    }
+
+    pub fn with_volume(mut self, volume: f32) -> Self {
+        self.volume = volume;
+        self
+    }
+
+    pub fn with_pitch(mut self, pitch: f32) -> Self {
+        self.pitch = pitch;
+        self
+    }
+
+    /// Doesn't send the sound to `player`.
+    pub fn except(mut self, player: Entity) -> Self {
+        self.except = Some(player);
+        self
+    }
+
+    /// Only sends the sound to `players`.
+    pub fn only(mut self, players: Vec<Entity>) -> Self {
+        self.listeners = Some(players);
+        self
+    }
+
+    /// How far away the sound can be heard, in blocks.
+    pub fn range(&self) -> f64 {
+        self.volume.max(1.0) as f64 * 16.0
+    }
+}
@@ -0,0 +1,68 @@
+use bevy_ecs::prelude::{Entity, Message};
+use ferrumc_text::TextComponent;
+
+/// How long a title fades in, stays on screen and fades out, in ticks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TitleTimes {
+    pub fade_in: i32,
+    pub stay: i32,
+    pub fade_out: i32,
+}
+
+impl Default for TitleTimes {
+    /// The times clients use until they're told otherwise.
+    fn default() -> Self {
+        Self {
+            fade_in: 10,
+            stay: 70,
+            fade_out: 20,
+        }
+    }
+}
+
+/// What to do with the text in the middle of players' screens.
+#[derive(Debug, Clone)]
+pub enum TitleAction {
+    /// Shows a title, along with the last subtitle sent.
+    Title(TextComponent),
+    /// Sets the subtitle shown with the next title.
+    Subtitle(TextComponent),
+    /// Shows text above the hotbar.
+    ActionBar(TextComponent),
+    Times(TitleTimes),
+    /// Hides the title on screen.
+    Clear,
+    /// Hides the title on screen, and forgets the subtitle and times.
+    Reset,
+}
+
+/// Fired to show titles, subtitles or action bar text to some players.
+///
+/// * Fired by: `/title`, or anything else that wants players to see something big.
+/// * Listened for by: `hud::send_titles`, which sends it to the players.
+#[derive(Message, Debug, Clone)]
+pub struct SendTitle {
+    pub players: Vec<Entity>,
+    pub action: TitleAction,
+}
+
+impl SendTitle {
+    pub fn new(players: Vec<Entity>, action: TitleAction) -> Self {
+        Self { players, action }
+    }
+
+    /// Shows a title and subtitle together, with the given times.
+    pub fn full(
+        players: Vec<Entity>,
+        title: TextComponent,
+        subtitle: Option<TextComponent>,
+        times: TitleTimes,
+    ) -> Vec<Self> {
+        let mut messages = vec![Self::new(players.clone(), TitleAction::Times(times))];
+        if let Some(subtitle) = subtitle {
+            messages.push(Self::new(players.clone(), TitleAction::Subtitle(subtitle)));
+        }
+        messages.push(Self::new(players, TitleAction::Title(title)));
+        messages
+    }
+}
@@ -0,0 +1,151 @@
+use crate::decode::errors::NetDecodeError;
+use crate::decode::{NetDecode, NetDecodeOpts};
+use crate::encode::errors::NetEncodeError;
+use crate::encode::{NetEncode, NetEncodeOpts};
+use crate::net_types::NetTypesError;
+use std::fmt::Display;
+use std::io::{Read, Write};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct VarLong(pub i64);
+
+impl From<i64> for VarLong {
+    fn from(value: i64) -> Self {
+        Self::new(value)
+    }
+}
+
+const SEGMENT_BITS: i64 = 0x7F;
+const CONTINUE_BIT: i64 = 0x80;
+
+impl Display for VarLong {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl VarLong {
+    pub const fn new(value: i64) -> Self {
+        Self(value)
+    }
+
+    pub fn read<R: Read>(cursor: &mut R) -> Result<Self, NetTypesError> {
+        let mut val = 0;
+        for i in 0..10 {
+            let byte = {
+                let mut buf = [0u8; 1];
+                cursor.read_exact(&mut buf)?;
+                buf[0]
+            } as i64;
+
+            val |= (byte & SEGMENT_BITS) << (7 * i);
+            if byte & CONTINUE_BIT == 0 {
+                return Ok(Self::new(val));
+            }
+        }
+
+        Err(NetTypesError::InvalidVarLong)
+    }
+
+    pub async fn read_async<R: AsyncRead + Unpin>(cursor: &mut R) -> Result<Self, NetTypesError> {
+        let mut val = 0;
+        for i in 0..10 {
+            let byte = cursor.read_u8().await? as i64;
+
+            val |= (byte & SEGMENT_BITS) << (7 * i);
+            if byte & CONTINUE_BIT == 0 {
+                return Ok(Self::new(val));
+            }
+        }
+
+        Err(NetTypesError::InvalidVarLong)
+    }
+
+    fn to_bytes(self) -> Vec<u8> {
+        let mut val = self.0;
+        let mut bytes = Vec::with_capacity(10);
+        loop {
+            if (val & !SEGMENT_BITS) == 0 {
+                bytes.push(val as u8);
+                return bytes;
+            }
+
+            bytes.push(((val & SEGMENT_BITS) | CONTINUE_BIT) as u8);
+            val = ((val as u64) >> 7) as i64;
+        }
+    }
+
+    pub fn write<W: Write>(&self, cursor: &mut W) -> Result<(), NetTypesError> {
+        cursor.write_all(&self.to_bytes())?;
+        Ok(())
+    }
+
+    pub async fn write_async<W: AsyncWrite + Unpin>(
+        &self,
+        cursor: &mut W,
+    ) -> Result<(), NetTypesError> {
+        cursor.write_all(&self.to_bytes()).await?;
+        Ok(())
+    }
+}
+
+impl NetDecode for VarLong {
+    fn decode<R: Read>(reader: &mut R, _opts: &NetDecodeOpts) -> Result<Self, NetDecodeError> {
+        VarLong::read(reader).map_err(|e| NetDecodeError::ExternalError(e.into()))
+    }
+    async fn decode_async<R: AsyncRead + Unpin>(
+        reader: &mut R,
+        _opts: &NetDecodeOpts,
+    ) -> Result<Self, NetDecodeError> {
+        VarLong::read_async(reader)
+            .await
+            .map_err(|e| NetDecodeError::ExternalError(e.into()))
+    }
+}
+
+impl NetEncode for VarLong {
+    fn encode<W: Write>(
+        &self,
+        writer: &mut W,
+        _opts: &NetEncodeOpts,
+    ) -> Result<(), NetEncodeError> {
+        self.write(writer)
+            .map_err(|e| NetEncodeError::ExternalError(e.into()))
+    }
+
+    async fn encode_async<W: AsyncWrite + Unpin>(
+        &self,
+        writer: &mut W,
+        _opts: &NetEncodeOpts,
+    ) -> Result<(), NetEncodeError> {
+        self.write_async(writer)
+            .await
+            .map_err(|e| NetEncodeError::ExternalError(e.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_varlong_roundtrip() {
+        for value in [0, 1, 127, 128, 25565, i64::MAX, -1, i64::MIN] {
+            let mut bytes = Vec::new();
+            VarLong::new(value).write(&mut bytes).unwrap();
+            assert!(bytes.len() <= 10);
+            assert_eq!(VarLong::read(&mut &bytes[..]).unwrap().0, value);
+        }
+    }
+
+    #[test]
+    fn test_varlong_known_bytes() {
+        let mut bytes = Vec::new();
+        VarLong::new(-1).write(&mut bytes).unwrap();
+        assert_eq!(
+            bytes,
+            [0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01]
+        );
+    }
+}
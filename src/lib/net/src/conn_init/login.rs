@@ -5,8 +5,9 @@ use crate::conn_init::{LoginResult, NetDecodeOpts};
 use crate::connection::StreamWriter;
 use crate::errors::{NetAuthenticationError, NetError, PacketError};
 use crate::packets::incoming::packet_skeleton::PacketSkeleton;
+use crate::packets::outgoing::login_disconnect::LoginDisconnectPacket;
 use crate::packets::outgoing::login_success::{LoginSuccessPacket, LoginSuccessProperties};
-use crate::packets::outgoing::set_default_spawn_position::DEFAULT_SPAWN_POSITION;
+use crate::packets::outgoing::set_default_spawn_position::SetDefaultSpawnPositionPacket;
 use crate::packets::outgoing::{commands::CommandsPacket, registry_data::REGISTRY_PACKETS};
 use crate::ConnState::*;
 use ferrumc_config::server_config::{get_global_config, ServerConfig};
@@ -194,6 +195,34 @@ async fn setup_encryption_and_auth(
     Ok(player_properties)
 }
 
+/// Loads the player's saved data, disconnecting them if it can't be read.
+///
+/// Letting them join with default data instead would overwrite what was saved the next time
+/// their data is saved.
+fn load_player_data(
+    conn_write: &StreamWriter,
+    state: &GlobalState,
+    login_start: &LoginStartPacket,
+) -> Result<Option<OfflinePlayerData>, NetError> {
+    match state
+        .world
+        .load_player_data(Uuid::from_u128(login_start.uuid))
+    {
+        Ok(data) => Ok(data),
+        Err(err) => {
+            error!(
+                "Error loading player data for {}: {:?}",
+                login_start.username, err
+            );
+            let disconnect = LoginDisconnectPacket::new("Failed to load your player data");
+            if let Err(send_err) = conn_write.send_packet(disconnect) {
+                error!("Failed to send login disconnect packet {:?}", send_err);
+            }
+            Err(err.into())
+        }
+    }
+}
+
 /// Sends Login Success packet and waits for client acknowledgement.
 async fn send_login_success(
     conn_read: &mut EncryptedReader<OwnedReadHalf>,
@@ -348,15 +377,11 @@ async fn finish_configuration(
 /// Sends initial play state packets (login_play, abilities, op level).
 fn send_initial_play_packets(
     conn_write: &StreamWriter,
-    state: &GlobalState,
+    player_data: Option<&OfflinePlayerData>,
     player_identity: &PlayerIdentity,
 ) -> Result<(), NetError> {
     // Send login_play
-    let player_data: OfflinePlayerData = state
-        .world
-        .load_player_data(player_identity.uuid)
-        .unwrap_or_default()
-        .unwrap_or_default();
+    let player_data = player_data.cloned().unwrap_or_default();
     let game_mode = player_data.gamemode;
 
     conn_write.send_packet(LoginPlayPacket::new(
@@ -383,32 +408,16 @@ async fn sync_player_position(
     conn_read: &mut EncryptedReader<OwnedReadHalf>,
     conn_write: &StreamWriter,
     state: &GlobalState,
-    player_identity: &PlayerIdentity,
+    player_data: Option<&OfflinePlayerData>,
     compressed: bool,
 ) -> Result<Position, NetError> {
     let teleport_id_i32: i32 = (rand::random::<u32>() & 0x3FFF_FFFF) as i32;
 
-    // Get spawn position from cache or use defaults
-    let (spawn_pos, spawn_rotation) = if let Some(data) = state
-        .world
-        .load_player_data::<OfflinePlayerData>(player_identity.uuid)
-        .unwrap_or_else(|err| {
-            error!(
-                "Error loading player data for {}: {:?}",
-                player_identity.username, err
-            );
-            None
-        }) {
+    // Get spawn position from the saved data or use the world spawn
+    let (spawn_pos, spawn_rotation) = if let Some(data) = player_data {
         (data.position.into(), data.rotation)
     } else {
-        (
-            Position::new(
-                DEFAULT_SPAWN_POSITION.x as f64,
-                DEFAULT_SPAWN_POSITION.y as f64,
-                DEFAULT_SPAWN_POSITION.z as f64,
-            ),
-            Rotation::default(),
-        )
+        (state.info.spawn_position(), Rotation::default())
     };
 
     // Send position sync
@@ -529,7 +538,7 @@ fn send_command_graph(conn_write: &StreamWriter) -> Result<(), NetError> {
 /// # Returns
 /// `(false, LoginResult)` on success, where:
 /// - `false` = keep connection open.
-/// - `LoginResult` contains player identity and compression settings.
+/// - `LoginResult` contains player identity, compression settings and saved player data.
 ///
 /// # Errors
 /// Returns `NetError` for protocol violations, unexpected packets, or I/O errors.
@@ -545,6 +554,7 @@ pub(super) async fn login(
     let compressed = setup_compression(conn_write, config)?;
     let player_properties =
         setup_encryption_and_auth(conn_read, conn_write, config, &login_start, compressed).await?;
+    let player_data = load_player_data(conn_write, &state, &login_start)?;
 
     let player_identity = send_login_success(
         conn_read,
@@ -561,10 +571,17 @@ pub(super) async fn login(
     finish_configuration(conn_read, conn_write, compressed).await?;
 
     // Phase 3: Play State Setup
-    send_initial_play_packets(conn_write, &state, &player_identity)?;
-    let pos =
-        sync_player_position(conn_read, conn_write, &state, &player_identity, compressed).await?;
+    send_initial_play_packets(conn_write, player_data.as_ref(), &player_identity)?;
+    let pos = sync_player_position(
+        conn_read,
+        conn_write,
+        &state,
+        player_data.as_ref(),
+        compressed,
+    )
+    .await?;
     send_player_info(conn_write, &player_identity)?;
+    conn_write.send_packet(SetDefaultSpawnPositionPacket::new(state.info.spawn))?;
     send_initial_chunks(
         conn_write,
         &state,
@@ -582,6 +599,7 @@ pub(super) async fn login(
             player_identity: Some(player_identity),
             compression: compressed,
             client_information_component: Some(client_info.into()),
+            player_data,
         },
     ))
 }
@@ -9,6 +9,7 @@ use crate::packets::incoming::handshake::Handshake;
 use crate::packets::incoming::packet_skeleton::PacketSkeleton;
 use crate::packets::outgoing::login_disconnect::LoginDisconnectPacket;
 use ferrumc_components::player::client_information::ClientInformationComponent;
+use ferrumc_components::player::offline_player_data::OfflinePlayerData;
 use ferrumc_core::identity::player_identity::PlayerIdentity;
 use ferrumc_macros::lookup_packet;
 use ferrumc_net_codec::decode::{NetDecode, NetDecodeOpts};
@@ -24,10 +25,12 @@ use tracing::{error, trace};
 ///
 /// - `player_identity`: Populated when login is successful and a player is identified.
 /// - `compression`: Indicates whether network compression should be enabled for this connection.
+/// - `player_data`: The player's saved data, if they have played before.
 pub(crate) struct LoginResult {
     pub player_identity: Option<PlayerIdentity>,
     pub compression: bool,
     pub client_information_component: Option<ClientInformationComponent>,
+    pub player_data: Option<OfflinePlayerData>,
 }
 
 /// Protocol version supported by this server implementation (Minecraft 1.21.8).
@@ -10,6 +10,7 @@ use crate::{handle_packet, PacketSender};
 use bevy_ecs::prelude::{Component, Entity};
 use crossbeam_channel::Sender;
 use ferrumc_components::player::client_information::ClientInformationComponent;
+use ferrumc_components::player::offline_player_data::OfflinePlayerData;
 use ferrumc_core::identity::player_identity::PlayerIdentity;
 use ferrumc_net_codec::encode::NetEncode;
 use ferrumc_net_codec::encode::NetEncodeOpts;
@@ -236,6 +237,8 @@ pub struct NewConnection {
     pub client_information_component: ClientInformationComponent,
     pub entity_return: oneshot::Sender<Entity>,
     pub disconnect_handle: oneshot::Sender<()>,
+    /// The player's saved data, or `None` if they haven't played before.
+    pub player_data: Option<OfflinePlayerData>,
 }
 
 #[derive(Component)]
@@ -349,6 +352,7 @@ pub async fn handle_connection(
             client_information_component: login_result
                 .client_information_component
                 .unwrap_or_default(),
+            player_data: login_result.player_data,
         })
         .map_err(|_| NetError::Misc("Failed to register new connection".to_string()))?;
 
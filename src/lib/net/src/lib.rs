@@ -14,6 +14,7 @@ mod conn_init;
 pub mod connection;
 pub mod errors;
 pub mod packets;
+pub mod rcon;
 pub mod server;
 
 setup_packet_handling!("\\src\\packets\\incoming");
@@ -12,6 +12,7 @@ pub struct ClickContainer {
     pub button: i8,
     pub mode: VarInt,
     pub changed_slots: LengthPrefixedVec<ChangedSlot>,
+    pub carried_item: PrefixedOptional<HashedItem>,
 }
 
 #[derive(NetDecode)]
@@ -0,0 +1,10 @@
+use ferrumc_macros::{packet, NetDecode};
+use ferrumc_net_codec::net_types::var_int::VarInt;
+
+/// Sent when the player clicks a button in a window, like one of an enchanting table's offers.
+#[derive(NetDecode)]
+#[packet(packet_id = "container_button_click", state = "play")]
+pub struct ContainerButtonClick {
+    pub window_id: VarInt,
+    pub button_id: VarInt,
+}
@@ -42,6 +42,8 @@ pub mod change_game_mode;
 pub mod click_container;
 pub mod client_command;
 pub mod close_container;
+pub mod container_button_click;
 pub mod encryption_response;
 pub mod interact;
+pub mod rename_item;
 pub mod use_item;
@@ -0,0 +1,8 @@
+use ferrumc_macros::{packet, NetDecode};
+
+/// Sent as the player types a name into an anvil.
+#[derive(NetDecode)]
+#[packet(packet_id = "rename_item", state = "play")]
+pub struct RenameItem {
+    pub name: String,
+}
@@ -0,0 +1,108 @@
+//! Boss Bar packet.
+//!
+//! Adds, removes or changes a boss bar at the top of the client's screen.
+
+use ferrumc_core::boss_bar::BossBar;
+use ferrumc_macros::{packet, NetEncode};
+use ferrumc_nbt::NBT;
+use ferrumc_net_codec::net_types::var_int::VarInt;
+use ferrumc_text::TextComponent;
+use uuid::Uuid;
+
+const ACTION_ADD: i32 = 0;
+const ACTION_REMOVE: i32 = 1;
+const ACTION_UPDATE_PROGRESS: i32 = 2;
+const ACTION_UPDATE_TITLE: i32 = 3;
+const ACTION_UPDATE_STYLE: i32 = 4;
+const ACTION_UPDATE_FLAGS: i32 = 5;
+
+#[derive(NetEncode, Debug, Clone)]
+#[packet(packet_id = "boss_event", state = "play")]
+pub struct BossEventPacket {
+    pub uuid: u128,
+    pub action: VarInt,
+    pub data: BossEventData,
+}
+
+/// What follows the action, which depends on it.
+#[derive(NetEncode, Debug, Clone)]
+pub enum BossEventData {
+    Add {
+        title: NBT<TextComponent>,
+        progress: f32,
+        color: VarInt,
+        style: VarInt,
+        flags: u8,
+    },
+    Remove,
+    UpdateProgress(f32),
+    UpdateTitle(NBT<TextComponent>),
+    UpdateStyle {
+        color: VarInt,
+        style: VarInt,
+    },
+    UpdateFlags(u8),
+}
+
+impl BossEventPacket {
+    fn new(uuid: Uuid, action: i32, data: BossEventData) -> Self {
+        Self {
+            uuid: uuid.as_u128(),
+            action: VarInt::new(action),
+            data,
+        }
+    }
+
+    pub fn add(bar: &BossBar) -> Self {
+        Self::new(
+            bar.uuid,
+            ACTION_ADD,
+            BossEventData::Add {
+                title: NBT::new(bar.title.clone()),
+                progress: bar.progress,
+                color: VarInt::new(bar.color.id()),
+                style: VarInt::new(bar.style.id()),
+                flags: bar.flags(),
+            },
+        )
+    }
+
+    pub fn remove(uuid: Uuid) -> Self {
+        Self::new(uuid, ACTION_REMOVE, BossEventData::Remove)
+    }
+
+    pub fn update_progress(bar: &BossBar) -> Self {
+        Self::new(
+            bar.uuid,
+            ACTION_UPDATE_PROGRESS,
+            BossEventData::UpdateProgress(bar.progress),
+        )
+    }
+
+    pub fn update_title(bar: &BossBar) -> Self {
+        Self::new(
+            bar.uuid,
+            ACTION_UPDATE_TITLE,
+            BossEventData::UpdateTitle(NBT::new(bar.title.clone())),
+        )
+    }
+
+    pub fn update_style(bar: &BossBar) -> Self {
+        Self::new(
+            bar.uuid,
+            ACTION_UPDATE_STYLE,
+            BossEventData::UpdateStyle {
+                color: VarInt::new(bar.color.id()),
+                style: VarInt::new(bar.style.id()),
+            },
+        )
+    }
+
+    pub fn update_flags(bar: &BossBar) -> Self {
+        Self::new(
+            bar.uuid,
+            ACTION_UPDATE_FLAGS,
+            BossEventData::UpdateFlags(bar.flags()),
+        )
+    }
+}
@@ -0,0 +1,15 @@
+use ferrumc_macros::{packet, NetEncode};
+
+/// Hides the title on screen.
+#[derive(NetEncode, Debug, Clone)]
+#[packet(packet_id = "clear_titles", state = "play")]
+pub struct ClearTitlesPacket {
+    /// Also forgets the subtitle and goes back to the default times
+    pub reset: bool,
+}
+
+impl ClearTitlesPacket {
+    pub fn new(reset: bool) -> Self {
+        Self { reset }
+    }
+}
@@ -0,0 +1,17 @@
+use ferrumc_macros::{packet, NetEncode};
+use ferrumc_net_codec::net_types::var_int::VarInt;
+
+/// Closes a window the client has open.
+#[derive(NetEncode)]
+#[packet(packet_id = "container_close", state = "play")]
+pub struct CloseContainerPacket {
+    pub window_id: VarInt,
+}
+
+impl CloseContainerPacket {
+    pub fn new(window_id: u8) -> Self {
+        Self {
+            window_id: VarInt::new(window_id as i32),
+        }
+    }
+}
@@ -0,0 +1,40 @@
+//! Damage Event packet.
+//!
+//! Sent to clients when an entity takes damage, so they can play the hurt animation and the
+//! sound for the type of damage.
+
+use ferrumc_macros::{packet, NetEncode};
+use ferrumc_net_codec::net_types::prefixed_optional::PrefixedOptional;
+use ferrumc_net_codec::net_types::var_int::VarInt;
+
+#[derive(NetEncode, Debug)]
+#[packet(packet_id = "damage_event", state = "play")]
+pub struct DamageEventPacket {
+    pub entity_id: VarInt,
+    /// The protocol ID of the damage type
+    pub source_type_id: VarInt,
+    /// The ID of the entity responsible for the damage plus one, or 0 if there is none
+    pub source_cause_id: VarInt,
+    /// The ID of the entity that directly dealt the damage plus one, or 0 if there is none
+    pub source_direct_id: VarInt,
+    pub source_position: PrefixedOptional<DamagePosition>,
+}
+
+#[derive(NetEncode, Debug)]
+pub struct DamagePosition {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl DamageEventPacket {
+    pub fn new(entity_id: i32, source_type_id: u16) -> Self {
+        Self {
+            entity_id: VarInt::new(entity_id),
+            source_type_id: VarInt::new(source_type_id as i32),
+            source_cause_id: VarInt::new(0),
+            source_direct_id: VarInt::new(0),
+            source_position: PrefixedOptional::None,
+        }
+    }
+}
@@ -2,8 +2,11 @@
 use crate::packets::outgoing::entity_metadata::entity_state::{EntityState, EntityStateMask};
 use crate::packets::outgoing::entity_metadata::index_type::EntityMetadataIndexType;
 use crate::packets::outgoing::entity_metadata::value::EntityMetadataValue;
+use ferrumc_inventories::slot::InventorySlot;
 use ferrumc_macros::{packet, NetEncode};
 use ferrumc_net_codec::encode::{NetEncode, NetEncodeOpts};
+use ferrumc_net_codec::net_types::network_position::NetworkPosition;
+use ferrumc_net_codec::net_types::prefixed_optional::PrefixedOptional;
 use ferrumc_net_codec::net_types::var_int::VarInt;
 use std::io::Write;
 
@@ -92,6 +95,83 @@ pub mod constructors {
             )
         }
 
+        /// Entity in sleeping pose. Should be sent along with `living_entity_sleeping_position()`
+        /// so the client knows which bed to put the entity in.
+        pub fn entity_sleeping_pose() -> Self {
+            Self::new(
+                EntityMetadataIndexType::Pose,
+                EntityMetadataValue::Entity6(EntityPose::Sleeping),
+            )
+        }
+
+        /// The bed a living entity is sleeping in, or `None` if it isn't sleeping
+        pub fn living_entity_sleeping_position(bed: Option<NetworkPosition>) -> Self {
+            let bed = match bed {
+                Some(pos) => PrefixedOptional::Some(pos),
+                None => PrefixedOptional::None,
+            };
+            Self::new(
+                EntityMetadataIndexType::OptionalPosition,
+                EntityMetadataValue::LivingEntity14(bed),
+            )
+        }
+
+        /// The golden hearts a player has from absorption (index 15)
+        pub fn player_absorption(amount: f32) -> Self {
+            Self::new(
+                EntityMetadataIndexType::Float,
+                EntityMetadataValue::Player15(amount),
+            )
+        }
+
+        /// The item stack shown by an item entity (index 8)
+        pub fn item_entity_item(item: InventorySlot) -> Self {
+            Self::new(
+                EntityMetadataIndexType::Slot,
+                EntityMetadataValue::ItemEntity8(item),
+            )
+        }
+
+        /// The XP a player gets from an experience orb, which decides how big it looks (index 8)
+        pub fn experience_orb_value(value: u32) -> Self {
+            Self::new(
+                EntityMetadataIndexType::Int,
+                EntityMetadataValue::ExperienceOrb8(VarInt::new(value as i32)),
+            )
+        }
+
+        /// An arrow shot from a fully drawn bow, which leaves a trail of particles (index 8)
+        pub fn arrow_critical() -> Self {
+            Self::new(
+                EntityMetadataIndexType::Byte,
+                EntityMetadataValue::AbstractArrow8(0x01),
+            )
+        }
+
+        /// Ticks until primed TNT explodes, which the client counts down to flash it (index 8)
+        pub fn primed_tnt_fuse(ticks: u32) -> Self {
+            Self::new(
+                EntityMetadataIndexType::Int,
+                EntityMetadataValue::PrimedTnt8(VarInt::new(ticks as i32)),
+            )
+        }
+
+        /// Whether a creeper is swelling up (1) or calming down (-1) (index 16)
+        pub fn creeper_swell_direction(direction: i32) -> Self {
+            Self::new(
+                EntityMetadataIndexType::Int,
+                EntityMetadataValue::Creeper16(VarInt::new(direction)),
+            )
+        }
+
+        /// Makes an ageable mob, like a chicken, a baby (index 16)
+        pub fn ageable_mob_baby() -> Self {
+            Self::new(
+                EntityMetadataIndexType::Boolean,
+                EntityMetadataValue::AgeableMob16(true),
+            )
+        }
+
         /// Entity state with swimming bit set
         pub fn entity_swimming_state() -> Self {
             Self::new(
@@ -134,8 +214,13 @@ mod index_type {
     /// See: https://minecraft.wiki/w/Minecraft_Wiki:Projects/wiki.vg_merge/Entity_metadata#Entity_Metadata_Format
     #[derive(Debug, Clone, Copy)]
     pub enum EntityMetadataIndexType {
-        Byte, // (0) Used for bit masks and small numbers
-        Pose, // (21) Used for entity pose - protocol 772 (1.21.4)
+        Byte,             // (0) Used for bit masks and small numbers
+        Int,              // (1) A VarInt
+        Float,            // (3) A single precision float
+        Boolean,          // (8) A single byte, 0 or 1
+        Slot,             // (7) An item stack
+        OptionalPosition, // (11) Boolean followed by a position if true
+        Pose,             // (21) Used for entity pose - protocol 772 (1.21.4)
     }
 
     impl EntityMetadataIndexType {
@@ -143,6 +228,11 @@ mod index_type {
             use EntityMetadataIndexType::*;
             let val = match self {
                 Byte => 0,
+                Int => 1,
+                Float => 3,
+                Boolean => 8,
+                Slot => 7,
+                OptionalPosition => 11,
                 Pose => 21,
             };
 
@@ -184,6 +274,14 @@ mod value {
     pub enum EntityMetadataValue {
         Entity0(EntityStateMask),
         Entity6(EntityPose),
+        ItemEntity8(InventorySlot),
+        ExperienceOrb8(VarInt),
+        AbstractArrow8(u8),
+        PrimedTnt8(VarInt),
+        LivingEntity14(PrefixedOptional<NetworkPosition>),
+        Player15(f32),
+        AgeableMob16(bool),
+        Creeper16(VarInt),
     }
 
     impl EntityMetadataValue {
@@ -192,6 +290,10 @@ mod value {
             match self {
                 Entity0(_) => 0,
                 Entity6(_) => 6,
+                ItemEntity8(_) | ExperienceOrb8(_) | AbstractArrow8(_) | PrimedTnt8(_) => 8,
+                LivingEntity14(_) => 14,
+                Player15(_) => 15,
+                AgeableMob16(_) | Creeper16(_) => 16,
             }
         }
     }
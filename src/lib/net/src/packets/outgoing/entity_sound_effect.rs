@@ -0,0 +1,39 @@
+//! Entity Sound Effect packet.
+//!
+//! Plays a sound that follows an entity around while it plays.
+
+use crate::packets::outgoing::sound_effect::SoundEvent;
+use ferrumc_core::sound::SoundCategory;
+use ferrumc_macros::{packet, NetEncode};
+use ferrumc_net_codec::net_types::var_int::VarInt;
+
+#[derive(NetEncode, Debug, Clone)]
+#[packet(packet_id = "sound_entity", state = "play")]
+pub struct EntitySoundEffectPacket {
+    pub sound: SoundEvent,
+    pub category: VarInt,
+    pub entity_id: VarInt,
+    pub volume: f32,
+    pub pitch: f32,
+    pub seed: i64,
+}
+
+impl EntitySoundEffectPacket {
+    pub fn new(
+        sound: SoundEvent,
+        category: SoundCategory,
+        entity_id: i32,
+        volume: f32,
+        pitch: f32,
+        seed: i64,
+    ) -> Self {
+        Self {
+            sound,
+            category: VarInt::new(category.id()),
+            entity_id: VarInt::new(entity_id),
+            volume,
+            pitch,
+            seed,
+        }
+    }
+}
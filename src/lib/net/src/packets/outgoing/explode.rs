@@ -0,0 +1,47 @@
+//! Explosion packet.
+//!
+//! Plays an explosion's particles and sound, and pushes the receiving player away from it. The
+//! destroyed blocks are sent separately as block updates.
+
+use crate::packets::outgoing::sound_effect::SoundEvent;
+use ferrumc_macros::{packet, NetEncode};
+use ferrumc_net_codec::net_types::prefixed_optional::PrefixedOptional;
+use ferrumc_particles::ParticleType;
+
+#[derive(NetEncode)]
+#[packet(packet_id = "explode", state = "play")]
+pub struct ExplodePacket {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    /// The velocity added to the receiving player, if they were pushed
+    pub player_knockback: PrefixedOptional<Knockback>,
+    pub particle: ParticleType,
+    pub sound: SoundEvent,
+}
+
+#[derive(NetEncode, Debug, Clone, Copy)]
+pub struct Knockback {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl ExplodePacket {
+    pub fn new(
+        x: f64,
+        y: f64,
+        z: f64,
+        player_knockback: Option<Knockback>,
+        particle: ParticleType,
+    ) -> Self {
+        Self {
+            x,
+            y,
+            z,
+            player_knockback: PrefixedOptional::new(player_knockback),
+            particle,
+            sound: SoundEvent::new("minecraft:entity.generic.explode"),
+        }
+    }
+}
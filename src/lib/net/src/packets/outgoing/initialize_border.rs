@@ -0,0 +1,38 @@
+use ferrumc_core::world_border::{WorldBorder, PORTAL_TELEPORT_BOUNDARY};
+use ferrumc_macros::{packet, NetEncode};
+use ferrumc_net_codec::net_types::var_int::VarInt;
+use ferrumc_net_codec::net_types::var_long::VarLong;
+
+/// Sets up the world border on the client, sent on join and when changing dimensions.
+#[derive(NetEncode)]
+#[packet(packet_id = "initialize_border", state = "play")]
+pub struct InitializeBorderPacket {
+    pub x: f64,
+    pub z: f64,
+    pub old_diameter: f64,
+    pub new_diameter: f64,
+    /// Milliseconds until `new_diameter` is reached.
+    pub speed: VarLong,
+    /// How far out portals can teleport entities, usually 29999984.
+    pub portal_teleport_boundary: VarInt,
+    /// Distance from the border, in blocks, at which the warning starts.
+    pub warning_blocks: VarInt,
+    /// Seconds before a shrinking border reaches the player at which the warning starts.
+    pub warning_time: VarInt,
+}
+
+impl InitializeBorderPacket {
+    pub fn new(border: &WorldBorder) -> Self {
+        let (x, z) = border.center();
+        Self {
+            x,
+            z,
+            old_diameter: border.diameter(),
+            new_diameter: border.target_diameter(),
+            speed: VarLong::new(border.remaining().as_millis() as i64),
+            portal_teleport_boundary: VarInt::new(PORTAL_TELEPORT_BOUNDARY),
+            warning_blocks: VarInt::new(border.warning_blocks),
+            warning_time: VarInt::new(border.warning_time),
+        }
+    }
+}
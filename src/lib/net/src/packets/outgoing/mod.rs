@@ -21,6 +21,7 @@ pub mod system_message;
 
 pub mod remove_entities;
 pub mod spawn_entity;
+pub mod take_item_entity;
 
 pub mod entity_animation;
 pub mod entity_event;
@@ -47,18 +48,47 @@ pub mod commands;
 pub(crate) mod set_compression;
 
 pub mod client_bound_plugin_message;
+pub mod close_container;
 pub mod encryption_request;
+pub mod open_screen;
 pub mod set_container_content;
+pub mod set_container_property;
 pub mod set_container_slot;
 pub mod set_player_inventory_slot;
 
+pub mod entity_sound_effect;
 pub mod particle;
+pub mod sound_effect;
+pub mod stop_sound;
 
 pub mod unload_chunk;
 
+pub mod damage_event;
+pub mod explode;
 pub mod hurt_animation;
+pub mod remove_mob_effect;
 pub mod respawn;
+pub mod set_cooldown;
+pub mod set_experience;
 pub mod set_health;
+pub mod update_attributes;
+pub mod update_mob_effect;
 pub mod update_time;
 
+pub mod initialize_border;
+
+pub mod reset_score;
+pub mod set_display_objective;
+pub mod set_objective;
+pub mod set_player_team;
+pub mod set_score;
+
+pub mod boss_event;
+pub mod clear_titles;
+pub mod set_action_bar_text;
+pub mod set_subtitle_text;
+pub mod set_title_text;
+pub mod set_titles_animation;
+pub mod tab_list_header_footer;
+
 pub mod synchronise_vehicle_position;
@@ -0,0 +1,24 @@
+use ferrumc_macros::{packet, NetEncode};
+use ferrumc_nbt::NBT;
+use ferrumc_net_codec::net_types::var_int::VarInt;
+use ferrumc_text::TextComponent;
+
+/// Opens a window on the client, like an enchanting table's.
+#[derive(NetEncode)]
+#[packet(packet_id = "open_screen", state = "play")]
+pub struct OpenScreen {
+    pub window_id: VarInt,
+    /// The protocol ID of the window's type, from the `minecraft:menu` registry.
+    pub window_type: VarInt,
+    pub title: NBT<TextComponent>,
+}
+
+impl OpenScreen {
+    pub fn new(window_id: u8, window_type: i32, title: TextComponent) -> Self {
+        Self {
+            window_id: VarInt::new(window_id as i32),
+            window_type: VarInt::new(window_type),
+            title: NBT::new(title),
+        }
+    }
+}
@@ -22,4 +22,14 @@ impl RemoveEntitiesPacket {
             entity_ids: LengthPrefixedVec::new(entity_ids),
         }
     }
+
+    /// Creates the packet from network entity IDs, e.g. `EntityIdentity::entity_id`.
+    pub fn from_ids<T>(entity_ids: T) -> Self
+    where
+        T: IntoIterator<Item = i32>,
+    {
+        Self {
+            entity_ids: LengthPrefixedVec::new(entity_ids.into_iter().map(VarInt::new).collect()),
+        }
+    }
 }
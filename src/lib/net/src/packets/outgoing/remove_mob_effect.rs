@@ -0,0 +1,23 @@
+//! Remove Mob Effect packet.
+//!
+//! Sent to clients when an entity loses an effect.
+
+use ferrumc_macros::{packet, NetEncode};
+use ferrumc_net_codec::net_types::var_int::VarInt;
+
+#[derive(NetEncode, Debug)]
+#[packet(packet_id = "remove_mob_effect", state = "play")]
+pub struct RemoveMobEffectPacket {
+    pub entity_id: VarInt,
+    /// The protocol ID of the effect
+    pub effect_id: VarInt,
+}
+
+impl RemoveMobEffectPacket {
+    pub fn new(entity_id: i32, effect_id: u8) -> Self {
+        Self {
+            entity_id: VarInt::new(entity_id),
+            effect_id: VarInt::new(effect_id as i32),
+        }
+    }
+}
@@ -0,0 +1,26 @@
+//! Reset Score packet.
+//!
+//! Removes the score of a holder from an objective, or from every objective.
+
+use ferrumc_macros::{packet, NetEncode};
+use ferrumc_net_codec::net_types::prefixed_optional::PrefixedOptional;
+
+#[derive(NetEncode, Debug, Clone)]
+#[packet(packet_id = "reset_score", state = "play")]
+pub struct ResetScorePacket {
+    pub holder: String,
+    /// Every score of the holder is removed if this isn't set
+    pub objective: PrefixedOptional<String>,
+}
+
+impl ResetScorePacket {
+    pub fn new(holder: &str, objective: Option<&str>) -> Self {
+        Self {
+            holder: holder.to_string(),
+            objective: match objective {
+                Some(objective) => PrefixedOptional::Some(objective.to_string()),
+                None => PrefixedOptional::None,
+            },
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use ferrumc_macros::{packet, NetEncode};
+use ferrumc_nbt::NBT;
+use ferrumc_text::TextComponent;
+
+/// Shows text above the hotbar.
+#[derive(NetEncode, Debug, Clone)]
+#[packet(packet_id = "set_action_bar_text", state = "play")]
+pub struct SetActionBarTextPacket {
+    pub text: NBT<TextComponent>,
+}
+
+impl SetActionBarTextPacket {
+    pub fn new(text: TextComponent) -> Self {
+        Self {
+            text: NBT::new(text),
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use ferrumc_macros::{packet, NetEncode};
+use ferrumc_net_codec::net_types::var_int::VarInt;
+
+/// Updates a value a window shows, like an enchanting table's level costs or an anvil's cost.
+#[derive(NetEncode)]
+#[packet(packet_id = "container_set_data", state = "play")]
+pub struct SetContainerProperty {
+    pub window_id: VarInt,
+    /// Which value it is. Depends on the window's type.
+    pub property: i16,
+    pub value: i16,
+}
+
+impl SetContainerProperty {
+    pub fn new(window_id: u8, property: i16, value: i16) -> Self {
+        Self {
+            window_id: VarInt::new(window_id as i32),
+            property,
+            value,
+        }
+    }
+}
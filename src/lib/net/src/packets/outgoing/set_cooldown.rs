@@ -0,0 +1,27 @@
+//! Set Cooldown packet.
+//!
+//! Greys out an item in the client's inventory for a while, like ender pearls after throwing one.
+
+use ferrumc_macros::{packet, NetEncode};
+use ferrumc_net_codec::net_types::var_int::VarInt;
+
+/// Packet sent to put a group of items on cooldown.
+///
+/// The client only uses this for the overlay, the server still has to check the cooldown itself.
+#[derive(NetEncode, Debug)]
+#[packet(packet_id = "cooldown", state = "play")]
+pub struct SetCooldownPacket {
+    /// The cooldown group, which is the item's name unless it has a `use_cooldown` component
+    pub group: String,
+    /// How long the cooldown lasts in ticks, or 0 to clear it
+    pub ticks: VarInt,
+}
+
+impl SetCooldownPacket {
+    pub fn new(group: impl Into<String>, ticks: i32) -> Self {
+        Self {
+            group: group.into(),
+            ticks: VarInt::new(ticks),
+        }
+    }
+}
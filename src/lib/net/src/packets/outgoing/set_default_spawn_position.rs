@@ -8,8 +8,8 @@ pub struct SetDefaultSpawnPositionPacket {
     pub angle: f32,
 }
 
-// Spawn in chunk (1, 1) at y=100 to ensure spawning above ground, since for some reason the terrain
-// gen can't create land at (0, 0)
+// New worlds spawn in chunk (1, 1) at y=100 to ensure spawning above ground, since for some reason
+// the terrain gen can't create land at (0, 0)
 pub const DEFAULT_SPAWN_POSITION: NetworkPosition = NetworkPosition {
     x: 16,
     y: 100,
@@ -18,16 +18,12 @@ pub const DEFAULT_SPAWN_POSITION: NetworkPosition = NetworkPosition {
 
 const DEFAULT_ANGLE: f32 = 0.0;
 
-impl Default for SetDefaultSpawnPositionPacket {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
 impl SetDefaultSpawnPositionPacket {
-    pub fn new() -> Self {
+    /// Points compasses at the world's spawn.
+    pub fn new(spawn: (i32, i32, i32)) -> Self {
+        let (x, y, z) = spawn;
         Self {
-            spawn_position: DEFAULT_SPAWN_POSITION,
+            spawn_position: NetworkPosition { x, y: y as i16, z },
             angle: DEFAULT_ANGLE,
         }
     }
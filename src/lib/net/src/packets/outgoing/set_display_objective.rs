@@ -0,0 +1,24 @@
+//! Display Objective packet.
+//!
+//! Shows an objective in one of the scoreboard's display slots, like the sidebar.
+
+use ferrumc_core::scoreboard::DisplaySlot;
+use ferrumc_macros::{packet, NetEncode};
+use ferrumc_net_codec::net_types::var_int::VarInt;
+
+#[derive(NetEncode, Debug, Clone)]
+#[packet(packet_id = "set_display_objective", state = "play")]
+pub struct SetDisplayObjectivePacket {
+    pub slot: VarInt,
+    /// An empty name clears the slot
+    pub objective: String,
+}
+
+impl SetDisplayObjectivePacket {
+    pub fn new(slot: DisplaySlot, objective: Option<&str>) -> Self {
+        Self {
+            slot: VarInt::new(slot.id()),
+            objective: objective.unwrap_or_default().to_string(),
+        }
+    }
+}
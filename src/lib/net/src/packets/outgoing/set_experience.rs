@@ -0,0 +1,22 @@
+use ferrumc_macros::{packet, NetEncode};
+use ferrumc_net_codec::net_types::var_int::VarInt;
+
+/// Sent by the server to update the player's experience bar and level.
+#[derive(NetEncode)]
+#[packet(packet_id = "set_experience", state = "play")]
+pub struct SetExperience {
+    /// How full the bar is, from 0.0 to 1.0
+    pub progress: f32,
+    pub level: VarInt,
+    pub total_experience: VarInt,
+}
+
+impl SetExperience {
+    pub fn new(progress: f32, level: u32, total_experience: u32) -> Self {
+        Self {
+            progress,
+            level: VarInt::new(level as i32),
+            total_experience: VarInt::new(total_experience as i32),
+        }
+    }
+}
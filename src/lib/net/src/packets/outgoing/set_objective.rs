@@ -0,0 +1,66 @@
+//! Update Objectives packet.
+//!
+//! Adds, removes or changes a scoreboard objective on the client.
+
+use ferrumc_core::scoreboard::Objective;
+use ferrumc_macros::{packet, NetEncode};
+use ferrumc_nbt::NBT;
+use ferrumc_net_codec::net_types::var_int::VarInt;
+use ferrumc_text::TextComponent;
+
+const MODE_CREATE: u8 = 0;
+const MODE_REMOVE: u8 = 1;
+const MODE_UPDATE: u8 = 2;
+
+#[derive(NetEncode, Debug, Clone)]
+#[packet(packet_id = "set_objective", state = "play")]
+pub struct SetObjectivePacket {
+    pub name: String,
+    pub mode: u8,
+    /// Left out when the objective is being removed
+    pub info: Option<ObjectiveInfo>,
+}
+
+#[derive(NetEncode, Debug, Clone)]
+pub struct ObjectiveInfo {
+    pub display_name: NBT<TextComponent>,
+    pub render_type: VarInt,
+    /// Always false, so scores use the client's default number format
+    pub has_number_format: bool,
+}
+
+impl ObjectiveInfo {
+    fn new(objective: &Objective) -> Self {
+        Self {
+            display_name: NBT::new(TextComponent::from(objective.display_name.clone())),
+            render_type: VarInt::new(objective.render_type.id()),
+            has_number_format: false,
+        }
+    }
+}
+
+impl SetObjectivePacket {
+    pub fn create(name: &str, objective: &Objective) -> Self {
+        Self {
+            name: name.to_string(),
+            mode: MODE_CREATE,
+            info: Some(ObjectiveInfo::new(objective)),
+        }
+    }
+
+    pub fn update(name: &str, objective: &Objective) -> Self {
+        Self {
+            name: name.to_string(),
+            mode: MODE_UPDATE,
+            info: Some(ObjectiveInfo::new(objective)),
+        }
+    }
+
+    pub fn remove(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            mode: MODE_REMOVE,
+            info: None,
+        }
+    }
+}
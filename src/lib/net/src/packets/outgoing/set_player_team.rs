@@ -0,0 +1,102 @@
+//! Update Teams packet.
+//!
+//! Adds, removes or changes a team on the client, or changes who is on it.
+
+use ferrumc_core::scoreboard::Team;
+use ferrumc_macros::{packet, NetEncode};
+use ferrumc_nbt::NBT;
+use ferrumc_net_codec::net_types::length_prefixed_vec::LengthPrefixedVec;
+use ferrumc_net_codec::net_types::var_int::VarInt;
+use ferrumc_text::TextComponent;
+
+const MODE_CREATE: u8 = 0;
+const MODE_REMOVE: u8 = 1;
+const MODE_UPDATE: u8 = 2;
+const MODE_ADD_MEMBERS: u8 = 3;
+const MODE_REMOVE_MEMBERS: u8 = 4;
+
+#[derive(NetEncode, Debug, Clone)]
+#[packet(packet_id = "set_player_team", state = "play")]
+pub struct SetPlayerTeamPacket {
+    pub name: String,
+    pub mode: u8,
+    /// Sent when the team is created or changed
+    pub info: Option<TeamInfo>,
+    /// Player names or entity UUIDs, sent when the team is created or its members change
+    pub members: Option<LengthPrefixedVec<String>>,
+}
+
+#[derive(NetEncode, Debug, Clone)]
+pub struct TeamInfo {
+    pub display_name: NBT<TextComponent>,
+    /// 0x01 for friendly fire, 0x02 to see invisible teammates
+    pub flags: u8,
+    pub name_tag_visibility: VarInt,
+    pub collision_rule: VarInt,
+    pub color: VarInt,
+    pub prefix: NBT<TextComponent>,
+    pub suffix: NBT<TextComponent>,
+}
+
+impl TeamInfo {
+    fn new(team: &Team) -> Self {
+        Self {
+            display_name: NBT::new(TextComponent::from(team.display_name.clone())),
+            flags: team.flags(),
+            name_tag_visibility: VarInt::new(team.name_tag_visibility.id()),
+            collision_rule: VarInt::new(team.collision_rule.id()),
+            color: VarInt::new(team.color.id()),
+            prefix: NBT::new(TextComponent::from(team.prefix.clone())),
+            suffix: NBT::new(TextComponent::from(team.suffix.clone())),
+        }
+    }
+}
+
+impl SetPlayerTeamPacket {
+    pub fn create(name: &str, team: &Team) -> Self {
+        Self {
+            name: name.to_string(),
+            mode: MODE_CREATE,
+            info: Some(TeamInfo::new(team)),
+            members: Some(LengthPrefixedVec::new(
+                team.members().map(str::to_string).collect(),
+            )),
+        }
+    }
+
+    pub fn remove(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            mode: MODE_REMOVE,
+            info: None,
+            members: None,
+        }
+    }
+
+    pub fn update(name: &str, team: &Team) -> Self {
+        Self {
+            name: name.to_string(),
+            mode: MODE_UPDATE,
+            info: Some(TeamInfo::new(team)),
+            members: None,
+        }
+    }
+
+    pub fn add_members(name: &str, members: Vec<String>) -> Self {
+        Self {
+            name: name.to_string(),
+            mode: MODE_ADD_MEMBERS,
+            info: None,
+            members: Some(LengthPrefixedVec::new(members)),
+        }
+    }
+
+    pub fn remove_members(name: &str, members: Vec<String>) -> Self {
+        Self {
+            name: name.to_string(),
+            mode: MODE_REMOVE_MEMBERS,
+            info: None,
+            members: Some(LengthPrefixedVec::new(members)),
+        }
+    }
+}
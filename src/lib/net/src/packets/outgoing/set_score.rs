@@ -0,0 +1,34 @@
+//! Update Score packet.
+//!
+//! Sets the score of a holder in an objective.
+
+use ferrumc_macros::{packet, NetEncode};
+use ferrumc_nbt::NBT;
+use ferrumc_net_codec::net_types::prefixed_optional::PrefixedOptional;
+use ferrumc_net_codec::net_types::var_int::VarInt;
+use ferrumc_text::TextComponent;
+
+#[derive(NetEncode, Debug, Clone)]
+#[packet(packet_id = "set_score", state = "play")]
+pub struct SetScorePacket {
+    /// The player name or entity UUID the score belongs to
+    pub holder: String,
+    pub objective: String,
+    pub value: VarInt,
+    /// Shown instead of the holder's name, if set
+    pub display_name: PrefixedOptional<NBT<TextComponent>>,
+    /// Always false, so the score uses the objective's number format
+    pub has_number_format: bool,
+}
+
+impl SetScorePacket {
+    pub fn new(holder: &str, objective: &str, value: i32) -> Self {
+        Self {
+            holder: holder.to_string(),
+            objective: objective.to_string(),
+            value: VarInt::new(value),
+            display_name: PrefixedOptional::None,
+            has_number_format: false,
+        }
+    }
+}
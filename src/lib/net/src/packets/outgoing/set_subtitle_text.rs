@@ -0,0 +1,18 @@
+use ferrumc_macros::{packet, NetEncode};
+use ferrumc_nbt::NBT;
+use ferrumc_text::TextComponent;
+
+/// Sets the subtitle shown under the next title.
+#[derive(NetEncode, Debug, Clone)]
+#[packet(packet_id = "set_subtitle_text", state = "play")]
+pub struct SetSubtitleTextPacket {
+    pub text: NBT<TextComponent>,
+}
+
+impl SetSubtitleTextPacket {
+    pub fn new(text: TextComponent) -> Self {
+        Self {
+            text: NBT::new(text),
+        }
+    }
+}
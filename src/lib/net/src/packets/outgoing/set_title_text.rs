@@ -0,0 +1,18 @@
+use ferrumc_macros::{packet, NetEncode};
+use ferrumc_nbt::NBT;
+use ferrumc_text::TextComponent;
+
+/// Shows a title in the middle of the screen, with the subtitle set before it.
+#[derive(NetEncode, Debug, Clone)]
+#[packet(packet_id = "set_title_text", state = "play")]
+pub struct SetTitleTextPacket {
+    pub text: NBT<TextComponent>,
+}
+
+impl SetTitleTextPacket {
+    pub fn new(text: TextComponent) -> Self {
+        Self {
+            text: NBT::new(text),
+        }
+    }
+}
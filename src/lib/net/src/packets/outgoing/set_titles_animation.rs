@@ -0,0 +1,20 @@
+use ferrumc_macros::{packet, NetEncode};
+
+/// Sets how long titles take to fade in, stay on screen and fade out, in ticks.
+#[derive(NetEncode, Debug, Clone)]
+#[packet(packet_id = "set_titles_animation", state = "play")]
+pub struct SetTitlesAnimationPacket {
+    pub fade_in: i32,
+    pub stay: i32,
+    pub fade_out: i32,
+}
+
+impl SetTitlesAnimationPacket {
+    pub fn new(fade_in: i32, stay: i32, fade_out: i32) -> Self {
+        Self {
+            fade_in,
+            stay,
+            fade_out,
+        }
+    }
+}
@@ -0,0 +1,67 @@
+//! Sound Effect packet.
+//!
+//! Plays a sound at a fixed position in the world.
+
+use bevy_math::DVec3;
+use ferrumc_core::sound::SoundCategory;
+use ferrumc_macros::{packet, NetEncode};
+use ferrumc_net_codec::net_types::prefixed_optional::PrefixedOptional;
+use ferrumc_net_codec::net_types::var_int::VarInt;
+
+#[derive(NetEncode, Debug, Clone)]
+#[packet(packet_id = "sound", state = "play")]
+pub struct SoundEffectPacket {
+    pub sound: SoundEvent,
+    pub category: VarInt,
+    /// The position in eighths of a block
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+    pub volume: f32,
+    pub pitch: f32,
+    /// Picks between the variants of the sound, so every client plays the same one
+    pub seed: i64,
+}
+
+/// A sound sent by name rather than registry ID.
+#[derive(NetEncode, Debug, Clone)]
+pub struct SoundEvent {
+    /// Always 0, which tells the client the sound follows inline
+    holder: VarInt,
+    pub name: String,
+    /// How far away the sound can be heard, if it doesn't depend on the volume
+    pub fixed_range: PrefixedOptional<f32>,
+}
+
+impl SoundEvent {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            holder: VarInt::new(0),
+            name: name.into(),
+            fixed_range: PrefixedOptional::None,
+        }
+    }
+}
+
+impl SoundEffectPacket {
+    pub fn new(
+        sound: SoundEvent,
+        category: SoundCategory,
+        position: DVec3,
+        volume: f32,
+        pitch: f32,
+        seed: i64,
+    ) -> Self {
+        let position = position * 8.0;
+        Self {
+            sound,
+            category: VarInt::new(category.id()),
+            x: position.x as i32,
+            y: position.y as i32,
+            z: position.z as i32,
+            volume,
+            pitch,
+            seed,
+        }
+    }
+}
@@ -5,6 +5,7 @@ use ferrumc_core::identity::entity_identity::EntityIdentity;
 use ferrumc_core::identity::player_identity::PlayerIdentity;
 use ferrumc_core::transform::position::Position;
 use ferrumc_core::transform::rotation::Rotation;
+use ferrumc_core::transform::velocity::Velocity;
 use ferrumc_macros::{get_registry_entry, packet, NetEncode};
 use ferrumc_net_codec::net_types::angle::NetAngle;
 use ferrumc_net_codec::net_types::var_int::VarInt;
@@ -124,4 +125,21 @@ impl SpawnEntityPacket {
             velocity_z: 0,
         })
     }
+
+    /// Sets the entity's extra data, whose meaning depends on its type. For projectiles it's the
+    /// entity ID of their owner plus one.
+    pub fn with_data(mut self, data: i32) -> Self {
+        self.data = VarInt::new(data);
+        self
+    }
+
+    /// Sets the velocity the entity spawns with, so the client can start moving it right away.
+    pub fn with_velocity(mut self, velocity: &Velocity) -> Self {
+        // Velocity is sent in units of 1/8000 of a block per tick
+        let encode = |v: f32| (v.clamp(-3.9, 3.9) * 8000.0) as i16;
+        self.velocity_x = encode(velocity.x);
+        self.velocity_y = encode(velocity.y);
+        self.velocity_z = encode(velocity.z);
+        self
+    }
 }
@@ -0,0 +1,36 @@
+//! Stop Sound packet.
+//!
+//! Stops sounds that are playing for the receiving player, optionally only ones in a category or
+//! with a name.
+
+use ferrumc_core::sound::SoundCategory;
+use ferrumc_macros::{packet, NetEncode};
+use ferrumc_net_codec::net_types::var_int::VarInt;
+
+#[derive(NetEncode, Debug, Clone)]
+#[packet(packet_id = "stop_sound", state = "play")]
+pub struct StopSoundPacket {
+    /// 0x01 if a category follows, 0x02 if a sound name follows
+    pub flags: u8,
+    pub category: Option<VarInt>,
+    pub sound: Option<String>,
+}
+
+impl StopSoundPacket {
+    /// Stops every sound in `category`, or every sound if it's `None`. If `sound` is set only
+    /// sounds with that name are stopped.
+    pub fn new(category: Option<SoundCategory>, sound: Option<String>) -> Self {
+        let mut flags = 0;
+        if category.is_some() {
+            flags |= 0x01;
+        }
+        if sound.is_some() {
+            flags |= 0x02;
+        }
+        Self {
+            flags,
+            category: category.map(|category| VarInt::new(category.id())),
+            sound,
+        }
+    }
+}
@@ -1,4 +1,3 @@
-use crate::packets::outgoing::set_default_spawn_position::DEFAULT_SPAWN_POSITION;
 use ferrumc_core::transform::position::Position;
 use ferrumc_core::transform::rotation::Rotation;
 use ferrumc_macros::{packet, NetEncode};
@@ -20,24 +19,6 @@ pub struct SynchronizePlayerPositionPacket {
     pub flags: TeleportFlags,
 }
 
-impl Default for SynchronizePlayerPositionPacket {
-    fn default() -> Self {
-        let default_pos = DEFAULT_SPAWN_POSITION;
-        Self::new(
-            (
-                default_pos.x as f64,
-                default_pos.y as f64,
-                default_pos.z as f64,
-            ),
-            (0.0, 0.0, 0.0),
-            0.0,
-            0.0,
-            0,
-            VarInt::new(0),
-        )
-    }
-}
-
 impl SynchronizePlayerPositionPacket {
     pub fn new(
         xyz: (f64, f64, f64),
@@ -0,0 +1,20 @@
+use ferrumc_macros::{packet, NetEncode};
+use ferrumc_nbt::NBT;
+use ferrumc_text::TextComponent;
+
+/// Sets the text above and below the player list. Empty text hides it.
+#[derive(NetEncode, Debug, Clone)]
+#[packet(packet_id = "tab_list", state = "play")]
+pub struct TabListHeaderFooterPacket {
+    pub header: NBT<TextComponent>,
+    pub footer: NBT<TextComponent>,
+}
+
+impl TabListHeaderFooterPacket {
+    pub fn new(header: TextComponent, footer: TextComponent) -> Self {
+        Self {
+            header: NBT::new(header),
+            footer: NBT::new(footer),
+        }
+    }
+}
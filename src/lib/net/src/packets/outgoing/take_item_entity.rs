@@ -0,0 +1,33 @@
+//! Take Item Entity packet.
+//!
+//! Sent to clients to play the pickup animation, where an item flies into the entity that
+//! collected it.
+
+use ferrumc_macros::{packet, NetEncode};
+use ferrumc_net_codec::net_types::var_int::VarInt;
+
+/// Packet sent when an entity picks up an item.
+///
+/// This only plays the animation, the item entity still has to be removed separately if the
+/// whole stack was picked up.
+#[derive(NetEncode, Debug)]
+#[packet(packet_id = "take_item_entity", state = "play")]
+pub struct TakeItemEntityPacket {
+    /// The entity ID of the item being picked up
+    pub collected_entity_id: VarInt,
+    /// The entity ID of the entity picking the item up
+    pub collector_entity_id: VarInt,
+    /// How many items were picked up
+    pub pickup_item_count: VarInt,
+}
+
+impl TakeItemEntityPacket {
+    /// Create a new take item entity packet.
+    pub fn new(collected_entity_id: i32, collector_entity_id: i32, count: i32) -> Self {
+        Self {
+            collected_entity_id: VarInt::new(collected_entity_id),
+            collector_entity_id: VarInt::new(collector_entity_id),
+            pickup_item_count: VarInt::new(count),
+        }
+    }
+}
@@ -0,0 +1,65 @@
+//! Update Attributes packet.
+//!
+//! Sent to clients to set the value of an entity's attributes, such as its movement speed.
+//! Clients work out the final value from the base value and modifiers themselves.
+
+use ferrumc_components::attributes::Attributes;
+use ferrumc_macros::{packet, NetEncode};
+use ferrumc_net_codec::net_types::length_prefixed_vec::LengthPrefixedVec;
+use ferrumc_net_codec::net_types::var_int::VarInt;
+
+#[derive(NetEncode, Debug)]
+#[packet(packet_id = "update_attributes", state = "play")]
+pub struct UpdateAttributesPacket {
+    pub entity_id: VarInt,
+    pub properties: LengthPrefixedVec<AttributeProperty>,
+}
+
+#[derive(NetEncode, Debug, Clone)]
+pub struct AttributeProperty {
+    /// The protocol ID of the attribute
+    pub id: VarInt,
+    pub value: f64,
+    pub modifiers: LengthPrefixedVec<AttributeModifierData>,
+}
+
+#[derive(NetEncode, Debug, Clone)]
+pub struct AttributeModifierData {
+    /// The resource location identifying the modifier, e.g. `minecraft:effect.speed`
+    pub id: String,
+    pub amount: f64,
+    /// 0 to add, 1 to multiply the base value and 2 to multiply the total
+    pub operation: u8,
+}
+
+impl UpdateAttributesPacket {
+    pub fn new(entity_id: i32, properties: Vec<AttributeProperty>) -> Self {
+        Self {
+            entity_id: VarInt::new(entity_id),
+            properties: LengthPrefixedVec::new(properties),
+        }
+    }
+
+    /// Creates the packet with every attribute that was changed from its default.
+    pub fn from_attributes(entity_id: i32, attributes: &Attributes) -> Self {
+        let properties = attributes
+            .iter()
+            .map(|(attribute, instance)| AttributeProperty {
+                id: VarInt::new(attribute.id as i32),
+                value: instance.base,
+                modifiers: LengthPrefixedVec::new(
+                    instance
+                        .modifiers()
+                        .iter()
+                        .map(|modifier| AttributeModifierData {
+                            id: modifier.id.clone(),
+                            amount: modifier.amount,
+                            operation: modifier.operation.id(),
+                        })
+                        .collect(),
+                ),
+            })
+            .collect();
+        Self::new(entity_id, properties)
+    }
+}
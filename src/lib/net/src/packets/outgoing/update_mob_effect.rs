@@ -0,0 +1,36 @@
+//! Update Mob Effect packet.
+//!
+//! Sent to clients to show an effect on an entity, or to update one it already has.
+
+use ferrumc_macros::{packet, NetEncode};
+use ferrumc_net_codec::net_types::var_int::VarInt;
+
+#[derive(NetEncode, Debug)]
+#[packet(packet_id = "update_mob_effect", state = "play")]
+pub struct UpdateMobEffectPacket {
+    pub entity_id: VarInt,
+    /// The protocol ID of the effect
+    pub effect_id: VarInt,
+    pub amplifier: VarInt,
+    /// Duration in ticks, or -1 for an infinite effect
+    pub duration: VarInt,
+    /// A bit field of the `FLAG_*` constants
+    pub flags: u8,
+}
+
+impl UpdateMobEffectPacket {
+    pub const FLAG_AMBIENT: u8 = 0x01;
+    pub const FLAG_SHOW_PARTICLES: u8 = 0x02;
+    pub const FLAG_SHOW_ICON: u8 = 0x04;
+    pub const FLAG_BLEND: u8 = 0x08;
+
+    pub fn new(entity_id: i32, effect_id: u8, amplifier: u8, duration: i32, flags: u8) -> Self {
+        Self {
+            entity_id: VarInt::new(entity_id),
+            effect_id: VarInt::new(effect_id as i32),
+            amplifier: VarInt::new(amplifier as i32),
+            duration: VarInt::new(duration),
+            flags,
+        }
+    }
+}
@@ -0,0 +1,166 @@
+//! Source RCON server, letting remote tools run commands on the server.
+//!
+//! Clients log in with the password from the `[rcon]` config section, then send commands.
+//! Commands are handed to the ECS through [`RconCommand`]s, and whatever they print is
+//! captured as plain text and sent back, split over several packets if needed.
+
+pub mod packet;
+
+use crate::errors::NetError;
+use crate::server::create_rcon_listener;
+use bevy_ecs::prelude::Resource;
+use crossbeam_channel::{Receiver, Sender};
+use ferrumc_config::server_config::get_global_config;
+use packet::{
+    split_response, RconPacket, SERVERDATA_AUTH, SERVERDATA_AUTH_RESPONSE, SERVERDATA_EXECCOMMAND,
+    SERVERDATA_RESPONSE_VALUE,
+};
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicU32, Ordering};
+use tokio::net::TcpStream;
+use tokio::sync::oneshot;
+use tracing::{debug, error, info, warn};
+
+static NEXT_REQUEST: AtomicU32 = AtomicU32::new(0);
+
+/// A command received over RCON, waiting to be run.
+pub struct RconCommand {
+    /// Unique id of this request, used to capture the command's output.
+    pub request: u32,
+    pub command: String,
+    /// Sends the command's output back to the client.
+    pub response: oneshot::Sender<String>,
+}
+
+/// Commands received over RCON since the last tick.
+#[derive(Resource)]
+pub struct RconCommandReceiver(pub Receiver<RconCommand>);
+
+/// Binds the RCON listener next to the game listener and serves clients until the runtime stops.
+///
+/// Does nothing if RCON is disabled or no password is set.
+pub async fn start_rcon_server(commands: Sender<RconCommand>) -> Result<(), NetError> {
+    let config = &get_global_config().rcon;
+    if !config.enabled {
+        return Ok(());
+    }
+    if config.password.is_empty() {
+        warn!("RCON is enabled but no password is set, not starting it");
+        return Ok(());
+    }
+
+    let listener = create_rcon_listener().await?;
+    info!("RCON is running on port {}", config.port);
+
+    loop {
+        let (stream, addy) = match listener.accept().await {
+            Ok(connection) => connection,
+            Err(e) => {
+                error!("Failed to accept RCON connection: {:?}", e);
+                continue;
+            }
+        };
+        debug!("Got RCON connection from {}", addy);
+        tokio::spawn({
+            let commands = commands.clone();
+            async move {
+                match handle_client(stream, addy, commands).await {
+                    Ok(()) | Err(NetError::ConnectionDropped) => {}
+                    Err(e) => warn!("RCON connection from {} failed: {}", addy, e),
+                }
+                debug!("RCON connection from {} closed", addy);
+            }
+        });
+    }
+}
+
+async fn handle_client(
+    mut stream: TcpStream,
+    addy: SocketAddr,
+    commands: Sender<RconCommand>,
+) -> Result<(), NetError> {
+    let mut authenticated = false;
+
+    while let Some(packet) = RconPacket::read(&mut stream).await? {
+        match packet.kind {
+            SERVERDATA_AUTH => {
+                authenticated = password_matches(&packet.body, &get_global_config().rcon.password);
+                let request_id = if authenticated {
+                    info!("RCON client {} logged in", addy);
+                    packet.request_id
+                } else {
+                    warn!("RCON client {} used a wrong password", addy);
+                    -1
+                };
+                RconPacket::new(request_id, SERVERDATA_AUTH_RESPONSE, "")
+                    .write(&mut stream)
+                    .await?;
+            }
+            SERVERDATA_EXECCOMMAND if authenticated => {
+                let (response, output) = oneshot::channel();
+                let command = RconCommand {
+                    request: NEXT_REQUEST.fetch_add(1, Ordering::Relaxed),
+                    command: packet.body,
+                    response,
+                };
+                if commands.send(command).is_err() {
+                    return Err(NetError::ConnectionDropped);
+                }
+                let output = output.await.unwrap_or_default();
+
+                for response in split_response(packet.request_id, &output) {
+                    response.write(&mut stream).await?;
+                }
+            }
+            // Clients send an empty response packet after a command and wait for it to be
+            // mirrored, to find where a multi-packet response ends.
+            SERVERDATA_RESPONSE_VALUE if authenticated => {
+                RconPacket::new(packet.request_id, SERVERDATA_RESPONSE_VALUE, "")
+                    .write(&mut stream)
+                    .await?;
+            }
+            _ if !authenticated => {
+                RconPacket::new(-1, SERVERDATA_AUTH_RESPONSE, "")
+                    .write(&mut stream)
+                    .await?;
+            }
+            kind => {
+                debug!("Unknown RCON packet type {} from {}", kind, addy);
+                RconPacket::new(
+                    packet.request_id,
+                    SERVERDATA_RESPONSE_VALUE,
+                    format!("Unknown request {kind:x}"),
+                )
+                .write(&mut stream)
+                .await?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Compares a password without stopping at the first wrong byte, so how long it takes doesn't
+/// give away how much of it was right.
+fn password_matches(given: &str, password: &str) -> bool {
+    let (given, password) = (given.as_bytes(), password.as_bytes());
+    let mut diff = given.len() ^ password.len();
+    for (i, byte) in password.iter().enumerate() {
+        diff |= (byte ^ given.get(i).copied().unwrap_or(0)) as usize;
+    }
+    diff == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_password_matches() {
+        assert!(password_matches("hunter2", "hunter2"));
+        assert!(!password_matches("hunter3", "hunter2"));
+        assert!(!password_matches("hunter", "hunter2"));
+        assert!(!password_matches("hunter2\0", "hunter2"));
+        assert!(!password_matches("", "hunter2"));
+    }
+}
@@ -0,0 +1,124 @@
+use crate::errors::NetError;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+/// Response to a command, or the mirror of an empty response sent by the client.
+pub const SERVERDATA_RESPONSE_VALUE: i32 = 0;
+/// Tells the client whether it logged in. Shares its id with [`SERVERDATA_EXECCOMMAND`].
+pub const SERVERDATA_AUTH_RESPONSE: i32 = 2;
+/// Runs a command.
+pub const SERVERDATA_EXECCOMMAND: i32 = 2;
+/// Logs in with the RCON password.
+pub const SERVERDATA_AUTH: i32 = 3;
+
+/// The largest packet a client may send, body included. Matches vanilla.
+const MAX_INCOMING_SIZE: i32 = 1460;
+/// The most body bytes sent in a single response packet. Longer output is split over several.
+pub const MAX_RESPONSE_BODY: usize = 4096;
+/// The request id and type fields, plus the body's terminator and the trailing empty string.
+const HEADER_SIZE: i32 = 4 + 4 + 2;
+
+/// A single Source RCON packet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RconPacket {
+    pub request_id: i32,
+    pub kind: i32,
+    pub body: String,
+}
+
+impl RconPacket {
+    pub fn new(request_id: i32, kind: i32, body: impl Into<String>) -> Self {
+        Self {
+            request_id,
+            kind,
+            body: body.into(),
+        }
+    }
+
+    /// Reads a packet, or `None` if the client closed the connection between packets.
+    pub async fn read(reader: &mut (impl AsyncRead + Unpin)) -> Result<Option<Self>, NetError> {
+        let size = match reader.read_i32_le().await {
+            Ok(size) => size,
+            Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        if !(HEADER_SIZE..=MAX_INCOMING_SIZE).contains(&size) {
+            return Err(NetError::Misc(format!("Invalid RCON packet size {size}")));
+        }
+
+        let request_id = reader.read_i32_le().await?;
+        let kind = reader.read_i32_le().await?;
+        let mut body = vec![0; (size - 8) as usize];
+        reader.read_exact(&mut body).await?;
+
+        // The body is null-terminated and followed by an empty, also null-terminated, string
+        let end = body.iter().position(|b| *b == 0).unwrap_or(body.len());
+        body.truncate(end);
+
+        Ok(Some(Self {
+            request_id,
+            kind,
+            body: String::from_utf8_lossy(&body).into_owned(),
+        }))
+    }
+
+    pub async fn write(&self, writer: &mut (impl AsyncWrite + Unpin)) -> Result<(), NetError> {
+        writer.write_all(&self.encode()).await?;
+        Ok(())
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let size = self.body.len() as i32 + HEADER_SIZE;
+        let mut bytes = Vec::with_capacity(size as usize + 4);
+        bytes.extend_from_slice(&size.to_le_bytes());
+        bytes.extend_from_slice(&self.request_id.to_le_bytes());
+        bytes.extend_from_slice(&self.kind.to_le_bytes());
+        bytes.extend_from_slice(self.body.as_bytes());
+        bytes.extend_from_slice(&[0, 0]);
+        bytes
+    }
+}
+
+/// Splits a command's output into response packets of at most [`MAX_RESPONSE_BODY`] bytes.
+pub fn split_response(request_id: i32, output: &str) -> Vec<RconPacket> {
+    let mut packets = Vec::new();
+    let mut rest = output;
+    loop {
+        let mut end = rest.len().min(MAX_RESPONSE_BODY);
+        while !rest.is_char_boundary(end) {
+            end -= 1;
+        }
+        let (body, remaining) = rest.split_at(end);
+        packets.push(RconPacket::new(request_id, SERVERDATA_RESPONSE_VALUE, body));
+        if remaining.is_empty() {
+            return packets;
+        }
+        rest = remaining;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_packet_roundtrip() {
+        let packet = RconPacket::new(7, SERVERDATA_EXECCOMMAND, "time set day");
+        let bytes = packet.encode();
+        assert_eq!(&bytes[..4], &(12 + 10i32).to_le_bytes());
+
+        let read = RconPacket::read(&mut bytes.as_slice()).await.unwrap();
+        assert_eq!(read, Some(packet));
+        assert_eq!(RconPacket::read(&mut [].as_slice()).await.unwrap(), None);
+    }
+
+    #[test]
+    fn test_split_response() {
+        let output = "a".repeat(MAX_RESPONSE_BODY * 2 + 10);
+        let packets = split_response(3, &output);
+        assert_eq!(packets.len(), 3);
+        assert_eq!(packets[2].body.len(), 10);
+        assert!(packets.iter().all(|packet| packet.request_id == 3));
+
+        assert_eq!(split_response(3, "").len(), 1);
+    }
+}
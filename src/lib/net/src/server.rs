@@ -22,3 +22,24 @@ pub async fn create_server_listener() -> Result<TcpListener, NetError> {
 
     Ok(listener?)
 }
+
+/// Creates the RCON listener, on the same host as the game listener but its own port.
+pub async fn create_rcon_listener() -> Result<TcpListener, NetError> {
+    let config = get_global_config();
+    let rcon_addy = format!("{}:{}", config.host, config.rcon.port);
+    let rcon_addy = rcon_addy.as_str();
+
+    let listener = match TcpListener::bind(rcon_addy).await {
+        Ok(l) => Ok::<TcpListener, std::io::Error>(l),
+        Err(e) => {
+            error!("Failed to bind RCON to addy: {}", rcon_addy);
+            error!("Perhaps the port {} is already in use?", config.rcon.port);
+
+            Err(e)
+        }
+    };
+
+    debug!("RCON listening on {}", rcon_addy);
+
+    Ok(listener?)
+}
@@ -65,6 +65,33 @@ impl TextComponent {
     }
 }
 
+impl TextComponent {
+    /// Turns this TextComponent into plain text, dropping all formatting
+    ///
+    /// # Returns
+    /// - The text of this component and all of its children
+    pub fn to_plain_string(&self) -> String {
+        let mut str = match &self.content {
+            TextContent::Text { text } => text.clone(),
+            TextContent::Translate { translate, with } => {
+                let with: Vec<_> = with.iter().map(TextComponent::to_plain_string).collect();
+                if with.is_empty() {
+                    translate.clone()
+                } else {
+                    format!("{translate}[{}]", with.join(", "))
+                }
+            }
+            TextContent::Keybind { keybind } => keybind.clone(),
+        };
+
+        for component in &self.extra {
+            str.push_str(&component.to_plain_string());
+        }
+
+        str
+    }
+}
+
 impl Color {
     /// Returns this color as an ANSI color code
     pub fn to_ansi_color(self) -> Option<String> {
@@ -90,6 +90,30 @@ impl TextComponent {
         NBTSerializable::serialize(self, &mut vec, &NBTSerializeOptions::Network);
         vec
     }
+
+    /// Parses JSON text the way commands like `/tellraw` take it: a component, a plain string, or
+    /// a list of them where the rest are added to the end of the first.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        Self::from_json_value(serde_json::from_str(json)?)
+    }
+
+    fn from_json_value(value: serde_json::Value) -> Result<Self, serde_json::Error> {
+        match value {
+            serde_json::Value::String(text) => Ok(text.into()),
+            serde_json::Value::Array(values) => {
+                let mut parts = values.into_iter().map(Self::from_json_value);
+                let Some(first) = parts.next() else {
+                    return Ok(Self::default());
+                };
+                let mut component = first?;
+                for part in parts {
+                    component.extra.push(part?);
+                }
+                Ok(component)
+            }
+            value => serde_json::from_value(value),
+        }
+    }
 }
 
 impl Default for TextContent {
@@ -101,6 +101,33 @@ struct TestPacket {
     overlay: bool,
 }
 
+#[test]
+fn test_from_json() {
+    assert_eq!(
+        TextComponent::from_json(r#"{"text":"Hello","bold":true}"#).unwrap(),
+        ComponentBuilder::text("Hello").bold().build()
+    );
+    assert_eq!(
+        TextComponent::from_json(r#""Hello""#).unwrap(),
+        TextComponent::from("Hello")
+    );
+    assert_eq!(
+        TextComponent::from_json(r#"["Hello, ", {"text":"world"}]"#).unwrap(),
+        TextComponent::from("Hello, ") + TextComponent::from("world")
+    );
+    assert!(TextComponent::from_json("Hello").is_err());
+}
+
+#[test]
+fn test_to_plain_string() {
+    let component = ComponentBuilder::text("Teleported ")
+        .color(NamedColor::Gold)
+        .bold()
+        .extra(ComponentBuilder::text("Steve").underlined().build())
+        .build();
+    assert_eq!(component.to_plain_string(), "Teleported Steve");
+}
+
 #[test]
 #[ignore]
 fn test_serialize_to_nbt() {
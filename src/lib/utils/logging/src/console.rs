@@ -0,0 +1,109 @@
+//! Terminal output shared between the logger and the interactive server console.
+//!
+//! While the console is reading a command, its input line is kept at the bottom of the
+//! terminal. Everything printed through this module (log events included) erases that line
+//! first and redraws it afterwards, so output never ends up in the middle of what the
+//! operator is typing.
+
+use std::io::{self, Write};
+use std::sync::Mutex;
+use tracing_subscriber::fmt::MakeWriter;
+
+/// The input line currently shown at the bottom of the terminal, if any.
+struct InputLine {
+    /// The prompt and the text typed so far.
+    text: String,
+    /// How many characters the cursor sits before the end of `text`.
+    cursor_from_end: usize,
+}
+
+static INPUT_LINE: Mutex<Option<InputLine>> = Mutex::new(None);
+
+fn draw(out: &mut impl Write, line: &InputLine) -> io::Result<()> {
+    write!(out, "{}", line.text)?;
+    if line.cursor_from_end > 0 {
+        write!(out, "\x1b[{}D", line.cursor_from_end)?;
+    }
+    Ok(())
+}
+
+/// Shows `text` as the console's input line, replacing the previous one.
+pub fn set_input_line(text: String, cursor_from_end: usize) {
+    let mut input = INPUT_LINE.lock().unwrap_or_else(|e| e.into_inner());
+    let line = input.insert(InputLine {
+        text,
+        cursor_from_end,
+    });
+
+    let mut out = io::stdout().lock();
+    let _ = write!(out, "\r\x1b[2K").and_then(|_| draw(&mut out, line));
+    let _ = out.flush();
+}
+
+/// Removes the console's input line. Output goes straight to stdout afterwards.
+pub fn clear_input_line() {
+    let mut input = INPUT_LINE.lock().unwrap_or_else(|e| e.into_inner());
+    if input.take().is_some() {
+        let mut out = io::stdout().lock();
+        let _ = write!(out, "\r\x1b[2K");
+        let _ = out.flush();
+    }
+}
+
+/// Prints `bytes` above the console's input line.
+fn print_bytes(bytes: &[u8]) -> io::Result<()> {
+    let input = INPUT_LINE.lock().unwrap_or_else(|e| e.into_inner());
+    let mut out = io::stdout().lock();
+    match input.as_ref() {
+        Some(line) => {
+            write!(out, "\r\x1b[2K")?;
+            out.write_all(bytes)?;
+            if !bytes.ends_with(b"\n") {
+                writeln!(out)?;
+            }
+            draw(&mut out, line)?;
+        }
+        None => out.write_all(bytes)?,
+    }
+    out.flush()
+}
+
+/// Prints a line of (possibly ANSI formatted) text to the console, above the input line.
+pub fn print_line(text: &str) {
+    let _ = print_bytes(format!("{text}\n").as_bytes());
+}
+
+/// A [`MakeWriter`] for log output that keeps the console's input line intact.
+pub(crate) struct ConsoleWriter;
+
+/// Buffers a single log event so it can be printed in one go when dropped.
+pub(crate) struct ConsoleEventWriter {
+    buffer: Vec<u8>,
+}
+
+impl Write for ConsoleEventWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.buffer.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Drop for ConsoleEventWriter {
+    fn drop(&mut self) {
+        if !self.buffer.is_empty() {
+            let _ = print_bytes(&self.buffer);
+        }
+    }
+}
+
+impl<'a> MakeWriter<'a> for ConsoleWriter {
+    type Writer = ConsoleEventWriter;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        ConsoleEventWriter { buffer: Vec::new() }
+    }
+}
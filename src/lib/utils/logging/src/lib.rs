@@ -1,3 +1,4 @@
+pub mod console;
 pub mod errors;
 
 use ferrumc_general_purpose::paths::get_root_path;
@@ -36,6 +37,7 @@ pub fn init_logging(trace_level: Level) {
                 .with_line_number(true)
                 .with_level(true)
                 .with_target(false)
+                .with_writer(console::ConsoleWriter)
         }
         #[cfg(not(debug_assertions))]
         {
@@ -46,6 +48,7 @@ pub fn init_logging(trace_level: Level) {
                 .with_line_number(false)
                 .with_level(true)
                 .with_target(false)
+                .with_writer(console::ConsoleWriter)
         }
     };
 
@@ -85,6 +85,34 @@ impl BlockStateId {
     pub const fn raw(&self) -> u32 {
         self.0
     }
+
+    /// Returns the namespaced name of the block (e.g. `minecraft:stone`) without cloning the
+    /// underlying `BlockData`.
+    pub fn name(&self) -> Option<&'static str> {
+        ID2BLOCK.get(self.0 as usize).map(|data| data.name.as_str())
+    }
+
+    /// Returns the value of a single block state property, e.g. `age` for crops or `facing` for
+    /// stairs. Doesn't clone, so it's fine to use this in hot loops.
+    pub fn property(&self, key: &str) -> Option<&'static str> {
+        ID2BLOCK
+            .get(self.0 as usize)?
+            .properties
+            .as_ref()?
+            .get(key)
+            .map(String::as_str)
+    }
+
+    /// Returns the state of the same block with the given property changed.
+    ///
+    /// Returns `None` if the block doesn't have that property or if the value isn't valid for it.
+    pub fn with_property(&self, key: &str, value: &str) -> Option<BlockStateId> {
+        let mut data = self.to_block_data()?;
+        let properties = data.properties.as_mut()?;
+        let slot = properties.get_mut(key)?;
+        *slot = value.to_string();
+        BLOCK2ID.get(&data).map(|id| BlockStateId(*id as u32))
+    }
 }
 
 impl Display for BlockStateId {
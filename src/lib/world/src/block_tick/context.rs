@@ -0,0 +1,78 @@
+use crate::block_state_id::BlockStateId;
+use crate::block_tick::registry::BlockBehaviourRegistry;
+use crate::pos::BlockPos;
+use crate::World;
+
+/// Everything a [`BlockTickFn`](crate::block_tick::BlockTickFn) gets access to while ticking a
+/// block.
+///
+/// Block changes should go through [`BlockTickContext::set_block`] so they're sent to players and
+/// neighbouring blocks get a chance to react.
+pub struct BlockTickContext<'a> {
+    pub world: &'a World,
+    pub dimension: &'a str,
+    pub registry: &'a BlockBehaviourRegistry,
+    /// The position of the block being ticked.
+    pub pos: BlockPos,
+    /// The state of the block being ticked.
+    pub block: BlockStateId,
+    changes: &'a mut Vec<(BlockPos, BlockStateId)>,
+}
+
+impl<'a> BlockTickContext<'a> {
+    pub fn new(
+        world: &'a World,
+        dimension: &'a str,
+        registry: &'a BlockBehaviourRegistry,
+        pos: BlockPos,
+        block: BlockStateId,
+        changes: &'a mut Vec<(BlockPos, BlockStateId)>,
+    ) -> Self {
+        Self {
+            world,
+            dimension,
+            registry,
+            pos,
+            block,
+            changes,
+        }
+    }
+
+    /// Gets the block at `offset` from the ticked block, or `None` if that chunk isn't loaded.
+    pub fn get_relative(&self, offset: (i32, i32, i32)) -> Option<BlockStateId> {
+        self.world
+            .get_loaded_block(self.pos + offset, self.dimension)
+    }
+
+    /// Gets the block at any position, or `None` if that chunk isn't loaded.
+    pub fn get_block(&self, pos: BlockPos) -> Option<BlockStateId> {
+        self.world.get_loaded_block(pos, self.dimension)
+    }
+
+    /// Sets a block, records the change so it gets broadcast and notifies the neighbours.
+    ///
+    /// Returns `false` if the chunk isn't loaded, in which case nothing changes.
+    pub fn set_block(&mut self, pos: BlockPos, block: BlockStateId) -> bool {
+        if !self.world.set_loaded_block(pos, self.dimension, block) {
+            return false;
+        }
+        if pos == self.pos {
+            self.block = block;
+        }
+        self.changes.push((pos, block));
+        self.registry
+            .notify_neighbours(self.world, self.dimension, pos);
+        true
+    }
+
+    /// Replaces the ticked block.
+    pub fn set_self(&mut self, block: BlockStateId) -> bool {
+        self.set_block(self.pos, block)
+    }
+
+    /// Schedules a tick for the block at `pos` after `delay` game ticks.
+    pub fn schedule_tick(&self, pos: BlockPos, block: BlockStateId, delay: u32, priority: i8) {
+        self.world
+            .schedule_block_tick(pos, self.dimension, block, delay, priority);
+    }
+}
@@ -0,0 +1,124 @@
+//! Block ticking.
+//!
+//! Blocks are ticked in two ways:
+//! - **Scheduled ticks** are queued for a position with a delay in game ticks. They're stored per
+//!   chunk in [`ScheduledTicks`] and saved along with it, so pending ticks survive unloading and
+//!   restarts. Used for things that react to changes, like falling blocks or repeaters.
+//! - **Random ticks** are handed to random blocks in each loaded section every game tick, with
+//!   `random_tick_speed` picks per section. Used for slow processes like crops growing or grass
+//!   spreading.
+//!
+//! What a block does when ticked is defined by a [`BlockBehaviour`] registered in a
+//! [`BlockBehaviourRegistry`]. The systems that drive all of this live in the server binary.
+
+pub mod context;
+pub mod registry;
+pub mod scheduled;
+
+pub use context::BlockTickContext;
+pub use registry::{BlockBehaviour, BlockBehaviourRegistry, BlockTickFn, NEIGHBOUR_OFFSETS};
+pub use scheduled::{ScheduledTick, ScheduledTicks};
+
+use crate::block_state_id::BlockStateId;
+use crate::chunk::section::ChunkSectionType;
+use crate::chunk::Chunk;
+use crate::pos::{BlockPos, ChunkBlockPos};
+use crate::World;
+use rand::Rng;
+
+impl Chunk {
+    /// Schedules a tick for `block` at `pos` in this chunk. See [`ScheduledTicks::schedule`].
+    pub fn schedule_tick(
+        &mut self,
+        pos: ChunkBlockPos,
+        block: BlockStateId,
+        delay: u32,
+        priority: i8,
+    ) -> bool {
+        self.scheduled_ticks.schedule(pos, block, delay, priority)
+    }
+
+    pub fn scheduled_ticks(&self) -> &ScheduledTicks {
+        &self.scheduled_ticks
+    }
+
+    pub fn scheduled_ticks_mut(&mut self) -> &mut ScheduledTicks {
+        &mut self.scheduled_ticks
+    }
+
+    /// Picks `speed` random blocks in every non-empty section and pushes the ones `accepts` says
+    /// should be ticked into `out`, along with their current state.
+    ///
+    /// Uniform sections of a block that doesn't tick are skipped without rolling any positions,
+    /// which covers most of the world (air, stone, water).
+    pub fn pick_random_ticks(
+        &self,
+        speed: u32,
+        rng: &mut impl Rng,
+        accepts: impl Fn(BlockStateId) -> bool,
+        out: &mut Vec<(ChunkBlockPos, BlockStateId)>,
+    ) {
+        if speed == 0 {
+            return;
+        }
+
+        for (index, section) in self.sections.iter().enumerate() {
+            if section.block_count() == 0 {
+                continue;
+            }
+            if let ChunkSectionType::Uniform(data) = &section.inner {
+                if !accepts(data.get_block()) {
+                    continue;
+                }
+            }
+
+            let base_y = self.height.min_y + index as i16 * 16;
+            for _ in 0..speed {
+                let pos = ChunkBlockPos::new(
+                    rng.gen_range(0..16),
+                    base_y + rng.gen_range(0..16),
+                    rng.gen_range(0..16),
+                );
+                let block = section.get_block(pos.section_block_pos());
+                if accepts(block) {
+                    out.push((pos, block));
+                }
+            }
+        }
+    }
+}
+
+impl World {
+    /// Gets a block only if its chunk is already in the cache. Ticking code uses this so it never
+    /// loads or generates chunks at the edge of the loaded area.
+    pub fn get_loaded_block(&self, pos: BlockPos, dimension: &str) -> Option<BlockStateId> {
+        let chunk = self.cache.get(&(pos.chunk(), dimension.to_string()))?;
+        Some(chunk.get_block(pos.chunk_block_pos()))
+    }
+
+    /// Sets a block only if its chunk is already in the cache. Returns `false` if it isn't.
+    pub fn set_loaded_block(&self, pos: BlockPos, dimension: &str, block: BlockStateId) -> bool {
+        let Some(mut chunk) = self.cache.get_mut(&(pos.chunk(), dimension.to_string())) else {
+            return false;
+        };
+        chunk.set_block(pos.chunk_block_pos(), block);
+        true
+    }
+
+    /// Schedules a tick for `block` at `pos`, if its chunk is loaded.
+    ///
+    /// Returns `false` if the chunk isn't loaded or the same tick is already pending.
+    pub fn schedule_block_tick(
+        &self,
+        pos: BlockPos,
+        dimension: &str,
+        block: BlockStateId,
+        delay: u32,
+        priority: i8,
+    ) -> bool {
+        let Some(mut chunk) = self.cache.get_mut(&(pos.chunk(), dimension.to_string())) else {
+            return false;
+        };
+        chunk.schedule_tick(pos.chunk_block_pos(), block, delay, priority)
+    }
+}
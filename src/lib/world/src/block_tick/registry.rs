@@ -0,0 +1,163 @@
+use crate::block_state_id::{BlockStateId, ID2BLOCK};
+use crate::block_tick::context::BlockTickContext;
+use crate::pos::BlockPos;
+use crate::World;
+use std::collections::HashMap;
+use tracing::warn;
+
+/// The function called when a block is ticked.
+pub type BlockTickFn = fn(&mut BlockTickContext);
+
+/// Offsets of the six blocks sharing a face with a block.
+pub const NEIGHBOUR_OFFSETS: [(i32, i32, i32); 6] = [
+    (0, -1, 0),
+    (0, 1, 0),
+    (0, 0, -1),
+    (0, 0, 1),
+    (-1, 0, 0),
+    (1, 0, 0),
+];
+
+/// Describes how a block reacts to ticks.
+///
+/// ```ignore
+/// let behaviour = BlockBehaviour::new()
+///     .random_tick(grow_crop)
+///     .scheduled_tick(check_support)
+///     .update_delay(2);
+/// ```
+#[derive(Clone, Copy, Default)]
+pub struct BlockBehaviour {
+    pub random_tick: Option<BlockTickFn>,
+    pub scheduled_tick: Option<BlockTickFn>,
+    /// If set, a scheduled tick is queued this many ticks after the block or one of its
+    /// neighbours changes.
+    pub update_delay: Option<u32>,
+}
+
+impl BlockBehaviour {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn random_tick(mut self, func: BlockTickFn) -> Self {
+        self.random_tick = Some(func);
+        self
+    }
+
+    pub fn scheduled_tick(mut self, func: BlockTickFn) -> Self {
+        self.scheduled_tick = Some(func);
+        self
+    }
+
+    pub fn update_delay(mut self, delay: u32) -> Self {
+        self.update_delay = Some(delay);
+        self
+    }
+}
+
+/// Maps blocks to their [`BlockBehaviour`].
+///
+/// Behaviours are registered by block name, and resolved to every state of that block up front
+/// so lookups by [`BlockStateId`] in the tick loop are a single index.
+pub struct BlockBehaviourRegistry {
+    behaviours: Vec<BlockBehaviour>,
+    by_name: HashMap<String, u16>,
+    by_state: Vec<Option<u16>>,
+}
+
+impl Default for BlockBehaviourRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BlockBehaviourRegistry {
+    pub fn new() -> Self {
+        Self {
+            behaviours: Vec::new(),
+            by_name: HashMap::new(),
+            by_state: vec![None; ID2BLOCK.len()],
+        }
+    }
+
+    /// Attaches a behaviour to every state of the block with the given name. The `minecraft:`
+    /// namespace is optional. Registering the same block twice replaces the old behaviour.
+    pub fn register(&mut self, name: &str, behaviour: BlockBehaviour) {
+        let name = if name.contains(':') {
+            name.to_string()
+        } else {
+            format!("minecraft:{name}")
+        };
+
+        if let Some(index) = self.by_name.get(&name) {
+            self.behaviours[*index as usize] = behaviour;
+            return;
+        }
+
+        let index = self.behaviours.len() as u16;
+        let mut found = false;
+        for (id, data) in ID2BLOCK.iter().enumerate() {
+            if data.name == name {
+                self.by_state[id] = Some(index);
+                found = true;
+            }
+        }
+        if !found {
+            warn!("Tried to register a behaviour for unknown block '{name}'");
+            return;
+        }
+
+        self.behaviours.push(behaviour);
+        self.by_name.insert(name, index);
+    }
+
+    /// Registers the same behaviour for several blocks.
+    pub fn register_all(&mut self, names: &[&str], behaviour: BlockBehaviour) {
+        for name in names {
+            self.register(name, behaviour);
+        }
+    }
+
+    #[inline]
+    pub fn get(&self, block: BlockStateId) -> Option<&BlockBehaviour> {
+        let index = (*self.by_state.get(block.raw() as usize)?)?;
+        self.behaviours.get(index as usize)
+    }
+
+    #[inline]
+    pub fn ticks_randomly(&self, block: BlockStateId) -> bool {
+        self.get(block).is_some_and(|b| b.random_tick.is_some())
+    }
+
+    /// Returns true if both states belong to the same registered block, regardless of their
+    /// properties. Used to check that a scheduled tick still applies to the block at its position.
+    pub fn same_block(&self, a: BlockStateId, b: BlockStateId) -> bool {
+        if a == b {
+            return true;
+        }
+        match (
+            self.by_state.get(a.raw() as usize),
+            self.by_state.get(b.raw() as usize),
+        ) {
+            (Some(Some(a)), Some(Some(b))) => a == b,
+            _ => false,
+        }
+    }
+
+    /// Queues scheduled ticks for the block at `pos` and its neighbours if their behaviour asks to
+    /// be updated when something next to them changes. Only chunks that are already loaded are
+    /// touched.
+    pub fn notify_neighbours(&self, world: &World, dimension: &str, pos: BlockPos) {
+        let positions = std::iter::once(pos).chain(NEIGHBOUR_OFFSETS.iter().map(|o| pos + *o));
+        for target in positions {
+            let Some(block) = world.get_loaded_block(target, dimension) else {
+                continue;
+            };
+            let Some(delay) = self.get(block).and_then(|b| b.update_delay) else {
+                continue;
+            };
+            world.schedule_block_tick(target, dimension, block, delay, 0);
+        }
+    }
+}
@@ -0,0 +1,170 @@
+use crate::block_state_id::BlockStateId;
+use crate::pos::ChunkBlockPos;
+use bitcode_derive::{Decode, Encode};
+use deepsize::DeepSizeOf;
+
+/// A single pending block tick.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, DeepSizeOf, Encode, Decode)]
+pub struct ScheduledTick {
+    x: u8,
+    y: i16,
+    z: u8,
+    /// The block the tick was scheduled for. If a different block is at the position by the time
+    /// the tick is due, the tick is dropped.
+    pub block: BlockStateId,
+    /// Game ticks left until this tick runs.
+    pub delay: u32,
+    /// Ticks that are due on the same game tick run in ascending priority order.
+    pub priority: i8,
+    /// Insertion order, used to keep ticks with the same priority in FIFO order.
+    sequence: u32,
+}
+
+impl ScheduledTick {
+    /// The position of the ticked block within its chunk.
+    pub fn pos(&self) -> ChunkBlockPos {
+        ChunkBlockPos::new(self.x, self.y, self.z)
+    }
+}
+
+/// The queue of scheduled ticks for a single chunk.
+///
+/// Delays are stored relative to the current tick rather than as absolute game times, so the
+/// queue only advances while the chunk is loaded and stays valid across restarts.
+#[derive(Clone, Default, DeepSizeOf, Encode, Decode)]
+pub struct ScheduledTicks {
+    ticks: Vec<ScheduledTick>,
+    next_sequence: u32,
+    /// Set whenever ticks are added or removed so the owning chunk gets saved. Delays counting
+    /// down don't set it, so a saved queue can be behind by however long the chunk went unsaved.
+    /// Never saved, so loaded queues start out clean.
+    #[bitcode(skip)]
+    pub(crate) dirty: bool,
+}
+
+impl ScheduledTicks {
+    /// Schedules a tick for `block` at `pos` after `delay` game ticks.
+    ///
+    /// Like vanilla, only one tick can be pending per position and block. Returns `false` if one
+    /// was already scheduled, in which case the existing tick is kept.
+    pub fn schedule(
+        &mut self,
+        pos: ChunkBlockPos,
+        block: BlockStateId,
+        delay: u32,
+        priority: i8,
+    ) -> bool {
+        if self.is_scheduled(pos, block) {
+            return false;
+        }
+
+        self.ticks.push(ScheduledTick {
+            x: pos.x(),
+            y: pos.y(),
+            z: pos.z(),
+            block,
+            delay,
+            priority,
+            sequence: self.next_sequence,
+        });
+        self.next_sequence = self.next_sequence.wrapping_add(1);
+        self.dirty = true;
+        true
+    }
+
+    /// Returns true if a tick for `block` is already pending at `pos`.
+    pub fn is_scheduled(&self, pos: ChunkBlockPos, block: BlockStateId) -> bool {
+        self.ticks
+            .iter()
+            .any(|tick| tick.block == block && tick.pos() == pos)
+    }
+
+    pub fn len(&self) -> usize {
+        self.ticks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ticks.is_empty()
+    }
+
+    /// Advances every pending tick by one game tick and removes the ones that are now due.
+    ///
+    /// The returned ticks are ordered by priority, then by the order they were scheduled in.
+    pub fn advance(&mut self) -> Vec<ScheduledTick> {
+        if self.ticks.is_empty() {
+            return Vec::new();
+        }
+
+        let mut due = Vec::new();
+        self.ticks.retain_mut(|tick| {
+            tick.delay = tick.delay.saturating_sub(1);
+            if tick.delay == 0 {
+                due.push(*tick);
+                false
+            } else {
+                true
+            }
+        });
+        due.sort_by_key(|tick| (tick.priority, tick.sequence));
+
+        if !due.is_empty() {
+            self.dirty = true;
+        }
+        due
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ferrumc_macros::block;
+
+    #[test]
+    fn ticks_run_in_priority_then_insertion_order() {
+        let mut queue = ScheduledTicks::default();
+        let pos = |x| ChunkBlockPos::new(x, 0, 0);
+
+        assert!(queue.schedule(pos(0), block!("stone"), 2, 0));
+        assert!(queue.schedule(pos(1), block!("stone"), 1, 0));
+        assert!(queue.schedule(pos(2), block!("stone"), 1, -1));
+        assert!(queue.schedule(pos(3), block!("stone"), 1, 0));
+        // Duplicate ticks for the same block and position are ignored
+        assert!(!queue.schedule(pos(3), block!("stone"), 5, 0));
+
+        let due = queue.advance();
+        assert_eq!(
+            due.iter().map(|t| t.pos()).collect::<Vec<_>>(),
+            vec![pos(2), pos(1), pos(3)]
+        );
+        assert_eq!(queue.len(), 1);
+
+        let due = queue.advance();
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].pos(), pos(0));
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn counting_down_does_not_dirty() {
+        let mut queue = ScheduledTicks::default();
+        queue.schedule(ChunkBlockPos::new(0, 0, 0), block!("stone"), 2, 0);
+        queue.dirty = false;
+
+        assert!(queue.advance().is_empty());
+        assert!(!queue.dirty);
+        assert_eq!(queue.advance().len(), 1);
+        assert!(queue.dirty);
+    }
+
+    #[test]
+    fn dirty_flag_is_not_saved() {
+        let mut queue = ScheduledTicks::default();
+        queue.schedule(ChunkBlockPos::new(0, 0, 0), block!("stone"), 3, 0);
+        assert!(queue.dirty);
+
+        let loaded: ScheduledTicks = bitcode::decode(&bitcode::encode(&queue)).unwrap();
+        assert!(!loaded.dirty);
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(loaded.next_sequence, 1);
+    }
+}
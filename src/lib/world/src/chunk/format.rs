@@ -0,0 +1,114 @@
+//! The format chunks are saved in.
+//!
+//! Saved chunks start with [`MAGIC`] and a version byte, followed by the bitcode encoded
+//! [`Chunk`]. Chunks saved before the format was versioned are just the bitcode encoded chunk and
+//! are read as version 0. Whenever the layout of [`Chunk`] changes, [`VERSION`] goes up and the
+//! old layout gets a struct here that converts it to the current one.
+
+use crate::block_tick::ScheduledTicks;
+use crate::chunk::heightmap::Heightmaps;
+use crate::chunk::section::ChunkSection;
+use crate::chunk::Chunk;
+use crate::errors::WorldError;
+use crate::pos::ChunkHeight;
+use bitcode_derive::{Decode, Encode};
+
+const MAGIC: [u8; 4] = *b"FCCK";
+
+/// The version chunks are currently saved as.
+const VERSION: u8 = 1;
+
+/// Chunks from before they had a scheduled tick queue.
+#[derive(Encode, Decode)]
+struct ChunkV0 {
+    sections: Box<[ChunkSection]>,
+    height: ChunkHeight,
+    heightmaps: Option<Heightmaps>,
+}
+
+impl From<ChunkV0> for Chunk {
+    fn from(chunk: ChunkV0) -> Self {
+        Self {
+            sections: chunk.sections,
+            height: chunk.height,
+            heightmaps: chunk.heightmaps,
+            scheduled_ticks: ScheduledTicks::default(),
+        }
+    }
+}
+
+fn decode<T: bitcode::DecodeOwned>(data: &[u8]) -> Result<T, WorldError> {
+    bitcode::decode(data).map_err(|e| WorldError::BitcodeDecodeError(e.to_string()))
+}
+
+impl Chunk {
+    /// Encodes the chunk in the current save format.
+    pub fn to_saved_bytes(&self) -> Vec<u8> {
+        let mut data = Vec::from(MAGIC);
+        data.push(VERSION);
+        data.extend(bitcode::encode(self));
+        data
+    }
+
+    /// Decodes a saved chunk, converting it from older formats if needed.
+    pub fn from_saved_bytes(data: &[u8]) -> Result<Chunk, WorldError> {
+        let Some(data) = data.strip_prefix(&MAGIC) else {
+            return decode::<ChunkV0>(data).map(Chunk::from);
+        };
+        match data.split_first() {
+            Some((&VERSION, chunk)) => decode(chunk),
+            Some((version, _)) => Err(WorldError::BitcodeDecodeError(format!(
+                "Unknown chunk format version {version}"
+            ))),
+            None => Err(WorldError::BitcodeDecodeError(
+                "Chunk data is missing its format version".to_string(),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pos::ChunkBlockPos;
+    use ferrumc_macros::block;
+
+    #[test]
+    fn test_roundtrip() {
+        let mut chunk = Chunk::new_empty();
+        chunk.set_block(ChunkBlockPos::new(1, 2, 3), block!("stone"));
+        chunk.schedule_tick(ChunkBlockPos::new(1, 2, 3), block!("stone"), 4, 0);
+
+        let loaded = Chunk::from_saved_bytes(&chunk.to_saved_bytes()).unwrap();
+        assert_eq!(
+            loaded.get_block(ChunkBlockPos::new(1, 2, 3)),
+            block!("stone")
+        );
+        assert_eq!(loaded.scheduled_ticks().len(), 1);
+    }
+
+    #[test]
+    fn test_unversioned_chunks() {
+        let mut chunk = Chunk::new_empty();
+        chunk.set_block(ChunkBlockPos::new(1, 2, 3), block!("stone"));
+        let old = ChunkV0 {
+            sections: chunk.sections.clone(),
+            height: chunk.height,
+            heightmaps: None,
+        };
+
+        let loaded = Chunk::from_saved_bytes(&bitcode::encode(&old)).unwrap();
+        assert_eq!(
+            loaded.get_block(ChunkBlockPos::new(1, 2, 3)),
+            block!("stone")
+        );
+        assert!(loaded.scheduled_ticks().is_empty());
+    }
+
+    #[test]
+    fn test_unknown_version() {
+        let mut data = Chunk::new_empty().to_saved_bytes();
+        data[MAGIC.len()] = VERSION + 1;
+        assert!(Chunk::from_saved_bytes(&data).is_err());
+    }
+}
@@ -1,3 +1,4 @@
+mod format;
 pub mod heightmap;
 pub mod light;
 pub mod network;
@@ -5,6 +6,7 @@ mod palette;
 pub mod section;
 
 use crate::block_state_id::BlockStateId;
+use crate::block_tick::ScheduledTicks;
 use crate::chunk::heightmap::Heightmaps;
 use crate::chunk::section::{ChunkSection, AIR};
 use crate::errors::WorldError;
@@ -18,9 +20,11 @@ use ferrumc_macros::block;
 #[derive(Clone, DeepSizeOf, Encode, Decode)]
 pub struct Chunk {
     pub sections: Box<[ChunkSection]>,
-    height: ChunkHeight,
+    pub(crate) height: ChunkHeight,
 
     heightmaps: Option<Heightmaps>,
+
+    pub(crate) scheduled_ticks: ScheduledTicks,
 }
 
 impl Chunk {
@@ -50,6 +54,7 @@ impl Chunk {
                 .into_boxed_slice(),
             height,
             heightmaps: None,
+            scheduled_ticks: ScheduledTicks::default(),
         }
     }
 
@@ -74,6 +79,7 @@ impl Chunk {
             sections: sections.to_vec().into_boxed_slice(),
             height,
             heightmaps: None,
+            scheduled_ticks: ScheduledTicks::default(),
         }
     }
 
@@ -130,6 +136,32 @@ impl Chunk {
         self.sections[section as usize].get_block(pos.section_block_pos())
     }
 
+    /// Returns true if anything in the chunk has changed since it was last saved.
+    pub fn is_dirty(&self) -> bool {
+        self.scheduled_ticks.dirty || self.sections.iter().any(|section| section.dirty)
+    }
+
+    /// Clears the dirty flags on the chunk and all of its sections.
+    pub fn mark_clean(&mut self) {
+        self.scheduled_ticks.dirty = false;
+        self.sections
+            .iter_mut()
+            .for_each(|section| section.dirty = false);
+    }
+
+    /// Returns true if anything in the chunk has changed since it was last encoded for the
+    /// network, meaning any cached chunk packet is out of date.
+    pub fn is_network_dirty(&self) -> bool {
+        self.sections.iter().any(|section| !section.network_clean)
+    }
+
+    /// Marks the chunk as matching what is about to be encoded for the network.
+    pub fn mark_network_clean(&mut self) {
+        self.sections
+            .iter_mut()
+            .for_each(|section| section.network_clean = true);
+    }
+
     /// Sets a block in the chunk.
     ///
     /// # Arguments
@@ -147,6 +179,47 @@ impl Chunk {
 
         self.sections[section as usize].set_block(pos.section_block_pos(), id);
     }
+
+    /// Gets the biome at a position in the chunk, as an index into the biome registry.
+    ///
+    /// # Arguments
+    ///
+    /// * `pos` - The position to get the biome at. Positions above or below the chunk use the
+    ///   biome of the top or bottom section.
+    pub fn get_biome(&self, pos: ChunkBlockPos) -> u8 {
+        let section =
+            ((pos.y() + -self.height.min_y) / 16).clamp(0, self.sections.len() as i16 - 1);
+        self.sections[section as usize]
+            .biome
+            .get_biome(pos.section_block_pos())
+            .0
+    }
+
+    /// Finds the highest block in a column of the chunk that isn't air.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The x of the column within the chunk.
+    /// * `z` - The z of the column within the chunk.
+    ///
+    /// # Returns
+    ///
+    /// * The y of the highest block, or `None` if the column is entirely air.
+    pub fn highest_block_y(&self, x: u8, z: u8) -> Option<i16> {
+        for (index, section) in self.sections.iter().enumerate().rev() {
+            if section.block_count() == 0 {
+                continue;
+            }
+            let section_y = self.height.min_y + index as i16 * 16;
+            for y in (section_y..section_y + 16).rev() {
+                let block = section.get_block(ChunkBlockPos::new(x, y, z).section_block_pos());
+                if block != block!("air") && block != block!("cave_air") {
+                    return Some(y);
+                }
+            }
+        }
+        None
+    }
 }
 
 impl TryFrom<&VanillaChunk> for Chunk {
@@ -178,6 +251,7 @@ impl TryFrom<&VanillaChunk> for Chunk {
                 .heightmaps
                 .as_ref()
                 .and_then(|v| Heightmaps::try_from(v).ok()),
+            scheduled_ticks: ScheduledTicks::default(),
         })
     }
 }
@@ -267,6 +341,35 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_network_dirty() {
+        let mut chunk = Chunk::new_empty();
+        assert!(chunk.is_network_dirty());
+
+        chunk.mark_network_clean();
+        assert!(!chunk.is_network_dirty());
+
+        chunk.set_block(ChunkBlockPos::new(0, 0, 0), block!("stone"));
+        assert!(chunk.is_network_dirty());
+
+        chunk.mark_network_clean();
+        let decoded: Chunk = bitcode::decode(&bitcode::encode(&chunk)).unwrap();
+        assert!(decoded.is_network_dirty());
+    }
+
+    #[test]
+    fn test_highest_block() {
+        let mut chunk = Chunk::new_empty();
+        assert_eq!(chunk.highest_block_y(3, 4), None);
+
+        chunk.set_block(ChunkBlockPos::new(3, -10, 4), block!("stone"));
+        chunk.set_block(ChunkBlockPos::new(3, 70, 4), block!("dirt"));
+        chunk.set_block(ChunkBlockPos::new(3, 71, 4), block!("cave_air"));
+        assert_eq!(chunk.highest_block_y(3, 4), Some(70));
+        assert_eq!(chunk.highest_block_y(4, 4), None);
+        assert_eq!(chunk.get_biome(ChunkBlockPos::new(3, 400, 4)), 5);
+    }
+
     #[test]
     #[ignore]
     fn test_memory() {
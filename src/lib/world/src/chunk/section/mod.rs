@@ -100,6 +100,10 @@ pub struct ChunkSection {
     pub(crate) light: SectionLightData,
     pub(crate) biome: BiomeData,
     pub dirty: bool,
+    /// Whether the section is unchanged since the chunk was last encoded for the network. Never
+    /// saved, so loaded chunks are always encoded again.
+    #[bitcode(skip)]
+    pub(crate) network_clean: bool,
 }
 
 impl ChunkSection {
@@ -109,6 +113,7 @@ impl ChunkSection {
             light: SectionLightData::default(),
             biome: BiomeData::Uniform(BiomeType(5)),
             dirty: true,
+            network_clean: false,
         }
     }
 
@@ -119,6 +124,7 @@ impl ChunkSection {
                 light: SectionLightData::default(),
                 biome: BiomeData::Uniform(BiomeType(5)),
                 dirty: true,
+                network_clean: false,
             }
         } else if unique_blocks < 256 {
             Self {
@@ -128,6 +134,7 @@ impl ChunkSection {
                 light: SectionLightData::default(),
                 biome: BiomeData::Uniform(BiomeType(5)),
                 dirty: true,
+                network_clean: false,
             }
         } else {
             Self {
@@ -135,6 +142,7 @@ impl ChunkSection {
                 light: SectionLightData::default(),
                 biome: BiomeData::Uniform(BiomeType(5)),
                 dirty: true,
+                network_clean: false,
             }
         }
     }
@@ -147,12 +155,14 @@ impl ChunkSection {
     #[inline]
     pub fn set_block(&mut self, pos: SectionBlockPos, id: BlockStateId) {
         self.dirty = true;
+        self.network_clean = false;
         self.inner.set_block(pos, id);
     }
 
     #[inline]
     pub fn fill(&mut self, id: BlockStateId) {
         self.dirty = true;
+        self.network_clean = false;
         self.inner.fill(id);
     }
 
@@ -227,6 +237,7 @@ impl TryFrom<&Section> for ChunkSection {
                     light: light_data,
                     biome: BiomeData::Uniform(BiomeType(5)),
                     dirty: false,
+                    network_clean: false,
 
                     inner: ChunkSectionType::Uniform(UniformSection::air()),
                 });
@@ -249,6 +260,7 @@ impl TryFrom<&Section> for ChunkSection {
                 light: light_data,
                 biome: BiomeData::Uniform(BiomeType(5)),
                 dirty: false,
+                network_clean: false,
                 inner: section_data,
             })
         } else {
@@ -256,6 +268,7 @@ impl TryFrom<&Section> for ChunkSection {
                 light: light_data,
                 biome: BiomeData::Uniform(BiomeType(5)),
                 dirty: false,
+                network_clean: false,
                 inner: ChunkSectionType::Uniform(UniformSection::air()),
             })
         }
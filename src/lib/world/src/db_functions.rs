@@ -22,7 +22,7 @@ impl World {
         chunk: Chunk,
     ) -> Result<(), WorldError> {
         let mut chunk = chunk;
-        chunk.sections.iter_mut().for_each(|c| c.dirty = false);
+        chunk.mark_clean();
         save_chunk_internal(self, pos, dimension, &chunk)?;
         // self.cache.insert((pos, dimension.to_string()), chunk);
         Ok(())
@@ -105,7 +105,7 @@ impl World {
         for pair in self.cache.iter() {
             let k = pair.key();
             let v = pair.value();
-            if v.sections.iter().any(|c| c.dirty) {
+            if v.is_dirty() {
                 trace!("Chunk at {:?} is dirty, saving.", k.0);
             } else {
                 continue;
@@ -196,7 +196,7 @@ pub(crate) fn save_chunk_internal(
         world.storage_backend.create_table("chunks".to_string())?;
     }
     let as_bytes = yazi::compress(
-        &bitcode::encode(chunk),
+        &chunk.to_saved_bytes(),
         yazi::Format::Zlib,
         CompressionLevel::BestSpeed,
     )?;
@@ -226,9 +226,7 @@ pub(crate) fn load_chunk_internal(
                     warn!("Chunk data does not have a checksum, skipping verification.");
                 }
             }
-            let chunk: Chunk = bitcode::decode(&data)
-                .map_err(|e| WorldError::BitcodeDecodeError(e.to_string()))?;
-            Ok(chunk)
+            Chunk::from_saved_bytes(&data)
         }
         None => Err(WorldError::ChunkNotFound),
     }
@@ -259,9 +257,7 @@ pub(crate) fn load_chunk_batch_internal(
                         warn!("Chunk data does not have a checksum, skipping verification.");
                     }
                 }
-                let chunk: Chunk = bitcode::decode(&data)
-                    .map_err(|e| WorldError::BitcodeDecodeError(e.to_string()))?;
-                Ok(chunk)
+                Chunk::from_saved_bytes(&data)
             }
             None => Err(WorldError::ChunkNotFound),
         })
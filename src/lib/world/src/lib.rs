@@ -1,11 +1,13 @@
 pub mod block_state_id;
+pub mod block_tick;
 pub mod chunk;
 mod db_functions;
 pub mod errors;
 mod importing;
-mod player;
+pub mod player;
 pub mod pos;
 pub mod vanilla_chunk_format;
+mod world_data;
 
 use crate::chunk::Chunk;
 use crate::errors::WorldError;
@@ -20,6 +22,11 @@ use std::process::exit;
 use tracing::{error, warn};
 use wyhash::WyHasherBuilder;
 
+/// The dimension everything happens in.
+///
+/// TODO: Use the dimension of the player, block or entity involved once there's more than one
+pub const DEFAULT_DIMENSION: &str = "overworld";
+
 type ChunkCache = DashMap<(ChunkPos, String), Chunk, WyHasherBuilder>;
 
 pub type MutChunk<'a> = dashmap::mapref::one::RefMut<'a, (ChunkPos, String), Chunk>;
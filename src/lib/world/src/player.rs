@@ -2,12 +2,56 @@ use crate::errors::WorldError;
 use crate::World;
 use tracing::trace;
 
+const MAGIC: [u8; 4] = *b"FCPD";
+
+/// Data saved for each player, tagged with the version of its layout.
+///
+/// Saved data starts with a magic number and [`PlayerDataFormat::VERSION`], followed by the
+/// bitcode encoded data. Data saved before the format was versioned is read as version 0.
+/// Whenever the layout changes, the version goes up and [`PlayerDataFormat::decode_old`]
+/// converts data saved by older versions.
+pub trait PlayerDataFormat: bitcode::Encode + bitcode::DecodeOwned {
+    /// The version the data is currently saved as.
+    const VERSION: u8;
+
+    /// Decodes data saved by an older version.
+    fn decode_old(version: u8, data: &[u8]) -> Result<Self, WorldError>;
+}
+
+/// Decodes bitcode data, for use by [`PlayerDataFormat::decode_old`].
+pub fn decode_player_data<T: bitcode::DecodeOwned>(data: &[u8]) -> Result<T, WorldError> {
+    bitcode::decode(data).map_err(|e| WorldError::BitcodeDecodeError(e.to_string()))
+}
+
+fn from_saved_bytes<T: PlayerDataFormat>(data: &[u8]) -> Result<T, WorldError> {
+    let Some(data) = data.strip_prefix(&MAGIC) else {
+        return T::decode_old(0, data);
+    };
+    match data.split_first() {
+        Some((&version, data)) if version == T::VERSION => decode_player_data(data),
+        Some((&version, data)) if version < T::VERSION => T::decode_old(version, data),
+        Some((version, _)) => Err(WorldError::BitcodeDecodeError(format!(
+            "Unknown player data format version {version}"
+        ))),
+        None => Err(WorldError::BitcodeDecodeError(
+            "Player data is missing its format version".to_string(),
+        )),
+    }
+}
+
+fn to_saved_bytes<T: PlayerDataFormat>(data: &T) -> Vec<u8> {
+    let mut bytes = Vec::from(MAGIC);
+    bytes.push(T::VERSION);
+    bytes.extend(bitcode::encode(data));
+    bytes
+}
+
 impl World {
     /// Loads player data from the storage backend and decodes it.
     ///
     /// # Type Parameters
     ///
-    /// * `T` - A type that implements the `bitcode::DecodeOwned` trait, representing the structure of the decoded data.
+    /// * `T` - A type that implements the [`PlayerDataFormat`] trait, representing the structure of the decoded data.
     ///
     /// # Arguments
     ///
@@ -17,8 +61,9 @@ impl World {
     ///
     /// * `Ok(Some(T))` - The decoded player data if it exists and can be successfully decoded.
     /// * `Ok(None)` - If no data is found for the given player.
-    /// * `Err(WorldError)` - If an error occurs during the operation or decoding fails.
-    pub fn load_player_data<T: bitcode::DecodeOwned>(
+    /// * `Err(WorldError)` - If an error occurs during the operation or decoding fails. Callers
+    ///   shouldn't fall back to defaults here, as saving them would overwrite the stored data.
+    pub fn load_player_data<T: PlayerDataFormat>(
         &self,
         uuid: uuid::Uuid,
     ) -> Result<Option<T>, WorldError> {
@@ -35,15 +80,15 @@ impl World {
         let data = self
             .storage_backend
             .get("player_data".to_string(), uuid.as_u128())
-            .map_err(WorldError::DatabaseError);
-        data.map(|opt_bytes| opt_bytes.and_then(|bytes| bitcode::decode(&bytes).ok()))
+            .map_err(WorldError::DatabaseError)?;
+        data.map(|bytes| from_saved_bytes(&bytes)).transpose()
     }
 
     /// Saves player data to the storage backend after encoding it.
     ///
     /// # Type Parameters
     ///
-    /// * `T` - A type that implements the `bitcode::Encode` trait, representing the structure of the data to be encoded.
+    /// * `T` - A type that implements the [`PlayerDataFormat`] trait, representing the structure of the data to be encoded.
     ///
     /// # Arguments
     ///
@@ -55,7 +100,7 @@ impl World {
     /// * `Ok(true)` - If the data was successfully saved.
     /// * `Ok(false)` - If the data could not be saved.
     /// * `Err(WorldError)` - If an error occurs during the operation.
-    pub fn save_player_data<T: bitcode::Encode>(
+    pub fn save_player_data<T: PlayerDataFormat>(
         &self,
         uuid: uuid::Uuid,
         data: &T,
@@ -72,8 +117,76 @@ impl World {
             .upsert(
                 "player_data".to_string(),
                 uuid.as_u128(),
-                bitcode::encode(data),
+                to_saved_bytes(data),
             )
             .map_err(WorldError::DatabaseError)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bitcode_derive::{Decode, Encode};
+
+    #[derive(Debug, PartialEq, Encode, Decode)]
+    struct DataV0 {
+        health: f32,
+    }
+
+    #[derive(Debug, PartialEq, Encode, Decode)]
+    struct Data {
+        health: f32,
+        level: u32,
+    }
+
+    impl PlayerDataFormat for Data {
+        const VERSION: u8 = 1;
+
+        fn decode_old(version: u8, data: &[u8]) -> Result<Self, WorldError> {
+            match version {
+                0 => decode_player_data::<DataV0>(data).map(|old| Data {
+                    health: old.health,
+                    level: 0,
+                }),
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let data = Data {
+            health: 12.0,
+            level: 3,
+        };
+        assert_eq!(
+            from_saved_bytes::<Data>(&to_saved_bytes(&data)).unwrap(),
+            data
+        );
+    }
+
+    #[test]
+    fn test_unversioned_data() {
+        let old = bitcode::encode(&DataV0 { health: 12.0 });
+        let data = from_saved_bytes::<Data>(&old).unwrap();
+        assert_eq!(
+            data,
+            Data {
+                health: 12.0,
+                level: 0
+            }
+        );
+    }
+
+    #[test]
+    fn test_bad_data_fails() {
+        let data = Data {
+            health: 12.0,
+            level: 3,
+        };
+        let mut bytes = to_saved_bytes(&data);
+        bytes[MAGIC.len()] = Data::VERSION + 1;
+        assert!(from_saved_bytes::<Data>(&bytes).is_err());
+        assert!(from_saved_bytes::<Data>(&[1, 2, 3]).is_err());
+    }
+}
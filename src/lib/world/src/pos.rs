@@ -15,7 +15,7 @@ use bitcode_derive::{Decode, Encode};
 use deepsize::DeepSizeOf;
 use ferrumc_net_codec::net_types::network_position::NetworkPosition;
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct BlockPos {
     /// (i26, i12, i26)
     pub pos: IVec3,
@@ -91,6 +91,165 @@ impl Add<(i32, i32, i32)> for BlockPos {
     }
 }
 
+impl Add<Direction> for BlockPos {
+    type Output = BlockPos;
+
+    fn add(self, rhs: Direction) -> Self::Output {
+        self + rhs.offset()
+    }
+}
+
+/// One of the six directions a block face can point in.
+///
+/// The names match the values used by block state properties like `facing`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Down,
+    Up,
+    North,
+    South,
+    West,
+    East,
+}
+
+impl Direction {
+    /// All directions, in the same order as the protocol's block face ids.
+    pub const ALL: [Direction; 6] = [
+        Direction::Down,
+        Direction::Up,
+        Direction::North,
+        Direction::South,
+        Direction::West,
+        Direction::East,
+    ];
+
+    /// The horizontal directions, in clockwise order starting from north.
+    pub const HORIZONTAL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// Converts a block face id as sent by the client (e.g. in `use_item_on`) into a direction.
+    pub fn from_face(face: i32) -> Option<Direction> {
+        Self::ALL.get(usize::try_from(face).ok()?).copied()
+    }
+
+    /// Parses a direction from a block state property value, e.g. `"north"`.
+    pub fn from_name(name: &str) -> Option<Direction> {
+        Some(match name {
+            "down" => Direction::Down,
+            "up" => Direction::Up,
+            "north" => Direction::North,
+            "south" => Direction::South,
+            "west" => Direction::West,
+            "east" => Direction::East,
+            _ => return None,
+        })
+    }
+
+    /// The name used for this direction in block state properties.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Direction::Down => "down",
+            Direction::Up => "up",
+            Direction::North => "north",
+            Direction::South => "south",
+            Direction::West => "west",
+            Direction::East => "east",
+        }
+    }
+
+    /// The horizontal direction an entity with the given yaw is looking in.
+    pub fn from_yaw(yaw: f32) -> Direction {
+        // Yaw 0 is south and increases clockwise when looking down
+        match ((yaw / 90.0 + 0.5).floor() as i32).rem_euclid(4) {
+            0 => Direction::South,
+            1 => Direction::West,
+            2 => Direction::North,
+            _ => Direction::East,
+        }
+    }
+
+    /// The direction an entity with the given rotation is looking in the most, including up and
+    /// down.
+    pub fn from_rotation(yaw: f32, pitch: f32) -> Direction {
+        if pitch <= -45.0 {
+            Direction::Up
+        } else if pitch >= 45.0 {
+            Direction::Down
+        } else {
+            Direction::from_yaw(yaw)
+        }
+    }
+
+    /// The name of the axis this direction lies on, as used by the `axis` block state property.
+    pub const fn axis(self) -> &'static str {
+        match self {
+            Direction::Down | Direction::Up => "y",
+            Direction::North | Direction::South => "z",
+            Direction::West | Direction::East => "x",
+        }
+    }
+
+    pub const fn offset(self) -> (i32, i32, i32) {
+        match self {
+            Direction::Down => (0, -1, 0),
+            Direction::Up => (0, 1, 0),
+            Direction::North => (0, 0, -1),
+            Direction::South => (0, 0, 1),
+            Direction::West => (-1, 0, 0),
+            Direction::East => (1, 0, 0),
+        }
+    }
+
+    pub const fn opposite(self) -> Direction {
+        match self {
+            Direction::Down => Direction::Up,
+            Direction::Up => Direction::Down,
+            Direction::North => Direction::South,
+            Direction::South => Direction::North,
+            Direction::West => Direction::East,
+            Direction::East => Direction::West,
+        }
+    }
+
+    pub const fn is_horizontal(self) -> bool {
+        !matches!(self, Direction::Down | Direction::Up)
+    }
+
+    /// Rotates a horizontal direction 90 degrees clockwise when looking down. Vertical directions
+    /// are returned unchanged.
+    pub const fn rotate_clockwise(self) -> Direction {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+            other => other,
+        }
+    }
+
+    /// Rotates a horizontal direction 90 degrees counter-clockwise when looking down. Vertical
+    /// directions are returned unchanged.
+    pub const fn rotate_counter_clockwise(self) -> Direction {
+        match self {
+            Direction::North => Direction::West,
+            Direction::West => Direction::South,
+            Direction::South => Direction::East,
+            Direction::East => Direction::North,
+            other => other,
+        }
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str(self.as_str())
+    }
+}
+
 #[derive(Clone, Copy, DeepSizeOf, Encode, Decode)]
 pub struct ChunkHeight {
     pub min_y: i16,
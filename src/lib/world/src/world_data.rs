@@ -0,0 +1,81 @@
+use crate::errors::WorldError;
+use crate::World;
+use std::hash::Hasher;
+use tracing::trace;
+
+const TABLE: &str = "world_data";
+
+fn create_key(name: &str) -> u128 {
+    let mut hasher = wyhash::WyHash::with_seed(0);
+    hasher.write(name.as_bytes());
+    hasher.finish() as u128
+}
+
+impl World {
+    /// Loads world-wide data, such as the weather, from the storage backend and decodes it.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T` - A type that implements the `bitcode::DecodeOwned` trait, representing the structure of the decoded data.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name the data was saved under.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(T))` - The decoded data if it exists and can be successfully decoded.
+    /// * `Ok(None)` - If no data was saved under the name.
+    /// * `Err(WorldError)` - If an error occurs during the operation or decoding fails.
+    pub fn load_world_data<T: bitcode::DecodeOwned>(
+        &self,
+        name: &str,
+    ) -> Result<Option<T>, WorldError> {
+        if !self.storage_backend.table_exists(TABLE.to_string())? {
+            trace!(
+                "World data table does not exist. Returning None for {}",
+                name
+            );
+            return Ok(None);
+        }
+        let data = self
+            .storage_backend
+            .get(TABLE.to_string(), create_key(name))
+            .map_err(WorldError::DatabaseError)?;
+        data.map(|bytes| {
+            bitcode::decode(&bytes).map_err(|e| WorldError::BitcodeDecodeError(e.to_string()))
+        })
+        .transpose()
+    }
+
+    /// Saves world-wide data to the storage backend after encoding it.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T` - A type that implements the `bitcode::Encode` trait, representing the structure of the data to be encoded.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name to save the data under, replacing anything saved under it before.
+    /// * `data` - A reference to the data to be encoded and saved.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(true)` - If the data was successfully saved.
+    /// * `Ok(false)` - If the data could not be saved.
+    /// * `Err(WorldError)` - If an error occurs during the operation.
+    pub fn save_world_data<T: bitcode::Encode>(
+        &self,
+        name: &str,
+        data: &T,
+    ) -> Result<bool, WorldError> {
+        if !self.storage_backend.table_exists(TABLE.to_string())? {
+            self.storage_backend
+                .create_table(TABLE.to_string())
+                .map_err(WorldError::DatabaseError)?;
+        }
+        self.storage_backend
+            .upsert(TABLE.to_string(), create_key(name), bitcode::encode(data))
+            .map_err(WorldError::DatabaseError)
+    }
+}